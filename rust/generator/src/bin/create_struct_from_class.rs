@@ -0,0 +1,28 @@
+extern crate spongejni_gen;
+
+use spongejni_gen::{Config, Generator};
+use std::env::args;
+
+fn main() {
+  let mut arguments = args().skip(1);
+  let config = arguments.next().expect("no config file provided");
+  let out_dir = arguments.next().expect("no output directory provided");
+  let config = Config::open(config);
+  let pin_file = config.pin_file.clone();
+  let mut generator = Generator::with_config(config);
+  for path in arguments {
+    generator = generator.source(path);
+  }
+  let bindings = generator.generate();
+  bindings.write_tree(&out_dir).expect("could not write bindings");
+  if let Some(pin_file) = pin_file {
+    bindings.write_pins(pin_file).expect("could not write pin file");
+  }
+  // print the feature list for the crate manifest
+  let features = bindings.features();
+  println!("[features]");
+  println!("default = [{}]", features.iter().map(|x| format!("\"{}\"", x)).collect::<Vec<_>>().join(", "));
+  for name in features {
+    println!("{} = []", name);
+  }
+}
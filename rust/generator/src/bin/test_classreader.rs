@@ -0,0 +1,9 @@
+extern crate spongejni_gen;
+
+use spongejni_gen::Class;
+use std::env::args;
+
+fn main() {
+  let class_path = args().nth(1).expect("no path provided");
+  println!("{:#?}", Class::open(class_path));
+}
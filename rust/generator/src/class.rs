@@ -0,0 +1,214 @@
+use classreader::{ClassReader, ConstantPoolInfo, Attribute};
+use std::fs::{self, File};
+use std::io::{Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+
+/// The parts of a parsed class file that bindings are generated from.
+#[derive(Debug)]
+pub struct Class {
+  /// The internal name of the class, such as `org/spongepowered/api/block/BlockSnapshot$Builder`.
+  pub name: String,
+  pub methods: Vec<Method>,
+  /// Whether the class is abstract or an interface, and so has no constructors to bind.
+  pub is_abstract: bool,
+  /// Whether the class is an interface, whose wrapper calls default methods rather than superclass methods.
+  pub is_interface: bool,
+  /// Whether the class is an enum, bound as a Rust `enum` of its `constants`.
+  pub is_enum: bool,
+  /// The names of the enum constants, in declaration (and so ordinal) order.
+  pub constants: Vec<String>,
+  /// The Javadoc of the class, if its source was read.
+  pub doc: Option<String>
+}
+
+#[derive(Debug)]
+pub struct Method {
+  pub original_name: String,
+  pub name: String,
+  pub descriptor: String,
+  pub signature: Option<String>,
+  pub is_public: bool,
+  pub is_static: bool,
+  /// Whether the compiler generated the method, such as a bridge for a covariant return type.
+  pub is_synthetic: bool,
+  /// The names of the parameters, empty if neither the class file nor the source recorded them.
+  pub parameter_names: Vec<String>,
+  /// The Javadoc of the method, if its source was read.
+  pub doc: Option<String>
+}
+
+impl Class {
+  /// Parses a class from the bytes of a class file.
+  pub fn read<R: Read>(reader: &mut R) -> Class {
+    let class = ClassReader::new_from_reader(reader).expect("could not read valid class info");
+    let class_name = class.constant_pool.get((class.this_class - 1) as usize).expect("could not get class from constant pool");
+    let class_name = if let ConstantPoolInfo::Class(index) = class_name {
+      utf8(&class.constant_pool, *index).expect("class name was not a utf8 string")
+    } else {
+      panic!("class was not a Class");
+    };
+    let methods = class.methods.iter()
+    .map(|method| {
+      let name = if let ConstantPoolInfo::Utf8(name) = class.constant_pool.get((method.name_index - 1) as usize).expect("no name in constant pool for method") {
+        name
+      } else {
+        panic!("method name in constant pool was not a utf8 string");
+      };
+      let descriptor = if let ConstantPoolInfo::Utf8(descriptor) = class.constant_pool.get((method.descriptor_index - 1) as usize).expect("no descriptor in constant pool for method") {
+        descriptor
+      } else {
+        panic!("method descriptor in constant pool was not a utf8 string");
+      };
+      let signature = method.attributes
+        .iter()
+        .filter_map(|attr| match *attr {
+          Attribute::Signature { signature_index: index } => utf8(&class.constant_pool, index),
+          _ => None
+        })
+        .next();
+      let is_static = method.access_flags & 0x0008 == 0x0008;
+      let parameter_names = parameter_names(&class.constant_pool, &method.attributes, descriptor, is_static);
+      Method {
+        original_name: name.clone(),
+        name: name.clone(),
+        descriptor: descriptor.clone(),
+        signature: signature.cloned(),
+        is_public: method.access_flags & 0x0001 == 0x0001,
+        is_static,
+        is_synthetic: method.access_flags & (0x0040 | 0x1000) != 0,
+        parameter_names,
+        doc: None
+      }
+    })
+    .collect();
+    let constants = class.fields.iter()
+      .filter(|field| field.access_flags & 0x4000 == 0x4000)
+      .map(|field| utf8(&class.constant_pool, field.name_index).expect("field name was not a utf8 string").clone())
+      .collect();
+    Class {
+      name: class_name.clone(),
+      methods,
+      is_abstract: class.access_flags & 0x0400 == 0x0400,
+      is_interface: class.access_flags & 0x0200 == 0x0200,
+      is_enum: class.access_flags & 0x4000 == 0x4000,
+      constants,
+      doc: None
+    }
+  }
+
+  /// Parses the class file at `path`.
+  pub fn open<P: AsRef<Path>>(path: P) -> Class {
+    let mut file = File::open(path).expect("could not open path");
+    Class::read(&mut file)
+  }
+}
+
+fn utf8(constant_pool: &[ConstantPoolInfo], index: u16) -> Option<&String> {
+  match constant_pool.get((index as usize).checked_sub(1)?) {
+    Some(ConstantPoolInfo::Utf8(string)) => Some(string),
+    _ => None
+  }
+}
+
+/// The parameter names recorded by `javac -parameters` (MethodParameters) or `javac -g` (LocalVariableTable).
+///
+/// Abstract methods have no local variables, so interfaces only get names from the MethodParameters attribute.
+fn parameter_names(constant_pool: &[ConstantPoolInfo], attributes: &[Attribute], descriptor: &str, is_static: bool) -> Vec<String> {
+  let parameters = attributes.iter()
+    .filter_map(|attr| match *attr {
+      Attribute::MethodParameters(ref parameters) => Some(parameters),
+      _ => None
+    })
+    .next();
+  if let Some(parameters) = parameters {
+    let names: Option<Vec<String>> = parameters.iter().map(|x| utf8(constant_pool, x.name_index).cloned()).collect();
+    if let Some(names) = names {
+      return names;
+    }
+  }
+  let locals = attributes.iter()
+    .filter_map(|attr| match *attr {
+      Attribute::Code { ref attributes, .. } => attributes.iter()
+        .filter_map(|attr| match *attr {
+          Attribute::LocalVariableTable(ref locals) => Some(locals),
+          _ => None
+        })
+        .next(),
+      _ => None
+    })
+    .next();
+  let locals = match locals {
+    Some(locals) => locals,
+    None => return Vec::new()
+  };
+  // parameters occupy the first local slots, after `this`; longs and doubles take two
+  let mut slot = if is_static { 0 } else { 1 };
+  let mut names = Vec::new();
+  for size in parameter_sizes(descriptor) {
+    let name = locals.iter()
+      .find(|x| x.index == slot && x.start_pc == 0)
+      .and_then(|x| utf8(constant_pool, x.name_index));
+    match name {
+      Some(name) => names.push(name.clone()),
+      None => return Vec::new()
+    }
+    slot += size;
+  }
+  names
+}
+
+/// The number of local variable slots each parameter in `descriptor` takes.
+fn parameter_sizes(descriptor: &str) -> Vec<u16> {
+  let params = descriptor[1..].split(')').next().expect("no params in descriptor");
+  let mut sizes = Vec::new();
+  let mut chars = params.chars();
+  while let Some(c) = chars.next() {
+    let mut c = c;
+    let is_array = c == '[';
+    while c == '[' {
+      c = chars.next().expect("no array type in param");
+    }
+    if c == 'L' {
+      for next in chars.by_ref() {
+        if next == ';' {
+          break;
+        }
+      }
+    }
+    sizes.push(if !is_array && (c == 'J' || c == 'D') { 2 } else { 1 });
+  }
+  sizes
+}
+
+/// Parses every class file below `path`, which may be a class file or a directory.
+pub fn read_directory(path: &Path, classes: &mut Vec<Class>) {
+  let mut files = Vec::new();
+  collect_class_files(path, &mut files);
+  files.sort();
+  classes.extend(files.iter().map(Class::open));
+}
+
+/// Parses every class file in a jar.
+pub fn read_jar<R: Read + Seek>(reader: R, classes: &mut Vec<Class>) {
+  let mut jar = ZipArchive::new(reader).expect("could not read jar");
+  let mut names: Vec<String> = jar.file_names()
+    .filter(|x| x.ends_with(".class"))
+    .map(|x| x.to_owned())
+    .collect();
+  names.sort();
+  for name in names {
+    let mut entry = jar.by_name(&name).expect("could not read jar entry");
+    classes.push(Class::read(&mut entry));
+  }
+}
+
+fn collect_class_files(path: &Path, files: &mut Vec<String>) {
+  if path.is_dir() {
+    for entry in fs::read_dir(path).expect("could not read directory") {
+      collect_class_files(&entry.expect("could not read directory entry").path(), files);
+    }
+  } else if path.extension().map(|x| x == "class").unwrap_or(false) {
+    files.push(path.to_string_lossy().into_owned());
+  }
+}
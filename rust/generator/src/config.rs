@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use toml;
+
+/// Generator configuration, usually read from a TOML file:
+///
+/// ```toml
+/// root_module = "sponge"
+/// convert = "::spongejni::convert"
+/// pin_file = "overloads.toml"
+/// exclude = [
+///   "org/spongepowered/api/eventgencore/",
+///   "org/spongepowered/api/util/generator/event/factory/ClassGenerator#generateField"
+/// ]
+///
+/// [packages]
+/// "org/spongepowered/api/" = ""
+/// "com/flowpowered/math/" = "flowpowered"
+///
+/// [types]
+/// "java/nio/file/Path" = "::std::path::PathBuf"
+///
+/// [renames]
+/// "org/spongepowered/api/text/Text#of(Ljava/lang/String;)Lorg/spongepowered/api/text/Text;" = "of_str"
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+  /// The name of the generated root module.
+  pub root_module: String,
+  /// The path generated code uses to refer to the root module, `::<root_module>` if unset.
+  pub root_path: Option<String>,
+  /// Whether each top-level module is gated behind a cargo feature of the same name.
+  pub feature_gates: bool,
+  /// The path of the module holding the `FromJava` and `ToJava` traits that mapped types are converted with.
+  pub convert: String,
+  /// The packages, in internal form with a trailing slash, whose classes are bound, each mapped to the module path
+  /// (relative to the root module, empty for the root module itself) its packages are generated in.
+  pub packages: BTreeMap<String, String>,
+  /// Classes, packages (with a trailing slash) and methods (`Class#name`, optionally followed by the descriptor) that
+  /// are not bound. References to excluded classes are left as `jobject`.
+  pub exclude: Vec<String>,
+  /// Java classes mapped to hand-written Rust types implementing `FromJava` and `ToJava` (or to `jobject`).
+  pub types: BTreeMap<String, String>,
+  /// Rust names for classes (`Class`) and methods (`Class#name`, optionally followed by the descriptor).
+  pub renames: BTreeMap<String, String>,
+  /// A file, relative to the config, keeping the names of overloaded methods (`Class#name(descriptor)`) stable
+  /// across regenerations. It is read if it exists and rewritten with the current names by `create_struct_from_class`.
+  pub pin_file: Option<String>,
+  /// The names read from `pin_file`.
+  #[serde(skip)]
+  pub pins: BTreeMap<String, String>
+}
+
+impl Default for Config {
+  fn default() -> Self {
+    let mut packages = BTreeMap::new();
+    packages.insert(String::from("org/spongepowered/api/"), String::new());
+    Config {
+      root_module: String::from("sponge"),
+      root_path: None,
+      feature_gates: true,
+      convert: String::from("::spongejni::convert"),
+      packages,
+      exclude: Vec::new(),
+      types: BTreeMap::new(),
+      renames: BTreeMap::new(),
+      pin_file: None,
+      pins: BTreeMap::new()
+    }
+  }
+}
+
+impl Config {
+  /// Parses a configuration from TOML. Keys that are not set keep their defaults.
+  pub fn from_toml(toml: &str) -> Result<Config, toml::de::Error> {
+    toml::from_str(toml)
+  }
+
+  /// Reads the configuration file at `path`, and the pin file it names if that exists.
+  pub fn open<P: AsRef<Path>>(path: P) -> Config {
+    let mut config = Config::from_toml(&read(path.as_ref()).expect("could not read config")).expect("invalid config");
+    if let Some(pin_file) = config.pin_file.take() {
+      let pin_file = path.as_ref().parent().unwrap_or_else(|| Path::new("")).join(pin_file);
+      if pin_file.exists() {
+        config.pins = toml::from_str(&read(&pin_file).expect("could not read pin file")).expect("invalid pin file");
+      }
+      config.pin_file = Some(pin_file.to_string_lossy().into_owned());
+    }
+    config
+  }
+
+  pub fn root_path(&self) -> String {
+    self.root_path.clone().unwrap_or_else(|| format!("::{}", self.root_module))
+  }
+
+  /// The bound package containing `class_name`, if the class is bound at all.
+  pub fn package_of(&self, class_name: &str) -> Option<&str> {
+    if self.is_excluded(class_name) {
+      return None;
+    }
+    self.packages.keys()
+      .filter(|x| class_name.starts_with(&***x))
+      .max_by_key(|x| x.len())
+      .map(|x| &**x)
+  }
+
+  /// Whether the class or method `name` is excluded.
+  pub fn is_excluded(&self, name: &str) -> bool {
+    self.exclude.iter().any(|x| matches(x, name))
+  }
+
+  /// The rename for the class or method `name`, if any.
+  pub fn rename(&self, name: &str) -> Option<&str> {
+    self.renames.iter()
+      .filter(|&(key, _)| matches(key, name))
+      .max_by_key(|&(key, _)| key.len())
+      .map(|(_, value)| &**value)
+  }
+}
+
+fn read(path: &Path) -> io::Result<String> {
+  let mut string = String::new();
+  File::open(path)?.read_to_string(&mut string)?;
+  Ok(string)
+}
+
+/// Matches an `exclude` or `renames` key against a class name (`a/B`) or method (`a/B#name(descriptor)`).
+///
+/// Keys ending in `/` match whole packages, method keys without a descriptor match every overload, and class keys
+/// match nothing but the class itself.
+fn matches(key: &str, name: &str) -> bool {
+  if key.ends_with('/') {
+    return name.starts_with(key);
+  }
+  if key.contains('#') && !key.contains('(') {
+    return name.split('(').next() == Some(key);
+  }
+  key == name
+}
@@ -0,0 +1,496 @@
+use inflector::Inflector;
+use toml;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use class::{Class, Method};
+use names::{self, Names, escape_keyword};
+
+const LINTS: &str = "non_snake_case, non_camel_case_types, unused_variables, unused_imports, clippy::wrong_self_convention, clippy::too_many_arguments, clippy::redundant_field_names, clippy::missing_safety_doc, clippy::module_inception, clippy::self_named_constructors, clippy::not_unsafe_ptr_arg_deref, clippy::macro_metavars_in_unsafe";
+const IMPORTS: &str = "use jni_sys::{JNIEnv, jvalue, jobject};\n";
+
+/// A Rust module in the generated tree.
+///
+/// Java packages become modules of their own, while the inner classes of a class are emitted inline in a module named
+/// after the outer class.
+#[derive(Debug, Default)]
+pub struct Module {
+  pub structs: Vec<String>,
+  pub packages: BTreeMap<String, Module>,
+  pub inner: BTreeMap<String, Module>
+}
+
+/// Generated bindings, ready to be written out.
+pub struct Bindings {
+  pub root_module: String,
+  pub feature_gates: bool,
+  pub root: Module,
+  /// The Rust name of every overloaded method, keyed like the config's `renames`.
+  pub overloads: BTreeMap<String, String>
+}
+
+impl Bindings {
+  /// The features gating each top-level package, which the crate including the bindings must declare.
+  pub fn features(&self) -> Vec<String> {
+    if self.feature_gates {
+      self.root.packages.keys().cloned().collect()
+    } else {
+      Vec::new()
+    }
+  }
+
+  /// Writes the names of overloaded methods to `path`, for the config's `pin_file`.
+  pub fn write_pins<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    let mut string = String::from("# The Rust names of overloaded methods, kept when the bindings are regenerated so calls keep pointing at\n");
+    string.push_str("# the same Java overload. Edit a name to rename the method; remove a line to derive its name again.\n");
+    string.push_str(&toml::to_string(&self.overloads).expect("could not serialize overload names"));
+    File::create(path)?.write_all(string.as_bytes())
+  }
+
+  /// Writes the bindings as a directory tree with a `mod.rs` per package, rooted at `path`.
+  pub fn write_tree<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    self.write_module(path.as_ref(), &self.root, true)
+  }
+
+  /// Writes the bindings as a single file declaring the root module, suitable for `include!` from a build script's
+  /// `OUT_DIR`.
+  pub fn write_file<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    if let Some(parent) = path.as_ref().parent() {
+      fs::create_dir_all(parent)?;
+    }
+    File::create(path)?.write_all(self.to_source().as_bytes())
+  }
+
+  /// The bindings as a single source string declaring the root module.
+  pub fn to_source(&self) -> String {
+    let body = self.render_inline(&self.root, true);
+    format!("#[allow({})]\npub mod {} {{\n{}\n}}\n", LINTS, self.root_module, indent(body.trim_end(), 1))
+  }
+
+  fn render_package_declarations(&self, module: &Module, is_root: bool, string: &mut String) {
+    if !module.packages.is_empty() {
+      string.push('\n');
+    }
+    for name in module.packages.keys() {
+      if is_root && self.feature_gates {
+        string.push_str(&format!("#[cfg(feature = \"{}\")]\n", name));
+      }
+      string.push_str(&format!("pub mod {};\n", name));
+    }
+  }
+
+  fn render_inline(&self, module: &Module, is_root: bool) -> String {
+    let mut string = String::from(IMPORTS);
+    for (name, package) in &module.packages {
+      string.push('\n');
+      if is_root && self.feature_gates {
+        string.push_str(&format!("#[cfg(feature = \"{}\")]\n", name));
+      }
+      let body = self.render_inline(package, false);
+      string.push_str(&format!("pub mod {} {{\n{}\n}}\n", name, indent(body.trim_end(), 1)));
+    }
+    render_items(module, &mut string);
+    string
+  }
+
+  fn write_module(&self, path: &Path, module: &Module, is_root: bool) -> io::Result<()> {
+    fs::create_dir_all(path)?;
+    let mut string = String::new();
+    if is_root {
+      string.push_str(&format!("#![allow({})]\n\n", LINTS));
+    }
+    string.push_str(IMPORTS);
+    self.render_package_declarations(module, is_root, &mut string);
+    render_items(module, &mut string);
+    File::create(path.join("mod.rs"))?.write_all(string.as_bytes())?;
+    for (name, package) in &module.packages {
+      self.write_module(&path.join(name), package, false)?;
+    }
+    Ok(())
+  }
+}
+
+/// Adds `class` to the module tree below `root`.
+pub fn add_class(names: &Names, root: &mut Module, class: Class, overloads: &mut BTreeMap<String, String>) {
+  let packages = names.package_segments(&class.name).expect("class is not bound").0.len();
+  let segments = names.module_segments(&class.name);
+  let mut module = root;
+  for (i, segment) in segments.into_iter().enumerate() {
+    module = if i < packages {
+      module.packages.entry(segment).or_default()
+    } else {
+      module.inner.entry(segment).or_default()
+    };
+  }
+  module.structs.push(create_struct(names, class, overloads));
+}
+
+pub fn create_method(names: &Names, class_name: &str, method: &Method) -> String {
+  let mut string = String::new();
+  let snake_case_name = method.name.to_snake_case();
+  let is_constructor = method.original_name == "<init>";
+  // constructors return the new object, which their `(...)V` descriptor does not say
+  let descriptor = if is_constructor {
+    format!("{}L{};", &method.descriptor[..method.descriptor.len() - 1], class_name)
+  } else {
+    method.descriptor.clone()
+  };
+  let rust_params = names.create_params(&descriptor, method.signature.clone(), method.is_static, &method.parameter_names);
+  let call_method = get_call_method(&descriptor, &rust_params, method.is_static);
+  // enum values carry no JNIEnv of their own
+  let is_enum_method = names.is_enum(class_name) && !method.is_static;
+  let rust_params = if is_enum_method {
+    rust_params.replacen("(&self", "(&self, env: *mut JNIEnv", 1)
+  } else {
+    rust_params
+  };
+  let map_params = rust_params
+    .split(", ")
+    .filter_map(|x| {
+      let mut x = x.split(")").next().expect("invalid params 0");
+      if x.starts_with('(') {
+        x = &x[1..];
+      }
+      let mut split = x.split(": ");
+      let name = split.next().expect("invalid param 1");
+      if name == "self" || name == "&self" {
+        return None;
+      }
+      let t = split.next().expect("invalid param 2");
+      if name == "env" && t == "*mut JNIEnv" {
+        None
+      } else {
+        Some((name, t))
+      }
+    })
+    .collect::<Vec<_>>();
+  let mut unimplemented = false;
+  if map_params.iter().any(|x| x.1.starts_with("Vec<")) {
+    unimplemented = true;
+  }
+  string.push('\n');
+  if let Some(ref doc) = method.doc {
+    string.push_str(&doc_comment(doc, "  "));
+  }
+  let features = names.required_features(class_name, &rust_params);
+  match features.len() {
+    0 => {},
+    1 => string.push_str(&format!("  #[cfg(feature = \"{}\")]\n", features.iter().next().unwrap())),
+    _ => string.push_str(&format!("  #[cfg(all({}))]\n", features.iter().map(|x| format!("feature = \"{}\"", x)).collect::<Vec<_>>().join(", ")))
+  }
+  string.push_str(&format!("  pub fn {}", snake_case_name));
+  string.push_str(&rust_params);
+  string.push_str(" {\n");
+  string.push_str("    ");
+  if call_method.contains("CryInside") {
+    unimplemented = true;
+  }
+  if unimplemented {
+    string.push_str("unimplemented!();\n  }");
+    return string;
+  }
+  if call_method.contains("ObjectMethod") {
+    string.push_str("let ret = ");
+  }
+  let (s, caller) = if method.is_static {
+    ("", None)
+  } else if is_enum_method {
+    ("", Some(format!("{}::ToJava::to_java(self, env)", names.config.convert)))
+  } else {
+    ("self.", Some("self.object".to_owned()))
+  };
+  if call_method.contains("CharMethod") {
+    string.push_str(&format!("{}::char_from_java(", names.config.convert));
+  }
+  if is_constructor {
+    string.push_str(&format!(r#"new_object!(env, "{}", "{}""#, class_name, method.descriptor));
+  } else {
+    // resolved on the declaring class, so interface and static methods are found wherever the object came from
+    match caller {
+      Some(caller) => string.push_str(&format!(r#"bound_method!({}env, "{}", {}, "{}", "{}", {}"#, s, class_name, caller, method.original_name, method.descriptor, call_method)),
+      None => string.push_str(&format!(r#"static_java_method!(env, "{}", "{}", "{}", {}"#, class_name, method.original_name, method.descriptor, call_method))
+    }
+  }
+  if !map_params.is_empty() {
+    for param in map_params {
+      let (name, t) = param;
+      if names.is_mapped(t) {
+        string.push_str(&format!(", {}::ToJava::to_java(&{}, {}env)", names.config.convert, name, s));
+        continue;
+      }
+      let obj = if !t.starts_with("Vec<") && t.split("::").last().expect("invalid type").chars().next().expect("invalid type 2").is_uppercase() {
+        ".object"
+      } else {
+        ""
+      };
+      string.push_str(&format!(", {}{}", name, obj));
+    }
+  }
+  string.push(')');
+  if call_method.contains("BooleanMethod") {
+    // any jboolean other than JNI_FALSE is true
+    string.push_str(" != 0");
+  }
+  if call_method.contains("CharMethod") {
+    string.push(')');
+  }
+  if call_method.contains("VoidMethod") || call_method.contains("ObjectMethod") {
+    string.push(';');
+  }
+  string.push('\n');
+  if call_method.contains("ObjectMethod") {
+    string.push_str(&format!("    if ret.is_null() {{ panic!(\"{}#{} was null\") }}\n", class_name, method.original_name));
+    let return_type = rust_params.split(" -> ").last().expect("no return type");
+    if return_type == "jobject" {
+      string.push_str("    ret\n");
+    } else if names.is_mapped(return_type) {
+      string.push_str(&format!("    <{} as {}::FromJava>::from_java({}env, ret)\n", return_type, names.config.convert, s));
+    } else if return_type.starts_with("Option") {
+      let optional_return_type = names.get_optional_return_type(method.signature.clone().expect("optional return type without signature"));
+      string.push_str(&format!(r#"    let unwrapped = bound_method!({}env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);"#, s));
+      string.push_str("\n    if unwrapped.is_null() { None } else { ");
+      if optional_return_type == "jobject" {
+        string.push_str("Some(unwrapped) }\n");
+      } else if names.is_mapped(&optional_return_type) {
+        string.push_str(&format!("Some(<{} as {}::FromJava>::from_java({}env, unwrapped)) }}\n", optional_return_type, names.config.convert, s));
+      } else {
+        string.push_str(&format!("Some({} {{ env: {}env, object: unwrapped }}) }}\n", optional_return_type, s));
+      }
+    } else {
+      string.push_str(&format!("    {} {{ env: {}env, object: ret }}\n", return_type, s));
+    }
+  }
+  string.push_str("  }\n");
+  string
+}
+
+/// Renders the struct or enum for `class`, recording the names given to overloaded methods in `overloads`.
+pub fn create_struct(names: &Names, class: Class, overloads: &mut BTreeMap<String, String>) -> String {
+  let mut string = String::new();
+  let end_name = names.struct_name(&class.name);
+  if let Some(ref doc) = class.doc {
+    string.push_str(&doc_comment(doc, ""));
+  }
+  let is_enum = names.is_enum(&class.name);
+  let class_name = class.name;
+  let is_abstract = class.is_abstract;
+  let mut methods: Vec<Method> = class.methods.into_iter()
+    .filter(|m| m.is_public && !m.is_synthetic)
+    // abstract classes and interfaces cannot be instantiated
+    .filter(|m| !(is_abstract && m.original_name == "<init>"))
+    // covered by VALUES and FromJava
+    .filter(|m| !(is_enum && (m.original_name == "values" || m.original_name == "valueOf") && m.is_static))
+    .filter(|m| !names.config.is_excluded(&format!("{}#{}{}", class_name, m.original_name, m.descriptor)))
+    .collect();
+  let mut used_names = HashSet::new();
+  if is_enum {
+    string.push_str(&create_enum(names, &class_name, &end_name, &class.constants));
+  } else {
+    string.push_str(&format!("#[derive(Debug)]\npub struct {} {{\n  pub env: *mut JNIEnv,\n  pub object: jobject\n}}", end_name));
+    string.push_str(&format!("\n\nimpl {} {{", end_name));
+    string.push_str("\n  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {\n");
+    string.push_str(&format!("    {} {{\n", end_name));
+    string.push_str("      env: env,\n      object: object\n    }\n  }\n");
+    used_names.insert(String::from("from"));
+    // lets Rust implementations of the class delegate to the Java implementation of a method they override
+    let (helper, implementation) = if class.is_interface {
+      ("call_default", "default implementation")
+    } else {
+      ("call_super", "implementation")
+    };
+    string.push_str(&format!("\n  /// Calls the method `name` with `descriptor` on this object through the {} in `{}`, even if\n", implementation, end_name));
+    string.push_str("  /// the class of the object overrides it.\n");
+    string.push_str(&format!("  pub fn {}<T: {}::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {{\n", helper, names.config.convert));
+    string.push_str(&format!("    let ret = {}::call_nonvirtual(self.env, self.object, \"{}\", name, descriptor, args);\n", names.config.convert, class_name));
+    string.push_str(&format!("    {}::FromJValue::from_jvalue(ret)\n  }}\n", names.config.convert));
+    used_names.insert(String::from(helper));
+  }
+  // overloads are told apart by their parameter types, unless a name is pinned or renamed in the config
+  let keys: Vec<String> = methods.iter()
+    .map(|m| format!("{}#{}{}", class_name, m.original_name, m.descriptor))
+    .collect();
+  let bases: Vec<String> = methods.iter()
+    .map(|m| if m.original_name == "<init>" { String::from("new") } else { escape_keyword(&m.name.to_snake_case()) })
+    .collect();
+  let mut overload_counts = HashMap::new();
+  for base in &bases {
+    *overload_counts.entry(base.clone()).or_insert(0) += 1;
+  }
+  let fixed: Vec<Option<String>> = keys.iter()
+    .map(|key| names.config.rename(key).or_else(|| names.config.pins.get(key).map(|x| &**x)).map(|x| x.to_owned()))
+    .collect();
+  used_names.extend(fixed.iter().flatten().map(|x| x.to_snake_case()));
+  for (i, method) in methods.iter_mut().enumerate() {
+    if method.original_name == "<init>" {
+      method.is_static = true;
+    }
+    let is_overloaded = overload_counts[&bases[i]] > 1;
+    method.name = match fixed[i] {
+      Some(ref name) => name.clone(),
+      None => {
+        let suffixed = if names::has_no_params(&method.descriptor) {
+          None
+        } else {
+          Some(format!("{}_{}", bases[i], names.overload_suffix(&method.descriptor)))
+        };
+        // a name taken by another method or a generated helper is told apart by its parameter types too
+        let mut candidates = Vec::new();
+        if !is_overloaded || suffixed.is_none() {
+          candidates.push(bases[i].clone());
+        }
+        candidates.extend(suffixed);
+        let name = candidates.into_iter()
+          .find(|x| !used_names.contains(&x.to_snake_case()))
+          .unwrap_or_else(|| panic!("no free name for {}; pin one in the pin file", keys[i]));
+        used_names.insert(name.to_snake_case());
+        name
+      }
+    };
+    let is_renamed = method.name != bases[i];
+    if (is_overloaded || is_renamed || names.config.pins.contains_key(&keys[i])) && names.config.rename(&keys[i]).is_none() {
+      overloads.insert(keys[i].clone(), method.name.to_snake_case());
+    }
+    string.push_str(&create_method(names, &class_name, method));
+  }
+  string.push_str("\n}");
+  string.push('\n');
+  if is_enum {
+    string.push_str(&create_enum_conversions(names, &end_name, &class.constants));
+  }
+  string
+}
+
+/// The name of the static holding the constants of the enum `end_name`.
+fn constants_name(end_name: &str) -> String {
+  format!("{}_CONSTANTS", end_name.to_screaming_snake_case())
+}
+
+/// Renders an enum class as a Rust `enum`, its constants and the start of its `impl` block.
+fn create_enum(names: &Names, class_name: &str, end_name: &str, constants: &[String]) -> String {
+  let variants: Vec<String> = constants.iter().map(|x| variant_name(x)).collect();
+  let mut string = String::from("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+  string.push_str(&format!("pub enum {} {{\n", end_name));
+  string.push_str(&variants.iter().map(|x| format!("  {},\n", x)).collect::<String>());
+  string.push_str("  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.\n");
+  string.push_str(&format!("  {}(jobject)\n", other_variant(&variants)));
+  string.push_str("}\n\n");
+  string.push_str(&format!(
+    "static {}: {}::EnumConstants = {}::EnumConstants::new(\"{}\", &[{}]);",
+    constants_name(end_name),
+    names.config.convert,
+    names.config.convert,
+    class_name,
+    constants.iter().map(|x| format!("\"{}\"", x)).collect::<Vec<_>>().join(", ")
+  ));
+  string.push_str(&format!("\n\nimpl {} {{\n", end_name));
+  string.push_str(&format!(
+    "  pub const VALUES: &'static [{}] = &[{}];\n",
+    end_name,
+    variants.iter().map(|x| format!("{}::{}", end_name, x)).collect::<Vec<_>>().join(", ")
+  ));
+  string
+}
+
+/// Renders the `FromJava` and `ToJava` implementations of the enum `end_name`, which go through the cached constants.
+fn create_enum_conversions(names: &Names, end_name: &str, constants: &[String]) -> String {
+  let convert = &names.config.convert;
+  let other = other_variant(&constants.iter().map(|x| variant_name(x)).collect::<Vec<_>>());
+  let constants = constants_name(end_name);
+  let mut string = String::new();
+  string.push_str(&format!("\nimpl {}::FromJava for {} {{\n", convert, end_name));
+  string.push_str("  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {\n");
+  string.push_str(&format!("    match {}.ordinal(env, object) {{\n", constants));
+  string.push_str(&format!("      Some(ordinal) => {}::VALUES[ordinal],\n", end_name));
+  string.push_str(&format!("      None => {}::{}(object)\n", end_name, other));
+  string.push_str("    }\n  }\n}\n");
+  string.push_str(&format!("\nimpl {}::ToJava for {} {{\n", convert, end_name));
+  string.push_str("  fn to_java(&self, env: *mut JNIEnv) -> jobject {\n");
+  string.push_str("    match *self {\n");
+  string.push_str(&format!("      {}::{}(object) => object,\n", end_name, other));
+  string.push_str(&format!("      value => {}.get(env, {}::VALUES.iter().position(|x| *x == value).expect(\"no constant for variant\"))\n", constants, end_name));
+  string.push_str("    }\n  }\n}\n");
+  string
+}
+
+/// The name of the variant holding constants the bindings do not list, `Other` unless a constant is called that.
+fn other_variant(variants: &[String]) -> String {
+  let mut name = String::from("Other");
+  while variants.contains(&name) {
+    name.push('_');
+  }
+  name
+}
+
+/// The Rust variant name of an enum constant (`ACCEPT_NONVALUE` is `AcceptNonvalue`).
+fn variant_name(constant: &str) -> String {
+  let name: String = constant.split('_')
+    .map(|x| {
+      let mut chars = x.chars();
+      chars.next().map(|first| first.to_uppercase().chain(chars.flat_map(|c| c.to_lowercase())).collect()).unwrap_or_default()
+    })
+    .collect::<Vec<String>>()
+    .concat();
+  if name.is_empty() || name == "Self" || name.chars().next().map(|x| x.is_ascii_digit()).unwrap_or(false) {
+    format!("_{}", constant)
+  } else {
+    name
+  }
+}
+
+fn get_call_method<'a>(descriptor: &'a str, params: &'a str, is_static: bool) -> String {
+  let num_params = params.split(",").collect::<Vec<_>>().len() - 1;
+  let return_type = descriptor.split(')').next_back().expect("no return type");
+  let first_letter = return_type.chars().next().expect("no first letter of return type");
+  let mut call_method = match first_letter {
+    'B' => "CallByteMethod",
+    'C' => "CallCharMethod",
+    'D' => "CallDoubleMethod",
+    'F' => "CallFloatMethod",
+    'I' => "CallIntMethod",
+    'J' => "CallLongMethod",
+    'S' => "CallShortMethod",
+    'Z' => "CallBooleanMethod",
+    'L' => "CallObjectMethod",
+    'V' => "CallVoidMethod",
+    '[' => "CryInside", // FIXME
+    _ => panic!("unsupported call method: {}", first_letter)
+  }.to_owned();
+  if num_params > 0 {
+    call_method.push('A');
+  }
+  if is_static {
+    call_method = call_method.replace("Call", "CallStatic");
+  }
+  // FIXME: varargs
+  call_method
+}
+
+/// Renders Javadoc, already converted to Markdown, as `///` comments.
+fn doc_comment(doc: &str, indent: &str) -> String {
+  doc.lines()
+    .map(|x| if x.is_empty() { format!("{}///\n", indent) } else { format!("{}/// {}\n", indent, x) })
+    .collect()
+}
+
+fn indent(code: &str, depth: usize) -> String {
+  let prefix = "  ".repeat(depth);
+  code.lines()
+    .map(|x| if x.is_empty() { String::new() } else { format!("{}{}", prefix, x) })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Renders the structs and inline inner class modules of `module`.
+fn render_items(module: &Module, string: &mut String) {
+  for item in &module.structs {
+    string.push('\n');
+    string.push_str(item);
+  }
+  for (name, inner) in &module.inner {
+    let mut body = String::from(IMPORTS);
+    render_items(inner, &mut body);
+    string.push_str(&format!("\npub mod {} {{\n{}\n}}\n", name, indent(&body, 1)));
+  }
+}
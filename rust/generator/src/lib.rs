@@ -0,0 +1,192 @@
+//! Generates Rust bindings for Java classes, calling into the JVM through the `bound_method!`, `static_java_method!`
+//! and `new_object!` macros exported by `spongejni`.
+//!
+//! The `create_struct_from_class` binary uses this to generate the SpongeAPI bindings checked in to `spongejni`, but
+//! it can also be called from a build script to bind other Java code alongside them:
+//!
+//! ```no_run
+//! extern crate spongejni_gen;
+//!
+//! use spongejni_gen::Generator;
+//! use std::env;
+//! use std::path::Path;
+//!
+//! fn main() {
+//!   let out_dir = env::var("OUT_DIR").unwrap();
+//!   let generator = Generator::config_file("mymod.toml")
+//!     .source("libs/mymod.jar");
+//!   generator.print_rerun_if_changed();
+//!   generator.generate()
+//!     .write_file(Path::new(&out_dir).join("mymod.rs"))
+//!     .expect("could not write bindings");
+//! }
+//! ```
+//!
+//! with `mymod.toml` binding the mod's packages (see [`Config`](struct.Config.html) for every key):
+//!
+//! ```toml
+//! root_module = "mymod"
+//! feature_gates = false
+//!
+//! [packages]
+//! "com/example/mymod/" = ""
+//!
+//! [types]
+//! "org/spongepowered/api/text/Text" = "jobject"
+//! ```
+//!
+//! The crate then includes the generated module from its root, next to `#[macro_use] extern crate spongejni;` and
+//! `extern crate jni_sys;`:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/mymod.rs"));
+//! ```
+
+#[macro_use]
+extern crate serde_derive;
+extern crate classreader;
+extern crate inflector;
+extern crate toml;
+extern crate zip;
+
+mod class;
+mod config;
+mod emit;
+mod names;
+mod source;
+
+pub use class::{Class, Method};
+pub use config::Config;
+pub use emit::Bindings;
+
+use emit::Module;
+use names::{Names, is_anonymous};
+use source::Source;
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Configures which classes bindings are generated for, and where they end up.
+#[derive(Default)]
+pub struct Generator {
+  config: Config,
+  sources: Vec<PathBuf>,
+  classes: Vec<Class>
+}
+
+impl Generator {
+  /// A generator for SpongeAPI, emitting the `sponge` module.
+  pub fn new() -> Self {
+    Generator::default()
+  }
+
+  /// A generator using `config`.
+  pub fn with_config(config: Config) -> Self {
+    Generator {
+      config,
+      ..Generator::default()
+    }
+  }
+
+  /// A generator using the TOML configuration file at `path`.
+  pub fn config_file<P: AsRef<Path>>(path: P) -> Self {
+    Generator::with_config(Config::open(path))
+  }
+
+  /// Binds only the package, in internal form with a trailing slash (`org/spongepowered/api/`), at the root module.
+  ///
+  /// Classes outside of it are skipped, and references to them are left as `jobject`.
+  pub fn package<S: Into<String>>(mut self, package: S) -> Self {
+    self.config.packages.clear();
+    self.config.packages.insert(package.into(), String::new());
+    self
+  }
+
+  /// Sets the name of the generated root module.
+  pub fn root_module<S: Into<String>>(mut self, name: S) -> Self {
+    self.config.root_module = name.into();
+    self
+  }
+
+  /// Sets the path generated code uses to refer to the root module. Defaults to `::<root module>`, which resolves
+  /// when the module is declared in the crate root of a 2015 edition crate; use `crate::<root module>` otherwise.
+  pub fn root_path<S: Into<String>>(mut self, path: S) -> Self {
+    self.config.root_path = Some(path.into());
+    self
+  }
+
+  /// Sets whether each top-level package is gated behind a cargo feature of the same name. Defaults to `true`.
+  pub fn feature_gates(mut self, feature_gates: bool) -> Self {
+    self.config.feature_gates = feature_gates;
+    self
+  }
+
+  /// Adds a class file, a directory of class files or a jar to read classes from.
+  ///
+  /// Java source files found the same way (such as in a `-sources` jar) name the parameters of the classes' methods
+  /// and carry their Javadoc over to the bindings.
+  pub fn source<P: Into<PathBuf>>(mut self, path: P) -> Self {
+    self.sources.push(path.into());
+    self
+  }
+
+  /// Adds an already parsed class.
+  pub fn class(mut self, class: Class) -> Self {
+    self.classes.push(class);
+    self
+  }
+
+  /// Prints `cargo:rerun-if-changed` for every source, for use from a build script.
+  pub fn print_rerun_if_changed(&self) {
+    for source in &self.sources {
+      println!("cargo:rerun-if-changed={}", source.display());
+    }
+  }
+
+  /// Reads every class from the sources, documented by any Java sources among them.
+  fn read_classes(&mut self) -> Vec<Class> {
+    let mut classes: Vec<Class> = self.classes.drain(..).collect();
+    let mut java_sources = Vec::new();
+    for source in &self.sources {
+      if source.is_dir() {
+        class::read_directory(source, &mut classes);
+        source::read_directory(source, &mut java_sources);
+      } else if source.extension().map(|x| x == "jar").unwrap_or(false) {
+        class::read_jar(File::open(source).expect("could not open jar"), &mut classes);
+        source::read_jar(File::open(source).expect("could not open jar"), &mut java_sources);
+      } else if source.extension().map(|x| x == "java").unwrap_or(false) {
+        java_sources.push(Source::open(source));
+      } else {
+        classes.push(Class::open(source));
+      }
+    }
+    source::apply(&java_sources, &mut classes);
+    classes
+  }
+
+  /// Generates bindings for every bound class in the sources.
+  pub fn generate(mut self) -> Bindings {
+    let mut classes: Vec<Class> = self.read_classes()
+      .into_iter()
+      .filter(|x| self.config.package_of(&x.name).is_some())
+      .filter(|x| x.name.split('/').next_back().expect("no end class name") != "package-info")
+      .filter(|x| !is_anonymous(&x.name))
+      .collect();
+    if classes.is_empty() {
+      panic!("no classes in the configured packages were found");
+    }
+    classes.sort_by(|a, b| a.name.cmp(&b.name));
+    let names = Names::new(&self.config, &classes);
+    let mut root = Module::default();
+    let mut overloads = BTreeMap::new();
+    for class in classes {
+      emit::add_class(&names, &mut root, class, &mut overloads);
+    }
+    Bindings {
+      root_module: self.config.root_module,
+      feature_gates: self.config.feature_gates,
+      root,
+      overloads
+    }
+  }
+}
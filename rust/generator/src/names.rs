@@ -0,0 +1,381 @@
+use inflector::Inflector;
+use std::collections::{BTreeSet, HashMap};
+
+use class::Class;
+use config::Config;
+
+const KEYWORDS: &[&str] = &[
+  "abstract", "alignof", "as", "become", "box", "break", "const", "continue", "crate", "do", "else", "enum", "extern",
+  "false", "final", "fn", "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "offsetof",
+  "override", "priv", "proc", "pub", "pure", "ref", "return", "self", "sizeof", "static", "struct", "super", "trait",
+  "true", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield"
+];
+
+/// Maps Java class names onto paths in the generated module tree.
+pub struct Names {
+  pub config: Config,
+  /// The path of the root module, such as `::sponge`.
+  pub root_path: String,
+  /// Every package module being generated, as its segments below the root module joined with `::`.
+  pub modules: BTreeSet<String>,
+  /// Every class bound as a Rust `enum`.
+  pub enums: BTreeSet<String>,
+  /// The Rust paths of `enums`.
+  enum_paths: BTreeSet<String>
+}
+
+pub fn escape_keyword(name: &str) -> String {
+  if KEYWORDS.contains(&name) {
+    format!("{}_", name)
+  } else {
+    name.to_owned()
+  }
+}
+
+/// The Rust name of a Java parameter. `env` is taken by the `JNIEnv` of static methods.
+pub fn parameter_name(name: &str) -> String {
+  match name.to_snake_case() {
+    ref x if x == "env" => String::from("env_"),
+    x => escape_keyword(&x)
+  }
+}
+
+/// Splits a class name relative to the bound package into its packages and its (possibly nested) class names.
+pub fn split_class_name(relative_name: &str) -> (Vec<&str>, Vec<&str>) {
+  let mut packages: Vec<&str> = relative_name.split('/').collect();
+  let classes = packages.pop().expect("no class name").split('$').collect();
+  (packages, classes)
+}
+
+/// Anonymous and local classes (`Foo$1`) are not part of the API and get no bindings.
+pub fn is_anonymous(class_name: &str) -> bool {
+  split_class_name(class_name).1.iter().skip(1).any(|x| x.chars().next().map(|c| c.is_ascii_digit()).unwrap_or(true))
+}
+
+impl Names {
+  pub fn new(config: &Config, classes: &[Class]) -> Self {
+    let mut names = Names {
+      config: config.clone(),
+      root_path: config.root_path(),
+      modules: BTreeSet::new(),
+      enums: BTreeSet::new(),
+      enum_paths: BTreeSet::new()
+    };
+    let mut modules = BTreeSet::new();
+    for class in classes {
+      if let Some((segments, _)) = names.package_segments(&class.name) {
+        for i in 1..segments.len() + 1 {
+          modules.insert(segments[..i].join("::"));
+        }
+      }
+    }
+    names.modules = modules;
+    names.enums = classes.iter()
+      .filter(|x| x.is_enum && !x.constants.is_empty() && names.is_bound(&x.name))
+      .map(|x| x.name.clone())
+      .collect();
+    names.enum_paths = names.enums.iter().map(|x| names.sanitize_class_name(x)).collect();
+    names
+  }
+
+  /// Whether bindings are generated for `class_name`.
+  pub fn is_bound(&self, class_name: &str) -> bool {
+    self.config.package_of(class_name).is_some()
+  }
+
+  /// The module segments of the package containing `class_name`, followed by the class and its outer classes.
+  pub fn package_segments<'a>(&self, class_name: &'a str) -> Option<(Vec<String>, Vec<&'a str>)> {
+    let package = self.config.package_of(class_name)?;
+    let module = &self.config.packages[package];
+    let (packages, classes) = split_class_name(&class_name[package.len()..]);
+    let segments = module.split("::")
+      .filter(|x| !x.is_empty())
+      .map(|x| x.to_owned())
+      .chain(packages.iter().map(|x| escape_keyword(x)))
+      .collect();
+    Some((segments, classes))
+  }
+
+  /// The module segments, below the root module, that contain `class_name`.
+  pub fn module_segments(&self, class_name: &str) -> Vec<String> {
+    let (mut segments, classes) = self.package_segments(class_name).expect("class is not bound");
+    for outer in &classes[..classes.len() - 1] {
+      let snake_case = escape_keyword(&outer.to_snake_case());
+      let mut path = segments.clone();
+      path.push(snake_case.clone());
+      // an inner class module may not share its name with a sibling package (data/Property vs data/property)
+      let snake_case = if self.modules.contains(&path.join("::")) { format!("{}_", snake_case) } else { snake_case };
+      segments.push(snake_case);
+    }
+    segments
+  }
+
+  /// The feature gating the top-level module of `class_name`, if it is not in the root module.
+  pub fn feature(&self, class_name: &str) -> Option<String> {
+    self.package_segments(class_name).and_then(|(segments, _)| segments.into_iter().next())
+  }
+
+  /// The name of the struct generated for `class_name`.
+  pub fn struct_name(&self, class_name: &str) -> String {
+    match self.config.rename(class_name) {
+      Some(name) => name.to_owned(),
+      None => class_name.split(['/', '$']).next_back().expect("no end class name").to_owned()
+    }
+  }
+
+  /// Whether `class_name` is bound as a Rust `enum`.
+  pub fn is_enum(&self, class_name: &str) -> bool {
+    self.enums.contains(class_name)
+  }
+
+  /// Whether `rust_type` is a hand-written type from the `types` mapping or a generated enum, converted with
+  /// `FromJava` and `ToJava`.
+  pub fn is_mapped(&self, rust_type: &str) -> bool {
+    rust_type != "jobject" && (self.config.types.values().any(|x| x == rust_type) || self.enum_paths.contains(rust_type))
+  }
+
+  /// The Rust type for the class `class_name` (with or without its trailing `;`).
+  pub fn sanitize_class_name(&self, class_name: &str) -> String {
+    let class_name = class_name.replace(";", "");
+    if let Some(mapped) = self.config.types.get(&class_name) {
+      return mapped.clone();
+    }
+    if !self.is_bound(&class_name) {
+      return String::from("jobject");
+    }
+    let mut path = vec![self.root_path.clone()];
+    path.extend(self.module_segments(&class_name));
+    path.push(self.struct_name(&class_name));
+    path.join("::")
+  }
+
+  pub fn get_return_type(&self, return_type: &str) -> Option<String> {
+    match return_type.chars().nth(0).expect("no first char") {
+      'B' => Some("i8".to_owned()),
+      'C' => Some("char".to_owned()),
+      'D' => Some("f64".to_owned()),
+      'F' => Some("f32".to_owned()),
+      'I' => Some("i32".to_owned()),
+      'J' => Some("i64".to_owned()),
+      '[' => Some({
+        let array_type = self.get_return_type(&return_type[1..]);
+        format!("Vec<{}>", array_type.unwrap_or("()".to_owned()))
+       }),
+      'L' => Some({
+        let class_name = &return_type[1..];
+        if class_name == "java/util/Optional;" {
+          String::from("Option")
+        } else {
+          self.sanitize_class_name(class_name)
+        }
+      }),
+      'S' => Some("i16".to_owned()),
+      'Z' => Some("bool".to_owned()),
+      'V' => None,
+      'T' => Some("jobject".to_owned()), // FIXME: generics
+      _ => panic!("unsupported return type: {}", return_type)
+    }
+  }
+
+  pub fn get_param_types(&self, params: &str) -> Vec<String> {
+    let mut types = Vec::new();
+    let mut chars = params.chars();
+    while let Some(c) = chars.next() {
+      if c == '[' {
+        let array_type = chars.next().expect("no array type in param");
+        let rust_type = if array_type == 'L' {
+          let mut class_name = String::new();
+          for next in chars.by_ref() {
+            if next == ';' {
+              break;
+            }
+            class_name.push(next);
+          }
+          format!("Vec<{}>", self.sanitize_class_name(&class_name))
+        } else {
+          format!("Vec<{}>", get_rust_type(array_type))
+        };
+        types.push(rust_type);
+        continue;
+      }
+      if c == 'L' {
+        let mut class_name = String::new();
+        for next in chars.by_ref() {
+          if next == ';' {
+            break;
+          }
+          class_name.push(next);
+        }
+        types.push(self.sanitize_class_name(&class_name));
+        continue;
+      }
+      types.push(get_rust_type(c).to_owned());
+    }
+    types
+  }
+
+  /// The parameter list and return type of a method, naming parameters `parameter_names` when they are known, and
+  /// after their types otherwise.
+  pub fn create_params(&self, descriptor: &str, signature: Option<String>, is_static: bool, parameter_names: &[String]) -> String {
+    let mut string = String::from("(");
+    if is_static {
+      string.push_str("env: *mut JNIEnv");
+    } else {
+      string.push_str("&self");
+    }
+    let split: Vec<&str> = descriptor[1..].split(')').collect();
+    let params = &split.first().expect("no params in descriptor");
+    let return_type = split.get(1).expect("no return type in descriptor");
+    let param_types = self.get_param_types(params);
+    let names = if parameter_names.len() == param_types.len() {
+      parameter_names.iter().map(|x| parameter_name(x)).collect()
+    } else {
+      type_parameter_names(params)
+    };
+    for (name, param) in names.into_iter().zip(param_types) {
+      string.push_str(&format!(", {}: {}", name, param));
+    }
+    string.push(')');
+    let return_type = self.get_return_type(return_type);
+    if let Some(return_type) = return_type {
+      let return_type = if return_type == "Option" {
+        let optional_return_type = self.get_optional_return_type(signature.clone().expect("optional return type without signature"));
+        format!("{}<{}>", return_type, optional_return_type)
+      } else {
+        return_type
+      };
+      string.push_str(&format!(" -> {}", return_type));
+    }
+    string
+  }
+
+  pub fn get_optional_return_type(&self, signature: String) -> String {
+    let optional_type = signature;
+    let optional_type = optional_type.split("Optional<").nth(1).expect("no type signature");
+    let mut optional_type = optional_type.split(">").nth(0).expect("invalid signature");
+    loop {
+      let mut split = optional_type.split("<");
+      let zero = split.nth(0);
+      let one = split.nth(0);
+      if one.is_none() {
+        break;
+      }
+      optional_type = zero.expect("bad split");
+    }
+    let optional_type = if let Some(optional_type) = optional_type.strip_prefix('+') {
+      optional_type
+    } else if optional_type == "*" {
+      "L*;" // FIXME: generics
+    } else {
+      optional_type
+    };
+    self.get_return_type(optional_type).expect("no return type from signature")
+  }
+
+  /// The suffix telling an overload apart from the others with its name, from the simple names of its parameter
+  /// types (`(Ljava/lang/String;[I)V` is `string_int_array`).
+  pub fn overload_suffix(&self, descriptor: &str) -> String {
+    let params = descriptor[1..].split(')').next().expect("no params in descriptor");
+    let mut tokens = Vec::new();
+    let mut chars = params.chars();
+    while let Some(mut c) = chars.next() {
+      let mut dimensions = 0;
+      while c == '[' {
+        dimensions += 1;
+        c = chars.next().expect("no array type in param");
+      }
+      let mut token = match c {
+        'L' => {
+          let class_name: String = chars.by_ref().take_while(|x| *x != ';').collect();
+          type_snake_case(class_name.split(['/', '$']).next_back().expect("no end class name"))
+        },
+        'Z' => String::from("bool"),
+        c => get_rust_type(c).to_owned()
+      };
+      for _ in 0..dimensions {
+        token.push_str("_array");
+      }
+      tokens.push(token);
+    }
+    tokens.join("_")
+  }
+
+  /// The features, other than the class's own, that must be enabled for the types in `rust_params` to exist.
+  pub fn required_features(&self, class_name: &str, rust_params: &str) -> BTreeSet<String> {
+    if !self.config.feature_gates {
+      return BTreeSet::new();
+    }
+    let own_feature = self.feature(class_name);
+    let prefix = format!("{}::", self.root_path);
+    rust_params.split(&*prefix)
+      .skip(1)
+      .filter_map(|x| {
+        let mut segments = x.split("::");
+        let first = segments.next().expect("no module segment");
+        // types in the root package (sponge::Game) have no second segment
+        segments.next().map(|_| first.to_owned())
+      })
+      .filter(|x| self.modules.contains(x) && Some(x) != own_feature.as_ref())
+      .collect()
+  }
+}
+
+/// The snake case of a class name, keeping acronyms together (`UUID` is `uuid`, `HTTPServer` is `http_server`).
+fn type_snake_case(name: &str) -> String {
+  let chars: Vec<char> = name.chars().collect();
+  let words: String = chars.iter().enumerate()
+    .map(|(i, c)| {
+      let after_upper = i > 0 && chars[i - 1].is_uppercase();
+      let before_lower = chars.get(i + 1).map(|x| x.is_lowercase()).unwrap_or(false);
+      if after_upper && !before_lower { c.to_ascii_lowercase() } else { *c }
+    })
+    .collect();
+  words.to_snake_case()
+}
+
+/// Names for the parameters `params` (a descriptor's parameter part) when neither the class file nor the sources
+/// have them, after their types: `Location` is `location`, `String[]` is `strings` and primitives are `value`.
+/// Later parameters with the same name are numbered from 2 (`value`, `value_2`).
+pub fn type_parameter_names(params: &str) -> Vec<String> {
+  let mut names = Vec::new();
+  let mut counts = HashMap::new();
+  let mut chars = params.chars();
+  while let Some(mut c) = chars.next() {
+    let mut is_array = false;
+    while c == '[' {
+      is_array = true;
+      c = chars.next().expect("no array type in param");
+    }
+    let name = match c {
+      'L' => {
+        let class_name: String = chars.by_ref().take_while(|x| *x != ';').collect();
+        type_snake_case(class_name.split(['/', '$']).next_back().expect("no end class name"))
+      },
+      _ => String::from("value")
+    };
+    let name = parameter_name(&if is_array { name.to_plural() } else { name });
+    let count = counts.entry(name.clone()).or_insert(0);
+    *count += 1;
+    names.push(if *count == 1 { name } else { format!("{}_{}", name, count) });
+  }
+  names
+}
+
+/// Whether the method with `descriptor` takes no parameters.
+pub fn has_no_params(descriptor: &str) -> bool {
+  descriptor.starts_with("()")
+}
+
+pub fn get_rust_type<'a>(c: char) -> &'a str {
+  match c {
+    'B' => "i8",
+    'C' => "char",
+    'D' => "f64",
+    'F' => "f32",
+    'I' => "i32",
+    'J' => "i64",
+    'S' => "i16",
+    'Z' => "bool",
+    _ => panic!("unsupported param type: {}", c)
+  }
+}
@@ -0,0 +1,448 @@
+use std::fs::{self, File};
+use std::io::{Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
+
+use class::Class;
+
+/// The declarations read from a Java source file that the generated bindings are documented with.
+#[derive(Debug, Default)]
+pub struct Source {
+  pub classes: Vec<SourceClass>
+}
+
+#[derive(Debug)]
+pub struct SourceClass {
+  /// The internal name of the class, such as `org/spongepowered/api/block/BlockSnapshot$Builder`.
+  pub name: String,
+  pub doc: Option<String>,
+  pub methods: Vec<SourceMethod>
+}
+
+#[derive(Debug)]
+pub struct SourceMethod {
+  /// The name of the method, or `<init>` for constructors.
+  pub name: String,
+  /// The simple, erased type and the name of each parameter (`List`, `entities`).
+  pub parameters: Vec<(String, String)>,
+  pub doc: Option<String>
+}
+
+impl Source {
+  /// Reads the classes and methods declared in a Java source file.
+  ///
+  /// This is not a Java parser: it only looks at declarations directly in class bodies, which is all that is needed
+  /// to name parameters and carry over Javadoc.
+  pub fn parse(source: &str) -> Source {
+    let mut parser = Parser::default();
+    parser.parse(source);
+    Source { classes: parser.classes }
+  }
+
+  /// Reads the Java source file at `path`.
+  pub fn open<P: AsRef<Path>>(path: P) -> Source {
+    let mut string = String::new();
+    File::open(path).expect("could not open source").read_to_string(&mut string).expect("could not read source");
+    Source::parse(&string)
+  }
+}
+
+/// Reads every Java source file below `path`.
+pub fn read_directory(path: &Path, sources: &mut Vec<Source>) {
+  if path.is_dir() {
+    let mut entries: Vec<_> = fs::read_dir(path).expect("could not read directory")
+      .map(|x| x.expect("could not read directory entry").path())
+      .collect();
+    entries.sort();
+    for entry in entries {
+      read_directory(&entry, sources);
+    }
+  } else if path.extension().map(|x| x == "java").unwrap_or(false) {
+    sources.push(Source::open(path));
+  }
+}
+
+/// Reads every Java source file in a jar, such as the `-sources` jar published next to SpongeAPI.
+pub fn read_jar<R: Read + Seek>(reader: R, sources: &mut Vec<Source>) {
+  let mut jar = ZipArchive::new(reader).expect("could not read jar");
+  let mut names: Vec<String> = jar.file_names()
+    .filter(|x| x.ends_with(".java"))
+    .map(|x| x.to_owned())
+    .collect();
+  names.sort();
+  for name in names {
+    let mut string = String::new();
+    jar.by_name(&name).expect("could not read jar entry").read_to_string(&mut string).expect("could not read source");
+    sources.push(Source::parse(&string));
+  }
+}
+
+/// Fills in the parameter names and Javadoc of `classes` from `sources`.
+pub fn apply(sources: &[Source], classes: &mut [Class]) {
+  for source_class in sources.iter().flat_map(|x| &x.classes) {
+    let class = match classes.iter_mut().find(|x| x.name == source_class.name) {
+      Some(class) => class,
+      None => continue
+    };
+    if class.doc.is_none() {
+      class.doc = source_class.doc.clone();
+    }
+    for method in &mut class.methods {
+      let types = parameter_types(&method.descriptor);
+      let candidates: Vec<&SourceMethod> = source_class.methods.iter()
+        .filter(|x| x.name == method.original_name && x.parameters.len() == types.len())
+        .collect();
+      // overloads with the same arity are told apart by their parameter types; type variables erase to Object
+      let source_method = if candidates.len() == 1 {
+        candidates[0]
+      } else {
+        let matching: Vec<&&SourceMethod> = candidates.iter()
+          .filter(|x| x.parameters.iter().zip(&types).all(|(a, b)| a.0 == *b || b == "Object"))
+          .collect();
+        if matching.len() != 1 {
+          continue;
+        }
+        matching[0]
+      };
+      if method.parameter_names.is_empty() {
+        method.parameter_names = source_method.parameters.iter().map(|x| x.1.clone()).collect();
+      }
+      if method.doc.is_none() {
+        method.doc = source_method.doc.clone();
+      }
+    }
+  }
+}
+
+/// The simple names of the parameter types in `descriptor`, as they would be written in source (`int`, `Text[]`).
+fn parameter_types(descriptor: &str) -> Vec<String> {
+  let params = descriptor[1..].split(')').next().expect("no params in descriptor");
+  let mut types = Vec::new();
+  let mut chars = params.chars();
+  while let Some(mut c) = chars.next() {
+    let mut dimensions = 0;
+    while c == '[' {
+      dimensions += 1;
+      c = chars.next().expect("no array type in param");
+    }
+    let mut name = match c {
+      'B' => String::from("byte"),
+      'C' => String::from("char"),
+      'D' => String::from("double"),
+      'F' => String::from("float"),
+      'I' => String::from("int"),
+      'J' => String::from("long"),
+      'S' => String::from("short"),
+      'Z' => String::from("boolean"),
+      'L' => {
+        let class_name: String = chars.by_ref().take_while(|x| *x != ';').collect();
+        class_name.split(['/', '$']).next_back().expect("no end class name").to_owned()
+      },
+      _ => panic!("unsupported param type: {}", c)
+    };
+    for _ in 0..dimensions {
+      name.push_str("[]");
+    }
+    types.push(name);
+  }
+  types
+}
+
+#[derive(Default)]
+struct Parser {
+  package: String,
+  classes: Vec<SourceClass>,
+  /// The classes being declared, as indices into `classes`, with the brace depth of their bodies.
+  stack: Vec<(usize, usize)>,
+  depth: usize,
+  /// The text of the declaration being read, since the last `;`, `{` or `}` in a class body.
+  declaration: String,
+  doc: Option<String>
+}
+
+impl Parser {
+  fn parse(&mut self, source: &str) {
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+      match c {
+        '/' if chars.peek() == Some(&'/') => {
+          for next in chars.by_ref() {
+            if next == '\n' {
+              break;
+            }
+          }
+          self.push(' ');
+        },
+        '/' if chars.peek() == Some(&'*') => {
+          chars.next();
+          let mut comment = String::new();
+          let mut last = ' ';
+          for next in chars.by_ref() {
+            if last == '*' && next == '/' {
+              comment.pop();
+              break;
+            }
+            comment.push(next);
+            last = next;
+          }
+          if comment.starts_with('*') && (self.depth == 0 || self.in_class_body()) {
+            self.doc = Some(clean_doc(&comment[1..]));
+          }
+          self.push(' ');
+        },
+        '"' | '\'' => {
+          let mut escaped = false;
+          for next in chars.by_ref() {
+            if !escaped && next == c {
+              break;
+            }
+            escaped = !escaped && next == '\\';
+          }
+          self.push('_');
+        },
+        '{' => {
+          if self.depth == 0 || self.in_class_body() {
+            self.open_declaration();
+          }
+          self.depth += 1;
+        },
+        '}' => {
+          if self.stack.last().map(|x| x.1 == self.depth).unwrap_or(false) {
+            self.stack.pop();
+          }
+          self.depth -= 1;
+          self.declaration.clear();
+          self.doc = None;
+        },
+        ';' => {
+          if self.depth == 0 {
+            let statement = self.declaration.trim().to_owned();
+            if let Some(package) = statement.strip_prefix("package ") {
+              self.package = format!("{}/", package.trim().replace('.', "/"));
+            }
+            self.declaration.clear();
+          } else if self.in_class_body() {
+            self.close_declaration();
+          }
+        },
+        _ => self.push(c)
+      }
+    }
+  }
+
+  fn in_class_body(&self) -> bool {
+    self.stack.last().map(|x| x.1 == self.depth).unwrap_or(false)
+  }
+
+  fn push(&mut self, c: char) {
+    if self.depth == 0 || self.in_class_body() {
+      self.declaration.push(c);
+    }
+  }
+
+  /// Handles a declaration followed by `{`, which is either a class or a method with a body.
+  fn open_declaration(&mut self) {
+    let declaration = strip_annotations(&self.declaration);
+    let words: Vec<&str> = declaration.split_whitespace().collect();
+    let class_name = words.iter()
+      .position(|x| *x == "class" || *x == "interface" || *x == "enum" || *x == "@interface")
+      .and_then(|i| words.get(i + 1))
+      .map(|x| x.split('<').next().expect("no class name").to_owned());
+    match class_name {
+      Some(name) => {
+        let name = match self.stack.last() {
+          Some(&(outer, _)) => format!("{}${}", self.classes[outer].name, name),
+          None => format!("{}{}", self.package, name)
+        };
+        self.classes.push(SourceClass {
+          name,
+          doc: self.doc.take(),
+          methods: Vec::new()
+        });
+        self.stack.push((self.classes.len() - 1, self.depth + 1));
+      },
+      None => self.close_declaration()
+    }
+    self.declaration.clear();
+    self.doc = None;
+  }
+
+  /// Handles a declaration ending in `;` or a method body, recording it if it is a method.
+  fn close_declaration(&mut self) {
+    let declaration = strip_annotations(&self.declaration);
+    self.declaration.clear();
+    let doc = self.doc.take();
+    let class = match self.stack.last() {
+      Some(&(class, _)) => class,
+      None => return
+    };
+    let open = match declaration.find('(') {
+      Some(open) => open,
+      None => return
+    };
+    let close = match declaration.rfind(')') {
+      Some(close) if close > open => close,
+      _ => return
+    };
+    let before = declaration[..open].trim_end();
+    let name: String = before.chars().rev().take_while(|x| x.is_alphanumeric() || *x == '_' || *x == '$').collect();
+    let name: String = name.chars().rev().collect();
+    // a method has a return type or modifiers before its name; fields, initializers and enum constants do not count
+    let head = before[..before.len() - name.len()].trim_end();
+    if name.is_empty() || head.is_empty() || head.ends_with('=') || head.ends_with(',') || head.contains("->") {
+      return;
+    }
+    let mut parameters = Vec::new();
+    for parameter in split_top_level(&declaration[open + 1..close]) {
+      let words: Vec<&str> = parameter.split_whitespace().filter(|x| *x != "final").collect();
+      if words.is_empty() {
+        continue;
+      }
+      if words.len() < 2 {
+        return;
+      }
+      let param_name = words[words.len() - 1];
+      if !param_name.chars().all(|x| x.is_alphanumeric() || x == '_' || x == '$') {
+        return;
+      }
+      parameters.push((simple_type(&words[..words.len() - 1].join(" ")), param_name.to_owned()));
+    }
+    let class_simple_name = self.classes[class].name.split(['/', '$']).next_back().expect("no end class name").to_owned();
+    let name = if name == class_simple_name { String::from("<init>") } else { name };
+    self.classes[class].methods.push(SourceMethod {
+      name,
+      parameters,
+      doc
+    });
+  }
+}
+
+/// Removes annotations (`@Nullable`, `@Deprecated(since = "1.0")`) from a declaration, keeping `@interface`.
+fn strip_annotations(declaration: &str) -> String {
+  let mut string = String::new();
+  let mut chars = declaration.chars().peekable();
+  while let Some(c) = chars.next() {
+    if c != '@' {
+      string.push(c);
+      continue;
+    }
+    let name: String = {
+      let mut name = String::new();
+      while let Some(&next) = chars.peek() {
+        if next.is_alphanumeric() || next == '_' || next == '.' {
+          name.push(next);
+          chars.next();
+        } else {
+          break;
+        }
+      }
+      name
+    };
+    if name == "interface" {
+      string.push_str("@interface");
+      continue;
+    }
+    while chars.peek().map(|x| x.is_whitespace()).unwrap_or(false) {
+      chars.next();
+    }
+    if chars.peek() == Some(&'(') {
+      let mut depth = 0;
+      for next in chars.by_ref() {
+        match next {
+          '(' => depth += 1,
+          ')' => depth -= 1,
+          _ => {}
+        }
+        if depth == 0 {
+          break;
+        }
+      }
+    }
+    string.push(' ');
+  }
+  string
+}
+
+/// Splits a parameter list on the commas that are not inside type arguments.
+fn split_top_level(parameters: &str) -> Vec<String> {
+  let mut split = vec![String::new()];
+  let mut depth = 0;
+  for c in parameters.chars() {
+    match c {
+      '<' => depth += 1,
+      '>' => depth -= 1,
+      ',' if depth == 0 => {
+        split.push(String::new());
+        continue;
+      },
+      _ => {}
+    }
+    split.last_mut().expect("no parameter").push(c);
+  }
+  split
+}
+
+/// The erased simple name of a source type (`java.util.List<Foo>` is `List`, `Foo...` is `Foo[]`).
+fn simple_type(source_type: &str) -> String {
+  let mut erased = String::new();
+  let mut depth = 0;
+  for c in source_type.chars() {
+    match c {
+      '<' => depth += 1,
+      '>' => depth -= 1,
+      _ if depth == 0 && !c.is_whitespace() => erased.push(c),
+      _ => {}
+    }
+  }
+  let erased = erased.replace("...", "[]");
+  erased.rsplit('.').next().expect("no simple type").to_owned()
+}
+
+/// Turns the body of a Javadoc comment into Markdown: leading `*`s and block tags are dropped, `{@code x}` and
+/// `{@link x}` become code spans, `{@link x label}` its label, and `<p>` starts a new paragraph.
+fn clean_doc(comment: &str) -> String {
+  let mut lines = Vec::new();
+  for line in comment.lines() {
+    let line = line.trim();
+    let line = line.strip_prefix('*').unwrap_or(line).trim();
+    if line.starts_with('@') {
+      break;
+    }
+    lines.push(line.to_owned());
+  }
+  let mut text = lines.join("\n");
+  for tag in &["{@code ", "{@link ", "{@linkplain ", "{@literal "] {
+    while let Some(start) = text.find(tag) {
+      let end = match text[start..].find('}') {
+        Some(end) => start + end,
+        None => break
+      };
+      let inner = text[start + tag.len()..end].trim().to_owned();
+      // links with a label read as the label
+      let replacement = match inner.find(char::is_whitespace) {
+        Some(i) if tag.starts_with("{@link") => inner[i..].trim().to_owned(),
+        _ => format!("`{}`", inner)
+      };
+      text = format!("{}{}{}", &text[..start], replacement, &text[end + 1..]);
+    }
+  }
+  let text = text.replace("<p>", "\n\n").replace("</p>", "");
+  let mut paragraphs = Vec::new();
+  let mut last_blank = true;
+  for line in text.lines() {
+    let line = line.trim();
+    if line.is_empty() {
+      if !last_blank {
+        paragraphs.push(String::new());
+      }
+      last_blank = true;
+    } else {
+      paragraphs.push(line.to_owned());
+      last_blank = false;
+    }
+  }
+  while paragraphs.last().map(|x| x.is_empty()).unwrap_or(false) {
+    paragraphs.pop();
+  }
+  paragraphs.join("\n")
+}
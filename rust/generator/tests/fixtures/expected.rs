@@ -0,0 +1,304 @@
+#[allow(non_snake_case, non_camel_case_types, unused_variables, unused_imports, clippy::wrong_self_convention, clippy::too_many_arguments, clippy::redundant_field_names, clippy::missing_safety_doc, clippy::module_inception, clippy::self_named_constructors, clippy::not_unsafe_ptr_arg_deref, clippy::macro_metavars_in_unsafe)]
+pub mod fixture {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  /// A base for shapes, which cannot be constructed itself.
+  #[derive(Debug)]
+  pub struct AbstractShape {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl AbstractShape {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      AbstractShape {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractShape`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::spongejni::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::spongejni::convert::call_nonvirtual(self.env, self.object, "spongejni/fixture/AbstractShape", name, descriptor, args);
+      ::spongejni::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "spongejni/fixture/AbstractShape", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/AbstractShape#getName was null") }
+      ret
+    }
+
+    pub fn get_color(&self) -> ::fixture::Color {
+      let ret = bound_method!(self.env, "spongejni/fixture/AbstractShape", self.object, "getColor", "()Lspongejni/fixture/Color;", CallObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/AbstractShape#getColor was null") }
+      <::fixture::Color as ::spongejni::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    pub fn set_color(&self, color: ::fixture::Color) {
+      bound_method!(self.env, "spongejni/fixture/AbstractShape", self.object, "setColor", "(Lspongejni/fixture/Color;)V", CallVoidMethodA, ::spongejni::convert::ToJava::to_java(&color, self.env));
+    }
+
+  }
+
+  /// The colors a `Shape` can be drawn in.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Color {
+    Red,
+    Green,
+    Blue,
+    LightGray,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static COLOR_CONSTANTS: ::spongejni::convert::EnumConstants = ::spongejni::convert::EnumConstants::new("spongejni/fixture/Color", &["RED", "GREEN", "BLUE", "LIGHT_GRAY"]);
+
+  impl Color {
+    pub const VALUES: &'static [Color] = &[Color::Red, Color::Green, Color::Blue, Color::LightGray];
+
+    pub fn is_primary(&self, env: *mut JNIEnv) -> bool {
+      bound_method!(env, "spongejni/fixture/Color", ::spongejni::convert::ToJava::to_java(self, env), "isPrimary", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn mix(env: *mut JNIEnv, first: ::fixture::Color, second: ::fixture::Color) -> ::fixture::Color {
+      let ret = static_java_method!(env, "spongejni/fixture/Color", "mix", "(Lspongejni/fixture/Color;Lspongejni/fixture/Color;)Lspongejni/fixture/Color;", CallStaticObjectMethodA, ::spongejni::convert::ToJava::to_java(&first, env), ::spongejni::convert::ToJava::to_java(&second, env));
+      if ret.is_null() { panic!("spongejni/fixture/Color#mix was null") }
+      <::fixture::Color as ::spongejni::convert::FromJava>::from_java(env, ret)
+    }
+
+  }
+
+  impl ::spongejni::convert::FromJava for Color {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match COLOR_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Color::VALUES[ordinal],
+        None => Color::Other(object)
+      }
+    }
+  }
+
+  impl ::spongejni::convert::ToJava for Color {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Color::Other(object) => object,
+        value => COLOR_CONSTANTS.get(env, Color::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+
+  /// A point on the grid.
+  #[derive(Debug)]
+  pub struct Point {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Point {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Point {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `Point`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::spongejni::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::spongejni::convert::call_nonvirtual(self.env, self.object, "spongejni/fixture/Point", name, descriptor, args);
+      ::spongejni::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv) -> ::fixture::Point {
+      let ret = new_object!(env, "spongejni/fixture/Point", "()V");
+      if ret.is_null() { panic!("spongejni/fixture/Point#<init> was null") }
+      ::fixture::Point { env: env, object: ret }
+    }
+
+    pub fn new_i32_i32(env: *mut JNIEnv, x: i32, y: i32) -> ::fixture::Point {
+      let ret = new_object!(env, "spongejni/fixture/Point", "(II)V", x, y);
+      if ret.is_null() { panic!("spongejni/fixture/Point#<init> was null") }
+      ::fixture::Point { env: env, object: ret }
+    }
+
+    pub fn of(env: *mut JNIEnv, x: i32, y: i32) -> ::fixture::Point {
+      let ret = static_java_method!(env, "spongejni/fixture/Point", "of", "(II)Lspongejni/fixture/Point;", CallStaticObjectMethodA, x, y);
+      if ret.is_null() { panic!("spongejni/fixture/Point#of was null") }
+      ::fixture::Point { env: env, object: ret }
+    }
+
+    pub fn get_x(&self) -> i32 {
+      bound_method!(self.env, "spongejni/fixture/Point", self.object, "getX", "()I", CallIntMethod)
+    }
+
+    pub fn get_y(&self) -> i32 {
+      bound_method!(self.env, "spongejni/fixture/Point", self.object, "getY", "()I", CallIntMethod)
+    }
+
+    pub fn add_point(&self, other: ::fixture::Point) -> ::fixture::Point {
+      let ret = bound_method!(self.env, "spongejni/fixture/Point", self.object, "add", "(Lspongejni/fixture/Point;)Lspongejni/fixture/Point;", CallObjectMethodA, other.object);
+      if ret.is_null() { panic!("spongejni/fixture/Point#add was null") }
+      ::fixture::Point { env: self.env, object: ret }
+    }
+
+    pub fn add_i32_i32(&self, x: i32, y: i32) -> ::fixture::Point {
+      let ret = bound_method!(self.env, "spongejni/fixture/Point", self.object, "add", "(II)Lspongejni/fixture/Point;", CallObjectMethodA, x, y);
+      if ret.is_null() { panic!("spongejni/fixture/Point#add was null") }
+      ::fixture::Point { env: self.env, object: ret }
+    }
+
+    pub fn builder(env: *mut JNIEnv) -> ::fixture::point::Builder {
+      let ret = static_java_method!(env, "spongejni/fixture/Point", "builder", "()Lspongejni/fixture/Point$Builder;", CallStaticObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/Point#builder was null") }
+      ::fixture::point::Builder { env: env, object: ret }
+    }
+
+  }
+
+  /// A shape on a grid of `Point`s.
+  #[derive(Debug)]
+  pub struct Shape {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Shape {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Shape {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Shape`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::spongejni::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::spongejni::convert::call_nonvirtual(self.env, self.object, "spongejni/fixture/Shape", name, descriptor, args);
+      ::spongejni::convert::FromJValue::from_jvalue(ret)
+    }
+
+    /// Gets the area of this shape.
+    pub fn get_area(&self) -> f64 {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "getArea", "()D", CallDoubleMethod)
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "spongejni/fixture/Shape", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/Shape#getName was null") }
+      ret
+    }
+
+    pub fn get_symbol(&self) -> char {
+      ::spongejni::convert::char_from_java(bound_method!(self.env, "spongejni/fixture/Shape", self.object, "getSymbol", "()C", CallCharMethod))
+    }
+
+    /// Gets whether this shape contains the point at `x`, `y`.
+    pub fn contains_i32_i32(&self, x: i32, y: i32) -> bool {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "contains", "(II)Z", CallBooleanMethodA, x, y) != 0
+    }
+
+    pub fn contains_f64_f64(&self, x: f64, y: f64) -> bool {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "contains", "(DD)Z", CallBooleanMethodA, x, y) != 0
+    }
+
+    pub fn contains_point(&self, point: ::fixture::Point) -> bool {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "contains", "(Lspongejni/fixture/Point;)Z", CallBooleanMethodA, point.object) != 0
+    }
+
+    pub fn get_center(&self) -> Option<::fixture::Point> {
+      let ret = bound_method!(self.env, "spongejni/fixture/Shape", self.object, "getCenter", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/Shape#getCenter was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::fixture::Point { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn transform(&self, function: jobject) -> jobject {
+      let ret = bound_method!(self.env, "spongejni/fixture/Shape", self.object, "transform", "(Ljava/util/function/Function;)Ljava/lang/Object;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("spongejni/fixture/Shape#transform was null") }
+      ret
+    }
+
+    pub fn scale(&self, factor: f32) {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "scale", "(F)V", CallVoidMethodA, factor);
+    }
+
+    pub fn tag(&self, tags: Vec<jobject>) {
+      unimplemented!();
+    }
+    pub fn get_bounds(&self) -> Vec<i32> {
+      unimplemented!();
+    }
+    pub fn set_bounds(&self, bounds: Vec<i32>) {
+      unimplemented!();
+    }
+    pub fn get_color(&self) -> ::fixture::Color {
+      let ret = bound_method!(self.env, "spongejni/fixture/Shape", self.object, "getColor", "()Lspongejni/fixture/Color;", CallObjectMethod);
+      if ret.is_null() { panic!("spongejni/fixture/Shape#getColor was null") }
+      <::fixture::Color as ::spongejni::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    pub fn set_color(&self, color: ::fixture::Color) {
+      bound_method!(self.env, "spongejni/fixture/Shape", self.object, "setColor", "(Lspongejni/fixture/Color;)V", CallVoidMethodA, ::spongejni::convert::ToJava::to_java(&color, self.env));
+    }
+
+  }
+
+  pub mod point {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    /// Builds a `Point`.
+    #[derive(Debug)]
+    pub struct Builder {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Builder {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Builder {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the implementation in `Builder`, even if
+      /// the class of the object overrides it.
+      pub fn call_super<T: ::spongejni::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::spongejni::convert::call_nonvirtual(self.env, self.object, "spongejni/fixture/Point$Builder", name, descriptor, args);
+        ::spongejni::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn new(env: *mut JNIEnv) -> ::fixture::point::Builder {
+        let ret = new_object!(env, "spongejni/fixture/Point$Builder", "()V");
+        if ret.is_null() { panic!("spongejni/fixture/Point$Builder#<init> was null") }
+        ::fixture::point::Builder { env: env, object: ret }
+      }
+
+      pub fn x(&self, x: i32) -> ::fixture::point::Builder {
+        let ret = bound_method!(self.env, "spongejni/fixture/Point$Builder", self.object, "x", "(I)Lspongejni/fixture/Point$Builder;", CallObjectMethodA, x);
+        if ret.is_null() { panic!("spongejni/fixture/Point$Builder#x was null") }
+        ::fixture::point::Builder { env: self.env, object: ret }
+      }
+
+      pub fn y(&self, y: i32) -> ::fixture::point::Builder {
+        let ret = bound_method!(self.env, "spongejni/fixture/Point$Builder", self.object, "y", "(I)Lspongejni/fixture/Point$Builder;", CallObjectMethodA, y);
+        if ret.is_null() { panic!("spongejni/fixture/Point$Builder#y was null") }
+        ::fixture::point::Builder { env: self.env, object: ret }
+      }
+
+      pub fn from_point(&self, point: ::fixture::Point) -> ::fixture::point::Builder {
+        let ret = bound_method!(self.env, "spongejni/fixture/Point$Builder", self.object, "from", "(Lspongejni/fixture/Point;)Lspongejni/fixture/Point$Builder;", CallObjectMethodA, point.object);
+        if ret.is_null() { panic!("spongejni/fixture/Point$Builder#from was null") }
+        ::fixture::point::Builder { env: self.env, object: ret }
+      }
+
+      pub fn build(&self) -> ::fixture::Point {
+        let ret = bound_method!(self.env, "spongejni/fixture/Point$Builder", self.object, "build", "()Lspongejni/fixture/Point;", CallObjectMethod);
+        if ret.is_null() { panic!("spongejni/fixture/Point$Builder#build was null") }
+        ::fixture::Point { env: self.env, object: ret }
+      }
+
+    }
+  }
+}
@@ -0,0 +1,34 @@
+package spongejni.fixture;
+
+/**
+ * A base for shapes, which cannot be constructed itself.
+ */
+public abstract class AbstractShape implements Shape {
+
+    private final String name;
+    private Color color = Color.RED;
+
+    protected AbstractShape(String name) {
+        this.name = name;
+    }
+
+    public AbstractShape() {
+        this("shape");
+    }
+
+    @Override
+    public String getName() {
+        return this.name;
+    }
+
+    @Override
+    public Color getColor() {
+        return this.color;
+    }
+
+    @Override
+    public void setColor(Color color) {
+        this.color = color;
+    }
+
+}
@@ -0,0 +1,19 @@
+package spongejni.fixture;
+
+/**
+ * The colors a {@link Shape} can be drawn in.
+ */
+public enum Color {
+    RED,
+    GREEN,
+    BLUE,
+    LIGHT_GRAY;
+
+    public boolean isPrimary() {
+        return this != LIGHT_GRAY;
+    }
+
+    public static Color mix(Color first, Color second) {
+        return first == second ? first : LIGHT_GRAY;
+    }
+}
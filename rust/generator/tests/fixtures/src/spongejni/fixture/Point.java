@@ -0,0 +1,76 @@
+package spongejni.fixture;
+
+/**
+ * A point on the grid.
+ */
+public class Point {
+
+    public static final Point ORIGIN = new Point();
+
+    private final int x;
+    private final int y;
+
+    public Point() {
+        this(0, 0);
+    }
+
+    public Point(int x, int y) {
+        this.x = x;
+        this.y = y;
+    }
+
+    public static Point of(int x, int y) {
+        return new Point(x, y);
+    }
+
+    public int getX() {
+        return this.x;
+    }
+
+    public int getY() {
+        return this.y;
+    }
+
+    public Point add(Point other) {
+        return new Point(this.x + other.x, this.y + other.y);
+    }
+
+    public Point add(int x, int y) {
+        return new Point(this.x + x, this.y + y);
+    }
+
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    /**
+     * Builds a {@link Point}.
+     */
+    public static class Builder {
+
+        private int x;
+        private int y;
+
+        public Builder x(int x) {
+            this.x = x;
+            return this;
+        }
+
+        public Builder y(int y) {
+            this.y = y;
+            return this;
+        }
+
+        public Builder from(Point point) {
+            this.x = point.x;
+            this.y = point.y;
+            return this;
+        }
+
+        public Point build() {
+            return new Point(this.x, this.y);
+        }
+
+    }
+
+}
@@ -0,0 +1,47 @@
+package spongejni.fixture;
+
+import java.util.Optional;
+import java.util.function.Function;
+
+/**
+ * A shape on a grid of {@link Point}s.
+ */
+public interface Shape {
+
+    /**
+     * Gets the area of this shape.
+     *
+     * @return The area
+     */
+    double getArea();
+
+    String getName();
+
+    char getSymbol();
+
+    /**
+     * Gets whether this shape contains the point at {@code x}, {@code y}.
+     */
+    boolean contains(int x, int y);
+
+    boolean contains(double x, double y);
+
+    boolean contains(Point point);
+
+    Optional<Point> getCenter();
+
+    <T> T transform(Function<Shape, T> function);
+
+    void scale(float factor);
+
+    void tag(String... tags);
+
+    int[] getBounds();
+
+    void setBounds(int[] bounds);
+
+    Color getColor();
+
+    void setColor(Color color);
+
+}
@@ -0,0 +1,95 @@
+//! Generates bindings for the classes in `tests/fixtures` and compares them to `tests/fixtures/expected.rs`.
+//!
+//! The class files are committed so the output does not depend on the installed JDK. After changing the Java
+//! sources, recompile them with
+//!
+//! `javac --release 8 -g -d tests/fixtures/classes tests/fixtures/src/spongejni/fixture/*.java`
+//!
+//! and run the tests with `UPDATE_GOLDEN=1` to rewrite the expected output, then review the difference.
+
+// the macros are used by the included bindings, which the unused import lint does not see
+#[allow(unused_imports)]
+#[macro_use]
+extern crate spongejni;
+extern crate spongejni_gen;
+extern crate jni_sys;
+
+use spongejni_gen::Generator;
+use std::env;
+use std::fs::File;
+use std::io::{Read, Write};
+
+// the expected output has to compile against spongejni, like the checked-in SpongeAPI bindings
+include!("fixtures/expected.rs");
+
+const EXPECTED: &str = "tests/fixtures/expected.rs";
+
+fn generate() -> String {
+  Generator::config_file("tests/fixtures/fixture.toml")
+    .source("tests/fixtures/classes")
+    .source("tests/fixtures/src")
+    .generate()
+    .to_source()
+}
+
+// the bindings generated from the class files alone, as without a sources jar
+fn generate_from_classes() -> String {
+  Generator::config_file("tests/fixtures/fixture.toml")
+    .source("tests/fixtures/classes")
+    .generate()
+    .to_source()
+}
+
+#[test]
+fn matches_expected_output() {
+  let generated = generate();
+  if env::var_os("UPDATE_GOLDEN").is_some() {
+    File::create(EXPECTED).expect("could not create expected output").write_all(generated.as_bytes()).expect("could not write expected output");
+    return;
+  }
+  let mut expected = String::new();
+  File::open(EXPECTED).expect("could not open expected output").read_to_string(&mut expected).expect("could not read expected output");
+  if generated != expected {
+    let line = generated.lines().zip(expected.lines()).position(|(a, b)| a != b).unwrap_or_else(|| generated.lines().count().min(expected.lines().count()));
+    panic!(
+      "generated bindings differ from {} at line {}:\n  generated: {:?}\n  expected:  {:?}\nrun with UPDATE_GOLDEN=1 to accept the new output",
+      EXPECTED,
+      line + 1,
+      generated.lines().nth(line),
+      expected.lines().nth(line)
+    );
+  }
+}
+
+#[test]
+fn is_reproducible() {
+  assert_eq!(generate(), generate());
+}
+
+#[test]
+fn carries_parameter_names_and_javadoc_from_sources() {
+  let generated = generate();
+  assert!(generated.contains("    /// Gets the area of this shape.\n    pub fn get_area(&self) -> f64 {"));
+  assert!(generated.contains("    /// Gets whether this shape contains the point at `x`, `y`.\n    pub fn contains_i32_i32(&self, x: i32, y: i32) -> bool {"));
+}
+
+#[test]
+fn names_parameters_from_class_files_or_their_types() {
+  let generated = generate_from_classes();
+  // compiled with -g, so the local variable table names the parameters of methods with code
+  assert!(generated.contains("pub fn of(env: *mut JNIEnv, x: i32, y: i32) -> ::fixture::Point {"));
+  assert!(generated.contains("pub fn add_point(&self, other: ::fixture::Point) -> ::fixture::Point {"));
+  // abstract methods have no local variables, so their parameters are named after their types
+  assert!(generated.contains("pub fn contains_point(&self, point: ::fixture::Point) -> bool {"));
+  assert!(generated.contains("pub fn contains_i32_i32(&self, value: i32, value_2: i32) -> bool {"));
+  assert!(generated.contains("pub fn tag(&self, strings: Vec<jobject>) {"));
+  assert!(!generated.contains("Gets the area of this shape."));
+}
+
+#[test]
+fn names_clashing_with_helpers_after_their_parameter_types() {
+  let generated = generate();
+  // the builder's from(Point) would clash with the generated from constructor
+  assert!(generated.contains("pub fn from_point(&self, point: ::fixture::Point) -> ::fixture::point::Builder {"));
+  assert!(!generated.contains("fn from_1("));
+}
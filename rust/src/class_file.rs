@@ -0,0 +1,428 @@
+//! Writes class files for the classes the plugin defines at runtime, such as command executors and listeners.
+//!
+//! This is the inverse of the `classreader` crate the generator reads SpongeAPI with, limited to what those classes
+//! need: a public no-argument constructor, `native` methods and runtime-visible annotations on them and their
+//! parameters. [`JavaUtils::define_class`](../plugin/struct.JavaUtils.html#method.define_class) loads the result into
+//! the plugin's class loader and binds the native methods to the Rust functions given with
+//! [`NativeMethod::bind`](struct.NativeMethod.html#method.bind), so their names do not have to be exported.
+
+use std::collections::HashMap;
+use std::os::raw::c_void;
+
+use convert;
+
+const MAGIC: u32 = 0xCAFE_BABE;
+// Java 8, which needs no stack map frames for the straight-line constructor
+const MAJOR_VERSION: u16 = 52;
+
+const ACC_PUBLIC: u16 = 0x0001;
+const ACC_SUPER: u16 = 0x0020;
+const ACC_NATIVE: u16 = 0x0100;
+
+/// A value of an annotation element.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElementValue {
+  String(String),
+  Int(i32),
+  Boolean(bool),
+  /// An enum constant, by the internal name of the enum (`org/spongepowered/api/event/Order`) and the constant name.
+  Enum(String, String),
+  /// A class literal, by internal name.
+  Class(String)
+}
+
+/// A runtime-visible annotation, such as `@Listener(order = Order.FIRST)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+  /// The internal name of the annotation type.
+  pub class_name: String,
+  pub elements: Vec<(String, ElementValue)>
+}
+
+impl Annotation {
+  pub fn new<S: Into<String>>(class_name: S) -> Self {
+    Annotation {
+      class_name: class_name.into(),
+      elements: Vec::new()
+    }
+  }
+
+  /// Sets the element `name` to `value`.
+  pub fn element<S: Into<String>>(mut self, name: S, value: ElementValue) -> Self {
+    self.elements.push((name.into(), value));
+    self
+  }
+}
+
+/// A `public native` method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NativeMethod {
+  pub name: String,
+  pub descriptor: String,
+  /// The internal names of the checked exceptions the method declares.
+  pub exceptions: Vec<String>,
+  pub annotations: Vec<Annotation>,
+  /// The annotations of each parameter, by index.
+  pub parameter_annotations: Vec<Vec<Annotation>>,
+  /// The Rust function implementing the method, or `None` to leave it to JNI's `Java_` symbol lookup.
+  pub function: Option<*mut c_void>
+}
+
+impl NativeMethod {
+  pub fn new(name: &str, descriptor: &str) -> Self {
+    NativeMethod {
+      name: name.to_owned(),
+      descriptor: descriptor.to_owned(),
+      parameter_annotations: vec![Vec::new(); convert::parameter_count(descriptor)],
+      exceptions: Vec::new(),
+      annotations: Vec::new(),
+      function: None
+    }
+  }
+
+  /// Implements the method with `function`, an `extern "C" fn` taking the `JNIEnv`, the object and the arguments
+  /// of the method, and returning its return type.
+  pub fn bind(mut self, function: *mut c_void) -> Self {
+    self.function = Some(function);
+    self
+  }
+
+  /// Declares that the method throws `class_name`.
+  pub fn throws<S: Into<String>>(mut self, class_name: S) -> Self {
+    self.exceptions.push(class_name.into());
+    self
+  }
+
+  pub fn annotation(mut self, annotation: Annotation) -> Self {
+    self.annotations.push(annotation);
+    self
+  }
+
+  /// Annotates the parameter at `index`, such as with `@First`.
+  pub fn parameter_annotation(mut self, index: usize, annotation: Annotation) -> Self {
+    self.parameter_annotations[index].push(annotation);
+    self
+  }
+}
+
+/// A public class with a no-argument constructor and native methods.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassFile {
+  /// The internal name of the class, such as `me/kyleclemens/spongejni/rust/generated/RustyListener`.
+  pub name: String,
+  pub super_name: String,
+  pub interfaces: Vec<String>,
+  pub methods: Vec<NativeMethod>
+}
+
+impl ClassFile {
+  /// A class named `name` (either `a/b/C` or `a.b.C`) extending `java.lang.Object`.
+  pub fn new(name: &str) -> Self {
+    ClassFile {
+      name: name.replace('.', "/"),
+      super_name: "java/lang/Object".to_owned(),
+      interfaces: Vec::new(),
+      methods: Vec::new()
+    }
+  }
+
+  pub fn interface<S: Into<String>>(mut self, class_name: S) -> Self {
+    self.interfaces.push(class_name.into());
+    self
+  }
+
+  pub fn method(mut self, method: NativeMethod) -> Self {
+    self.methods.push(method);
+    self
+  }
+
+  /// The bytes of the class file.
+  pub fn to_bytes(&self) -> Vec<u8> {
+    let mut pool = ConstantPool::default();
+    let this_class = pool.class(&self.name);
+    let super_class = pool.class(&self.super_name);
+    let interfaces: Vec<u16> = self.interfaces.iter().map(|x| pool.class(x)).collect();
+
+    let mut methods = Vec::new();
+    write_u16(&mut methods, self.methods.len() as u16 + 1);
+    write_constructor(&mut methods, &mut pool, super_class);
+    for method in &self.methods {
+      write_method(&mut methods, &mut pool, method);
+    }
+
+    let mut bytes = Vec::new();
+    write_u32(&mut bytes, MAGIC);
+    write_u16(&mut bytes, 0);
+    write_u16(&mut bytes, MAJOR_VERSION);
+    pool.write(&mut bytes);
+    write_u16(&mut bytes, ACC_PUBLIC | ACC_SUPER);
+    write_u16(&mut bytes, this_class);
+    write_u16(&mut bytes, super_class);
+    write_u16(&mut bytes, interfaces.len() as u16);
+    for interface in interfaces {
+      write_u16(&mut bytes, interface);
+    }
+    // no fields
+    write_u16(&mut bytes, 0);
+    bytes.extend(methods);
+    // no class attributes
+    write_u16(&mut bytes, 0);
+    bytes
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Constant {
+  Utf8(String),
+  Integer(i32),
+  Class(u16),
+  NameAndType(u16, u16),
+  Methodref(u16, u16)
+}
+
+/// The constant pool of a class file being written, adding each constant once.
+#[derive(Default)]
+struct ConstantPool {
+  constants: Vec<Constant>,
+  indices: HashMap<Constant, u16>
+}
+
+impl ConstantPool {
+  fn add(&mut self, constant: Constant) -> u16 {
+    if let Some(index) = self.indices.get(&constant) {
+      return *index;
+    }
+    self.constants.push(constant.clone());
+    // the pool is indexed from one
+    let index = self.constants.len() as u16;
+    self.indices.insert(constant, index);
+    index
+  }
+
+  fn utf8(&mut self, string: &str) -> u16 {
+    self.add(Constant::Utf8(string.to_owned()))
+  }
+
+  fn class(&mut self, name: &str) -> u16 {
+    let name = self.utf8(name);
+    self.add(Constant::Class(name))
+  }
+
+  fn method_ref(&mut self, class: u16, name: &str, descriptor: &str) -> u16 {
+    let name = self.utf8(name);
+    let descriptor = self.utf8(descriptor);
+    let name_and_type = self.add(Constant::NameAndType(name, descriptor));
+    self.add(Constant::Methodref(class, name_and_type))
+  }
+
+  fn write(&self, bytes: &mut Vec<u8>) {
+    write_u16(bytes, self.constants.len() as u16 + 1);
+    for constant in &self.constants {
+      match *constant {
+        Constant::Utf8(ref string) => {
+          bytes.push(1);
+          let encoded = modified_utf8(string);
+          write_u16(bytes, encoded.len() as u16);
+          bytes.extend(encoded);
+        },
+        Constant::Integer(value) => {
+          bytes.push(3);
+          write_u32(bytes, value as u32);
+        },
+        Constant::Class(name) => {
+          bytes.push(7);
+          write_u16(bytes, name);
+        },
+        Constant::NameAndType(name, descriptor) => {
+          bytes.push(12);
+          write_u16(bytes, name);
+          write_u16(bytes, descriptor);
+        },
+        Constant::Methodref(class, name_and_type) => {
+          bytes.push(10);
+          write_u16(bytes, class);
+          write_u16(bytes, name_and_type);
+        }
+      }
+    }
+  }
+}
+
+/// `public <init>() { super(); }`
+fn write_constructor(bytes: &mut Vec<u8>, pool: &mut ConstantPool, super_class: u16) {
+  let super_constructor = pool.method_ref(super_class, "<init>", "()V");
+  write_u16(bytes, ACC_PUBLIC);
+  write_u16(bytes, pool.utf8("<init>"));
+  write_u16(bytes, pool.utf8("()V"));
+  write_u16(bytes, 1);
+  // aload_0, invokespecial, return
+  let code = [0x2a, 0xb7, (super_constructor >> 8) as u8, super_constructor as u8, 0xb1];
+  let mut attribute = Vec::new();
+  // max stack and locals
+  write_u16(&mut attribute, 1);
+  write_u16(&mut attribute, 1);
+  write_u32(&mut attribute, code.len() as u32);
+  attribute.extend(&code);
+  // no exception table or attributes
+  write_u16(&mut attribute, 0);
+  write_u16(&mut attribute, 0);
+  write_attribute(bytes, pool, "Code", &attribute);
+}
+
+fn write_method(bytes: &mut Vec<u8>, pool: &mut ConstantPool, method: &NativeMethod) {
+  write_u16(bytes, ACC_PUBLIC | ACC_NATIVE);
+  write_u16(bytes, pool.utf8(&method.name));
+  write_u16(bytes, pool.utf8(&method.descriptor));
+  let has_parameter_annotations = method.parameter_annotations.iter().any(|x| !x.is_empty());
+  let attributes = [!method.exceptions.is_empty(), !method.annotations.is_empty(), has_parameter_annotations];
+  write_u16(bytes, attributes.iter().filter(|x| **x).count() as u16);
+  if !method.exceptions.is_empty() {
+    let mut attribute = Vec::new();
+    write_u16(&mut attribute, method.exceptions.len() as u16);
+    for exception in &method.exceptions {
+      write_u16(&mut attribute, pool.class(exception));
+    }
+    write_attribute(bytes, pool, "Exceptions", &attribute);
+  }
+  if !method.annotations.is_empty() {
+    let mut attribute = Vec::new();
+    write_annotations(&mut attribute, pool, &method.annotations);
+    write_attribute(bytes, pool, "RuntimeVisibleAnnotations", &attribute);
+  }
+  if has_parameter_annotations {
+    let mut attribute = vec![method.parameter_annotations.len() as u8];
+    for annotations in &method.parameter_annotations {
+      write_annotations(&mut attribute, pool, annotations);
+    }
+    write_attribute(bytes, pool, "RuntimeVisibleParameterAnnotations", &attribute);
+  }
+}
+
+fn write_annotations(bytes: &mut Vec<u8>, pool: &mut ConstantPool, annotations: &[Annotation]) {
+  write_u16(bytes, annotations.len() as u16);
+  for annotation in annotations {
+    write_u16(bytes, pool.utf8(&format!("L{};", annotation.class_name)));
+    write_u16(bytes, annotation.elements.len() as u16);
+    for (name, value) in &annotation.elements {
+      write_u16(bytes, pool.utf8(name));
+      match *value {
+        ElementValue::String(ref string) => {
+          bytes.push(b's');
+          write_u16(bytes, pool.utf8(string));
+        },
+        ElementValue::Int(value) => {
+          bytes.push(b'I');
+          write_u16(bytes, pool.add(Constant::Integer(value)));
+        },
+        ElementValue::Boolean(value) => {
+          bytes.push(b'Z');
+          write_u16(bytes, pool.add(Constant::Integer(value as i32)));
+        },
+        ElementValue::Enum(ref class_name, ref constant) => {
+          bytes.push(b'e');
+          write_u16(bytes, pool.utf8(&format!("L{};", class_name)));
+          write_u16(bytes, pool.utf8(constant));
+        },
+        ElementValue::Class(ref class_name) => {
+          bytes.push(b'c');
+          write_u16(bytes, pool.utf8(&format!("L{};", class_name)));
+        }
+      }
+    }
+  }
+}
+
+fn write_attribute(bytes: &mut Vec<u8>, pool: &mut ConstantPool, name: &str, attribute: &[u8]) {
+  write_u16(bytes, pool.utf8(name));
+  write_u32(bytes, attribute.len() as u32);
+  bytes.extend(attribute);
+}
+
+fn write_u16(bytes: &mut Vec<u8>, value: u16) {
+  bytes.push((value >> 8) as u8);
+  bytes.push(value as u8);
+}
+
+fn write_u32(bytes: &mut Vec<u8>, value: u32) {
+  write_u16(bytes, (value >> 16) as u16);
+  write_u16(bytes, value as u16);
+}
+
+/// Class files encode strings as modified UTF-8: nul is two bytes, and characters outside the BMP are surrogate pairs.
+fn modified_utf8(string: &str) -> Vec<u8> {
+  let mut bytes = Vec::new();
+  for unit in string.encode_utf16() {
+    match unit {
+      0x0001..=0x007f => bytes.push(unit as u8),
+      0x0000 | 0x0080..=0x07ff => {
+        bytes.push(0xc0 | (unit >> 6) as u8);
+        bytes.push(0x80 | (unit & 0x3f) as u8);
+      },
+      _ => {
+        bytes.push(0xe0 | (unit >> 12) as u8);
+        bytes.push(0x80 | ((unit >> 6) & 0x3f) as u8);
+        bytes.push(0x80 | (unit & 0x3f) as u8);
+      }
+    }
+  }
+  bytes
+}
+
+#[cfg(test)]
+mod tests {
+  extern crate classreader;
+
+  use self::classreader::{Attribute, ClassReader, ConstantPoolInfo};
+  use std::io::Cursor;
+
+  use super::{Annotation, ClassFile, ElementValue, NativeMethod};
+
+  fn utf8(constant_pool: &[ConstantPoolInfo], index: u16) -> &str {
+    match constant_pool[index as usize - 1] {
+      ConstantPoolInfo::Utf8(ref string) => string,
+      ref constant => panic!("{:?} is not a utf8 string", constant)
+    }
+  }
+
+  #[test]
+  fn reads_back_native_methods_and_annotations() {
+    let class = ClassFile::new("com.example.Listener")
+      .interface("java/lang/Runnable")
+      .method(
+        NativeMethod::new("joinReceived", "(Lcom/example/Join;Ljava/lang/Object;)V")
+          .throws("java/lang/Exception")
+          .annotation(Annotation::new("org/spongepowered/api/event/Listener")
+            .element("order", ElementValue::Enum("org/spongepowered/api/event/Order".to_owned(), "FIRST".to_owned())))
+          .parameter_annotation(1, Annotation::new("org/spongepowered/api/event/filter/cause/First"))
+      );
+    let read = ClassReader::new_from_reader(&mut Cursor::new(class.to_bytes())).expect("could not read class");
+    let pool = &read.constant_pool;
+    assert_eq!(read.major_version, 52);
+    assert!(read.is_public());
+    assert_eq!(read.interfaces.len(), 1);
+
+    let names: Vec<&str> = read.methods.iter().map(|x| utf8(pool, x.name_index)).collect();
+    assert_eq!(names, vec!["<init>", "joinReceived"]);
+    let method = &read.methods[1];
+    assert!(method.is_public() && method.access_flags & 0x0100 != 0);
+    assert_eq!(utf8(pool, method.descriptor_index), "(Lcom/example/Join;Ljava/lang/Object;)V");
+    let mut attributes = 0;
+    for attribute in &method.attributes {
+      match *attribute {
+        Attribute::Exceptions { ref exception_index_table } => assert_eq!(exception_index_table.len(), 1),
+        Attribute::RuntimeVisibleAnnotations(ref annotations) => {
+          assert_eq!(utf8(pool, annotations[0].type_index), "Lorg/spongepowered/api/event/Listener;");
+          assert_eq!(utf8(pool, annotations[0].element_value_pairs[0].element_name_index), "order");
+        },
+        Attribute::RuntimeVisibleParameterAnnotations(ref parameters) => {
+          assert_eq!(parameters.len(), 2);
+          assert!(parameters[0].is_empty());
+          assert_eq!(utf8(pool, parameters[1][0].type_index), "Lorg/spongepowered/api/event/filter/cause/First;");
+        },
+        ref attribute => panic!("unexpected attribute {:?}", attribute)
+      }
+      attributes += 1;
+    }
+    assert_eq!(attributes, 3);
+  }
+}
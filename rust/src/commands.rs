@@ -1,34 +1,39 @@
 use jni_sys::{JNIEnv, jobject};
 
-use generated_types::*;
-use plugin::{Plugin, JavaUtils, INSTANCE};
+use sponge::command::{CommandCallable, CommandResult, CommandSource};
+use sponge::command::spec::CommandSpec;
+use sponge::entity::living::player::User;
+use sponge::text::channel::MessageReceiver;
+use players::{self, Players};
+use plugin::{JavaUtils, Plugin};
+use state::{self, States};
 use extensions::*;
 
-type CommandCallable = command_CommandCallable;
-type CommandContext = command_args_CommandContext;
-type CommandResult = command_CommandResult;
-type CommandSource = command_CommandSource;
-type CommandSpec = command_spec_CommandSpec;
-type MessageReceiver = text_channel_MessageReceiver;
-type Text = text_Text;
+/// The data the example plugin keeps for each player: how many times it greeted them.
+#[derive(Default, Serialize, Deserialize)]
+pub struct Greetings {
+  pub count: isize
+}
 
 pub struct Commands;
 
 impl Commands {
   pub fn register(plugin: &Plugin) {
-    let executor = plugin.generate_command_executor("me.kyleclemens.spongejni.rust.generated.HelloCommandExecutor");
+    let executor = plugin.generate_command_executor(plugin.generated_class_name("HelloCommandExecutor").as_str(), execute);
     let command = CommandSpec::builder(plugin.env)
       .executor(executor)
       .build();
-    let list = JavaUtils::make_array_list(plugin.env, "java/lang/String", vec!["rusty".into_java_string(plugin.env)]);
     let callable = unsafe { CommandCallable::from(plugin.env, command.object) };
-    plugin.get_game().get_command_manager().register_1(plugin.object, callable, list);
+    plugin.register_command(callable, &["rusty"]);
   }
 }
 
-#[allow(non_snake_case)]
-#[no_mangle]
-pub extern fn Java_me_kyleclemens_spongejni_rust_generated_HelloCommandExecutor_execute(env: *mut JNIEnv, _: jobject, src: jobject, _: jobject) -> jobject {
+extern "C" fn execute(env: *mut JNIEnv, _: jobject, src: jobject, _: jobject) -> jobject {
+  greet(env, src, state::states())
+}
+
+// the command itself, counting in the greetings kept in `states`
+fn greet(env: *mut JNIEnv, src: jobject, states: &States) -> jobject {
   // Here we use the static from method, which is inherently unsafe (no checks – can crash JVM)
   let (src, receiver) = unsafe {
     // Convert the src jobject to a CommandSource
@@ -38,15 +43,20 @@ pub extern fn Java_me_kyleclemens_spongejni_rust_generated_HelloCommandExecutor_
     // Move these back into scope (Rust-specific)
     (src, receiver)
   };
-  let hello_string = {
-    // Get the global singleton (very discouraged in Rust, but required to share any sort of state)
-    // This lock will drop when it goes out of scope at the end of this block. This will free it for
-    // use by other threads (listeners, for example). Other threads will block waiting for a lock,
-    // so it is important to request a lock and drop it as quickly as possible.
-    let mut instance = INSTANCE.lock().unwrap();
-    // Get the amount of times we've said hello to this player
-    // FIXME: use UUIDs
-    let hello_count = instance.player_count.entry(src.get_name().into_rust_string(env)).or_insert(0);
+  // Only players have a profile whose UUID their greetings are kept under
+  let user_class = JavaUtils::load_class(env, "org/spongepowered/api/entity/living/player/User");
+  let hello_string = if unsafe { ((**env).IsInstanceOf)(env, src.object, user_class) } == 0 {
+    "I only count how often I greet players.".to_owned()
+  } else {
+    let uuid = players::uuid_of(env, &unsafe { User::from(env, src.object) });
+    // Get the greetings of every player, which the plugin registered as its state. Commands run on
+    // the server thread, where the state lives, so nothing is locked. Borrowing it mutably panics if
+    // it is already borrowed, so the borrow ends with this block, before anything else could want it.
+    let players = states.get::<Players<Greetings>>();
+    let mut players = players.borrow_mut();
+    // Get the amount of times we've said hello to this player, which is read from disk if they
+    // have not joined since the server started
+    let hello_count = &mut players.get(&uuid).count;
     // Create a string based on hello_count
     let string = if *hello_count == 0 {
       "I've never said hello to you before.".to_owned()
@@ -68,3 +78,57 @@ pub extern fn Java_me_kyleclemens_spongejni_rust_generated_HelloCommandExecutor_
   // Return success
   CommandResult::success(env).object
 }
+
+#[cfg(test)]
+mod tests {
+  use jni_sys::jobject;
+  use std::env;
+  use std::fs;
+  use std::process;
+
+  use convert::ToJValue;
+  use mock::MockEnv;
+  use players::Players;
+  use state::States;
+  use super::{Greetings, greet};
+
+  #[test]
+  fn hello_counts_greetings() {
+    let dir = env::temp_dir().join(format!("spongejni-hello-counts-greetings-{}", process::id()));
+    let states = States::new();
+    states.register(Players::<Greetings>::new(dir.clone()));
+    let mock = MockEnv::new();
+    let env = mock.env();
+    mock.class("org/spongepowered/api/entity/living/player/Player", &[
+      "org/spongepowered/api/command/CommandSource",
+      "org/spongepowered/api/entity/living/player/User",
+      "org/spongepowered/api/text/channel/MessageReceiver"
+    ]);
+    mock.class("org/spongepowered/api/profile/GameProfile", &["org/spongepowered/api/util/Identifiable"]);
+    mock.on("org/spongepowered/api/command/CommandSource", "getName", "()Ljava/lang/String;", |mock, _, _| mock.string("kyle").to_jvalue());
+    mock.on("org/spongepowered/api/entity/living/player/User", "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", |mock, _, _| mock.object("org/spongepowered/api/profile/GameProfile").to_jvalue());
+    mock.on("org/spongepowered/api/util/Identifiable", "getUniqueId", "()Ljava/util/UUID;", |mock, _, _| mock.object("java/util/UUID").to_jvalue());
+    mock.on("java/util/UUID", "toString", "()Ljava/lang/String;", |mock, _, _| mock.string("069a79f4-44e9-4726-a5be-fca90e38aaf5").to_jvalue());
+    // a string stands in for the Text it would become
+    mock.on("org/spongepowered/api/text/Text", "of", "(Ljava/lang/String;)Lorg/spongepowered/api/text/LiteralText;", |_, _, args| args[0]);
+    let success = mock.object("org/spongepowered/api/command/CommandResult");
+    mock.returns("org/spongepowered/api/command/CommandResult", "success", "()Lorg/spongepowered/api/command/CommandResult;", success);
+
+    let player = mock.object("org/spongepowered/api/entity/living/player/Player");
+    assert_eq!(greet(env, player, &states), success);
+    greet(env, player, &states);
+
+    let messages: Vec<String> = mock.calls().into_iter()
+      .filter(|x| x.name == "sendMessage" && x.this == player)
+      .map(|x| mock.string_value(x.arg::<jobject>(0)).expect("message was not a string"))
+      .collect();
+    assert_eq!(messages, vec![
+      "Hello, kyle! I've never said hello to you before.",
+      "Hello, kyle! I've said hello to you 1 time before."
+    ]);
+    // only created once a player is saved
+    if dir.exists() {
+      fs::remove_dir_all(dir).unwrap();
+    }
+  }
+}
@@ -0,0 +1,146 @@
+//! Typed configuration for native plugins, read from and written to their own config files.
+//!
+//! Each native plugin has a HOCON file, `config/<id>.conf`, which `SpongeJNIShim.loadConfig` loads with Configurate.
+//! The defaults given to [`load`](fn.load.html) are merged into it, so that keys missing from the file are written
+//! with their default value and comment, and the node is handed back as plain Java values (maps, lists, strings,
+//! numbers and booleans). Those are converted to a `serde_json::Value` here, which deserializes into the plugin's
+//! `#[derive(Deserialize)]` struct.
+
+use jni_sys::{JNIEnv, JNI_TRUE, jclass, jmethodID, jobject, jvalue};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Map, Number, Value};
+use std::ffi::CString;
+use std::ptr;
+
+use extensions::{ConvertStringToJava, ConvertStringToRust};
+use lookup;
+use plugin::Plugin;
+use proxy::Boxed;
+
+/// Loads the config of `plugin` into a `T`.
+///
+/// `defaults` fills in and is saved for every key the file does not have yet, and `comments` are written above the
+/// keys at their paths (`"greeting"` or `"limits.per-player"`) that have no comment. An error is returned if the file
+/// does not match `T`, in which case it is left as it was, apart from the added keys.
+pub fn load<T: Serialize + DeserializeOwned>(plugin: &Plugin, defaults: &T, comments: &[(&str, &str)]) -> Result<T, serde_json::Error> {
+  let env = plugin.env;
+  let defaults = to_object(env, &serde_json::to_value(defaults)?);
+  let comments = comments.iter()
+    .map(|&(path, comment)| (path.to_owned(), Value::String(comment.to_owned())))
+    .collect();
+  let comments = to_object(env, &Value::Object(comments));
+  let config = java_method!(env, plugin.shim, "loadConfig", "(Ljava/lang/Object;Ljava/util/Map;)Ljava/lang/Object;", CallObjectMethodA, defaults, comments);
+  let value = to_value(env, config);
+  unsafe {
+    ((**env).DeleteLocalRef)(env, defaults);
+    ((**env).DeleteLocalRef)(env, comments);
+    ((**env).DeleteLocalRef)(env, config);
+  }
+  serde_json::from_value(value)
+}
+
+/// Converts `value` to the Java values Configurate stores: a `LinkedHashMap`, an `ArrayList`, a `String`, an `Integer`,
+/// `Long` or `Double`, a `Boolean`, or null.
+pub fn to_object(env: *mut JNIEnv, value: &Value) -> jobject {
+  match *value {
+    Value::Null => ptr::null_mut(),
+    Value::Bool(b) => b.to_boxed(env),
+    Value::Number(ref n) => match n.as_i64() {
+      Some(i) if i as i32 as i64 == i => (i as i32).to_boxed(env),
+      Some(i) => i.to_boxed(env),
+      None => n.as_f64().unwrap_or_default().to_boxed(env)
+    },
+    Value::String(ref s) => s.as_str().into_java_string(env),
+    Value::Array(ref values) => {
+      let list = new_object!(env, "java/util/ArrayList", "()V");
+      for value in values {
+        let element = to_object(env, value);
+        bound_method!(env, "java/util/List", list, "add", "(Ljava/lang/Object;)Z", CallBooleanMethodA, element);
+        delete_local(env, element);
+      }
+      list
+    },
+    Value::Object(ref entries) => {
+      let map = new_object!(env, "java/util/LinkedHashMap", "()V");
+      for (key, value) in entries {
+        let key = key.as_str().into_java_string(env);
+        let value = to_object(env, value);
+        bound_method!(env, "java/util/Map", map, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, key, value);
+        delete_local(env, key);
+        delete_local(env, value);
+      }
+      map
+    }
+  }
+}
+
+/// Converts the Java value `object` to a `Value`. Maps and collections are converted element by element, with the keys
+/// of maps turned into strings, and objects of any other class are converted by their `toString`.
+pub fn to_value(env: *mut JNIEnv, object: jobject) -> Value {
+  if object.is_null() {
+    return Value::Null;
+  }
+  if is_instance(env, object, "java/lang/String") {
+    Value::String(object.into_rust_string(env))
+  } else if is_instance(env, object, "java/lang/Boolean") {
+    Value::Bool(bool::from_boxed(env, object))
+  } else if is_instance(env, object, "java/lang/Double") || is_instance(env, object, "java/lang/Float") {
+    let number = bound_method!(env, "java/lang/Number", object, "doubleValue", "()D", CallDoubleMethod);
+    Number::from_f64(number).map(Value::Number).unwrap_or(Value::Null)
+  } else if is_instance(env, object, "java/lang/Number") {
+    let number = bound_method!(env, "java/lang/Number", object, "longValue", "()J", CallLongMethod);
+    Value::Number(number.into())
+  } else if is_instance(env, object, "java/util/Map") {
+    let keys = bound_method!(env, "java/util/Map", object, "keySet", "()Ljava/util/Set;", CallObjectMethod);
+    let mut entries = Map::new();
+    for key in elements(env, keys) {
+      let value = bound_method!(env, "java/util/Map", object, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, key);
+      entries.insert(to_string(env, key), to_value(env, value));
+      delete_local(env, key);
+      delete_local(env, value);
+    }
+    delete_local(env, keys);
+    Value::Object(entries)
+  } else if is_instance(env, object, "java/util/Collection") {
+    let values = elements(env, object).into_iter()
+      .map(|element| {
+        let value = to_value(env, element);
+        delete_local(env, element);
+        value
+      })
+      .collect();
+    Value::Array(values)
+  } else {
+    Value::String(to_string(env, object))
+  }
+}
+
+fn is_instance(env: *mut JNIEnv, object: jobject, class_name: &str) -> bool {
+  let class = lookup::load_class(env, class_name);
+  unsafe { ((**env).IsInstanceOf)(env, object, class) == JNI_TRUE }
+}
+
+fn to_string(env: *mut JNIEnv, object: jobject) -> String {
+  let string = static_java_method!(env, "java/lang/String", "valueOf", "(Ljava/lang/Object;)Ljava/lang/String;", CallStaticObjectMethodA, object);
+  let rust = string.into_rust_string(env);
+  delete_local(env, string);
+  rust
+}
+
+// local references to the elements of `collection`, which the caller deletes
+fn elements(env: *mut JNIEnv, collection: jobject) -> Vec<jobject> {
+  let array = bound_method!(env, "java/util/Collection", collection, "toArray", "()[Ljava/lang/Object;", CallObjectMethod);
+  let length = unsafe { ((**env).GetArrayLength)(env, array) };
+  let elements = (0..length)
+    .map(|i| unsafe { ((**env).GetObjectArrayElement)(env, array, i) })
+    .collect();
+  delete_local(env, array);
+  elements
+}
+
+fn delete_local(env: *mut JNIEnv, object: jobject) {
+  if !object.is_null() {
+    unsafe { ((**env).DeleteLocalRef)(env, object) };
+  }
+}
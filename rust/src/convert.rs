@@ -0,0 +1,337 @@
+use jni_sys::{JNIEnv, JNI_FALSE, JNI_TRUE, jboolean, jchar, jvalue, jobject, jclass, jmethodID};
+use std::ffi::CString;
+use std::path::PathBuf;
+use std::mem;
+use std::sync::{Mutex, MutexGuard};
+use url::Url;
+
+use extensions::{ConvertStringToJava, ConvertStringToRust};
+use lookup;
+use plugin::JavaUtils;
+
+/// A Rust type that the bindings convert Java objects to, where `bindings.toml` maps their class to it.
+pub trait FromJava {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self;
+}
+
+/// A Rust type that the bindings convert to a Java object, where `bindings.toml` maps its class to it.
+pub trait ToJava {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject;
+}
+
+/// A value passed to Java as a method argument.
+///
+/// `jvalue` is the 64 bits of a JNI `jvalue` union, so each value is written through the accessor of its own JNI
+/// type: booleans as a `jboolean`, chars as a UTF-16 `jchar` and floats as a `jfloat`, rather than by a numeric cast to
+/// the whole 64 bits.
+pub trait ToJValue {
+  fn to_jvalue(&self) -> jvalue;
+}
+
+macro_rules! to_jvalue {
+  ($($t:ty => $accessor:ident, $value:ident, $java:expr);+) => {
+    $(
+      impl ToJValue for $t {
+        fn to_jvalue(&self) -> jvalue {
+          let $value = *self;
+          let mut result = jvalue::default();
+          unsafe { *result.$accessor() = $java };
+          result
+        }
+      }
+    )+
+  }
+}
+
+to_jvalue! {
+  bool => z, x, x as jboolean;
+  char => c, x, char_to_java(x);
+  u8 => z, x, x;
+  u16 => c, x, x;
+  i8 => b, x, x;
+  i16 => s, x, x;
+  i32 => i, x, x;
+  i64 => j, x, x;
+  f32 => f, x, x;
+  f64 => d, x, x;
+  jobject => l, x, x
+}
+
+impl ToJValue for jvalue {
+  fn to_jvalue(&self) -> jvalue {
+    *self
+  }
+}
+
+/// A value read back out of a `jvalue`, the reverse of [`ToJValue`](trait.ToJValue.html), through the same accessor
+/// it was written with.
+pub trait FromJValue {
+  fn from_jvalue(value: jvalue) -> Self;
+}
+
+macro_rules! from_jvalue {
+  ($($t:ty => $accessor:ident, $java:ident, $value:expr);+) => {
+    $(
+      impl FromJValue for $t {
+        fn from_jvalue(mut value: jvalue) -> Self {
+          let $java = unsafe { *value.$accessor() };
+          $value
+        }
+      }
+    )+
+  }
+}
+
+impl FromJValue for () {
+  fn from_jvalue(_value: jvalue) -> Self {}
+}
+
+from_jvalue! {
+  bool => z, x, x != JNI_FALSE;
+  char => c, x, char_from_java(x);
+  u8 => z, x, x;
+  u16 => c, x, x;
+  i8 => b, x, x;
+  i16 => s, x, x;
+  i32 => i, x, x;
+  i64 => j, x, x;
+  f32 => f, x, x;
+  f64 => d, x, x;
+  jobject => l, x, x
+}
+
+/// The character of a Java `char`, which is a UTF-16 code unit.
+///
+/// A lone surrogate is half of a character that Java needs two `char`s for, and becomes U+FFFD.
+pub fn char_from_java(value: jchar) -> char {
+  char::decode_utf16(Some(value)).next().expect("no char decoded").unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+/// The Java `char` of a character, the reverse of [`char_from_java`](fn.char_from_java.html).
+///
+/// A character outside the Basic Multilingual Plane needs two `char`s in Java, so it cannot be passed as one and becomes
+/// U+FFFD, like a lone surrogate coming back from Java does.
+pub fn char_to_java(value: char) -> jchar {
+  let mut units = [0; 2];
+  match *value.encode_utf16(&mut units) {
+    [unit] => unit,
+    _ => char::REPLACEMENT_CHARACTER as jchar
+  }
+}
+
+/// The number of parameters in a method descriptor.
+pub fn parameter_count(descriptor: &str) -> usize {
+  let params = descriptor[1..].split(')').next().expect("no params in descriptor");
+  let mut count = 0;
+  let mut chars = params.chars();
+  while let Some(c) = chars.next() {
+    match c {
+      '[' => continue,
+      'L' => { chars.by_ref().find(|x| *x == ';'); },
+      _ => {}
+    }
+    count += 1;
+  }
+  count
+}
+
+/// Calls the method `name` with `descriptor` on `object` as `class_name` implements it, through the
+/// `CallNonvirtual<Type>MethodA` functions, even where the class of `object` overrides it.
+pub fn call_nonvirtual(env: *mut JNIEnv, object: jobject, class_name: &str, name: &str, descriptor: &str, args: &[jvalue]) -> jvalue {
+  let class = lookup::load_class(env, class_name);
+  let method_id = unsafe {
+    ((**env).GetMethodID)(env, class, CString::new(name).unwrap().as_ptr(), CString::new(descriptor).unwrap().as_ptr())
+  };
+  if method_id.is_null() { panic!("{}#{}{} was null", class_name, name, descriptor); }
+  let args = args.as_ptr();
+  let return_type = descriptor.rsplit(')').next().expect("no return type in descriptor");
+  unsafe {
+    match return_type.chars().next() {
+      Some('V') => {
+        ((**env).CallNonvirtualVoidMethodA)(env, object, class, method_id, args);
+        jvalue::default()
+      },
+      Some('Z') => ((**env).CallNonvirtualBooleanMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('B') => ((**env).CallNonvirtualByteMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('C') => ((**env).CallNonvirtualCharMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('S') => ((**env).CallNonvirtualShortMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('I') => ((**env).CallNonvirtualIntMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('J') => ((**env).CallNonvirtualLongMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('F') => ((**env).CallNonvirtualFloatMethodA)(env, object, class, method_id, args).to_jvalue(),
+      Some('D') => ((**env).CallNonvirtualDoubleMethodA)(env, object, class, method_id, args).to_jvalue(),
+      _ => ((**env).CallNonvirtualObjectMethodA)(env, object, class, method_id, args).to_jvalue()
+    }
+  }
+}
+
+/// `java.nio.file.Path`
+impl FromJava for PathBuf {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    let string = java_method!(env, object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if string.is_null() { panic!("java/nio/file/Path#toString was null") }
+    PathBuf::from(string.into_rust_string(env))
+  }
+}
+
+impl ToJava for PathBuf {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    let string = self.to_str().expect("path was not valid unicode").into_java_string(env);
+    let more = JavaUtils::make_array(env, "java/lang/String", Vec::new());
+    let path = static_java_method!(env, "java/nio/file/Paths", "get", "(Ljava/lang/String;[Ljava/lang/String;)Ljava/nio/file/Path;", CallStaticObjectMethodA, string, more);
+    if path.is_null() { panic!("java/nio/file/Paths#get was null") }
+    path
+  }
+}
+
+/// `java.net.URL`
+impl FromJava for Url {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    let string = bound_method!(env, "java/net/URL", object, "toExternalForm", "()Ljava/lang/String;", CallObjectMethod);
+    if string.is_null() { panic!("java/net/URL#toExternalForm was null") }
+    let string = string.into_rust_string(env);
+    Url::parse(&string).unwrap_or_else(|e| panic!("java/net/URL {} could not be parsed: {}", string, e))
+  }
+}
+
+impl ToJava for Url {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    let string = self.as_str().into_java_string(env);
+    let url = new_object!(env, "java/net/URL", "(Ljava/lang/String;)V", string);
+    if url.is_null() { panic!("java/net/URL#<init> was null") }
+    url
+  }
+}
+
+/// Global references to the constants of a Java enum, which generated enums convert through.
+///
+/// The constants are looked up by name the first time either conversion is used, so the Rust variants do not depend
+/// on the ordinals of the Java enum the bindings were generated from.
+pub struct EnumConstants {
+  class_name: &'static str,
+  names: &'static [&'static str],
+  // jobjects are not Send; these are global references, valid on any thread
+  constants: Mutex<Vec<usize>>
+}
+
+impl EnumConstants {
+  pub const fn new(class_name: &'static str, names: &'static [&'static str]) -> Self {
+    EnumConstants {
+      class_name,
+      names,
+      constants: Mutex::new(Vec::new())
+    }
+  }
+
+  fn load(&'static self, env: *mut JNIEnv) -> MutexGuard<'static, Vec<usize>> {
+    {
+      let constants = self.constants.lock().unwrap();
+      if !constants.is_empty() {
+        return constants;
+      }
+    }
+    // not locked while looking up, which runs the static initializer of the enum, and that may convert it too
+    let resolved = self.resolve(env);
+    let mut constants = self.constants.lock().unwrap();
+    if constants.is_empty() {
+      *constants = resolved;
+      LOADED_ENUMS.lock().unwrap().push(self);
+    } else {
+      // another thread got there first
+      for constant in resolved {
+        unsafe { ((**env).DeleteGlobalRef)(env, constant as jobject) };
+      }
+    }
+    constants
+  }
+
+  // global references to the constants, in the order of the names
+  fn resolve(&self, env: *mut JNIEnv) -> Vec<usize> {
+    unsafe {
+      let class = lookup::load_class(env, self.class_name);
+      let signature = CString::new(format!("L{};", self.class_name)).unwrap();
+      self.names.iter()
+        .map(|name| {
+          let field_id = ((**env).GetStaticFieldID)(env, class, CString::new(*name).unwrap().as_ptr(), signature.as_ptr());
+          if field_id.is_null() { panic!("{}#{} was null", self.class_name, name); }
+          let constant = ((**env).GetStaticObjectField)(env, class, field_id);
+          let global = ((**env).NewGlobalRef)(env, constant) as usize;
+          ((**env).DeleteLocalRef)(env, constant);
+          global
+        })
+        .collect()
+    }
+  }
+
+  /// A global reference to the constant at `index` in the names.
+  pub fn get(&'static self, env: *mut JNIEnv, index: usize) -> jobject {
+    self.load(env)[index] as jobject
+  }
+
+  /// The index in the names of the constant `object`, or `None` if it is a constant the names do not list, such as one
+  /// added to the Java enum after the bindings were generated.
+  pub fn ordinal(&'static self, env: *mut JNIEnv, object: jobject) -> Option<usize> {
+    // copied out, so nothing is locked during the comparisons
+    let constants = self.load(env).clone();
+    constants.iter().position(|x| unsafe { ((**env).IsSameObject)(env, *x as jobject, object) } == JNI_TRUE)
+  }
+}
+
+lazy_static! {
+  // the enums whose constants were looked up, to release them when the library is unloaded
+  static ref LOADED_ENUMS: Mutex<Vec<&'static EnumConstants>> = Mutex::new(Vec::new());
+}
+
+/// Deletes the global references to the enum constants looked up so far.
+pub fn release_enum_constants(env: *mut JNIEnv) {
+  // not locked while the constants are, which load locks the other way around
+  let loaded = mem::take(&mut *LOADED_ENUMS.lock().unwrap());
+  for constants in loaded {
+    for constant in constants.constants.lock().unwrap().drain(..) {
+      unsafe { ((**env).DeleteGlobalRef)(env, constant as jobject) };
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use mock::MockEnv;
+  use super::{EnumConstants, FromJValue, ToJValue, char_from_java, char_to_java};
+
+  #[test]
+  fn chars_outside_the_bmp_become_replacement_characters() {
+    assert_eq!(char_to_java('é'), 0xe9);
+    assert_eq!(unsafe { *'é'.to_jvalue().c() }, 0xe9);
+    assert_eq!(char_to_java('🦀'), 0xfffd);
+    assert_eq!(unsafe { *'🦀'.to_jvalue().c() }, 0xfffd);
+    // a lone surrogate is replaced the same way on the way back
+    assert_eq!(char_from_java(0xd83e), '\u{fffd}');
+  }
+
+  #[test]
+  fn values_are_read_back_through_the_accessor_they_were_written_with() {
+    assert!(bool::from_jvalue(true.to_jvalue()));
+    assert_eq!(unsafe { *false.to_jvalue().z() }, 0);
+    assert_eq!(i8::from_jvalue((-3i8).to_jvalue()), -3);
+    assert_eq!(i16::from_jvalue((-300i16).to_jvalue()), -300);
+    assert_eq!(i32::from_jvalue((-70000i32).to_jvalue()), -70000);
+    assert_eq!(i64::from_jvalue(i64::MIN.to_jvalue()), i64::MIN);
+    assert_eq!(f32::from_jvalue(1.5f32.to_jvalue()), 1.5);
+    assert_eq!(unsafe { *1.5f32.to_jvalue().f() }, 1.5);
+    assert_eq!(f64::from_jvalue((-0.25f64).to_jvalue()), -0.25);
+    assert_eq!(char::from_jvalue('é'.to_jvalue()), 'é');
+  }
+
+  #[test]
+  fn constants_the_names_do_not_list_have_no_ordinal() {
+    static CONSTANTS: EnumConstants = EnumConstants::new("com/example/Color", &["RED", "GREEN"]);
+    let mock = MockEnv::new();
+    mock.class("com/example/Color", &[]);
+    let (red, green, blue) = (mock.object("com/example/Color"), mock.object("com/example/Color"), mock.object("com/example/Color"));
+    mock.set_static_field("com/example/Color", "RED", red);
+    mock.set_static_field("com/example/Color", "GREEN", green);
+    mock.set_static_field("com/example/Color", "BLUE", blue);
+    assert_eq!(CONSTANTS.ordinal(mock.env(), green), Some(1));
+    assert_eq!(CONSTANTS.ordinal(mock.env(), red), Some(0));
+    assert_eq!(CONSTANTS.ordinal(mock.env(), blue), None);
+  }
+}
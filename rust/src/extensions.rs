@@ -1,16 +1,17 @@
 use jni_sys::{JNIEnv, jstring};
 use std::ffi::{CString, CStr};
 
-use generated_types::*;
-
-type Text = text_Text;
-type MessageReceiver = text_channel_MessageReceiver;
+#[cfg(feature = "text")]
+use sponge::text::Text;
+#[cfg(feature = "text")]
+use sponge::text::channel::MessageReceiver;
 
+#[cfg(feature = "text")]
 pub trait GoodText {
-  fn of_rust(env: *mut JNIEnv, string: &str) -> text_Text {
+  fn of_rust(env: *mut JNIEnv, string: &str) -> Text {
     unsafe {
-      text_Text::from(env,
-        text_Text::of_1(env,
+      Text::from(env,
+        Text::of_string(env,
           string.into_java_string(env)
         ).object
       )
@@ -18,6 +19,7 @@ pub trait GoodText {
   }
 }
 
+#[cfg(feature = "text")]
 impl GoodText for Text {}
 
 pub trait ConvertStringToRust {
@@ -45,13 +47,15 @@ impl<'a, S> ConvertStringToJava for S where S: Into<&'a str> {
   }
 }
 
+#[cfg(feature = "text")]
 pub trait RustMessageReceiver {
   fn send_rust_message(&self, string: &str);
 }
 
+#[cfg(feature = "text")]
 impl RustMessageReceiver for MessageReceiver {
   fn send_rust_message(&self, string: &str) {
     // Use the extension of_rust to ease some of the JNI quirks (use extensions::GoodText)
-    self.send_message(Text::of_rust(self.env, string));
+    self.send_message_text(Text::of_rust(self.env, string));
   }
 }
@@ -1,11 +1,19 @@
 #![cfg_attr(feature="clippy", feature(plugin))]
 #![cfg_attr(feature="clippy", plugin(clippy))]
-#![allow(not_unsafe_ptr_arg_deref)]
+#![allow(clippy::not_unsafe_ptr_arg_deref, clippy::macro_metavars_in_unsafe)]
 
 extern crate jni_sys;
 #[macro_use]
 extern crate lazy_static;
+#[macro_use]
+extern crate log;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate url;
 
+#[macro_export]
 macro_rules! java_method {
     ($env:expr, $caller:expr, $method:expr, $descriptor:expr, $call_using:ident, $($args:expr),*) => {{
       unsafe {
@@ -13,7 +21,7 @@ macro_rules! java_method {
         if class.is_null() { panic!("class was null"); }
         let method_id: jmethodID = ((**$env).GetMethodID)($env, class, CString::new($method).unwrap().as_ptr(), CString::new($descriptor).unwrap().as_ptr());
         if method_id.is_null() { panic!("method_id was null"); }
-        let args = vec![ $( jvalue { _data: $args as u64 } ),* ];
+        let args: Vec<jvalue> = vec![ $( $crate::convert::ToJValue::to_jvalue(&$args) ),* ];
         let ret = ((**$env).$call_using)($env, $caller, method_id, args.as_ptr());
         ((**$env).DeleteLocalRef)($env, class);
         ret
@@ -32,32 +40,63 @@ macro_rules! java_method {
     }}
 }
 
+/// Calls `$method` on `$caller`, resolving it on `$class_name`, the class declaring it.
+///
+/// The class and method ID are looked up once for each use of the macro, through the plugin's class loader.
+#[macro_export]
+macro_rules! bound_method {
+    ($env:expr, $class_name:expr, $caller:expr, $method:expr, $descriptor:expr, $call_using:ident, $($args:expr),*) => {{
+      static METHOD: $crate::lookup::BoundMethod = $crate::lookup::BoundMethod::new($class_name, $method, $descriptor, false);
+      let (_, method_id) = METHOD.get($env);
+      unsafe {
+        let args: Vec<jvalue> = vec![ $( $crate::convert::ToJValue::to_jvalue(&$args) ),* ];
+        ((**$env).$call_using)($env, $caller, method_id, args.as_ptr())
+      }
+    }};
+    ($env:expr, $class_name:expr, $caller:expr, $method:expr, $descriptor:expr, $call_using:ident) => {{
+      static METHOD: $crate::lookup::BoundMethod = $crate::lookup::BoundMethod::new($class_name, $method, $descriptor, false);
+      let (_, method_id) = METHOD.get($env);
+      unsafe {
+        ((**$env).$call_using)($env, $caller, method_id)
+      }
+    }}
+}
+
+#[macro_export]
 macro_rules! static_java_method {
     ($env:expr, $caller:expr, $method:expr, $descriptor:expr, $call_using:ident, $($args:expr),*) => {{
+      static METHOD: $crate::lookup::BoundMethod = $crate::lookup::BoundMethod::new($caller, $method, $descriptor, true);
+      let (class, method_id) = METHOD.get($env);
       unsafe {
-        let class: jclass = ((**$env).FindClass)($env, CString::new($caller).unwrap().as_ptr());
-        if class.is_null() { panic!("class was null"); }
-        let method_id: jmethodID = ((**$env).GetStaticMethodID)($env, class, CString::new($method).unwrap().as_ptr(), CString::new($descriptor).unwrap().as_ptr());
-        if method_id.is_null() { panic!("method_id was null"); }
-        let args = vec![ $( jvalue { _data: $args as u64 } ),* ];
-        let ret = ((**$env).$call_using)($env, class, method_id, args.as_ptr());
-        ((**$env).DeleteLocalRef)($env, class);
-        ret
+        let args: Vec<jvalue> = vec![ $( $crate::convert::ToJValue::to_jvalue(&$args) ),* ];
+        ((**$env).$call_using)($env, class, method_id, args.as_ptr())
       }
     }};
     ($env:expr, $caller:expr, $method:expr, $descriptor:expr, $call_using:ident) => {{
+      static METHOD: $crate::lookup::BoundMethod = $crate::lookup::BoundMethod::new($caller, $method, $descriptor, true);
+      let (class, method_id) = METHOD.get($env);
       unsafe {
-        let class: jclass = ((**$env).FindClass)($env, CString::new($caller).unwrap().as_ptr());
-        if class.is_null() { panic!("class was null"); }
-        let method_id: jmethodID = ((**$env).GetStaticMethodID)($env, class, CString::new($method).unwrap().as_ptr(), CString::new($descriptor).unwrap().as_ptr());
-        if method_id.is_null() { panic!("method_id was null"); }
-        let ret = ((**$env).$call_using)($env, class, method_id);
-        ((**$env).DeleteLocalRef)($env, class);
-        ret
+        ((**$env).$call_using)($env, class, method_id)
       }
     }}
 }
 
+#[macro_export]
+macro_rules! new_object {
+    ($env:expr, $class_name:expr, $descriptor:expr, $($args:expr),*) => {{
+      static METHOD: $crate::lookup::BoundMethod = $crate::lookup::BoundMethod::new($class_name, "<init>", $descriptor, false);
+      let (class, method_id) = METHOD.get($env);
+      unsafe {
+        let args: Vec<jvalue> = vec![ $( $crate::convert::ToJValue::to_jvalue(&$args) ),* ];
+        ((**$env).NewObjectA)($env, class, method_id, args.as_ptr())
+      }
+    }};
+    ($env:expr, $class_name:expr, $descriptor:expr) => {{
+      new_object!($env, $class_name, $descriptor,)
+    }}
+}
+
+#[macro_export]
 macro_rules! java_field {
     ($env:expr, $caller:expr, $field:expr, $sig:expr, $call_using:ident) => {{
       unsafe {
@@ -70,8 +109,24 @@ macro_rules! java_field {
     }}
 }
 
+#[cfg(all(feature = "command", feature = "text", feature = "entity", feature = "event", feature = "profile", feature = "util"))]
 pub mod commands;
+#[cfg(all(feature = "data", feature = "entity", feature = "event"))]
 pub mod listeners;
+pub mod class_file;
+pub mod config;
+pub mod convert;
+pub mod math;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+#[cfg(all(feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+pub mod players;
 pub mod plugin;
-pub mod generated_types;
+pub mod proxy;
+pub mod reload;
+pub mod rusty;
+pub mod sponge;
+pub mod state;
 pub mod extensions;
+pub mod lifecycle;
+pub mod lookup;
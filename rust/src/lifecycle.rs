@@ -0,0 +1,121 @@
+//! Lets the plugin follow the game through its states, beyond `init`.
+//!
+//! SpongeJNI listens for the `GameStateEvent`s and passes each on to every library through `SpongeJNIShim.lifecycle`,
+//! which calls the [`Lifecycle`](trait.Lifecycle.html) given to [`register`](fn.register.html). A library loaded by a
+//! dev reload is taken through the states the game already passed.
+//!
+//! `JNI_OnLoad` keeps the `JavaVM` the library was loaded in, and `JNI_OnUnload` calls
+//! [`Lifecycle::on_unload`](trait.Lifecycle.html#method.on_unload) if the library is unloaded without being asked to
+//! first.
+
+use jni_sys::{JNIEnv, JNI_OK, JavaVM, jint, jobject, jstring};
+use std::os::raw::c_void;
+use std::ptr;
+use std::sync::{Arc, Mutex};
+
+use extensions::ConvertStringToRust;
+use plugin::{JavaUtils, Plugin};
+
+pub const JNI_VERSION_1_8: jint = 0x0001_0008;
+
+/// The states of the game a plugin can react to. Every method does nothing by default.
+///
+/// Apart from `on_unload`, the methods are called on the server thread.
+pub trait Lifecycle: Send + Sync + 'static {
+  /// `GamePreInitializationEvent`: the plugin's configuration should be loaded.
+  fn on_pre_init(&self, _plugin: &Plugin) {}
+
+  /// `GameInitializationEvent`: the plugin should register its commands and listeners.
+  fn on_init(&self, _plugin: &Plugin) {}
+
+  /// `GameStartingServerEvent`: the server is starting, and worlds are being loaded.
+  fn on_server_starting(&self, _plugin: &Plugin) {}
+
+  /// `GameStartedServerEvent`: the server is ready for players.
+  fn on_server_started(&self, _plugin: &Plugin) {}
+
+  /// `GameStoppingEvent`: the game is stopping, and the plugin should save its data.
+  fn on_stopping(&self, _plugin: &Plugin) {}
+
+  /// `GameReloadEvent`: the plugin should reload its configuration.
+  fn on_reload(&self, _plugin: &Plugin) {}
+
+  /// The library is about to be unloaded, by a dev reload or because its class loader was collected. It is called
+  /// once, and only gets a `JNIEnv`, since the plugin may be gone.
+  fn on_unload(&self, _env: *mut JNIEnv) {}
+}
+
+lazy_static! {
+  static ref LIFECYCLE: Mutex<Option<Arc<dyn Lifecycle>>> = Mutex::new(None);
+  // the JavaVM pointer, valid on every thread, or 0 before JNI_OnLoad
+  static ref VM: Mutex<usize> = Mutex::new(0);
+}
+
+/// Calls `lifecycle` as the game changes states from now on, replacing any lifecycle registered before.
+pub fn register<T: Lifecycle>(lifecycle: T) {
+  *LIFECYCLE.lock().unwrap() = Some(Arc::new(lifecycle));
+}
+
+/// The `JavaVM` the library was loaded in, or null if it was not loaded by a JVM.
+pub fn vm() -> *mut JavaVM {
+  *VM.lock().unwrap() as *mut JavaVM
+}
+
+/// Calls `on_unload` on the registered lifecycle, unless it already was.
+pub fn unload(env: *mut JNIEnv) {
+  let lifecycle = LIFECYCLE.lock().unwrap().take();
+  if let Some(lifecycle) = lifecycle {
+    lifecycle.on_unload(env);
+  }
+}
+
+fn with_lifecycle<F: FnOnce(&dyn Lifecycle)>(f: F) {
+  // not locked while it is called, so it can register another lifecycle
+  let lifecycle = LIFECYCLE.lock().unwrap().clone();
+  if let Some(lifecycle) = lifecycle {
+    f(lifecycle.as_ref());
+  }
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn Java_me_kyleclemens_spongejni_SpongeJNIShim_lifecycle(env: *mut JNIEnv, this: jobject, state: jstring) {
+  let plugin = JavaUtils::get_plugin(env, this);
+  let state = state.into_rust_string(env);
+  with_lifecycle(|lifecycle| match state.as_str() {
+    "pre_init" => lifecycle.on_pre_init(&plugin),
+    "init" => lifecycle.on_init(&plugin),
+    "server_starting" => lifecycle.on_server_starting(&plugin),
+    "server_started" => lifecycle.on_server_started(&plugin),
+    "stopping" => {
+      lifecycle.on_stopping(&plugin);
+      save_data(&plugin);
+    },
+    "reload" => lifecycle.on_reload(&plugin),
+    _ => warn!("Ignoring the unknown game state {}", state)
+  });
+}
+
+/// Saves the persistent states and the player data of `plugin`, when the game stops and before a dev reload.
+pub fn save_data(plugin: &Plugin) {
+  ::state::save(plugin);
+  #[cfg(all(feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+  ::players::save_all();
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn JNI_OnLoad(vm: *mut JavaVM, _: *mut c_void) -> jint {
+  *VM.lock().unwrap() = vm as usize;
+  JNI_VERSION_1_8
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn JNI_OnUnload(vm: *mut JavaVM, _: *mut c_void) {
+  let mut env: *mut c_void = ptr::null_mut();
+  if unsafe { ((**vm).GetEnv)(vm, &mut env, JNI_VERSION_1_8) } == JNI_OK {
+    unload(env as *mut JNIEnv);
+  }
+  *VM.lock().unwrap() = 0;
+}
@@ -1,29 +1,25 @@
 use jni_sys::*;
-use std::ffi::CString;
 
-use plugin::Plugin;
-use generated_types::*;
-
-type CollectionValue = data_value_mutable_CollectionValue;
-type Event = event_Event;
-type Living = entity_living_Living;
-type Player = entity_living_player_Player;
-type TargetHumanoidEvent = event_entity_living_humanoid_TargetHumanoidEvent;
-type User = entity_living_player_User;
-type Value = data_value_mutable_Value;
+use plugin::{JavaUtils, Plugin};
+use proxy::Boxed;
+use sponge::data::value::mutable::{CollectionValue, Value};
+use sponge::entity::living::Living;
+use sponge::entity::living::player::{Player, User};
+use sponge::event::Event;
+use sponge::event::entity::living::humanoid::TargetHumanoidEvent;
 
 pub struct Listeners;
 
 impl Listeners {
   pub fn register(plugin: &Plugin) {
     let listeners = plugin.generate_listeners(
-      "me.kyleclemens.spongejni.rust.generated.RustyListener",
+      plugin.generated_class_name("RustyListener").as_str(),
       &[
-        "org.spongepowered.api.event.network.ClientConnectionEvent$Join",
-        "org.spongepowered.api.event.achievement.GrantAchievementEvent"
+        ("org.spongepowered.api.event.network.ClientConnectionEvent$Join", join_received),
+        ("org.spongepowered.api.event.achievement.GrantAchievementEvent", grant_achievement_event_received)
       ]
     );
-    plugin.get_game().get_event_manager().register_listeners(plugin.object, listeners);
+    plugin.register_listeners(listeners);
   }
 }
 
@@ -33,24 +29,18 @@ fn set_player_hearts(env: *mut JNIEnv, player: Player) {
   let new_hearts_count = (collection.size() as f64 * 0.5) + 10.0;
   let living = unsafe { Living::from(env, player.object) };
   let max_health = living.get_health_data().max_health();
-  let box_double = static_java_method!(env, "java/lang/Double", "valueOf", "(J)Ljava/lang/Double;", CallStaticObjectMethod, new_hearts_count);
-  unsafe { Value::from(env, max_health.object) }.set(box_double);
+  unsafe { Value::from(env, max_health.object) }.set(new_hearts_count.to_boxed(env));
 }
 
-#[allow(non_snake_case)]
-#[no_mangle]
-pub extern fn Java_me_kyleclemens_spongejni_rust_generated_RustyListener_joinReceived(env: *mut JNIEnv, _: jobject, event: jobject) {
+extern "C" fn join_received(env: *mut JNIEnv, _: jobject, event: jobject) {
   let event = unsafe { TargetHumanoidEvent::from(env, event) };
   let player = unsafe { Player::from(env, event.get_target_entity().object) };
   set_player_hearts(env, player);
 }
 
-#[allow(non_snake_case)]
-#[no_mangle]
-pub extern fn Java_me_kyleclemens_spongejni_rust_generated_RustyListener_grantAchievementEventReceived(env: *mut JNIEnv, _: jobject, event: jobject) {
+extern "C" fn grant_achievement_event_received(env: *mut JNIEnv, _: jobject, event: jobject) {
   let raw_event = unsafe { Event::from(env, event) };
-  let player_class_string = CString::new("org/spongepowered/api/entity/living/player/Player").unwrap();
-  let player_class = unsafe { ((**env).FindClass)(env, player_class_string.as_ptr()) };
+  let player_class = JavaUtils::load_class(env, "org/spongepowered/api/entity/living/player/Player");
   let player = match raw_event.get_cause().first(player_class) {
     Some(u) => unsafe { Player::from(env, u) },
     None => return
@@ -0,0 +1,213 @@
+//! Finds classes and method IDs the way the plugin sees them.
+//!
+//! Sponge loads plugins and SpongeAPI with its own class loader, so `FindClass` only finds SpongeAPI classes on threads
+//! whose context happens to use it. Once [`set_class_loader`](fn.set_class_loader.html) is given the plugin's class
+//! loader, classes are loaded through it with `Class.forName` instead.
+
+use jni_sys::{JNIEnv, jbyte, jclass, jmethodID, jobject, jsize};
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::sync::Mutex;
+
+use convert::ToJValue;
+use extensions::ConvertStringToJava;
+
+lazy_static! {
+  // a global reference, or 0 before the plugin is initialized
+  static ref CLASS_LOADER: Mutex<usize> = Mutex::new(0);
+  // global references to every class loaded so far, by internal name
+  static ref CLASSES: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+/// Loads classes through `loader` from now on. Classes that were already loaded are kept.
+pub fn set_class_loader(env: *mut JNIEnv, loader: jobject) {
+  let mut class_loader = CLASS_LOADER.lock().unwrap();
+  unsafe {
+    if *class_loader != 0 {
+      ((**env).DeleteGlobalRef)(env, *class_loader as jobject);
+    }
+    *class_loader = ((**env).NewGlobalRef)(env, loader) as usize;
+  }
+}
+
+/// Loads the class `class_name` (`org/spongepowered/api/Game` or `org.spongepowered.api.Game`), returning a global
+/// reference to it.
+///
+/// The reference is cached and shared by every caller, so it must not be deleted.
+pub fn load_class(env: *mut JNIEnv, class_name: &str) -> jclass {
+  let class_name = class_name.replace('.', "/");
+  if let Some(class) = cached(&class_name) {
+    return class;
+  }
+  let class = find_class(env, &class_name, class_loader());
+  cache(env, class_name, class)
+}
+
+/// Defines the class `class_name` in the plugin's class loader from the bytes of its class file, returning a global
+/// reference to it.
+///
+/// Like loaded classes, the reference is cached and must not be deleted. A class that was already defined or loaded is
+/// returned as it is, since a class loader can only define each name once.
+pub fn define_class(env: *mut JNIEnv, class_name: &str, bytes: &[u8]) -> jclass {
+  let class_name = class_name.replace('.', "/");
+  if let Some(class) = cached(&class_name) {
+    return class;
+  }
+  let loader = class_loader();
+  if loader.is_null() { panic!("no class loader to define {} in", class_name); }
+  let name = CString::new(class_name.as_str()).unwrap();
+  let class = unsafe {
+    let class = ((**env).DefineClass)(env, name.as_ptr(), loader, bytes.as_ptr() as *const jbyte, bytes.len() as jsize);
+    if class.is_null() {
+      // another thread defined it first, so the loader already has it
+      ((**env).ExceptionClear)(env);
+      find_class(env, &class_name, loader)
+    } else {
+      let global = ((**env).NewGlobalRef)(env, class);
+      ((**env).DeleteLocalRef)(env, class);
+      global
+    }
+  };
+  cache(env, class_name, class)
+}
+
+/// Deletes the class loader and every cached class, so that the classes defined by the library can be unloaded.
+///
+/// Method IDs cached by the binding macros still refer to the deleted classes, so nothing that uses them may be called
+/// afterwards.
+pub fn release(env: *mut JNIEnv) {
+  // taken one at a time, like everywhere else, and not held while calling into the JVM
+  let loader = ::std::mem::replace(&mut *CLASS_LOADER.lock().unwrap(), 0);
+  let classes: Vec<usize> = CLASSES.lock().unwrap().drain().map(|(_, class)| class).collect();
+  unsafe {
+    for class in classes {
+      ((**env).DeleteGlobalRef)(env, class as jobject);
+    }
+    if loader != 0 {
+      ((**env).DeleteGlobalRef)(env, loader as jobject);
+    }
+  }
+}
+
+// the class loader set by the plugin, or null
+fn class_loader() -> jobject {
+  *CLASS_LOADER.lock().unwrap() as jobject
+}
+
+fn cached(class_name: &str) -> Option<jclass> {
+  CLASSES.lock().unwrap().get(class_name).map(|class| *class as jclass)
+}
+
+// caches the global reference `class`, unless another thread cached the class first, whose reference is kept instead
+fn cache(env: *mut JNIEnv, class_name: String, class: jclass) -> jclass {
+  let mut classes = CLASSES.lock().unwrap();
+  if let Some(cached) = classes.get(&class_name) {
+    unsafe { ((**env).DeleteGlobalRef)(env, class) };
+    return *cached as jclass;
+  }
+  classes.insert(class_name, class as usize);
+  class
+}
+
+// no lock may be held while this runs: loading the class can run its static initializer, which may call back into the
+// library
+fn find_class(env: *mut JNIEnv, class_name: &str, loader: jobject) -> jclass {
+  unsafe {
+    let class = if loader.is_null() {
+      ((**env).FindClass)(env, CString::new(class_name).unwrap().as_ptr())
+    } else {
+      // java.lang.Class is visible to every class loader
+      let class_class = ((**env).FindClass)(env, CString::new("java/lang/Class").unwrap().as_ptr());
+      let for_name = ((**env).GetStaticMethodID)(
+        env,
+        class_class,
+        CString::new("forName").unwrap().as_ptr(),
+        CString::new("(Ljava/lang/String;ZLjava/lang/ClassLoader;)Ljava/lang/Class;").unwrap().as_ptr()
+      );
+      if for_name.is_null() { panic!("java/lang/Class#forName was null"); }
+      let name = class_name.replace('/', ".").as_str().into_java_string(env);
+      let args = [name.to_jvalue(), true.to_jvalue(), loader.to_jvalue()];
+      let class = ((**env).CallStaticObjectMethodA)(env, class_class, for_name, args.as_ptr());
+      ((**env).DeleteLocalRef)(env, name);
+      ((**env).DeleteLocalRef)(env, class_class);
+      class
+    };
+    if class.is_null() { panic!("class {} was null", class_name); }
+    let global = ((**env).NewGlobalRef)(env, class);
+    ((**env).DeleteLocalRef)(env, class);
+    global
+  }
+}
+
+/// A method of a bound class, whose class and ID are looked up the first time it is called and then reused.
+///
+/// The binding macros declare one of these for every call site, resolving the method on the class declaring it rather
+/// than on the class of the object it is called on.
+pub struct BoundMethod {
+  class_name: &'static str,
+  name: &'static str,
+  descriptor: &'static str,
+  is_static: bool,
+  // jclass and jmethodID are not Send; the class is a global reference and method IDs are valid on any thread
+  ids: Mutex<Option<(usize, usize)>>
+}
+
+impl BoundMethod {
+  pub const fn new(class_name: &'static str, name: &'static str, descriptor: &'static str, is_static: bool) -> Self {
+    BoundMethod {
+      class_name,
+      name,
+      descriptor,
+      is_static,
+      ids: Mutex::new(None)
+    }
+  }
+
+  /// The declaring class, as a global reference, and the ID of the method.
+  pub fn get(&self, env: *mut JNIEnv) -> (jclass, jmethodID) {
+    if let Some((class, method_id)) = *self.ids.lock().unwrap() {
+      return (class as jclass, method_id as jmethodID);
+    }
+    // not locked while looking up, which can call back into the library; threads racing here find the same IDs
+    let class = load_class(env, self.class_name);
+    let name = CString::new(self.name).unwrap();
+    let descriptor = CString::new(self.descriptor).unwrap();
+    let method_id = unsafe {
+      if self.is_static {
+        ((**env).GetStaticMethodID)(env, class, name.as_ptr(), descriptor.as_ptr())
+      } else {
+        ((**env).GetMethodID)(env, class, name.as_ptr(), descriptor.as_ptr())
+      }
+    };
+    if method_id.is_null() { panic!("{}#{}{} was null", self.class_name, self.name, self.descriptor); }
+    *self.ids.lock().unwrap() = Some((class as usize, method_id as usize));
+    (class, method_id)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use jni_sys::{JNIEnv, jobject};
+
+  use mock::MockEnv;
+
+  fn name(env: *mut JNIEnv, object: jobject) -> jobject {
+    bound_method!(env, "com/example/Named", object, "getName", "()Ljava/lang/String;", CallObjectMethod)
+  }
+
+  #[test]
+  fn resolves_on_the_declaring_class() {
+    // the method ID is cached by the first mock and reused by the second
+    for player_name in &["kyle", "Notch"] {
+      let mock = MockEnv::new();
+      mock.class("com/example/Player", &["com/example/Named"]);
+      mock.returns("com/example/Named", "getName", "()Ljava/lang/String;", mock.string(player_name));
+      let player = mock.object("com/example/Player");
+      assert_eq!(mock.string_value(name(mock.env(), player)), Some(player_name.to_string()));
+      let calls = mock.calls();
+      assert_eq!(calls.len(), 1);
+      assert_eq!(calls[0].class, "com/example/Named");
+      assert_eq!(calls[0].this, player);
+    }
+  }
+}
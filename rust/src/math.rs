@@ -0,0 +1,347 @@
+//! Rust counterparts of the `com.flowpowered.math` types used for positions, rotations and velocities.
+//!
+//! The bindings convert to and from these where `bindings.toml` maps the Java classes to them, so they can be used like
+//! any other Rust value afterwards. Vectors are read with a single call to their `toArray` and one copy out of the
+//! array, rather than a call per component.
+
+use jni_sys::{JNIEnv, jdouble, jint, jsize, jvalue, jobject};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use convert::{FromJava, ToJava};
+
+/// Implements component-wise arithmetic, and scaling by `$scalar`, for a vector type.
+macro_rules! vector_ops {
+  ($name:ident, $scalar:ty, $($field:ident),+) => {
+    impl Add for $name {
+      type Output = $name;
+
+      fn add(self, other: $name) -> $name {
+        $name { $($field: self.$field + other.$field),+ }
+      }
+    }
+
+    impl Sub for $name {
+      type Output = $name;
+
+      fn sub(self, other: $name) -> $name {
+        $name { $($field: self.$field - other.$field),+ }
+      }
+    }
+
+    impl Mul<$scalar> for $name {
+      type Output = $name;
+
+      fn mul(self, scalar: $scalar) -> $name {
+        $name { $($field: self.$field * scalar),+ }
+      }
+    }
+
+    impl Div<$scalar> for $name {
+      type Output = $name;
+
+      fn div(self, scalar: $scalar) -> $name {
+        $name { $($field: self.$field / scalar),+ }
+      }
+    }
+
+    impl Neg for $name {
+      type Output = $name;
+
+      fn neg(self) -> $name {
+        $name { $($field: -self.$field),+ }
+      }
+    }
+
+    impl $name {
+      pub fn dot(&self, other: $name) -> $scalar {
+        let mut sum = Default::default();
+        $(sum += self.$field * other.$field;)+
+        sum
+      }
+
+      pub fn length_squared(&self) -> $scalar {
+        self.dot(*self)
+      }
+    }
+  }
+}
+
+/// `com.flowpowered.math.vector.Vector2i`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector2i {
+  pub x: i32,
+  pub y: i32
+}
+
+/// `com.flowpowered.math.vector.Vector3i`, used for block positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector3i {
+  pub x: i32,
+  pub y: i32,
+  pub z: i32
+}
+
+/// `com.flowpowered.math.vector.Vector3d`, used for entity positions, rotations and velocities.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3d {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64
+}
+
+/// `com.flowpowered.math.imaginary.Quaterniond`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaterniond {
+  pub x: f64,
+  pub y: f64,
+  pub z: f64,
+  pub w: f64
+}
+
+vector_ops!(Vector2i, i32, x, y);
+vector_ops!(Vector3i, i32, x, y, z);
+vector_ops!(Vector3d, f64, x, y, z);
+
+impl Vector2i {
+  pub fn new(x: i32, y: i32) -> Self {
+    Vector2i { x, y }
+  }
+}
+
+impl Vector3i {
+  pub fn new(x: i32, y: i32, z: i32) -> Self {
+    Vector3i { x, y, z }
+  }
+
+  pub fn to_double(&self) -> Vector3d {
+    Vector3d::new(self.x as f64, self.y as f64, self.z as f64)
+  }
+}
+
+impl Vector3d {
+  pub fn new(x: f64, y: f64, z: f64) -> Self {
+    Vector3d { x, y, z }
+  }
+
+  pub fn length(&self) -> f64 {
+    self.length_squared().sqrt()
+  }
+
+  pub fn normalize(&self) -> Vector3d {
+    *self / self.length()
+  }
+
+  pub fn cross(&self, other: Vector3d) -> Vector3d {
+    Vector3d::new(
+      self.y * other.z - self.z * other.y,
+      self.z * other.x - self.x * other.z,
+      self.x * other.y - self.y * other.x
+    )
+  }
+
+  /// The position of the block containing this point.
+  pub fn to_block(&self) -> Vector3i {
+    Vector3i::new(self.x.floor() as i32, self.y.floor() as i32, self.z.floor() as i32)
+  }
+}
+
+impl Default for Quaterniond {
+  fn default() -> Self {
+    Quaterniond::IDENTITY
+  }
+}
+
+impl Quaterniond {
+  pub const IDENTITY: Quaterniond = Quaterniond { x: 0.0, y: 0.0, z: 0.0, w: 1.0 };
+
+  pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+    Quaterniond { x, y, z, w }
+  }
+
+  /// The rotation of `angle` degrees around `axis`.
+  pub fn from_angle_deg_axis(angle: f64, axis: Vector3d) -> Self {
+    let half = angle.to_radians() / 2.0;
+    let axis = axis.normalize() * half.sin();
+    Quaterniond::new(axis.x, axis.y, axis.z, half.cos())
+  }
+
+  pub fn conjugate(&self) -> Quaterniond {
+    Quaterniond::new(-self.x, -self.y, -self.z, self.w)
+  }
+
+  pub fn length(&self) -> f64 {
+    (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
+  }
+
+  pub fn normalize(&self) -> Quaterniond {
+    let length = self.length();
+    Quaterniond::new(self.x / length, self.y / length, self.z / length, self.w / length)
+  }
+
+  /// Rotates `vector` by this quaternion, which should be normalized.
+  pub fn rotate(&self, vector: Vector3d) -> Vector3d {
+    let rotated = *self * Quaterniond::new(vector.x, vector.y, vector.z, 0.0) * self.conjugate();
+    Vector3d::new(rotated.x, rotated.y, rotated.z)
+  }
+}
+
+impl Mul for Quaterniond {
+  type Output = Quaterniond;
+
+  fn mul(self, other: Quaterniond) -> Quaterniond {
+    Quaterniond::new(
+      self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+      self.w * other.y + self.y * other.w + self.z * other.x - self.x * other.z,
+      self.w * other.z + self.z * other.w + self.x * other.y - self.y * other.x,
+      self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z
+    )
+  }
+}
+
+// copies the components out of the array returned by a vector's toArray, which is then deleted
+fn int_components<const N: usize>(env: *mut JNIEnv, array: jobject) -> [jint; N] {
+  let mut components = [0; N];
+  unsafe {
+    ((**env).GetIntArrayRegion)(env, array, 0, N as jsize, components.as_mut_ptr());
+    ((**env).DeleteLocalRef)(env, array);
+  }
+  components
+}
+
+fn double_components<const N: usize>(env: *mut JNIEnv, array: jobject) -> [jdouble; N] {
+  let mut components = [0.0; N];
+  unsafe {
+    ((**env).GetDoubleArrayRegion)(env, array, 0, N as jsize, components.as_mut_ptr());
+    ((**env).DeleteLocalRef)(env, array);
+  }
+  components
+}
+
+impl FromJava for Vector2i {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    let array = bound_method!(env, "com/flowpowered/math/vector/Vector2i", object, "toArray", "()[I", CallObjectMethod);
+    let [x, y] = int_components(env, array);
+    Vector2i { x, y }
+  }
+}
+
+impl ToJava for Vector2i {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    new_object!(env, "com/flowpowered/math/vector/Vector2i", "(II)V", self.x, self.y)
+  }
+}
+
+impl FromJava for Vector3i {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    let array = bound_method!(env, "com/flowpowered/math/vector/Vector3i", object, "toArray", "()[I", CallObjectMethod);
+    let [x, y, z] = int_components(env, array);
+    Vector3i { x, y, z }
+  }
+}
+
+impl ToJava for Vector3i {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    new_object!(env, "com/flowpowered/math/vector/Vector3i", "(III)V", self.x, self.y, self.z)
+  }
+}
+
+impl FromJava for Vector3d {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    let array = bound_method!(env, "com/flowpowered/math/vector/Vector3d", object, "toArray", "()[D", CallObjectMethod);
+    let [x, y, z] = double_components(env, array);
+    Vector3d { x, y, z }
+  }
+}
+
+impl ToJava for Vector3d {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    new_object!(env, "com/flowpowered/math/vector/Vector3d", "(DDD)V", self.x, self.y, self.z)
+  }
+}
+
+/// Quaternions have no `toArray`, so their components are read one call each.
+impl FromJava for Quaterniond {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    Quaterniond {
+      x: bound_method!(env, "com/flowpowered/math/imaginary/Quaterniond", object, "getX", "()D", CallDoubleMethod),
+      y: bound_method!(env, "com/flowpowered/math/imaginary/Quaterniond", object, "getY", "()D", CallDoubleMethod),
+      z: bound_method!(env, "com/flowpowered/math/imaginary/Quaterniond", object, "getZ", "()D", CallDoubleMethod),
+      w: bound_method!(env, "com/flowpowered/math/imaginary/Quaterniond", object, "getW", "()D", CallDoubleMethod)
+    }
+  }
+}
+
+impl ToJava for Quaterniond {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    new_object!(env, "com/flowpowered/math/imaginary/Quaterniond", "(DDDD)V", self.x, self.y, self.z, self.w)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use convert::{FromJava, ToJValue};
+  use mock::MockEnv;
+  use super::{Quaterniond, Vector2i, Vector3d, Vector3i};
+
+  fn assert_close(actual: Vector3d, expected: Vector3d) {
+    assert!((actual - expected).length() < 1e-9, "{:?} was not {:?}", actual, expected);
+  }
+
+  #[test]
+  fn vectors_are_added_and_scaled_by_component() {
+    let a = Vector3i::new(1, 2, 3);
+    let b = Vector3i::new(4, -5, 6);
+    assert_eq!(a + b, Vector3i::new(5, -3, 9));
+    assert_eq!(a - b, Vector3i::new(-3, 7, -3));
+    assert_eq!(a * 2, Vector3i::new(2, 4, 6));
+    assert_eq!(b / 2, Vector3i::new(2, -2, 3));
+    assert_eq!(-a, Vector3i::new(-1, -2, -3));
+    assert_eq!(a.dot(b), 12);
+    assert_eq!(Vector2i::new(3, 4).length_squared(), 25);
+    assert_eq!(Vector3d::new(0.0, 3.0, 4.0).length(), 5.0);
+    assert_eq!(Vector3d::new(0.0, 0.0, 2.0).normalize(), Vector3d::new(0.0, 0.0, 1.0));
+  }
+
+  #[test]
+  fn cross_follows_the_right_hand_rule() {
+    let x = Vector3d::new(1.0, 0.0, 0.0);
+    let y = Vector3d::new(0.0, 1.0, 0.0);
+    assert_eq!(x.cross(y), Vector3d::new(0.0, 0.0, 1.0));
+    assert_eq!(y.cross(x), Vector3d::new(0.0, 0.0, -1.0));
+    assert_eq!(x.cross(x), Vector3d::default());
+  }
+
+  #[test]
+  fn points_round_down_to_their_block() {
+    assert_eq!(Vector3d::new(1.5, 64.0, -0.5).to_block(), Vector3i::new(1, 64, -1));
+    assert_eq!(Vector3d::new(-1.0, -0.01, 0.99).to_block(), Vector3i::new(-1, -1, 0));
+    assert_eq!(Vector3i::new(2, -3, 4).to_double(), Vector3d::new(2.0, -3.0, 4.0));
+  }
+
+  #[test]
+  fn quaternions_compose_rotations() {
+    let up = Vector3d::new(0.0, 1.0, 0.0);
+    let quarter = Quaterniond::from_angle_deg_axis(90.0, up);
+    assert_close(quarter.rotate(Vector3d::new(1.0, 0.0, 0.0)), Vector3d::new(0.0, 0.0, -1.0));
+    // two quarter turns make a half turn
+    let half = quarter * quarter;
+    assert_close(half.rotate(Vector3d::new(1.0, 0.0, 0.0)), Vector3d::new(-1.0, 0.0, 0.0));
+    assert_eq!(Quaterniond::IDENTITY * quarter, quarter);
+    assert_close(half.conjugate().rotate(half.rotate(up * 2.0)), up * 2.0);
+    assert!((Quaterniond::new(1.0, 1.0, 1.0, 1.0).normalize().length() - 1.0).abs() < 1e-12);
+  }
+
+  #[test]
+  fn vectors_are_read_from_java_in_one_call() {
+    let mock = MockEnv::new();
+    let env = mock.env();
+    mock.on("com/flowpowered/math/vector/Vector3i", "toArray", "()[I", |mock, _, _| mock.primitive_array("[I", &[1, -2, 3]).to_jvalue());
+    mock.on("com/flowpowered/math/vector/Vector3d", "toArray", "()[D", |mock, _, _| mock.primitive_array("[D", &[0.5, 64.0, -1.25]).to_jvalue());
+
+    let position = Vector3i::from_java(env, mock.object("com/flowpowered/math/vector/Vector3i"));
+    let velocity = Vector3d::from_java(env, mock.object("com/flowpowered/math/vector/Vector3d"));
+    assert_eq!(position, Vector3i::new(1, -2, 3));
+    assert_eq!(velocity, Vector3d::new(0.5, 64.0, -1.25));
+    assert_eq!(mock.calls().len(), 2);
+  }
+}
@@ -0,0 +1,633 @@
+//! A fake `JNIEnv` for testing plugin code with `cargo test`, without a JVM.
+//!
+//! [`MockEnv`](struct.MockEnv.html) implements the JNI functions the binding macros call on top of an in-memory object
+//! model: objects have a class name and fields, classes declare the classes and interfaces they extend, and methods run
+//! handlers registered by the test. Methods without a handler return `null`, `0` or `false`. Every call is recorded, so
+//! tests can check what the code under test did afterwards.
+//!
+//! The module is only built for this crate's tests and with the `mock` feature, which plugins enable in their
+//! `[dev-dependencies]`.
+//!
+//! ```
+//! # #[macro_use] extern crate spongejni;
+//! # extern crate jni_sys;
+//! # use jni_sys::{jvalue, jobject, jclass, jmethodID};
+//! # use std::ffi::CString;
+//! use spongejni::convert::ToJValue;
+//! use spongejni::mock::MockEnv;
+//!
+//! # fn main() {
+//! let mock = MockEnv::new();
+//! let env = mock.env();
+//! mock.class("com/example/Player", &["com/example/Named"]);
+//! mock.on("com/example/Named", "getName", "()Ljava/lang/String;", |mock, _, _| mock.string("kyle").to_jvalue());
+//!
+//! let player = mock.object("com/example/Player");
+//! let name = java_method!(env, player, "getName", "()Ljava/lang/String;", CallObjectMethod);
+//! assert_eq!(mock.string_value(name), Some("kyle".to_owned()));
+//! assert_eq!(mock.calls()[0].name, "getName");
+//! # }
+//! ```
+//!
+//! Handlers run inside `extern "C"` functions, which abort rather than unwind if they panic; make assertions on the
+//! recorded calls instead. JNI functions the mock does not implement abort as well.
+//!
+//! The binding macros cache classes and method IDs for the life of the process, so these are the same in every mock:
+//! a class or ID looked up through one mock can be used with any other.
+
+use jni_sys::{JNIEnv, JNINativeInterface_, JNI_FALSE, JNI_TRUE, jboolean, jbyte, jchar, jclass, jdouble, jfieldID,
+  jfloat, jint, jlong, jmethodID, jobject, jshort, jsize, jstring, jvalue};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::os::raw::c_char;
+use std::process;
+use std::ptr;
+use std::rc::Rc;
+use std::sync::Mutex;
+
+use convert::{self, FromJValue, ToJValue};
+
+type Handler = dyn Fn(&MockEnv, jobject, &[jvalue]) -> jvalue;
+
+/// A method call made through the mock.
+#[derive(Clone)]
+pub struct Call {
+  /// The class the method was looked up on.
+  pub class: String,
+  pub name: String,
+  pub descriptor: String,
+  /// The object the method was called on, or the class for static methods and constructors.
+  pub this: jobject,
+  /// The arguments, which are only known for the `A` variants of the call functions.
+  pub args: Vec<jvalue>
+}
+
+impl Call {
+  /// The argument at `index`, read as a `T`.
+  pub fn arg<T: FromJValue>(&self, index: usize) -> T {
+    T::from_jvalue(self.args[index])
+  }
+}
+
+enum Value {
+  Plain,
+  String(CString),
+  Array(Vec<jobject>),
+  // the elements of an array of a primitive type
+  Primitives(Vec<jvalue>)
+}
+
+struct Object {
+  class: String,
+  value: Value,
+  fields: HashMap<String, jvalue>
+}
+
+#[derive(Default)]
+struct State {
+  // an object's handle is its index plus one, so that no object is null
+  objects: Vec<Object>,
+  static_fields: HashMap<String, HashMap<String, jvalue>>,
+  supertypes: HashMap<String, Vec<String>>,
+  handlers: HashMap<(String, String, String), Rc<Handler>>,
+  calls: Vec<Call>,
+  exception: Option<(String, String)>
+}
+
+/// The classes, methods and fields of every mock, whose handles and IDs are their index plus one.
+#[derive(Default)]
+struct Ids {
+  classes: Vec<String>,
+  methods: Vec<(String, String, String)>,
+  fields: Vec<(String, String)>
+}
+
+lazy_static! {
+  static ref IDS: Mutex<Ids> = Mutex::new(Ids::default());
+}
+
+// class handles have the top bit set, so they never clash with the objects of a mock
+const CLASS_TAG: usize = !(usize::MAX >> 1);
+
+/// A `JNIEnv` backed by an in-memory object model instead of a JVM.
+///
+/// It is boxed so that the pointer returned by [`env`](#method.env) stays valid for as long as the mock lives.
+#[repr(C)]
+pub struct MockEnv {
+  // a JNIEnv points to a pointer to the function table, so this has to come first
+  functions: *const JNINativeInterface_,
+  table: Box<JNINativeInterface_>,
+  state: RefCell<State>
+}
+
+impl MockEnv {
+  pub fn new() -> Box<MockEnv> {
+    let table = Box::new(function_table());
+    let mock = Box::new(MockEnv {
+      functions: &*table,
+      table,
+      state: RefCell::new(State::default())
+    });
+    mock.on("java/lang/Class", "getName", "()Ljava/lang/String;", |mock, this, _| {
+      let name = mock.class_object_name(this).expect("not a class").replace('/', ".");
+      mock.string(&name).to_jvalue()
+    });
+    mock
+  }
+
+  /// The `JNIEnv` to pass to the code under test.
+  pub fn env(&self) -> *mut JNIEnv {
+    self as *const MockEnv as *mut JNIEnv
+  }
+
+  /// Declares the classes and interfaces that `class_name` extends, whose handlers its objects use too.
+  pub fn class(&self, class_name: &str, supertypes: &[&str]) {
+    self.state.borrow_mut().supertypes.insert(class_name.to_owned(), supertypes.iter().map(|x| x.to_string()).collect());
+  }
+
+  /// Creates an object of `class_name`.
+  pub fn object(&self, class_name: &str) -> jobject {
+    self.add_object(class_name, Value::Plain)
+  }
+
+  /// Creates a `java.lang.String`.
+  pub fn string(&self, string: &str) -> jstring {
+    self.add_object("java/lang/String", Value::String(CString::new(string).expect("string contained a nul byte")))
+  }
+
+  /// The contents of `object`, if it is a string.
+  pub fn string_value(&self, object: jobject) -> Option<String> {
+    if is_class(object) {
+      return None;
+    }
+    match self.state.borrow().object(object).value {
+      Value::String(ref string) => Some(string.to_string_lossy().into_owned()),
+      _ => None
+    }
+  }
+
+  /// The elements of `object`, if it is an array.
+  pub fn array_elements(&self, object: jobject) -> Option<Vec<jobject>> {
+    if is_class(object) {
+      return None;
+    }
+    match self.state.borrow().object(object).value {
+      Value::Array(ref elements) => Some(elements.clone()),
+      _ => None
+    }
+  }
+
+  /// Creates an array of a primitive type, such as `[I` for an `int[]`, holding `values`.
+  pub fn primitive_array<T: ToJValue>(&self, class_name: &str, values: &[T]) -> jobject {
+    self.add_object(class_name, Value::Primitives(values.iter().map(|x| x.to_jvalue()).collect()))
+  }
+
+  /// The name of the class of `object`.
+  pub fn class_name(&self, object: jobject) -> String {
+    if is_class(object) {
+      return "java/lang/Class".to_owned();
+    }
+    self.state.borrow().object(object).class.clone()
+  }
+
+  /// Sets the field `name` of `object`, read by `Get<Type>Field`.
+  pub fn set_field<T: ToJValue>(&self, object: jobject, name: &str, value: T) {
+    self.state.borrow_mut().object_mut(object).fields.insert(name.to_owned(), value.to_jvalue());
+  }
+
+  /// The field `name` of `object`, as set by [`set_field`](#method.set_field), or `0` if it was never set.
+  pub fn field(&self, object: jobject, name: &str) -> jvalue {
+    self.state.borrow().object(object).fields.get(name).cloned().unwrap_or(jvalue::default())
+  }
+
+  /// Sets the static field `name` of `class_name`, read by `GetStatic<Type>Field`.
+  pub fn set_static_field<T: ToJValue>(&self, class_name: &str, name: &str, value: T) {
+    self.state.borrow_mut().static_fields.entry(class_name.to_owned()).or_default().insert(name.to_owned(), value.to_jvalue());
+  }
+
+  /// Runs `handler` with the mock, the object (or class, for static methods) and the arguments whenever the method
+  /// `name` with `descriptor` is called on `class_name` or a class extending it. Constructors are named `<init>`.
+  pub fn on<F>(&self, class_name: &str, name: &str, descriptor: &str, handler: F)
+    where F: Fn(&MockEnv, jobject, &[jvalue]) -> jvalue + 'static {
+    let key = (class_name.to_owned(), name.to_owned(), descriptor.to_owned());
+    self.state.borrow_mut().handlers.insert(key, Rc::new(handler));
+  }
+
+  /// Makes the method `name` with `descriptor` on `class_name` return `value`.
+  pub fn returns<T: ToJValue>(&self, class_name: &str, name: &str, descriptor: &str, value: T) {
+    let value = value.to_jvalue();
+    self.on(class_name, name, descriptor, move |_, _, _| value);
+  }
+
+  /// Every method call made so far, in order.
+  pub fn calls(&self) -> Vec<Call> {
+    self.state.borrow().calls.clone()
+  }
+
+  /// The class and message of the exception thrown with `ThrowNew`, if it has not been cleared.
+  pub fn exception(&self) -> Option<(String, String)> {
+    self.state.borrow().exception.clone()
+  }
+
+  fn add_object(&self, class_name: &str, value: Value) -> jobject {
+    let mut state = self.state.borrow_mut();
+    state.objects.push(Object {
+      class: class_name.to_owned(),
+      value,
+      fields: HashMap::new()
+    });
+    state.objects.len() as jobject
+  }
+
+  fn class_object(&self, class_name: &str) -> jclass {
+    let mut ids = IDS.lock().unwrap();
+    let index = match ids.classes.iter().position(|x| x == class_name) {
+      Some(index) => index,
+      None => {
+        ids.classes.push(class_name.to_owned());
+        ids.classes.len() - 1
+      }
+    };
+    ((index + 1) | CLASS_TAG) as jclass
+  }
+
+  fn class_object_name(&self, class: jclass) -> Option<String> {
+    if !is_class(class) {
+      return None;
+    }
+    IDS.lock().unwrap().classes.get(((class as usize) & !CLASS_TAG) - 1).cloned()
+  }
+
+  fn call(&self, this: jobject, method_id: jmethodID, args: &[jvalue]) -> jvalue {
+    let handler = {
+      let mut state = self.state.borrow_mut();
+      let (class, name, descriptor) = IDS.lock().unwrap().methods[method_id as usize - 1].clone();
+      let handler = state.handler(&class, &name, &descriptor);
+      state.calls.push(Call { class, name, descriptor, this, args: args.to_vec() });
+      handler
+    };
+    match handler {
+      Some(handler) => handler(self, this, args),
+      None => jvalue::default()
+    }
+  }
+
+  /// The arguments at `args` for the method `method_id`, which JNI passes without their count.
+  fn args(&self, method_id: jmethodID, args: *const jvalue) -> Vec<jvalue> {
+    let count = convert::parameter_count(&IDS.lock().unwrap().methods[method_id as usize - 1].2);
+    if args.is_null() || count == 0 {
+      Vec::new()
+    } else {
+      unsafe { ::std::slice::from_raw_parts(args, count) }.to_vec()
+    }
+  }
+}
+
+impl State {
+  fn object(&self, object: jobject) -> &Object {
+    self.objects.get((object as usize).wrapping_sub(1)).expect("not an object of this mock")
+  }
+
+  fn object_mut(&mut self, object: jobject) -> &mut Object {
+    self.objects.get_mut((object as usize).wrapping_sub(1)).expect("not an object of this mock")
+  }
+
+  fn handler(&self, class: &str, name: &str, descriptor: &str) -> Option<Rc<Handler>> {
+    let key = (class.to_owned(), name.to_owned(), descriptor.to_owned());
+    if let Some(handler) = self.handlers.get(&key) {
+      return Some(handler.clone());
+    }
+    self.supertypes.get(class)
+      .and_then(|supertypes| supertypes.iter().filter_map(|x| self.handler(x, name, descriptor)).next())
+  }
+
+  fn is_subtype(&self, class: &str, supertype: &str) -> bool {
+    class == supertype || self.supertypes.get(class)
+      .is_some_and(|supertypes| supertypes.iter().any(|x| self.is_subtype(x, supertype)))
+  }
+
+  fn field(&self, object: jobject, field_id: jfieldID) -> jvalue {
+    let (class, name) = IDS.lock().unwrap().fields[field_id as usize - 1].clone();
+    let fields = if is_class(object) {
+      self.static_fields.get(&class)
+    } else {
+      Some(&self.object(object).fields)
+    };
+    fields.and_then(|x| x.get(&name)).cloned().unwrap_or(jvalue::default())
+  }
+}
+
+impl Ids {
+  fn method_id(&mut self, class: String, name: String, descriptor: String) -> jmethodID {
+    let method = (class, name, descriptor);
+    let index = match self.methods.iter().position(|x| *x == method) {
+      Some(index) => index,
+      None => {
+        self.methods.push(method);
+        self.methods.len() - 1
+      }
+    };
+    (index + 1) as jmethodID
+  }
+
+  fn field_id(&mut self, class: String, name: String) -> jfieldID {
+    let field = (class, name);
+    let index = match self.fields.iter().position(|x| *x == field) {
+      Some(index) => index,
+      None => {
+        self.fields.push(field);
+        self.fields.len() - 1
+      }
+    };
+    (index + 1) as jfieldID
+  }
+}
+
+fn is_class(object: jobject) -> bool {
+  object as usize & CLASS_TAG != 0
+}
+
+fn mock<'a>(env: *mut JNIEnv) -> &'a MockEnv {
+  unsafe { &*(env as *const MockEnv) }
+}
+
+fn string(name: *const c_char) -> String {
+  unsafe { CStr::from_ptr(name) }.to_str().expect("name was not valid unicode").to_owned()
+}
+
+unsafe extern "C" fn unsupported() {
+  eprintln!("a JNI function the mock does not implement was called");
+  process::abort();
+}
+
+const SLOTS: usize = mem::size_of::<JNINativeInterface_>() / mem::size_of::<usize>();
+
+/// Fills in the `Call<Type>Method`, `CallNonvirtual<Type>MethodA`, `Get<Type>Field` and `GetStatic<Type>Field`
+/// functions for each type.
+macro_rules! typed_functions {
+  ($table:ident, $($t:ty => $call:ident, $call_a:ident, $call_nonvirtual_a:ident, $call_static:ident, $call_static_a:ident, $get:ident, $get_static:ident);+) => {
+    $(
+      $table.$call = {
+        unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, method_id: jmethodID) -> $t {
+          FromJValue::from_jvalue(mock(env).call(object, method_id, &[]))
+        }
+        mem::transmute::<unsafe extern "C" fn(*mut JNIEnv, jobject, jmethodID) -> $t, unsafe extern "C" fn(*mut JNIEnv, jobject, jmethodID, ...) -> $t>(call)
+      };
+      $table.$call_a = {
+        unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, method_id: jmethodID, args: *const jvalue) -> $t {
+          let args = mock(env).args(method_id, args);
+          FromJValue::from_jvalue(mock(env).call(object, method_id, &args))
+        }
+        call
+      };
+      $table.$call_nonvirtual_a = {
+        unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, _: jclass, method_id: jmethodID, args: *const jvalue) -> $t {
+          let args = mock(env).args(method_id, args);
+          FromJValue::from_jvalue(mock(env).call(object, method_id, &args))
+        }
+        call
+      };
+      $table.$call_static = {
+        unsafe extern "C" fn call(env: *mut JNIEnv, class: jclass, method_id: jmethodID) -> $t {
+          FromJValue::from_jvalue(mock(env).call(class, method_id, &[]))
+        }
+        mem::transmute::<unsafe extern "C" fn(*mut JNIEnv, jclass, jmethodID) -> $t, unsafe extern "C" fn(*mut JNIEnv, jclass, jmethodID, ...) -> $t>(call)
+      };
+      $table.$call_static_a = {
+        unsafe extern "C" fn call(env: *mut JNIEnv, class: jclass, method_id: jmethodID, args: *const jvalue) -> $t {
+          let args = mock(env).args(method_id, args);
+          FromJValue::from_jvalue(mock(env).call(class, method_id, &args))
+        }
+        call
+      };
+      $table.$get = {
+        unsafe extern "C" fn get(env: *mut JNIEnv, object: jobject, field_id: jfieldID) -> $t {
+          FromJValue::from_jvalue(mock(env).state.borrow().field(object, field_id))
+        }
+        get
+      };
+      $table.$get_static = {
+        unsafe extern "C" fn get(env: *mut JNIEnv, class: jclass, field_id: jfieldID) -> $t {
+          FromJValue::from_jvalue(mock(env).state.borrow().field(class, field_id))
+        }
+        get
+      };
+    )+
+  }
+}
+
+/// Fills in the `Get<Type>ArrayRegion` functions for each primitive type.
+macro_rules! primitive_array_functions {
+  ($table:ident, $($t:ty => $get_region:ident);+) => {
+    $(
+      $table.$get_region = {
+        unsafe extern "C" fn get(env: *mut JNIEnv, array: jobject, start: jsize, length: jsize, buffer: *mut $t) {
+          let elements = match mock(env).state.borrow().object(array).value {
+            Value::Primitives(ref elements) => elements[start as usize..(start + length) as usize].to_vec(),
+            _ => panic!("not an array of a primitive type")
+          };
+          for (i, element) in elements.into_iter().enumerate() {
+            *buffer.add(i) = FromJValue::from_jvalue(element);
+          }
+        }
+        get
+      };
+    )+
+  }
+}
+
+fn function_table() -> JNINativeInterface_ {
+  // every function aborts until it is replaced below; the unsupported ones never read their arguments
+  let mut table: JNINativeInterface_ = unsafe { mem::transmute([unsupported as *const () as usize; SLOTS]) };
+  table.reserved0 = ptr::null_mut();
+  table.reserved1 = ptr::null_mut();
+  table.reserved2 = ptr::null_mut();
+  table.reserved3 = ptr::null_mut();
+
+  unsafe extern "C" fn find_class(env: *mut JNIEnv, name: *const c_char) -> jclass {
+    mock(env).class_object(&string(name))
+  }
+  table.FindClass = find_class;
+
+  unsafe extern "C" fn get_object_class(env: *mut JNIEnv, object: jobject) -> jclass {
+    let mock = mock(env);
+    mock.class_object(&mock.class_name(object))
+  }
+  table.GetObjectClass = get_object_class;
+
+  unsafe extern "C" fn get_method_id(env: *mut JNIEnv, class: jclass, name: *const c_char, sig: *const c_char) -> jmethodID {
+    let mock = mock(env);
+    let class = mock.class_object_name(class).expect("not a class");
+    IDS.lock().unwrap().method_id(class, string(name), string(sig))
+  }
+  table.GetMethodID = get_method_id;
+  table.GetStaticMethodID = get_method_id;
+
+  unsafe extern "C" fn get_field_id(env: *mut JNIEnv, class: jclass, name: *const c_char, _: *const c_char) -> jfieldID {
+    let mock = mock(env);
+    let class = mock.class_object_name(class).expect("not a class");
+    IDS.lock().unwrap().field_id(class, string(name))
+  }
+  table.GetFieldID = get_field_id;
+  table.GetStaticFieldID = get_field_id;
+
+  unsafe extern "C" fn new_object_a(env: *mut JNIEnv, class: jclass, method_id: jmethodID, args: *const jvalue) -> jobject {
+    let mock = mock(env);
+    let object = mock.object(&mock.class_object_name(class).expect("not a class"));
+    let args = mock.args(method_id, args);
+    mock.call(object, method_id, &args);
+    object
+  }
+  table.NewObjectA = new_object_a;
+
+  unsafe extern "C" fn new_ref(_: *mut JNIEnv, object: jobject) -> jobject {
+    object
+  }
+  table.NewGlobalRef = new_ref;
+  table.NewLocalRef = new_ref;
+
+  unsafe extern "C" fn delete_ref(_: *mut JNIEnv, _: jobject) {}
+  table.DeleteGlobalRef = delete_ref;
+  table.DeleteLocalRef = delete_ref;
+
+  unsafe extern "C" fn is_same_object(_: *mut JNIEnv, first: jobject, second: jobject) -> jboolean {
+    if first == second { JNI_TRUE } else { JNI_FALSE }
+  }
+  table.IsSameObject = is_same_object;
+
+  unsafe extern "C" fn is_instance_of(env: *mut JNIEnv, object: jobject, class: jclass) -> jboolean {
+    let mock = mock(env);
+    let class = mock.class_object_name(class).expect("not a class");
+    let state = mock.state.borrow();
+    if !object.is_null() && state.is_subtype(&state.object(object).class, &class) { JNI_TRUE } else { JNI_FALSE }
+  }
+  table.IsInstanceOf = is_instance_of;
+
+  unsafe extern "C" fn throw_new(env: *mut JNIEnv, class: jclass, message: *const c_char) -> jint {
+    let mock = mock(env);
+    let class = mock.class_object_name(class).expect("not a class");
+    mock.state.borrow_mut().exception = Some((class, string(message)));
+    0
+  }
+  table.ThrowNew = throw_new;
+
+  unsafe extern "C" fn exception_check(env: *mut JNIEnv) -> jboolean {
+    if mock(env).state.borrow().exception.is_some() { JNI_TRUE } else { JNI_FALSE }
+  }
+  table.ExceptionCheck = exception_check;
+
+  unsafe extern "C" fn exception_clear(env: *mut JNIEnv) {
+    mock(env).state.borrow_mut().exception = None;
+  }
+  table.ExceptionClear = exception_clear;
+
+  unsafe extern "C" fn new_string_utf(env: *mut JNIEnv, chars: *const c_char) -> jstring {
+    mock(env).string(&CStr::from_ptr(chars).to_string_lossy())
+  }
+  table.NewStringUTF = new_string_utf;
+
+  unsafe extern "C" fn get_string_utf_chars(env: *mut JNIEnv, string: jstring, is_copy: *mut jboolean) -> *const c_char {
+    if !is_copy.is_null() {
+      *is_copy = JNI_FALSE;
+    }
+    match mock(env).state.borrow().object(string).value {
+      // the contents are never changed, so the pointer stays valid for as long as the mock
+      Value::String(ref string) => string.as_ptr(),
+      _ => ptr::null()
+    }
+  }
+  table.GetStringUTFChars = get_string_utf_chars;
+
+  unsafe extern "C" fn release_string_utf_chars(_: *mut JNIEnv, _: jstring, _: *const c_char) {}
+  table.ReleaseStringUTFChars = release_string_utf_chars;
+
+  unsafe extern "C" fn new_object_array(env: *mut JNIEnv, length: jsize, class: jclass, init: jobject) -> jobject {
+    let mock = mock(env);
+    let class_name = format!("[L{};", mock.class_object_name(class).expect("not a class"));
+    mock.add_object(&class_name, Value::Array(vec![init; length as usize]))
+  }
+  table.NewObjectArray = new_object_array;
+
+  unsafe extern "C" fn get_array_length(env: *mut JNIEnv, array: jobject) -> jsize {
+    match mock(env).state.borrow().object(array).value {
+      Value::Array(ref elements) => elements.len() as jsize,
+      Value::Primitives(ref elements) => elements.len() as jsize,
+      _ => panic!("not an array")
+    }
+  }
+  table.GetArrayLength = get_array_length;
+
+  unsafe extern "C" fn get_object_array_element(env: *mut JNIEnv, array: jobject, index: jsize) -> jobject {
+    mock(env).array_elements(array).expect("not an array")[index as usize]
+  }
+  table.GetObjectArrayElement = get_object_array_element;
+
+  unsafe extern "C" fn set_object_array_element(env: *mut JNIEnv, array: jobject, index: jsize, value: jobject) {
+    if let Value::Array(ref mut elements) = mock(env).state.borrow_mut().object_mut(array).value {
+      elements[index as usize] = value;
+    }
+  }
+  table.SetObjectArrayElement = set_object_array_element;
+
+  unsafe {
+    primitive_array_functions! {
+      table,
+      jboolean => GetBooleanArrayRegion;
+      jbyte => GetByteArrayRegion;
+      jchar => GetCharArrayRegion;
+      jshort => GetShortArrayRegion;
+      jint => GetIntArrayRegion;
+      jlong => GetLongArrayRegion;
+      jfloat => GetFloatArrayRegion;
+      jdouble => GetDoubleArrayRegion
+    }
+    typed_functions! {
+      table,
+      jobject => CallObjectMethod, CallObjectMethodA, CallNonvirtualObjectMethodA, CallStaticObjectMethod, CallStaticObjectMethodA, GetObjectField, GetStaticObjectField;
+      jboolean => CallBooleanMethod, CallBooleanMethodA, CallNonvirtualBooleanMethodA, CallStaticBooleanMethod, CallStaticBooleanMethodA, GetBooleanField, GetStaticBooleanField;
+      jbyte => CallByteMethod, CallByteMethodA, CallNonvirtualByteMethodA, CallStaticByteMethod, CallStaticByteMethodA, GetByteField, GetStaticByteField;
+      jchar => CallCharMethod, CallCharMethodA, CallNonvirtualCharMethodA, CallStaticCharMethod, CallStaticCharMethodA, GetCharField, GetStaticCharField;
+      jshort => CallShortMethod, CallShortMethodA, CallNonvirtualShortMethodA, CallStaticShortMethod, CallStaticShortMethodA, GetShortField, GetStaticShortField;
+      jint => CallIntMethod, CallIntMethodA, CallNonvirtualIntMethodA, CallStaticIntMethod, CallStaticIntMethodA, GetIntField, GetStaticIntField;
+      jlong => CallLongMethod, CallLongMethodA, CallNonvirtualLongMethodA, CallStaticLongMethod, CallStaticLongMethodA, GetLongField, GetStaticLongField;
+      jfloat => CallFloatMethod, CallFloatMethodA, CallNonvirtualFloatMethodA, CallStaticFloatMethod, CallStaticFloatMethodA, GetFloatField, GetStaticFloatField;
+      jdouble => CallDoubleMethod, CallDoubleMethodA, CallNonvirtualDoubleMethodA, CallStaticDoubleMethod, CallStaticDoubleMethodA, GetDoubleField, GetStaticDoubleField
+    }
+    table.CallVoidMethod = {
+      unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, method_id: jmethodID) {
+        mock(env).call(object, method_id, &[]);
+      }
+      mem::transmute::<unsafe extern "C" fn(*mut JNIEnv, jobject, jmethodID), unsafe extern "C" fn(*mut JNIEnv, jobject, jmethodID, ...)>(call)
+    };
+    table.CallVoidMethodA = {
+      unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, method_id: jmethodID, args: *const jvalue) {
+        let args = mock(env).args(method_id, args);
+        mock(env).call(object, method_id, &args);
+      }
+      call
+    };
+    table.CallNonvirtualVoidMethodA = {
+      unsafe extern "C" fn call(env: *mut JNIEnv, object: jobject, _: jclass, method_id: jmethodID, args: *const jvalue) {
+        let args = mock(env).args(method_id, args);
+        mock(env).call(object, method_id, &args);
+      }
+      call
+    };
+    table.CallStaticVoidMethod = {
+      unsafe extern "C" fn call(env: *mut JNIEnv, class: jclass, method_id: jmethodID) {
+        mock(env).call(class, method_id, &[]);
+      }
+      mem::transmute::<unsafe extern "C" fn(*mut JNIEnv, jclass, jmethodID), unsafe extern "C" fn(*mut JNIEnv, jclass, jmethodID, ...)>(call)
+    };
+    table.CallStaticVoidMethodA = {
+      unsafe extern "C" fn call(env: *mut JNIEnv, class: jclass, method_id: jmethodID, args: *const jvalue) {
+        let args = mock(env).args(method_id, args);
+        mock(env).call(class, method_id, &args);
+      }
+      call
+    };
+  }
+  table
+}
@@ -0,0 +1,198 @@
+//! Durable data for each player, keyed by the UUID of their `GameProfile`.
+//!
+//! [`Plugin::register_player_data`](../plugin/struct.Plugin.html#method.register_player_data) registers a
+//! [`Players<T>`](struct.Players.html) as a [state](../state/index.html) of the plugin, which keeps a `T` for every
+//! player as `<uuid>.json` in `players/<name>` of the plugin's data directory. A player's data is loaded when they
+//! join and saved and dropped when they leave, through `ClientConnectionEvent` listeners registered with the first
+//! `Players`, and everything still loaded is saved when the game stops and before a dev reload.
+
+use jni_sys::{JNIEnv, jobject};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use extensions::ConvertStringToRust;
+use plugin::Plugin;
+use sponge::entity::living::player::User;
+use sponge::event::entity::living::humanoid::player::TargetPlayerEvent;
+use sponge::util::Identifiable;
+use state;
+
+/// The data of type `T` of every player, loaded from disk as they are needed.
+pub struct Players<T> {
+  dir: PathBuf,
+  loaded: HashMap<String, T>
+}
+
+impl<T: Serialize + DeserializeOwned + Default> Players<T> {
+  /// Players whose data is kept in `dir`.
+  pub fn new(dir: PathBuf) -> Players<T> {
+    Players { dir, loaded: HashMap::new() }
+  }
+
+  /// The data of the player with `uuid`, read from disk if they have not joined since it was last saved, or the
+  /// default for a player without any.
+  pub fn get(&mut self, uuid: &str) -> &mut T {
+    let dir = &self.dir;
+    self.loaded.entry(uuid.to_owned()).or_insert_with(|| read(dir, uuid))
+  }
+
+  /// Writes the data of the player with `uuid` to disk, if it is loaded.
+  pub fn save(&self, uuid: &str) {
+    let data = match self.loaded.get(uuid) {
+      Some(data) => data,
+      None => return
+    };
+    let path = self.dir.join(format!("{}.json", uuid));
+    let written = fs::create_dir_all(&self.dir).map_err(|e| e.to_string())
+      .and_then(|_| serde_json::to_vec_pretty(data).map_err(|e| e.to_string()))
+      .and_then(|bytes| fs::write(&path, bytes).map_err(|e| e.to_string()));
+    if let Err(e) = written {
+      error!(error:% = e; "The data of player {} could not be saved to {}", uuid, path.display());
+    }
+  }
+
+  /// Saves the data of the player with `uuid` and stops keeping it in memory.
+  pub fn unload(&mut self, uuid: &str) {
+    self.save(uuid);
+    self.loaded.remove(uuid);
+  }
+}
+
+fn read<T: DeserializeOwned + Default>(dir: &Path, uuid: &str) -> T {
+  let path = dir.join(format!("{}.json", uuid));
+  let bytes = match fs::read(&path) {
+    Ok(bytes) => bytes,
+    Err(_) => return T::default()
+  };
+  serde_json::from_slice(&bytes).unwrap_or_else(|e| {
+    warn!(error:% = e; "The data of player {} in {} could not be read, starting from its default", uuid, path.display());
+    T::default()
+  })
+}
+
+// the player data of every type, without its type, for the connection listeners
+trait Store {
+  fn load(&mut self, uuid: &str);
+
+  fn unload(&mut self, uuid: &str);
+
+  fn save_all(&self);
+}
+
+impl<T: Serialize + DeserializeOwned + Default> Store for Players<T> {
+  fn load(&mut self, uuid: &str) {
+    self.get(uuid);
+  }
+
+  fn unload(&mut self, uuid: &str) {
+    Players::unload(self, uuid);
+  }
+
+  fn save_all(&self) {
+    for uuid in self.loaded.keys() {
+      self.save(uuid);
+    }
+  }
+}
+
+// registered as a state along with the first Players
+struct Stores(Vec<Rc<RefCell<dyn Store>>>);
+
+/// Registers `Players<T>` as a state of `plugin`, keeping its data in `players/<name>` of the plugin's data directory.
+///
+/// The first player data registered also registers the listeners loading and unloading it.
+pub fn register<T: Serialize + DeserializeOwned + Default + 'static>(plugin: &Plugin, name: &str) -> Rc<RefCell<Players<T>>> {
+  let players = state::register(Players::<T>::new(plugin.get_data_dir().join("players").join(name)));
+  match state::try_get::<Stores>() {
+    Some(stores) => stores.borrow_mut().0.push(players.clone()),
+    None => {
+      state::register(Stores(vec![players.clone()]));
+      let listeners = plugin.generate_listeners(
+        plugin.generated_class_name("PlayerDataListener").as_str(),
+        &[
+          ("org.spongepowered.api.event.network.ClientConnectionEvent$Join", player_joined),
+          ("org.spongepowered.api.event.network.ClientConnectionEvent$Disconnect", player_left)
+        ]
+      );
+      plugin.register_listeners(listeners);
+    }
+  }
+  players
+}
+
+/// Saves the data of every player still loaded.
+pub fn save_all() {
+  if let Some(stores) = state::try_get::<Stores>() {
+    for store in &stores.borrow().0 {
+      store.borrow().save_all();
+    }
+  }
+}
+
+/// The UUID of the `GameProfile` of `user`, as the string its data is kept under.
+pub fn uuid_of(env: *mut JNIEnv, user: &User) -> String {
+  let profile = user.get_profile();
+  let uuid = unsafe { Identifiable::from(env, profile.object) }.get_unique_id();
+  bound_method!(env, "java/util/UUID", uuid, "toString", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(env)
+}
+
+fn target_uuid(env: *mut JNIEnv, event: jobject) -> String {
+  let event = unsafe { TargetPlayerEvent::from(env, event) };
+  let user = unsafe { User::from(env, event.get_target_entity().object) };
+  uuid_of(env, &user)
+}
+
+fn with_stores<F: Fn(&mut dyn Store)>(f: F) {
+  if let Some(stores) = state::try_get::<Stores>() {
+    for store in &stores.borrow().0 {
+      f(&mut *store.borrow_mut());
+    }
+  }
+}
+
+extern "C" fn player_joined(env: *mut JNIEnv, _: jobject, event: jobject) {
+  let uuid = target_uuid(env, event);
+  with_stores(|store| store.load(&uuid));
+}
+
+extern "C" fn player_left(env: *mut JNIEnv, _: jobject, event: jobject) {
+  let uuid = target_uuid(env, event);
+  with_stores(|store| store.unload(&uuid));
+}
+
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::fs;
+  use std::process;
+
+  use super::Players;
+
+  #[derive(Default, Serialize, Deserialize)]
+  struct Visits {
+    count: u32
+  }
+
+  #[test]
+  fn keeps_players_on_disk_by_uuid() {
+    let dir = env::temp_dir().join(format!("spongejni-players-{}", process::id()));
+    let uuid = "069a79f4-44e9-4726-a5be-fca90e38aaf5";
+    let mut players = Players::<Visits>::new(dir.clone());
+    players.get(uuid).count += 1;
+    players.unload(uuid);
+    assert!(dir.join(format!("{}.json", uuid)).exists());
+
+    // read back by a new store, as after a restart
+    let mut players = Players::<Visits>::new(dir.clone());
+    players.get(uuid).count += 1;
+    assert_eq!(players.get(uuid).count, 2);
+    assert_eq!(players.get("853c80ef-3c37-49fd-aa49-938b674adae6").count, 0);
+    fs::remove_dir_all(dir).unwrap();
+  }
+}
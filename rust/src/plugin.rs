@@ -1,32 +1,115 @@
 use std;
-use std::collections::HashMap;
+use std::any::Any;
+use std::cell::RefCell;
 use std::ffi::CString;
+use std::path::PathBuf;
+use std::rc::Rc;
 use std::sync::Mutex;
 
 use jni_sys::*;
+use log::{self, Level, LevelFilter, Log, Metadata, Record};
+use log::kv::Key;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json;
 
-use commands;
+use class_file::{Annotation, ClassFile, NativeMethod};
+use convert::ToJValue;
+use config;
 use extensions::*;
-use generated_types::*;
-use listeners;
+use lifecycle::{self, JNI_VERSION_1_8};
+use lookup::{self, BoundMethod};
+#[cfg(all(feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+use players::{self, Players};
+use proxy::{self, JavaInterface};
+use reload;
+use rusty::Rusty;
+use state;
+#[cfg(feature = "command")]
+use sponge::command::{CommandCallable, CommandMapping};
+#[cfg(feature = "command")]
+use sponge::command::spec::CommandExecutor;
+use sponge::Game;
+#[cfg(feature = "plugin")]
+use sponge::plugin::PluginContainer;
+#[cfg(feature = "scheduler")]
+use sponge::scheduler::Task;
 
 pub struct JavaUtils;
 
 impl JavaUtils {
+  /// Loads the class `class_name` through the plugin's class loader, which can see SpongeAPI whichever thread this
+  /// is called on. The returned global reference is cached, and must not be deleted.
+  pub fn load_class(env: *mut JNIEnv, class_name: &str) -> jclass {
+    lookup::load_class(env, class_name)
+  }
+
+  /// Defines `class` in the plugin's class loader, returning a global reference to it. The reference is cached, and
+  /// must not be deleted.
+  ///
+  /// Its bound native methods are registered with `RegisterNatives`, so the class can have any name. If the class was
+  /// already defined, they are registered again, replacing the functions it was bound to.
+  pub fn define_class(env: *mut JNIEnv, class: &ClassFile) -> jclass {
+    let defined = lookup::define_class(env, &class.name, &class.to_bytes());
+    let names: Vec<(CString, CString, *mut std::os::raw::c_void)> = class.methods.iter()
+      .filter_map(|method| method.function.map(|function| (
+        CString::new(method.name.as_str()).unwrap(),
+        CString::new(method.descriptor.as_str()).unwrap(),
+        function
+      )))
+      .collect();
+    if !names.is_empty() {
+      let natives: Vec<JNINativeMethod> = names.iter()
+        .map(|&(ref name, ref descriptor, function)| JNINativeMethod {
+          name: name.as_ptr() as *mut _,
+          signature: descriptor.as_ptr() as *mut _,
+          fnPtr: function as *mut _
+        })
+        .collect();
+      let result = unsafe { ((**env).RegisterNatives)(env, defined, natives.as_ptr(), natives.len() as jint) };
+      if result != JNI_OK { panic!("natives of {} could not be registered", class.name); }
+    }
+    defined
+  }
+
+  /// Constructs an instance of `class` with its no-argument constructor.
+  pub fn new_instance(env: *mut JNIEnv, class: jclass) -> jobject {
+    unsafe {
+      let method_id = ((**env).GetMethodID)(env, class, CString::new("<init>").unwrap().as_ptr(), CString::new("()V").unwrap().as_ptr());
+      if method_id.is_null() { panic!("method_id was null"); }
+      let object = ((**env).NewObjectA)(env, class, method_id, std::ptr::null());
+      if object.is_null() { panic!("<init> was null"); }
+      object
+    }
+  }
+
   pub fn make_array(env: *mut JNIEnv, class_name: &str, vec: Vec<jobject>) -> jarray {
+    let class = JavaUtils::load_class(env, class_name);
     unsafe {
-      let class_name_string = CString::new(class_name).unwrap();
-      let class = ((**env).FindClass)(env, class_name_string.as_ptr());
-      if class.is_null() { panic!("class {} was null", class_name); }
       let array = ((**env).NewObjectArray)(env, vec.len() as i32, class, std::ptr::null_mut());
       for (i, item) in vec.into_iter().enumerate() {
         ((**env).SetObjectArrayElement)(env, array, i as i32, item);
       }
-      ((**env).DeleteLocalRef)(env, class);
       array
     }
   }
 
+  pub fn make_byte_array(env: *mut JNIEnv, bytes: &[u8]) -> jbyteArray {
+    unsafe {
+      let array = ((**env).NewByteArray)(env, bytes.len() as jsize);
+      ((**env).SetByteArrayRegion)(env, array, 0, bytes.len() as jsize, bytes.as_ptr() as *const jbyte);
+      array
+    }
+  }
+
+  pub fn read_byte_array(env: *mut JNIEnv, array: jbyteArray) -> Vec<u8> {
+    unsafe {
+      let mut bytes = vec![0u8; ((**env).GetArrayLength)(env, array) as usize];
+      ((**env).GetByteArrayRegion)(env, array, 0, bytes.len() as jsize, bytes.as_mut_ptr() as *mut jbyte);
+      bytes
+    }
+  }
+
   pub fn make_list(env: *mut JNIEnv, array: jarray) -> jobject {
     static_java_method!(env, "java/util/Arrays", "asList", "([Ljava/lang/Object;)Ljava/util/List;", CallStaticObjectMethodA, array)
   }
@@ -38,8 +121,9 @@ impl JavaUtils {
   pub fn get_plugin(env: *mut JNIEnv, shim: jobject) -> Plugin {
     let plugin = java_method!(env, shim, "getPlugin", "()Lme/kyleclemens/spongejni/SpongeJNI;", CallObjectMethod);
     Plugin {
-      env: env,
-      object: plugin
+      env,
+      object: plugin,
+      shim
     }
   }
 
@@ -50,36 +134,173 @@ impl JavaUtils {
   }
 }
 
+/// Implements `CommandExecutor#execute`, receiving the executor, the `CommandSource` and the `CommandContext`.
+#[cfg(feature = "command")]
+pub type ExecuteFn = extern "C" fn(*mut JNIEnv, jobject, jobject, jobject) -> jobject;
+
+/// Receives an event, along with the listener.
+pub type ListenerFn = extern "C" fn(*mut JNIEnv, jobject, jobject);
+
+/// The plugin, as seen by one native library.
+///
+/// SpongeJNI can load several libraries, each through a shim of its own, so that every library has its own `init`,
+/// class loader and statics. Commands and listeners are registered for `object`, the SpongeJNI plugin, since Sponge
+/// only accepts the plugins it loaded.
 pub struct Plugin {
   pub env: *mut JNIEnv,
-  pub object: jobject
+  pub object: jobject,
+  /// The `SpongeJNIShim` this library was initialized through.
+  pub shim: jobject
 }
 
 impl Plugin {
-  pub fn generate_command_executor<'a, S: Into<&'a str>>(&self, fqcn: S) -> command_spec_CommandExecutor {
-    let fqcn = fqcn.into();
-    let fqcn_java = fqcn.into_java_string(self.env);
-    let object = java_method!(self.env, self.object, "generateCommandExecutor", "(Ljava/lang/String;)Lorg/spongepowered/api/command/spec/CommandExecutor;", CallObjectMethodA, fqcn_java);
-    unsafe { command_spec_CommandExecutor::from(self.env, object) }
-  }
-
-  pub fn generate_listeners<'a, S: Into<&'a str>>(&self, fqcn: S, class_names: &'a [&'a str]) -> jobject {
-    let fqcn = fqcn.into();
-    let fqcn_java = fqcn.into_java_string(self.env);
-    let class_list = JavaUtils::make_array_list(self.env,
-      "java/lang/Class",
-      class_names.iter()
-        .map(|class_name| {
-          let class_string = CString::new(class_name.replace(".", "/")).unwrap();
-          let class = unsafe { ((**self.env).FindClass)(self.env, class_string.as_ptr()) };
-          if class.is_null() {
-            panic!("class for {} was null", class_name);
-          }
-          class
-        })
-        .collect()
-    );
-    java_method!(self.env, self.object, "generateListeners", "(Ljava/lang/String;Ljava/util/List;)Ljava/lang/Object;", CallObjectMethodA, fqcn_java, class_list)
+  /// Defines and instantiates a `CommandExecutor` named `fqcn` (`com.example.generated.MyExecutor`).
+  ///
+  /// The executor has one `public native` method called `execute`, which receives a `CommandSource` and a
+  /// `CommandContext` and returns a `CommandResult`. It is implemented by `execute`.
+  #[cfg(feature = "command")]
+  pub fn generate_command_executor<'a, S: Into<&'a str>>(&self, fqcn: S, execute: ExecuteFn) -> CommandExecutor {
+    let class = ClassFile::new(fqcn.into())
+      .interface("org/spongepowered/api/command/spec/CommandExecutor")
+      .method(
+        NativeMethod::new("execute", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandContext;)Lorg/spongepowered/api/command/CommandResult;")
+          .throws("org/spongepowered/api/command/CommandException")
+          .bind(execute as *mut _)
+      );
+    let class = JavaUtils::define_class(self.env, &class);
+    unsafe { CommandExecutor::from(self.env, JavaUtils::new_instance(self.env, class)) }
+  }
+
+  /// Defines and instantiates a listener named `fqcn` (`com.example.generated.MyListener`).
+  ///
+  /// The listener has a `public native` method annotated with `@Listener` for every event class in `listeners`,
+  /// named after the simple name of the class and implemented by the function paired with it:
+  /// `GrantAchievementEvent` is received by `grantAchievementEventReceived(GrantAchievementEvent event)`.
+  pub fn generate_listeners<'a, S: Into<&'a str>>(&self, fqcn: S, listeners: &'a [(&'a str, ListenerFn)]) -> jobject {
+    let mut class = ClassFile::new(fqcn.into());
+    for &(class_name, function) in listeners {
+      let class_name = class_name.replace('.', "/");
+      let simple_name = class_name.rsplit(&['/', '$'][..]).next().expect("empty class name");
+      let method_name = format!("{}{}Received", simple_name[..1].to_lowercase(), &simple_name[1..]);
+      class = class.method(
+        NativeMethod::new(&method_name, &format!("(L{};)V", class_name))
+          .annotation(Annotation::new("org/spongepowered/api/event/Listener"))
+          .bind(function as *mut _)
+      );
+    }
+    let class = JavaUtils::define_class(self.env, &class);
+    JavaUtils::new_instance(self.env, class)
+  }
+
+  /// Creates a Java object implementing the interfaces of `implementation`, whose methods call it.
+  pub fn implement<T: JavaInterface>(&self, implementation: T) -> jobject {
+    let interfaces = implementation.interfaces().iter()
+      .map(|class_name| JavaUtils::load_class(self.env, class_name))
+      .collect();
+    let interfaces = JavaUtils::make_array_list(self.env, "java/lang/Class", interfaces);
+    let handle = proxy::into_handle(implementation);
+    java_method!(self.env, self.shim, "implement", "(Ljava/util/List;J)Ljava/lang/Object;", CallObjectMethodA, interfaces, handle)
+  }
+
+  /// The id of the native plugin in this library, as set in the SpongeJNI config.
+  pub fn get_id(&self) -> String {
+    java_method!(self.env, self.shim, "getId", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(self.env)
+  }
+
+  /// The container of the native plugin in this library, which has its id and logger.
+  #[cfg(feature = "plugin")]
+  pub fn get_container(&self) -> PluginContainer {
+    let container = java_method!(self.env, self.shim, "getContainer", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    unsafe { PluginContainer::from(self.env, container) }
+  }
+
+  /// The name of a class defined by this library, namespaced by the plugin id so that classes of different libraries
+  /// are told apart: `HelloCommandExecutor` becomes `me.kyleclemens.spongejni.rust.hello.HelloCommandExecutor`.
+  pub fn generated_class_name(&self, simple_name: &str) -> String {
+    let package: String = self.get_id().chars()
+      .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+      .collect();
+    format!("me.kyleclemens.spongejni.rust.{}.{}", package, simple_name)
+  }
+
+  /// Registers `callable` under `aliases`, removing it again if the library is unloaded.
+  #[cfg(feature = "command")]
+  pub fn register_command(&self, callable: CommandCallable, aliases: &[&str]) -> Option<CommandMapping> {
+    let aliases = aliases.iter().map(|x| x.into_java_string(self.env)).collect();
+    let aliases = JavaUtils::make_array_list(self.env, "java/lang/String", aliases);
+    let mapping = self.get_game().get_command_manager().register_object_command_callable_list(self.object, callable, aliases);
+    if let Some(ref mapping) = mapping {
+      reload::track_command(self.env, mapping.object);
+    }
+    mapping
+  }
+
+  /// Registers the `@Listener` methods of `listeners`, unregistering them again if the library is unloaded.
+  #[cfg(feature = "event")]
+  pub fn register_listeners(&self, listeners: jobject) {
+    self.get_game().get_event_manager().register_listeners(self.object, listeners);
+    reload::track_listeners(self.env, listeners);
+  }
+
+  /// Cancels `task` if the library is unloaded.
+  #[cfg(feature = "scheduler")]
+  pub fn track_task(&self, task: &Task) {
+    reload::track_task(self.env, task.object);
+  }
+
+  /// The state saved by the library this one replaced, if it was reloaded.
+  pub fn get_previous_state(&self) -> Option<Vec<u8>> {
+    let state = java_method!(self.env, self.shim, "getPreviousState", "()[B", CallObjectMethod);
+    if state.is_null() { None } else { Some(JavaUtils::read_byte_array(self.env, state)) }
+  }
+
+  /// Registers `state` as the plugin's state of type `T`, to be got back with `state`. See
+  /// [`state`](../state/index.html).
+  pub fn register_state<T: Any>(&self, state: T) -> Rc<RefCell<T>> {
+    state::register(state)
+  }
+
+  /// Registers the plugin's state of type `T` like `register_state`, saving it under `name` when the game stops and
+  /// across dev reloads. `default` is used if no state was saved yet.
+  pub fn register_persistent_state<T: Any + Serialize + DeserializeOwned>(&self, name: &str, default: T) -> Rc<RefCell<T>> {
+    state::register_persistent(self, name, default)
+  }
+
+  /// The plugin's state of type `T`, which can only be used on the server thread. Other threads change it with
+  /// [`state::hand_off`](../state/fn.hand_off.html).
+  pub fn state<T: Any>(&self) -> Rc<RefCell<T>> {
+    state::get()
+  }
+
+  /// Registers `Players<T>` as the plugin's state, keeping a `T` for each player on disk. See
+  /// [`players`](../players/index.html).
+  #[cfg(all(feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+  pub fn register_player_data<T: Serialize + DeserializeOwned + Default + 'static>(&self, name: &str) -> Rc<RefCell<Players<T>>> {
+    players::register(self, name)
+  }
+
+  /// The directory for the files of the native plugin, `config/<id>`, which is created if it does not exist.
+  pub fn get_data_dir(&self) -> PathBuf {
+    PathBuf::from(java_method!(self.env, self.shim, "getDataDirectory", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(self.env))
+  }
+
+  /// Loads the config file of the native plugin, `config/<id>.conf`, into a `T`, writing `defaults` and `comments` for
+  /// the keys it is missing. See [`config::load`](../config/fn.load.html).
+  pub fn load_config<T: Serialize + DeserializeOwned>(&self, defaults: &T, comments: &[(&str, &str)]) -> Result<T, serde_json::Error> {
+    config::load(self, defaults, comments)
+  }
+
+  /// The slf4j `Logger` of the native plugin, named after its id.
+  pub fn get_logger(&self) -> jobject {
+    java_method!(self.env, self.shim, "getLogger", "()Lorg/slf4j/Logger;", CallObjectMethod)
+  }
+
+  /// The class loader of this library, which defines its classes and can see SpongeAPI through the plugin's.
+  pub fn get_class_loader(&self) -> jobject {
+    let class: jclass = unsafe { ((**self.env).GetObjectClass)(self.env, self.shim) };
+    let loader = java_method!(self.env, class, "getClassLoader", "()Ljava/lang/ClassLoader;", CallObjectMethod);
+    unsafe { ((**self.env).DeleteLocalRef)(self.env, class) };
+    loader
   }
 
   pub fn get_game(&self) -> Game {
@@ -88,30 +309,210 @@ impl Plugin {
   }
 }
 
-pub struct Instance {
-  pub player_count: HashMap<String, isize>
+/// A `log` backend writing to the slf4j `Logger` of the native plugin, which is named after its id, so that records
+/// show up in the server console and log files.
+///
+/// Records logged on threads that are not attached to the JVM are kept until a record is logged, or the logger is
+/// flushed, on an attached thread. The `error` key-value of a record is passed to the `Logger` as a `Throwable`:
+/// `error!(error:% = e; "could not save")`.
+pub struct PluginLogger {
+  // a global reference to the Logger, or 0 before init
+  logger: Mutex<usize>,
+  buffered: Mutex<Vec<Buffered>>
+}
+
+struct Buffered {
+  level: Level,
+  message: String,
+  error: Option<String>
 }
 
-impl Default for Instance {
-  fn default() -> Self {
-    Instance {
-      player_count: HashMap::new()
+static LOGGER: PluginLogger = PluginLogger::new();
+
+// by level, from error to trace
+static ENABLED: [BoundMethod; 5] = [
+  BoundMethod::new("org/slf4j/Logger", "isErrorEnabled", "()Z", false),
+  BoundMethod::new("org/slf4j/Logger", "isWarnEnabled", "()Z", false),
+  BoundMethod::new("org/slf4j/Logger", "isInfoEnabled", "()Z", false),
+  BoundMethod::new("org/slf4j/Logger", "isDebugEnabled", "()Z", false),
+  BoundMethod::new("org/slf4j/Logger", "isTraceEnabled", "()Z", false)
+];
+static LOG: [BoundMethod; 5] = [
+  BoundMethod::new("org/slf4j/Logger", "error", "(Ljava/lang/String;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "warn", "(Ljava/lang/String;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "info", "(Ljava/lang/String;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "debug", "(Ljava/lang/String;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "trace", "(Ljava/lang/String;)V", false)
+];
+static LOG_THROWABLE: [BoundMethod; 5] = [
+  BoundMethod::new("org/slf4j/Logger", "error", "(Ljava/lang/String;Ljava/lang/Throwable;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "warn", "(Ljava/lang/String;Ljava/lang/Throwable;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "info", "(Ljava/lang/String;Ljava/lang/Throwable;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "debug", "(Ljava/lang/String;Ljava/lang/Throwable;)V", false),
+  BoundMethod::new("org/slf4j/Logger", "trace", "(Ljava/lang/String;Ljava/lang/Throwable;)V", false)
+];
+
+impl PluginLogger {
+  const fn new() -> Self {
+    PluginLogger {
+      logger: Mutex::new(0),
+      buffered: Mutex::new(Vec::new())
+    }
+  }
+
+  /// Makes the logger of `plugin` the backend of the `log` macros, for the levels it has enabled.
+  pub fn init(plugin: &Plugin) {
+    let env = plugin.env;
+    let logger = plugin.get_logger();
+    let max_level = LevelFilter::iter()
+      .skip(1)
+      .zip(ENABLED.iter())
+      .filter(|&(_, enabled)| unsafe { ((**env).CallBooleanMethodA)(env, logger, enabled.get(env).1, std::ptr::null()) } != 0)
+      .map(|(level, _)| level)
+      .last()
+      .unwrap_or(LevelFilter::Off);
+    let old = std::mem::replace(&mut *LOGGER.logger.lock().unwrap(), unsafe { ((**env).NewGlobalRef)(env, logger) } as usize);
+    if old != 0 {
+      unsafe { ((**env).DeleteGlobalRef)(env, old as jobject) };
+    }
+    // fails if the logger was set before, which can only have been this one
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(max_level);
+  }
+
+  /// Lets go of the logger, writing what was buffered first. Records logged afterwards are buffered.
+  pub fn release(env: *mut JNIEnv) {
+    let logger = std::mem::replace(&mut *LOGGER.logger.lock().unwrap(), 0);
+    if logger != 0 {
+      LOGGER.drain(env, logger as jobject);
+      unsafe { ((**env).DeleteGlobalRef)(env, logger as jobject) };
+    }
+  }
+
+  /// The `JNIEnv` of this thread and the logger, if the thread is attached and there is a logger.
+  fn attached(&self) -> Option<(*mut JNIEnv, jobject)> {
+    let vm = lifecycle::vm();
+    let logger = *self.logger.lock().unwrap();
+    if vm.is_null() || logger == 0 {
+      return None;
+    }
+    let mut env: *mut std::os::raw::c_void = std::ptr::null_mut();
+    if unsafe { ((**vm).GetEnv)(vm, &mut env, JNI_VERSION_1_8) } != JNI_OK {
+      return None;
+    }
+    Some((env as *mut JNIEnv, logger as jobject))
+  }
+
+  fn drain(&self, env: *mut JNIEnv, logger: jobject) {
+    let buffered = std::mem::take(&mut *self.buffered.lock().unwrap());
+    for record in &buffered {
+      PluginLogger::write(env, logger, record);
+    }
+  }
+
+  fn write(env: *mut JNIEnv, logger: jobject, record: &Buffered) {
+    let index = record.level as usize - 1;
+    let message = record.message.as_str().into_java_string(env);
+    let throwable = record.error.as_ref().map(|error| {
+      let error = error.as_str().into_java_string(env);
+      let throwable = new_object!(env, "java/lang/RuntimeException", "(Ljava/lang/String;)V", error);
+      unsafe { ((**env).DeleteLocalRef)(env, error) };
+      throwable
+    });
+    unsafe {
+      match throwable {
+        Some(throwable) => {
+          let args = [message.to_jvalue(), throwable.to_jvalue()];
+          ((**env).CallVoidMethodA)(env, logger, LOG_THROWABLE[index].get(env).1, args.as_ptr());
+          ((**env).DeleteLocalRef)(env, throwable);
+        },
+        None => ((**env).CallVoidMethodA)(env, logger, LOG[index].get(env).1, [message.to_jvalue()].as_ptr())
+      }
+      ((**env).DeleteLocalRef)(env, message);
+    }
+  }
+}
+
+impl Log for PluginLogger {
+  fn enabled(&self, metadata: &Metadata) -> bool {
+    metadata.level() <= log::max_level()
+  }
+
+  fn log(&self, record: &Record) {
+    let buffered = Buffered {
+      level: record.level(),
+      message: format!("[{}] {}", record.target(), record.args()),
+      error: record.key_values().get(Key::from_str("error")).map(|x| x.to_string())
+    };
+    match self.attached() {
+      Some((env, logger)) => {
+        self.drain(env, logger);
+        PluginLogger::write(env, logger, &buffered);
+      },
+      None => self.buffered.lock().unwrap().push(buffered)
+    }
+  }
+
+  fn flush(&self) {
+    if let Some((env, logger)) = self.attached() {
+      self.drain(env, logger);
     }
   }
 }
 
-lazy_static! {
-  pub static ref INSTANCE: Mutex<Instance> = Mutex::new(Instance::default());
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn Java_me_kyleclemens_spongejni_SpongeJNIShim_init(env: *mut JNIEnv, this: jobject) -> jboolean {
+  let plugin = JavaUtils::get_plugin(env, this);
+  lookup::set_class_loader(env, plugin.get_class_loader());
+  PluginLogger::init(&plugin);
+  state::init();
+  lifecycle::register(Rusty);
+
+  1
 }
 
 #[allow(non_snake_case)]
 #[no_mangle]
-pub extern fn Java_me_kyleclemens_spongejni_SpongeJNIShim_init(env: *mut JNIEnv, this: jobject) -> jboolean {
+pub extern "C" fn Java_me_kyleclemens_spongejni_SpongeJNIShim_unload(env: *mut JNIEnv, this: jobject) -> jbyteArray {
   let plugin = JavaUtils::get_plugin(env, this);
+  lifecycle::unload(env);
+  lifecycle::save_data(&plugin);
+  PluginLogger::release(env);
+  match reload::unload(&plugin) {
+    Some(state) => JavaUtils::make_byte_array(env, &state),
+    None => std::ptr::null_mut()
+  }
+}
 
-  commands::Commands::register(&plugin);
+#[cfg(test)]
+mod tests {
+  use jni_sys::jobject;
+  use log::{Level, Log, Record};
 
-  listeners::Listeners::register(&plugin);
+  use mock::MockEnv;
+  use super::PluginLogger;
 
-  1
+  #[test]
+  fn buffers_records_until_attached() {
+    let logger = PluginLogger::new();
+    // there is no JVM, so no thread is attached
+    logger.log(&Record::builder().level(Level::Warn).target("spongejni::test").args(format_args!("{} players", 2)).build());
+    logger.log(&Record::builder().level(Level::Error).target("spongejni::test").args(format_args!("lost")).key_values(&[("error", "disk full")]).build());
+
+    let mock = MockEnv::new();
+    let slf4j = mock.object("org/slf4j/Logger");
+    logger.drain(mock.env(), slf4j);
+    let calls: Vec<(String, String, String)> = mock.calls().into_iter()
+      .filter(|x| x.this == slf4j)
+      .map(|x| (x.name.clone(), x.descriptor.clone(), mock.string_value(x.arg::<jobject>(0)).expect("message was not a string")))
+      .collect();
+    assert_eq!(calls, vec![
+      ("warn".to_owned(), "(Ljava/lang/String;)V".to_owned(), "[spongejni::test] 2 players".to_owned()),
+      ("error".to_owned(), "(Ljava/lang/String;Ljava/lang/Throwable;)V".to_owned(), "[spongejni::test] lost".to_owned())
+    ]);
+    let throwable = mock.calls().into_iter().find(|x| x.name == "<init>").expect("no throwable was created");
+    assert_eq!(throwable.class, "java/lang/RuntimeException");
+    assert_eq!(mock.string_value(throwable.arg::<jobject>(0)), Some("disk full".to_owned()));
+  }
 }
@@ -0,0 +1,236 @@
+//! Implements Java interfaces with Rust types.
+//!
+//! [`Plugin::implement`](../plugin/struct.Plugin.html#method.implement) wraps a
+//! [`JavaInterface`](trait.JavaInterface.html) in a `java.lang.reflect.Proxy`, whose `RustInvocationHandler` passes
+//! every call back to it by method name and descriptor. Unlike the classes generated by the plugin, this needs no
+//! exported function for each method, so any SpongeAPI interface can be implemented at runtime. The
+//! [`java_interface!`](../macro.java_interface.html) macro writes the dispatch:
+//!
+//! ```no_run
+//! # #[macro_use] extern crate spongejni;
+//! # extern crate jni_sys;
+//! use jni_sys::{JNIEnv, jobject};
+//! use spongejni::sponge::command::CommandResult;
+//!
+//! struct Ping;
+//!
+//! impl Ping {
+//!   fn execute(&self, env: *mut JNIEnv, _: jobject, _: &[jobject]) -> jobject {
+//!     CommandResult::success(env).object
+//!   }
+//! }
+//!
+//! java_interface! {
+//!   Ping: ["org/spongepowered/api/command/spec/CommandExecutor"] {
+//!     "execute", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandContext;)Lorg/spongepowered/api/command/CommandResult;" => execute
+//!   }
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! Java boxes the primitive arguments and return values of proxy methods; [`Boxed`](trait.Boxed.html) converts them.
+
+use jni_sys::{JNIEnv, jlong, jobject, jobjectArray, jstring, jvalue};
+use std::ffi::CString;
+use std::ptr;
+use std::sync::Arc;
+
+use convert;
+use extensions::ConvertStringToRust;
+use plugin::JavaUtils;
+use reload;
+
+/// A Rust type implementing Java interfaces, whose methods are called through a proxy.
+///
+/// Proxies can be called from any thread, and are dropped on the finalizer thread, so implementations have to be
+/// `Send` and `Sync`.
+pub trait JavaInterface: Send + Sync + 'static {
+  /// The interfaces implemented, such as `org/spongepowered/api/command/spec/CommandExecutor`.
+  fn interfaces(&self) -> &'static [&'static str];
+
+  /// Handles a call of the method `name` with `descriptor` on `proxy`, returning the result, boxed if it is a
+  /// primitive, or null for `void` methods.
+  fn invoke(&self, env: *mut JNIEnv, proxy: jobject, name: &str, descriptor: &str, args: &[jobject]) -> jobject;
+}
+
+/// Implements [`JavaInterface`](proxy/trait.JavaInterface.html) for a type, calling one of its methods for each of
+/// the listed Java methods.
+///
+/// Each method takes the `JNIEnv`, the proxy and the arguments, and returns a `jobject`, like
+/// [`JavaInterface::invoke`](proxy/trait.JavaInterface.html#tymethod.invoke). Java methods that are not listed throw
+/// an `UnsupportedOperationException`. `JNIEnv` and `jobject` have to be in scope.
+#[macro_export]
+macro_rules! java_interface {
+    ($t:ty: [$($interface:expr),+] { $($name:expr, $descriptor:expr => $method:ident),* $(,)* }) => {
+      impl $crate::proxy::JavaInterface for $t {
+        fn interfaces(&self) -> &'static [&'static str] {
+          &[$($interface),+]
+        }
+
+        fn invoke(&self, env: *mut JNIEnv, proxy: jobject, name: &str, descriptor: &str, args: &[jobject]) -> jobject {
+          $(
+            if name == $name && descriptor == $descriptor {
+              return self.$method(env, proxy, args);
+            }
+          )*
+          $crate::proxy::unsupported(env, name, descriptor)
+        }
+      }
+    }
+}
+
+/// Throws an `UnsupportedOperationException` for the method `name` with `descriptor`, returning null.
+pub fn unsupported(env: *mut JNIEnv, name: &str, descriptor: &str) -> jobject {
+  let class = JavaUtils::load_class(env, "java/lang/UnsupportedOperationException");
+  let message = CString::new(format!("{}{} is not implemented in Rust", name, descriptor)).unwrap();
+  unsafe { ((**env).ThrowNew)(env, class, message.as_ptr()) };
+  ptr::null_mut()
+}
+
+/// A primitive passed to or returned from a proxy method, which Java boxes (`int` becomes `java.lang.Integer`).
+pub trait Boxed: Sized {
+  fn to_boxed(&self, env: *mut JNIEnv) -> jobject;
+
+  fn from_boxed(env: *mut JNIEnv, object: jobject) -> Self;
+}
+
+macro_rules! boxed {
+  ($($t:ty => $class:expr, $value_of:expr, $value:expr, $value_descriptor:expr, $call_using:ident, $x:ident, $from:expr);+) => {
+    $(
+      impl Boxed for $t {
+        fn to_boxed(&self, env: *mut JNIEnv) -> jobject {
+          static_java_method!(env, $class, "valueOf", $value_of, CallStaticObjectMethodA, *self)
+        }
+
+        fn from_boxed(env: *mut JNIEnv, object: jobject) -> Self {
+          let $x = bound_method!(env, $class, object, $value, $value_descriptor, $call_using);
+          $from
+        }
+      }
+    )+
+  }
+}
+
+boxed! {
+  bool => "java/lang/Boolean", "(Z)Ljava/lang/Boolean;", "booleanValue", "()Z", CallBooleanMethod, x, x != 0;
+  char => "java/lang/Character", "(C)Ljava/lang/Character;", "charValue", "()C", CallCharMethod, x, convert::char_from_java(x);
+  i8 => "java/lang/Byte", "(B)Ljava/lang/Byte;", "byteValue", "()B", CallByteMethod, x, x;
+  i16 => "java/lang/Short", "(S)Ljava/lang/Short;", "shortValue", "()S", CallShortMethod, x, x;
+  i32 => "java/lang/Integer", "(I)Ljava/lang/Integer;", "intValue", "()I", CallIntMethod, x, x;
+  i64 => "java/lang/Long", "(J)Ljava/lang/Long;", "longValue", "()J", CallLongMethod, x, x;
+  f32 => "java/lang/Float", "(F)Ljava/lang/Float;", "floatValue", "()F", CallFloatMethod, x, x;
+  f64 => "java/lang/Double", "(D)Ljava/lang/Double;", "doubleValue", "()D", CallDoubleMethod, x, x
+}
+
+/// Keeps `implementation` until the library is unloaded, returning the handle that a `RustInvocationHandler` owns.
+pub fn into_handle<T: JavaInterface>(implementation: T) -> jlong {
+  reload::track_proxy(Arc::new(implementation))
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn Java_me_kyleclemens_spongejni_RustInvocationHandler_dispatch(env: *mut JNIEnv, _: jobject, handle: jlong, proxy: jobject, name: jstring, descriptor: jstring, args: jobjectArray) -> jobject {
+  let implementation = match reload::proxy(handle) {
+    Some(implementation) => implementation,
+    None => return unloaded(env)
+  };
+  let name = name.into_rust_string(env);
+  let descriptor = descriptor.into_rust_string(env);
+  let args: Vec<jobject> = unsafe {
+    (0..((**env).GetArrayLength)(env, args))
+      .map(|i| ((**env).GetObjectArrayElement)(env, args, i))
+      .collect()
+  };
+  implementation.invoke(env, proxy, &name, &descriptor, &args)
+}
+
+#[allow(non_snake_case)]
+#[no_mangle]
+pub extern "C" fn Java_me_kyleclemens_spongejni_RustInvocationHandler_release(_: *mut JNIEnv, _: jobject, handle: jlong) {
+  reload::release_proxy(handle);
+}
+
+// the classes the library cached are released by then, so the exception is found without them
+fn unloaded(env: *mut JNIEnv) -> jobject {
+  unsafe {
+    let class = ((**env).FindClass)(env, CString::new("java/lang/IllegalStateException").unwrap().as_ptr());
+    let message = CString::new("the library implementing this proxy was unloaded").unwrap();
+    ((**env).ThrowNew)(env, class, message.as_ptr());
+    ((**env).DeleteLocalRef)(env, class);
+  }
+  ptr::null_mut()
+}
+
+#[cfg(test)]
+mod tests {
+  use jni_sys::{JNIEnv, jlong, jobject};
+  use std::ptr;
+
+  use convert::ToJValue;
+  use extensions::ConvertStringToJava;
+  use mock::MockEnv;
+  use plugin::JavaUtils;
+  use super::{Boxed, Java_me_kyleclemens_spongejni_RustInvocationHandler_dispatch as dispatch, Java_me_kyleclemens_spongejni_RustInvocationHandler_release as release, into_handle};
+
+  struct Doubler;
+
+  impl Doubler {
+    fn apply(&self, env: *mut JNIEnv, _: jobject, args: &[jobject]) -> jobject {
+      (i32::from_boxed(env, args[0]) * 2).to_boxed(env)
+    }
+  }
+
+  java_interface! {
+    Doubler: ["java/util/function/IntUnaryOperator"] {
+      "applyAsInt", "(I)I" => apply
+    }
+  }
+
+  fn call(mock: &MockEnv, name: &str, descriptor: &str, args: Vec<jobject>) -> jobject {
+    call_handle(mock, into_handle(Doubler), name, descriptor, args)
+  }
+
+  fn call_handle(mock: &MockEnv, handle: jlong, name: &str, descriptor: &str, args: Vec<jobject>) -> jobject {
+    let env = mock.env();
+    let args = JavaUtils::make_array(env, "java/lang/Object", args);
+    dispatch(env, ptr::null_mut(), handle, ptr::null_mut(), name.into_java_string(env), descriptor.into_java_string(env), args)
+  }
+
+  #[test]
+  fn dispatches_by_name_and_descriptor() {
+    let mock = MockEnv::new();
+    let env = mock.env();
+    mock.on("java/lang/Integer", "valueOf", "(I)Ljava/lang/Integer;", |mock, _, args| {
+      let boxed = mock.object("java/lang/Integer");
+      mock.set_field(boxed, "value", args[0]);
+      boxed.to_jvalue()
+    });
+    mock.on("java/lang/Integer", "intValue", "()I", |mock, this, _| mock.field(this, "value"));
+
+    let result = call(&mock, "applyAsInt", "(I)I", vec![21.to_boxed(env)]);
+    assert_eq!(i32::from_boxed(env, result), 42);
+    assert_eq!(mock.exception(), None);
+
+    assert!(call(&mock, "andThen", "(Ljava/util/function/IntUnaryOperator;)Ljava/util/function/IntUnaryOperator;", vec![ptr::null_mut()]).is_null());
+    assert_eq!(mock.exception(), Some((
+      "java/lang/UnsupportedOperationException".to_owned(),
+      "andThen(Ljava/util/function/IntUnaryOperator;)Ljava/util/function/IntUnaryOperator; is not implemented in Rust".to_owned()
+    )));
+  }
+
+  #[test]
+  fn released_proxies_throw_instead_of_calling_rust() {
+    let mock = MockEnv::new();
+    let env = mock.env();
+    let handle = into_handle(Doubler);
+    release(env, ptr::null_mut(), handle);
+    // released again by the finalizer after an unload
+    release(env, ptr::null_mut(), handle);
+
+    assert!(call_handle(&mock, handle, "applyAsInt", "(I)I", vec![ptr::null_mut()]).is_null());
+    assert_eq!(mock.exception(), Some((
+      "java/lang/IllegalStateException".to_owned(),
+      "the library implementing this proxy was unloaded".to_owned()
+    )));
+  }
+}
@@ -0,0 +1,114 @@
+//! Unloads the library, so that SpongeJNI can load a newly built one in its place during development.
+//!
+//! With `dev = true` in its config, SpongeJNI loads a copy of each library and registers a `spongejni-reload` command.
+//! The command calls `SpongeJNIShim.unload`, which undoes what the library registered through
+//! [`Plugin`](../plugin/struct.Plugin.html) (its commands, listeners, tracked tasks and proxies) and releases the
+//! global references it holds, so that its class loader can be collected. Proxies that outlive the library throw an
+//! `IllegalStateException` instead of calling into it. A fresh copy of the library is then loaded and
+//! initialized, and can pick up the state the old one saved with [`save_state_with`](fn.save_state_with.html)
+//! through [`Plugin::get_previous_state`](../plugin/struct.Plugin.html#method.get_previous_state).
+
+use jni_sys::{JNIEnv, jlong, jobject};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use convert;
+use lookup;
+use proxy::JavaInterface;
+#[cfg(feature = "command")]
+use sponge::command::CommandMapping;
+#[cfg(feature = "scheduler")]
+use sponge::scheduler::Task;
+use plugin::Plugin;
+
+#[derive(Default)]
+struct Registrations {
+  // global references
+  commands: Vec<usize>,
+  listeners: Vec<usize>,
+  tasks: Vec<usize>,
+  // the implementations behind proxies, by handle
+  proxies: HashMap<jlong, Arc<dyn JavaInterface>>,
+  save_state: Option<fn() -> Vec<u8>>
+}
+
+lazy_static! {
+  static ref REGISTRATIONS: Mutex<Registrations> = Mutex::new(Registrations::default());
+}
+
+// not reset on unload, so a stale handle never finds a newer implementation
+static NEXT_PROXY: AtomicUsize = AtomicUsize::new(1);
+
+fn global(env: *mut JNIEnv, object: jobject) -> usize {
+  unsafe { ((**env).NewGlobalRef)(env, object) as usize }
+}
+
+/// Removes the command mapped by `mapping` when the library is unloaded.
+pub fn track_command(env: *mut JNIEnv, mapping: jobject) {
+  REGISTRATIONS.lock().unwrap().commands.push(global(env, mapping));
+}
+
+/// Unregisters `listeners` when the library is unloaded.
+pub fn track_listeners(env: *mut JNIEnv, listeners: jobject) {
+  REGISTRATIONS.lock().unwrap().listeners.push(global(env, listeners));
+}
+
+/// Cancels `task` when the library is unloaded.
+pub fn track_task(env: *mut JNIEnv, task: jobject) {
+  REGISTRATIONS.lock().unwrap().tasks.push(global(env, task));
+}
+
+/// Keeps `implementation` until its proxy is released or the library is unloaded, returning its handle.
+pub fn track_proxy(implementation: Arc<dyn JavaInterface>) -> jlong {
+  let handle = NEXT_PROXY.fetch_add(1, Ordering::Relaxed) as jlong;
+  REGISTRATIONS.lock().unwrap().proxies.insert(handle, implementation);
+  handle
+}
+
+/// The implementation behind the proxy with `handle`, or `None` once it was released or the library was unloaded.
+pub fn proxy(handle: jlong) -> Option<Arc<dyn JavaInterface>> {
+  REGISTRATIONS.lock().unwrap().proxies.get(&handle).cloned()
+}
+
+/// Drops the implementation behind the proxy with `handle`, if it is still kept.
+pub fn release_proxy(handle: jlong) {
+  let implementation = REGISTRATIONS.lock().unwrap().proxies.remove(&handle);
+  // dropped outside the lock, in case it releases proxies itself
+  drop(implementation);
+}
+
+/// Calls `save` when the library is unloaded, passing what it returns to the library replacing it.
+pub fn save_state_with(save: fn() -> Vec<u8>) {
+  REGISTRATIONS.lock().unwrap().save_state = Some(save);
+}
+
+/// Unregisters everything tracked and releases the library's global references, returning the state to hand over.
+///
+/// Nothing from the library may be called afterwards: the classes and method IDs it cached are gone.
+pub fn unload(plugin: &Plugin) -> Option<Vec<u8>> {
+  let env = plugin.env;
+  let registrations = ::std::mem::take(&mut *REGISTRATIONS.lock().unwrap());
+  let game = plugin.get_game();
+  for mapping in registrations.commands {
+    #[cfg(feature = "command")]
+    game.get_command_manager().remove_mapping(CommandMapping { env, object: mapping as jobject });
+    unsafe { ((**env).DeleteGlobalRef)(env, mapping as jobject) };
+  }
+  for listeners in registrations.listeners {
+    #[cfg(feature = "event")]
+    game.get_event_manager().unregister_listeners(listeners as jobject);
+    unsafe { ((**env).DeleteGlobalRef)(env, listeners as jobject) };
+  }
+  for task in registrations.tasks {
+    #[cfg(feature = "scheduler")]
+    Task { env, object: task as jobject }.cancel();
+    unsafe { ((**env).DeleteGlobalRef)(env, task as jobject) };
+  }
+  // calls already running keep their implementation until they return
+  drop(registrations.proxies);
+  let state = registrations.save_state.map(|save| save());
+  convert::release_enum_constants(env);
+  lookup::release(env);
+  state
+}
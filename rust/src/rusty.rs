@@ -0,0 +1,24 @@
+#[cfg(all(feature = "command", feature = "text", feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+use commands::{self, Greetings};
+use lifecycle::Lifecycle;
+#[cfg(all(feature = "data", feature = "entity", feature = "event"))]
+use listeners;
+use plugin::Plugin;
+
+/// The example plugin, registering the `rusty` command and its listeners.
+pub struct Rusty;
+
+impl Lifecycle for Rusty {
+  fn on_init(&self, plugin: &Plugin) {
+    info!("Registering the rusty command and listeners");
+
+    #[cfg(all(feature = "command", feature = "text", feature = "entity", feature = "event", feature = "profile", feature = "util"))]
+    {
+      plugin.register_player_data::<Greetings>("greetings");
+      commands::Commands::register(plugin);
+    }
+
+    #[cfg(all(feature = "data", feature = "entity", feature = "event"))]
+    listeners::Listeners::register(plugin);
+  }
+}
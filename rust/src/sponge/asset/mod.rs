@@ -0,0 +1,105 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Asset {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Asset {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Asset {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Asset`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/asset/Asset", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_owner(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "getOwner", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#getOwner was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_url(&self) -> ::url::Url {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "getUrl", "()Ljava/net/URL;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#getUrl was null") }
+    <::url::Url as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn copy_to_file(&self, path: ::std::path::PathBuf) {
+    bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "copyToFile", "(Ljava/nio/file/Path;)V", CallVoidMethodA, ::convert::ToJava::to_java(&path, self.env));
+  }
+
+  pub fn read_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "readString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#readString was null") }
+    ret
+  }
+
+  pub fn read_string_charset(&self, charset: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "readString", "(Ljava/nio/charset/Charset;)Ljava/lang/String;", CallObjectMethodA, charset);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#readString was null") }
+    ret
+  }
+
+  pub fn read_lines(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "readLines", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#readLines was null") }
+    ret
+  }
+
+  pub fn read_lines_charset(&self, charset: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/Asset", self.object, "readLines", "(Ljava/nio/charset/Charset;)Ljava/util/List;", CallObjectMethodA, charset);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/Asset#readLines was null") }
+    ret
+  }
+
+  pub fn read_bytes(&self) -> Vec<i8> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct AssetManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AssetManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AssetManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AssetManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/asset/AssetManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_asset_object_string(&self, object: jobject, string: jobject) -> Option<::sponge::asset::Asset> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/AssetManager", self.object, "getAsset", "(Ljava/lang/Object;Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/AssetManager#getAsset was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::asset::Asset { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_asset_string(&self, string: jobject) -> Option<::sponge::asset::Asset> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/asset/AssetManager", self.object, "getAsset", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/asset/AssetManager#getAsset was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::asset::Asset { env: self.env, object: unwrapped }) }
+  }
+
+}
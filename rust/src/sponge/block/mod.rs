@@ -0,0 +1,396 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod tileentity;
+pub mod trait_;
+
+#[derive(Debug)]
+pub struct BlockSnapshot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockSnapshot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockSnapshot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockSnapshot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockSnapshot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::block::block_snapshot::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/block/BlockSnapshot", "builder", "()Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#builder was null") }
+    ::sponge::block::block_snapshot::Builder { env: env, object: ret }
+  }
+
+  pub fn get_state(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "getState", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#getState was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  pub fn get_extended_state(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "getExtendedState", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#getExtendedState was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  pub fn with_state(&self, block_state: ::sponge::block::BlockState) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "withState", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethodA, block_state.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#withState was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn with_container(&self, data_container: ::sponge::data::DataContainer) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "withContainer", "(Lorg/spongepowered/api/data/DataContainer;)Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethodA, data_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#withContainer was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn restore(&self, value: bool, block_change_flag: ::sponge::world::BlockChangeFlag) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "restore", "(ZLorg/spongepowered/api/world/BlockChangeFlag;)Z", CallBooleanMethodA, value, ::convert::ToJava::to_java(&block_change_flag, self.env)) != 0
+  }
+
+  pub fn get_creator(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "getCreator", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#getCreator was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_notifier(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot", self.object, "getNotifier", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot#getNotifier was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockState {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockState {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockState {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockState`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockState", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::block::block_state::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/block/BlockState", "builder", "()Lorg/spongepowered/api/block/BlockState$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#builder was null") }
+    ::sponge::block::block_state::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::block::BlockType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getType", "()Lorg/spongepowered/api/block/BlockType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getType was null") }
+    ::sponge::block::BlockType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn with_extended_properties(&self, location: ::sponge::world::Location) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "withExtendedProperties", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#withExtendedProperties was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn cycle_value(&self, key: ::sponge::data::key::Key) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "cycleValue", "(Lorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#cycleValue was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn snapshot_for(&self, location: ::sponge::world::Location) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "snapshotFor", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#snapshotFor was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  pub fn get_trait_value(&self, block_trait: ::sponge::block::trait_::BlockTrait) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getTraitValue", "(Lorg/spongepowered/api/block/trait/BlockTrait;)Ljava/util/Optional;", CallObjectMethodA, block_trait.object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getTraitValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_trait(&self, string: jobject) -> Option<::sponge::block::trait_::BlockTrait> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getTrait", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getTrait was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::trait_::BlockTrait { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn with_trait(&self, block_trait: ::sponge::block::trait_::BlockTrait, object: jobject) -> Option<::sponge::block::BlockState> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "withTrait", "(Lorg/spongepowered/api/block/trait/BlockTrait;Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, block_trait.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#withTrait was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::BlockState { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_traits(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getTraits", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getTraits was null") }
+    ret
+  }
+
+  pub fn get_trait_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getTraitValues", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getTraitValues was null") }
+    ret
+  }
+
+  pub fn get_trait_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState", self.object, "getTraitMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockState#getTraitMap was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockType#getName was null") }
+    ret
+  }
+
+  pub fn get_default_state(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getDefaultState", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockType#getDefaultState was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_item(&self) -> Option<::sponge::item::ItemType> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getItem", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockType#getItem was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::ItemType { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_tick_randomly(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getTickRandomly", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_tick_randomly(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "setTickRandomly", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_traits(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getTraits", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockType#getTraits was null") }
+    ret
+  }
+
+  pub fn get_trait(&self, string: jobject) -> Option<::sponge::block::trait_::BlockTrait> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockType", self.object, "getTrait", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/block/BlockType#getTrait was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::trait_::BlockTrait { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ScheduledBlockUpdate {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ScheduledBlockUpdate {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ScheduledBlockUpdate {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ScheduledBlockUpdate`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/ScheduledBlockUpdate", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_ticks(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/block/ScheduledBlockUpdate", self.object, "getTicks", "()I", CallIntMethod)
+  }
+
+  pub fn set_ticks(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/block/ScheduledBlockUpdate", self.object, "setTicks", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_priority(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/block/ScheduledBlockUpdate", self.object, "getPriority", "()I", CallIntMethod)
+  }
+
+  pub fn set_priority(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/block/ScheduledBlockUpdate", self.object, "setPriority", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+pub mod block_snapshot {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockSnapshot$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn world(&self, world_properties: ::sponge::world::storage::WorldProperties) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "world", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, world_properties.object);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#world was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn block_state(&self, block_state: ::sponge::block::BlockState) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "blockState", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#blockState was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn position(&self, vector_3i: ::math::Vector3i) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "position", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#position was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "world")]
+    pub fn from_location(&self, location: ::sponge::world::Location) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "from", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#from was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn creator(&self, uuid: jobject) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "creator", "(Ljava/util/UUID;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, uuid);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#creator was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn notifier(&self, uuid: jobject) -> ::sponge::block::block_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockSnapshot$Builder", self.object, "notifier", "(Ljava/util/UUID;)Lorg/spongepowered/api/block/BlockSnapshot$Builder;", CallObjectMethodA, uuid);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockSnapshot$Builder#notifier was null") }
+      ::sponge::block::block_snapshot::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod block_state {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/BlockState$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn block_type(&self, block_type: ::sponge::block::BlockType) -> ::sponge::block::block_state::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/block/BlockState$Builder", self.object, "blockType", "(Lorg/spongepowered/api/block/BlockType;)Lorg/spongepowered/api/block/BlockState$Builder;", CallObjectMethodA, block_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/block/BlockState$Builder#blockType was null") }
+      ::sponge::block::block_state::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,264 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Beacon {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Beacon {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Beacon {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Beacon`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Beacon", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_completed_levels(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Beacon", self.object, "getCompletedLevels", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_beacon_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::BeaconData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Beacon", self.object, "getBeaconData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Beacon#getBeaconData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::BeaconData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn primary_effect(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Beacon", self.object, "primaryEffect", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Beacon#primaryEffect was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn secondary_effect(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Beacon", self.object, "secondaryEffect", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Beacon#secondaryEffect was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BrewingStand {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrewingStand {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrewingStand {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrewingStand`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/BrewingStand", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn brew(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/BrewingStand", self.object, "brew", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Chest {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Chest {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Chest {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Chest`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Chest", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Dispenser {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dispenser {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dispenser {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dispenser`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Dispenser", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Dropper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dropper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dropper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dropper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Dropper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Furnace {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Furnace {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Furnace {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Furnace`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Furnace", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn smelt(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "smelt", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_furnace_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::FurnaceData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "getFurnaceData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Furnace#getFurnaceData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::FurnaceData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn passed_burn_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "passedBurnTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Furnace#passedBurnTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn max_burn_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "maxBurnTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Furnace#maxBurnTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn passed_cook_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "passedCookTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Furnace#passedCookTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn max_cook_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Furnace", self.object, "maxCookTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/Furnace#maxCookTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Hopper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hopper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hopper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hopper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/Hopper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn transfer_item(&self) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/Hopper", self.object, "transferItem", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TileEntityCarrier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntityCarrier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntityCarrier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntityCarrier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/carrier/TileEntityCarrier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_inventory(&self) -> ::sponge::item::inventory::type_::TileEntityInventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/carrier/TileEntityCarrier", self.object, "getInventory", "()Lorg/spongepowered/api/item/inventory/type/TileEntityInventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/carrier/TileEntityCarrier#getInventory was null") }
+    ::sponge::item::inventory::type_::TileEntityInventory { env: self.env, object: ret }
+  }
+
+}
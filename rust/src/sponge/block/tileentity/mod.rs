@@ -0,0 +1,578 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod carrier;
+
+#[derive(Debug)]
+pub struct Banner {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Banner {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Banner {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Banner`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Banner", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_banner_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::BannerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Banner", self.object, "getBannerData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/BannerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Banner#getBannerData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::BannerData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn base_color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Banner", self.object, "baseColor", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Banner#baseColor was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn patterns_list(&self) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Banner", self.object, "patternsList", "()Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Banner#patternsList was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandBlock {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandBlock {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandBlock {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandBlock`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/CommandBlock", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn execute(&self) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/CommandBlock", self.object, "execute", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Comparator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Comparator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Comparator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Comparator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Comparator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DaylightDetector {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DaylightDetector {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DaylightDetector {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DaylightDetector`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/DaylightDetector", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnchantmentTable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnchantmentTable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnchantmentTable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnchantmentTable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/EnchantmentTable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EndPortal {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EndPortal {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EndPortal {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EndPortal`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/EndPortal", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnderChest {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnderChest {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnderChest {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnderChest`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/EnderChest", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FlowerPot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FlowerPot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FlowerPot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FlowerPot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/FlowerPot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Jukebox {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Jukebox {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Jukebox {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Jukebox`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Jukebox", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn play_record(&self) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/Jukebox", self.object, "playRecord", "()V", CallVoidMethod);
+  }
+
+  pub fn eject_record(&self) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/Jukebox", self.object, "ejectRecord", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "item")]
+  pub fn insert_record(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/Jukebox", self.object, "insertRecord", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MobSpawner {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MobSpawner {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MobSpawner {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MobSpawner`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/MobSpawner", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn spawn_entity_batch_immediately(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "spawnEntityBatchImmediately", "(Z)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_mob_spawner_data(&self) -> ::sponge::data::manipulator::mutable::MobSpawnerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "getMobSpawnerData", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#getMobSpawnerData was null") }
+    ::sponge::data::manipulator::mutable::MobSpawnerData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remaining_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "remainingDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#remainingDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn minimum_spawn_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "minimumSpawnDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#minimumSpawnDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn maximum_spawn_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "maximumSpawnDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#maximumSpawnDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn spawn_count(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "spawnCount", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#spawnCount was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn maximum_nearby_entities(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "maximumNearbyEntities", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#maximumNearbyEntities was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn required_player_range(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "requiredPlayerRange", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#requiredPlayerRange was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn spawn_range(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "spawnRange", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#spawnRange was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn next_entity_to_spawn(&self) -> ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "nextEntityToSpawn", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#nextEntityToSpawn was null") }
+    ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn possible_entities_to_spawn(&self) -> ::sponge::data::value::mutable::WeightedCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/MobSpawner", self.object, "possibleEntitiesToSpawn", "()Lorg/spongepowered/api/data/value/mutable/WeightedCollectionValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/MobSpawner#possibleEntitiesToSpawn was null") }
+    ::sponge::data::value::mutable::WeightedCollectionValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Note {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Note {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Note {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Note`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Note", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn play_note(&self) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/Note", self.object, "playNote", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_note_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::NoteData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Note", self.object, "getNoteData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/NoteData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Note#getNoteData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::NoteData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn note(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Note", self.object, "note", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Note#note was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Piston {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Piston {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Piston {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Piston`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Piston", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Sign {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Sign {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Sign {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Sign`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Sign", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_sign_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::SignData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Sign", self.object, "getSignData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/SignData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Sign#getSignData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::SignData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn lines(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Sign", self.object, "lines", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Sign#lines was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Skull {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Skull {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Skull {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Skull`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/Skull", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_skull_data(&self) -> ::sponge::data::manipulator::mutable::SkullData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Skull", self.object, "getSkullData", "()Lorg/spongepowered/api/data/manipulator/mutable/SkullData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Skull#getSkullData was null") }
+    ::sponge::data::manipulator::mutable::SkullData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn skull_type(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/Skull", self.object, "skullType", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/Skull#skullType was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TileEntity {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntity {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntity {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntity`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/TileEntity", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_valid(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/TileEntity", self.object, "isValid", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_valid(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/block/tileentity/TileEntity", self.object, "setValid", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_type(&self) -> ::sponge::block::tileentity::TileEntityType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/TileEntity", self.object, "getType", "()Lorg/spongepowered/api/block/tileentity/TileEntityType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/TileEntity#getType was null") }
+    ::sponge::block::tileentity::TileEntityType { env: self.env, object: ret }
+  }
+
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/TileEntity", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/TileEntity#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TileEntityType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntityType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntityType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntityType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/TileEntityType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_tile_entity_type(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/tileentity/TileEntityType", self.object, "getTileEntityType", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/tileentity/TileEntityType#getTileEntityType was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TileEntityTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntityTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntityTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntityTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/tileentity/TileEntityTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
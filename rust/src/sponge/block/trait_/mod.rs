@@ -0,0 +1,186 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BlockTrait {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockTrait {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockTrait {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockTrait`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/BlockTrait", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/trait/BlockTrait", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/trait/BlockTrait#getName was null") }
+    ret
+  }
+
+  pub fn get_possible_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/trait/BlockTrait", self.object, "getPossibleValues", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/trait/BlockTrait#getPossibleValues was null") }
+    ret
+  }
+
+  pub fn get_value_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/trait/BlockTrait", self.object, "getValueClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/trait/BlockTrait#getValueClass was null") }
+    ret
+  }
+
+  pub fn get_predicate(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/block/trait/BlockTrait", self.object, "getPredicate", "()Ljava/util/function/Predicate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/block/trait/BlockTrait#getPredicate was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BooleanTrait {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BooleanTrait {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BooleanTrait {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BooleanTrait`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/BooleanTrait", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BooleanTraits {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BooleanTraits {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BooleanTraits {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BooleanTraits`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/BooleanTraits", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnumTrait {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnumTrait {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnumTrait {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnumTrait`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/EnumTrait", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnumTraits {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnumTraits {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnumTraits {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnumTraits`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/EnumTraits", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IntegerTrait {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IntegerTrait {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IntegerTrait {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IntegerTrait`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/IntegerTrait", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IntegerTraits {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IntegerTraits {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IntegerTraits {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IntegerTraits`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/block/trait/IntegerTraits", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,378 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BossBar {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BossBar {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BossBar {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BossBar`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/BossBar", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "getName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#getName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_name(&self, text: ::sponge::text::Text) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setName was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn get_percent(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "getPercent", "()F", CallFloatMethod)
+  }
+
+  pub fn set_percent(&self, value: f32) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setPercent", "(F)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setPercent was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn get_color(&self) -> ::sponge::boss::BossBarColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "getColor", "()Lorg/spongepowered/api/boss/BossBarColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#getColor was null") }
+    ::sponge::boss::BossBarColor { env: self.env, object: ret }
+  }
+
+  pub fn set_color(&self, boss_bar_color: ::sponge::boss::BossBarColor) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setColor", "(Lorg/spongepowered/api/boss/BossBarColor;)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, boss_bar_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setColor was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn get_overlay(&self) -> ::sponge::boss::BossBarOverlay {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "getOverlay", "()Lorg/spongepowered/api/boss/BossBarOverlay;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#getOverlay was null") }
+    ::sponge::boss::BossBarOverlay { env: self.env, object: ret }
+  }
+
+  pub fn set_overlay(&self, boss_bar_overlay: ::sponge::boss::BossBarOverlay) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setOverlay", "(Lorg/spongepowered/api/boss/BossBarOverlay;)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, boss_bar_overlay.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setOverlay was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn should_darken_sky(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "shouldDarkenSky", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_darken_sky(&self, value: bool) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setDarkenSky", "(Z)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setDarkenSky was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn should_play_end_boss_music(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "shouldPlayEndBossMusic", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_play_end_boss_music(&self, value: bool) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setPlayEndBossMusic", "(Z)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setPlayEndBossMusic was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+  pub fn should_create_fog(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "shouldCreateFog", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_create_fog(&self, value: bool) -> ::sponge::boss::BossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/BossBar", self.object, "setCreateFog", "(Z)Lorg/spongepowered/api/boss/BossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/BossBar#setCreateFog was null") }
+    ::sponge::boss::BossBar { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BossBarColor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BossBarColor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BossBarColor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BossBarColor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/BossBarColor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BossBarColors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BossBarColors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BossBarColors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BossBarColors`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/BossBarColors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BossBarOverlay {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BossBarOverlay {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BossBarOverlay {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BossBarOverlay`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/BossBarOverlay", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BossBarOverlays {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BossBarOverlays {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BossBarOverlays {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BossBarOverlays`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/BossBarOverlays", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ServerBossBar {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ServerBossBar {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ServerBossBar {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ServerBossBar`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/ServerBossBar", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::boss::server_boss_bar::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/boss/ServerBossBar", "builder", "()Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#builder was null") }
+    ::sponge::boss::server_boss_bar::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_name(&self, text: ::sponge::text::Text) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setName was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_percent(&self, value: f32) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setPercent", "(F)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setPercent was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_color(&self, boss_bar_color: ::sponge::boss::BossBarColor) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setColor", "(Lorg/spongepowered/api/boss/BossBarColor;)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, boss_bar_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setColor was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_overlay(&self, boss_bar_overlay: ::sponge::boss::BossBarOverlay) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setOverlay", "(Lorg/spongepowered/api/boss/BossBarOverlay;)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, boss_bar_overlay.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setOverlay was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_darken_sky(&self, value: bool) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setDarkenSky", "(Z)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setDarkenSky was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_play_end_boss_music(&self, value: bool) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setPlayEndBossMusic", "(Z)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setPlayEndBossMusic was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn set_create_fog(&self, value: bool) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setCreateFog", "(Z)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setCreateFog was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn is_visible(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "isVisible", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_visible(&self, value: bool) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "setVisible", "(Z)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#setVisible was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  pub fn get_players(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "getPlayers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#getPlayers was null") }
+    ret
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn add_player(&self, player: ::sponge::entity::living::player::Player) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "addPlayer", "(Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#addPlayer was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn remove_player(&self, player: ::sponge::entity::living::player::Player) -> ::sponge::boss::ServerBossBar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar", self.object, "removePlayer", "(Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethodA, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar#removePlayer was null") }
+    ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+  }
+
+}
+
+pub mod server_boss_bar {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/boss/ServerBossBar$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn name(&self, text: ::sponge::text::Text) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "name", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#name was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn percent(&self, value: f32) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "percent", "(F)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#percent was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, boss_bar_color: ::sponge::boss::BossBarColor) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "color", "(Lorg/spongepowered/api/boss/BossBarColor;)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, boss_bar_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#color was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn overlay(&self, boss_bar_overlay: ::sponge::boss::BossBarOverlay) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "overlay", "(Lorg/spongepowered/api/boss/BossBarOverlay;)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, boss_bar_overlay.object);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#overlay was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn darken_sky(&self, value: bool) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "darkenSky", "(Z)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#darkenSky was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn play_end_boss_music(&self, value: bool) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "playEndBossMusic", "(Z)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#playEndBossMusic was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn create_fog(&self, value: bool) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "createFog", "(Z)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#createFog was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn visible(&self, value: bool) -> ::sponge::boss::server_boss_bar::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "visible", "(Z)Lorg/spongepowered/api/boss/ServerBossBar$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#visible was null") }
+      ::sponge::boss::server_boss_bar::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::boss::ServerBossBar {
+      let ret = bound_method!(self.env, "org/spongepowered/api/boss/ServerBossBar$Builder", self.object, "build", "()Lorg/spongepowered/api/boss/ServerBossBar;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/boss/ServerBossBar$Builder#build was null") }
+      ::sponge::boss::ServerBossBar { env: self.env, object: ret }
+    }
+
+  }
+}
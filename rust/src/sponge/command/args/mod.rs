@@ -0,0 +1,1596 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod parsing;
+
+#[derive(Debug)]
+pub struct ArgUtils {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArgUtils {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArgUtils {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArgUtils`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/ArgUtils", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn text_to_arg_key(env: *mut JNIEnv, text: ::sponge::text::Text) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/ArgUtils", "textToArgKey", "(Lorg/spongepowered/api/text/Text;)Ljava/lang/String;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgUtils#textToArgKey was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArgumentParseException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArgumentParseException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArgumentParseException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ArgumentParseException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/ArgumentParseException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_string_i32(env: *mut JNIEnv, text: ::sponge::text::Text, string: jobject, value: i32) -> ::sponge::command::args::ArgumentParseException {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/ArgumentParseException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/String;I)V", text.object, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgumentParseException#<init> was null") }
+    ::sponge::command::args::ArgumentParseException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_throwable_string_i32(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject, string: jobject, value: i32) -> ::sponge::command::args::ArgumentParseException {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/ArgumentParseException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;Ljava/lang/String;I)V", text.object, throwable, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgumentParseException#<init> was null") }
+    ::sponge::command::args::ArgumentParseException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ArgumentParseException", self.object, "getText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgumentParseException#getText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_annotated_position(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ArgumentParseException", self.object, "getAnnotatedPosition", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgumentParseException#getAnnotatedPosition was null") }
+    ret
+  }
+
+  pub fn get_position(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/ArgumentParseException", self.object, "getPosition", "()I", CallIntMethod)
+  }
+
+  pub fn get_source_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ArgumentParseException", self.object, "getSourceString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ArgumentParseException#getSourceString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChildCommandElementExecutor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChildCommandElementExecutor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChildCommandElementExecutor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ChildCommandElementExecutor`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/ChildCommandElementExecutor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, command_executor: ::sponge::command::spec::CommandExecutor) -> ::sponge::command::args::ChildCommandElementExecutor {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", "(Lorg/spongepowered/api/command/spec/CommandExecutor;)V", command_executor.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ChildCommandElementExecutor#<init> was null") }
+    ::sponge::command::args::ChildCommandElementExecutor { env: env, object: ret }
+  }
+
+  pub fn register_command_callable_list(&self, command_callable: ::sponge::command::CommandCallable, list: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", self.object, "register", "(Lorg/spongepowered/api/command/CommandCallable;Ljava/util/List;)Ljava/util/Optional;", CallObjectMethodA, command_callable.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ChildCommandElementExecutor#register was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn register_command_callable_object_array(&self, command_callable: ::sponge::command::CommandCallable, objects: Vec<jobject>) -> Option<::sponge::command::CommandMapping> {
+    unimplemented!();
+  }
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ChildCommandElementExecutor#complete was null") }
+    ret
+  }
+
+  pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+  }
+
+  pub fn execute(&self, command_source: ::sponge::command::CommandSource, command_context: ::sponge::command::args::CommandContext) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", self.object, "execute", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandContext;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ChildCommandElementExecutor#execute was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/ChildCommandElementExecutor", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/ChildCommandElementExecutor#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandArgs {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandArgs {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandArgs {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CommandArgs`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/CommandArgs", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, list: jobject) -> ::sponge::command::args::CommandArgs {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/CommandArgs", "(Ljava/lang/String;Ljava/util/List;)V", string, list);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#<init> was null") }
+    ::sponge::command::args::CommandArgs { env: env, object: ret }
+  }
+
+  pub fn has_next(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "hasNext", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn peek(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "peek", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#peek was null") }
+    ret
+  }
+
+  pub fn next(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "next", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#next was null") }
+    ret
+  }
+
+  pub fn next_if_present(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "nextIfPresent", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#nextIfPresent was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn create_error(&self, text: ::sponge::text::Text) -> ::sponge::command::args::ArgumentParseException {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "createError", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/ArgumentParseException;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#createError was null") }
+    ::sponge::command::args::ArgumentParseException { env: self.env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "getAll", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#getAll was null") }
+    ret
+  }
+
+  pub fn get_state(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "getState", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#getState was null") }
+    ret
+  }
+
+  pub fn set_state(&self, object: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "setState", "(Ljava/lang/Object;)V", CallVoidMethodA, object);
+  }
+
+  pub fn get_raw(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "getRaw", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandArgs#getRaw was null") }
+    ret
+  }
+
+  pub fn insert_arg(&self, string: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "insertArg", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+  }
+
+  pub fn remove_args(&self, object: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "removeArgs", "(Ljava/lang/Object;Ljava/lang/Object;)V", CallVoidMethodA, object, object_2);
+  }
+
+  pub fn get_raw_position(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandArgs", self.object, "getRawPosition", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandContext {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandContext {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandContext {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CommandContext`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/CommandContext", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::command::args::CommandContext {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/CommandContext", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandContext#<init> was null") }
+    ::sponge::command::args::CommandContext { env: env, object: ret }
+  }
+
+  pub fn get_all_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "getAll", "(Ljava/lang/String;)Ljava/util/Collection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandContext#getAll was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_all_text(&self, text: ::sponge::text::Text) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "getAll", "(Lorg/spongepowered/api/text/Text;)Ljava/util/Collection;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandContext#getAll was null") }
+    ret
+  }
+
+  pub fn get_one_string(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "getOne", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandContext#getOne was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_one_text(&self, text: ::sponge::text::Text) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "getOne", "(Lorg/spongepowered/api/text/Text;)Ljava/util/Optional;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandContext#getOne was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn put_arg_string_object(&self, string: jobject, object: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "putArg", "(Ljava/lang/String;Ljava/lang/Object;)V", CallVoidMethodA, string, object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn put_arg_text_object(&self, text: ::sponge::text::Text, object: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "putArg", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Object;)V", CallVoidMethodA, text.object, object);
+  }
+
+  pub fn check_permission(&self, command_source: ::sponge::command::CommandSource, string: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "checkPermission", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)V", CallVoidMethodA, command_source.object, string);
+  }
+
+  pub fn has_any_string(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "hasAny", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn has_any_text(&self, text: ::sponge::text::Text) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandContext", self.object, "hasAny", "(Lorg/spongepowered/api/text/Text;)Z", CallBooleanMethodA, text.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandElement {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandElement {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandElement {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandElement`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/CommandElement", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_key(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandElement", self.object, "getKey", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandElement#getKey was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_untranslated_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandElement", self.object, "getUntranslatedKey", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandElement#getUntranslatedKey was null") }
+    ret
+  }
+
+  pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+  }
+
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandElement#complete was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandElement#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandFlags {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandFlags {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandFlags {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandFlags`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/CommandFlags", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+    bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags#complete was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GenericArguments {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GenericArguments {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GenericArguments {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GenericArguments`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn none(env: *mut JNIEnv) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "none", "()Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#none was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn player_or_source(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "playerOrSource", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#playerOrSource was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn player(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "player", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#player was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn user(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "user", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#user was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn user_or_source(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "userOrSource", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#userOrSource was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn world(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "world", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#world was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn dimension(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "dimension", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#dimension was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn vector_3d(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "vector3d", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#vector3d was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn location(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "location", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#location was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn cataloged_element(env: *mut JNIEnv, text: ::sponge::text::Text, class: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "catalogedElement", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Class;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#catalogedElement was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn plugin(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "plugin", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#plugin was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn flags(env: *mut JNIEnv) -> ::sponge::command::args::command_flags::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "flags", "()Lorg/spongepowered/api/command/args/CommandFlags$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#flags was null") }
+    ::sponge::command::args::command_flags::Builder { env: env, object: ret }
+  }
+
+  pub fn seq(env: *mut JNIEnv, command_elements: Vec<::sponge::command::args::CommandElement>) -> ::sponge::command::args::CommandElement {
+    unimplemented!();
+  }
+  #[cfg(feature = "text")]
+  pub fn choices_text_map(env: *mut JNIEnv, text: ::sponge::text::Text, map: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "choices", "(Lorg/spongepowered/api/text/Text;Ljava/util/Map;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, map);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#choices was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn choices_text_map_bool(env: *mut JNIEnv, text: ::sponge::text::Text, map: jobject, value: bool) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "choices", "(Lorg/spongepowered/api/text/Text;Ljava/util/Map;Z)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, map, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#choices was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn choices_text_supplier_function(env: *mut JNIEnv, text: ::sponge::text::Text, supplier: jobject, function: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "choices", "(Lorg/spongepowered/api/text/Text;Ljava/util/function/Supplier;Ljava/util/function/Function;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, supplier, function);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#choices was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn choices_text_supplier_function_bool(env: *mut JNIEnv, text: ::sponge::text::Text, supplier: jobject, function: jobject, value: bool) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "choices", "(Lorg/spongepowered/api/text/Text;Ljava/util/function/Supplier;Ljava/util/function/Function;Z)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, supplier, function, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#choices was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn first_parsing(env: *mut JNIEnv, command_elements: Vec<::sponge::command::args::CommandElement>) -> ::sponge::command::args::CommandElement {
+    unimplemented!();
+  }
+  pub fn optional_command_element(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "optional", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#optional was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn optional_command_element_object(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement, object: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "optional", "(Lorg/spongepowered/api/command/args/CommandElement;Ljava/lang/Object;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#optional was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn optional_weak_command_element(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "optionalWeak", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#optionalWeak was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn optional_weak_command_element_object(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement, object: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "optionalWeak", "(Lorg/spongepowered/api/command/args/CommandElement;Ljava/lang/Object;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#optionalWeak was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn repeated(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement, value: i32) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "repeated", "(Lorg/spongepowered/api/command/args/CommandElement;I)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#repeated was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn all_of(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "allOf", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#allOf was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn string(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "string", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#string was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn integer(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "integer", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#integer was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn long_num(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "longNum", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#longNum was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn double_num(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "doubleNum", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#doubleNum was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn bool(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "bool", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#bool was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn enum_value(env: *mut JNIEnv, text: ::sponge::text::Text, class: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "enumValue", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Class;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#enumValue was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn remaining_joined_strings(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "remainingJoinedStrings", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#remainingJoinedStrings was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn literal_text_object_array(env: *mut JNIEnv, text: ::sponge::text::Text, objects: Vec<jobject>) -> ::sponge::command::args::CommandElement {
+    unimplemented!();
+  }
+  #[cfg(feature = "text")]
+  pub fn literal_text_object_object_array(env: *mut JNIEnv, text: ::sponge::text::Text, object: jobject, objects: Vec<jobject>) -> ::sponge::command::args::CommandElement {
+    unimplemented!();
+  }
+  pub fn only_one(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "onlyOne", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#onlyOne was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  pub fn requiring_permission(env: *mut JNIEnv, command_element: ::sponge::command::args::CommandElement, string: jobject) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "requiringPermission", "(Lorg/spongepowered/api/command/args/CommandElement;Ljava/lang/String;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, command_element.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#requiringPermission was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn entity(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "entity", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#entity was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn entity_or_source(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::args::CommandElement {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/GenericArguments", "entityOrSource", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/CommandElement;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments#entityOrSource was null") }
+    ::sponge::command::args::CommandElement { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PatternMatchingCommandElement {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PatternMatchingCommandElement {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PatternMatchingCommandElement {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PatternMatchingCommandElement`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/PatternMatchingCommandElement", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/PatternMatchingCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/PatternMatchingCommandElement#complete was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SelectorCommandElement {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SelectorCommandElement {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SelectorCommandElement {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SelectorCommandElement`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/SelectorCommandElement", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/SelectorCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/SelectorCommandElement#complete was null") }
+    ret
+  }
+
+}
+
+pub mod command_flags {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/CommandFlags$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn flag(&self, objects: Vec<jobject>) -> ::sponge::command::args::command_flags::Builder {
+      unimplemented!();
+    }
+    pub fn permission_flag(&self, object: jobject, objects: Vec<jobject>) -> ::sponge::command::args::command_flags::Builder {
+      unimplemented!();
+    }
+    pub fn value_flag(&self, command_element: ::sponge::command::args::CommandElement, objects: Vec<jobject>) -> ::sponge::command::args::command_flags::Builder {
+      unimplemented!();
+    }
+    pub fn set_accepts_arbitrary_long_flags(&self, value: bool) -> ::sponge::command::args::command_flags::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags$Builder", self.object, "setAcceptsArbitraryLongFlags", "(Z)Lorg/spongepowered/api/command/args/CommandFlags$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags$Builder#setAcceptsArbitraryLongFlags was null") }
+      ::sponge::command::args::command_flags::Builder { env: self.env, object: ret }
+    }
+
+    pub fn set_unknown_long_flag_behavior(&self, unknown_flag_behavior: ::sponge::command::args::command_flags::UnknownFlagBehavior) -> ::sponge::command::args::command_flags::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags$Builder", self.object, "setUnknownLongFlagBehavior", "(Lorg/spongepowered/api/command/args/CommandFlags$UnknownFlagBehavior;)Lorg/spongepowered/api/command/args/CommandFlags$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&unknown_flag_behavior, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags$Builder#setUnknownLongFlagBehavior was null") }
+      ::sponge::command::args::command_flags::Builder { env: self.env, object: ret }
+    }
+
+    pub fn set_unknown_short_flag_behavior(&self, unknown_flag_behavior: ::sponge::command::args::command_flags::UnknownFlagBehavior) -> ::sponge::command::args::command_flags::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags$Builder", self.object, "setUnknownShortFlagBehavior", "(Lorg/spongepowered/api/command/args/CommandFlags$UnknownFlagBehavior;)Lorg/spongepowered/api/command/args/CommandFlags$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&unknown_flag_behavior, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags$Builder#setUnknownShortFlagBehavior was null") }
+      ::sponge::command::args::command_flags::Builder { env: self.env, object: ret }
+    }
+
+    pub fn set_anchor_flags(&self, value: bool) -> ::sponge::command::args::command_flags::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags$Builder", self.object, "setAnchorFlags", "(Z)Lorg/spongepowered/api/command/args/CommandFlags$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags$Builder#setAnchorFlags was null") }
+      ::sponge::command::args::command_flags::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build_with(&self, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::args::CommandElement {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/CommandFlags$Builder", self.object, "buildWith", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/args/CommandElement;", CallObjectMethodA, command_element.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/CommandFlags$Builder#buildWith was null") }
+      ::sponge::command::args::CommandElement { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum UnknownFlagBehavior {
+    Error,
+    AcceptNonvalue,
+    AcceptValue,
+    Ignore,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static UNKNOWN_FLAG_BEHAVIOR_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/command/args/CommandFlags$UnknownFlagBehavior", &["ERROR", "ACCEPT_NONVALUE", "ACCEPT_VALUE", "IGNORE"]);
+
+  impl UnknownFlagBehavior {
+    pub const VALUES: &'static [UnknownFlagBehavior] = &[UnknownFlagBehavior::Error, UnknownFlagBehavior::AcceptNonvalue, UnknownFlagBehavior::AcceptValue, UnknownFlagBehavior::Ignore];
+
+  }
+
+  impl ::convert::FromJava for UnknownFlagBehavior {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match UNKNOWN_FLAG_BEHAVIOR_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => UnknownFlagBehavior::VALUES[ordinal],
+        None => UnknownFlagBehavior::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for UnknownFlagBehavior {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        UnknownFlagBehavior::Other(object) => object,
+        value => UNKNOWN_FLAG_BEHAVIOR_CONSTANTS.get(env, UnknownFlagBehavior::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
+
+pub mod generic_arguments {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct AllOfCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl AllOfCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      AllOfCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `AllOfCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$AllOfCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct CatalogedTypeCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl CatalogedTypeCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      CatalogedTypeCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogedTypeCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$CatalogedTypeCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ChoicesCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ChoicesCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ChoicesCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChoicesCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse_value(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement", self.object, "parseValue", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;)Ljava/lang/Object;", CallObjectMethodA, command_source.object, command_args.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement#parseValue was null") }
+      ret
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$ChoicesCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntityCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$EntityCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$EntityCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$EntityCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EnumValueElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EnumValueElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EnumValueElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnumValueElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$EnumValueElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct FirstParsingCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl FirstParsingCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      FirstParsingCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `FirstParsingCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$FirstParsingCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct KeyElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl KeyElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      KeyElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `KeyElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$KeyElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$KeyElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$KeyElement#complete was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct LiteralCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl LiteralCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      LiteralCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `LiteralCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$LiteralCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$LiteralCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$LiteralCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$LiteralCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$LiteralCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct LocationCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl LocationCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      LocationCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `LocationCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$LocationCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$LocationCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$LocationCommandElement#complete was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct MarkTrueCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl MarkTrueCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      MarkTrueCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `MarkTrueCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$MarkTrueCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::command::args::generic_arguments::MarkTrueCommandElement {
+      let ret = new_object!(env, "org/spongepowered/api/command/args/GenericArguments$MarkTrueCommandElement", "(Ljava/lang/String;)V", string);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$MarkTrueCommandElement#<init> was null") }
+      ::sponge::command::args::generic_arguments::MarkTrueCommandElement { env: env, object: ret }
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$MarkTrueCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$MarkTrueCommandElement#complete was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct NumericElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl NumericElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      NumericElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `NumericElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$NumericElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse_value(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$NumericElement", self.object, "parseValue", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;)Ljava/lang/Object;", CallObjectMethodA, command_source.object, command_args.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$NumericElement#parseValue was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct OnlyOneCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OnlyOneCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OnlyOneCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OnlyOneCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$OnlyOneCommandElement#complete was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct OptionalCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OptionalCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OptionalCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OptionalCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$OptionalCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PermissionCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PermissionCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PermissionCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PermissionCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement#complete was null") }
+      ret
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$PermissionCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PlayerCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PlayerCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PlayerCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$PlayerCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$PlayerCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$PlayerCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PluginCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PluginCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PluginCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PluginCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$PluginCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct RemainingJoinedStringsCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl RemainingJoinedStringsCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      RemainingJoinedStringsCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `RemainingJoinedStringsCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$RemainingJoinedStringsCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$RemainingJoinedStringsCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$RemainingJoinedStringsCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct RepeatedCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl RepeatedCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      RepeatedCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `RepeatedCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$RepeatedCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct SequenceCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl SequenceCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      SequenceCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `SequenceCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+      bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement", self.object, "parse", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement#complete was null") }
+      ret
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$SequenceCommandElement#getUsage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct StringElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl StringElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      StringElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `StringElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$StringElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse_value(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$StringElement", self.object, "parseValue", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;)Ljava/lang/Object;", CallObjectMethodA, command_source.object, command_args.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$StringElement#parseValue was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct UserCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl UserCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      UserCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `UserCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$UserCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Vector3dCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Vector3dCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Vector3dCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Vector3dCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$Vector3dCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$Vector3dCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$Vector3dCommandElement#complete was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct WorldPropertiesCommandElement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl WorldPropertiesCommandElement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      WorldPropertiesCommandElement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldPropertiesCommandElement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/GenericArguments$WorldPropertiesCommandElement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn parse_value(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$WorldPropertiesCommandElement", self.object, "parseValue", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;)Ljava/lang/Object;", CallObjectMethodA, command_source.object, command_args.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$WorldPropertiesCommandElement#parseValue was null") }
+      ret
+    }
+
+    pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/args/GenericArguments$WorldPropertiesCommandElement", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/args/GenericArguments$WorldPropertiesCommandElement#complete was null") }
+      ret
+    }
+
+  }
+}
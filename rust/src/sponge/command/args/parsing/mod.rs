@@ -0,0 +1,248 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct InputTokenizer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InputTokenizer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InputTokenizer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InputTokenizer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/InputTokenizer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn quoted_strings(env: *mut JNIEnv, value: bool) -> ::sponge::command::args::parsing::InputTokenizer {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/parsing/InputTokenizer", "quotedStrings", "(Z)Lorg/spongepowered/api/command/args/parsing/InputTokenizer;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/InputTokenizer#quotedStrings was null") }
+    ::sponge::command::args::parsing::InputTokenizer { env: env, object: ret }
+  }
+
+  pub fn space_split_string(env: *mut JNIEnv) -> ::sponge::command::args::parsing::InputTokenizer {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/parsing/InputTokenizer", "spaceSplitString", "()Lorg/spongepowered/api/command/args/parsing/InputTokenizer;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/InputTokenizer#spaceSplitString was null") }
+    ::sponge::command::args::parsing::InputTokenizer { env: env, object: ret }
+  }
+
+  pub fn raw_input(env: *mut JNIEnv) -> ::sponge::command::args::parsing::InputTokenizer {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/args/parsing/InputTokenizer", "rawInput", "()Lorg/spongepowered/api/command/args/parsing/InputTokenizer;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/InputTokenizer#rawInput was null") }
+    ::sponge::command::args::parsing::InputTokenizer { env: env, object: ret }
+  }
+
+  pub fn tokenize(&self, string: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/InputTokenizer", self.object, "tokenize", "(Ljava/lang/String;Z)Ljava/util/List;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/InputTokenizer#tokenize was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct QuotedStringTokenizer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl QuotedStringTokenizer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    QuotedStringTokenizer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `QuotedStringTokenizer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/QuotedStringTokenizer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn tokenize(&self, string: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/QuotedStringTokenizer", self.object, "tokenize", "(Ljava/lang/String;Z)Ljava/util/List;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/QuotedStringTokenizer#tokenize was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RawStringInputTokenizer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RawStringInputTokenizer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RawStringInputTokenizer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RawStringInputTokenizer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/RawStringInputTokenizer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn tokenize(&self, string: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/RawStringInputTokenizer", self.object, "tokenize", "(Ljava/lang/String;Z)Ljava/util/List;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/RawStringInputTokenizer#tokenize was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SingleArg {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SingleArg {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SingleArg {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SingleArg`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/SingleArg", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, value: i32, value_2: i32) -> ::sponge::command::args::parsing::SingleArg {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/parsing/SingleArg", "(Ljava/lang/String;II)V", string, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/SingleArg#<init> was null") }
+    ::sponge::command::args::parsing::SingleArg { env: env, object: ret }
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "getValue", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/SingleArg#getValue was null") }
+    ret
+  }
+
+  pub fn get_start_idx(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "getStartIdx", "()I", CallIntMethod)
+  }
+
+  pub fn get_end_idx(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "getEndIdx", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SingleArg", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/SingleArg#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpaceSplitInputTokenizer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpaceSplitInputTokenizer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpaceSplitInputTokenizer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpaceSplitInputTokenizer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/SpaceSplitInputTokenizer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn tokenize(&self, string: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/SpaceSplitInputTokenizer", self.object, "tokenize", "(Ljava/lang/String;Z)Ljava/util/List;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/SpaceSplitInputTokenizer#tokenize was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TokenizerState {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TokenizerState {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TokenizerState {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TokenizerState`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/args/parsing/TokenizerState", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, value: bool) -> ::sponge::command::args::parsing::TokenizerState {
+    let ret = new_object!(env, "org/spongepowered/api/command/args/parsing/TokenizerState", "(Ljava/lang/String;Z)V", string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/TokenizerState#<init> was null") }
+    ::sponge::command::args::parsing::TokenizerState { env: env, object: ret }
+  }
+
+  pub fn has_more(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "hasMore", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn peek(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "peek", "()I", CallIntMethod)
+  }
+
+  pub fn next(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "next", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn create_exception(&self, text: ::sponge::text::Text) -> ::sponge::command::args::ArgumentParseException {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "createException", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/args/ArgumentParseException;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/args/parsing/TokenizerState#createException was null") }
+    ::sponge::command::args::ArgumentParseException { env: self.env, object: ret }
+  }
+
+  pub fn is_lenient(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "isLenient", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_index(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/args/parsing/TokenizerState", self.object, "getIndex", "()I", CallIntMethod)
+  }
+
+}
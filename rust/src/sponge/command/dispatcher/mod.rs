@@ -0,0 +1,275 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Disambiguator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Disambiguator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Disambiguator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Disambiguator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/dispatcher/Disambiguator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn disambiguate(&self, command_source: ::sponge::command::CommandSource, string: jobject, list: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Disambiguator", self.object, "disambiguate", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;Ljava/util/List;)Ljava/util/Optional;", CallObjectMethodA, command_source.object, string, list);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Disambiguator#disambiguate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Dispatcher {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dispatcher {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dispatcher {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dispatcher`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/dispatcher/Dispatcher", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_commands(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "getCommands", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#getCommands was null") }
+    ret
+  }
+
+  pub fn get_primary_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "getPrimaryAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#getPrimaryAliases was null") }
+    ret
+  }
+
+  pub fn get_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "getAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#getAliases was null") }
+    ret
+  }
+
+  pub fn get_string(&self, string: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "get", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_string_command_source(&self, string: jobject, command_source: ::sponge::command::CommandSource) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "get", "(Ljava/lang/String;Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, string, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_all_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "getAll", "(Ljava/lang/String;)Ljava/util/Set;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#getAll was null") }
+    ret
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "getAll", "()Lcom/google/common/collect/Multimap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/Dispatcher#getAll was null") }
+    ret
+  }
+
+  pub fn contains_alias(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "containsAlias", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn contains_mapping(&self, command_mapping: ::sponge::command::CommandMapping) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/Dispatcher", self.object, "containsMapping", "(Lorg/spongepowered/api/command/CommandMapping;)Z", CallBooleanMethodA, command_mapping.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SimpleDispatcher {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SimpleDispatcher {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SimpleDispatcher {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SimpleDispatcher`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::command::dispatcher::SimpleDispatcher {
+    let ret = new_object!(env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#<init> was null") }
+    ::sponge::command::dispatcher::SimpleDispatcher { env: env, object: ret }
+  }
+
+  pub fn new_disambiguator(env: *mut JNIEnv, disambiguator: ::sponge::command::dispatcher::Disambiguator) -> ::sponge::command::dispatcher::SimpleDispatcher {
+    let ret = new_object!(env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", "(Lorg/spongepowered/api/command/dispatcher/Disambiguator;)V", disambiguator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#<init> was null") }
+    ::sponge::command::dispatcher::SimpleDispatcher { env: env, object: ret }
+  }
+
+  pub fn register_command_callable_object_array(&self, command_callable: ::sponge::command::CommandCallable, objects: Vec<jobject>) -> Option<::sponge::command::CommandMapping> {
+    unimplemented!();
+  }
+  pub fn register_command_callable_list(&self, command_callable: ::sponge::command::CommandCallable, list: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "register", "(Lorg/spongepowered/api/command/CommandCallable;Ljava/util/List;)Ljava/util/Optional;", CallObjectMethodA, command_callable.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#register was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn register_command_callable_list_function(&self, command_callable: ::sponge::command::CommandCallable, list: jobject, function: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "register", "(Lorg/spongepowered/api/command/CommandCallable;Ljava/util/List;Ljava/util/function/Function;)Ljava/util/Optional;", CallObjectMethodA, command_callable.object, list, function);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#register was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn remove(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "remove", "(Ljava/lang/String;)Ljava/util/Collection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#remove was null") }
+    ret
+  }
+
+  pub fn remove_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "removeAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove_mapping(&self, command_mapping: ::sponge::command::CommandMapping) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "removeMapping", "(Lorg/spongepowered/api/command/CommandMapping;)Ljava/util/Optional;", CallObjectMethodA, command_mapping.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#removeMapping was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn remove_mappings(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "removeMappings", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn get_commands(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getCommands", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getCommands was null") }
+    ret
+  }
+
+  pub fn get_primary_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getPrimaryAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getPrimaryAliases was null") }
+    ret
+  }
+
+  pub fn get_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getAliases was null") }
+    ret
+  }
+
+  pub fn get_string(&self, string: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "get", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_string_command_source(&self, string: jobject, command_source: ::sponge::command::CommandSource) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "get", "(Ljava/lang/String;Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, string, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn contains_alias(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "containsAlias", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn contains_mapping(&self, command_mapping: ::sponge::command::CommandMapping) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "containsMapping", "(Lorg/spongepowered/api/command/CommandMapping;)Z", CallBooleanMethodA, command_mapping.object) != 0
+  }
+
+  pub fn process(&self, command_source: ::sponge::command::CommandSource, string: jobject) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "process", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#process was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_suggestions(&self, command_source: ::sponge::command::CommandSource, string: jobject, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getSuggestions", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;Lorg/spongepowered/api/world/Location;)Ljava/util/List;", CallObjectMethodA, command_source.object, string, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getSuggestions was null") }
+    ret
+  }
+
+  pub fn test_permission(&self, command_source: ::sponge::command::CommandSource) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "testPermission", "(Lorg/spongepowered/api/command/CommandSource;)Z", CallBooleanMethodA, command_source.object) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_short_description(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getShortDescription", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getShortDescription was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_help(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getHelp", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getHelp was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "size", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_all_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getAll", "(Ljava/lang/String;)Ljava/util/Set;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getAll was null") }
+    ret
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/dispatcher/SimpleDispatcher", self.object, "getAll", "()Lcom/google/common/collect/Multimap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/dispatcher/SimpleDispatcher#getAll was null") }
+    ret
+  }
+
+}
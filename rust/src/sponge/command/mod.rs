@@ -0,0 +1,631 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod args;
+pub mod dispatcher;
+pub mod source;
+pub mod spec;
+
+#[derive(Debug)]
+pub struct CommandCallable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandCallable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandCallable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandCallable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandCallable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn process(&self, command_source: ::sponge::command::CommandSource, string: jobject) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "process", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandCallable#process was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_suggestions(&self, command_source: ::sponge::command::CommandSource, string: jobject, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "getSuggestions", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;Lorg/spongepowered/api/world/Location;)Ljava/util/List;", CallObjectMethodA, command_source.object, string, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandCallable#getSuggestions was null") }
+    ret
+  }
+
+  pub fn test_permission(&self, command_source: ::sponge::command::CommandSource) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "testPermission", "(Lorg/spongepowered/api/command/CommandSource;)Z", CallBooleanMethodA, command_source.object) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_short_description(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "getShortDescription", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandCallable#getShortDescription was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_help(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "getHelp", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandCallable#getHelp was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandCallable", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandCallable#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CommandException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::CommandException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandException", "(Lorg/spongepowered/api/text/Text;)V", text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandException#<init> was null") }
+    ::sponge::command::CommandException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_throwable(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject) -> ::sponge::command::CommandException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;)V", text.object, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandException#<init> was null") }
+    ::sponge::command::CommandException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_bool(env: *mut JNIEnv, text: ::sponge::text::Text, value: bool) -> ::sponge::command::CommandException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandException", "(Lorg/spongepowered/api/text/Text;Z)V", text.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandException#<init> was null") }
+    ::sponge::command::CommandException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_throwable_bool(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject, value: bool) -> ::sponge::command::CommandException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;Z)V", text.object, throwable, value);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandException#<init> was null") }
+    ::sponge::command::CommandException { env: env, object: ret }
+  }
+
+  pub fn should_include_usage(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/CommandException", self.object, "shouldIncludeUsage", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_object_command_callable_object_array(&self, object: jobject, command_callable: ::sponge::command::CommandCallable, objects: Vec<jobject>) -> Option<::sponge::command::CommandMapping> {
+    unimplemented!();
+  }
+  pub fn register_object_command_callable_list(&self, object: jobject, command_callable: ::sponge::command::CommandCallable, list: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "register", "(Ljava/lang/Object;Lorg/spongepowered/api/command/CommandCallable;Ljava/util/List;)Ljava/util/Optional;", CallObjectMethodA, object, command_callable.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#register was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn register_object_command_callable_list_function(&self, object: jobject, command_callable: ::sponge::command::CommandCallable, list: jobject, function: jobject) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "register", "(Ljava/lang/Object;Lorg/spongepowered/api/command/CommandCallable;Ljava/util/List;Ljava/util/function/Function;)Ljava/util/Optional;", CallObjectMethodA, object, command_callable.object, list, function);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#register was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn remove_mapping(&self, command_mapping: ::sponge::command::CommandMapping) -> Option<::sponge::command::CommandMapping> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "removeMapping", "(Lorg/spongepowered/api/command/CommandMapping;)Ljava/util/Optional;", CallObjectMethodA, command_mapping.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#removeMapping was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandMapping { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_plugin_containers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "getPluginContainers", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#getPluginContainers was null") }
+    ret
+  }
+
+  pub fn get_owned_by(&self, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "getOwnedBy", "(Ljava/lang/Object;)Ljava/util/Set;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#getOwnedBy was null") }
+    ret
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_owner(&self, command_mapping: ::sponge::command::CommandMapping) -> Option<::sponge::plugin::PluginContainer> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "getOwner", "(Lorg/spongepowered/api/command/CommandMapping;)Ljava/util/Optional;", CallObjectMethodA, command_mapping.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#getOwner was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::plugin::PluginContainer { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn process(&self, command_source: ::sponge::command::CommandSource, string: jobject) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "process", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#process was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_suggestions(&self, command_source: ::sponge::command::CommandSource, string: jobject, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandManager", self.object, "getSuggestions", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;Lorg/spongepowered/api/world/Location;)Ljava/util/List;", CallObjectMethodA, command_source.object, string, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandManager#getSuggestions was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandMapping {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandMapping {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandMapping {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandMapping`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandMapping", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_primary_alias(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandMapping", self.object, "getPrimaryAlias", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandMapping#getPrimaryAlias was null") }
+    ret
+  }
+
+  pub fn get_all_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandMapping", self.object, "getAllAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandMapping#getAllAliases was null") }
+    ret
+  }
+
+  pub fn get_callable(&self) -> ::sponge::command::CommandCallable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandMapping", self.object, "getCallable", "()Lorg/spongepowered/api/command/CommandCallable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandMapping#getCallable was null") }
+    ::sponge::command::CommandCallable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandMessageFormatting {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandMessageFormatting {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandMessageFormatting {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandMessageFormatting`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandMessageFormatting", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn error(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/CommandMessageFormatting", "error", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandMessageFormatting#error was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn debug(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/CommandMessageFormatting", "debug", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandMessageFormatting#debug was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandNotFoundException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandNotFoundException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandNotFoundException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CommandNotFoundException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandNotFoundException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::command::CommandNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandNotFoundException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandNotFoundException#<init> was null") }
+    ::sponge::command::CommandNotFoundException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_string(env: *mut JNIEnv, text: ::sponge::text::Text, string: jobject) -> ::sponge::command::CommandNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandNotFoundException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/String;)V", text.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandNotFoundException#<init> was null") }
+    ::sponge::command::CommandNotFoundException { env: env, object: ret }
+  }
+
+  pub fn get_command(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandNotFoundException", self.object, "getCommand", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandNotFoundException#getCommand was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandPermissionException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandPermissionException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandPermissionException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CommandPermissionException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandPermissionException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::command::CommandPermissionException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandPermissionException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandPermissionException#<init> was null") }
+    ::sponge::command::CommandPermissionException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::command::CommandPermissionException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandPermissionException", "(Lorg/spongepowered/api/text/Text;)V", text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandPermissionException#<init> was null") }
+    ::sponge::command::CommandPermissionException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_throwable(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject) -> ::sponge::command::CommandPermissionException {
+    let ret = new_object!(env, "org/spongepowered/api/command/CommandPermissionException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;)V", text.object, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandPermissionException#<init> was null") }
+    ::sponge::command::CommandPermissionException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::command::command_result::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/CommandResult", "builder", "()Lorg/spongepowered/api/command/CommandResult$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#builder was null") }
+    ::sponge::command::command_result::Builder { env: env, object: ret }
+  }
+
+  pub fn empty(env: *mut JNIEnv) -> ::sponge::command::CommandResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/CommandResult", "empty", "()Lorg/spongepowered/api/command/CommandResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#empty was null") }
+    ::sponge::command::CommandResult { env: env, object: ret }
+  }
+
+  pub fn success(env: *mut JNIEnv) -> ::sponge::command::CommandResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/CommandResult", "success", "()Lorg/spongepowered/api/command/CommandResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#success was null") }
+    ::sponge::command::CommandResult { env: env, object: ret }
+  }
+
+  pub fn get_success_count(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult", self.object, "getSuccessCount", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#getSuccessCount was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_affected_blocks(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult", self.object, "getAffectedBlocks", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#getAffectedBlocks was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_affected_entities(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult", self.object, "getAffectedEntities", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#getAffectedEntities was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_affected_items(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult", self.object, "getAffectedItems", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#getAffectedItems was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_query_result(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult", self.object, "getQueryResult", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult#getQueryResult was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandSource", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandSource#getName was null") }
+    ret
+  }
+
+  pub fn get_locale(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandSource", self.object, "getLocale", "()Ljava/util/Locale;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/CommandSource#getLocale was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCommandMapping {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCommandMapping {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCommandMapping {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ImmutableCommandMapping`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/ImmutableCommandMapping", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_command_callable_object_object_array(env: *mut JNIEnv, command_callable: ::sponge::command::CommandCallable, object: jobject, objects: Vec<jobject>) -> ::sponge::command::ImmutableCommandMapping {
+    unimplemented!();
+  }
+  pub fn new_command_callable_string_collection(env: *mut JNIEnv, command_callable: ::sponge::command::CommandCallable, string: jobject, collection: jobject) -> ::sponge::command::ImmutableCommandMapping {
+    let ret = new_object!(env, "org/spongepowered/api/command/ImmutableCommandMapping", "(Lorg/spongepowered/api/command/CommandCallable;Ljava/lang/String;Ljava/util/Collection;)V", command_callable.object, string, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/command/ImmutableCommandMapping#<init> was null") }
+    ::sponge::command::ImmutableCommandMapping { env: env, object: ret }
+  }
+
+  pub fn get_primary_alias(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/ImmutableCommandMapping", self.object, "getPrimaryAlias", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/ImmutableCommandMapping#getPrimaryAlias was null") }
+    ret
+  }
+
+  pub fn get_all_aliases(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/ImmutableCommandMapping", self.object, "getAllAliases", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/ImmutableCommandMapping#getAllAliases was null") }
+    ret
+  }
+
+  pub fn get_callable(&self) -> ::sponge::command::CommandCallable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/ImmutableCommandMapping", self.object, "getCallable", "()Lorg/spongepowered/api/command/CommandCallable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/ImmutableCommandMapping#getCallable was null") }
+    ::sponge::command::CommandCallable { env: self.env, object: ret }
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/ImmutableCommandMapping", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/ImmutableCommandMapping#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InvocationCommandException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InvocationCommandException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InvocationCommandException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `InvocationCommandException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/InvocationCommandException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject) -> ::sponge::command::InvocationCommandException {
+    let ret = new_object!(env, "org/spongepowered/api/command/InvocationCommandException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;)V", text.object, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/command/InvocationCommandException#<init> was null") }
+    ::sponge::command::InvocationCommandException { env: env, object: ret }
+  }
+
+}
+
+pub mod command_result {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/CommandResult$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn success_count(&self, integer: jobject) -> ::sponge::command::command_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "successCount", "(Ljava/lang/Integer;)Lorg/spongepowered/api/command/CommandResult$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#successCount was null") }
+      ::sponge::command::command_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn affected_blocks(&self, integer: jobject) -> ::sponge::command::command_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "affectedBlocks", "(Ljava/lang/Integer;)Lorg/spongepowered/api/command/CommandResult$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#affectedBlocks was null") }
+      ::sponge::command::command_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn affected_entities(&self, integer: jobject) -> ::sponge::command::command_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "affectedEntities", "(Ljava/lang/Integer;)Lorg/spongepowered/api/command/CommandResult$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#affectedEntities was null") }
+      ::sponge::command::command_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn affected_items(&self, integer: jobject) -> ::sponge::command::command_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "affectedItems", "(Ljava/lang/Integer;)Lorg/spongepowered/api/command/CommandResult$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#affectedItems was null") }
+      ::sponge::command::command_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn query_result(&self, integer: jobject) -> ::sponge::command::command_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "queryResult", "(Ljava/lang/Integer;)Lorg/spongepowered/api/command/CommandResult$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#queryResult was null") }
+      ::sponge::command::command_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::command::CommandResult {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/CommandResult$Builder", self.object, "build", "()Lorg/spongepowered/api/command/CommandResult;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/command/CommandResult$Builder#build was null") }
+      ::sponge::command::CommandResult { env: self.env, object: ret }
+    }
+
+  }
+}
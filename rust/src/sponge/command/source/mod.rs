@@ -0,0 +1,202 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CommandBlockSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandBlockSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandBlockSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandBlockSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/CommandBlockSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_command_data(&self) -> ::sponge::data::manipulator::mutable::CommandData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/CommandBlockSource", self.object, "getCommandData", "()Lorg/spongepowered/api/data/manipulator/mutable/CommandData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/CommandBlockSource#getCommandData was null") }
+    ::sponge::data::manipulator::mutable::CommandData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn stored_command(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/CommandBlockSource", self.object, "storedCommand", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/CommandBlockSource#storedCommand was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn success_count(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/CommandBlockSource", self.object, "successCount", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/CommandBlockSource#successCount was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn does_track_output(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/CommandBlockSource", self.object, "doesTrackOutput", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/CommandBlockSource#doesTrackOutput was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn last_output(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/CommandBlockSource", self.object, "lastOutput", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/CommandBlockSource#lastOutput was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConsoleSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConsoleSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConsoleSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConsoleSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/ConsoleSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ProxySource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProxySource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProxySource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ProxySource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/ProxySource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_source(&self) -> ::sponge::command::CommandSource {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/ProxySource", self.object, "getOriginalSource", "()Lorg/spongepowered/api/command/CommandSource;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/ProxySource#getOriginalSource was null") }
+    ::sponge::command::CommandSource { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RconSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RconSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RconSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RconSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/RconSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_logged_in(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/source/RconSource", self.object, "getLoggedIn", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_logged_in(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/command/source/RconSource", self.object, "setLoggedIn", "(Z)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RemoteSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RemoteSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RemoteSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RemoteSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/RemoteSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "network")]
+  pub fn get_connection(&self) -> ::sponge::network::RemoteConnection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/RemoteSource", self.object, "getConnection", "()Lorg/spongepowered/api/network/RemoteConnection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/RemoteSource#getConnection was null") }
+    ::sponge::network::RemoteConnection { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SignSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SignSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SignSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SignSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/source/SignSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_sign(&self) -> ::sponge::block::tileentity::Sign {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/source/SignSource", self.object, "getSign", "()Lorg/spongepowered/api/block/tileentity/Sign;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/source/SignSource#getSign was null") }
+    ::sponge::block::tileentity::Sign { env: self.env, object: ret }
+  }
+
+}
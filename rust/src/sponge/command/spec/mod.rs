@@ -0,0 +1,222 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CommandExecutor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandExecutor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandExecutor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandExecutor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/spec/CommandExecutor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn execute(&self, command_source: ::sponge::command::CommandSource, command_context: ::sponge::command::args::CommandContext) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandExecutor", self.object, "execute", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandContext;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandExecutor#execute was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandSpec {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandSpec {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandSpec {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandSpec`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/spec/CommandSpec", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::command::spec::command_spec::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/command/spec/CommandSpec", "builder", "()Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#builder was null") }
+    ::sponge::command::spec::command_spec::Builder { env: env, object: ret }
+  }
+
+  pub fn check_permission(&self, command_source: ::sponge::command::CommandSource) {
+    bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "checkPermission", "(Lorg/spongepowered/api/command/CommandSource;)V", CallVoidMethodA, command_source.object);
+  }
+
+  pub fn populate_context(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) {
+    bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "populateContext", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)V", CallVoidMethodA, command_source.object, command_args.object, command_context.object);
+  }
+
+  pub fn complete(&self, command_source: ::sponge::command::CommandSource, command_args: ::sponge::command::args::CommandArgs, command_context: ::sponge::command::args::CommandContext) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "complete", "(Lorg/spongepowered/api/command/CommandSource;Lorg/spongepowered/api/command/args/CommandArgs;Lorg/spongepowered/api/command/args/CommandContext;)Ljava/util/List;", CallObjectMethodA, command_source.object, command_args.object, command_context.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#complete was null") }
+    ret
+  }
+
+  pub fn get_executor(&self) -> ::sponge::command::spec::CommandExecutor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getExecutor", "()Lorg/spongepowered/api/command/spec/CommandExecutor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getExecutor was null") }
+    ::sponge::command::spec::CommandExecutor { env: self.env, object: ret }
+  }
+
+  pub fn get_input_tokenizer(&self) -> ::sponge::command::args::parsing::InputTokenizer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getInputTokenizer", "()Lorg/spongepowered/api/command/args/parsing/InputTokenizer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getInputTokenizer was null") }
+    ::sponge::command::args::parsing::InputTokenizer { env: self.env, object: ret }
+  }
+
+  pub fn process(&self, command_source: ::sponge::command::CommandSource, string: jobject) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "process", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, command_source.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#process was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_suggestions(&self, command_source: ::sponge::command::CommandSource, string: jobject, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getSuggestions", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;Lorg/spongepowered/api/world/Location;)Ljava/util/List;", CallObjectMethodA, command_source.object, string, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getSuggestions was null") }
+    ret
+  }
+
+  pub fn test_permission(&self, command_source: ::sponge::command::CommandSource) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "testPermission", "(Lorg/spongepowered/api/command/CommandSource;)Z", CallBooleanMethodA, command_source.object) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_short_description(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getShortDescription", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getShortDescription was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_usage(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getUsage", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getUsage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_help(&self, command_source: ::sponge::command::CommandSource) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "getHelp", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Optional;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#getHelp was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec#toString was null") }
+    ret
+  }
+
+}
+
+pub mod command_spec {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/command/spec/CommandSpec$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn permission(&self, string: jobject) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "permission", "(Ljava/lang/String;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#permission was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn executor(&self, command_executor: ::sponge::command::spec::CommandExecutor) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "executor", "(Lorg/spongepowered/api/command/spec/CommandExecutor;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, command_executor.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#executor was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn children(&self, map: jobject) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "children", "(Ljava/util/Map;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, map);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#children was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn child(&self, command_callable: ::sponge::command::CommandCallable, objects: Vec<jobject>) -> ::sponge::command::spec::command_spec::Builder {
+      unimplemented!();
+    }
+    #[cfg(feature = "text")]
+    pub fn description(&self, text: ::sponge::text::Text) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "description", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#description was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn extended_description(&self, text: ::sponge::text::Text) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "extendedDescription", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#extendedDescription was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn arguments_command_element(&self, command_element: ::sponge::command::args::CommandElement) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "arguments", "(Lorg/spongepowered/api/command/args/CommandElement;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, command_element.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#arguments was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn arguments_command_element_array(&self, command_elements: Vec<::sponge::command::args::CommandElement>) -> ::sponge::command::spec::command_spec::Builder {
+      unimplemented!();
+    }
+    pub fn input_tokenizer(&self, input_tokenizer: ::sponge::command::args::parsing::InputTokenizer) -> ::sponge::command::spec::command_spec::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "inputTokenizer", "(Lorg/spongepowered/api/command/args/parsing/InputTokenizer;)Lorg/spongepowered/api/command/spec/CommandSpec$Builder;", CallObjectMethodA, input_tokenizer.object);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#inputTokenizer was null") }
+      ::sponge::command::spec::command_spec::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::command::spec::CommandSpec {
+      let ret = bound_method!(self.env, "org/spongepowered/api/command/spec/CommandSpec$Builder", self.object, "build", "()Lorg/spongepowered/api/command/spec/CommandSpec;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/command/spec/CommandSpec$Builder#build was null") }
+      ::sponge::command::spec::CommandSpec { env: self.env, object: ret }
+    }
+
+  }
+}
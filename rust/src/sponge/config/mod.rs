@@ -0,0 +1,131 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ConfigDir {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConfigDir {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConfigDir {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConfigDir`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/config/ConfigDir", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn shared_root(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/config/ConfigDir", self.object, "sharedRoot", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConfigManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConfigManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConfigManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConfigManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/config/ConfigManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_shared_config(&self, object: jobject) -> ::sponge::config::ConfigRoot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/config/ConfigManager", self.object, "getSharedConfig", "(Ljava/lang/Object;)Lorg/spongepowered/api/config/ConfigRoot;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/config/ConfigManager#getSharedConfig was null") }
+    ::sponge::config::ConfigRoot { env: self.env, object: ret }
+  }
+
+  pub fn get_plugin_config(&self, object: jobject) -> ::sponge::config::ConfigRoot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/config/ConfigManager", self.object, "getPluginConfig", "(Ljava/lang/Object;)Lorg/spongepowered/api/config/ConfigRoot;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/config/ConfigManager#getPluginConfig was null") }
+    ::sponge::config::ConfigRoot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConfigRoot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConfigRoot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConfigRoot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConfigRoot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/config/ConfigRoot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_config_path(&self) -> ::std::path::PathBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/config/ConfigRoot", self.object, "getConfigPath", "()Ljava/nio/file/Path;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/config/ConfigRoot#getConfigPath was null") }
+    <::std::path::PathBuf as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_config(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/config/ConfigRoot", self.object, "getConfig", "()Lninja/leaping/configurate/loader/ConfigurationLoader;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/config/ConfigRoot#getConfig was null") }
+    ret
+  }
+
+  pub fn get_directory(&self) -> ::std::path::PathBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/config/ConfigRoot", self.object, "getDirectory", "()Ljava/nio/file/Path;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/config/ConfigRoot#getDirectory was null") }
+    <::std::path::PathBuf as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DefaultConfig {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DefaultConfig {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DefaultConfig {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DefaultConfig`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/config/DefaultConfig", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn shared_root(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/config/DefaultConfig", self.object, "sharedRoot", "()Z", CallBooleanMethod) != 0
+  }
+
+}
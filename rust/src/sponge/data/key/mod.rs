@@ -0,0 +1,112 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Key {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Key {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Key {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Key`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/key/Key", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_value_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/key/Key", self.object, "getValueClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/Key#getValueClass was null") }
+    ret
+  }
+
+  pub fn get_query(&self) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/key/Key", self.object, "getQuery", "()Lorg/spongepowered/api/data/DataQuery;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/Key#getQuery was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct KeyFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl KeyFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    KeyFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `KeyFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/key/KeyFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn make_single_key(env: *mut JNIEnv, class: jobject, class_2: jobject, data_query: ::sponge::data::DataQuery) -> ::sponge::data::key::Key {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/key/KeyFactory", "makeSingleKey", "(Ljava/lang/Class;Ljava/lang/Class;Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/key/Key;", CallStaticObjectMethodA, class, class_2, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/KeyFactory#makeSingleKey was null") }
+    ::sponge::data::key::Key { env: env, object: ret }
+  }
+
+  pub fn make_list_key(env: *mut JNIEnv, class: jobject, data_query: ::sponge::data::DataQuery) -> ::sponge::data::key::Key {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/key/KeyFactory", "makeListKey", "(Ljava/lang/Class;Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/key/Key;", CallStaticObjectMethodA, class, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/KeyFactory#makeListKey was null") }
+    ::sponge::data::key::Key { env: env, object: ret }
+  }
+
+  pub fn make_set_key(env: *mut JNIEnv, class: jobject, data_query: ::sponge::data::DataQuery) -> ::sponge::data::key::Key {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/key/KeyFactory", "makeSetKey", "(Ljava/lang/Class;Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/key/Key;", CallStaticObjectMethodA, class, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/KeyFactory#makeSetKey was null") }
+    ::sponge::data::key::Key { env: env, object: ret }
+  }
+
+  pub fn make_map_key(env: *mut JNIEnv, class: jobject, class_2: jobject, data_query: ::sponge::data::DataQuery) -> ::sponge::data::key::Key {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/key/KeyFactory", "makeMapKey", "(Ljava/lang/Class;Ljava/lang/Class;Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/key/Key;", CallStaticObjectMethodA, class, class_2, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/KeyFactory#makeMapKey was null") }
+    ::sponge::data::key::Key { env: env, object: ret }
+  }
+
+  pub fn make_optional_key(env: *mut JNIEnv, class: jobject, data_query: ::sponge::data::DataQuery) -> ::sponge::data::key::Key {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/key/KeyFactory", "makeOptionalKey", "(Ljava/lang/Class;Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/key/Key;", CallStaticObjectMethodA, class, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/key/KeyFactory#makeOptionalKey was null") }
+    ::sponge::data::key::Key { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Keys {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Keys {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Keys {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Keys`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/key/Keys", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,93 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CatalogBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/catalog/CatalogBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogEntityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogEntityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogEntityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogEntityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/catalog/CatalogEntityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/catalog/CatalogItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogTileEntityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogTileEntityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogTileEntityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogTileEntityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/catalog/CatalogTileEntityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
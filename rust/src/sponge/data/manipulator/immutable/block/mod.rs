@@ -0,0 +1,1193 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableAttachedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAttachedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAttachedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAttachedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableAttachedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn attached(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableAttachedData", self.object, "attached", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableAttachedData#attached was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAxisData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAxisData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAxisData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAxisData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableAxisData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn axis(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableAxisData", self.object, "axis", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableAxisData#axis was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBigMushroomData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBigMushroomData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBigMushroomData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBigMushroomData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableBigMushroomData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBrickData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBrickData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBrickData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBrickData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableBrickData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableComparatorData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableComparatorData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableComparatorData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableComparatorData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableComparatorData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableConnectedDirectionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableConnectedDirectionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableConnectedDirectionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableConnectedDirectionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn connected_directions(&self) -> ::sponge::data::value::immutable::ImmutableSetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", self.object, "connectedDirections", "()Lorg/spongepowered/api/data/value/immutable/ImmutableSetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData#connectedDirections was null") }
+    ::sponge::data::value::immutable::ImmutableSetValue { env: self.env, object: ret }
+  }
+
+  pub fn connected_north(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", self.object, "connectedNorth", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData#connectedNorth was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn connected_south(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", self.object, "connectedSouth", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData#connectedSouth was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn connected_east(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", self.object, "connectedEast", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData#connectedEast was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn connected_west(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData", self.object, "connectedWest", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableConnectedDirectionData#connectedWest was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDecayableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDecayableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDecayableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDecayableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDecayableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn decayable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDecayableData", self.object, "decayable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableDecayableData#decayable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDelayableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDelayableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDelayableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDelayableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDelayableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn delay(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDelayableData", self.object, "delay", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableDelayableData#delay was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDirectionalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDirectionalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDirectionalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDirectionalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDirectionalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDirectionalData", self.object, "direction", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableDirectionalData#direction was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDirtData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDirtData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDirtData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDirtData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDirtData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDisarmedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDisarmedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDisarmedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDisarmedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDisarmedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn disarmed(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDisarmedData", self.object, "disarmed", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableDisarmedData#disarmed was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDisguisedBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDisguisedBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDisguisedBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDisguisedBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDisguisedBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDoublePlantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDoublePlantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDoublePlantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDoublePlantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDoublePlantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDropData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDropData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDropData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDropData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDropData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn will_drop(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableDropData", self.object, "willDrop", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableDropData#willDrop was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableExtendedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableExtendedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableExtendedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableExtendedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableExtendedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn extended(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableExtendedData", self.object, "extended", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableExtendedData#extended was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFilledData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFilledData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFilledData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFilledData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableFilledData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn filled(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableFilledData", self.object, "filled", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableFilledData#filled was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFluidLevelData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFluidLevelData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFluidLevelData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFluidLevelData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableFluidLevelData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn level(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableFluidLevelData", self.object, "level", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableFluidLevelData#level was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGrowthData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGrowthData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGrowthData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGrowthData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableGrowthData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn growth_stage(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableGrowthData", self.object, "growthStage", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableGrowthData#growthStage was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableHingeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableHingeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableHingeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableHingeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableHingeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableInWallData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableInWallData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableInWallData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableInWallData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableInWallData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn in_wall(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableInWallData", self.object, "inWall", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableInWallData#inWall was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableLayeredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableLayeredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableLayeredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableLayeredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableLayeredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn layer(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableLayeredData", self.object, "layer", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableLayeredData#layer was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableLogAxisData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableLogAxisData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableLogAxisData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableLogAxisData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableLogAxisData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMoistureData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMoistureData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMoistureData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMoistureData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableMoistureData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn moisture(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableMoistureData", self.object, "moisture", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableMoistureData#moisture was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableOccupiedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableOccupiedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableOccupiedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableOccupiedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableOccupiedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn occupied(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableOccupiedData", self.object, "occupied", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableOccupiedData#occupied was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableOpenData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableOpenData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableOpenData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableOpenData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableOpenData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn open(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableOpenData", self.object, "open", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableOpenData#open was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePistonData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePistonData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePistonData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePistonData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePistonData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePlantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePlantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePlantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePlantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePlantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePortionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePortionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePortionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePortionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePortionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePoweredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePoweredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePoweredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePoweredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePoweredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn powered(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePoweredData", self.object, "powered", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutablePoweredData#powered was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePrismarineData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePrismarineData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePrismarineData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePrismarineData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutablePrismarineData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableQuartzData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableQuartzData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableQuartzData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableQuartzData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableQuartzData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRailDirectionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRailDirectionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRailDirectionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRailDirectionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableRailDirectionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRedstonePoweredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRedstonePoweredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRedstonePoweredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRedstonePoweredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableRedstonePoweredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn power(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableRedstonePoweredData", self.object, "power", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableRedstonePoweredData#power was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSandstoneData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSandstoneData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSandstoneData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSandstoneData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSandstoneData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSeamlessData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSeamlessData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSeamlessData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSeamlessData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSeamlessData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn seamless(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSeamlessData", self.object, "seamless", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableSeamlessData#seamless was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableShrubData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableShrubData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableShrubData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableShrubData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableShrubData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSlabData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSlabData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSlabData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSlabData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSlabData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSnowedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSnowedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSnowedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSnowedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSnowedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_snow(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableSnowedData", self.object, "hasSnow", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableSnowedData#hasSnow was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableStairShapeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableStairShapeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableStairShapeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableStairShapeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableStairShapeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableStoneData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableStoneData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableStoneData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableStoneData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableStoneData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableTreeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableTreeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableTreeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableTreeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableTreeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableWallData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableWallData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableWallData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableWallData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWallData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableWireAttachmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableWireAttachmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableWireAttachmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableWireAttachmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn wire_attachments(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", self.object, "wireAttachments", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData#wireAttachments was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_north(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", self.object, "wireAttachmentNorth", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData#wireAttachmentNorth was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_south(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", self.object, "wireAttachmentSouth", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData#wireAttachmentSouth was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_east(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", self.object, "wireAttachmentEast", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData#wireAttachmentEast was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_west(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData", self.object, "wireAttachmentWest", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/block/ImmutableWireAttachmentData#wireAttachmentWest was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
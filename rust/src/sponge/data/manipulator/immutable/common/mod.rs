@@ -0,0 +1,355 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractImmutableBooleanData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableBooleanData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableBooleanData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableBooleanData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBooleanData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_data_manipulator(&self, immutable_data_manipulator: ::sponge::data::manipulator::ImmutableDataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBooleanData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;)I", CallIntMethodA, immutable_data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBooleanData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableBoundedComparableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableBoundedComparableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableBoundedComparableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableBoundedComparableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBoundedComparableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_data_manipulator(&self, immutable_data_manipulator: ::sponge::data::manipulator::ImmutableDataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBoundedComparableData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;)I", CallIntMethodA, immutable_data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableBoundedComparableData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn copy(&self) -> ::sponge::data::manipulator::ImmutableDataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "copy", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#copy was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_value(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "getValue", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn get_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "getKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "getValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#getValues was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableListData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableListData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableListData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableListData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_list_data(&self, immutable_list_data: ::sponge::data::manipulator::immutable::ImmutableListData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/immutable/ImmutableListData;)I", CallIntMethodA, immutable_list_data.object)
+  }
+
+  pub fn get_list_value(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData", self.object, "getListValue", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData#getListValue was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_list(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData", self.object, "asList", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData#asList was null") }
+    ret
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableListData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableMappedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableMappedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableMappedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableMappedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_mapped_data(&self, immutable_mapped_data: ::sponge::data::manipulator::immutable::ImmutableMappedData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMappedData;)I", CallIntMethodA, immutable_mapped_data.object)
+  }
+
+  pub fn get(&self, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", self.object, "get", "(Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_map_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", self.object, "getMapKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData#getMapKeys was null") }
+    ret
+  }
+
+  pub fn get_map_value(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", self.object, "getMapValue", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData#getMapValue was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableMappedData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableSingleCatalogData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableSingleCatalogData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableSingleCatalogData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableSingleCatalogData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_variant_data(&self, immutable_variant_data: ::sponge::data::manipulator::immutable::ImmutableVariantData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/immutable/ImmutableVariantData;)I", CallIntMethodA, immutable_variant_data.object)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn type_(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData", self.object, "type", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData#type was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleCatalogData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableSingleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableSingleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableSingleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableSingleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData", self.object, "asMutable", "()Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData#asMutable was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn get_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData", self.object, "getKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleData#getKeys was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractImmutableSingleEnumData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractImmutableSingleEnumData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractImmutableSingleEnumData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractImmutableSingleEnumData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleEnumData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_immutable_data_manipulator(&self, immutable_data_manipulator: ::sponge::data::manipulator::ImmutableDataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleEnumData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;)I", CallIntMethodA, immutable_data_manipulator.object)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleEnumData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleEnumData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/common/AbstractImmutableSingleEnumData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
@@ -0,0 +1,2301 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableAchievementData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAchievementData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAchievementData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAchievementData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAchievementData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn achievements(&self) -> ::sponge::data::value::immutable::ImmutableSetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAchievementData", self.object, "achievements", "()Lorg/spongepowered/api/data/value/immutable/ImmutableSetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAchievementData#achievements was null") }
+    ::sponge::data::value::immutable::ImmutableSetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAffectsSpawningData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAffectsSpawningData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAffectsSpawningData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAffectsSpawningData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAffectsSpawningData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn affects_spawning(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAffectsSpawningData", self.object, "affectsSpawning", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAffectsSpawningData#affectsSpawning was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAgeableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAgeableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAgeableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAgeableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn age(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData", self.object, "age", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData#age was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn baby(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData", self.object, "baby", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData#baby was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn adult(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData", self.object, "adult", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgeableData#adult was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAgentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAgentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAgentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAgentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn ai_enabled(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgentData", self.object, "aiEnabled", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAgentData#aiEnabled was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAggressiveData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAggressiveData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAggressiveData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAggressiveData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAggressiveData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn aggressive(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAggressiveData", self.object, "aggressive", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAggressiveData#aggressive was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableAngerableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAngerableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAngerableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAngerableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAngerableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn anger_level(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAngerableData", self.object, "angerLevel", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableAngerableData#angerLevel was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableArmorStandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableArmorStandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableArmorStandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableArmorStandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn marker(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", self.object, "marker", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData#marker was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn small(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", self.object, "small", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData#small was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn gravity(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", self.object, "gravity", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData#gravity was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn arms(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", self.object, "arms", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData#arms was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn base_plate(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData", self.object, "basePlate", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArmorStandData#basePlate was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableArtData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableArtData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableArtData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableArtData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableArtData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBodyPartRotationalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBodyPartRotationalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBodyPartRotationalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBodyPartRotationalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn part_rotation(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "partRotation", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#partRotation was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn head_direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "headDirection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#headDirection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn body_rotation(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "bodyRotation", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#bodyRotation was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn left_arm_direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "leftArmDirection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#leftArmDirection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn right_arm_direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "rightArmDirection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#rightArmDirection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn left_leg_direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "leftLegDirection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#leftLegDirection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn right_leg_direction(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData", self.object, "rightLegDirection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBodyPartRotationalData#rightLegDirection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBreathingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBreathingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBreathingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBreathingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreathingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_air(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreathingData", self.object, "remainingAir", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreathingData#remainingAir was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_air(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreathingData", self.object, "maxAir", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreathingData#maxAir was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBreedableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBreedableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBreedableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBreedableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreedableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn breedable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreedableData", self.object, "breedable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableBreedableData#breedable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCareerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCareerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCareerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCareerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCareerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn career(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCareerData", self.object, "career", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCareerData#career was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableChargedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableChargedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableChargedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableChargedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableChargedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn charged(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableChargedData", self.object, "charged", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableChargedData#charged was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCriticalHitData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCriticalHitData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCriticalHitData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCriticalHitData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCriticalHitData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn critical_hit(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCriticalHitData", self.object, "criticalHit", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCriticalHitData#criticalHit was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCustomNameVisibleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCustomNameVisibleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCustomNameVisibleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCustomNameVisibleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCustomNameVisibleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn custom_name_visible(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCustomNameVisibleData", self.object, "customNameVisible", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableCustomNameVisibleData#customNameVisible was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDamageableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDamageableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDamageableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDamageableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamageableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn last_attacker(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamageableData", self.object, "lastAttacker", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamageableData#lastAttacker was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn last_damage(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamageableData", self.object, "lastDamage", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamageableData#lastDamage was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDamagingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDamagingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDamagingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDamagingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamagingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn damage(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamagingData", self.object, "damage", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamagingData#damage was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn damage_for_entity_types(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamagingData", self.object, "damageForEntityTypes", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDamagingData#damageForEntityTypes was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDominantHandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDominantHandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDominantHandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDominantHandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDominantHandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn dominant_hand(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDominantHandData", self.object, "dominantHand", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableDominantHandData#dominantHand was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableElderData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableElderData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableElderData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableElderData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableElderData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn elder(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableElderData", self.object, "elder", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableElderData#elder was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableExpOrbData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableExpOrbData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableExpOrbData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableExpOrbData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpOrbData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn experience(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpOrbData", self.object, "experience", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpOrbData#experience was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableExperienceHolderData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableExperienceHolderData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableExperienceHolderData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableExperienceHolderData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn experience_since_level(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData", self.object, "experienceSinceLevel", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData#experienceSinceLevel was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn experience_between_levels(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData", self.object, "experienceBetweenLevels", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData#experienceBetweenLevels was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn level(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData", self.object, "level", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData#level was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn total_experience(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData", self.object, "totalExperience", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExperienceHolderData#totalExperience was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableExpirableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableExpirableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableExpirableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableExpirableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpirableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn expire_ticks(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpirableData", self.object, "expireTicks", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExpirableData#expireTicks was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableExplosionRadiusData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableExplosionRadiusData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableExplosionRadiusData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableExplosionRadiusData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExplosionRadiusData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn explosion_radius(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExplosionRadiusData", self.object, "explosionRadius", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableExplosionRadiusData#explosionRadius was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFallDistanceData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFallDistanceData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFallDistanceData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFallDistanceData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallDistanceData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fall_distance(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallDistanceData", self.object, "fallDistance", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallDistanceData#fallDistance was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFallingBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFallingBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFallingBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFallingBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fall_damage_per_block(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "fallDamagePerBlock", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#fallDamagePerBlock was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn max_fall_damage(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "maxFallDamage", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#maxFallDamage was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn block_state(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "blockState", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#blockState was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn can_place_as_block(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "canPlaceAsBlock", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#canPlaceAsBlock was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn can_drop_as_item(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "canDropAsItem", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#canDropAsItem was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn fall_time(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "fallTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#fallTime was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn can_hurt_entities(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData", self.object, "canHurtEntities", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData#canHurtEntities was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFlammableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFlammableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFlammableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFlammableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlammableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flammable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlammableData", self.object, "flammable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlammableData#flammable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFlyingAbilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFlyingAbilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFlyingAbilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFlyingAbilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingAbilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn can_fly(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingAbilityData", self.object, "canFly", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingAbilityData#canFly was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFlyingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFlyingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFlyingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFlyingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flying(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingData", self.object, "flying", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFlyingData#flying was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFoodData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFoodData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFoodData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFoodData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn food_level(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData", self.object, "foodLevel", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData#foodLevel was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn exhaustion(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData", self.object, "exhaustion", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData#exhaustion was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn saturation(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData", self.object, "saturation", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFoodData#saturation was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFuseData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFuseData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFuseData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFuseData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFuseData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fuse_duration(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFuseData", self.object, "fuseDuration", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFuseData#fuseDuration was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn ticks_remaining(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFuseData", self.object, "ticksRemaining", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableFuseData#ticksRemaining was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGameModeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGameModeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGameModeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGameModeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGameModeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGlowingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGlowingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGlowingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGlowingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGlowingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn glowing(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGlowingData", self.object, "glowing", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGlowingData#glowing was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGriefingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGriefingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGriefingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGriefingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGriefingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn can_grief(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGriefingData", self.object, "canGrief", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableGriefingData#canGrief was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableHealthData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableHealthData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableHealthData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableHealthData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn health(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthData", self.object, "health", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthData#health was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_health(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthData", self.object, "maxHealth", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthData#maxHealth was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableHealthScalingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableHealthScalingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableHealthScalingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableHealthScalingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthScalingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn health_scale(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthScalingData", self.object, "healthScale", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHealthScalingData#healthScale was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableHorseData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableHorseData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableHorseData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableHorseData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn color(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData", self.object, "color", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData#color was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn style(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData", self.object, "style", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData#style was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn variant(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData", self.object, "variant", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableHorseData#variant was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableIgniteableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableIgniteableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableIgniteableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableIgniteableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableIgniteableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fire_ticks(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableIgniteableData", self.object, "fireTicks", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableIgniteableData#fireTicks was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn fire_delay(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableIgniteableData", self.object, "fireDelay", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableIgniteableData#fireDelay was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableInvisibilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableInvisibilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableInvisibilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableInvisibilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn invisible(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData", self.object, "invisible", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData#invisible was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn vanish(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData", self.object, "vanish", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData#vanish was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn ignores_collision_detection(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData", self.object, "ignoresCollisionDetection", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData#ignoresCollisionDetection was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn untargetable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData", self.object, "untargetable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvisibilityData#untargetable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableInvulnerabilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableInvulnerabilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableInvulnerabilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableInvulnerabilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvulnerabilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn invulnerable_ticks(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvulnerabilityData", self.object, "invulnerableTicks", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableInvulnerabilityData#invulnerableTicks was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableJoinData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableJoinData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableJoinData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableJoinData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableJoinData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn first_played(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableJoinData", self.object, "firstPlayed", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableJoinData#firstPlayed was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn last_played(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableJoinData", self.object, "lastPlayed", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableJoinData#lastPlayed was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableKnockbackData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableKnockbackData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableKnockbackData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableKnockbackData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableKnockbackData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn knockback_strength(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableKnockbackData", self.object, "knockbackStrength", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableKnockbackData#knockbackStrength was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableLeashData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableLeashData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableLeashData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableLeashData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableLeashData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn leash_holder(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableLeashData", self.object, "leashHolder", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableLeashData#leashHolder was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMinecartBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMinecartBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMinecartBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMinecartBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMinecartBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn block(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMinecartBlockData", self.object, "block", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMinecartBlockData#block was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn offset(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMinecartBlockData", self.object, "offset", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMinecartBlockData#offset was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMovementSpeedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMovementSpeedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMovementSpeedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMovementSpeedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMovementSpeedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn walk_speed(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMovementSpeedData", self.object, "walkSpeed", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMovementSpeedData#walkSpeed was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn fly_speed(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMovementSpeedData", self.object, "flySpeed", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableMovementSpeedData#flySpeed was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableOcelotData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableOcelotData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableOcelotData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableOcelotData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableOcelotData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePassengerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePassengerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePassengerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePassengerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePassengerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn passengers(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePassengerData", self.object, "passengers", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePassengerData#passengers was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePersistingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePersistingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePersistingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePersistingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePersistingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn persists(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePersistingData", self.object, "persists", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePersistingData#persists was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePickupRuleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePickupRuleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePickupRuleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePickupRuleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePickupRuleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePigSaddleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePigSaddleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePigSaddleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePigSaddleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePigSaddleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn saddle(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePigSaddleData", self.object, "saddle", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePigSaddleData#saddle was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePlayerCreatedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePlayerCreatedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePlayerCreatedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePlayerCreatedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayerCreatedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn player_created(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayerCreatedData", self.object, "playerCreated", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayerCreatedData#playerCreated was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePlayingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePlayingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePlayingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePlayingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn playing(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayingData", self.object, "playing", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutablePlayingData#playing was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRabbitData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRabbitData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRabbitData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRabbitData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRabbitData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRespawnLocation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRespawnLocation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRespawnLocation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRespawnLocation`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRespawnLocation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn respawn_location(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRespawnLocation", self.object, "respawnLocation", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRespawnLocation#respawnLocation was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "util", feature = "world"))]
+  pub fn get_for_world(&self, world: ::sponge::world::World) -> Option<::sponge::util::RespawnLocation> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRespawnLocation", self.object, "getForWorld", "(Lorg/spongepowered/api/world/World;)Ljava/util/Optional;", CallObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableRespawnLocation#getForWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::RespawnLocation { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableScreamingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableScreamingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableScreamingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableScreamingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableScreamingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn screaming(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableScreamingData", self.object, "screaming", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableScreamingData#screaming was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableShatteringData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableShatteringData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableShatteringData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableShatteringData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShatteringData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn will_shatter(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShatteringData", self.object, "willShatter", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShatteringData#willShatter was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableShearedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableShearedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableShearedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableShearedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShearedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sheared(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShearedData", self.object, "sheared", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableShearedData#sheared was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSilentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSilentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSilentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSilentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSilentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn silent(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSilentData", self.object, "silent", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSilentData#silent was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSittingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSittingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSittingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSittingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSittingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sitting(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSittingData", self.object, "sitting", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSittingData#sitting was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSizeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSizeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSizeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSizeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn base(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData", self.object, "base", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData#base was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn height(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData", self.object, "height", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData#height was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn scale(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData", self.object, "scale", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSizeData#scale was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSkeletonData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSkeletonData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSkeletonData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSkeletonData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSkeletonData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSkinData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSkinData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSkinData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSkinData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSkinData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn skin_unique_id(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSkinData", self.object, "skinUniqueId", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSkinData#skinUniqueId was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSleepingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSleepingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSleepingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSleepingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSleepingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sleeping(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSleepingData", self.object, "sleeping", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSleepingData#sleeping was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn ignore_sleeping(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSleepingData", self.object, "ignoreSleeping", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSleepingData#ignoreSleeping was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSlimeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSlimeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSlimeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSlimeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSlimeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn size(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSlimeData", self.object, "size", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSlimeData#size was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSneakingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSneakingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSneakingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSneakingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSneakingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sneaking(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSneakingData", self.object, "sneaking", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSneakingData#sneaking was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSprintData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSprintData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSprintData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSprintData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSprintData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sprinting(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSprintData", self.object, "sprinting", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableSprintData#sprinting was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableStatisticData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableStatisticData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableStatisticData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableStatisticData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStatisticData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn statistics(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStatisticData", self.object, "statistics", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStatisticData#statistics was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableStuckArrowsData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableStuckArrowsData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableStuckArrowsData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableStuckArrowsData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStuckArrowsData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn stuck_arrows(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStuckArrowsData", self.object, "stuckArrows", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableStuckArrowsData#stuckArrows was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableTameableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableTameableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableTameableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableTameableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTameableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn owner(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTameableData", self.object, "owner", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTameableData#owner was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableTradeOfferData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableTradeOfferData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableTradeOfferData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableTradeOfferData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTradeOfferData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn trade_offers(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTradeOfferData", self.object, "tradeOffers", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableTradeOfferData#tradeOffers was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableVehicleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableVehicleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableVehicleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableVehicleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVehicleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn vehicle(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVehicleData", self.object, "vehicle", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVehicleData#vehicle was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn base_vehicle(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVehicleData", self.object, "baseVehicle", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVehicleData#baseVehicle was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableVelocityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableVelocityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableVelocityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableVelocityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVelocityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn velocity(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVelocityData", self.object, "velocity", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVelocityData#velocity was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableVillagerZombieData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableVillagerZombieData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableVillagerZombieData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableVillagerZombieData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/entity/ImmutableVillagerZombieData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,517 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableAuthorData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableAuthorData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableAuthorData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableAuthorData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableAuthorData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn author(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableAuthorData", self.object, "author", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableAuthorData#author was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBlockItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBlockItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBlockItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBlockItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableBlockItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn state(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableBlockItemData", self.object, "state", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableBlockItemData#state was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBreakableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBreakableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBreakableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBreakableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableBreakableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn breakable(&self) -> ::sponge::data::value::immutable::ImmutableSetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableBreakableData", self.object, "breakable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableSetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableBreakableData#breakable was null") }
+    ::sponge::data::value::immutable::ImmutableSetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCoalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCoalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCoalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCoalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableCoalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCookedFishData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCookedFishData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCookedFishData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCookedFishData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableCookedFishData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDurabilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDurabilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDurabilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDurabilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableDurabilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn durability(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableDurabilityData", self.object, "durability", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableDurabilityData#durability was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn unbreakable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableDurabilityData", self.object, "unbreakable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableDurabilityData#unbreakable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableEnchantmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableEnchantmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableEnchantmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableEnchantmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableEnchantmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn enchantments(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableEnchantmentData", self.object, "enchantments", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableEnchantmentData#enchantments was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFishData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFishData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFishData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFishData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableFishData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGenerationData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGenerationData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGenerationData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGenerationData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableGenerationData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn generation(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableGenerationData", self.object, "generation", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableGenerationData#generation was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableGoldenAppleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableGoldenAppleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableGoldenAppleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableGoldenAppleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableGoldenAppleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableHideData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableHideData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableHideData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableHideData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn hide_enchantments(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideEnchantments", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideEnchantments was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn hide_attributes(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideAttributes", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideAttributes was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn hide_unbreakable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideUnbreakable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideUnbreakable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn hide_can_destroy(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideCanDestroy", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideCanDestroy was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn hide_can_place(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideCanPlace", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideCanPlace was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn hide_miscellaneous(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData", self.object, "hideMiscellaneous", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableHideData#hideMiscellaneous was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableInventoryItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableInventoryItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableInventoryItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableInventoryItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableInventoryItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableLoreData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableLoreData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableLoreData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableLoreData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableLoreData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lore(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableLoreData", self.object, "lore", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableLoreData#lore was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMapItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMapItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMapItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMapItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableMapItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePagedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePagedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePagedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePagedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutablePagedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn pages(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutablePagedData", self.object, "pages", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutablePagedData#pages was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePlaceableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePlaceableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePlaceableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePlaceableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutablePlaceableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn placeable(&self) -> ::sponge::data::value::immutable::ImmutableSetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutablePlaceableData", self.object, "placeable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableSetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutablePlaceableData#placeable was null") }
+    ::sponge::data::value::immutable::ImmutableSetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSpawnableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSpawnableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSpawnableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSpawnableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableSpawnableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableStoredEnchantmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableStoredEnchantmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableStoredEnchantmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableStoredEnchantmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableStoredEnchantmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn enchantments(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/item/ImmutableStoredEnchantmentData", self.object, "enchantments", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/item/ImmutableStoredEnchantmentData#enchantments was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,649 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod block;
+pub mod common;
+pub mod entity;
+pub mod item;
+pub mod tileentity;
+
+#[derive(Debug)]
+pub struct ImmutableColoredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableColoredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableColoredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableColoredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableColoredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn color(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableColoredData", self.object, "color", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableColoredData#color was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCommandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCommandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCommandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCommandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn stored_command(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData", self.object, "storedCommand", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData#storedCommand was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn success_count(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData", self.object, "successCount", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData#successCount was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn does_track_output(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData", self.object, "doesTrackOutput", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData#doesTrackOutput was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn last_output(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData", self.object, "lastOutput", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableCommandData#lastOutput was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDisplayNameData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDisplayNameData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDisplayNameData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDisplayNameData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableDisplayNameData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn display_name(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableDisplayNameData", self.object, "displayName", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableDisplayNameData#displayName was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDyeableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDyeableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDyeableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDyeableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableDyeableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFireworkEffectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFireworkEffectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFireworkEffectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFireworkEffectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkEffectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn effects(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkEffectData", self.object, "effects", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkEffectData#effects was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFireworkRocketData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFireworkRocketData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFireworkRocketData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFireworkRocketData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkRocketData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flight_modifier(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkRocketData", self.object, "flightModifier", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableFireworkRocketData#flightModifier was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableListData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableListData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableListData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableListData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableListData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_list_value(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableListData", self.object, "getListValue", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableListData#getListValue was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_list(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableListData", self.object, "asList", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableListData#asList was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableListData", self.object, "get", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableListData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableListData", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMappedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMappedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMappedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMappedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", self.object, "get", "(Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_map_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", self.object, "getMapKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData#getMapKeys was null") }
+    ret
+  }
+
+  pub fn get_map_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", self.object, "getMapValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData#getMapValues was null") }
+    ret
+  }
+
+  pub fn get_map_value(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", self.object, "getMapValue", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData#getMapValue was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn as_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData", self.object, "asMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMappedData#asMap was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMobSpawnerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMobSpawnerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMobSpawnerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMobSpawnerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_delay(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "remainingDelay", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#remainingDelay was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn minimum_spawn_delay(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "minimumSpawnDelay", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#minimumSpawnDelay was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn maximum_spawn_delay(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "maximumSpawnDelay", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#maximumSpawnDelay was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn spawn_count(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "spawnCount", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#spawnCount was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn maximum_nearby_entities(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "maximumNearbyEntities", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#maximumNearbyEntities was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn required_player_range(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "requiredPlayerRange", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#requiredPlayerRange was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn spawn_range(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "spawnRange", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#spawnRange was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn next_entity_to_spawn(&self) -> ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "nextEntityToSpawn", "()Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#nextEntityToSpawn was null") }
+    ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue { env: self.env, object: ret }
+  }
+
+  pub fn possible_entities_to_spawn(&self) -> ::sponge::data::value::immutable::ImmutableWeightedCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData", self.object, "possibleEntitiesToSpawn", "()Lorg/spongepowered/api/data/value/immutable/ImmutableWeightedCollectionValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData#possibleEntitiesToSpawn was null") }
+    ::sponge::data::value::immutable::ImmutableWeightedCollectionValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePotionEffectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePotionEffectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePotionEffectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePotionEffectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutablePotionEffectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn effects(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutablePotionEffectData", self.object, "effects", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutablePotionEffectData#effects was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRepresentedItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRepresentedItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRepresentedItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRepresentedItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn item(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedItemData", self.object, "item", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedItemData#item was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRepresentedPlayerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRepresentedPlayerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRepresentedPlayerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRepresentedPlayerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedPlayerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn owner(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedPlayerData", self.object, "owner", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableRepresentedPlayerData#owner was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableRotationalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableRotationalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableRotationalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableRotationalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableRotationalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSkullData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSkullData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSkullData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSkullData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableSkullData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableTargetedLocationData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableTargetedLocationData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableTargetedLocationData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableTargetedLocationData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableTargetedLocationData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn target(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableTargetedLocationData", self.object, "target", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableTargetedLocationData#target was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableVariantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableVariantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableVariantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableVariantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableVariantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableVariantData", self.object, "type", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableVariantData#type was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableWetData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableWetData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableWetData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableWetData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableWetData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn wet(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableWetData", self.object, "wet", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableWetData#wet was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+pub mod immutable_mob_spawner_data {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ImmutableNextEntityToSpawnValue {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ImmutableNextEntityToSpawnValue {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ImmutableNextEntityToSpawnValue {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableNextEntityToSpawnValue`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn with_entity_type_collection(&self, entity_type: ::sponge::entity::EntityType, collection: jobject) -> ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", self.object, "with", "(Lorg/spongepowered/api/entity/EntityType;Ljava/util/Collection;)Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue;", CallObjectMethodA, entity_type.object, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue#with was null") }
+      ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn with_weighted_serializable_object(&self, weighted_serializable_object: ::sponge::util::weighted::WeightedSerializableObject) -> ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", self.object, "with", "(Lorg/spongepowered/api/util/weighted/WeightedSerializableObject;)Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue;", CallObjectMethodA, weighted_serializable_object.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue#with was null") }
+      ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+    pub fn transform(&self, function: jobject) -> ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue#transform was null") }
+      ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+    pub fn as_mutable(&self) -> ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue#asMutable was null") }
+      ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+    pub fn with_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue#with was null") }
+      ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,269 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableBannerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBannerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBannerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBannerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBannerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn base_color(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBannerData", self.object, "baseColor", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBannerData#baseColor was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn patterns(&self) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBannerData", self.object, "patterns", "()Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBannerData#patterns was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBeaconData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBeaconData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBeaconData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBeaconData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn primary_effect(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData", self.object, "primaryEffect", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData#primaryEffect was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn secondary_effect(&self) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData", self.object, "secondaryEffect", "()Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData#secondaryEffect was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn clear_effects(&self) -> ::sponge::data::manipulator::immutable::tileentity::ImmutableBeaconData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData", self.object, "clearEffects", "()Lorg/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBeaconData#clearEffects was null") }
+    ::sponge::data::manipulator::immutable::tileentity::ImmutableBeaconData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBrewingStandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBrewingStandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBrewingStandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBrewingStandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBrewingStandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_brew_time(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBrewingStandData", self.object, "remainingBrewTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableBrewingStandData#remainingBrewTime was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCooldownData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCooldownData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCooldownData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCooldownData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableCooldownData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn cooldown(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableCooldownData", self.object, "cooldown", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableCooldownData#cooldown was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFurnaceData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFurnaceData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFurnaceData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFurnaceData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn passed_burn_time(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData", self.object, "passedBurnTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData#passedBurnTime was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_burn_time(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData", self.object, "maxBurnTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData#maxBurnTime was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn passed_cook_time(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData", self.object, "passedCookTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData#passedCookTime was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_cook_time(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData", self.object, "maxCookTime", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableFurnaceData#maxCookTime was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableLockableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableLockableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableLockableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableLockableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableLockableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lock_token(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableLockableData", self.object, "lockToken", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableLockableData#lockToken was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableNoteData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableNoteData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableNoteData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableNoteData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableNoteData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn note(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableNoteData", self.object, "note", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableNoteData#note was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSignData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSignData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSignData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSignData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableSignData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lines(&self) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableSignData", self.object, "lines", "()Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableSignData#lines was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,222 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod catalog;
+pub mod immutable;
+pub mod mutable;
+
+#[derive(Debug)]
+pub struct DataManipulator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataManipulator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataManipulator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataManipulator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/DataManipulator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fill_data_holder(&self, data_holder: ::sponge::data::DataHolder) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "fill", "(Lorg/spongepowered/api/data/DataHolder;)Ljava/util/Optional;", CallObjectMethodA, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#fill was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn fill_data_holder_merge_function(&self, data_holder: ::sponge::data::DataHolder, merge_function: ::sponge::data::merge::MergeFunction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "fill", "(Lorg/spongepowered/api/data/DataHolder;Lorg/spongepowered/api/data/merge/MergeFunction;)Ljava/util/Optional;", CallObjectMethodA, data_holder.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#fill was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn from_data_container(&self, data_container: ::sponge::data::DataContainer) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "from", "(Lorg/spongepowered/api/data/DataContainer;)Ljava/util/Optional;", CallObjectMethodA, data_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#from was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#set was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn set_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "set", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#set was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn set_base_value_array(&self, base_values: Vec<::sponge::data::value::BaseValue>) -> ::sponge::data::manipulator::DataManipulator {
+    unimplemented!();
+  }
+  pub fn set_iterable(&self, iterable: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "set", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#set was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "transform", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#transform was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn copy(&self) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "copy", "()Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#copy was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::manipulator::ImmutableDataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulator", self.object, "asImmutable", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulator#asImmutable was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulator { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataManipulatorBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataManipulatorBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataManipulatorBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataManipulatorBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/DataManipulatorBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create(&self) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulatorBuilder", self.object, "create", "()Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulatorBuilder#create was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn create_from(&self, data_holder: ::sponge::data::DataHolder) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/DataManipulatorBuilder", self.object, "createFrom", "(Lorg/spongepowered/api/data/DataHolder;)Ljava/util/Optional;", CallObjectMethodA, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/DataManipulatorBuilder#createFrom was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDataManipulator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDataManipulator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDataManipulator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDataManipulator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/ImmutableDataManipulator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn with_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulator", self.object, "with", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulator#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn with_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulator", self.object, "with", "(Lorg/spongepowered/api/data/value/BaseValue;)Ljava/util/Optional;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulator#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn copy(&self) -> ::sponge::data::manipulator::ImmutableDataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulator", self.object, "copy", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulator#copy was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulator", self.object, "asMutable", "()Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulator#asMutable was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDataManipulatorBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDataManipulatorBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDataManipulatorBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDataManipulatorBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_immutable(&self) -> ::sponge::data::manipulator::ImmutableDataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder", self.object, "createImmutable", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder#createImmutable was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn create_from_data_holder(&self, data_holder: ::sponge::data::DataHolder) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder", self.object, "createFrom", "(Lorg/spongepowered/api/data/DataHolder;)Ljava/util/Optional;", CallObjectMethodA, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder#createFrom was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn create_from_immutable_data_holder(&self, immutable_data_holder: ::sponge::data::ImmutableDataHolder) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder", self.object, "createFrom", "(Lorg/spongepowered/api/data/ImmutableDataHolder;)Ljava/util/Optional;", CallObjectMethodA, immutable_data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder#createFrom was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn reset(&self) -> ::sponge::data::manipulator::ImmutableDataManipulatorBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder", self.object, "reset", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/ImmutableDataManipulatorBuilder#reset was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulatorBuilder { env: self.env, object: ret }
+  }
+
+}
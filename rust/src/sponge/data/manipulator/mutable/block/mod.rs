@@ -0,0 +1,1187 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AttachedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AttachedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AttachedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AttachedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/AttachedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn attached(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/AttachedData", self.object, "attached", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/AttachedData#attached was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AxisData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AxisData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AxisData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AxisData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/AxisData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BigMushroomData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BigMushroomData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BigMushroomData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BigMushroomData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/BigMushroomData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BrickData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrickData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrickData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrickData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/BrickData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ComparatorData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ComparatorData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ComparatorData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ComparatorData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/ComparatorData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConnectedDirectionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConnectedDirectionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConnectedDirectionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConnectedDirectionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn connected_directions(&self) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", self.object, "connectedDirections", "()Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData#connectedDirections was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+  pub fn connected_north(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", self.object, "connectedNorth", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData#connectedNorth was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn connected_south(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", self.object, "connectedSouth", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData#connectedSouth was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn connected_east(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", self.object, "connectedEast", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData#connectedEast was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn connected_west(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData", self.object, "connectedWest", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ConnectedDirectionData#connectedWest was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DecayableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DecayableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DecayableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DecayableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DecayableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn decayable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/DecayableData", self.object, "decayable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/DecayableData#decayable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DelayableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DelayableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DelayableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DelayableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DelayableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/DelayableData", self.object, "delay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/DelayableData#delay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DirectionalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DirectionalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DirectionalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DirectionalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DirectionalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/DirectionalData", self.object, "direction", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/DirectionalData#direction was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DirtData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DirtData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DirtData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DirtData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DirtData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisarmedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisarmedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisarmedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisarmedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DisarmedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn disarmed(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/DisarmedData", self.object, "disarmed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/DisarmedData#disarmed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisguisedBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisguisedBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisguisedBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisguisedBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DisguisedBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DoublePlantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DoublePlantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DoublePlantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DoublePlantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DoublePlantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DropData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DropData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DropData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DropData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/DropData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn will_drop(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/DropData", self.object, "willDrop", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/DropData#willDrop was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExtendedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExtendedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExtendedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExtendedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/ExtendedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn extended(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/ExtendedData", self.object, "extended", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/ExtendedData#extended was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FilledData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FilledData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FilledData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FilledData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/FilledData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn filled(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/FilledData", self.object, "filled", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/FilledData#filled was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidLevelData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidLevelData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidLevelData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidLevelData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/FluidLevelData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/FluidLevelData", self.object, "level", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/FluidLevelData#level was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GrowthData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GrowthData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GrowthData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GrowthData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/GrowthData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn growth_stage(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/GrowthData", self.object, "growthStage", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/GrowthData#growthStage was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HingeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HingeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HingeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HingeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/HingeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InWallData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InWallData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InWallData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InWallData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/InWallData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn in_wall(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/InWallData", self.object, "inWall", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/InWallData#inWall was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LayeredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LayeredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LayeredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LayeredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/LayeredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn layer(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/LayeredData", self.object, "layer", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/LayeredData#layer was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LogAxisData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LogAxisData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LogAxisData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LogAxisData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/LogAxisData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MoistureData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MoistureData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MoistureData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MoistureData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/MoistureData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn moisture(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/MoistureData", self.object, "moisture", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/MoistureData#moisture was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OccupiedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OccupiedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OccupiedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OccupiedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/OccupiedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn occupied(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/OccupiedData", self.object, "occupied", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/OccupiedData#occupied was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OpenData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OpenData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OpenData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OpenData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/OpenData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn open(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/OpenData", self.object, "open", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/OpenData#open was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PistonData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PistonData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PistonData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PistonData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/PistonData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/PlantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/PortionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PoweredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PoweredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PoweredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PoweredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/PoweredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn powered(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/PoweredData", self.object, "powered", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/PoweredData#powered was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PrismarineData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PrismarineData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PrismarineData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PrismarineData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/PrismarineData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct QuartzData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl QuartzData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    QuartzData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `QuartzData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/QuartzData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RailDirectionData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RailDirectionData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RailDirectionData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RailDirectionData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/RailDirectionData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RedstonePoweredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RedstonePoweredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RedstonePoweredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RedstonePoweredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/RedstonePoweredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn power(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/RedstonePoweredData", self.object, "power", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/RedstonePoweredData#power was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/SandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandstoneData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandstoneData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandstoneData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandstoneData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/SandstoneData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SeamlessData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SeamlessData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SeamlessData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SeamlessData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/SeamlessData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn seamless(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/SeamlessData", self.object, "seamless", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/SeamlessData#seamless was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShrubData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShrubData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShrubData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShrubData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/ShrubData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlabData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlabData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlabData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SlabData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/SlabData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SnowedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SnowedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SnowedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SnowedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/SnowedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_snow(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/SnowedData", self.object, "hasSnow", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/SnowedData#hasSnow was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StairShapeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StairShapeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StairShapeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StairShapeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/StairShapeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StoneData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StoneData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StoneData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StoneData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/StoneData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TreeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TreeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TreeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TreeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/TreeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WallData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WallData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WallData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WallData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/WallData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WireAttachmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WireAttachmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WireAttachmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WireAttachmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn wire_attachments(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", self.object, "wireAttachments", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData#wireAttachments was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_north(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", self.object, "wireAttachmentNorth", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData#wireAttachmentNorth was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_south(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", self.object, "wireAttachmentSouth", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData#wireAttachmentSouth was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_east(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", self.object, "wireAttachmentEast", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData#wireAttachmentEast was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn wire_attachment_west(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData", self.object, "wireAttachmentWest", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/block/WireAttachmentData#wireAttachmentWest was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,464 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractBooleanData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractBooleanData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractBooleanData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractBooleanData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBooleanData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBooleanData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBooleanData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractBooleanData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBooleanData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractBoundedComparableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractBoundedComparableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractBoundedComparableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractBoundedComparableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn set_value_comparable(&self, comparable: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", self.object, "setValue", "(Ljava/lang/Comparable;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, comparable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData#setValue was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn set_value_object(&self, object: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", self.object, "setValue", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData#setValue was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractBoundedComparableData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#set was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::manipulator::DataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "transform", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/manipulator/DataManipulator;", CallObjectMethodA, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#transform was null") }
+    ::sponge::data::manipulator::DataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_value(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "getValue", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn get_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "getKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "getValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#getValues was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractIntData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractIntData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractIntData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractIntData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractIntData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractIntData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractIntData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractListData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractListData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractListData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractListData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractListData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::manipulator::immutable::ImmutableListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "asImmutable", "()Lorg/spongepowered/api/data/manipulator/immutable/ImmutableListData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractListData#asImmutable was null") }
+    ::sponge::data::manipulator::immutable::ImmutableListData { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_list_data(&self, list_data: ::sponge::data::manipulator::mutable::ListData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/mutable/ListData;)I", CallIntMethodA, list_data.object)
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn get_list_value(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "getListValue", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractListData#getListValue was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_list(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "asList", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractListData#asList was null") }
+    ret
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractListData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractMappedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractMappedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractMappedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractMappedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_mapped_data(&self, mapped_data: ::sponge::data::manipulator::mutable::MappedData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/mutable/MappedData;)I", CallIntMethodA, mapped_data.object)
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn get_map_value(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "getMapValue", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData#getMapValue was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn as_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "asMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData#asMap was null") }
+    ret
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractMappedData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSingleCatalogData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSingleCatalogData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSingleCatalogData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSingleCatalogData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_variant_data(&self, variant_data: ::sponge::data::manipulator::mutable::VariantData) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/mutable/VariantData;)I", CallIntMethodA, variant_data.object)
+  }
+
+  pub fn type_(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", self.object, "type", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData#type was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleCatalogData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSingleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSingleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSingleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSingleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::manipulator::ImmutableDataManipulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "asImmutable", "()Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData#asImmutable was null") }
+    ::sponge::data::manipulator::ImmutableDataManipulator { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSingleEnumData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSingleEnumData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSingleEnumData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSingleEnumData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleEnumData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn compare_to_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleEnumData", self.object, "compareTo", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)I", CallIntMethodA, data_manipulator.object)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleEnumData", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleEnumData#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/common/AbstractSingleEnumData", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
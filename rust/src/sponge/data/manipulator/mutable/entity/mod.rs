@@ -0,0 +1,2289 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AchievementData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AchievementData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AchievementData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AchievementData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AchievementData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn achievements(&self) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AchievementData", self.object, "achievements", "()Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AchievementData#achievements was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AffectsSpawningData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AffectsSpawningData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AffectsSpawningData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AffectsSpawningData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AffectsSpawningData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn affects_spawning(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AffectsSpawningData", self.object, "affectsSpawning", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AffectsSpawningData#affectsSpawning was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AgeableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AgeableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AgeableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AgeableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AgeableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn age(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AgeableData", self.object, "age", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AgeableData#age was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn baby(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AgeableData", self.object, "baby", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AgeableData#baby was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn adult(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AgeableData", self.object, "adult", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AgeableData#adult was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AgentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AgentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AgentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AgentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AgentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn ai_enabled(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AgentData", self.object, "aiEnabled", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AgentData#aiEnabled was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AggressiveData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AggressiveData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AggressiveData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AggressiveData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AggressiveData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn aggressive(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AggressiveData", self.object, "aggressive", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AggressiveData#aggressive was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AngerableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AngerableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AngerableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AngerableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/AngerableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn anger_level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/AngerableData", self.object, "angerLevel", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/AngerableData#angerLevel was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorStandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorStandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorStandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArmorStandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn marker(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", self.object, "marker", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData#marker was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn small(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", self.object, "small", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData#small was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn gravity(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", self.object, "gravity", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData#gravity was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn arms(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", self.object, "arms", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData#arms was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn base_plate(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData", self.object, "basePlate", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData#basePlate was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArtData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArtData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArtData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArtData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ArtData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BodyPartRotationalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BodyPartRotationalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BodyPartRotationalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BodyPartRotationalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn part_rotation(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "partRotation", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#partRotation was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn head_direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "headDirection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#headDirection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn body_rotation(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "bodyRotation", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#bodyRotation was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn left_arm_direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "leftArmDirection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#leftArmDirection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn right_arm_direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "rightArmDirection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#rightArmDirection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn left_leg_direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "leftLegDirection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#leftLegDirection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn right_leg_direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData", self.object, "rightLegDirection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData#rightLegDirection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BreathingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BreathingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BreathingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BreathingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/BreathingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_air(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BreathingData", self.object, "remainingAir", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BreathingData#remainingAir was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_air(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BreathingData", self.object, "maxAir", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BreathingData#maxAir was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BreedableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BreedableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BreedableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BreedableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/BreedableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn breedable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/BreedableData", self.object, "breedable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/BreedableData#breedable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CareerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CareerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CareerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CareerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/CareerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChargedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChargedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChargedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChargedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ChargedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn charged(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ChargedData", self.object, "charged", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ChargedData#charged was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CriticalHitData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CriticalHitData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CriticalHitData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CriticalHitData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/CriticalHitData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn critical_hit(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/CriticalHitData", self.object, "criticalHit", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/CriticalHitData#criticalHit was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CustomNameVisibleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CustomNameVisibleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CustomNameVisibleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CustomNameVisibleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/CustomNameVisibleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn custom_name_visible(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/CustomNameVisibleData", self.object, "customNameVisible", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/CustomNameVisibleData#customNameVisible was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/DamageableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn last_attacker(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/DamageableData", self.object, "lastAttacker", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/DamageableData#lastAttacker was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn last_damage(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/DamageableData", self.object, "lastDamage", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/DamageableData#lastDamage was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamagingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamagingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamagingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamagingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/DamagingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn damage(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/DamagingData", self.object, "damage", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/DamagingData#damage was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn damage_for_entity(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/DamagingData", self.object, "damageForEntity", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/DamagingData#damageForEntity was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DominantHandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DominantHandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DominantHandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DominantHandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/DominantHandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn dominant_hand(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/DominantHandData", self.object, "dominantHand", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/DominantHandData#dominantHand was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ElderData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ElderData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ElderData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ElderData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ElderData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn elder(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ElderData", self.object, "elder", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ElderData#elder was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExpOrbData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExpOrbData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExpOrbData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExpOrbData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ExpOrbData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn experience(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExpOrbData", self.object, "experience", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExpOrbData#experience was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExperienceHolderData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExperienceHolderData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExperienceHolderData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExperienceHolderData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn experience_since_level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData", self.object, "experienceSinceLevel", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData#experienceSinceLevel was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn get_experience_between_levels(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData", self.object, "getExperienceBetweenLevels", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData#getExperienceBetweenLevels was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData", self.object, "level", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData#level was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn total_experience(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData", self.object, "totalExperience", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExperienceHolderData#totalExperience was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExpirableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExpirableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExpirableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExpirableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ExpirableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn expire_ticks(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExpirableData", self.object, "expireTicks", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExpirableData#expireTicks was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExplosionRadiusData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExplosionRadiusData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExplosionRadiusData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExplosionRadiusData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ExplosionRadiusData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn explosion_radius(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ExplosionRadiusData", self.object, "explosionRadius", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ExplosionRadiusData#explosionRadius was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FallDistanceData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FallDistanceData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FallDistanceData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FallDistanceData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FallDistanceData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fall_distance(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallDistanceData", self.object, "fallDistance", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallDistanceData#fallDistance was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FallingBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FallingBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FallingBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FallingBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fall_damage_per_block(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "fallDamagePerBlock", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#fallDamagePerBlock was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn max_fall_damage(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "maxFallDamage", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#maxFallDamage was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn block_state(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "blockState", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#blockState was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn can_place_as_block(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "canPlaceAsBlock", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#canPlaceAsBlock was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn can_drop_as_item(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "canDropAsItem", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#canDropAsItem was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn fall_time(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "fallTime", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#fallTime was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn can_hurt_entities(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData", self.object, "canHurtEntities", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData#canHurtEntities was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FlammableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FlammableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FlammableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FlammableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FlammableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flammable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FlammableData", self.object, "flammable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FlammableData#flammable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FlyingAbilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FlyingAbilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FlyingAbilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FlyingAbilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FlyingAbilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn can_fly(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FlyingAbilityData", self.object, "canFly", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FlyingAbilityData#canFly was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FlyingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FlyingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FlyingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FlyingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FlyingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flying(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FlyingData", self.object, "flying", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FlyingData#flying was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FoodData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FoodData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FoodData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FoodData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FoodData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn food_level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FoodData", self.object, "foodLevel", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FoodData#foodLevel was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn exhaustion(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FoodData", self.object, "exhaustion", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FoodData#exhaustion was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn saturation(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FoodData", self.object, "saturation", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FoodData#saturation was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FuseData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FuseData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FuseData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FuseData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/FuseData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fuse_duration(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FuseData", self.object, "fuseDuration", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FuseData#fuseDuration was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn ticks_remaining(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/FuseData", self.object, "ticksRemaining", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/FuseData#ticksRemaining was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameModeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameModeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameModeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameModeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/GameModeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GlowingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GlowingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GlowingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GlowingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/GlowingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn glowing(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/GlowingData", self.object, "glowing", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/GlowingData#glowing was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GriefingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GriefingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GriefingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GriefingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/GriefingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn can_grief(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/GriefingData", self.object, "canGrief", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/GriefingData#canGrief was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealthData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealthData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealthData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealthData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/HealthData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn health(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HealthData", self.object, "health", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HealthData#health was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_health(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HealthData", self.object, "maxHealth", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HealthData#maxHealth was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealthScalingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealthScalingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealthScalingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealthScalingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/HealthScalingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn health_scale(&self) -> ::sponge::data::value::BoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HealthScalingData", self.object, "healthScale", "()Lorg/spongepowered/api/data/value/BoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HealthScalingData#healthScale was null") }
+    ::sponge::data::value::BoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/HorseData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HorseData", self.object, "color", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HorseData#color was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn style(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HorseData", self.object, "style", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HorseData#style was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn variant(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/HorseData", self.object, "variant", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/HorseData#variant was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IgniteableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IgniteableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IgniteableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IgniteableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/IgniteableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fire_ticks(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/IgniteableData", self.object, "fireTicks", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/IgniteableData#fireTicks was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn fire_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/IgniteableData", self.object, "fireDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/IgniteableData#fireDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InvisibilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InvisibilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InvisibilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InvisibilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn invisible(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData", self.object, "invisible", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData#invisible was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn vanish(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData", self.object, "vanish", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData#vanish was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn ignores_collision_detection(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData", self.object, "ignoresCollisionDetection", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData#ignoresCollisionDetection was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn untargetable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData", self.object, "untargetable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/InvisibilityData#untargetable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InvulnerabilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InvulnerabilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InvulnerabilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InvulnerabilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/InvulnerabilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn invulnerable_ticks(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/InvulnerabilityData", self.object, "invulnerableTicks", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/InvulnerabilityData#invulnerableTicks was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct JoinData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl JoinData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    JoinData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `JoinData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/JoinData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn first_played(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/JoinData", self.object, "firstPlayed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/JoinData#firstPlayed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn last_played(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/JoinData", self.object, "lastPlayed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/JoinData#lastPlayed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct KnockbackData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl KnockbackData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    KnockbackData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `KnockbackData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/KnockbackData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn knockback_strength(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/KnockbackData", self.object, "knockbackStrength", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/KnockbackData#knockbackStrength was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LeashData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LeashData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LeashData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LeashData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/LeashData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn leash_holder(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/LeashData", self.object, "leashHolder", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/LeashData#leashHolder was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MinecartBlockData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MinecartBlockData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MinecartBlockData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MinecartBlockData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/MinecartBlockData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn block(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/MinecartBlockData", self.object, "block", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/MinecartBlockData#block was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn offset(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/MinecartBlockData", self.object, "offset", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/MinecartBlockData#offset was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MovementSpeedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MovementSpeedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MovementSpeedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MovementSpeedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/MovementSpeedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn walk_speed(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/MovementSpeedData", self.object, "walkSpeed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/MovementSpeedData#walkSpeed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn fly_speed(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/MovementSpeedData", self.object, "flySpeed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/MovementSpeedData#flySpeed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OcelotData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OcelotData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OcelotData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OcelotData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/OcelotData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PassengerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PassengerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PassengerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PassengerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PassengerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn passengers(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/PassengerData", self.object, "passengers", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/PassengerData#passengers was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PersistingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PersistingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PersistingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PersistingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PersistingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn persists(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/PersistingData", self.object, "persists", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/PersistingData#persists was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PickupRuleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PickupRuleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PickupRuleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PickupRuleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PickupRuleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PigSaddleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PigSaddleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PigSaddleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PigSaddleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PigSaddleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn saddle(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/PigSaddleData", self.object, "saddle", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/PigSaddleData#saddle was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlayerCreatedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlayerCreatedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlayerCreatedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerCreatedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PlayerCreatedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn player_created(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/PlayerCreatedData", self.object, "playerCreated", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/PlayerCreatedData#playerCreated was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlayingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlayingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlayingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/PlayingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn playing(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/PlayingData", self.object, "playing", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/PlayingData#playing was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RabbitData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RabbitData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RabbitData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RabbitData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/RabbitData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RespawnLocationData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RespawnLocationData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RespawnLocationData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RespawnLocationData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/RespawnLocationData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn respawn_location(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/RespawnLocationData", self.object, "respawnLocation", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/RespawnLocationData#respawnLocation was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "util", feature = "world"))]
+  pub fn get_for_world(&self, world: ::sponge::world::World) -> Option<::sponge::util::RespawnLocation> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/RespawnLocationData", self.object, "getForWorld", "(Lorg/spongepowered/api/world/World;)Ljava/util/Optional;", CallObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/RespawnLocationData#getForWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::RespawnLocation { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ScreamingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ScreamingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ScreamingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ScreamingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ScreamingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn screaming(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ScreamingData", self.object, "screaming", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ScreamingData#screaming was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShatteringData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShatteringData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShatteringData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShatteringData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ShatteringData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn will_shatter(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ShatteringData", self.object, "willShatter", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ShatteringData#willShatter was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShearedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShearedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShearedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShearedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/ShearedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sheared(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/ShearedData", self.object, "sheared", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/ShearedData#sheared was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SilentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SilentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SilentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SilentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SilentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn silent(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SilentData", self.object, "silent", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SilentData#silent was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SittingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SittingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SittingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SittingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SittingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sitting(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SittingData", self.object, "sitting", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SittingData#sitting was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SizeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SizeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SizeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SizeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SizeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn base(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SizeData", self.object, "base", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SizeData#base was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn height(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SizeData", self.object, "height", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SizeData#height was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn scale(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SizeData", self.object, "scale", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SizeData#scale was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkeletonData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkeletonData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkeletonData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkeletonData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SkeletonData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkinData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkinData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkinData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkinData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SkinData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn skin_unique_id(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SkinData", self.object, "skinUniqueId", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SkinData#skinUniqueId was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SleepingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SleepingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SleepingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SleepingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SleepingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sleeping(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SleepingData", self.object, "sleeping", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SleepingData#sleeping was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlimeData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlimeData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlimeData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SlimeData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SlimeData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn size(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SlimeData", self.object, "size", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SlimeData#size was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SneakingData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SneakingData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SneakingData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SneakingData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SneakingData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sneaking(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SneakingData", self.object, "sneaking", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SneakingData#sneaking was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SprintData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SprintData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SprintData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SprintData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/SprintData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn sprinting(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/SprintData", self.object, "sprinting", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/SprintData#sprinting was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/StatisticData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn statistics(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/StatisticData", self.object, "statistics", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/StatisticData#statistics was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StuckArrowsData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StuckArrowsData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StuckArrowsData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StuckArrowsData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/StuckArrowsData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn stuck_arrows(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/StuckArrowsData", self.object, "stuckArrows", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/StuckArrowsData#stuckArrows was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TameableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TameableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TameableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TameableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/TameableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn owner(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/TameableData", self.object, "owner", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/TameableData#owner was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TradeOfferData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TradeOfferData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TradeOfferData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TradeOfferData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/TradeOfferData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn trade_offers(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/TradeOfferData", self.object, "tradeOffers", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/TradeOfferData#tradeOffers was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VehicleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VehicleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VehicleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VehicleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/VehicleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn vehicle(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/VehicleData", self.object, "vehicle", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/VehicleData#vehicle was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn base_vehicle(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/VehicleData", self.object, "baseVehicle", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/VehicleData#baseVehicle was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VelocityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VelocityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VelocityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VelocityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/VelocityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn velocity(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/entity/VelocityData", self.object, "velocity", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/entity/VelocityData#velocity was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VillagerZombieData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VillagerZombieData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VillagerZombieData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VillagerZombieData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/entity/VillagerZombieData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,517 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AuthorData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AuthorData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AuthorData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AuthorData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/AuthorData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn author(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/AuthorData", self.object, "author", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/AuthorData#author was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/BlockItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn state(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/BlockItemData", self.object, "state", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/BlockItemData#state was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BreakableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BreakableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BreakableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BreakableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/BreakableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn breakable(&self) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/BreakableData", self.object, "breakable", "()Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/BreakableData#breakable was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CoalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CoalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CoalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CoalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/CoalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CookedFishData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CookedFishData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CookedFishData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CookedFishData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/CookedFishData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DurabilityData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DurabilityData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DurabilityData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DurabilityData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/DurabilityData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn durability(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/DurabilityData", self.object, "durability", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/DurabilityData#durability was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn unbreakable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/DurabilityData", self.object, "unbreakable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/DurabilityData#unbreakable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnchantmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnchantmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnchantmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnchantmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/EnchantmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn enchantments(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/EnchantmentData", self.object, "enchantments", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/EnchantmentData#enchantments was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FishData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FishData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FishData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FishData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/FishData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GenerationData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GenerationData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GenerationData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GenerationData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/GenerationData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn generation(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/GenerationData", self.object, "generation", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/GenerationData#generation was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GoldenAppleData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GoldenAppleData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GoldenAppleData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GoldenAppleData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/GoldenAppleData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HideData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HideData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HideData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HideData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/HideData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn hide_enchantments(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideEnchantments", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideEnchantments was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn hide_attributes(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideAttributes", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideAttributes was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn hide_unbreakable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideUnbreakable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideUnbreakable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn hide_can_destroy(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideCanDestroy", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideCanDestroy was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn hide_can_place(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideCanPlace", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideCanPlace was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn hide_miscellaneous(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/HideData", self.object, "hideMiscellaneous", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/HideData#hideMiscellaneous was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InventoryItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventoryItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventoryItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InventoryItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/InventoryItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LoreData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LoreData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LoreData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LoreData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/LoreData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lore(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/LoreData", self.object, "lore", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/LoreData#lore was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MapItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MapItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MapItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MapItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/MapItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PagedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PagedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PagedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PagedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/PagedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn pages(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/PagedData", self.object, "pages", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/PagedData#pages was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlaceableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlaceableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlaceableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlaceableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/PlaceableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn placeable(&self) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/PlaceableData", self.object, "placeable", "()Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/PlaceableData#placeable was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpawnableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpawnableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpawnableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/SpawnableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StoredEnchantmentData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StoredEnchantmentData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StoredEnchantmentData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StoredEnchantmentData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/item/StoredEnchantmentData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn enchantments(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/item/StoredEnchantmentData", self.object, "enchantments", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/item/StoredEnchantmentData#enchantments was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
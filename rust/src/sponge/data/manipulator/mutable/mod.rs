@@ -0,0 +1,702 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod block;
+pub mod common;
+pub mod entity;
+pub mod item;
+pub mod tileentity;
+
+#[derive(Debug)]
+pub struct ColoredData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ColoredData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ColoredData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ColoredData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/ColoredData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ColoredData", self.object, "color", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ColoredData#color was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/CommandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn stored_command(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/CommandData", self.object, "storedCommand", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/CommandData#storedCommand was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn success_count(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/CommandData", self.object, "successCount", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/CommandData#successCount was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn does_track_output(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/CommandData", self.object, "doesTrackOutput", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/CommandData#doesTrackOutput was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn last_output(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/CommandData", self.object, "lastOutput", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/CommandData#lastOutput was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisplayNameData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisplayNameData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisplayNameData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisplayNameData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/DisplayNameData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn display_name(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/DisplayNameData", self.object, "displayName", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/DisplayNameData#displayName was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DyeableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DyeableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DyeableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DyeableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/DyeableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FireworkEffectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FireworkEffectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FireworkEffectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FireworkEffectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/FireworkEffectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn effects(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/FireworkEffectData", self.object, "effects", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/FireworkEffectData#effects was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FireworkRocketData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FireworkRocketData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FireworkRocketData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FireworkRocketData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/FireworkRocketData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn flight_modifier(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/FireworkRocketData", self.object, "flightModifier", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/FireworkRocketData#flightModifier was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ListData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ListData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ListData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ListData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/ListData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_list_value(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "getListValue", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#getListValue was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_list(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "asList", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#asList was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "get", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn set_element(&self, value: i32, object: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "setElement", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#setElement was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn set_elements(&self, list: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "setElements", "(Ljava/util/List;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, list);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#setElements was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn add_element_object(&self, object: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "addElement", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#addElement was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn add_element_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "addElement", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#addElement was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn add_elements(&self, iterable: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "addElements", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#addElements was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn remove_i32(&self, value: i32) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "remove", "(I)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#remove was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn remove_object(&self, object: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "remove", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#remove was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_iterable(&self, iterable: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "removeAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#removeAll was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_predicate(&self, predicate: jobject) -> ::sponge::data::manipulator::mutable::ListData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/ListData", self.object, "removeAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/manipulator/mutable/ListData;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/ListData#removeAll was null") }
+    ::sponge::data::manipulator::mutable::ListData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MappedData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MappedData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MappedData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MappedData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/MappedData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "get", "(Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_map_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "getMapKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#getMapKeys was null") }
+    ret
+  }
+
+  pub fn get_map_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "getMapValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#getMapValues was null") }
+    ret
+  }
+
+  pub fn get_map_value(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "getMapValue", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#getMapValue was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn as_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "asMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#asMap was null") }
+    ret
+  }
+
+  pub fn put(&self, object: jobject, object_2: jobject) -> ::sponge::data::manipulator::mutable::MappedData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/MappedData;", CallObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#put was null") }
+    ::sponge::data::manipulator::mutable::MappedData { env: self.env, object: ret }
+  }
+
+  pub fn put_all(&self, map: jobject) -> ::sponge::data::manipulator::mutable::MappedData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "putAll", "(Ljava/util/Map;)Lorg/spongepowered/api/data/manipulator/mutable/MappedData;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#putAll was null") }
+    ::sponge::data::manipulator::mutable::MappedData { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, object: jobject) -> ::sponge::data::manipulator::mutable::MappedData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MappedData", self.object, "remove", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/manipulator/mutable/MappedData;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MappedData#remove was null") }
+    ::sponge::data::manipulator::mutable::MappedData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MobSpawnerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MobSpawnerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MobSpawnerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MobSpawnerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "remainingDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#remainingDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn minimum_spawn_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "minimumSpawnDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#minimumSpawnDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn maximum_spawn_delay(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "maximumSpawnDelay", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#maximumSpawnDelay was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn spawn_count(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "spawnCount", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#spawnCount was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn maximum_nearby_entities(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "maximumNearbyEntities", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#maximumNearbyEntities was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn required_player_range(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "requiredPlayerRange", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#requiredPlayerRange was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn spawn_range(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "spawnRange", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#spawnRange was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn next_entity_to_spawn(&self) -> ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "nextEntityToSpawn", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#nextEntityToSpawn was null") }
+    ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue { env: self.env, object: ret }
+  }
+
+  pub fn possible_entities_to_spawn(&self) -> ::sponge::data::value::mutable::WeightedCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData", self.object, "possibleEntitiesToSpawn", "()Lorg/spongepowered/api/data/value/mutable/WeightedCollectionValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData#possibleEntitiesToSpawn was null") }
+    ::sponge::data::value::mutable::WeightedCollectionValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PotionEffectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PotionEffectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PotionEffectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PotionEffectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/PotionEffectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn effects(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/PotionEffectData", self.object, "effects", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/PotionEffectData#effects was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RepresentedItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RepresentedItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RepresentedItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RepresentedItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/RepresentedItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn item(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/RepresentedItemData", self.object, "item", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/RepresentedItemData#item was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RepresentedPlayerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RepresentedPlayerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RepresentedPlayerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RepresentedPlayerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/RepresentedPlayerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn owner(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/RepresentedPlayerData", self.object, "owner", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/RepresentedPlayerData#owner was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RotationalData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RotationalData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RotationalData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RotationalData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/RotationalData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkullData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkullData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkullData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkullData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/SkullData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetedLocationData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetedLocationData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetedLocationData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetedLocationData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/TargetedLocationData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn target(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/TargetedLocationData", self.object, "target", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/TargetedLocationData#target was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VariantData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VariantData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VariantData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VariantData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/VariantData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/VariantData", self.object, "type", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/VariantData#type was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WetData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WetData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WetData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WetData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/WetData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn wet(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/WetData", self.object, "wet", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/WetData#wet was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+pub mod mob_spawner_data {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct NextEntityToSpawnValue {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl NextEntityToSpawnValue {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      NextEntityToSpawnValue {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `NextEntityToSpawnValue`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn set(&self, entity_type: ::sponge::entity::EntityType, collection: jobject) -> ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue", self.object, "set", "(Lorg/spongepowered/api/entity/EntityType;Ljava/util/Collection;)Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue;", CallObjectMethodA, entity_type.object, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue#set was null") }
+      ::sponge::data::manipulator::mutable::mob_spawner_data::NextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+    pub fn as_immutable(&self) -> ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue", self.object, "asImmutable", "()Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData$ImmutableNextEntityToSpawnValue;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/MobSpawnerData$NextEntityToSpawnValue#asImmutable was null") }
+      ::sponge::data::manipulator::immutable::immutable_mob_spawner_data::ImmutableNextEntityToSpawnValue { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,269 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BannerData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BannerData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BannerData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BannerData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/BannerData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn base_color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BannerData", self.object, "baseColor", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BannerData#baseColor was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn patterns_list(&self) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BannerData", self.object, "patternsList", "()Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BannerData#patternsList was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BeaconData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BeaconData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BeaconData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BeaconData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn primary_effect(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData", self.object, "primaryEffect", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData#primaryEffect was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn secondary_effect(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData", self.object, "secondaryEffect", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData#secondaryEffect was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn clear_effects(&self) -> ::sponge::data::manipulator::mutable::tileentity::BeaconData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData", self.object, "clearEffects", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BeaconData#clearEffects was null") }
+    ::sponge::data::manipulator::mutable::tileentity::BeaconData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BrewingStandData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrewingStandData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrewingStandData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrewingStandData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/BrewingStandData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn remaining_brew_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/BrewingStandData", self.object, "remainingBrewTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/BrewingStandData#remainingBrewTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CooldownData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CooldownData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CooldownData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CooldownData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/CooldownData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn cooldown(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/CooldownData", self.object, "cooldown", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/CooldownData#cooldown was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FurnaceData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FurnaceData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FurnaceData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FurnaceData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn passed_burn_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData", self.object, "passedBurnTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData#passedBurnTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_burn_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData", self.object, "maxBurnTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData#maxBurnTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn passed_cook_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData", self.object, "passedCookTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData#passedCookTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn max_cook_time(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData", self.object, "maxCookTime", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/FurnaceData#maxCookTime was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LockableData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LockableData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LockableData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LockableData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/LockableData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lock_token(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/LockableData", self.object, "lockToken", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/LockableData#lockToken was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NoteData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NoteData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NoteData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NoteData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/NoteData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn note(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/NoteData", self.object, "note", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/NoteData#note was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SignData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SignData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SignData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SignData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/manipulator/mutable/tileentity/SignData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn lines(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/manipulator/mutable/tileentity/SignData", self.object, "lines", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/manipulator/mutable/tileentity/SignData#lines was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,36 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct MergeFunction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MergeFunction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MergeFunction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MergeFunction`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/merge/MergeFunction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn merge(&self, value_container: ::sponge::data::value::ValueContainer, value_container_2: ::sponge::data::value::ValueContainer) -> ::sponge::data::value::ValueContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/merge/MergeFunction", self.object, "merge", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/value/ValueContainer;)Lorg/spongepowered/api/data/value/ValueContainer;", CallObjectMethodA, value_container.object, value_container_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/merge/MergeFunction#merge was null") }
+    ::sponge::data::value::ValueContainer { env: self.env, object: ret }
+  }
+
+  pub fn and_then(&self, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::merge::MergeFunction {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/merge/MergeFunction", self.object, "andThen", "(Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/merge/MergeFunction;", CallObjectMethodA, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/merge/MergeFunction#andThen was null") }
+    ::sponge::data::merge::MergeFunction { env: self.env, object: ret }
+  }
+
+}
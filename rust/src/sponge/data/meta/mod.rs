@@ -0,0 +1,152 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ItemEnchantment {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemEnchantment {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemEnchantment {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ItemEnchantment`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/meta/ItemEnchantment", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn new(env: *mut JNIEnv, enchantment: ::sponge::item::Enchantment, value: i32) -> ::sponge::data::meta::ItemEnchantment {
+    let ret = new_object!(env, "org/spongepowered/api/data/meta/ItemEnchantment", "(Lorg/spongepowered/api/item/Enchantment;I)V", enchantment.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/ItemEnchantment#<init> was null") }
+    ::sponge::data::meta::ItemEnchantment { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_enchantment(&self) -> ::sponge::item::Enchantment {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "getEnchantment", "()Lorg/spongepowered/api/item/Enchantment;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/ItemEnchantment#getEnchantment was null") }
+    ::sponge::item::Enchantment { env: self.env, object: ret }
+  }
+
+  pub fn get_level(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "getLevel", "()I", CallIntMethod)
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/ItemEnchantment#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/meta/ItemEnchantment", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PatternLayer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PatternLayer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PatternLayer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PatternLayer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/meta/PatternLayer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::meta::PatternLayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/meta/PatternLayer", "of", "(Lorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/meta/PatternLayer;", CallStaticObjectMethodA, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer#of was null") }
+    ::sponge::data::meta::PatternLayer { env: env, object: ret }
+  }
+
+  pub fn get_shape(&self) -> ::sponge::data::type_::BannerPatternShape {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer", self.object, "getShape", "()Lorg/spongepowered/api/data/type/BannerPatternShape;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer#getShape was null") }
+    ::sponge::data::type_::BannerPatternShape { env: self.env, object: ret }
+  }
+
+  pub fn get_color(&self) -> ::sponge::data::type_::DyeColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer", self.object, "getColor", "()Lorg/spongepowered/api/data/type/DyeColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer#getColor was null") }
+    ::sponge::data::type_::DyeColor { env: self.env, object: ret }
+  }
+
+}
+
+pub mod pattern_layer {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/meta/PatternLayer$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn pattern(&self, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape) -> ::sponge::data::meta::pattern_layer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer$Builder", self.object, "pattern", "(Lorg/spongepowered/api/data/type/BannerPatternShape;)Lorg/spongepowered/api/data/meta/PatternLayer$Builder;", CallObjectMethodA, banner_pattern_shape.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer$Builder#pattern was null") }
+      ::sponge::data::meta::pattern_layer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::meta::pattern_layer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer$Builder", self.object, "color", "(Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/meta/PatternLayer$Builder;", CallObjectMethodA, dye_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer$Builder#color was null") }
+      ::sponge::data::meta::pattern_layer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::data::meta::PatternLayer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer$Builder", self.object, "build", "()Lorg/spongepowered/api/data/meta/PatternLayer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer$Builder#build was null") }
+      ::sponge::data::meta::PatternLayer { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::data::meta::pattern_layer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/meta/PatternLayer$Builder", self.object, "reset", "()Lorg/spongepowered/api/data/meta/PatternLayer$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/meta/PatternLayer$Builder#reset was null") }
+      ::sponge::data::meta::pattern_layer::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,1621 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod key;
+pub mod manipulator;
+pub mod merge;
+pub mod meta;
+pub mod persistence;
+pub mod property;
+pub mod translator;
+pub mod type_;
+pub mod value;
+
+#[derive(Debug)]
+pub struct DataContainer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataContainer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataContainer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataContainer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataContainer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_data_query_object(&self, data_query: ::sponge::data::DataQuery, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataContainer", self.object, "set", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, data_query.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataContainer#set was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn set_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataContainer", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataContainer#set was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataContainer", self.object, "remove", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataContainer#remove was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn validate_raw_data(&self, data_container: ::sponge::data::DataContainer) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/DataHolder", self.object, "validateRawData", "(Lorg/spongepowered/api/data/DataContainer;)Z", CallBooleanMethodA, data_container.object) != 0
+  }
+
+  pub fn set_raw_data(&self, data_container: ::sponge::data::DataContainer) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataHolder", self.object, "setRawData", "(Lorg/spongepowered/api/data/DataContainer;)V", CallVoidMethodA, data_container.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_builder(&self, class: jobject, data_builder: ::sponge::data::persistence::DataBuilder) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "registerBuilder", "(Ljava/lang/Class;Lorg/spongepowered/api/data/persistence/DataBuilder;)V", CallVoidMethodA, class, data_builder.object);
+  }
+
+  pub fn register_content_updater(&self, class: jobject, data_content_updater: ::sponge::data::persistence::DataContentUpdater) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "registerContentUpdater", "(Ljava/lang/Class;Lorg/spongepowered/api/data/persistence/DataContentUpdater;)V", CallVoidMethodA, class, data_content_updater.object);
+  }
+
+  pub fn get_wrapped_content_updater(&self, class: jobject, value: i32, value_2: i32) -> Option<::sponge::data::persistence::DataContentUpdater> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getWrappedContentUpdater", "(Ljava/lang/Class;II)Ljava/util/Optional;", CallObjectMethodA, class, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getWrappedContentUpdater was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::persistence::DataContentUpdater { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_builder(&self, class: jobject) -> Option<::sponge::data::persistence::DataBuilder> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getBuilder", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getBuilder was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::persistence::DataBuilder { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn deserialize(&self, class: jobject, data_view: ::sponge::data::DataView) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "deserialize", "(Ljava/lang/Class;Lorg/spongepowered/api/data/DataView;)Ljava/util/Optional;", CallObjectMethodA, class, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#deserialize was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn register_class_immutable_data_builder(&self, class: jobject, immutable_data_builder: ::sponge::data::ImmutableDataBuilder) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "register", "(Ljava/lang/Class;Lorg/spongepowered/api/data/ImmutableDataBuilder;)V", CallVoidMethodA, class, immutable_data_builder.object);
+  }
+
+  pub fn register_class_class_data_manipulator_builder(&self, class: jobject, class_2: jobject, data_manipulator_builder: ::sponge::data::manipulator::DataManipulatorBuilder) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "register", "(Ljava/lang/Class;Ljava/lang/Class;Lorg/spongepowered/api/data/manipulator/DataManipulatorBuilder;)V", CallVoidMethodA, class, class_2, data_manipulator_builder.object);
+  }
+
+  pub fn get_immutable_builder(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getImmutableBuilder", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getImmutableBuilder was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_manipulator_builder(&self, class: jobject) -> Option<::sponge::data::manipulator::DataManipulatorBuilder> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getManipulatorBuilder", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getManipulatorBuilder was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::manipulator::DataManipulatorBuilder { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_immutable_manipulator_builder(&self, class: jobject) -> Option<::sponge::data::manipulator::DataManipulatorBuilder> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getImmutableManipulatorBuilder", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getImmutableManipulatorBuilder was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::manipulator::DataManipulatorBuilder { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn register_serializer(&self, class: jobject, data_serializer: ::sponge::data::persistence::DataSerializer) {
+    bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "registerSerializer", "(Ljava/lang/Class;Lorg/spongepowered/api/data/persistence/DataSerializer;)V", CallVoidMethodA, class, data_serializer.object);
+  }
+
+  pub fn get_serializer(&self, class: jobject) -> Option<::sponge::data::persistence::DataSerializer> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataManager", self.object, "getSerializer", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataManager#getSerializer was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::persistence::DataSerializer { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataQuery {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataQuery {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataQuery {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataQuery`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataQuery", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv) -> ::sponge::data::DataQuery {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataQuery", "of", "()Lorg/spongepowered/api/data/DataQuery;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#of was null") }
+    ::sponge::data::DataQuery { env: env, object: ret }
+  }
+
+  pub fn of_char_string(env: *mut JNIEnv, value: char, string: jobject) -> ::sponge::data::DataQuery {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataQuery", "of", "(CLjava/lang/String;)Lorg/spongepowered/api/data/DataQuery;", CallStaticObjectMethodA, value, string);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#of was null") }
+    ::sponge::data::DataQuery { env: env, object: ret }
+  }
+
+  pub fn of_object_array(env: *mut JNIEnv, objects: Vec<jobject>) -> ::sponge::data::DataQuery {
+    unimplemented!();
+  }
+  pub fn of_list(env: *mut JNIEnv, list: jobject) -> ::sponge::data::DataQuery {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataQuery", "of", "(Ljava/util/List;)Lorg/spongepowered/api/data/DataQuery;", CallStaticObjectMethodA, list);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#of was null") }
+    ::sponge::data::DataQuery { env: env, object: ret }
+  }
+
+  pub fn get_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "getParts", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#getParts was null") }
+    ret
+  }
+
+  pub fn then(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "then", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataQuery;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#then was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+  pub fn get_query_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "getQueryParts", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#getQueryParts was null") }
+    ret
+  }
+
+  pub fn pop(&self) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "pop", "()Lorg/spongepowered/api/data/DataQuery;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#pop was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+  pub fn last(&self) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "last", "()Lorg/spongepowered/api/data/DataQuery;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#last was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+  pub fn as_string_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "asString", "(Ljava/lang/String;)Ljava/lang/String;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#asString was null") }
+    ret
+  }
+
+  pub fn as_string_char(&self, value: char) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "asString", "(C)Ljava/lang/String;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#asString was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataQuery#toString was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/DataQuery", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataSerializable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataSerializable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataSerializable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataSerializable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataSerializable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/DataSerializable", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataSerializable", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataSerializable#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataTransactionResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataTransactionResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataTransactionResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataTransactionResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataTransactionResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::data::data_transaction_result::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "builder", "()Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#builder was null") }
+    ::sponge::data::data_transaction_result::Builder { env: env, object: ret }
+  }
+
+  pub fn success_no_data(env: *mut JNIEnv) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successNoData", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successNoData was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn success_result(env: *mut JNIEnv, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successResult", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, immutable_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn success_replace_result_immutable_value_immutable_value(env: *mut JNIEnv, immutable_value: ::sponge::data::value::immutable::ImmutableValue, immutable_value_2: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successReplaceResult", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, immutable_value.object, immutable_value_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successReplaceResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn success_replace_result_collection_collection(env: *mut JNIEnv, collection: jobject, collection_2: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successReplaceResult", "(Ljava/util/Collection;Ljava/util/Collection;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, collection, collection_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successReplaceResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn success_remove_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successRemove", "(Ljava/util/Collection;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successRemove was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn success_remove_immutable_value(env: *mut JNIEnv, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "successRemove", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, immutable_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#successRemove was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn fail_result_immutable_value(env: *mut JNIEnv, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "failResult", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, immutable_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#failResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn fail_result_iterable(env: *mut JNIEnv, iterable: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "failResult", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#failResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn fail_no_data(env: *mut JNIEnv) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "failNoData", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#failNoData was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn error_result(env: *mut JNIEnv, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::DataTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/DataTransactionResult", "errorResult", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallStaticObjectMethodA, immutable_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#errorResult was null") }
+    ::sponge::data::DataTransactionResult { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::data::data_transaction_result::Type {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "getType", "()Lorg/spongepowered/api/data/DataTransactionResult$Type;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#getType was null") }
+    <::sponge::data::data_transaction_result::Type as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn is_successful(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "isSuccessful", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_successful_data(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "getSuccessfulData", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#getSuccessfulData was null") }
+    ret
+  }
+
+  pub fn get_rejected_data(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "getRejectedData", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#getRejectedData was null") }
+    ret
+  }
+
+  pub fn get_replaced_data(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "getReplacedData", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#getReplacedData was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataView {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataView {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataView {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataView`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataView", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_current_path(&self) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getCurrentPath", "()Lorg/spongepowered/api/data/DataQuery;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getCurrentPath was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getName was null") }
+    ret
+  }
+
+  pub fn get_parent(&self) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getParent", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getParent was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_keys(&self, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getKeys", "(Z)Ljava/util/Set;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getValues", "(Z)Ljava/util/Map;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getValues was null") }
+    ret
+  }
+
+  pub fn contains_data_query(&self, data_query: ::sponge::data::DataQuery) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "contains", "(Lorg/spongepowered/api/data/DataQuery;)Z", CallBooleanMethodA, data_query.object) != 0
+  }
+
+  pub fn contains_data_query_data_query_array(&self, data_query: ::sponge::data::DataQuery, data_queries: Vec<::sponge::data::DataQuery>) -> bool {
+    unimplemented!();
+  }
+  pub fn contains_key(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "contains", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn contains_key_key_array(&self, key: ::sponge::data::key::Key, keys: Vec<::sponge::data::key::Key>) -> bool {
+    unimplemented!();
+  }
+  pub fn get(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "get", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_data_query_object(&self, data_query: ::sponge::data::DataQuery, object: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "set", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#set was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn set_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#set was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "remove", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#remove was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn create_view_data_query(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "createView", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#createView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn create_view_data_query_map(&self, data_query: ::sponge::data::DataQuery, map: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "createView", "(Lorg/spongepowered/api/data/DataQuery;Ljava/util/Map;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#createView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn get_view(&self, data_query: ::sponge::data::DataQuery) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getView", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getView was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_map(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getMap", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getMap was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_boolean(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getBoolean", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getBoolean was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_short(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getShort", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getShort was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_byte(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getByte", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getByte was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_int(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getInt", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getInt was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_long(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getLong", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getLong was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_float(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getFloat", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getFloat was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_double(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getDouble", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getDouble was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_string(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getString", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getString was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_string_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getStringList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getStringList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_character_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getCharacterList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getCharacterList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_boolean_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getBooleanList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getBooleanList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_byte_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getByteList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getByteList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_short_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getShortList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getShortList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_integer_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getIntegerList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getIntegerList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_long_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getLongList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getLongList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_float_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getFloatList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getFloatList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_double_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getDoubleList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getDoubleList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_map_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getMapList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getMapList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_view_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getViewList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getViewList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_serializable(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getSerializable", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getSerializable was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_serializable_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getSerializableList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getSerializableList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_object(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getObject", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getObject was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_object_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getObjectList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getObjectList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_catalog_type(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getCatalogType", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getCatalogType was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_catalog_type_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "getCatalogTypeList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#getCatalogTypeList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn copy(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/DataView", self.object, "copy", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/DataView#copy was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDataBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDataBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDataBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDataBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/ImmutableDataBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn add_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> ::sponge::data::ImmutableDataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "add", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)Lorg/spongepowered/api/data/ImmutableDataBuilder;", CallObjectMethodA, data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#add was null") }
+    ::sponge::data::ImmutableDataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn add_immutable_data_manipulator(&self, immutable_data_manipulator: ::sponge::data::manipulator::ImmutableDataManipulator) -> ::sponge::data::ImmutableDataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "add", "(Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;)Lorg/spongepowered/api/data/ImmutableDataBuilder;", CallObjectMethodA, immutable_data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#add was null") }
+    ::sponge::data::ImmutableDataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn add_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::ImmutableDataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "add", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/ImmutableDataBuilder;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#add was null") }
+    ::sponge::data::ImmutableDataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_immutable_data_holder(&self, immutable_data_holder: ::sponge::data::ImmutableDataHolder) -> ::sponge::data::ImmutableDataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "from", "(Lorg/spongepowered/api/data/ImmutableDataHolder;)Lorg/spongepowered/api/data/ImmutableDataBuilder;", CallObjectMethodA, immutable_data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#from was null") }
+    ::sponge::data::ImmutableDataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn build(&self) -> ::sponge::data::ImmutableDataHolder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "build", "()Lorg/spongepowered/api/data/ImmutableDataHolder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#build was null") }
+    ::sponge::data::ImmutableDataHolder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::data::ImmutableDataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "reset", "()Lorg/spongepowered/api/data/ImmutableDataBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#reset was null") }
+    ::sponge::data::ImmutableDataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#from was null") }
+    ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableDataHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableDataHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableDataHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableDataHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/ImmutableDataHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_manipulators(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/ImmutableDataHolder", self.object, "getManipulators", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/ImmutableDataHolder#getManipulators was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LocatableSnapshot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LocatableSnapshot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LocatableSnapshot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LocatableSnapshot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/LocatableSnapshot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_world_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/LocatableSnapshot", self.object, "getWorldUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/LocatableSnapshot#getWorldUniqueId was null") }
+    ret
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/LocatableSnapshot", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/LocatableSnapshot#getPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/LocatableSnapshot", self.object, "getLocation", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/LocatableSnapshot#getLocation was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn with_location(&self, location: ::sponge::world::Location) -> ::sponge::data::LocatableSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/LocatableSnapshot", self.object, "withLocation", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/data/LocatableSnapshot;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/LocatableSnapshot#withLocation was null") }
+    ::sponge::data::LocatableSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MemoryDataContainer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MemoryDataContainer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MemoryDataContainer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `MemoryDataContainer`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/MemoryDataContainer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::data::MemoryDataContainer {
+    let ret = new_object!(env, "org/spongepowered/api/data/MemoryDataContainer", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#<init> was null") }
+    ::sponge::data::MemoryDataContainer { env: env, object: ret }
+  }
+
+  pub fn get_parent(&self) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataContainer", self.object, "getParent", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#getParent was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataContainer", self.object, "getContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#getContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn set_data_query_object(&self, data_query: ::sponge::data::DataQuery, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataContainer", self.object, "set", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, data_query.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#set was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn set_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataContainer", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#set was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataContainer", self.object, "remove", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataContainer#remove was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MemoryDataView {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MemoryDataView {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MemoryDataView {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MemoryDataView`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/MemoryDataView", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_current_path(&self) -> ::sponge::data::DataQuery {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getCurrentPath", "()Lorg/spongepowered/api/data/DataQuery;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getCurrentPath was null") }
+    ::sponge::data::DataQuery { env: self.env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getName was null") }
+    ret
+  }
+
+  pub fn get_parent(&self) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getParent", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getParent was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_keys(&self, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getKeys", "(Z)Ljava/util/Set;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getValues", "(Z)Ljava/util/Map;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getValues was null") }
+    ret
+  }
+
+  pub fn contains_data_query(&self, data_query: ::sponge::data::DataQuery) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "contains", "(Lorg/spongepowered/api/data/DataQuery;)Z", CallBooleanMethodA, data_query.object) != 0
+  }
+
+  pub fn contains_data_query_data_query_array(&self, data_query: ::sponge::data::DataQuery, data_queries: Vec<::sponge::data::DataQuery>) -> bool {
+    unimplemented!();
+  }
+  pub fn get(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "get", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_data_query_object(&self, data_query: ::sponge::data::DataQuery, object: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "set", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#set was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn set_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "set", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#set was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "remove", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#remove was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn create_view_data_query(&self, data_query: ::sponge::data::DataQuery) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "createView", "(Lorg/spongepowered/api/data/DataQuery;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#createView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn create_view_data_query_map(&self, data_query: ::sponge::data::DataQuery, map: jobject) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "createView", "(Lorg/spongepowered/api/data/DataQuery;Ljava/util/Map;)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, data_query.object, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#createView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  pub fn get_view(&self, data_query: ::sponge::data::DataQuery) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getView", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getView was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_map(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getMap", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getMap was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_boolean(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getBoolean", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getBoolean was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_byte(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getByte", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getByte was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_short(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getShort", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getShort was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_int(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getInt", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getInt was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_long(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getLong", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getLong was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_float(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getFloat", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getFloat was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_double(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getDouble", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getDouble was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_string(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getString", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getString was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_string_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getStringList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getStringList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_character_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getCharacterList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getCharacterList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_boolean_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getBooleanList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getBooleanList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_byte_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getByteList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getByteList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_short_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getShortList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getShortList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_integer_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getIntegerList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getIntegerList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_long_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getLongList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getLongList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_float_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getFloatList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getFloatList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_double_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getDoubleList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getDoubleList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_map_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getMapList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getMapList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_view_list(&self, data_query: ::sponge::data::DataQuery) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getViewList", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getViewList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_serializable(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getSerializable", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getSerializable was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_serializable_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getSerializableList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getSerializableList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_catalog_type(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getCatalogType", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getCatalogType was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_catalog_type_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getCatalogTypeList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getCatalogTypeList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_object(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getObject", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getObject was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_object_list(&self, data_query: ::sponge::data::DataQuery, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "getObjectList", "(Lorg/spongepowered/api/data/DataQuery;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, data_query.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#getObjectList was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn copy(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "copy", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#copy was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/MemoryDataView", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/MemoryDataView#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Property {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Property {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Property {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Property`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/Property", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Property", self.object, "getKey", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Property#getKey was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Property", self.object, "getValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Property#getValue was null") }
+    ret
+  }
+
+  pub fn get_operator(&self) -> ::sponge::data::property_::Operator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Property", self.object, "getOperator", "()Lorg/spongepowered/api/data/Property$Operator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Property#getOperator was null") }
+    <::sponge::data::property_::Operator as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn matches(&self, property: ::sponge::data::Property) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/Property", self.object, "matches", "(Lorg/spongepowered/api/data/Property;)Z", CallBooleanMethodA, property.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Queries {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Queries {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Queries {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Queries`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/Queries", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Transaction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Transaction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Transaction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Transaction`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/Transaction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, data_serializable: ::sponge::data::DataSerializable, data_serializable_2: ::sponge::data::DataSerializable) -> ::sponge::data::Transaction {
+    let ret = new_object!(env, "org/spongepowered/api/data/Transaction", "(Lorg/spongepowered/api/data/DataSerializable;Lorg/spongepowered/api/data/DataSerializable;)V", data_serializable.object, data_serializable_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#<init> was null") }
+    ::sponge::data::Transaction { env: env, object: ret }
+  }
+
+  pub fn get_original(&self) -> ::sponge::data::DataSerializable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "getOriginal", "()Lorg/spongepowered/api/data/DataSerializable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#getOriginal was null") }
+    ::sponge::data::DataSerializable { env: self.env, object: ret }
+  }
+
+  pub fn get_default(&self) -> ::sponge::data::DataSerializable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "getDefault", "()Lorg/spongepowered/api/data/DataSerializable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#getDefault was null") }
+    ::sponge::data::DataSerializable { env: self.env, object: ret }
+  }
+
+  pub fn get_custom(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "getCustom", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#getCustom was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_custom(&self, data_serializable: ::sponge::data::DataSerializable) {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "setCustom", "(Lorg/spongepowered/api/data/DataSerializable;)V", CallVoidMethodA, data_serializable.object);
+  }
+
+  pub fn get_final(&self) -> ::sponge::data::DataSerializable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "getFinal", "()Lorg/spongepowered/api/data/DataSerializable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#getFinal was null") }
+    ::sponge::data::DataSerializable { env: self.env, object: ret }
+  }
+
+  pub fn is_valid(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "isValid", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_valid(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "setValid", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#toString was null") }
+    ret
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/Transaction", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/Transaction#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+pub mod data_transaction_result {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/DataTransactionResult$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn result(&self, type_: ::sponge::data::data_transaction_result::Type) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "result", "(Lorg/spongepowered/api/data/DataTransactionResult$Type;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&type_, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#result was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn replace_immutable_value(&self, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "replace", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, immutable_value.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#replace was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn replace_iterable(&self, iterable: jobject) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "replace", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#replace was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reject_immutable_value(&self, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "reject", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, immutable_value.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#reject was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reject_iterable(&self, iterable: jobject) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "reject", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#reject was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn success_immutable_value(&self, immutable_value: ::sponge::data::value::immutable::ImmutableValue) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "success", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValue;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, immutable_value.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#success was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn success_iterable(&self, iterable: jobject) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "success", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#success was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn absorb_result(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "absorbResult", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, data_transaction_result.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#absorbResult was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::data::DataTransactionResult {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "build", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#build was null") }
+      ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+    }
+
+    pub fn from_data_transaction_result(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "from", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethodA, data_transaction_result.object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#from was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::data::data_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "reset", "()Lorg/spongepowered/api/data/DataTransactionResult$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#reset was null") }
+      ::sponge::data::data_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/DataTransactionResult$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/DataTransactionResult$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Type {
+    Undefined,
+    Success,
+    Failure,
+    Error,
+    Cancelled,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static TYPE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/data/DataTransactionResult$Type", &["UNDEFINED", "SUCCESS", "FAILURE", "ERROR", "CANCELLED"]);
+
+  impl Type {
+    pub const VALUES: &'static [Type] = &[Type::Undefined, Type::Success, Type::Failure, Type::Error, Type::Cancelled];
+
+  }
+
+  impl ::convert::FromJava for Type {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match TYPE_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Type::VALUES[ordinal],
+        None => Type::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Type {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Type::Other(object) => object,
+        value => TYPE_CONSTANTS.get(env, Type::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
+
+pub mod property_ {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Operator {
+    Delegate,
+    Equal,
+    Notequal,
+    Greater,
+    Gequal,
+    Less,
+    Lequal,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static OPERATOR_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/data/Property$Operator", &["DELEGATE", "EQUAL", "NOTEQUAL", "GREATER", "GEQUAL", "LESS", "LEQUAL"]);
+
+  impl Operator {
+    pub const VALUES: &'static [Operator] = &[Operator::Delegate, Operator::Equal, Operator::Notequal, Operator::Greater, Operator::Gequal, Operator::Less, Operator::Lequal];
+
+    pub fn compare(&self, env: *mut JNIEnv, property: ::sponge::data::Property, property_2: ::sponge::data::Property) -> bool {
+      bound_method!(env, "org/spongepowered/api/data/Property$Operator", ::convert::ToJava::to_java(self, env), "compare", "(Lorg/spongepowered/api/data/Property;Lorg/spongepowered/api/data/Property;)Z", CallBooleanMethodA, property.object, property_2.object) != 0
+    }
+
+    pub fn default_operator(env: *mut JNIEnv) -> ::sponge::data::property_::Operator {
+      let ret = static_java_method!(env, "org/spongepowered/api/data/Property$Operator", "defaultOperator", "()Lorg/spongepowered/api/data/Property$Operator;", CallStaticObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/Property$Operator#defaultOperator was null") }
+      <::sponge::data::property_::Operator as ::convert::FromJava>::from_java(env, ret)
+    }
+
+  }
+
+  impl ::convert::FromJava for Operator {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match OPERATOR_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Operator::VALUES[ordinal],
+        None => Operator::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Operator {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Operator::Other(object) => object,
+        value => OPERATOR_CONSTANTS.get(env, Operator::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
@@ -0,0 +1,295 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractDataBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractDataBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractDataBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractDataBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/AbstractDataBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn build(&self, data_view: ::sponge::data::DataView) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/AbstractDataBuilder", self.object, "build", "(Lorg/spongepowered/api/data/DataView;)Ljava/util/Optional;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/AbstractDataBuilder#build was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/DataBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn build(&self, data_view: ::sponge::data::DataView) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataBuilder", self.object, "build", "(Lorg/spongepowered/api/data/DataView;)Ljava/util/Optional;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataBuilder#build was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn reset(&self) -> ::sponge::data::persistence::DataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataBuilder", self.object, "reset", "()Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataBuilder#reset was null") }
+    ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataBuilder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataBuilder#from was null") }
+    ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataContentUpdater {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataContentUpdater {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataContentUpdater {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataContentUpdater`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/DataContentUpdater", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataFormat {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataFormat {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataFormat {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataFormat`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/DataFormat", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn read_from(&self, input_stream: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataFormat", self.object, "readFrom", "(Ljava/io/InputStream;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, input_stream);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataFormat#readFrom was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn write_to(&self, output_stream: jobject, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/data/persistence/DataFormat", self.object, "writeTo", "(Ljava/io/OutputStream;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, output_stream, data_view.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataFormats {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataFormats {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataFormats {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataFormats`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/DataFormats", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/DataSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_token(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataSerializer", self.object, "getToken", "()Lcom/google/common/reflect/TypeToken;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataSerializer#getToken was null") }
+    ret
+  }
+
+  pub fn deserialize(&self, data_view: ::sponge::data::DataView) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataSerializer", self.object, "deserialize", "(Lorg/spongepowered/api/data/DataView;)Ljava/util/Optional;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataSerializer#deserialize was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn serialize(&self, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/persistence/DataSerializer", self.object, "serialize", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/DataSerializer#serialize was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InvalidDataException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InvalidDataException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InvalidDataException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `InvalidDataException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/InvalidDataException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::data::persistence::InvalidDataException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::data::persistence::InvalidDataException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::data::persistence::InvalidDataException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::data::persistence::InvalidDataException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InvalidDataFormatException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InvalidDataFormatException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InvalidDataFormatException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `InvalidDataFormatException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/persistence/InvalidDataFormatException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::data::persistence::InvalidDataFormatException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataFormatException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataFormatException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataFormatException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::data::persistence::InvalidDataFormatException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataFormatException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataFormatException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataFormatException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::data::persistence::InvalidDataFormatException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataFormatException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataFormatException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataFormatException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::data::persistence::InvalidDataFormatException {
+    let ret = new_object!(env, "org/spongepowered/api/data/persistence/InvalidDataFormatException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/persistence/InvalidDataFormatException#<init> was null") }
+    ::sponge::data::persistence::InvalidDataFormatException { env: env, object: ret }
+  }
+
+}
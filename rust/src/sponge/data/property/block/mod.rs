@@ -0,0 +1,769 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BlastResistanceProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlastResistanceProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlastResistanceProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `BlastResistanceProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/BlastResistanceProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::block::BlastResistanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/BlastResistanceProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/BlastResistanceProperty#<init> was null") }
+    ::sponge::data::property::block::BlastResistanceProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::BlastResistanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/BlastResistanceProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/BlastResistanceProperty#<init> was null") }
+    ::sponge::data::property::block::BlastResistanceProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CollectiveProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollectiveProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollectiveProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CollectiveProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/CollectiveProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::data::property::block::CollectiveProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/CollectiveProperty", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/CollectiveProperty#<init> was null") }
+    ::sponge::data::property::block::CollectiveProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FlammableProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FlammableProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FlammableProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FlammableProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/FlammableProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::FlammableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/FlammableProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/FlammableProperty#<init> was null") }
+    ::sponge::data::property::block::FlammableProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::FlammableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/FlammableProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/FlammableProperty#<init> was null") }
+    ::sponge::data::property::block::FlammableProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FullBlockSelectionBoxProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FullBlockSelectionBoxProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FullBlockSelectionBoxProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FullBlockSelectionBoxProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/FullBlockSelectionBoxProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::FullBlockSelectionBoxProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/FullBlockSelectionBoxProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/FullBlockSelectionBoxProperty#<init> was null") }
+    ::sponge::data::property::block::FullBlockSelectionBoxProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::FullBlockSelectionBoxProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/FullBlockSelectionBoxProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/FullBlockSelectionBoxProperty#<init> was null") }
+    ::sponge::data::property::block::FullBlockSelectionBoxProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GravityAffectedProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GravityAffectedProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GravityAffectedProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `GravityAffectedProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/GravityAffectedProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::GravityAffectedProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/GravityAffectedProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/GravityAffectedProperty#<init> was null") }
+    ::sponge::data::property::block::GravityAffectedProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::GravityAffectedProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/GravityAffectedProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/GravityAffectedProperty#<init> was null") }
+    ::sponge::data::property::block::GravityAffectedProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GroundLuminanceProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GroundLuminanceProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GroundLuminanceProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `GroundLuminanceProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/GroundLuminanceProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::block::GroundLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/GroundLuminanceProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/GroundLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::GroundLuminanceProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::GroundLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/GroundLuminanceProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/GroundLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::GroundLuminanceProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::GroundLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/GroundLuminanceProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/GroundLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::GroundLuminanceProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HardnessProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HardnessProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HardnessProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `HardnessProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/HardnessProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::block::HardnessProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/HardnessProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/HardnessProperty#<init> was null") }
+    ::sponge::data::property::block::HardnessProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::HardnessProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/HardnessProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/HardnessProperty#<init> was null") }
+    ::sponge::data::property::block::HardnessProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HeldItemProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HeldItemProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HeldItemProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `HeldItemProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/HeldItemProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn new_item_type(env: *mut JNIEnv, item_type: ::sponge::item::ItemType) -> ::sponge::data::property::block::HeldItemProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/HeldItemProperty", "(Lorg/spongepowered/api/item/ItemType;)V", item_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/HeldItemProperty#<init> was null") }
+    ::sponge::data::property::block::HeldItemProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn new_item_type_operator(env: *mut JNIEnv, item_type: ::sponge::item::ItemType, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::HeldItemProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/HeldItemProperty", "(Lorg/spongepowered/api/item/ItemType;Lorg/spongepowered/api/data/Property$Operator;)V", item_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/HeldItemProperty#<init> was null") }
+    ::sponge::data::property::block::HeldItemProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/block/HeldItemProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/block/HeldItemProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IndirectlyPoweredProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IndirectlyPoweredProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IndirectlyPoweredProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `IndirectlyPoweredProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/IndirectlyPoweredProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::IndirectlyPoweredProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/IndirectlyPoweredProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/IndirectlyPoweredProperty#<init> was null") }
+    ::sponge::data::property::block::IndirectlyPoweredProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::IndirectlyPoweredProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/IndirectlyPoweredProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/IndirectlyPoweredProperty#<init> was null") }
+    ::sponge::data::property::block::IndirectlyPoweredProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LightEmissionProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LightEmissionProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LightEmissionProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `LightEmissionProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/LightEmissionProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::block::LightEmissionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/LightEmissionProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/LightEmissionProperty#<init> was null") }
+    ::sponge::data::property::block::LightEmissionProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::LightEmissionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/LightEmissionProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/LightEmissionProperty#<init> was null") }
+    ::sponge::data::property::block::LightEmissionProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::LightEmissionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/LightEmissionProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/LightEmissionProperty#<init> was null") }
+    ::sponge::data::property::block::LightEmissionProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MatterProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MatterProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MatterProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `MatterProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/MatterProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_matter(env: *mut JNIEnv, matter: ::sponge::data::property::block::matter_property::Matter) -> ::sponge::data::property::block::MatterProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/MatterProperty", "(Lorg/spongepowered/api/data/property/block/MatterProperty$Matter;)V", ::convert::ToJava::to_java(&matter, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/MatterProperty#<init> was null") }
+    ::sponge::data::property::block::MatterProperty { env: env, object: ret }
+  }
+
+  pub fn new_matter_operator(env: *mut JNIEnv, matter: ::sponge::data::property::block::matter_property::Matter, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::MatterProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/MatterProperty", "(Lorg/spongepowered/api/data/property/block/MatterProperty$Matter;Lorg/spongepowered/api/data/Property$Operator;)V", ::convert::ToJava::to_java(&matter, env), ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/MatterProperty#<init> was null") }
+    ::sponge::data::property::block::MatterProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/block/MatterProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/block/MatterProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PassableProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PassableProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PassableProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `PassableProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/PassableProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::PassableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/PassableProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/PassableProperty#<init> was null") }
+    ::sponge::data::property::block::PassableProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::PassableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/PassableProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/PassableProperty#<init> was null") }
+    ::sponge::data::property::block::PassableProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PoweredProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PoweredProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PoweredProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `PoweredProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/PoweredProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::PoweredProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/PoweredProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/PoweredProperty#<init> was null") }
+    ::sponge::data::property::block::PoweredProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::PoweredProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/PoweredProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/PoweredProperty#<init> was null") }
+    ::sponge::data::property::block::PoweredProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ReplaceableProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ReplaceableProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ReplaceableProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ReplaceableProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/ReplaceableProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::ReplaceableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/ReplaceableProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/ReplaceableProperty#<init> was null") }
+    ::sponge::data::property::block::ReplaceableProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::ReplaceableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/ReplaceableProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/ReplaceableProperty#<init> was null") }
+    ::sponge::data::property::block::ReplaceableProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkyLuminanceProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkyLuminanceProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkyLuminanceProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkyLuminanceProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/SkyLuminanceProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::block::SkyLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SkyLuminanceProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SkyLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::SkyLuminanceProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::SkyLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SkyLuminanceProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SkyLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::SkyLuminanceProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::SkyLuminanceProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SkyLuminanceProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SkyLuminanceProperty#<init> was null") }
+    ::sponge::data::property::block::SkyLuminanceProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SolidCubeProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SolidCubeProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SolidCubeProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SolidCubeProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/SolidCubeProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::SolidCubeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SolidCubeProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SolidCubeProperty#<init> was null") }
+    ::sponge::data::property::block::SolidCubeProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::SolidCubeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SolidCubeProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SolidCubeProperty#<init> was null") }
+    ::sponge::data::property::block::SolidCubeProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticsTrackedProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticsTrackedProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticsTrackedProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `StatisticsTrackedProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/StatisticsTrackedProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::StatisticsTrackedProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/StatisticsTrackedProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/StatisticsTrackedProperty#<init> was null") }
+    ::sponge::data::property::block::StatisticsTrackedProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::StatisticsTrackedProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/StatisticsTrackedProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/StatisticsTrackedProperty#<init> was null") }
+    ::sponge::data::property::block::StatisticsTrackedProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SurrogateBlockProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SurrogateBlockProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SurrogateBlockProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SurrogateBlockProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/SurrogateBlockProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::SurrogateBlockProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SurrogateBlockProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SurrogateBlockProperty#<init> was null") }
+    ::sponge::data::property::block::SurrogateBlockProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::SurrogateBlockProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/SurrogateBlockProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/SurrogateBlockProperty#<init> was null") }
+    ::sponge::data::property::block::SurrogateBlockProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TemperatureProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TemperatureProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TemperatureProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TemperatureProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/TemperatureProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::block::TemperatureProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/TemperatureProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/TemperatureProperty#<init> was null") }
+    ::sponge::data::property::block::TemperatureProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::TemperatureProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/TemperatureProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/TemperatureProperty#<init> was null") }
+    ::sponge::data::property::block::TemperatureProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnbreakableProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnbreakableProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnbreakableProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `UnbreakableProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/block/UnbreakableProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::block::UnbreakableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/UnbreakableProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/UnbreakableProperty#<init> was null") }
+    ::sponge::data::property::block::UnbreakableProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::block::UnbreakableProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/block/UnbreakableProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/block/UnbreakableProperty#<init> was null") }
+    ::sponge::data::property::block::UnbreakableProperty { env: env, object: ret }
+  }
+
+}
+
+pub mod matter_property {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Matter {
+    Solid,
+    Liquid,
+    Gas,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static MATTER_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/data/property/block/MatterProperty$Matter", &["SOLID", "LIQUID", "GAS"]);
+
+  impl Matter {
+    pub const VALUES: &'static [Matter] = &[Matter::Solid, Matter::Liquid, Matter::Gas];
+
+  }
+
+  impl ::convert::FromJava for Matter {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match MATTER_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Matter::VALUES[ordinal],
+        None => Matter::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Matter {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Matter::Other(object) => object,
+        value => MATTER_CONSTANTS.get(env, Matter::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
@@ -0,0 +1,122 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DominantHandProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DominantHandProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DominantHandProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DominantHandProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/entity/DominantHandProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_hand_type(env: *mut JNIEnv, hand_type: ::sponge::data::type_::HandType) -> ::sponge::data::property::entity::DominantHandProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/DominantHandProperty", "(Lorg/spongepowered/api/data/type/HandType;)V", hand_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/DominantHandProperty#<init> was null") }
+    ::sponge::data::property::entity::DominantHandProperty { env: env, object: ret }
+  }
+
+  pub fn new_hand_type_operator(env: *mut JNIEnv, hand_type: ::sponge::data::type_::HandType, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::entity::DominantHandProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/DominantHandProperty", "(Lorg/spongepowered/api/data/type/HandType;Lorg/spongepowered/api/data/Property$Operator;)V", hand_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/DominantHandProperty#<init> was null") }
+    ::sponge::data::property::entity::DominantHandProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/entity/DominantHandProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/entity/DominantHandProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EyeHeightProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EyeHeightProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EyeHeightProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EyeHeightProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/entity/EyeHeightProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::entity::EyeHeightProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/EyeHeightProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/EyeHeightProperty#<init> was null") }
+    ::sponge::data::property::entity::EyeHeightProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::entity::EyeHeightProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/EyeHeightProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/EyeHeightProperty#<init> was null") }
+    ::sponge::data::property::entity::EyeHeightProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EyeLocationProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EyeLocationProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EyeLocationProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EyeLocationProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/entity/EyeLocationProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_vector_3d(env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::data::property::entity::EyeLocationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/EyeLocationProperty", "(Lcom/flowpowered/math/vector/Vector3d;)V", ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/EyeLocationProperty#<init> was null") }
+    ::sponge::data::property::entity::EyeLocationProperty { env: env, object: ret }
+  }
+
+  pub fn new_vector_3d_operator(env: *mut JNIEnv, vector_3d: ::math::Vector3d, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::entity::EyeLocationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/entity/EyeLocationProperty", "(Lcom/flowpowered/math/vector/Vector3d;Lorg/spongepowered/api/data/Property$Operator;)V", ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/entity/EyeLocationProperty#<init> was null") }
+    ::sponge::data::property::entity::EyeLocationProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/entity/EyeLocationProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/entity/EyeLocationProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
@@ -0,0 +1,458 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ApplicableEffectProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ApplicableEffectProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ApplicableEffectProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ApplicableEffectProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/ApplicableEffectProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_set(env: *mut JNIEnv, set: jobject) -> ::sponge::data::property::item::ApplicableEffectProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ApplicableEffectProperty", "(Ljava/util/Set;)V", set);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ApplicableEffectProperty#<init> was null") }
+    ::sponge::data::property::item::ApplicableEffectProperty { env: env, object: ret }
+  }
+
+  pub fn new_set_operator(env: *mut JNIEnv, set: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::ApplicableEffectProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ApplicableEffectProperty", "(Ljava/util/Set;Lorg/spongepowered/api/data/Property$Operator;)V", set, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ApplicableEffectProperty#<init> was null") }
+    ::sponge::data::property::item::ApplicableEffectProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ApplicableEffectProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ApplicableEffectProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorTypeProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorTypeProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorTypeProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ArmorTypeProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/ArmorTypeProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_armor_type(env: *mut JNIEnv, armor_type: ::sponge::data::type_::ArmorType) -> ::sponge::data::property::item::ArmorTypeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ArmorTypeProperty", "(Lorg/spongepowered/api/data/type/ArmorType;)V", armor_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ArmorTypeProperty#<init> was null") }
+    ::sponge::data::property::item::ArmorTypeProperty { env: env, object: ret }
+  }
+
+  pub fn new_armor_type_operator(env: *mut JNIEnv, armor_type: ::sponge::data::type_::ArmorType, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::ArmorTypeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ArmorTypeProperty", "(Lorg/spongepowered/api/data/type/ArmorType;Lorg/spongepowered/api/data/Property$Operator;)V", armor_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ArmorTypeProperty#<init> was null") }
+    ::sponge::data::property::item::ArmorTypeProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ArmorTypeProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ArmorTypeProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BurningFuelProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BurningFuelProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BurningFuelProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `BurningFuelProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/BurningFuelProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::item::BurningFuelProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/BurningFuelProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/BurningFuelProperty#<init> was null") }
+    ::sponge::data::property::item::BurningFuelProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::BurningFuelProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/BurningFuelProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/BurningFuelProperty#<init> was null") }
+    ::sponge::data::property::item::BurningFuelProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageAbsorptionProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageAbsorptionProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageAbsorptionProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DamageAbsorptionProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/DamageAbsorptionProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::item::DamageAbsorptionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/DamageAbsorptionProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/DamageAbsorptionProperty#<init> was null") }
+    ::sponge::data::property::item::DamageAbsorptionProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::DamageAbsorptionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/DamageAbsorptionProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/DamageAbsorptionProperty#<init> was null") }
+    ::sponge::data::property::item::DamageAbsorptionProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::DamageAbsorptionProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/DamageAbsorptionProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/DamageAbsorptionProperty#<init> was null") }
+    ::sponge::data::property::item::DamageAbsorptionProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EfficiencyProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EfficiencyProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EfficiencyProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EfficiencyProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/EfficiencyProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::item::EfficiencyProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EfficiencyProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EfficiencyProperty#<init> was null") }
+    ::sponge::data::property::item::EfficiencyProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::EfficiencyProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EfficiencyProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EfficiencyProperty#<init> was null") }
+    ::sponge::data::property::item::EfficiencyProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::EfficiencyProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EfficiencyProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EfficiencyProperty#<init> was null") }
+    ::sponge::data::property::item::EfficiencyProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EquipmentProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EquipmentProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/EquipmentProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn new_equipment_type(env: *mut JNIEnv, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> ::sponge::data::property::item::EquipmentProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EquipmentProperty", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)V", equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EquipmentProperty#<init> was null") }
+    ::sponge::data::property::item::EquipmentProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn new_equipment_type_operator(env: *mut JNIEnv, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::EquipmentProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EquipmentProperty", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;Lorg/spongepowered/api/data/Property$Operator;)V", equipment_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EquipmentProperty#<init> was null") }
+    ::sponge::data::property::item::EquipmentProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::EquipmentProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/EquipmentProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/EquipmentProperty#<init> was null") }
+    ::sponge::data::property::item::EquipmentProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/EquipmentProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/EquipmentProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FoodRestorationProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FoodRestorationProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FoodRestorationProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FoodRestorationProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/FoodRestorationProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::item::FoodRestorationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/FoodRestorationProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/FoodRestorationProperty#<init> was null") }
+    ::sponge::data::property::item::FoodRestorationProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::FoodRestorationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/FoodRestorationProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/FoodRestorationProperty#<init> was null") }
+    ::sponge::data::property::item::FoodRestorationProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::FoodRestorationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/FoodRestorationProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/FoodRestorationProperty#<init> was null") }
+    ::sponge::data::property::item::FoodRestorationProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HarvestingProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HarvestingProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HarvestingProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `HarvestingProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/HarvestingProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_set(env: *mut JNIEnv, set: jobject) -> ::sponge::data::property::item::HarvestingProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/HarvestingProperty", "(Ljava/util/Set;)V", set);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/HarvestingProperty#<init> was null") }
+    ::sponge::data::property::item::HarvestingProperty { env: env, object: ret }
+  }
+
+  pub fn new_set_operator(env: *mut JNIEnv, set: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::HarvestingProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/HarvestingProperty", "(Ljava/util/Set;Lorg/spongepowered/api/data/Property$Operator;)V", set, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/HarvestingProperty#<init> was null") }
+    ::sponge::data::property::item::HarvestingProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/HarvestingProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/HarvestingProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SaturationProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SaturationProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SaturationProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SaturationProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/SaturationProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_double(env: *mut JNIEnv, double: jobject) -> ::sponge::data::property::item::SaturationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/SaturationProperty", "(Ljava/lang/Double;)V", double);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/SaturationProperty#<init> was null") }
+    ::sponge::data::property::item::SaturationProperty { env: env, object: ret }
+  }
+
+  pub fn new_double_operator(env: *mut JNIEnv, double: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::SaturationProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/SaturationProperty", "(Ljava/lang/Double;Lorg/spongepowered/api/data/Property$Operator;)V", double, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/SaturationProperty#<init> was null") }
+    ::sponge::data::property::item::SaturationProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ToolTypeProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ToolTypeProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ToolTypeProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ToolTypeProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/ToolTypeProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_tool_type(env: *mut JNIEnv, tool_type: ::sponge::data::type_::ToolType) -> ::sponge::data::property::item::ToolTypeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ToolTypeProperty", "(Lorg/spongepowered/api/data/type/ToolType;)V", tool_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ToolTypeProperty#<init> was null") }
+    ::sponge::data::property::item::ToolTypeProperty { env: env, object: ret }
+  }
+
+  pub fn new_tool_type_operator(env: *mut JNIEnv, tool_type: ::sponge::data::type_::ToolType, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::ToolTypeProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/ToolTypeProperty", "(Lorg/spongepowered/api/data/type/ToolType;Lorg/spongepowered/api/data/Property$Operator;)V", tool_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/ToolTypeProperty#<init> was null") }
+    ::sponge::data::property::item::ToolTypeProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ToolTypeProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/item/ToolTypeProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UseLimitProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UseLimitProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UseLimitProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `UseLimitProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/item/UseLimitProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::item::UseLimitProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/UseLimitProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/UseLimitProperty#<init> was null") }
+    ::sponge::data::property::item::UseLimitProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::UseLimitProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/UseLimitProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/UseLimitProperty#<init> was null") }
+    ::sponge::data::property::item::UseLimitProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::item::UseLimitProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/item/UseLimitProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/item/UseLimitProperty#<init> was null") }
+    ::sponge::data::property::item::UseLimitProperty { env: env, object: ret }
+  }
+
+}
@@ -0,0 +1,590 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod block;
+pub mod entity;
+pub mod item;
+
+#[derive(Debug)]
+pub struct AbstractProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/AbstractProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "getKey", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/AbstractProperty#getKey was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "getValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/AbstractProperty#getValue was null") }
+    ret
+  }
+
+  pub fn get_operator(&self) -> ::sponge::data::property_::Operator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "getOperator", "()Lorg/spongepowered/api/data/Property$Operator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/AbstractProperty#getOperator was null") }
+    <::sponge::data::property_::Operator as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn matches(&self, property: ::sponge::data::Property) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "matches", "(Lorg/spongepowered/api/data/Property;)Z", CallBooleanMethodA, property.object) != 0
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/AbstractProperty", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/AbstractProperty#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BooleanProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BooleanProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BooleanProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `BooleanProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/BooleanProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_bool(env: *mut JNIEnv, value: bool) -> ::sponge::data::property::BooleanProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/BooleanProperty", "(Z)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#<init> was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn new_bool_operator(env: *mut JNIEnv, value: bool, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::BooleanProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/BooleanProperty", "(ZLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#<init> was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::BooleanProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/BooleanProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#<init> was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/BooleanProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#of was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#not was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#greaterThan was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#greaterThanOrEqual was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#lessThan was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::BooleanProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/BooleanProperty", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/BooleanProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/BooleanProperty#lessThanOrEqual was null") }
+    ::sponge::data::property::BooleanProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/BooleanProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DirectionRelativePropertyHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DirectionRelativePropertyHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DirectionRelativePropertyHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DirectionRelativePropertyHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/DirectionRelativePropertyHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_property(&self, direction: ::sponge::util::Direction, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/DirectionRelativePropertyHolder", self.object, "getProperty", "(Lorg/spongepowered/api/util/Direction;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DirectionRelativePropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisplacementProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisplacementProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisplacementProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DisplacementProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/DisplacementProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_set(env: *mut JNIEnv, set: jobject) -> ::sponge::data::property::DisplacementProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/DisplacementProperty", "(Ljava/util/Set;)V", set);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DisplacementProperty#<init> was null") }
+    ::sponge::data::property::DisplacementProperty { env: env, object: ret }
+  }
+
+  pub fn new_set_operator(env: *mut JNIEnv, set: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::DisplacementProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/DisplacementProperty", "(Ljava/util/Set;Lorg/spongepowered/api/data/Property$Operator;)V", set, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DisplacementProperty#<init> was null") }
+    ::sponge::data::property::DisplacementProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/DisplacementProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/DisplacementProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DoubleProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DoubleProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DoubleProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DoubleProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/DoubleProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_f64(env: *mut JNIEnv, value: f64) -> ::sponge::data::property::DoubleProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/DoubleProperty", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#<init> was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn new_f64_operator(env: *mut JNIEnv, value: f64, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::DoubleProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/DoubleProperty", "(DLorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#<init> was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::DoubleProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/DoubleProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#<init> was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/DoubleProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#of was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#not was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#greaterThan was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#greaterThanOrEqual was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#lessThan was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::DoubleProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/DoubleProperty", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/DoubleProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/DoubleProperty#lessThanOrEqual was null") }
+    ::sponge::data::property::DoubleProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/DoubleProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IntProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IntProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IntProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `IntProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/IntProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::data::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/IntProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#<init> was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/IntProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#<init> was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::data::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/data/property/IntProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#<init> was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/IntProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#of was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#not was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#greaterThan was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#greaterThanOrEqual was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#lessThan was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::data::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/property/IntProperty", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/IntProperty#lessThanOrEqual was null") }
+    ::sponge::data::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/IntProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LocationBasePropertyHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LocationBasePropertyHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LocationBasePropertyHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LocationBasePropertyHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/LocationBasePropertyHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_property_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperty", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_property_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperty", "(IIILjava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_property_vector_3i_direction_class(&self, vector_3i: ::math::Vector3i, direction: ::sponge::util::Direction, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperty", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/util/Direction;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&direction, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_property_i32_i32_i32_direction_class(&self, value: i32, value_2: i32, value_3: i32, direction: ::sponge::util::Direction, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperty", "(IIILorg/spongepowered/api/util/Direction;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, ::convert::ToJava::to_java(&direction, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_properties_vector_3i(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperties", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Collection;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperties was null") }
+    ret
+  }
+
+  pub fn get_properties_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getProperties", "(III)Ljava/util/Collection;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getProperties was null") }
+    ret
+  }
+
+  pub fn get_faces_with_property_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getFacesWithProperty", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getFacesWithProperty was null") }
+    ret
+  }
+
+  pub fn get_faces_with_property_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/LocationBasePropertyHolder", self.object, "getFacesWithProperty", "(IIILjava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, value, value_2, value_3, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/LocationBasePropertyHolder#getFacesWithProperty was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PropertyHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PropertyHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PropertyHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PropertyHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/PropertyHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_property(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyHolder", self.object, "getProperty", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyHolder#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_applicable_properties(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyHolder", self.object, "getApplicableProperties", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyHolder#getApplicableProperties was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PropertyRegistry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PropertyRegistry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PropertyRegistry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PropertyRegistry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/PropertyRegistry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register(&self, class: jobject, property_store: ::sponge::data::property::PropertyStore) {
+    bound_method!(self.env, "org/spongepowered/api/data/property/PropertyRegistry", self.object, "register", "(Ljava/lang/Class;Lorg/spongepowered/api/data/property/PropertyStore;)V", CallVoidMethodA, class, property_store.object);
+  }
+
+  pub fn get_store(&self, class: jobject) -> Option<::sponge::data::property::PropertyStore> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyRegistry", self.object, "getStore", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyRegistry#getStore was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::property::PropertyStore { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PropertyStore {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PropertyStore {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PropertyStore {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PropertyStore`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/property/PropertyStore", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_for_property_holder(&self, property_holder: ::sponge::data::property::PropertyHolder) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyStore", self.object, "getFor", "(Lorg/spongepowered/api/data/property/PropertyHolder;)Ljava/util/Optional;", CallObjectMethodA, property_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyStore#getFor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_for_location(&self, location: ::sponge::world::Location) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyStore", self.object, "getFor", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Optional;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyStore#getFor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(all(feature = "util", feature = "world"))]
+  pub fn get_for_location_direction(&self, location: ::sponge::world::Location, direction: ::sponge::util::Direction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/property/PropertyStore", self.object, "getFor", "(Lorg/spongepowered/api/world/Location;Lorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, location.object, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/data/property/PropertyStore#getFor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_priority(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/property/PropertyStore", self.object, "getPriority", "()I", CallIntMethod)
+  }
+
+}
@@ -0,0 +1,95 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ConfigurateTranslator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConfigurateTranslator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConfigurateTranslator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConfigurateTranslator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/translator/ConfigurateTranslator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn instance(env: *mut JNIEnv) -> ::sponge::data::translator::ConfigurateTranslator {
+    let ret = static_java_method!(env, "org/spongepowered/api/data/translator/ConfigurateTranslator", "instance", "()Lorg/spongepowered/api/data/translator/ConfigurateTranslator;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/ConfigurateTranslator#instance was null") }
+    ::sponge::data::translator::ConfigurateTranslator { env: env, object: ret }
+  }
+
+  pub fn translate_data(&self, data_view: ::sponge::data::DataView) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/translator/ConfigurateTranslator", self.object, "translateData", "(Lorg/spongepowered/api/data/DataView;)Lninja/leaping/configurate/ConfigurationNode;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/ConfigurateTranslator#translateData was null") }
+    ret
+  }
+
+  pub fn translate_container_to_data_configuration_node_data_view(&self, configuration_node: jobject, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/data/translator/ConfigurateTranslator", self.object, "translateContainerToData", "(Lninja/leaping/configurate/ConfigurationNode;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, configuration_node, data_view.object);
+  }
+
+  pub fn translate_from_configuration_node(&self, configuration_node: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/translator/ConfigurateTranslator", self.object, "translateFrom", "(Lninja/leaping/configurate/ConfigurationNode;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, configuration_node);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/ConfigurateTranslator#translateFrom was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn translate_from_object(&self, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/translator/ConfigurateTranslator", self.object, "translateFrom", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/ConfigurateTranslator#translateFrom was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn translate_container_to_data_object_data_view(&self, object: jobject, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/data/translator/ConfigurateTranslator", self.object, "translateContainerToData", "(Ljava/lang/Object;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, object, data_view.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DataTranslator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DataTranslator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DataTranslator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DataTranslator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/translator/DataTranslator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn translate_data(&self, data_view: ::sponge::data::DataView) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/translator/DataTranslator", self.object, "translateData", "(Lorg/spongepowered/api/data/DataView;)Ljava/lang/Object;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/DataTranslator#translateData was null") }
+    ret
+  }
+
+  pub fn translate_container_to_data(&self, object: jobject, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/data/translator/DataTranslator", self.object, "translateContainerToData", "(Ljava/lang/Object;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, object, data_view.object);
+  }
+
+  pub fn translate_from(&self, object: jobject) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/translator/DataTranslator", self.object, "translateFrom", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/DataContainer;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/translator/DataTranslator#translateFrom was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
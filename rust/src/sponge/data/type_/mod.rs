@@ -0,0 +1,2211 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ArmorType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArmorType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ArmorType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_repair_item_type(&self) -> Option<::sponge::item::ItemType> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/ArmorType", self.object, "getRepairItemType", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/ArmorType#getRepairItemType was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::ItemType { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArmorTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ArmorTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Art {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Art {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Art {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Art`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Art", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_height(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/type/Art", self.object, "getHeight", "()I", CallIntMethod)
+  }
+
+  pub fn get_width(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/type/Art", self.object, "getWidth", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Arts {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Arts {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Arts {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Arts`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Arts", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BannerPatternShape {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BannerPatternShape {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BannerPatternShape {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BannerPatternShape`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BannerPatternShape", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BannerPatternShapes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BannerPatternShapes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BannerPatternShapes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BannerPatternShapes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BannerPatternShapes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BigMushroomType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BigMushroomType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BigMushroomType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BigMushroomType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BigMushroomType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BigMushroomTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BigMushroomTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BigMushroomTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BigMushroomTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BigMushroomTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BodyPart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BodyPart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BodyPart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BodyPart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BodyPart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BodyParts {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BodyParts {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BodyParts {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BodyParts`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BodyParts", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BrickType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrickType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrickType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrickType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BrickType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BrickTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrickTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrickTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrickTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/BrickTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Career {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Career {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Career {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Career`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Career", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_profession(&self) -> ::sponge::data::type_::Profession {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/Career", self.object, "getProfession", "()Lorg/spongepowered/api/data/type/Profession;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/Career#getProfession was null") }
+    ::sponge::data::type_::Profession { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Careers {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Careers {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Careers {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Careers`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Careers", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CoalType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CoalType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CoalType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CoalType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/CoalType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CoalTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CoalTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CoalTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CoalTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/CoalTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ComparatorType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ComparatorType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ComparatorType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ComparatorType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ComparatorType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ComparatorTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ComparatorTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ComparatorTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ComparatorTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ComparatorTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CookedFish {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CookedFish {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CookedFish {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CookedFish`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/CookedFish", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_raw_fish(&self) -> ::sponge::data::type_::Fish {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/CookedFish", self.object, "getRawFish", "()Lorg/spongepowered/api/data/type/Fish;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/CookedFish#getRawFish was null") }
+    ::sponge::data::type_::Fish { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CookedFishes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CookedFishes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CookedFishes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CookedFishes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/CookedFishes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DirtType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DirtType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DirtType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DirtType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DirtType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DirtTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DirtTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DirtTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DirtTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DirtTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisguisedBlockType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisguisedBlockType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisguisedBlockType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisguisedBlockType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DisguisedBlockType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisguisedBlockTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisguisedBlockTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisguisedBlockTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisguisedBlockTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DisguisedBlockTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DoublePlantType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DoublePlantType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DoublePlantType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DoublePlantType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DoublePlantType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DoublePlantTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DoublePlantTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DoublePlantTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DoublePlantTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DoublePlantTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DyeColor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DyeColor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DyeColor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DyeColor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DyeColor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_color(&self) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/DyeColor", self.object, "getColor", "()Lorg/spongepowered/api/util/Color;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/DyeColor#getColor was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DyeColors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DyeColors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DyeColors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DyeColors`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/DyeColors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Fish {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Fish {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Fish {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Fish`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Fish", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_cooked_fish(&self) -> Option<::sponge::data::type_::CookedFish> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/Fish", self.object, "getCookedFish", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/Fish#getCookedFish was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::type_::CookedFish { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Fishes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Fishes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Fishes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Fishes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Fishes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GoldenApple {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GoldenApple {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GoldenApple {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GoldenApple`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/GoldenApple", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GoldenApples {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GoldenApples {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GoldenApples {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GoldenApples`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/GoldenApples", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HandType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HandType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HandType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HandType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HandType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HandTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HandTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HandTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HandTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HandTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Hinge {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hinge {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hinge {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hinge`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Hinge", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Hinges {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hinges {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hinges {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hinges`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Hinges", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseColor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseColor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseColor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseColor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseColor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseColors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseColors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseColors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseColors`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseColors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseStyle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseStyle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseStyle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseStyle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseStyle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseStyles {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseStyles {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseStyles {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseStyles`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseStyles", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseVariant {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseVariant {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseVariant {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseVariant`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseVariant", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HorseVariants {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HorseVariants {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HorseVariants {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HorseVariants`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/HorseVariants", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InstrumentType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InstrumentType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InstrumentType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InstrumentType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/InstrumentType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InstrumentTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InstrumentTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InstrumentTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InstrumentTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/InstrumentTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LogAxes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LogAxes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LogAxes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LogAxes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/LogAxes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LogAxis {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LogAxis {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LogAxis {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LogAxis`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/LogAxis", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NotePitch {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NotePitch {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NotePitch {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NotePitch`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/NotePitch", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NotePitches {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NotePitches {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NotePitches {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NotePitches`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/NotePitches", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OcelotType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OcelotType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OcelotType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OcelotType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/OcelotType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OcelotTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OcelotTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OcelotTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OcelotTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/OcelotTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PickupRule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PickupRule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PickupRule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PickupRule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PickupRule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PickupRules {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PickupRules {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PickupRules {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PickupRules`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PickupRules", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PistonType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PistonType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PistonType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PistonType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PistonType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PistonTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PistonTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PistonTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PistonTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PistonTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlantType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlantType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlantType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlantType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PlantType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlantTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlantTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlantTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlantTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PlantTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortionType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortionType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortionType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortionType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PortionType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortionTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortionTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortionTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortionTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PortionTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PrismarineType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PrismarineType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PrismarineType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PrismarineType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PrismarineType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PrismarineTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PrismarineTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PrismarineTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PrismarineTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/PrismarineTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Profession {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Profession {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Profession {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Profession`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Profession", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_careers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/type/Profession", self.object, "getCareers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/type/Profession#getCareers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Professions {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Professions {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Professions {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Professions`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/Professions", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct QuartzType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl QuartzType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    QuartzType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `QuartzType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/QuartzType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct QuartzTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl QuartzTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    QuartzTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `QuartzTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/QuartzTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RabbitType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RabbitType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RabbitType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RabbitType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/RabbitType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RabbitTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RabbitTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RabbitTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RabbitTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/RabbitTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RailDirection {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RailDirection {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RailDirection {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RailDirection`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/RailDirection", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RailDirections {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RailDirections {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RailDirections {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RailDirections`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/RailDirections", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SandType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SandTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandstoneType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandstoneType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandstoneType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandstoneType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SandstoneType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SandstoneTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SandstoneTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SandstoneTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SandstoneTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SandstoneTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShrubType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShrubType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShrubType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShrubType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ShrubType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShrubTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShrubTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShrubTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShrubTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ShrubTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkeletonType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkeletonType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkeletonType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkeletonType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkeletonType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkeletonTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkeletonTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkeletonTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkeletonTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkeletonTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkinPart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkinPart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkinPart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkinPart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkinPart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkinParts {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkinParts {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkinParts {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkinParts`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkinParts", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkullType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkullType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkullType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkullType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkullType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkullTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkullTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkullTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkullTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SkullTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlabType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlabType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlabType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SlabType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SlabType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlabTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlabTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlabTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SlabTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/SlabTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StairShape {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StairShape {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StairShape {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StairShape`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/StairShape", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StairShapes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StairShapes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StairShapes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StairShapes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/StairShapes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StoneType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StoneType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StoneType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StoneType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/StoneType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StoneTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StoneTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StoneTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StoneTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/StoneTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ToolType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ToolType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ToolType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ToolType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ToolType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ToolTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ToolTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ToolTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ToolTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/ToolTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TreeType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TreeType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TreeType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TreeType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/TreeType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TreeTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TreeTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TreeTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TreeTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/TreeTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WallType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WallType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WallType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WallType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/WallType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WallTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WallTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WallTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WallTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/WallTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WireAttachmentType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WireAttachmentType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WireAttachmentType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WireAttachmentType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/WireAttachmentType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WireAttachmentTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WireAttachmentTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WireAttachmentTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WireAttachmentTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/type/WireAttachmentTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
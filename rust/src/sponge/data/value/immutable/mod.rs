@@ -0,0 +1,695 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableBoundedValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBoundedValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBoundedValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBoundedValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableBoundedValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableBoundedValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableBoundedValue#transform was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableBoundedValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableBoundedValue#asMutable was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableCollectionValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableCollectionValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableCollectionValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableCollectionValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn with_collection(&self, collection: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "with", "(Ljava/util/Collection;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_element(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "withElement", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#withElement was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#transform was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_all(&self, iterable: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "withAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#withAll was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn without(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "without", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#without was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "withoutAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "withoutAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_all(&self, iterable: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "containsAll", "(Ljava/lang/Iterable;)Z", CallBooleanMethodA, iterable) != 0
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "getAll", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#getAll was null") }
+    ret
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#asMutable was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableCollectionValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableCollectionValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableListValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableListValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableListValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableListValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableListValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "get", "(I)Ljava/lang/Object;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableListValue#get was null") }
+    ret
+  }
+
+  pub fn with_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "with", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "with", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn without(&self, value: i32) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "without", "(I)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableListValue#without was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn set(&self, value: i32, object: jobject) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "set", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableListValue#set was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn index_of(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableListValue", self.object, "indexOf", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableMapValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableMapValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableMapValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableMapValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn with_object_object(&self, object: jobject, object_2: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "with", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn with_map(&self, map: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "with", "(Ljava/util/Map;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn with_all(&self, map: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "withAll", "(Ljava/util/Map;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#withAll was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn without(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "without", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#without was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "withoutAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "withoutAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn contains_key(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "containsKey", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_value(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "containsValue", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn key_set(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "keySet", "()Lcom/google/common/collect/ImmutableSet;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#keySet was null") }
+    ret
+  }
+
+  pub fn entry_set(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "entrySet", "()Lcom/google/common/collect/ImmutableSet;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#entrySet was null") }
+    ret
+  }
+
+  pub fn values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "values", "()Lcom/google/common/collect/ImmutableCollection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#values was null") }
+    ret
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#transform was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#asMutable was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn with_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableMapValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableMapValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableOptionalValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableOptionalValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableOptionalValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableOptionalValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn with_optional(&self, optional: jobject) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "with", "(Ljava/util/Optional;)Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethodA, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#transform was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn instead(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableOptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "instead", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableOptionalValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#instead was null") }
+    ::sponge::data::value::immutable::ImmutableOptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn or(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "or", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#or was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#asMutable was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn with_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableOptionalValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableOptionalValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutablePatternListValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutablePatternListValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutablePatternListValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutablePatternListValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn with_banner_pattern_shape_dye_color(&self, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(Lorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_i32_pattern_layer(&self, value: i32, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(ILorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_i32_banner_pattern_shape_dye_color(&self, value: i32, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(ILorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_list(&self, list: jobject) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(Ljava/util/List;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, list);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_element_pattern_layer(&self, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "withElement", "(Lorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#withElement was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_all(&self, iterable: jobject) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "withAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#withAll was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_banner_pattern_shape_dye_color(&self, value: i32, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "set", "(ILorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#set was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_pattern_layer(&self, value: i32, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "set", "(ILorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#set was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn without_pattern_layer(&self, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "without", "(Lorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#without was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn without_i32(&self, value: i32) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "without", "(I)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#without was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "withoutAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn without_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "withoutAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#withoutAll was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#asMutable was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "set", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#set was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn with_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::immutable::ImmutableListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableListValue { env: self.env, object: ret }
+  }
+
+  pub fn without_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "without", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#without was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_element_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "withElement", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#withElement was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_collection(&self, collection: jobject) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(Ljava/util/Collection;)Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn with_object(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutablePatternListValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutablePatternListValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableSetValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableSetValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableSetValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableSetValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableSetValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn with(&self, object: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValue", self.object, "with", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValue#with was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValue#transform was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValue", self.object, "asMutable", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValue#asMutable was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableValueStore {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableValueStore {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableValueStore {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableValueStore`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "get", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_create(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "getOrCreate", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#getOrCreate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "supports", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  pub fn transform(&self, key: ::sponge::data::key::Key, function: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "transform", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Ljava/util/Optional;", CallObjectMethodA, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#transform was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn with_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "with", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn with_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "with", "(Lorg/spongepowered/api/data/value/BaseValue;)Ljava/util/Optional;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn with_value_container(&self, value_container: ::sponge::data::value::ValueContainer) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "with", "(Lorg/spongepowered/api/data/value/ValueContainer;)Ljava/util/Optional;", CallObjectMethodA, value_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn with_iterable(&self, iterable: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "with", "(Ljava/lang/Iterable;)Ljava/util/Optional;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#with was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn without(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "without", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#without was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn merge_immutable_value_store(&self, immutable_value_store: ::sponge::data::value::immutable::ImmutableValueStore) -> ::sponge::data::value::immutable::ImmutableValueStore {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "merge", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValueStore;)Lorg/spongepowered/api/data/value/immutable/ImmutableValueStore;", CallObjectMethodA, immutable_value_store.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#merge was null") }
+    ::sponge::data::value::immutable::ImmutableValueStore { env: self.env, object: ret }
+  }
+
+  pub fn merge_immutable_value_store_merge_function(&self, immutable_value_store: ::sponge::data::value::immutable::ImmutableValueStore, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::value::immutable::ImmutableValueStore {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "merge", "(Lorg/spongepowered/api/data/value/immutable/ImmutableValueStore;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/value/immutable/ImmutableValueStore;", CallObjectMethodA, immutable_value_store.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#merge was null") }
+    ::sponge::data::value::immutable::ImmutableValueStore { env: self.env, object: ret }
+  }
+
+  pub fn get_containers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableValueStore", self.object, "getContainers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableValueStore#getContainers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableWeightedCollectionValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableWeightedCollectionValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableWeightedCollectionValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableWeightedCollectionValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/immutable/ImmutableWeightedCollectionValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/immutable/ImmutableWeightedCollectionValue", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/immutable/ImmutableWeightedCollectionValue#get was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,324 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod immutable;
+pub mod mutable;
+
+#[derive(Debug)]
+pub struct BaseValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BaseValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BaseValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BaseValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/BaseValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BaseValue", self.object, "get", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BaseValue#get was null") }
+    ret
+  }
+
+  pub fn exists(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/BaseValue", self.object, "exists", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_default(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BaseValue", self.object, "getDefault", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BaseValue#getDefault was null") }
+    ret
+  }
+
+  pub fn get_direct(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BaseValue", self.object, "getDirect", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BaseValue#getDirect was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_key(&self) -> ::sponge::data::key::Key {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BaseValue", self.object, "getKey", "()Lorg/spongepowered/api/data/key/Key;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BaseValue#getKey was null") }
+    ::sponge::data::key::Key { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BoundedValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BoundedValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BoundedValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BoundedValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/BoundedValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_min_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BoundedValue", self.object, "getMinValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BoundedValue#getMinValue was null") }
+    ret
+  }
+
+  pub fn get_max_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BoundedValue", self.object, "getMaxValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BoundedValue#getMaxValue was null") }
+    ret
+  }
+
+  pub fn get_comparator(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/BoundedValue", self.object, "getComparator", "()Ljava/util/Comparator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/BoundedValue#getComparator was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ValueContainer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ValueContainer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ValueContainer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ValueContainer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/ValueContainer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_null(&self, key: ::sponge::data::key::Key) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "getOrNull", "(Lorg/spongepowered/api/data/key/Key;)Ljava/lang/Object;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#getOrNull was null") }
+    ret
+  }
+
+  pub fn get_or_else(&self, key: ::sponge::data::key::Key, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "getOrElse", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#getOrElse was null") }
+    ret
+  }
+
+  pub fn get_value(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "getValue", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports_key(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  pub fn supports_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "supports", "(Lorg/spongepowered/api/data/value/BaseValue;)Z", CallBooleanMethodA, base_value.object) != 0
+  }
+
+  pub fn copy(&self) -> ::sponge::data::value::ValueContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "copy", "()Lorg/spongepowered/api/data/value/ValueContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#copy was null") }
+    ::sponge::data::value::ValueContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "getKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueContainer", self.object, "getValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueContainer#getValues was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ValueFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ValueFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ValueFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ValueFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/ValueFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_value_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createValue was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn create_value_key_object_object(&self, key: ::sponge::data::key::Key, object: jobject, object_2: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, key.object, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createValue was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn create_list_value_key_list(&self, key: ::sponge::data::key::Key, list: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createListValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/List;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, key.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createListValue was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn create_list_value_key_list_list(&self, key: ::sponge::data::key::Key, list: jobject, list_2: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createListValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/List;Ljava/util/List;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, key.object, list, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createListValue was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn create_set_value_key_set(&self, key: ::sponge::data::key::Key, set: jobject) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createSetValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Set;)Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethodA, key.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createSetValue was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+  pub fn create_set_value_key_set_set(&self, key: ::sponge::data::key::Key, set: jobject, set_2: jobject) -> ::sponge::data::value::mutable::SetValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createSetValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Set;Ljava/util/Set;)Lorg/spongepowered/api/data/value/mutable/SetValue;", CallObjectMethodA, key.object, set, set_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createSetValue was null") }
+    ::sponge::data::value::mutable::SetValue { env: self.env, object: ret }
+  }
+
+  pub fn create_map_value_key_map(&self, key: ::sponge::data::key::Key, map: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createMapValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Map;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, key.object, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createMapValue was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn create_map_value_key_map_map(&self, key: ::sponge::data::key::Key, map: jobject, map_2: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createMapValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Map;Ljava/util/Map;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, key.object, map, map_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createMapValue was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn create_bounded_value_builder(&self, key: ::sponge::data::key::Key) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createBoundedValueBuilder", "(Lorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createBoundedValueBuilder was null") }
+    ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+  }
+
+  pub fn create_optional_value_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createOptionalValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createOptionalValue was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn create_optional_value_key_object_object(&self, key: ::sponge::data::key::Key, object: jobject, object_2: jobject) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory", self.object, "createOptionalValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethodA, key.object, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory#createOptionalValue was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+}
+
+pub mod value_factory {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct BoundedValueBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl BoundedValueBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      BoundedValueBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `BoundedValueBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn comparator(&self, comparator: jobject) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "comparator", "(Ljava/util/Comparator;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, comparator);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#comparator was null") }
+      ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+    }
+
+    pub fn minimum(&self, object: jobject) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "minimum", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#minimum was null") }
+      ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+    }
+
+    pub fn maximum(&self, object: jobject) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "maximum", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#maximum was null") }
+      ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+    }
+
+    pub fn default_value(&self, object: jobject) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "defaultValue", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#defaultValue was null") }
+      ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+    }
+
+    pub fn actual_value(&self, object: jobject) -> ::sponge::data::value::value_factory::BoundedValueBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "actualValue", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#actualValue was null") }
+      ::sponge::data::value::value_factory::BoundedValueBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+      let ret = bound_method!(self.env, "org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder", self.object, "build", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/data/value/ValueFactory$BoundedValueBuilder#build was null") }
+      ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+    }
+
+  }
+}
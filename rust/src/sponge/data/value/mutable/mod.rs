@@ -0,0 +1,788 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CollectionValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollectionValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollectionValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CollectionValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/CollectionValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_collection(&self, collection: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "set", "(Ljava/util/Collection;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#set was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#transform was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn add(&self, object: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "add", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#add was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn add_all(&self, iterable: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "addAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#addAll was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, object: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "remove", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#remove was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "removeAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#removeAll was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "removeAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#removeAll was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "containsAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn filter(&self, predicate: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "filter", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#filter was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "getAll", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#getAll was null") }
+    ret
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::value::immutable::ImmutableCollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "asImmutable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableCollectionValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#asImmutable was null") }
+    ::sponge::data::value::immutable::ImmutableCollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn set_object(&self, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CollectionValue", self.object, "set", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CollectionValue#set was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CompositeValueStore {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CompositeValueStore {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CompositeValueStore {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CompositeValueStore`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/CompositeValueStore", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "get", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_create(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "getOrCreate", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#getOrCreate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn supports(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "supports", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  pub fn transform(&self, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "transform", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#transform was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn try_offer_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_key_object_cause(&self, key: ::sponge::data::key::Key, object: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn try_offer_key_object_cause(&self, key: ::sponge::data::key::Key, object: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn try_offer_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_base_value_cause(&self, base_value: ::sponge::data::value::BaseValue, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/BaseValue;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn try_offer_base_value_cause(&self, base_value: ::sponge::data::value::BaseValue, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/value/BaseValue;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_value_container(&self, value_container: ::sponge::data::value::ValueContainer) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn try_offer_value_container(&self, value_container: ::sponge::data::value::ValueContainer) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/value/ValueContainer;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_value_container_cause(&self, value_container: ::sponge::data::value::ValueContainer, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_value_container_merge_function(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn try_offer_value_container_merge_function(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_value_container_merge_function_cause(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn try_offer_value_container_merge_function_cause(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "tryOffer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#tryOffer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_iterable(&self, iterable: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_iterable_cause(&self, iterable: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Ljava/lang/Iterable;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn offer_iterable_merge_function(&self, iterable: jobject, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Ljava/lang/Iterable;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn offer_iterable_merge_function_cause(&self, iterable: jobject, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "offer", "(Ljava/lang/Iterable;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn remove_class(&self, class: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "remove", "(Ljava/lang/Class;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn remove_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "remove", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn remove_key(&self, key: ::sponge::data::key::Key) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "remove", "(Lorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn undo(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "undo", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#undo was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn copy_from_composite_value_store(&self, composite_value_store: ::sponge::data::value::mutable::CompositeValueStore) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "copyFrom", "(Lorg/spongepowered/api/data/value/mutable/CompositeValueStore;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, composite_value_store.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn copy_from_composite_value_store_merge_function(&self, composite_value_store: ::sponge::data::value::mutable::CompositeValueStore, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "copyFrom", "(Lorg/spongepowered/api/data/value/mutable/CompositeValueStore;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, composite_value_store.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_containers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/CompositeValueStore", self.object, "getContainers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/CompositeValueStore#getContainers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ListValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ListValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ListValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ListValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/ListValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "get", "(I)Ljava/lang/Object;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/ListValue#get was null") }
+    ret
+  }
+
+  pub fn add_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "add", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/ListValue#add was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "add", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/ListValue#add was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, value: i32) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "remove", "(I)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/ListValue#remove was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn set(&self, value: i32, object: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "set", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/ListValue#set was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn index_of(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/ListValue", self.object, "indexOf", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MapValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MapValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MapValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MapValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/MapValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn put(&self, object: jobject, object_2: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#put was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn put_all(&self, map: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "putAll", "(Ljava/util/Map;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#putAll was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn remove(&self, object: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "remove", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#remove was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "removeAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#removeAll was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "removeAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#removeAll was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn contains_key(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "containsKey", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_value(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "containsValue", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn key_set(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "keySet", "()Lcom/google/common/collect/ImmutableSet;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#keySet was null") }
+    ret
+  }
+
+  pub fn entry_set(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "entrySet", "()Lcom/google/common/collect/ImmutableSet;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#entrySet was null") }
+    ret
+  }
+
+  pub fn values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "values", "()Lcom/google/common/collect/ImmutableCollection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#values was null") }
+    ret
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#transform was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MapValue", self.object, "asImmutable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MapValue#asImmutable was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableBoundedValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableBoundedValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableBoundedValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableBoundedValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/MutableBoundedValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::value::immutable::ImmutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/MutableBoundedValue", self.object, "asImmutable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/MutableBoundedValue#asImmutable was null") }
+    ::sponge::data::value::immutable::ImmutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OptionalValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OptionalValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OptionalValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OptionalValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/OptionalValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_optional(&self, optional: jobject) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/OptionalValue", self.object, "set", "(Ljava/util/Optional;)Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethodA, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/OptionalValue#set was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn set_to(&self, object: jobject) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/OptionalValue", self.object, "setTo", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/OptionalValue#setTo was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn or(&self, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/OptionalValue", self.object, "or", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/OptionalValue#or was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn set_object(&self, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/OptionalValue", self.object, "set", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/OptionalValue#set was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PatternListValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PatternListValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PatternListValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PatternListValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/PatternListValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn add_pattern_layer(&self, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(Lorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_banner_pattern_shape_dye_color(&self, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(Lorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_i32_pattern_layer(&self, value: i32, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(ILorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, value, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_i32_banner_pattern_shape_dye_color(&self, value: i32, banner_pattern_shape: ::sponge::data::type_::BannerPatternShape, dye_color: ::sponge::data::type_::DyeColor) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(ILorg/spongepowered/api/data/type/BannerPatternShape;Lorg/spongepowered/api/data/type/DyeColor;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, value, banner_pattern_shape.object, dye_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, value, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_pattern_layer(&self, value: i32, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "set", "(ILorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, value, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#set was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_list(&self, list: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "set", "(Ljava/util/List;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, list);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#set was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#transform was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_all(&self, iterable: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "addAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#addAll was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_i32(&self, value: i32) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "remove", "(I)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#remove was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_pattern_layer(&self, pattern_layer: ::sponge::data::meta::PatternLayer) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "remove", "(Lorg/spongepowered/api/data/meta/PatternLayer;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, pattern_layer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#remove was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_iterable(&self, iterable: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "removeAll", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#removeAll was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_all_predicate(&self, predicate: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "removeAll", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#removeAll was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn filter(&self, predicate: jobject) -> ::sponge::data::value::mutable::PatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "filter", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/data/value/mutable/PatternListValue;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#filter was null") }
+    ::sponge::data::value::mutable::PatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::value::immutable::ImmutablePatternListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "asImmutable", "()Lorg/spongepowered/api/data/value/immutable/ImmutablePatternListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#asImmutable was null") }
+    ::sponge::data::value::immutable::ImmutablePatternListValue { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "set", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#set was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn add_i32_object(&self, value: i32, object: jobject) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(ILjava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethodA, value, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+  pub fn remove_object(&self, object: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "remove", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#remove was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn add_object(&self, object: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "add", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#add was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn set_collection(&self, collection: jobject) -> ::sponge::data::value::mutable::CollectionValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "set", "(Ljava/util/Collection;)Lorg/spongepowered/api/data/value/mutable/CollectionValue;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#set was null") }
+    ::sponge::data::value::mutable::CollectionValue { env: self.env, object: ret }
+  }
+
+  pub fn set_object(&self, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/PatternListValue", self.object, "set", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/PatternListValue#set was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SetValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SetValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SetValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SetValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/SetValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Value {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Value {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Value {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Value`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/Value", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set(&self, object: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/Value", self.object, "set", "(Ljava/lang/Object;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/Value#set was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn transform(&self, function: jobject) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/Value", self.object, "transform", "(Ljava/util/function/Function;)Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethodA, function);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/Value#transform was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn as_immutable(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/Value", self.object, "asImmutable", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/Value#asImmutable was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeightedCollectionValue {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeightedCollectionValue {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeightedCollectionValue {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WeightedCollectionValue`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/data/value/mutable/WeightedCollectionValue", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/data/value/mutable/WeightedCollectionValue", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/data/value/mutable/WeightedCollectionValue#get was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,96 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod particle;
+pub mod potion;
+pub mod sound;
+
+#[derive(Debug)]
+pub struct Viewer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Viewer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Viewer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Viewer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/Viewer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn spawn_particles_particle_effect_vector_3d(&self, particle_effect: ::sponge::effect::particle::ParticleEffect, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "spawnParticles", "(Lorg/spongepowered/api/effect/particle/ParticleEffect;Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, particle_effect.object, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+  pub fn spawn_particles_particle_effect_vector_3d_i32(&self, particle_effect: ::sponge::effect::particle::ParticleEffect, vector_3d: ::math::Vector3d, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "spawnParticles", "(Lorg/spongepowered/api/effect/particle/ParticleEffect;Lcom/flowpowered/math/vector/Vector3d;I)V", CallVoidMethodA, particle_effect.object, ::convert::ToJava::to_java(&vector_3d, self.env), value);
+  }
+
+  pub fn play_sound_sound_type_vector_3d_f64(&self, sound_type: ::sponge::effect::sound::SoundType, vector_3d: ::math::Vector3d, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lcom/flowpowered/math/vector/Vector3d;D)V", CallVoidMethodA, sound_type.object, ::convert::ToJava::to_java(&vector_3d, self.env), value);
+  }
+
+  pub fn play_sound_sound_type_sound_category_vector_3d_f64(&self, sound_type: ::sponge::effect::sound::SoundType, sound_category: ::sponge::effect::sound::SoundCategory, vector_3d: ::math::Vector3d, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lorg/spongepowered/api/effect/sound/SoundCategory;Lcom/flowpowered/math/vector/Vector3d;D)V", CallVoidMethodA, sound_type.object, sound_category.object, ::convert::ToJava::to_java(&vector_3d, self.env), value);
+  }
+
+  pub fn play_sound_sound_type_vector_3d_f64_f64(&self, sound_type: ::sponge::effect::sound::SoundType, vector_3d: ::math::Vector3d, value: f64, value_2: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lcom/flowpowered/math/vector/Vector3d;DD)V", CallVoidMethodA, sound_type.object, ::convert::ToJava::to_java(&vector_3d, self.env), value, value_2);
+  }
+
+  pub fn play_sound_sound_type_sound_category_vector_3d_f64_f64(&self, sound_type: ::sponge::effect::sound::SoundType, sound_category: ::sponge::effect::sound::SoundCategory, vector_3d: ::math::Vector3d, value: f64, value_2: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lorg/spongepowered/api/effect/sound/SoundCategory;Lcom/flowpowered/math/vector/Vector3d;DD)V", CallVoidMethodA, sound_type.object, sound_category.object, ::convert::ToJava::to_java(&vector_3d, self.env), value, value_2);
+  }
+
+  pub fn play_sound_sound_type_vector_3d_f64_f64_f64(&self, sound_type: ::sponge::effect::sound::SoundType, vector_3d: ::math::Vector3d, value: f64, value_2: f64, value_3: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lcom/flowpowered/math/vector/Vector3d;DDD)V", CallVoidMethodA, sound_type.object, ::convert::ToJava::to_java(&vector_3d, self.env), value, value_2, value_3);
+  }
+
+  pub fn play_sound_sound_type_sound_category_vector_3d_f64_f64_f64(&self, sound_type: ::sponge::effect::sound::SoundType, sound_category: ::sponge::effect::sound::SoundCategory, vector_3d: ::math::Vector3d, value: f64, value_2: f64, value_3: f64) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "playSound", "(Lorg/spongepowered/api/effect/sound/SoundType;Lorg/spongepowered/api/effect/sound/SoundCategory;Lcom/flowpowered/math/vector/Vector3d;DDD)V", CallVoidMethodA, sound_type.object, sound_category.object, ::convert::ToJava::to_java(&vector_3d, self.env), value, value_2, value_3);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn send_title(&self, title: ::sponge::text::title::Title) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "sendTitle", "(Lorg/spongepowered/api/text/title/Title;)V", CallVoidMethodA, title.object);
+  }
+
+  pub fn reset_title(&self) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "resetTitle", "()V", CallVoidMethod);
+  }
+
+  pub fn clear_title(&self) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "clearTitle", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn send_book_view(&self, book_view: ::sponge::text::BookView) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "sendBookView", "(Lorg/spongepowered/api/text/BookView;)V", CallVoidMethodA, book_view.object);
+  }
+
+  #[cfg(feature = "block")]
+  pub fn send_block_change_vector_3i_block_state(&self, vector_3i: ::math::Vector3i, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "sendBlockChange", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_state.object);
+  }
+
+  #[cfg(feature = "block")]
+  pub fn send_block_change_i32_i32_i32_block_state(&self, value: i32, value_2: i32, value_3: i32, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "sendBlockChange", "(IIILorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, value, value_2, value_3, block_state.object);
+  }
+
+  pub fn reset_block_change_vector_3i(&self, vector_3i: ::math::Vector3i) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "resetBlockChange", "(Lcom/flowpowered/math/vector/Vector3i;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+  }
+
+  pub fn reset_block_change_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) {
+    bound_method!(self.env, "org/spongepowered/api/effect/Viewer", self.object, "resetBlockChange", "(III)V", CallVoidMethodA, value, value_2, value_3);
+  }
+
+}
@@ -0,0 +1,679 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BlockParticle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockParticle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockParticle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockParticle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/BlockParticle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::block_particle::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/BlockParticle", "builder", "()Lorg/spongepowered/api/effect/particle/BlockParticle$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/BlockParticle#builder was null") }
+    ::sponge::effect::particle::block_particle::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_state(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/BlockParticle", self.object, "getBlockState", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/BlockParticle#getBlockState was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ColoredParticle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ColoredParticle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ColoredParticle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ColoredParticle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ColoredParticle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::colored_particle::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/ColoredParticle", "builder", "()Lorg/spongepowered/api/effect/particle/ColoredParticle$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ColoredParticle#builder was null") }
+    ::sponge::effect::particle::colored_particle::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_color(&self) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ColoredParticle", self.object, "getColor", "()Lorg/spongepowered/api/util/Color;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ColoredParticle#getColor was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemParticle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemParticle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemParticle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemParticle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ItemParticle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::item_particle::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/ItemParticle", "builder", "()Lorg/spongepowered/api/effect/particle/ItemParticle$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ItemParticle#builder was null") }
+    ::sponge::effect::particle::item_particle::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_item(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ItemParticle", self.object, "getItem", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ItemParticle#getItem was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NoteParticle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NoteParticle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NoteParticle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NoteParticle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/NoteParticle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::note_particle::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/NoteParticle", "builder", "()Lorg/spongepowered/api/effect/particle/NoteParticle$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/NoteParticle#builder was null") }
+    ::sponge::effect::particle::note_particle::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_note(&self) -> ::sponge::data::type_::NotePitch {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/NoteParticle", self.object, "getNote", "()Lorg/spongepowered/api/data/type/NotePitch;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/NoteParticle#getNote was null") }
+    ::sponge::data::type_::NotePitch { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ParticleEffect {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ParticleEffect {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ParticleEffect {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ParticleEffect`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleEffect", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::particle_effect::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/ParticleEffect", "builder", "()Lorg/spongepowered/api/effect/particle/ParticleEffect$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect#builder was null") }
+    ::sponge::effect::particle::particle_effect::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::effect::particle::ParticleType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect", self.object, "getType", "()Lorg/spongepowered/api/effect/particle/ParticleType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect#getType was null") }
+    ::sponge::effect::particle::ParticleType { env: self.env, object: ret }
+  }
+
+  pub fn get_motion(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect", self.object, "getMotion", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect#getMotion was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_offset(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect", self.object, "getOffset", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect#getOffset was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_count(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect", self.object, "getCount", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ParticleType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ParticleType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ParticleType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ParticleType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_motion(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType", self.object, "hasMotion", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ParticleTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ParticleTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ParticleTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ParticleTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ResizableParticle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResizableParticle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResizableParticle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ResizableParticle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ResizableParticle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::particle::resizable_particle::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/particle/ResizableParticle", "builder", "()Lorg/spongepowered/api/effect/particle/ResizableParticle$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ResizableParticle#builder was null") }
+    ::sponge::effect::particle::resizable_particle::Builder { env: env, object: ret }
+  }
+
+  pub fn get_size(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/effect/particle/ResizableParticle", self.object, "getSize", "()F", CallFloatMethod)
+  }
+
+}
+
+pub mod block_particle {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/BlockParticle$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_state: ::sponge::block::BlockState) -> ::sponge::effect::particle::block_particle::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/BlockParticle$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/effect/particle/BlockParticle$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/BlockParticle$Builder#block was null") }
+      ::sponge::effect::particle::block_particle::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod colored_particle {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ColoredParticle$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn color(&self, color: ::sponge::util::Color) -> ::sponge::effect::particle::colored_particle::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ColoredParticle$Builder", self.object, "color", "(Lorg/spongepowered/api/util/Color;)Lorg/spongepowered/api/effect/particle/ColoredParticle$Builder;", CallObjectMethodA, color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ColoredParticle$Builder#color was null") }
+      ::sponge::effect::particle::colored_particle::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod item_particle {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ItemParticle$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "item")]
+    pub fn item(&self, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::effect::particle::item_particle::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ItemParticle$Builder", self.object, "item", "(Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/effect/particle/ItemParticle$Builder;", CallObjectMethodA, item_stack_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ItemParticle$Builder#item was null") }
+      ::sponge::effect::particle::item_particle::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod note_particle {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/NoteParticle$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn note(&self, note_pitch: ::sponge::data::type_::NotePitch) -> ::sponge::effect::particle::note_particle::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/NoteParticle$Builder", self.object, "note", "(Lorg/spongepowered/api/data/type/NotePitch;)Lorg/spongepowered/api/effect/particle/NoteParticle$Builder;", CallObjectMethodA, note_pitch.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/NoteParticle$Builder#note was null") }
+      ::sponge::effect::particle::note_particle::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod particle_effect {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleEffect$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ParticleBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ParticleBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ParticleBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ParticleBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, particle_type: ::sponge::effect::particle::ParticleType) -> ::sponge::effect::particle::particle_effect::ParticleBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", self.object, "type", "(Lorg/spongepowered/api/effect/particle/ParticleType;)Lorg/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder;", CallObjectMethodA, particle_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder#type was null") }
+      ::sponge::effect::particle::particle_effect::ParticleBuilder { env: self.env, object: ret }
+    }
+
+    pub fn motion(&self, vector_3d: ::math::Vector3d) -> ::sponge::effect::particle::particle_effect::ParticleBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", self.object, "motion", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder#motion was null") }
+      ::sponge::effect::particle::particle_effect::ParticleBuilder { env: self.env, object: ret }
+    }
+
+    pub fn offset(&self, vector_3d: ::math::Vector3d) -> ::sponge::effect::particle::particle_effect::ParticleBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", self.object, "offset", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder#offset was null") }
+      ::sponge::effect::particle::particle_effect::ParticleBuilder { env: self.env, object: ret }
+    }
+
+    pub fn count(&self, value: i32) -> ::sponge::effect::particle::particle_effect::ParticleBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", self.object, "count", "(I)Lorg/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder#count was null") }
+      ::sponge::effect::particle::particle_effect::ParticleBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::effect::particle::ParticleEffect {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder", self.object, "build", "()Lorg/spongepowered/api/effect/particle/ParticleEffect;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleEffect$ParticleBuilder#build was null") }
+      ::sponge::effect::particle::ParticleEffect { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod particle_type {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Block {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Block {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Block {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Block`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType$Block", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn get_default_block_state(&self) -> ::sponge::block::BlockState {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType$Block", self.object, "getDefaultBlockState", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleType$Block#getDefaultBlockState was null") }
+      ::sponge::block::BlockState { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Colorable {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Colorable {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Colorable {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Colorable`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType$Colorable", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn get_default_color(&self) -> ::sponge::util::Color {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType$Colorable", self.object, "getDefaultColor", "()Lorg/spongepowered/api/util/Color;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleType$Colorable#getDefaultColor was null") }
+      ::sponge::util::Color { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Item {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Item {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Item {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Item`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType$Item", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "item")]
+    pub fn get_default_item(&self) -> ::sponge::item::inventory::ItemStack {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType$Item", self.object, "getDefaultItem", "()Lorg/spongepowered/api/item/inventory/ItemStack;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleType$Item#getDefaultItem was null") }
+      ::sponge::item::inventory::ItemStack { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Note {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Note {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Note {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Note`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType$Note", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn get_default_note(&self) -> ::sponge::data::type_::NotePitch {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType$Note", self.object, "getDefaultNote", "()Lorg/spongepowered/api/data/type/NotePitch;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ParticleType$Note#getDefaultNote was null") }
+      ::sponge::data::type_::NotePitch { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Resizable {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Resizable {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Resizable {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Resizable`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ParticleType$Resizable", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_default_size(&self) -> f32 {
+      bound_method!(self.env, "org/spongepowered/api/effect/particle/ParticleType$Resizable", self.object, "getDefaultSize", "()F", CallFloatMethod)
+    }
+
+  }
+}
+
+pub mod resizable_particle {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/particle/ResizableParticle$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn size(&self, value: f32) -> ::sponge::effect::particle::resizable_particle::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/particle/ResizableParticle$Builder", self.object, "size", "(F)Lorg/spongepowered/api/effect/particle/ResizableParticle$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/particle/ResizableParticle$Builder#size was null") }
+      ::sponge::effect::particle::resizable_particle::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,204 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct PotionEffect {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PotionEffect {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PotionEffect {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PotionEffect`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/potion/PotionEffect", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::effect::potion::potion_effect::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/potion/PotionEffect", "builder", "()Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect#builder was null") }
+    ::sponge::effect::potion::potion_effect::Builder { env: env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, potion_effect_type: ::sponge::effect::potion::PotionEffectType, value: i32, value_2: i32) -> ::sponge::effect::potion::PotionEffect {
+    let ret = static_java_method!(env, "org/spongepowered/api/effect/potion/PotionEffect", "of", "(Lorg/spongepowered/api/effect/potion/PotionEffectType;II)Lorg/spongepowered/api/effect/potion/PotionEffect;", CallStaticObjectMethodA, potion_effect_type.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect#of was null") }
+    ::sponge::effect::potion::PotionEffect { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::effect::potion::PotionEffectType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect", self.object, "getType", "()Lorg/spongepowered/api/effect/potion/PotionEffectType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect#getType was null") }
+    ::sponge::effect::potion::PotionEffectType { env: self.env, object: ret }
+  }
+
+  pub fn get_duration(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect", self.object, "getDuration", "()I", CallIntMethod)
+  }
+
+  pub fn get_amplifier(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect", self.object, "getAmplifier", "()I", CallIntMethod)
+  }
+
+  pub fn is_ambient(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect", self.object, "isAmbient", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_show_particles(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect", self.object, "getShowParticles", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PotionEffectType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PotionEffectType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PotionEffectType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PotionEffectType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/potion/PotionEffectType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_instant(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffectType", self.object, "isInstant", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_potion_translation(&self) -> ::sponge::text::translation::Translation {
+    let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffectType", self.object, "getPotionTranslation", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffectType#getPotionTranslation was null") }
+    ::sponge::text::translation::Translation { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PotionEffectTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PotionEffectTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PotionEffectTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PotionEffectTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/potion/PotionEffectTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod potion_effect {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/potion/PotionEffect$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn from_potion_effect(&self, potion_effect: ::sponge::effect::potion::PotionEffect) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "from", "(Lorg/spongepowered/api/effect/potion/PotionEffect;)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, potion_effect.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#from was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn potion_type(&self, potion_effect_type: ::sponge::effect::potion::PotionEffectType) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "potionType", "(Lorg/spongepowered/api/effect/potion/PotionEffectType;)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, potion_effect_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#potionType was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn duration(&self, value: i32) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "duration", "(I)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#duration was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn amplifier(&self, value: i32) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "amplifier", "(I)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#amplifier was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn ambience(&self, value: bool) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "ambience", "(Z)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#ambience was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn particles(&self, value: bool) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "particles", "(Z)Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#particles was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::effect::potion::PotionEffect {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "build", "()Lorg/spongepowered/api/effect/potion/PotionEffect;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#build was null") }
+      ::sponge::effect::potion::PotionEffect { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::effect::potion::potion_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "reset", "()Lorg/spongepowered/api/effect/potion/PotionEffect$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#reset was null") }
+      ::sponge::effect::potion::potion_effect::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#from was null") }
+      ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/effect/potion/PotionEffect$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/effect/potion/PotionEffect$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,116 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct PitchModulation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PitchModulation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PitchModulation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PitchModulation`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/sound/PitchModulation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SoundCategories {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SoundCategories {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SoundCategories {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SoundCategories`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/sound/SoundCategories", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SoundCategory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SoundCategory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SoundCategory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SoundCategory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/sound/SoundCategory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SoundType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SoundType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SoundType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SoundType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/sound/SoundType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SoundTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SoundTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SoundTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SoundTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/effect/sound/SoundTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
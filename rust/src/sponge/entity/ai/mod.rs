@@ -0,0 +1,124 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod task;
+
+#[derive(Debug)]
+pub struct Goal {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Goal {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Goal {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Goal`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/Goal", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::entity::ai::GoalType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "getType", "()Lorg/spongepowered/api/entity/ai/GoalType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#getType was null") }
+    ::sponge::entity::ai::GoalType { env: self.env, object: ret }
+  }
+
+  pub fn get_owner(&self) -> ::sponge::entity::living::Agent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "getOwner", "()Lorg/spongepowered/api/entity/living/Agent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#getOwner was null") }
+    ::sponge::entity::living::Agent { env: self.env, object: ret }
+  }
+
+  pub fn add_task(&self, value: i32, ai_task: ::sponge::entity::ai::task::AITask) -> ::sponge::entity::ai::Goal {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "addTask", "(ILorg/spongepowered/api/entity/ai/task/AITask;)Lorg/spongepowered/api/entity/ai/Goal;", CallObjectMethodA, value, ai_task.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#addTask was null") }
+    ::sponge::entity::ai::Goal { env: self.env, object: ret }
+  }
+
+  pub fn remove_task(&self, ai_task: ::sponge::entity::ai::task::AITask) -> ::sponge::entity::ai::Goal {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "removeTask", "(Lorg/spongepowered/api/entity/ai/task/AITask;)Lorg/spongepowered/api/entity/ai/Goal;", CallObjectMethodA, ai_task.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#removeTask was null") }
+    ::sponge::entity::ai::Goal { env: self.env, object: ret }
+  }
+
+  pub fn remove_tasks(&self, ai_task_type: ::sponge::entity::ai::task::AITaskType) -> ::sponge::entity::ai::Goal {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "removeTasks", "(Lorg/spongepowered/api/entity/ai/task/AITaskType;)Lorg/spongepowered/api/entity/ai/Goal;", CallObjectMethodA, ai_task_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#removeTasks was null") }
+    ::sponge::entity::ai::Goal { env: self.env, object: ret }
+  }
+
+  pub fn get_tasks_by_type(&self, ai_task_type: ::sponge::entity::ai::task::AITaskType) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "getTasksByType", "(Lorg/spongepowered/api/entity/ai/task/AITaskType;)Ljava/util/List;", CallObjectMethodA, ai_task_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#getTasksByType was null") }
+    ret
+  }
+
+  pub fn get_tasks(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "getTasks", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/Goal#getTasks was null") }
+    ret
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/Goal", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GoalType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GoalType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GoalType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GoalType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/GoalType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_goal_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/GoalType", self.object, "getGoalClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/GoalType#getGoalClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GoalTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GoalTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GoalTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GoalTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/GoalTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,67 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct RunAroundLikeCrazyAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RunAroundLikeCrazyAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RunAroundLikeCrazyAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RunAroundLikeCrazyAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask", self.object, "getSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::horse::RunAroundLikeCrazyAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask", self.object, "setSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask#setSpeed was null") }
+    ::sponge::entity::ai::task::builtin::creature::horse::RunAroundLikeCrazyAITask { env: self.env, object: ret }
+  }
+
+}
+
+pub mod run_around_like_crazy_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::horse::run_around_like_crazy_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask$Builder", self.object, "speed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/horse/RunAroundLikeCrazyAITask$Builder#speed was null") }
+      ::sponge::entity::ai::task::builtin::creature::horse::run_around_like_crazy_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,384 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod horse;
+pub mod target;
+
+#[derive(Debug)]
+pub struct AttackLivingAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AttackLivingAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AttackLivingAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AttackLivingAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask", self.object, "getSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::AttackLivingAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask", self.object, "setSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask#setSpeed was null") }
+    ::sponge::entity::ai::task::builtin::creature::AttackLivingAITask { env: self.env, object: ret }
+  }
+
+  pub fn has_long_memory(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask", self.object, "hasLongMemory", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_long_memory(&self, value: bool) -> ::sponge::entity::ai::task::builtin::creature::AttackLivingAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask", self.object, "setLongMemory", "(Z)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask#setLongMemory was null") }
+    ::sponge::entity::ai::task::builtin::creature::AttackLivingAITask { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AvoidEntityAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AvoidEntityAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AvoidEntityAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AvoidEntityAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_target_selector(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "getTargetSelector", "()Ljava/util/function/Predicate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask#getTargetSelector was null") }
+    ret
+  }
+
+  pub fn set_target_selector(&self, predicate: jobject) -> ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "setTargetSelector", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask#setTargetSelector was null") }
+    ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_search_distance(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "getSearchDistance", "()F", CallFloatMethod)
+  }
+
+  pub fn set_search_distance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "setSearchDistance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask#setSearchDistance was null") }
+    ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_close_range_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "getCloseRangeSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_close_range_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "setCloseRangeSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask#setCloseRangeSpeed was null") }
+    ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_far_range_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "getFarRangeSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_far_range_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask", self.object, "setFarRangeSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask#setFarRangeSpeed was null") }
+    ::sponge::entity::ai::task::builtin::creature::AvoidEntityAITask { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WanderAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WanderAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WanderAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WanderAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask", self.object, "getSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::WanderAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask", self.object, "setSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask#setSpeed was null") }
+    ::sponge::entity::ai::task::builtin::creature::WanderAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_execution_chance(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask", self.object, "getExecutionChance", "()I", CallIntMethod)
+  }
+
+  pub fn set_execution_chance(&self, value: i32) -> ::sponge::entity::ai::task::builtin::creature::WanderAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask", self.object, "setExecutionChance", "(I)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask#setExecutionChance was null") }
+    ::sponge::entity::ai::task::builtin::creature::WanderAITask { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WatchClosestAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WatchClosestAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WatchClosestAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WatchClosestAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_watched_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "getWatchedClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask#getWatchedClass was null") }
+    ret
+  }
+
+  pub fn set_watched_class(&self, class: jobject) -> ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "setWatchedClass", "(Ljava/lang/Class;)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask#setWatchedClass was null") }
+    ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_max_distance(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "getMaxDistance", "()F", CallFloatMethod)
+  }
+
+  pub fn set_max_distance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "setMaxDistance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask#setMaxDistance was null") }
+    ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask { env: self.env, object: ret }
+  }
+
+  pub fn get_chance(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "getChance", "()F", CallFloatMethod)
+  }
+
+  pub fn set_chance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask", self.object, "setChance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask#setChance was null") }
+    ::sponge::entity::ai::task::builtin::creature::WatchClosestAITask { env: self.env, object: ret }
+  }
+
+}
+
+pub mod attack_living_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::attack_living_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder", self.object, "speed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder#speed was null") }
+      ::sponge::entity::ai::task::builtin::creature::attack_living_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn long_memory(&self) -> ::sponge::entity::ai::task::builtin::creature::attack_living_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder", self.object, "longMemory", "()Lorg/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AttackLivingAITask$Builder#longMemory was null") }
+      ::sponge::entity::ai::task::builtin::creature::attack_living_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod avoid_entity_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn target_selector(&self, predicate: jobject) -> ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder", self.object, "targetSelector", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder;", CallObjectMethodA, predicate);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder#targetSelector was null") }
+      ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn search_distance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder", self.object, "searchDistance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder#searchDistance was null") }
+      ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn close_range_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder", self.object, "closeRangeSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder#closeRangeSpeed was null") }
+      ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn far_range_speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder", self.object, "farRangeSpeed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/AvoidEntityAITask$Builder#farRangeSpeed was null") }
+      ::sponge::entity::ai::task::builtin::creature::avoid_entity_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod wander_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn speed(&self, value: f64) -> ::sponge::entity::ai::task::builtin::creature::wander_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder", self.object, "speed", "(D)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder#speed was null") }
+      ::sponge::entity::ai::task::builtin::creature::wander_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn execution_chance(&self, value: i32) -> ::sponge::entity::ai::task::builtin::creature::wander_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder", self.object, "executionChance", "(I)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WanderAITask$Builder#executionChance was null") }
+      ::sponge::entity::ai::task::builtin::creature::wander_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod watch_closest_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn watch(&self, class: jobject) -> ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder", self.object, "watch", "(Ljava/lang/Class;)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder;", CallObjectMethodA, class);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder#watch was null") }
+      ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn max_distance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder", self.object, "maxDistance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder#maxDistance was null") }
+      ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn chance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder", self.object, "chance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/creature/WatchClosestAITask$Builder#chance was null") }
+      ::sponge::entity::ai::task::builtin::creature::watch_closest_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
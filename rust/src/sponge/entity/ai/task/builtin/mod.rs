@@ -0,0 +1,67 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod creature;
+
+#[derive(Debug)]
+pub struct SwimmingAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SwimmingAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SwimmingAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SwimmingAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_swim_chance(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask", self.object, "getSwimChance", "()F", CallFloatMethod)
+  }
+
+  pub fn set_swim_chance(&self, value: f32) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask", self.object, "setSwimChance", "(F)V", CallVoidMethodA, value);
+  }
+
+}
+
+pub mod swimming_a_i_task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn swim_chance(&self, value: f32) -> ::sponge::entity::ai::task::builtin::swimming_a_i_task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask$Builder", self.object, "swimChance", "(F)Lorg/spongepowered/api/entity/ai/task/builtin/SwimmingAITask$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/builtin/SwimmingAITask$Builder#swimChance was null") }
+      ::sponge::entity::ai::task::builtin::swimming_a_i_task::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
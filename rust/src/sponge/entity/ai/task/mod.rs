@@ -0,0 +1,191 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod builtin;
+
+#[derive(Debug)]
+pub struct AITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/AITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::entity::ai::task::AITaskType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITask", self.object, "getType", "()Lorg/spongepowered/api/entity/ai/task/AITaskType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AITask#getType was null") }
+    ::sponge::entity::ai::task::AITaskType { env: self.env, object: ret }
+  }
+
+  pub fn get_goal(&self) -> Option<::sponge::entity::ai::Goal> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITask", self.object, "getGoal", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AITask#getGoal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::ai::Goal { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_owner(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITask", self.object, "getOwner", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AITask#getOwner was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn can_run_concurrent_with(&self, ai_task: ::sponge::entity::ai::task::AITask) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITask", self.object, "canRunConcurrentWith", "(Lorg/spongepowered/api/entity/ai/task/AITask;)Z", CallBooleanMethodA, ai_task.object) != 0
+  }
+
+  pub fn can_be_interrupted(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITask", self.object, "canBeInterrupted", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AITaskBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AITaskBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AITaskBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AITaskBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/AITaskBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn build(&self, agent: ::sponge::entity::living::Agent) -> ::sponge::entity::ai::task::AITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITaskBuilder", self.object, "build", "(Lorg/spongepowered/api/entity/living/Agent;)Lorg/spongepowered/api/entity/ai/task/AITask;", CallObjectMethodA, agent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AITaskBuilder#build was null") }
+    ::sponge::entity::ai::task::AITask { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AITaskType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AITaskType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AITaskType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AITaskType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/AITaskType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_a_i_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AITaskType", self.object, "getAIClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AITaskType#getAIClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AITaskTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AITaskTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AITaskTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AITaskTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/AITaskTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractAITask {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractAITask {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractAITask {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractAITask`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ai/task/AbstractAITask", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::entity::ai::task::AITaskType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "getType", "()Lorg/spongepowered/api/entity/ai/task/AITaskType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AbstractAITask#getType was null") }
+    ::sponge::entity::ai::task::AITaskType { env: self.env, object: ret }
+  }
+
+  pub fn get_goal(&self) -> Option<::sponge::entity::ai::Goal> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "getGoal", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ai/task/AbstractAITask#getGoal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::ai::Goal { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn start(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "start", "()V", CallVoidMethod);
+  }
+
+  pub fn should_update(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "shouldUpdate", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn update(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "update", "()V", CallVoidMethod);
+  }
+
+  pub fn continue_updating(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "continueUpdating", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn reset(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ai/task/AbstractAITask", self.object, "reset", "()V", CallVoidMethod);
+  }
+
+}
@@ -0,0 +1,117 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Explosive {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Explosive {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Explosive {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Explosive`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/explosive/Explosive", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_explosion_radius_data(&self) -> ::sponge::data::manipulator::mutable::entity::ExplosionRadiusData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/explosive/Explosive", self.object, "getExplosionRadiusData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ExplosionRadiusData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/explosive/Explosive#getExplosionRadiusData was null") }
+    ::sponge::data::manipulator::mutable::entity::ExplosionRadiusData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn explosion_radius(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/explosive/Explosive", self.object, "explosionRadius", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/explosive/Explosive#explosionRadius was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn detonate(&self, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/entity/explosive/Explosive", self.object, "detonate", "(Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, cause.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FusedExplosive {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FusedExplosive {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FusedExplosive {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FusedExplosive`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/explosive/FusedExplosive", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_fuse_data(&self) -> ::sponge::data::manipulator::mutable::entity::FuseData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/explosive/FusedExplosive", self.object, "getFuseData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/FuseData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/explosive/FusedExplosive#getFuseData was null") }
+    ::sponge::data::manipulator::mutable::entity::FuseData { env: self.env, object: ret }
+  }
+
+  pub fn is_primed(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/explosive/FusedExplosive", self.object, "isPrimed", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn prime(&self, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/entity/explosive/FusedExplosive", self.object, "prime", "(Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, cause.object);
+  }
+
+  #[cfg(feature = "event")]
+  pub fn defuse(&self, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/entity/explosive/FusedExplosive", self.object, "defuse", "(Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, cause.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PrimedTNT {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PrimedTNT {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PrimedTNT {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PrimedTNT`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/explosive/PrimedTNT", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_detonator(&self) -> Option<::sponge::entity::living::Living> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/explosive/PrimedTNT", self.object, "getDetonator", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/explosive/PrimedTNT#getDetonator was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::Living { env: self.env, object: unwrapped }) }
+  }
+
+}
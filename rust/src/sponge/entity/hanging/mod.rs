@@ -0,0 +1,127 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Hanging {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hanging {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hanging {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hanging`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/hanging/Hanging", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_directional_data(&self) -> ::sponge::data::manipulator::mutable::block::DirectionalData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/hanging/Hanging", self.object, "getDirectionalData", "()Lorg/spongepowered/api/data/manipulator/mutable/block/DirectionalData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/hanging/Hanging#getDirectionalData was null") }
+    ::sponge::data::manipulator::mutable::block::DirectionalData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn direction(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/hanging/Hanging", self.object, "direction", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/hanging/Hanging#direction was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemFrame {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemFrame {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemFrame {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemFrame`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/hanging/ItemFrame", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LeashHitch {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LeashHitch {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LeashHitch {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LeashHitch`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/hanging/LeashHitch", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_leashed_entity(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/hanging/LeashHitch", self.object, "getLeashedEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/hanging/LeashHitch#getLeashedEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Painting {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Painting {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Painting {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Painting`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/hanging/Painting", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_art_data(&self) -> ::sponge::data::manipulator::mutable::entity::ArtData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/hanging/Painting", self.object, "getArtData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ArtData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/hanging/Painting#getArtData was null") }
+    ::sponge::data::manipulator::mutable::entity::ArtData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn art(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/hanging/Painting", self.object, "art", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/hanging/Painting#art was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
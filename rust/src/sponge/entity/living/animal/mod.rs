@@ -0,0 +1,338 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Animal {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Animal {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Animal {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Animal`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Animal", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Chicken {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Chicken {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Chicken {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Chicken`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Chicken", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Cow {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Cow {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Cow {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Cow`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Cow", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Horse {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Horse {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Horse {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Horse`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Horse", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_horse_data(&self) -> ::sponge::data::manipulator::mutable::entity::HorseData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Horse", self.object, "getHorseData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/HorseData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Horse#getHorseData was null") }
+    ::sponge::data::manipulator::mutable::entity::HorseData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn variant(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Horse", self.object, "variant", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Horse#variant was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn style(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Horse", self.object, "style", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Horse#style was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Horse", self.object, "color", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Horse#color was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Mooshroom {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Mooshroom {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Mooshroom {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Mooshroom`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Mooshroom", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Ocelot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ocelot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ocelot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ocelot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Ocelot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_ocelot_data(&self) -> ::sponge::data::manipulator::mutable::entity::OcelotData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Ocelot", self.object, "getOcelotData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/OcelotData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Ocelot#getOcelotData was null") }
+    ::sponge::data::manipulator::mutable::entity::OcelotData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn variant(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Ocelot", self.object, "variant", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Ocelot#variant was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Pig {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Pig {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Pig {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pig`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Pig", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_pig_saddle_data(&self) -> ::sponge::data::manipulator::mutable::entity::PigSaddleData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Pig", self.object, "getPigSaddleData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/PigSaddleData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Pig#getPigSaddleData was null") }
+    ::sponge::data::manipulator::mutable::entity::PigSaddleData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn saddled(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Pig", self.object, "saddled", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Pig#saddled was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PolarBear {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PolarBear {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PolarBear {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PolarBear`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/PolarBear", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Rabbit {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Rabbit {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Rabbit {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Rabbit`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Rabbit", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_rabbit_data(&self) -> ::sponge::data::manipulator::mutable::entity::RabbitData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Rabbit", self.object, "getRabbitData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/RabbitData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Rabbit#getRabbitData was null") }
+    ::sponge::data::manipulator::mutable::entity::RabbitData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn variant(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Rabbit", self.object, "variant", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Rabbit#variant was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Sheep {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Sheep {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Sheep {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Sheep`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Sheep", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_dye_data(&self) -> ::sponge::data::manipulator::mutable::DyeableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Sheep", self.object, "getDyeData", "()Lorg/spongepowered/api/data/manipulator/mutable/DyeableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Sheep#getDyeData was null") }
+    ::sponge::data::manipulator::mutable::DyeableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn color(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/animal/Sheep", self.object, "color", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/animal/Sheep#color was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Wolf {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Wolf {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Wolf {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Wolf`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/animal/Wolf", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,124 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ComplexLiving {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ComplexLiving {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ComplexLiving {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ComplexLiving`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/complex/ComplexLiving", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/complex/ComplexLiving", self.object, "getParts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/complex/ComplexLiving#getParts was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ComplexLivingPart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ComplexLivingPart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ComplexLivingPart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ComplexLivingPart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/complex/ComplexLivingPart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_parent(&self) -> ::sponge::entity::living::complex::ComplexLiving {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/complex/ComplexLivingPart", self.object, "getParent", "()Lorg/spongepowered/api/entity/living/complex/ComplexLiving;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/complex/ComplexLivingPart#getParent was null") }
+    ::sponge::entity::living::complex::ComplexLiving { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnderDragon {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnderDragon {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnderDragon {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnderDragon`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/complex/EnderDragon", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/complex/EnderDragon", self.object, "getParts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/complex/EnderDragon#getParts was null") }
+    ret
+  }
+
+  pub fn get_healing_crystal(&self) -> Option<::sponge::entity::EnderCrystal> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/complex/EnderDragon", self.object, "getHealingCrystal", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/complex/EnderDragon#getHealingCrystal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::EnderCrystal { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnderDragonPart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnderDragonPart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnderDragonPart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnderDragonPart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/complex/EnderDragonPart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_parent(&self) -> ::sponge::entity::living::complex::EnderDragon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/complex/EnderDragonPart", self.object, "getParent", "()Lorg/spongepowered/api/entity/living/complex/EnderDragon;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/complex/EnderDragonPart#getParent was null") }
+    ::sponge::entity::living::complex::EnderDragon { env: self.env, object: ret }
+  }
+
+}
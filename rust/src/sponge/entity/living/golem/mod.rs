@@ -0,0 +1,100 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Golem {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Golem {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Golem {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Golem`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/golem/Golem", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IronGolem {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IronGolem {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IronGolem {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IronGolem`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/golem/IronGolem", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn player_created(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/golem/IronGolem", self.object, "playerCreated", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/golem/IronGolem#playerCreated was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Shulker {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Shulker {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Shulker {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Shulker`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/golem/Shulker", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SnowGolem {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SnowGolem {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SnowGolem {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SnowGolem`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/golem/SnowGolem", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,562 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod animal;
+pub mod complex;
+pub mod golem;
+pub mod monster;
+pub mod player;
+
+#[derive(Debug)]
+pub struct Aerial {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Aerial {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Aerial {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Aerial`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Aerial", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Ageable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ageable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ageable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ageable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Ageable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_scale_for_age(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/Ageable", self.object, "setScaleForAge", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_age_data(&self) -> ::sponge::data::manipulator::mutable::entity::AgeableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Ageable", self.object, "getAgeData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/AgeableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Ageable#getAgeData was null") }
+    ::sponge::data::manipulator::mutable::entity::AgeableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn age(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Ageable", self.object, "age", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Ageable#age was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Agent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Agent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Agent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Agent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Agent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_target(&self) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Agent", self.object, "getTarget", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Agent#getTarget was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set_target(&self, entity: ::sponge::entity::Entity) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/Agent", self.object, "setTarget", "(Lorg/spongepowered/api/entity/Entity;)V", CallVoidMethodA, entity.object);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_agent_data(&self) -> ::sponge::data::manipulator::mutable::entity::AgentData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Agent", self.object, "getAgentData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/AgentData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Agent#getAgentData was null") }
+    ::sponge::data::manipulator::mutable::entity::AgentData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn ai_enabled(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Agent", self.object, "aiEnabled", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Agent#aiEnabled was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn get_goal(&self, goal_type: ::sponge::entity::ai::GoalType) -> Option<::sponge::entity::ai::Goal> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Agent", self.object, "getGoal", "(Lorg/spongepowered/api/entity/ai/GoalType;)Ljava/util/Optional;", CallObjectMethodA, goal_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Agent#getGoal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::ai::Goal { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Ambient {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ambient {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ambient {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ambient`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Ambient", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Aquatic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Aquatic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Aquatic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Aquatic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Aquatic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorStand {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorStand {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorStand {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArmorStand`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/ArmorStand", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_body_part_rotational_data(&self) -> ::sponge::data::manipulator::mutable::entity::BodyPartRotationalData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "getBodyPartRotationalData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/BodyPartRotationalData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#getBodyPartRotationalData was null") }
+    ::sponge::data::manipulator::mutable::entity::BodyPartRotationalData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn marker(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "marker", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#marker was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn small(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "small", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#small was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn base_plate(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "basePlate", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#basePlate was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn arms(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "arms", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#arms was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn gravity(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "gravity", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#gravity was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_armor_stand_data(&self) -> ::sponge::data::manipulator::mutable::entity::ArmorStandData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "getArmorStandData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ArmorStandData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/ArmorStand#getArmorStandData was null") }
+    ::sponge::data::manipulator::mutable::entity::ArmorStandData { env: self.env, object: ret }
+  }
+
+  pub fn is_small(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "isSmall", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_small(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "setSmall", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn has_gravity(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "hasGravity", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_gravity(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "setGravity", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn does_show_arms(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "doesShowArms", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_show_arms(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "setShowArms", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn has_base_plate(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "hasBasePlate", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_has_base_plate(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/ArmorStand", self.object, "setHasBasePlate", "(Z)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Bat {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Bat {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Bat {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Bat`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Bat", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Creature {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Creature {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Creature {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Creature`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Creature", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Hostile {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hostile {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hostile {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hostile`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Hostile", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Human {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Human {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Human {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Human`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Human", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Humanoid {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Humanoid {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Humanoid {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Humanoid`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Humanoid", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_food_data(&self) -> ::sponge::data::manipulator::mutable::entity::FoodData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Humanoid", self.object, "getFoodData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/FoodData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Humanoid#getFoodData was null") }
+    ::sponge::data::manipulator::mutable::entity::FoodData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn food_level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Humanoid", self.object, "foodLevel", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Humanoid#foodLevel was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn exhaustion(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Humanoid", self.object, "exhaustion", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Humanoid#exhaustion was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn saturation(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Humanoid", self.object, "saturation", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Humanoid#saturation was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Living {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Living {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Living {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Living`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Living", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_health_data(&self) -> ::sponge::data::manipulator::mutable::entity::HealthData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "getHealthData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/HealthData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#getHealthData was null") }
+    ::sponge::data::manipulator::mutable::entity::HealthData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn health(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "health", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#health was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn max_health(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "maxHealth", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#maxHealth was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_mortal_data(&self) -> ::sponge::data::manipulator::mutable::entity::DamageableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "getMortalData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/DamageableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#getMortalData was null") }
+    ::sponge::data::manipulator::mutable::entity::DamageableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn last_attacker(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "lastAttacker", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#lastAttacker was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn last_damage(&self) -> ::sponge::data::value::mutable::OptionalValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "lastDamage", "()Lorg/spongepowered/api/data/value/mutable/OptionalValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#lastDamage was null") }
+    ::sponge::data::value::mutable::OptionalValue { env: self.env, object: ret }
+  }
+
+  pub fn get_head_rotation(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "getHeadRotation", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Living#getHeadRotation was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_head_rotation(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "setHeadRotation", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+  pub fn look_at(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/Living", self.object, "lookAt", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Squid {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Squid {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Squid {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Squid`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Squid", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Villager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Villager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Villager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Villager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/Villager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_trading(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/Villager", self.object, "isTrading", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_career_data(&self) -> ::sponge::data::manipulator::mutable::entity::CareerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Villager", self.object, "getCareerData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/CareerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Villager#getCareerData was null") }
+    ::sponge::data::manipulator::mutable::entity::CareerData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn career(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/Villager", self.object, "career", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/Villager#career was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
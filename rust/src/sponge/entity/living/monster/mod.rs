@@ -0,0 +1,547 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Blaze {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Blaze {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Blaze {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Blaze`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Blaze", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn aflame(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Blaze", self.object, "aflame", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Blaze#aflame was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Boss {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Boss {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Boss {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Boss`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Boss", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CaveSpider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CaveSpider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CaveSpider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CaveSpider`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/CaveSpider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Creeper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Creeper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Creeper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Creeper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Creeper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn charged(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Creeper", self.object, "charged", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Creeper#charged was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Enderman {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Enderman {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Enderman {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Enderman`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Enderman", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Endermite {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Endermite {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Endermite {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Endermite`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Endermite", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_expirable_data(&self) -> ::sponge::data::manipulator::mutable::entity::ExpirableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Endermite", self.object, "getExpirableData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ExpirableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Endermite#getExpirableData was null") }
+    ::sponge::data::manipulator::mutable::entity::ExpirableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn expire_ticks(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Endermite", self.object, "expireTicks", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Endermite#expireTicks was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Ghast {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ghast {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ghast {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ghast`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Ghast", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Giant {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Giant {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Giant {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Giant`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Giant", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Guardian {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Guardian {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Guardian {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Guardian`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Guardian", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_elder_data(&self) -> ::sponge::data::manipulator::mutable::entity::ElderData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Guardian", self.object, "getElderData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ElderData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Guardian#getElderData was null") }
+    ::sponge::data::manipulator::mutable::entity::ElderData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn elder(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Guardian", self.object, "elder", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Guardian#elder was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn get_beam_target(&self) -> Option<::sponge::entity::living::Living> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Guardian", self.object, "getBeamTarget", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Guardian#getBeamTarget was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::Living { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set_beam_target(&self, living: ::sponge::entity::living::Living) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Guardian", self.object, "setBeamTarget", "(Lorg/spongepowered/api/entity/living/Living;)V", CallVoidMethodA, living.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MagmaCube {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MagmaCube {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MagmaCube {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MagmaCube`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/MagmaCube", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Monster {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Monster {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Monster {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Monster`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Monster", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Silverfish {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Silverfish {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Silverfish {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Silverfish`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Silverfish", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Skeleton {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Skeleton {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Skeleton {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Skeleton`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Skeleton", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_skeleton_data(&self) -> ::sponge::data::manipulator::mutable::entity::SkeletonData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Skeleton", self.object, "getSkeletonData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/SkeletonData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Skeleton#getSkeletonData was null") }
+    ::sponge::data::manipulator::mutable::entity::SkeletonData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn variant(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Skeleton", self.object, "variant", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Skeleton#variant was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Slime {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Slime {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Slime {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Slime`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Slime", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_slime_data(&self) -> ::sponge::data::manipulator::mutable::entity::SlimeData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Slime", self.object, "getSlimeData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/SlimeData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Slime#getSlimeData was null") }
+    ::sponge::data::manipulator::mutable::entity::SlimeData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn slime_size(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Slime", self.object, "slimeSize", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Slime#slimeSize was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Spider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Spider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Spider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Spider`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Spider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_climbing(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Spider", self.object, "isClimbing", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Witch {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Witch {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Witch {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Witch`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Witch", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Wither {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Wither {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Wither {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Wither`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Wither", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_targets(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Wither", self.object, "getTargets", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/Wither#getTargets was null") }
+    ret
+  }
+
+  pub fn set_targets(&self, list: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/monster/Wither", self.object, "setTargets", "(Ljava/util/List;)V", CallVoidMethodA, list);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Zombie {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Zombie {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Zombie {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Zombie`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/Zombie", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ZombiePigman {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ZombiePigman {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ZombiePigman {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ZombiePigman`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/monster/ZombiePigman", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_anger_data(&self) -> ::sponge::data::manipulator::mutable::entity::AngerableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/ZombiePigman", self.object, "getAngerData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/AngerableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/ZombiePigman#getAngerData was null") }
+    ::sponge::data::manipulator::mutable::entity::AngerableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn anger_level(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/monster/ZombiePigman", self.object, "angerLevel", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/monster/ZombiePigman#angerLevel was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,47 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct GameMode {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameMode {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameMode {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameMode`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/gamemode/GameMode", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameModes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameModes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameModes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameModes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/gamemode/GameModes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,224 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod gamemode;
+pub mod tab;
+
+#[derive(Debug)]
+pub struct Player {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Player {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Player {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Player`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/Player", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_viewing_inventory(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "isViewingInventory", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_open_inventory(&self) -> Option<::sponge::item::inventory::Inventory> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getOpenInventory", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getOpenInventory was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Inventory { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "event", feature = "item"))]
+  pub fn open_inventory(&self, inventory: ::sponge::item::inventory::Inventory, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "openInventory", "(Lorg/spongepowered/api/item/inventory/Inventory;Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, inventory.object, cause.object);
+  }
+
+  #[cfg(feature = "event")]
+  pub fn close_inventory(&self, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "closeInventory", "(Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, cause.object);
+  }
+
+  pub fn get_view_distance(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getViewDistance", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_chat_visibility(&self) -> ::sponge::text::chat::ChatVisibility {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getChatVisibility", "()Lorg/spongepowered/api/text/chat/ChatVisibility;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getChatVisibility was null") }
+    ::sponge::text::chat::ChatVisibility { env: self.env, object: ret }
+  }
+
+  pub fn is_chat_colors_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "isChatColorsEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_displayed_skin_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getDisplayedSkinParts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getDisplayedSkinParts was null") }
+    ret
+  }
+
+  #[cfg(feature = "network")]
+  pub fn get_connection(&self) -> ::sponge::network::PlayerConnection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getConnection", "()Lorg/spongepowered/api/network/PlayerConnection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getConnection was null") }
+    ::sponge::network::PlayerConnection { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "resourcepack")]
+  pub fn send_resource_pack(&self, resource_pack: ::sponge::resourcepack::ResourcePack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "sendResourcePack", "(Lorg/spongepowered/api/resourcepack/ResourcePack;)V", CallVoidMethodA, resource_pack.object);
+  }
+
+  pub fn get_tab_list(&self) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getTabList", "()Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getTabList was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  pub fn kick(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "kick", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn kick_text(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "kick", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn get_scoreboard(&self) -> ::sponge::scoreboard::Scoreboard {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getScoreboard", "()Lorg/spongepowered/api/scoreboard/Scoreboard;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getScoreboard was null") }
+    ::sponge::scoreboard::Scoreboard { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn set_scoreboard(&self, scoreboard: ::sponge::scoreboard::Scoreboard) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "setScoreboard", "(Lorg/spongepowered/api/scoreboard/Scoreboard;)V", CallVoidMethodA, scoreboard.object);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_join_data(&self) -> ::sponge::data::manipulator::mutable::entity::JoinData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getJoinData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/JoinData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getJoinData was null") }
+    ::sponge::data::manipulator::mutable::entity::JoinData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn first_played(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "firstPlayed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#firstPlayed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn last_played(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "lastPlayed", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#lastPlayed was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn has_played_before(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "hasPlayedBefore", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_display_name_data(&self) -> ::sponge::data::manipulator::mutable::DisplayNameData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getDisplayNameData", "()Lorg/spongepowered/api/data/manipulator/mutable/DisplayNameData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getDisplayNameData was null") }
+    ::sponge::data::manipulator::mutable::DisplayNameData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_game_mode_data(&self) -> ::sponge::data::manipulator::mutable::entity::GameModeData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "getGameModeData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/GameModeData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#getGameModeData was null") }
+    ::sponge::data::manipulator::mutable::entity::GameModeData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn game_mode(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "gameMode", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/Player#gameMode was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  pub fn is_sleeping_ignored(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "isSleepingIgnored", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_sleeping_ignored(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/Player", self.object, "setSleepingIgnored", "(Z)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct User {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl User {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    User {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `User`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/User", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn get_profile(&self) -> ::sponge::profile::GameProfile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/User#getProfile was null") }
+    ::sponge::profile::GameProfile { env: self.env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/User#getName was null") }
+    ret
+  }
+
+  pub fn is_online(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "isOnline", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_player(&self) -> Option<::sponge::entity::living::player::Player> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "getPlayer", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/User#getPlayer was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::Player { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_achievement_data(&self) -> ::sponge::data::manipulator::mutable::entity::AchievementData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "getAchievementData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/AchievementData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/User#getAchievementData was null") }
+    ::sponge::data::manipulator::mutable::entity::AchievementData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_statistic_data(&self) -> ::sponge::data::manipulator::mutable::entity::StatisticData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/User", self.object, "getStatisticData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/StatisticData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/User#getStatisticData was null") }
+    ::sponge::data::manipulator::mutable::entity::StatisticData { env: self.env, object: ret }
+  }
+
+}
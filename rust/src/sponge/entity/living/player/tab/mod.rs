@@ -0,0 +1,237 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct TabList {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TabList {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TabList {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TabList`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/tab/TabList", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_player(&self) -> ::sponge::entity::living::player::Player {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "getPlayer", "()Lorg/spongepowered/api/entity/living/player/Player;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#getPlayer was null") }
+    ::sponge::entity::living::player::Player { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_header(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "getHeader", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#getHeader was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_header(&self, text: ::sponge::text::Text) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "setHeader", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#setHeader was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_footer(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "getFooter", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#getFooter was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_footer(&self, text: ::sponge::text::Text) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "setFooter", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#setFooter was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_header_and_footer(&self, text: ::sponge::text::Text, text_2: ::sponge::text::Text) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "setHeaderAndFooter", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethodA, text.object, text_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#setHeaderAndFooter was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  pub fn get_entries(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "getEntries", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#getEntries was null") }
+    ret
+  }
+
+  pub fn get_entry(&self, uuid: jobject) -> Option<::sponge::entity::living::player::tab::TabListEntry> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "getEntry", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#getEntry was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn add_entry(&self, tab_list_entry: ::sponge::entity::living::player::tab::TabListEntry) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "addEntry", "(Lorg/spongepowered/api/entity/living/player/tab/TabListEntry;)Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethodA, tab_list_entry.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#addEntry was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  pub fn remove_entry(&self, uuid: jobject) -> Option<::sponge::entity::living::player::tab::TabListEntry> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabList", self.object, "removeEntry", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabList#removeEntry was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TabListEntry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TabListEntry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TabListEntry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TabListEntry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/tab/TabListEntry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", "builder", "()Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#builder was null") }
+    ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: env, object: ret }
+  }
+
+  pub fn get_list(&self) -> ::sponge::entity::living::player::tab::TabList {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "getList", "()Lorg/spongepowered/api/entity/living/player/tab/TabList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#getList was null") }
+    ::sponge::entity::living::player::tab::TabList { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn get_profile(&self) -> ::sponge::profile::GameProfile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#getProfile was null") }
+    ::sponge::profile::GameProfile { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_display_name(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "getDisplayName", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#getDisplayName was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_display_name(&self, text: ::sponge::text::Text) -> ::sponge::entity::living::player::tab::TabListEntry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "setDisplayName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#setDisplayName was null") }
+    ::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: ret }
+  }
+
+  pub fn get_latency(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "getLatency", "()I", CallIntMethod)
+  }
+
+  pub fn set_latency(&self, value: i32) -> ::sponge::entity::living::player::tab::TabListEntry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "setLatency", "(I)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#setLatency was null") }
+    ::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: ret }
+  }
+
+  pub fn get_game_mode(&self) -> ::sponge::entity::living::player::gamemode::GameMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "getGameMode", "()Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#getGameMode was null") }
+    ::sponge::entity::living::player::gamemode::GameMode { env: self.env, object: ret }
+  }
+
+  pub fn set_game_mode(&self, game_mode: ::sponge::entity::living::player::gamemode::GameMode) -> ::sponge::entity::living::player::tab::TabListEntry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry", self.object, "setGameMode", "(Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry;", CallObjectMethodA, game_mode.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry#setGameMode was null") }
+    ::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: ret }
+  }
+
+}
+
+pub mod tab_list_entry {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn list(&self, tab_list: ::sponge::entity::living::player::tab::TabList) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "list", "(Lorg/spongepowered/api/entity/living/player/tab/TabList;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallObjectMethodA, tab_list.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#list was null") }
+      ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "profile")]
+    pub fn profile(&self, game_profile: ::sponge::profile::GameProfile) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "profile", "(Lorg/spongepowered/api/profile/GameProfile;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallObjectMethodA, game_profile.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#profile was null") }
+      ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn display_name(&self, text: ::sponge::text::Text) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "displayName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#displayName was null") }
+      ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: self.env, object: ret }
+    }
+
+    pub fn latency(&self, value: i32) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "latency", "(I)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#latency was null") }
+      ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: self.env, object: ret }
+    }
+
+    pub fn game_mode(&self, game_mode: ::sponge::entity::living::player::gamemode::GameMode) -> ::sponge::entity::living::player::tab::tab_list_entry::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "gameMode", "(Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;)Lorg/spongepowered/api/entity/living/player/tab/TabListEntry$Builder;", CallObjectMethodA, game_mode.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#gameMode was null") }
+      ::sponge::entity::living::player::tab::tab_list_entry::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::entity::living::player::tab::TabListEntry {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder", self.object, "build", "()Lorg/spongepowered/api/entity/living/player/tab/TabListEntry;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/living/player/tab/TabListEntry$Builder#build was null") }
+      ::sponge::entity::living::player::tab::TabListEntry { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,1019 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod ai;
+pub mod explosive;
+pub mod hanging;
+pub mod living;
+pub mod projectile;
+pub mod vehicle;
+pub mod weather;
+
+#[derive(Debug)]
+pub struct AreaEffectCloud {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AreaEffectCloud {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AreaEffectCloud {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AreaEffectCloud`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/AreaEffectCloud", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorEquipable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorEquipable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorEquipable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArmorEquipable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ArmorEquipable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_helmet(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "getHelmet", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ArmorEquipable#getHelmet was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn set_helmet(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "setHelmet", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_chestplate(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "getChestplate", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ArmorEquipable#getChestplate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn set_chestplate(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "setChestplate", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_leggings(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "getLeggings", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ArmorEquipable#getLeggings was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn set_leggings(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "setLeggings", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_boots(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "getBoots", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ArmorEquipable#getBoots was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn set_boots(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "setBoots", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn get_item_in_hand(&self, hand_type: ::sponge::data::type_::HandType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "getItemInHand", "(Lorg/spongepowered/api/data/type/HandType;)Ljava/util/Optional;", CallObjectMethodA, hand_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ArmorEquipable#getItemInHand was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn set_item_in_hand(&self, hand_type: ::sponge::data::type_::HandType, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/entity/ArmorEquipable", self.object, "setItemInHand", "(Lorg/spongepowered/api/data/type/HandType;Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, hand_type.object, item_stack.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnderCrystal {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnderCrystal {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnderCrystal {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnderCrystal`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/EnderCrystal", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Entity {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Entity {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Entity {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Entity`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/Entity", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::entity::EntityType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getType", "()Lorg/spongepowered/api/entity/EntityType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getType was null") }
+    ::sponge::entity::EntityType { env: self.env, object: ret }
+  }
+
+  pub fn create_snapshot(&self) -> ::sponge::entity::EntitySnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "createSnapshot", "()Lorg/spongepowered/api/entity/EntitySnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#createSnapshot was null") }
+    ::sponge::entity::EntitySnapshot { env: self.env, object: ret }
+  }
+
+  pub fn get_random(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getRandom", "()Ljava/util/Random;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getRandom was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location(&self, location: ::sponge::world::Location) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocation", "(Lorg/spongepowered/api/world/Location;)Z", CallBooleanMethodA, location.object) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location_safely(&self, location: ::sponge::world::Location) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocationSafely", "(Lorg/spongepowered/api/world/Location;)Z", CallBooleanMethodA, location.object) != 0
+  }
+
+  pub fn get_rotation(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getRotation", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getRotation was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_rotation(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setRotation", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location_and_rotation_location_vector_3d(&self, location: ::sponge::world::Location, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocationAndRotation", "(Lorg/spongepowered/api/world/Location;Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, location.object, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location_and_rotation_safely_location_vector_3d(&self, location: ::sponge::world::Location, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocationAndRotationSafely", "(Lorg/spongepowered/api/world/Location;Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, location.object, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location_and_rotation_location_vector_3d_enum_set(&self, location: ::sponge::world::Location, vector_3d: ::math::Vector3d, enum_set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocationAndRotation", "(Lorg/spongepowered/api/world/Location;Lcom/flowpowered/math/vector/Vector3d;Ljava/util/EnumSet;)Z", CallBooleanMethodA, location.object, ::convert::ToJava::to_java(&vector_3d, self.env), enum_set) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location_and_rotation_safely_location_vector_3d_enum_set(&self, location: ::sponge::world::Location, vector_3d: ::math::Vector3d, enum_set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setLocationAndRotationSafely", "(Lorg/spongepowered/api/world/Location;Lcom/flowpowered/math/vector/Vector3d;Ljava/util/EnumSet;)Z", CallBooleanMethodA, location.object, ::convert::ToJava::to_java(&vector_3d, self.env), enum_set) != 0
+  }
+
+  pub fn get_scale(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getScale", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getScale was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_scale(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setScale", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+  pub fn get_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn set_transform(&self, transform: ::sponge::entity::Transform) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setTransform", "(Lorg/spongepowered/api/entity/Transform;)Z", CallBooleanMethodA, transform.object) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn transfer_to_world_world(&self, world: ::sponge::world::World) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "transferToWorld", "(Lorg/spongepowered/api/world/World;)Z", CallBooleanMethodA, world.object) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn transfer_to_world_world_vector_3d(&self, world: ::sponge::world::World, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "transferToWorld", "(Lorg/spongepowered/api/world/World;Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, world.object, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  pub fn transfer_to_world_string_vector_3d(&self, string: jobject, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "transferToWorld", "(Ljava/lang/String;Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, string, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  pub fn transfer_to_world_uuid_vector_3d(&self, uuid: jobject, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "transferToWorld", "(Ljava/util/UUID;Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, uuid, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_bounding_box(&self) -> Option<::sponge::util::AABB> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getBoundingBox", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getBoundingBox was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::AABB { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_passengers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getPassengers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getPassengers was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn add_passenger(&self, entity: ::sponge::entity::Entity) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "addPassenger", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#addPassenger was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_passenger(&self, entity: ::sponge::entity::Entity) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "removePassenger", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#removePassenger was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn clear_passengers(&self) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "clearPassengers", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#clearPassengers was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_vehicle(&self) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getVehicle", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getVehicle was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_vehicle(&self, entity: ::sponge::entity::Entity) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setVehicle", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#setVehicle was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_base_vehicle(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getBaseVehicle", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getBaseVehicle was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+  pub fn get_velocity(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getVelocity", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getVelocity was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_velocity(&self, vector_3d: ::math::Vector3d) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setVelocity", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#setVelocity was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn is_on_ground(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "isOnGround", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_removed(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "isRemoved", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_loaded(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "isLoaded", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn remove(&self) {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "remove", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "event")]
+  pub fn damage_f64_damage_source(&self, value: f64, damage_source: ::sponge::event::cause::entity::damage::source::DamageSource) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "damage", "(DLorg/spongepowered/api/event/cause/entity/damage/source/DamageSource;)Z", CallBooleanMethodA, value, damage_source.object) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn damage_f64_damage_source_cause(&self, value: f64, damage_source: ::sponge::event::cause::entity::damage::source::DamageSource, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "damage", "(DLorg/spongepowered/api/event/cause/entity/damage/source/DamageSource;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, damage_source.object, cause.object) != 0
+  }
+
+  pub fn get_nearby_entities_f64(&self, value: f64) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getNearbyEntities", "(D)Ljava/util/Collection;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getNearbyEntities was null") }
+    ret
+  }
+
+  pub fn get_nearby_entities_predicate(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getNearbyEntities", "(Ljava/util/function/Predicate;)Ljava/util/Collection;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getNearbyEntities was null") }
+    ret
+  }
+
+  pub fn get_creator(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getCreator", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getCreator was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_notifier(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "getNotifier", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Entity#getNotifier was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_creator(&self, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setCreator", "(Ljava/util/UUID;)V", CallVoidMethodA, uuid);
+  }
+
+  pub fn set_notifier(&self, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "setNotifier", "(Ljava/util/UUID;)V", CallVoidMethodA, uuid);
+  }
+
+  pub fn can_see(&self, entity: ::sponge::entity::Entity) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Entity", self.object, "canSee", "(Lorg/spongepowered/api/entity/Entity;)Z", CallBooleanMethodA, entity.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntitySnapshot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntitySnapshot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntitySnapshot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntitySnapshot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/EntitySnapshot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::entity::entity_snapshot::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/entity/EntitySnapshot", "builder", "()Lorg/spongepowered/api/entity/EntitySnapshot$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot#builder was null") }
+    ::sponge::entity::entity_snapshot::Builder { env: env, object: ret }
+  }
+
+  pub fn get_unique_id(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot", self.object, "getUniqueId", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot#getUniqueId was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_transform(&self) -> Option<::sponge::entity::Transform> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot", self.object, "getTransform", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot#getTransform was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Transform { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_type(&self) -> ::sponge::entity::EntityType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot", self.object, "getType", "()Lorg/spongepowered/api/entity/EntityType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot#getType was null") }
+    ::sponge::entity::EntityType { env: self.env, object: ret }
+  }
+
+  pub fn restore(&self) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot", self.object, "restore", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot#restore was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/EntityType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_entity_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntityType", self.object, "getEntityClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/EntityType#getEntityClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/EntityTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Equipable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Equipable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Equipable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Equipable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/Equipable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn can_equip_equipment_type(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Equipable", self.object, "canEquip", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Z", CallBooleanMethodA, equipment_type.object) != 0
+  }
+
+  #[cfg(feature = "item")]
+  pub fn can_equip_equipment_type_item_stack(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Equipable", self.object, "canEquip", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, equipment_type.object, item_stack.object) != 0
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_equipped(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Equipable", self.object, "getEquipped", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Equipable#getEquipped was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn equip(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Equipable", self.object, "equip", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, equipment_type.object, item_stack.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExperienceOrb {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExperienceOrb {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExperienceOrb {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExperienceOrb`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ExperienceOrb", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn experience_held(&self) -> ::sponge::data::manipulator::mutable::entity::ExpOrbData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ExperienceOrb", self.object, "experienceHeld", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ExpOrbData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ExperienceOrb#experienceHeld was null") }
+    ::sponge::data::manipulator::mutable::entity::ExpOrbData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn experience(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/ExperienceOrb", self.object, "experience", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/ExperienceOrb#experience was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FallingBlock {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FallingBlock {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FallingBlock {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FallingBlock`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/FallingBlock", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_falling_block_data(&self) -> ::sponge::data::manipulator::mutable::entity::FallingBlockData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "getFallingBlockData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/FallingBlockData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#getFallingBlockData was null") }
+    ::sponge::data::manipulator::mutable::entity::FallingBlockData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fall_damage_per_block(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "fallDamagePerBlock", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#fallDamagePerBlock was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn max_fall_damage(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "maxFallDamage", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#maxFallDamage was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn block_state(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "blockState", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#blockState was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn can_place_as_block(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "canPlaceAsBlock", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#canPlaceAsBlock was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn can_drop_as_item(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "canDropAsItem", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#canDropAsItem was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fall_time(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "fallTime", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#fallTime was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn can_hurt_entities(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/FallingBlock", self.object, "canHurtEntities", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/FallingBlock#canHurtEntities was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Item {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Item {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Item {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Item`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/Item", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_item_data(&self) -> ::sponge::data::manipulator::mutable::RepresentedItemData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Item", self.object, "getItemData", "()Lorg/spongepowered/api/data/manipulator/mutable/RepresentedItemData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Item#getItemData was null") }
+    ::sponge::data::manipulator::mutable::RepresentedItemData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn item(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Item", self.object, "item", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Item#item was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_item_type(&self) -> ::sponge::item::ItemType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Item", self.object, "getItemType", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Item#getItemType was null") }
+    ::sponge::item::ItemType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShulkerBullet {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShulkerBullet {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShulkerBullet {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShulkerBullet`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/ShulkerBullet", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Tamer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Tamer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Tamer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Tamer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/Tamer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Tamer", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Tamer#getName was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Transform {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Transform {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Transform {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Transform`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/Transform", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_location(env: *mut JNIEnv, location: ::sponge::world::Location) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/Location;)V", location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_extent(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/extent/Extent;)V", extent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_extent_vector_3d(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3d;)V", extent.object, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_extent_vector_3d_vector_3d(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)V", extent.object, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_location_vector_3d_vector_3d(env: *mut JNIEnv, location: ::sponge::world::Location, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/Location;Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)V", location.object, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new_extent_vector_3d_vector_3d_vector_3d(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d, vector_3d_3: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = new_object!(env, "org/spongepowered/api/entity/Transform", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)V", extent.object, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env), ::convert::ToJava::to_java(&vector_3d_3, env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#<init> was null") }
+    ::sponge::entity::Transform { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_location(&self, location: ::sponge::world::Location) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setLocation", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setLocation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_extent(&self) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getExtent", "()Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getExtent was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_extent(&self, extent: ::sponge::world::extent::Extent) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setExtent", "(Lorg/spongepowered/api/world/extent/Extent;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, extent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setExtent was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getPosition was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_position(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setPosition", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setPosition was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn get_rotation(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getRotation", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getRotation was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_rotation_quaterniond(&self, quaterniond: ::math::Quaterniond) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setRotation", "(Lcom/flowpowered/math/imaginary/Quaterniond;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&quaterniond, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setRotation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn get_rotation_as_quaternion(&self) -> ::math::Quaterniond {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getRotationAsQuaternion", "()Lcom/flowpowered/math/imaginary/Quaterniond;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getRotationAsQuaternion was null") }
+    <::math::Quaterniond as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_rotation_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setRotation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setRotation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn get_pitch(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getPitch", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_yaw(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getYaw", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_roll(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getRoll", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_scale(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "getScale", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#getScale was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_scale(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "setScale", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#setScale was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn add(&self, transform: ::sponge::entity::Transform) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "add", "(Lorg/spongepowered/api/entity/Transform;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, transform.object);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#add was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn add_translation(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "addTranslation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#addTranslation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn add_rotation_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "addRotation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#addRotation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn add_rotation_quaterniond(&self, quaterniond: ::math::Quaterniond) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "addRotation", "(Lcom/flowpowered/math/imaginary/Quaterniond;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&quaterniond, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#addRotation was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn add_scale(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "addScale", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Transform;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#addScale was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  pub fn to_matrix(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "toMatrix", "()Lcom/flowpowered/math/matrix/Matrix4d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#toMatrix was null") }
+    ret
+  }
+
+  pub fn is_valid(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "isValid", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/Transform", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/Transform#toString was null") }
+    ret
+  }
+
+}
+
+pub mod entity_snapshot {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/EntitySnapshot$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn world(&self, world_properties: ::sponge::world::storage::WorldProperties) -> ::sponge::entity::entity_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot$Builder", self.object, "world", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Lorg/spongepowered/api/entity/EntitySnapshot$Builder;", CallObjectMethodA, world_properties.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot$Builder#world was null") }
+      ::sponge::entity::entity_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, entity_type: ::sponge::entity::EntityType) -> ::sponge::entity::entity_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot$Builder", self.object, "type", "(Lorg/spongepowered/api/entity/EntityType;)Lorg/spongepowered/api/entity/EntitySnapshot$Builder;", CallObjectMethodA, entity_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot$Builder#type was null") }
+      ::sponge::entity::entity_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn position(&self, vector_3d: ::math::Vector3d) -> ::sponge::entity::entity_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot$Builder", self.object, "position", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/EntitySnapshot$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot$Builder#position was null") }
+      ::sponge::entity::entity_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::entity::entity_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/entity/EntitySnapshot$Builder", self.object, "from", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/entity/EntitySnapshot$Builder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/entity/EntitySnapshot$Builder#from was null") }
+      ::sponge::entity::entity_snapshot::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,84 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Arrow {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Arrow {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Arrow {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Arrow`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/arrow/Arrow", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_knockback_data(&self) -> ::sponge::data::manipulator::mutable::entity::KnockbackData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/arrow/Arrow", self.object, "getKnockbackData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/KnockbackData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/arrow/Arrow#getKnockbackData was null") }
+    ::sponge::data::manipulator::mutable::entity::KnockbackData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn knockback_strength(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/arrow/Arrow", self.object, "knockbackStrength", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/arrow/Arrow#knockbackStrength was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpectralArrow {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpectralArrow {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpectralArrow {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpectralArrow`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/arrow/SpectralArrow", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TippedArrow {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TippedArrow {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TippedArrow {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TippedArrow`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/arrow/TippedArrow", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
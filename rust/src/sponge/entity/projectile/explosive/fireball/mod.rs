@@ -0,0 +1,70 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Fireball {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Fireball {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Fireball {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Fireball`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/explosive/fireball/Fireball", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LargeFireball {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LargeFireball {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LargeFireball {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LargeFireball`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/explosive/fireball/LargeFireball", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SmallFireball {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SmallFireball {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SmallFireball {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SmallFireball`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/explosive/fireball/SmallFireball", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,49 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod fireball;
+
+#[derive(Debug)]
+pub struct DragonFireball {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DragonFireball {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DragonFireball {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DragonFireball`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/explosive/DragonFireball", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WitherSkull {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WitherSkull {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WitherSkull {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WitherSkull`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/explosive/WitherSkull", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,319 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod arrow;
+pub mod explosive;
+pub mod source;
+
+#[derive(Debug)]
+pub struct DamagingProjectile {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamagingProjectile {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamagingProjectile {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamagingProjectile`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/DamagingProjectile", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_damaging_data(&self) -> ::sponge::data::manipulator::mutable::entity::DamagingData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/DamagingProjectile", self.object, "getDamagingData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/DamagingData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/DamagingProjectile#getDamagingData was null") }
+    ::sponge::data::manipulator::mutable::entity::DamagingData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn damage(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/DamagingProjectile", self.object, "damage", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/DamagingProjectile#damage was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn damage_for_entity(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/DamagingProjectile", self.object, "damageForEntity", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/DamagingProjectile#damageForEntity was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Egg {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Egg {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Egg {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Egg`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/Egg", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EnderPearl {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EnderPearl {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EnderPearl {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EnderPearl`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/EnderPearl", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EyeOfEnder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EyeOfEnder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EyeOfEnder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EyeOfEnder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/EyeOfEnder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_target_location_data(&self) -> ::sponge::data::manipulator::mutable::TargetedLocationData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/EyeOfEnder", self.object, "getTargetLocationData", "()Lorg/spongepowered/api/data/manipulator/mutable/TargetedLocationData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/EyeOfEnder#getTargetLocationData was null") }
+    ::sponge::data::manipulator::mutable::TargetedLocationData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn target(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/EyeOfEnder", self.object, "target", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/EyeOfEnder#target was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Firework {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Firework {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Firework {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Firework`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/Firework", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_firework_data(&self) -> ::sponge::data::manipulator::mutable::FireworkEffectData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/Firework", self.object, "getFireworkData", "()Lorg/spongepowered/api/data/manipulator/mutable/FireworkEffectData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/Firework#getFireworkData was null") }
+    ::sponge::data::manipulator::mutable::FireworkEffectData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn effects(&self) -> ::sponge::data::value::mutable::ListValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/Firework", self.object, "effects", "()Lorg/spongepowered/api/data/value/mutable/ListValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/Firework#effects was null") }
+    ::sponge::data::value::mutable::ListValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FishHook {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FishHook {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FishHook {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FishHook`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/FishHook", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_hooked_entity(&self) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/FishHook", self.object, "getHookedEntity", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/FishHook#getHookedEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set_hooked_entity(&self, entity: ::sponge::entity::Entity) {
+    bound_method!(self.env, "org/spongepowered/api/entity/projectile/FishHook", self.object, "setHookedEntity", "(Lorg/spongepowered/api/entity/Entity;)V", CallVoidMethodA, entity.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Projectile {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Projectile {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Projectile {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Projectile`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/Projectile", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_shooter(&self) -> ::sponge::entity::projectile::source::ProjectileSource {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/Projectile", self.object, "getShooter", "()Lorg/spongepowered/api/entity/projectile/source/ProjectileSource;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/Projectile#getShooter was null") }
+    ::sponge::entity::projectile::source::ProjectileSource { env: self.env, object: ret }
+  }
+
+  pub fn set_shooter(&self, projectile_source: ::sponge::entity::projectile::source::ProjectileSource) {
+    bound_method!(self.env, "org/spongepowered/api/entity/projectile/Projectile", self.object, "setShooter", "(Lorg/spongepowered/api/entity/projectile/source/ProjectileSource;)V", CallVoidMethodA, projectile_source.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Snowball {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Snowball {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Snowball {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Snowball`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/Snowball", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ThrownExpBottle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ThrownExpBottle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ThrownExpBottle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ThrownExpBottle`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/ThrownExpBottle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ThrownPotion {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ThrownPotion {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ThrownPotion {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ThrownPotion`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/ThrownPotion", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_potion_item_data(&self) -> ::sponge::data::manipulator::mutable::RepresentedItemData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/ThrownPotion", self.object, "getPotionItemData", "()Lorg/spongepowered/api/data/manipulator/mutable/RepresentedItemData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/ThrownPotion#getPotionItemData was null") }
+    ::sponge::data::manipulator::mutable::RepresentedItemData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn item(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/ThrownPotion", self.object, "item", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/ThrownPotion#item was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,112 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BlockProjectileSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockProjectileSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockProjectileSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockProjectileSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/source/BlockProjectileSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/BlockProjectileSource", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/BlockProjectileSource#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/BlockProjectileSource", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/BlockProjectileSource#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ProjectileSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProjectileSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProjectileSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ProjectileSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/source/ProjectileSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn launch_projectile_class(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/ProjectileSource", self.object, "launchProjectile", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/ProjectileSource#launchProjectile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn launch_projectile_class_vector_3d(&self, class: jobject, vector_3d: ::math::Vector3d) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/ProjectileSource", self.object, "launchProjectile", "(Ljava/lang/Class;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Optional;", CallObjectMethodA, class, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/ProjectileSource#launchProjectile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnknownProjectileSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnknownProjectileSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnknownProjectileSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnknownProjectileSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/projectile/source/UnknownProjectileSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn launch_projectile_class(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/UnknownProjectileSource", self.object, "launchProjectile", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/UnknownProjectileSource#launchProjectile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn launch_projectile_class_vector_3d(&self, class: jobject, vector_3d: ::math::Vector3d) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/projectile/source/UnknownProjectileSource", self.object, "launchProjectile", "(Ljava/lang/Class;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Optional;", CallObjectMethodA, class, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/entity/projectile/source/UnknownProjectileSource#launchProjectile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
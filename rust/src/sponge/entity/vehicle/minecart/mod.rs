@@ -0,0 +1,281 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChestMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChestMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChestMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChestMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/ChestMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CommandBlockMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CommandBlockMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CommandBlockMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CommandBlockMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/CommandBlockMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ContainerMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ContainerMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ContainerMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ContainerMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/ContainerMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_inventory(&self) -> ::sponge::item::inventory::type_::CarriedInventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/ContainerMinecart", self.object, "getInventory", "()Lorg/spongepowered/api/item/inventory/type/CarriedInventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/vehicle/minecart/ContainerMinecart#getInventory was null") }
+    ::sponge::item::inventory::type_::CarriedInventory { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FurnaceMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FurnaceMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FurnaceMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FurnaceMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/FurnaceMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_fuel(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/FurnaceMinecart", self.object, "getFuel", "()I", CallIntMethod)
+  }
+
+  pub fn set_fuel(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/FurnaceMinecart", self.object, "setFuel", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HopperMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HopperMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HopperMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HopperMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/HopperMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_cooldown_data(&self) -> ::sponge::data::manipulator::mutable::tileentity::CooldownData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/HopperMinecart", self.object, "getCooldownData", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/CooldownData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/vehicle/minecart/HopperMinecart#getCooldownData was null") }
+    ::sponge::data::manipulator::mutable::tileentity::CooldownData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Minecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Minecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Minecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Minecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/Minecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_on_rail(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "isOnRail", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_swiftness(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "getSwiftness", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_swiftness(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "setSwiftness", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_potential_max_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "getPotentialMaxSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn does_slow_when_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "doesSlowWhenEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_slow_when_empty(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "setSlowWhenEmpty", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_airborne_velocity_mod(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "getAirborneVelocityMod", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/vehicle/minecart/Minecart#getAirborneVelocityMod was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_airborne_velocity_mod(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "setAirborneVelocityMod", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+  pub fn get_derailed_velocity_mod(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "getDerailedVelocityMod", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/vehicle/minecart/Minecart#getDerailedVelocityMod was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_derailed_velocity_mod(&self, vector_3d: ::math::Vector3d) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/Minecart", self.object, "setDerailedVelocityMod", "(Lcom/flowpowered/math/vector/Vector3d;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MobSpawnerMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MobSpawnerMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MobSpawnerMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MobSpawnerMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/MobSpawnerMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_spawner_data(&self) -> ::sponge::data::manipulator::mutable::MobSpawnerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/vehicle/minecart/MobSpawnerMinecart", self.object, "getSpawnerData", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/vehicle/minecart/MobSpawnerMinecart#getSpawnerData was null") }
+    ::sponge::data::manipulator::mutable::MobSpawnerData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RideableMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RideableMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RideableMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RideableMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/RideableMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TNTMinecart {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TNTMinecart {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TNTMinecart {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TNTMinecart`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/minecart/TNTMinecart", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
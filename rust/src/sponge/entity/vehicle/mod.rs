@@ -0,0 +1,62 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod minecart;
+
+#[derive(Debug)]
+pub struct Boat {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Boat {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Boat {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Boat`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/vehicle/Boat", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_in_water(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "isInWater", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_max_speed(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "getMaxSpeed", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_max_speed(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "setMaxSpeed", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn can_move_on_land(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "canMoveOnLand", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_move_on_land(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "setMoveOnLand", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_occupied_deceleration(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "getOccupiedDeceleration", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_occupied_deceleration(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "setOccupiedDeceleration", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_unoccupied_deceleration(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "getUnoccupiedDeceleration", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_unoccupied_deceleration(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/entity/vehicle/Boat", self.object, "setUnoccupiedDeceleration", "(D)V", CallVoidMethodA, value);
+  }
+
+}
@@ -0,0 +1,69 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Lightning {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Lightning {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Lightning {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Lightning`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/weather/Lightning", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeatherEffect {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeatherEffect {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeatherEffect {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WeatherEffect`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/entity/weather/WeatherEffect", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_effect(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/entity/weather/WeatherEffect", self.object, "isEffect", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_effect(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/entity/weather/WeatherEffect", self.object, "setEffect", "(Z)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_expiring_data(&self) -> ::sponge::data::manipulator::mutable::entity::ExpirableData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/weather/WeatherEffect", self.object, "getExpiringData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/ExpirableData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/weather/WeatherEffect#getExpiringData was null") }
+    ::sponge::data::manipulator::mutable::entity::ExpirableData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn expire_ticks(&self) -> ::sponge::data::value::mutable::MutableBoundedValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/entity/weather/WeatherEffect", self.object, "expireTicks", "()Lorg/spongepowered/api/data/value/mutable/MutableBoundedValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/entity/weather/WeatherEffect#expireTicks was null") }
+    ::sponge::data::value::mutable::MutableBoundedValue { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,58 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct GrantAchievementEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GrantAchievementEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GrantAchievementEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GrantAchievementEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/achievement/GrantAchievementEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "statistic")]
+  pub fn get_achievement(&self) -> ::sponge::statistic::achievement::Achievement {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/achievement/GrantAchievementEvent", self.object, "getAchievement", "()Lorg/spongepowered/api/statistic/achievement/Achievement;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/achievement/GrantAchievementEvent#getAchievement was null") }
+    ::sponge::statistic::achievement::Achievement { env: self.env, object: ret }
+  }
+
+}
+
+pub mod grant_achievement_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/achievement/GrantAchievementEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,443 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CollideEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollideEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollideEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CollideEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/CollideEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FishingEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FishingEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FishingEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FishingEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/FishingEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_original_fish_hook(&self) -> ::sponge::entity::EntitySnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/action/FishingEvent", self.object, "getOriginalFishHook", "()Lorg/spongepowered/api/entity/EntitySnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/action/FishingEvent#getOriginalFishHook was null") }
+    ::sponge::entity::EntitySnapshot { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_fish_hook(&self) -> ::sponge::entity::projectile::FishHook {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/action/FishingEvent", self.object, "getFishHook", "()Lorg/spongepowered/api/entity/projectile/FishHook;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/action/FishingEvent#getFishHook was null") }
+    ::sponge::entity::projectile::FishHook { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InteractEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InteractEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InteractEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InteractEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/InteractEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_interaction_point(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/action/InteractEvent", self.object, "getInteractionPoint", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/action/InteractEvent#getInteractionPoint was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LightningEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LightningEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LightningEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LightningEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/LightningEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SleepingEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SleepingEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SleepingEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SleepingEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/SleepingEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_bed(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/action/SleepingEvent", self.object, "getBed", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/action/SleepingEvent#getBed was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+pub mod collide_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Impact {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Impact {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Impact {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Impact`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/CollideEvent$Impact", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn get_impact_point(&self) -> ::sponge::world::Location {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/action/CollideEvent$Impact", self.object, "getImpactPoint", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/action/CollideEvent$Impact#getImpactPoint was null") }
+      ::sponge::world::Location { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod fishing_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct HookEntity {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl HookEntity {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      HookEntity {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `HookEntity`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/FishingEvent$HookEntity", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Start {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Start {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Start {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Start`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/FishingEvent$Start", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Stop {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Stop {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Stop {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Stop`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/FishingEvent$Stop", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_item_stack_transaction(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/action/FishingEvent$Stop", self.object, "getItemStackTransaction", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/action/FishingEvent$Stop#getItemStackTransaction was null") }
+      ret
+    }
+
+  }
+}
+
+pub mod lightning_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/LightningEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/LightningEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Strike {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Strike {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Strike {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Strike`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/LightningEvent$Strike", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod sleeping_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Finish {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Finish {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Finish {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Finish`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/SleepingEvent$Finish", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/SleepingEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn is_spawn_set(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/event/action/SleepingEvent$Post", self.object, "isSpawnSet", "()Z", CallBooleanMethod) != 0
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_spawn_transform(&self) -> Option<::sponge::entity::Transform> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/action/SleepingEvent$Post", self.object, "getSpawnTransform", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/action/SleepingEvent$Post#getSpawnTransform was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::entity::Transform { env: self.env, object: unwrapped }) }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn set_spawn_transform(&self, transform: ::sponge::entity::Transform) {
+      bound_method!(self.env, "org/spongepowered/api/event/action/SleepingEvent$Post", self.object, "setSpawnTransform", "(Lorg/spongepowered/api/entity/Transform;)V", CallVoidMethodA, transform.object);
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/SleepingEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Tick {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Tick {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Tick {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Tick`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/action/SleepingEvent$Tick", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,644 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod tileentity;
+
+#[derive(Debug)]
+pub struct ChangeBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_transactions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/ChangeBlockEvent", self.object, "getTransactions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/ChangeBlockEvent#getTransactions was null") }
+    ret
+  }
+
+  pub fn filter(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/ChangeBlockEvent", self.object, "filter", "(Ljava/util/function/Predicate;)Ljava/util/List;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/ChangeBlockEvent#filter was null") }
+    ret
+  }
+
+  pub fn filter_all(&self) {
+    bound_method!(self.env, "org/spongepowered/api/event/block/ChangeBlockEvent", self.object, "filterAll", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CollideBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollideBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollideBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CollideBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/CollideBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_target_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/CollideBlockEvent", self.object, "getTargetLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/CollideBlockEvent#getTargetLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/CollideBlockEvent", self.object, "getTargetBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/CollideBlockEvent#getTargetBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_target_side(&self) -> ::sponge::util::Direction {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/CollideBlockEvent", self.object, "getTargetSide", "()Lorg/spongepowered/api/util/Direction;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/CollideBlockEvent#getTargetSide was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InteractBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InteractBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InteractBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InteractBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_target_side(&self) -> ::sponge::util::Direction {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent", self.object, "getTargetSide", "()Lorg/spongepowered/api/util/Direction;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/InteractBlockEvent#getTargetSide was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NotifyNeighborBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NotifyNeighborBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NotifyNeighborBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NotifyNeighborBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/NotifyNeighborBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_neighbors(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/NotifyNeighborBlockEvent", self.object, "getOriginalNeighbors", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/NotifyNeighborBlockEvent#getOriginalNeighbors was null") }
+    ret
+  }
+
+  pub fn get_neighbors(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/NotifyNeighborBlockEvent", self.object, "getNeighbors", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/NotifyNeighborBlockEvent#getNeighbors was null") }
+    ret
+  }
+
+  pub fn filter_directions(&self, predicate: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/block/NotifyNeighborBlockEvent", self.object, "filterDirections", "(Ljava/util/function/Predicate;)V", CallVoidMethodA, predicate);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/TargetBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_block(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/TargetBlockEvent", self.object, "getTargetBlock", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/TargetBlockEvent#getTargetBlock was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TickBlockEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TickBlockEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TickBlockEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TickBlockEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/TickBlockEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod change_block_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Break {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Break {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Break {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Break`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Break", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Decay {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Decay {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Decay {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Decay`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Decay", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Grow {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Grow {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Grow {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Grow`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Grow", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Modify {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Modify {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Modify {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Modify`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Modify", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Place {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Place {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Place {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Place`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Place", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/ChangeBlockEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_locations(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/ChangeBlockEvent$Pre", self.object, "getLocations", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/ChangeBlockEvent$Pre#getLocations was null") }
+      ret
+    }
+
+  }
+}
+
+pub mod collide_block_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Impact {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Impact {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Impact {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Impact`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/CollideBlockEvent$Impact", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod interact_block_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Primary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Primary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Primary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Primary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Secondary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Secondary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Secondary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn get_original_use_item_result(&self) -> ::sponge::util::Tristate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "getOriginalUseItemResult", "()Lorg/spongepowered/api/util/Tristate;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/InteractBlockEvent$Secondary#getOriginalUseItemResult was null") }
+      <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn get_original_use_block_result(&self) -> ::sponge::util::Tristate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "getOriginalUseBlockResult", "()Lorg/spongepowered/api/util/Tristate;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/InteractBlockEvent$Secondary#getOriginalUseBlockResult was null") }
+      <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn get_use_item_result(&self) -> ::sponge::util::Tristate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "getUseItemResult", "()Lorg/spongepowered/api/util/Tristate;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/InteractBlockEvent$Secondary#getUseItemResult was null") }
+      <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn get_use_block_result(&self) -> ::sponge::util::Tristate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "getUseBlockResult", "()Lorg/spongepowered/api/util/Tristate;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/InteractBlockEvent$Secondary#getUseBlockResult was null") }
+      <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn set_use_item_result(&self, tristate: ::sponge::util::Tristate) {
+      bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "setUseItemResult", "(Lorg/spongepowered/api/util/Tristate;)V", CallVoidMethodA, ::convert::ToJava::to_java(&tristate, self.env));
+    }
+
+    #[cfg(feature = "util")]
+    pub fn set_use_block_result(&self, tristate: ::sponge::util::Tristate) {
+      bound_method!(self.env, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary", self.object, "setUseBlockResult", "(Lorg/spongepowered/api/util/Tristate;)V", CallVoidMethodA, ::convert::ToJava::to_java(&tristate, self.env));
+    }
+
+  }
+
+  pub mod primary {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct MainHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl MainHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        MainHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `MainHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Primary$MainHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct OffHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl OffHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        OffHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `OffHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Primary$OffHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+
+  pub mod secondary {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct MainHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl MainHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        MainHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `MainHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary$MainHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct OffHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl OffHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        OffHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `OffHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/InteractBlockEvent$Secondary$OffHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+}
+
+pub mod tick_block_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Random {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Random {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Random {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Random`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/TickBlockEvent$Random", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Scheduled {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Scheduled {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Scheduled {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Scheduled`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/TickBlockEvent$Scheduled", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,388 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BrewingEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BrewingEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BrewingEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BrewingEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/BrewingEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_tile(&self) -> ::sponge::block::tileentity::carrier::BrewingStand {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/BrewingEvent", self.object, "getTargetTile", "()Lorg/spongepowered/api/block/tileentity/carrier/BrewingStand;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/BrewingEvent#getTargetTile was null") }
+    ::sponge::block::tileentity::carrier::BrewingStand { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_ingredient(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/BrewingEvent", self.object, "getIngredient", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/BrewingEvent#getIngredient was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeSignEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeSignEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeSignEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeSignEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/ChangeSignEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_tile(&self) -> ::sponge::block::tileentity::Sign {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/ChangeSignEvent", self.object, "getTargetTile", "()Lorg/spongepowered/api/block/tileentity/Sign;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/ChangeSignEvent#getTargetTile was null") }
+    ::sponge::block::tileentity::Sign { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_original_text(&self) -> ::sponge::data::manipulator::immutable::tileentity::ImmutableSignData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/ChangeSignEvent", self.object, "getOriginalText", "()Lorg/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableSignData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/ChangeSignEvent#getOriginalText was null") }
+    ::sponge::data::manipulator::immutable::tileentity::ImmutableSignData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_text(&self) -> ::sponge::data::manipulator::mutable::tileentity::SignData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/ChangeSignEvent", self.object, "getText", "()Lorg/spongepowered/api/data/manipulator/mutable/tileentity/SignData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/ChangeSignEvent#getText was null") }
+    ::sponge::data::manipulator::mutable::tileentity::SignData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SmeltEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SmeltEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SmeltEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SmeltEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_tile(&self) -> ::sponge::block::tileentity::carrier::Furnace {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/SmeltEvent", self.object, "getTargetTile", "()Lorg/spongepowered/api/block/tileentity/carrier/Furnace;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/SmeltEvent#getTargetTile was null") }
+    ::sponge::block::tileentity::carrier::Furnace { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_fuel(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/SmeltEvent", self.object, "getFuel", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/SmeltEvent#getFuel was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetTileEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetTileEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetTileEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetTileEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/TargetTileEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_target_tile(&self) -> ::sponge::block::tileentity::TileEntity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/TargetTileEntityEvent", self.object, "getTargetTile", "()Lorg/spongepowered/api/block/tileentity/TileEntity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/TargetTileEntityEvent#getTargetTile was null") }
+    ::sponge::block::tileentity::TileEntity { env: self.env, object: ret }
+  }
+
+}
+
+pub mod brewing_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Finish {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Finish {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Finish {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Finish`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Finish", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_brewed_item_stacks(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Finish", self.object, "getBrewedItemStacks", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/BrewingEvent$Finish#getBrewedItemStacks was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Interrupt {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Interrupt {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Interrupt {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Interrupt`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Interrupt", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_brewed_item_stacks(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Interrupt", self.object, "getBrewedItemStacks", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/BrewingEvent$Interrupt#getBrewedItemStacks was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Start {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Start {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Start {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Start`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Start", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Tick {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Tick {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Tick {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Tick`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/BrewingEvent$Tick", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod smelt_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ConsumeFuel {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ConsumeFuel {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ConsumeFuel {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConsumeFuel`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent$ConsumeFuel", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Finish {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Finish {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Finish {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Finish`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Finish", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_smelted_items(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Finish", self.object, "getSmeltedItems", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/SmeltEvent$Finish#getSmeltedItems was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Interrupt {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Interrupt {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Interrupt {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Interrupt`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Interrupt", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_smelted_items(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Interrupt", self.object, "getSmeltedItems", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/block/tileentity/SmeltEvent$Interrupt#getSmeltedItems was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Start {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Start {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Start {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Start`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Start", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Tick {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Tick {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Tick {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Tick`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/block/tileentity/SmeltEvent$Tick", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,253 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod source;
+
+#[derive(Debug)]
+pub struct DamageModifier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageModifier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageModifier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageModifier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageModifier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::damage_modifier::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier#builder was null") }
+    ::sponge::event::cause::entity::damage::damage_modifier::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageModifierType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageModifierType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier#getType was null") }
+    ::sponge::event::cause::entity::damage::DamageModifierType { env: self.env, object: ret }
+  }
+
+  pub fn get_cause(&self) -> ::sponge::event::cause::Cause {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier", self.object, "getCause", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier#getCause was null") }
+    ::sponge::event::cause::Cause { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageModifierType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageModifierType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageModifierType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageModifierType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageModifierType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageModifierTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageModifierTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageModifierTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageModifierTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageModifierTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod damage_modifier {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, damage_modifier_type: ::sponge::event::cause::entity::damage::DamageModifierType) -> ::sponge::event::cause::entity::damage::damage_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifierType;)Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder;", CallObjectMethodA, damage_modifier_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#type was null") }
+      ::sponge::event::cause::entity::damage::damage_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn cause(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::event::cause::entity::damage::damage_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "cause", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder;", CallObjectMethodA, cause.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#cause was null") }
+      ::sponge::event::cause::entity::damage::damage_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::damage::DamageModifier {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#build was null") }
+      ::sponge::event::cause::entity::damage::DamageModifier { env: self.env, object: ret }
+    }
+
+    pub fn from_damage_modifier(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> ::sponge::event::cause::entity::damage::damage_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder;", CallObjectMethodA, damage_modifier.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#from was null") }
+      ::sponge::event::cause::entity::damage::damage_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::event::cause::entity::damage::damage_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#reset was null") }
+      ::sponge::event::cause::entity::damage::damage_modifier::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  pub mod builder {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct ImplementedDamageModifier {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl ImplementedDamageModifier {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        ImplementedDamageModifier {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImplementedDamageModifier`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageModifierType {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageModifierType;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier#getType was null") }
+        ::sponge::event::cause::entity::damage::DamageModifierType { env: self.env, object: ret }
+      }
+
+      pub fn get_cause(&self) -> ::sponge::event::cause::Cause {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", self.object, "getCause", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier#getCause was null") }
+        ::sponge::event::cause::Cause { env: self.env, object: ret }
+      }
+
+      pub fn hash_code(&self) -> i32 {
+        bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", self.object, "hashCode", "()I", CallIntMethod)
+      }
+
+      pub fn equals(&self, object: jobject) -> bool {
+        bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+      }
+
+      pub fn to_string(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/DamageModifier$Builder$ImplementedDamageModifier#toString was null") }
+        ret
+      }
+
+    }
+  }
+}
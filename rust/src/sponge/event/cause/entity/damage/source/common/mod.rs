@@ -0,0 +1,352 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource#getType was null") }
+    ::sponge::event::cause::entity::damage::DamageType { env: self.env, object: ret }
+  }
+
+  pub fn is_absolute(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "isAbsolute", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_bypassing_armor(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "isBypassingArmor", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_scaled_by_difficulty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "isScaledByDifficulty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_explosive(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "isExplosive", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_affect_creative(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSource", self.object, "doesAffectCreative", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractDamageSourceBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractDamageSourceBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractDamageSourceBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractDamageSourceBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn scales_with_difficulty(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "scalesWithDifficulty", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#scalesWithDifficulty was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn bypasses_armor(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "bypassesArmor", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#bypassesArmor was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn explosion(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "explosion", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#explosion was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn absolute(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "absolute", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#absolute was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn magical(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "magical", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#magical was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn creative(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "creative", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#creative was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn type_(&self, damage_type: ::sponge::event::cause::entity::damage::DamageType) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageType;)Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethodA, damage_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#type was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_damage_source(&self, damage_source: ::sponge::event::cause::entity::damage::source::DamageSource) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource;)Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethodA, damage_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#from was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#reset was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractDamageSourceBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEntityDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntityDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntityDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource#getSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource#getType was null") }
+    ::sponge::event::cause::entity::damage::DamageType { env: self.env, object: ret }
+  }
+
+  pub fn is_absolute(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "isAbsolute", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_bypassing_armor(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "isBypassingArmor", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_scaled_by_difficulty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "isScaledByDifficulty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_explosive(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "isExplosive", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_affect_creative(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource", self.object, "doesAffectCreative", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractIndirectEntityDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractIndirectEntityDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractIndirectEntityDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractIndirectEntityDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource#getSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource#getType was null") }
+    ::sponge::event::cause::entity::damage::DamageType { env: self.env, object: ret }
+  }
+
+  pub fn is_absolute(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "isAbsolute", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_bypassing_armor(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "isBypassingArmor", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_scaled_by_difficulty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "isScaledByDifficulty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_explosive(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "isExplosive", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_affect_creative(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "doesAffectCreative", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_indirect_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource", self.object, "getIndirectSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource#getIndirectSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+pub mod abstract_entity_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct AbstractEntityDamageSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl AbstractEntityDamageSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      AbstractEntityDamageSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityDamageSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource$AbstractEntityDamageSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource$AbstractEntityDamageSourceBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource$AbstractEntityDamageSourceBuilder#entity was null") }
+      ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource$AbstractEntityDamageSourceBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractEntityDamageSource$AbstractEntityDamageSourceBuilder#reset was null") }
+      ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod abstract_indirect_entity_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct AbstractIndirectEntityDamageSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl AbstractIndirectEntityDamageSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      AbstractIndirectEntityDamageSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractIndirectEntityDamageSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder#entity was null") }
+      ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn proxy_source(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder", self.object, "proxySource", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder#proxySource was null") }
+      ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/common/AbstractIndirectEntityDamageSource$AbstractIndirectEntityDamageSourceBuilder#reset was null") }
+      ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
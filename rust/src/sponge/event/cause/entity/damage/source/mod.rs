@@ -0,0 +1,530 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod common;
+
+#[derive(Debug)]
+pub struct BlockDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::source::block_damage_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource#builder was null") }
+    ::sponge::event::cause::entity::damage::source::block_damage_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_snapshot(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource", self.object, "getBlockSnapshot", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource#getBlockSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::source::damage_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource#builder was null") }
+    ::sponge::event::cause::entity::damage::source::damage_source::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::damage::DamageType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/damage/DamageType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource#getType was null") }
+    ::sponge::event::cause::entity::damage::DamageType { env: self.env, object: ret }
+  }
+
+  pub fn is_absolute(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "isAbsolute", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_bypassing_armor(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "isBypassingArmor", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_scaled_by_difficulty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "isScaledByDifficulty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_explosive(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "isExplosive", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_affect_creative(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource", self.object, "doesAffectCreative", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageSources {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageSources {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageSources {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageSources`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/DamageSources", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::source::entity_damage_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource#builder was null") }
+    ::sponge::event::cause::entity::damage::source::entity_damage_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource#getSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FallingBlockDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FallingBlockDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FallingBlockDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FallingBlockDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::source::falling_block_damage_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource#builder was null") }
+    ::sponge::event::cause::entity::damage::source::falling_block_damage_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::FallingBlock {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/FallingBlock;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource#getSource was null") }
+    ::sponge::entity::FallingBlock { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_falling_block_data(&self) -> ::sponge::data::manipulator::immutable::entity::ImmutableFallingBlockData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource", self.object, "getFallingBlockData", "()Lorg/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource#getFallingBlockData was null") }
+    ::sponge::data::manipulator::immutable::entity::ImmutableFallingBlockData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IndirectEntityDamageSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IndirectEntityDamageSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IndirectEntityDamageSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IndirectEntityDamageSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource#builder was null") }
+    ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_indirect_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource", self.object, "getIndirectSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource#getIndirectSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+pub mod block_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn block_location(&self, location: ::sponge::world::Location) -> ::sponge::event::cause::entity::damage::source::block_damage_source::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder", self.object, "block", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder;", CallObjectMethodA, location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder#block was null") }
+      ::sponge::event::cause::entity::damage::source::block_damage_source::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block_block_snapshot(&self, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::cause::entity::damage::source::block_damage_source::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder;", CallObjectMethodA, block_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/BlockDamageSource$Builder#block was null") }
+      ::sponge::event::cause::entity::damage::source::block_damage_source::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct DamageSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl DamageSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      DamageSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn scales_with_difficulty(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "scalesWithDifficulty", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#scalesWithDifficulty was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn bypasses_armor(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "bypassesArmor", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#bypassesArmor was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn explosion(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "explosion", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#explosion was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn absolute(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "absolute", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#absolute was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn magical(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "magical", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#magical was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn creative(&self) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "creative", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#creative was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, damage_type: ::sponge::event::cause::entity::damage::DamageType) -> ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageType;)Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder;", CallObjectMethodA, damage_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#type was null") }
+      ::sponge::event::cause::entity::damage::source::damage_source::DamageSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::damage::source::DamageSource {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/damage/source/DamageSource;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/DamageSource$DamageSourceBuilder#build was null") }
+      ::sponge::event::cause::entity::damage::source::DamageSource { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod entity_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntityDamageSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityDamageSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityDamageSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityDamageSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/EntityDamageSource$EntityDamageSourceBuilder#entity was null") }
+      ::sponge::event::cause::entity::damage::source::entity_damage_source::EntityDamageSourceBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod falling_block_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn falling_block(&self, immutable_falling_block_data: ::sponge::data::manipulator::immutable::entity::ImmutableFallingBlockData) -> ::sponge::event::cause::entity::damage::source::falling_block_damage_source::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource$Builder", self.object, "fallingBlock", "(Lorg/spongepowered/api/data/manipulator/immutable/entity/ImmutableFallingBlockData;)Lorg/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource$Builder;", CallObjectMethodA, immutable_falling_block_data.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/FallingBlockDamageSource$Builder#fallingBlock was null") }
+      ::sponge::event::cause::entity::damage::source::falling_block_damage_source::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod indirect_entity_damage_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct AbstractBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl AbstractBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      AbstractBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn proxy_source(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder", self.object, "proxySource", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$AbstractBuilder#proxySource was null") }
+      ::sponge::event::cause::entity::damage::source::indirect_entity_damage_source::AbstractBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/damage/source/IndirectEntityDamageSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,47 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DismountType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DismountType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DismountType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DismountType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/dismount/DismountType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DismountTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DismountTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DismountTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DismountTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/dismount/DismountTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
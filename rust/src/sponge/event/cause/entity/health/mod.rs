@@ -0,0 +1,259 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod source;
+
+#[derive(Debug)]
+pub struct HealingType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealingType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealingType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealingType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealingType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealingTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealingTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealingTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealingTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealingTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealthModifier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealthModifier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealthModifier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealthModifier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealthModifier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/HealthModifier", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier#builder was null") }
+    ::sponge::event::cause::entity::health::health_modifier::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::health::HealthModifierType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifierType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier#getType was null") }
+    ::sponge::event::cause::entity::health::HealthModifierType { env: self.env, object: ret }
+  }
+
+  pub fn get_cause(&self) -> ::sponge::event::cause::Cause {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier", self.object, "getCause", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier#getCause was null") }
+    ::sponge::event::cause::Cause { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealthModifierType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealthModifierType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealthModifierType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealthModifierType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealthModifierType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealthModifierTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealthModifierTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealthModifierTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealthModifierTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealthModifierTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod health_modifier {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+      let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallStaticObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#builder was null") }
+      ::sponge::event::cause::entity::health::health_modifier::Builder { env: env, object: ret }
+    }
+
+    pub fn type_(&self, health_modifier_type: ::sponge::event::cause::entity::health::HealthModifierType) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifierType;)Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallObjectMethodA, health_modifier_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#type was null") }
+      ::sponge::event::cause::entity::health::health_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn cause(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "cause", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallObjectMethodA, cause.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#cause was null") }
+      ::sponge::event::cause::entity::health::health_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::health::HealthModifier {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#build was null") }
+      ::sponge::event::cause::entity::health::HealthModifier { env: self.env, object: ret }
+    }
+
+    pub fn from_health_modifier(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallObjectMethodA, health_modifier.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#from was null") }
+      ::sponge::event::cause::entity::health::health_modifier::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::event::cause::entity::health::health_modifier::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifier$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#reset was null") }
+      ::sponge::event::cause::entity::health::health_modifier::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  pub mod builder {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct ImplementedHealthModifier {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl ImplementedHealthModifier {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        ImplementedHealthModifier {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImplementedHealthModifier`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn get_type(&self) -> ::sponge::event::cause::entity::health::HealthModifierType {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/health/HealthModifierType;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier#getType was null") }
+        ::sponge::event::cause::entity::health::HealthModifierType { env: self.env, object: ret }
+      }
+
+      pub fn get_cause(&self) -> ::sponge::event::cause::Cause {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", self.object, "getCause", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier#getCause was null") }
+        ::sponge::event::cause::Cause { env: self.env, object: ret }
+      }
+
+      pub fn hash_code(&self) -> i32 {
+        bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", self.object, "hashCode", "()I", CallIntMethod)
+      }
+
+      pub fn equals(&self, object: jobject) -> bool {
+        bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+      }
+
+      pub fn to_string(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/HealthModifier$Builder$ImplementedHealthModifier#toString was null") }
+        ret
+      }
+
+    }
+  }
+}
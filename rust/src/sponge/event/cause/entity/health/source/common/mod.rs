@@ -0,0 +1,206 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractEntityHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntityHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntityHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSource#getSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEntityHealingSourceBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntityHealingSourceBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntityHealingSourceBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityHealingSourceBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn from_entity_healing_source(&self, entity_healing_source: ::sponge::event::cause::entity::health::source::EntityHealingSource) -> ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource;)Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder;", CallObjectMethodA, entity_healing_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder#from was null") }
+    ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder#reset was null") }
+    ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder#entity was null") }
+    ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_healing_source(&self, healing_source: ::sponge::event::cause::entity::health::source::HealingSource) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource;)Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethodA, healing_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder#from was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractEntityHealingSourceBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_healing_type(&self) -> ::sponge::event::cause::entity::health::HealingType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSource", self.object, "getHealingType", "()Lorg/spongepowered/api/event/cause/entity/health/HealingType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSource#getHealingType was null") }
+    ::sponge::event::cause::entity::health::HealingType { env: self.env, object: ret }
+  }
+
+  pub fn is_difficulty_scaled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSource", self.object, "isDifficultyScaled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractHealingSourceBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractHealingSourceBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractHealingSourceBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractHealingSourceBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn scales_with_difficulty(&self) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "scalesWithDifficulty", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#scalesWithDifficulty was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn magical(&self) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "magical", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#magical was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn type_(&self, healing_type: ::sponge::event::cause::entity::health::HealingType) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/health/HealingType;)Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethodA, healing_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#type was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_healing_source(&self, healing_source: ::sponge::event::cause::entity::health::source::HealingSource) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource;)Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethodA, healing_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#from was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#reset was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/common/AbstractHealingSourceBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractIndirectEntityHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractIndirectEntityHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractIndirectEntityHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractIndirectEntityHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/common/AbstractIndirectEntityHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
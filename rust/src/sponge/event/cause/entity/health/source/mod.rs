@@ -0,0 +1,413 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod common;
+
+#[derive(Debug)]
+pub struct BlockHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::source::block_healing_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource#builder was null") }
+    ::sponge::event::cause::entity::health::source::block_healing_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource#getBlock was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::source::entity_healing_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource#builder was null") }
+    ::sponge::event::cause::entity::health::source::entity_healing_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource", self.object, "getSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource#getSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/HealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::source::healing_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource#builder was null") }
+    ::sponge::event::cause::entity::health::source::healing_source::Builder { env: env, object: ret }
+  }
+
+  pub fn get_healing_type(&self) -> ::sponge::event::cause::entity::health::HealingType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource", self.object, "getHealingType", "()Lorg/spongepowered/api/event/cause/entity/health/HealingType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource#getHealingType was null") }
+    ::sponge::event::cause::entity::health::HealingType { env: self.env, object: ret }
+  }
+
+  pub fn is_difficulty_scaled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource", self.object, "isDifficultyScaled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_magic(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource", self.object, "isMagic", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealingSources {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealingSources {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealingSources {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealingSources`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/HealingSources", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IndirectEntityHealingSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IndirectEntityHealingSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IndirectEntityHealingSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IndirectEntityHealingSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::health::source::indirect_entity_healing_source::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource", "builder", "()Lorg/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource#builder was null") }
+    ::sponge::event::cause::entity::health::source::indirect_entity_healing_source::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_indirect_source(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource", self.object, "getIndirectSource", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource#getIndirectSource was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+pub mod block_healing_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn block_location(&self, location: ::sponge::world::Location) -> ::sponge::event::cause::entity::health::source::block_healing_source::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder", self.object, "block", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder;", CallObjectMethodA, location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder#block was null") }
+      ::sponge::event::cause::entity::health::source::block_healing_source::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block_block_snapshot(&self, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::cause::entity::health::source::block_healing_source::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder;", CallObjectMethodA, block_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/BlockHealingSource$Builder#block was null") }
+      ::sponge::event::cause::entity::health::source::block_healing_source::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod entity_healing_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntityHealingSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityHealingSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityHealingSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityHealingSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/EntityHealingSource$EntityHealingSourceBuilder#entity was null") }
+      ::sponge::event::cause::entity::health::source::entity_healing_source::EntityHealingSourceBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod healing_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct HealingSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl HealingSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      HealingSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealingSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn scales_with_difficulty(&self) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder", self.object, "scalesWithDifficulty", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder#scalesWithDifficulty was null") }
+      ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn magical(&self) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder", self.object, "magical", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder#magical was null") }
+      ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, healing_type: ::sponge::event::cause::entity::health::HealingType) -> ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/health/HealingType;)Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder;", CallObjectMethodA, healing_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder#type was null") }
+      ::sponge::event::cause::entity::health::source::healing_source::HealingSourceBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::health::source::HealingSource {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/health/source/HealingSource;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/HealingSource$HealingSourceBuilder#build was null") }
+      ::sponge::event::cause::entity::health::source::HealingSource { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod indirect_entity_healing_source {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct IndirectEntityHealingSourceBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl IndirectEntityHealingSourceBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      IndirectEntityHealingSourceBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `IndirectEntityHealingSourceBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$IndirectEntityHealingSourceBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn indirect_entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::health::source::indirect_entity_healing_source::IndirectEntityHealingSourceBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$IndirectEntityHealingSourceBuilder", self.object, "indirectEntity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$IndirectEntityHealingSourceBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/health/source/IndirectEntityHealingSource$IndirectEntityHealingSourceBuilder#indirectEntity was null") }
+      ::sponge::event::cause::entity::health::source::indirect_entity_healing_source::IndirectEntityHealingSourceBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
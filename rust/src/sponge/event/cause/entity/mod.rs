@@ -0,0 +1,7 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod damage;
+pub mod dismount;
+pub mod health;
+pub mod spawn;
+pub mod teleport;
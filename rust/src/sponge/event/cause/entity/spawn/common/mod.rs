@@ -0,0 +1,248 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractBlockSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractBlockSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractBlockSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractBlockSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_snapshot(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCause", self.object, "getBlockSnapshot", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCause#getBlockSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractBlockSpawnCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractBlockSpawnCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractBlockSpawnCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractBlockSpawnCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn block(&self, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", self.object, "block", "(Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder;", CallObjectMethodA, block_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder#block was null") }
+    ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_block_spawn_cause(&self, block_spawn_cause: ::sponge::event::cause::entity::spawn::BlockSpawnCause) -> ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause;)Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder;", CallObjectMethodA, block_spawn_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_spawn_cause(&self, spawn_cause: ::sponge::event::cause::entity::spawn::SpawnCause) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause;)Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethodA, spawn_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractBlockSpawnCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEntitySpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntitySpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntitySpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntitySpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_entity(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCause", self.object, "getEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCause#getEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEntitySpawnCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntitySpawnCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntitySpawnCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntitySpawnCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder#entity was null") }
+    ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_entity_spawn_cause(&self, entity_spawn_cause: ::sponge::event::cause::entity::spawn::EntitySpawnCause) -> ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause;)Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder;", CallObjectMethodA, entity_spawn_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_spawn_cause(&self, spawn_cause: ::sponge::event::cause::entity::spawn::SpawnCause) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause;)Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethodA, spawn_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractEntitySpawnCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::spawn::SpawnType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCause", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/spawn/SpawnType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCause#getType was null") }
+    ::sponge::event::cause::entity::spawn::SpawnType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSpawnCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSpawnCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSpawnCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSpawnCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_(&self, spawn_type: ::sponge::event::cause::entity::spawn::SpawnType) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/spawn/SpawnType;)Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethodA, spawn_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder#type was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_spawn_cause(&self, spawn_cause: ::sponge::event::cause::entity::spawn::SpawnCause) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause;)Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethodA, spawn_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/common/AbstractSpawnCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
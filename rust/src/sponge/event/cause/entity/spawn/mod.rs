@@ -0,0 +1,542 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod common;
+
+#[derive(Debug)]
+pub struct BlockSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::block_spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::block_spawn_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_snapshot(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause", self.object, "getBlockSnapshot", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause#getBlockSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BreedingSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BreedingSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BreedingSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BreedingSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::breeding_spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::breeding_spawn_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_mate(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause", self.object, "getMate", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause#getMate was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntitySpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntitySpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntitySpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntitySpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::entity_spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::entity_spawn_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_entity(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause", self.object, "getEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause#getEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MobSpawnerSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MobSpawnerSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MobSpawnerSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MobSpawnerSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::mob_spawner_spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::mob_spawner_spawn_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_mob_spawner_data(&self) -> ::sponge::data::manipulator::immutable::ImmutableMobSpawnerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause", self.object, "getMobSpawnerData", "()Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause#getMobSpawnerData was null") }
+    ::sponge::data::manipulator::immutable::ImmutableMobSpawnerData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/SpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::spawn_cause::Builder { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::event::cause::entity::spawn::SpawnType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause", self.object, "getType", "()Lorg/spongepowered/api/event/cause/entity/spawn/SpawnType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/SpawnCause#getType was null") }
+    ::sponge::event::cause::entity::spawn::SpawnType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpawnType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpawnType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpawnType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/SpawnType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpawnTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpawnTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpawnTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/SpawnTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeatherSpawnCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeatherSpawnCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeatherSpawnCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WeatherSpawnCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::spawn::weather_spawn_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause#builder was null") }
+    ::sponge::event::cause::entity::spawn::weather_spawn_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_weather(&self) -> ::sponge::world::weather::Weather {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause", self.object, "getWeather", "()Lorg/spongepowered/api/world/weather/Weather;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause#getWeather was null") }
+    ::sponge::world::weather::Weather { env: self.env, object: ret }
+  }
+
+}
+
+pub mod block_spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct BlockSpawnCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl BlockSpawnCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      BlockSpawnCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockSpawnCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder", self.object, "block", "(Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder;", CallObjectMethodA, block_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$BlockSpawnCauseBuilder#block was null") }
+      ::sponge::event::cause::entity::spawn::block_spawn_cause::BlockSpawnCauseBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/BlockSpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod breeding_spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn mate(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::spawn::breeding_spawn_cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause$Builder", self.object, "mate", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause$Builder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/BreedingSpawnCause$Builder#mate was null") }
+      ::sponge::event::cause::entity::spawn::breeding_spawn_cause::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod entity_spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntitySpawnCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntitySpawnCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntitySpawnCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntitySpawnCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/EntitySpawnCause$EntitySpawnCauseBuilder#entity was null") }
+      ::sponge::event::cause::entity::spawn::entity_spawn_cause::EntitySpawnCauseBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod mob_spawner_spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn spawner_data(&self, immutable_mob_spawner_data: ::sponge::data::manipulator::immutable::ImmutableMobSpawnerData) -> ::sponge::event::cause::entity::spawn::mob_spawner_spawn_cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause$Builder", self.object, "spawnerData", "(Lorg/spongepowered/api/data/manipulator/immutable/ImmutableMobSpawnerData;)Lorg/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause$Builder;", CallObjectMethodA, immutable_mob_spawner_data.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/MobSpawnerSpawnCause$Builder#spawnerData was null") }
+      ::sponge::event::cause::entity::spawn::mob_spawner_spawn_cause::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct SpawnCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl SpawnCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      SpawnCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, spawn_type: ::sponge::event::cause::entity::spawn::SpawnType) -> ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/spawn/SpawnType;)Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder;", CallObjectMethodA, spawn_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder#type was null") }
+      ::sponge::event::cause::entity::spawn::spawn_cause::SpawnCauseBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::spawn::SpawnCause {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/spawn/SpawnCause;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/SpawnCause$SpawnCauseBuilder#build was null") }
+      ::sponge::event::cause::entity::spawn::SpawnCause { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod weather_spawn_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn weather(&self, weather: ::sponge::world::weather::Weather) -> ::sponge::event::cause::entity::spawn::weather_spawn_cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause$Builder", self.object, "weather", "(Lorg/spongepowered/api/world/weather/Weather;)Lorg/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause$Builder;", CallObjectMethodA, weather.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/spawn/WeatherSpawnCause$Builder#weather was null") }
+      ::sponge::event::cause::entity::spawn::weather_spawn_cause::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
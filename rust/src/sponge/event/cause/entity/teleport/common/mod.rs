@@ -0,0 +1,248 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractEntityTeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntityTeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntityTeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityTeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_teleporter(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCause", self.object, "getTeleporter", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCause#getTeleporter was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEntityTeleportCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEntityTeleportCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEntityTeleportCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEntityTeleportCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder#entity was null") }
+    ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_entity_teleport_cause(&self, entity_teleport_cause: ::sponge::event::cause::entity::teleport::EntityTeleportCause) -> ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause;)Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder;", CallObjectMethodA, entity_teleport_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_teleport_cause(&self, teleport_cause: ::sponge::event::cause::entity::teleport::TeleportCause) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, teleport_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractEntityTeleportCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractPortalTeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractPortalTeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractPortalTeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractPortalTeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_teleporter(&self) -> ::sponge::world::PortalAgent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCause", self.object, "getTeleporter", "()Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCause#getTeleporter was null") }
+    ::sponge::world::PortalAgent { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractPortalTeleportCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractPortalTeleportCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractPortalTeleportCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractPortalTeleportCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn agent(&self, portal_agent: ::sponge::world::PortalAgent) -> ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", self.object, "agent", "(Lorg/spongepowered/api/world/PortalAgent;)Lorg/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder;", CallObjectMethodA, portal_agent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder#agent was null") }
+    ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_portal_teleport_cause(&self, portal_teleport_cause: ::sponge::event::cause::entity::teleport::PortalTeleportCause) -> ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause;)Lorg/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder;", CallObjectMethodA, portal_teleport_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::teleport::portal_teleport_cause::PortalTeleportCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_teleport_cause(&self, teleport_cause: ::sponge::event::cause::entity::teleport::TeleportCause) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, teleport_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractPortalTeleportCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractTeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractTeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractTeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractTeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_teleport_type(&self) -> ::sponge::event::cause::entity::teleport::TeleportType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCause", self.object, "getTeleportType", "()Lorg/spongepowered/api/event/cause/entity/teleport/TeleportType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCause#getTeleportType was null") }
+    ::sponge::event::cause::entity::teleport::TeleportType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractTeleportCauseBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractTeleportCauseBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractTeleportCauseBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractTeleportCauseBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_(&self, teleport_type: ::sponge::event::cause::entity::teleport::TeleportType) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/teleport/TeleportType;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, teleport_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder#type was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn from_teleport_cause(&self, teleport_cause: ::sponge::event::cause::entity::teleport::TeleportCause) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder", self.object, "from", "(Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, teleport_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder#from was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder#reset was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/common/AbstractTeleportCauseBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,332 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod common;
+
+#[derive(Debug)]
+pub struct EntityTeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityTeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityTeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityTeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::teleport::entity_teleport_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause#builder was null") }
+    ::sponge::event::cause::entity::teleport::entity_teleport_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_teleporter(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause", self.object, "getTeleporter", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause#getTeleporter was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortalTeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortalTeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortalTeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortalTeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::teleport::portal_teleport_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause#builder was null") }
+    ::sponge::event::cause::entity::teleport::portal_teleport_cause::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_teleporter(&self) -> ::sponge::world::PortalAgent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause", self.object, "getTeleporter", "()Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause#getTeleporter was null") }
+    ::sponge::world::PortalAgent { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeleportCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeleportCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeleportCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeleportCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::entity::teleport::teleport_cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause", "builder", "()Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/TeleportCause#builder was null") }
+    ::sponge::event::cause::entity::teleport::teleport_cause::Builder { env: env, object: ret }
+  }
+
+  pub fn get_teleport_type(&self) -> ::sponge::event::cause::entity::teleport::TeleportType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause", self.object, "getTeleportType", "()Lorg/spongepowered/api/event/cause/entity/teleport/TeleportType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/TeleportCause#getTeleportType was null") }
+    ::sponge::event::cause::entity::teleport::TeleportType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeleportType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeleportType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeleportType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeleportType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/TeleportType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeleportTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeleportTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeleportTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeleportTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/TeleportTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod entity_teleport_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntityTeleportCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityTeleportCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityTeleportCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityTeleportCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity: ::sponge::entity::Entity) -> ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder", self.object, "entity", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder;", CallObjectMethodA, entity.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/EntityTeleportCause$EntityTeleportCauseBuilder#entity was null") }
+      ::sponge::event::cause::entity::teleport::entity_teleport_cause::EntityTeleportCauseBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod portal_teleport_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PortalTeleportCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PortalTeleportCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PortalTeleportCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortalTeleportCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn agent(&self, portal_agent: ::sponge::world::PortalAgent) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder", self.object, "agent", "(Lorg/spongepowered/api/world/PortalAgent;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, portal_agent.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/PortalTeleportCause$PortalTeleportCauseBuilder#agent was null") }
+      ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod teleport_cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TeleporterCauseBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TeleporterCauseBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TeleporterCauseBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeleporterCauseBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, teleport_type: ::sponge::event::cause::entity::teleport::TeleportType) -> ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder", self.object, "type", "(Lorg/spongepowered/api/event/cause/entity/teleport/TeleportType;)Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder;", CallObjectMethodA, teleport_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder#type was null") }
+      ::sponge::event::cause::entity::teleport::teleport_cause::TeleporterCauseBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::entity::teleport::TeleportCause {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder", self.object, "build", "()Lorg/spongepowered/api/event/cause/entity/teleport/TeleportCause;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/entity/teleport/TeleportCause$TeleporterCauseBuilder#build was null") }
+      ::sponge::event::cause::entity::teleport::TeleportCause { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,361 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod entity;
+
+#[derive(Debug)]
+pub struct Cause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Cause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Cause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Cause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/Cause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::event::cause::cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/Cause", "builder", "()Lorg/spongepowered/api/event/cause/Cause$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#builder was null") }
+    ::sponge::event::cause::cause::Builder { env: env, object: ret }
+  }
+
+  pub fn source(env: *mut JNIEnv, object: jobject) -> ::sponge::event::cause::cause::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/Cause", "source", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#source was null") }
+    ::sponge::event::cause::cause::Builder { env: env, object: ret }
+  }
+
+  pub fn of_named_cause(env: *mut JNIEnv, named_cause: ::sponge::event::cause::NamedCause) -> ::sponge::event::cause::Cause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/Cause", "of", "(Lorg/spongepowered/api/event/cause/NamedCause;)Lorg/spongepowered/api/event/cause/Cause;", CallStaticObjectMethodA, named_cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#of was null") }
+    ::sponge::event::cause::Cause { env: env, object: ret }
+  }
+
+  pub fn of_named_cause_named_cause_array(env: *mut JNIEnv, named_cause: ::sponge::event::cause::NamedCause, named_causes: Vec<::sponge::event::cause::NamedCause>) -> ::sponge::event::cause::Cause {
+    unimplemented!();
+  }
+  pub fn of_iterable(env: *mut JNIEnv, iterable: jobject) -> ::sponge::event::cause::Cause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/Cause", "of", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/event/cause/Cause;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#of was null") }
+    ::sponge::event::cause::Cause { env: env, object: ret }
+  }
+
+  pub fn root(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "root", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#root was null") }
+    ret
+  }
+
+  pub fn first(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "first", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#first was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn last(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "last", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#last was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get(&self, string: jobject, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "get", "(Ljava/lang/String;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, string, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn before_class(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "before", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#before was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn before_string(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "before", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#before was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn after_class(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "after", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#after was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn after_string(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "after", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#after was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn contains_type(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "containsType", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_named(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "containsNamed", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn all_of(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "allOf", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#allOf was null") }
+    ret
+  }
+
+  pub fn none_of(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "noneOf", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#noneOf was null") }
+    ret
+  }
+
+  pub fn all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "all", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#all was null") }
+    ret
+  }
+
+  pub fn with_named_cause_named_cause_array(&self, named_cause: ::sponge::event::cause::NamedCause, named_causes: Vec<::sponge::event::cause::NamedCause>) -> ::sponge::event::cause::Cause {
+    unimplemented!();
+  }
+  pub fn with_iterable(&self, iterable: jobject) -> ::sponge::event::cause::Cause {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "with", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#with was null") }
+    ::sponge::event::cause::Cause { env: self.env, object: ret }
+  }
+
+  pub fn merge(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::event::cause::Cause {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "merge", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#merge was null") }
+    ::sponge::event::cause::Cause { env: self.env, object: ret }
+  }
+
+  pub fn get_named_causes(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "getNamedCauses", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#getNamedCauses was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NamedCause {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NamedCause {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NamedCause {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NamedCause`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/NamedCause", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn source(env: *mut JNIEnv, object: jobject) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "source", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#source was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  pub fn owner(env: *mut JNIEnv, object: jobject) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "owner", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#owner was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  pub fn notifier(env: *mut JNIEnv, object: jobject) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "notifier", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#notifier was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  pub fn simulated_object(env: *mut JNIEnv, object: jobject) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "simulated", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#simulated was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn simulated_player(env: *mut JNIEnv, player: ::sponge::entity::living::player::Player) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "simulated", "(Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#simulated was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn simulated_user(env: *mut JNIEnv, user: ::sponge::entity::living::player::User) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "simulated", "(Lorg/spongepowered/api/entity/living/player/User;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, user.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#simulated was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn simulated_game_profile(env: *mut JNIEnv, game_profile: ::sponge::profile::GameProfile) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "simulated", "(Lorg/spongepowered/api/profile/GameProfile;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#simulated was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, string: jobject, object: jobject) -> ::sponge::event::cause::NamedCause {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/cause/NamedCause", "of", "(Ljava/lang/String;Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/NamedCause;", CallStaticObjectMethodA, string, object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#of was null") }
+    ::sponge::event::cause::NamedCause { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/NamedCause", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#getName was null") }
+    ret
+  }
+
+  pub fn get_cause_object(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/NamedCause", self.object, "getCauseObject", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#getCauseObject was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/NamedCause", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/cause/NamedCause", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/NamedCause", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/cause/NamedCause#toString was null") }
+    ret
+  }
+
+}
+
+pub mod cause {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/cause/Cause$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn owner(&self, object: jobject) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "owner", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#owner was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn notifier(&self, object: jobject) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "notifier", "(Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#notifier was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn named_named_cause(&self, named_cause: ::sponge::event::cause::NamedCause) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "named", "(Lorg/spongepowered/api/event/cause/NamedCause;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, named_cause.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#named was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn named_string_object(&self, string: jobject, object: jobject) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "named", "(Ljava/lang/String;Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, string, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#named was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn suggest_named(&self, string: jobject, object: jobject) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "suggestNamed", "(Ljava/lang/String;Ljava/lang/Object;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, string, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#suggestNamed was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_all(&self, collection: jobject) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "addAll", "(Ljava/util/Collection;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#addAll was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::event::cause::Cause {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "build", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#build was null") }
+      ::sponge::event::cause::Cause { env: self.env, object: ret }
+    }
+
+    pub fn from_cause(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "from", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethodA, cause.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#from was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::event::cause::cause::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "reset", "()Lorg/spongepowered/api/event/cause/Cause$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#reset was null") }
+      ::sponge::event::cause::cause::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/cause/Cause$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/cause/Cause$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,159 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct SendCommandEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SendCommandEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SendCommandEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SendCommandEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/command/SendCommandEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_command(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "getCommand", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/SendCommandEvent#getCommand was null") }
+    ret
+  }
+
+  pub fn set_command(&self, string: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "setCommand", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+  }
+
+  pub fn get_arguments(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "getArguments", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/SendCommandEvent#getArguments was null") }
+    ret
+  }
+
+  pub fn set_arguments(&self, string: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "setArguments", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_result(&self) -> ::sponge::command::CommandResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "getResult", "()Lorg/spongepowered/api/command/CommandResult;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/SendCommandEvent#getResult was null") }
+    ::sponge::command::CommandResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn set_result(&self, command_result: ::sponge::command::CommandResult) {
+    bound_method!(self.env, "org/spongepowered/api/event/command/SendCommandEvent", self.object, "setResult", "(Lorg/spongepowered/api/command/CommandResult;)V", CallVoidMethodA, command_result.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TabCompleteEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TabCompleteEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TabCompleteEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TabCompleteEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/command/TabCompleteEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_raw_message(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/TabCompleteEvent", self.object, "getRawMessage", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/TabCompleteEvent#getRawMessage was null") }
+    ret
+  }
+
+  pub fn get_original_tab_completions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/TabCompleteEvent", self.object, "getOriginalTabCompletions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/TabCompleteEvent#getOriginalTabCompletions was null") }
+    ret
+  }
+
+  pub fn get_tab_completions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/command/TabCompleteEvent", self.object, "getTabCompletions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/command/TabCompleteEvent#getTabCompletions was null") }
+    ret
+  }
+
+}
+
+pub mod tab_complete_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Chat {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Chat {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Chat {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Chat`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/command/TabCompleteEvent$Chat", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Command {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Command {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Command {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Command`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/command/TabCompleteEvent$Command", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_command(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/command/TabCompleteEvent$Command", self.object, "getCommand", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/command/TabCompleteEvent$Command#getCommand was null") }
+      ret
+    }
+
+    pub fn get_arguments(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/command/TabCompleteEvent$Command", self.object, "getArguments", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/command/TabCompleteEvent$Command#getArguments was null") }
+      ret
+    }
+
+  }
+}
@@ -0,0 +1,79 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChangeDataHolderEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeDataHolderEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeDataHolderEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeDataHolderEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/data/ChangeDataHolderEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_target_holder(&self) -> ::sponge::data::DataHolder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/data/ChangeDataHolderEvent", self.object, "getTargetHolder", "()Lorg/spongepowered/api/data/DataHolder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/data/ChangeDataHolderEvent#getTargetHolder was null") }
+    ::sponge::data::DataHolder { env: self.env, object: ret }
+  }
+
+}
+
+pub mod change_data_holder_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ValueChange {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ValueChange {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ValueChange {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ValueChange`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn get_original_changes(&self) -> ::sponge::data::DataTransactionResult {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange", self.object, "getOriginalChanges", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange#getOriginalChanges was null") }
+      ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn propose_changes(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::event::data::change_data_holder_event::ValueChange {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange", self.object, "proposeChanges", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange;", CallObjectMethodA, data_transaction_result.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange#proposeChanges was null") }
+      ::sponge::event::data::change_data_holder_event::ValueChange { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn get_end_result(&self) -> ::sponge::data::DataTransactionResult {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange", self.object, "getEndResult", "()Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange#getEndResult was null") }
+      ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,31 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct EconomyTransactionEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EconomyTransactionEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EconomyTransactionEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EconomyTransactionEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/economy/EconomyTransactionEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "service")]
+  pub fn get_transaction_result(&self) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/economy/EconomyTransactionEvent", self.object, "getTransactionResult", "()Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/economy/EconomyTransactionEvent#getTransactionResult was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+}
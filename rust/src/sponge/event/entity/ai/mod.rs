@@ -0,0 +1,100 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AITaskEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AITaskEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AITaskEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AITaskEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ai/AITaskEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_goal(&self) -> ::sponge::entity::ai::Goal {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ai/AITaskEvent", self.object, "getGoal", "()Lorg/spongepowered/api/entity/ai/Goal;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ai/AITaskEvent#getGoal was null") }
+    ::sponge::entity::ai::Goal { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_task(&self) -> ::sponge::entity::ai::task::AITask {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ai/AITaskEvent", self.object, "getTask", "()Lorg/spongepowered/api/entity/ai/task/AITask;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ai/AITaskEvent#getTask was null") }
+    ::sponge::entity::ai::task::AITask { env: self.env, object: ret }
+  }
+
+  pub fn get_priority(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/ai/AITaskEvent", self.object, "getPriority", "()I", CallIntMethod)
+  }
+
+}
+
+pub mod a_i_task_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Add {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Add {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Add {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Add`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ai/AITaskEvent$Add", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_original_priority(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/ai/AITaskEvent$Add", self.object, "getOriginalPriority", "()I", CallIntMethod)
+    }
+
+    pub fn set_priority(&self, value: i32) {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/ai/AITaskEvent$Add", self.object, "setPriority", "(I)V", CallVoidMethodA, value);
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Remove {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Remove {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Remove {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Remove`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ai/AITaskEvent$Remove", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
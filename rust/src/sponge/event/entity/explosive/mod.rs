@@ -0,0 +1,244 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DefuseExplosiveEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DefuseExplosiveEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DefuseExplosiveEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DefuseExplosiveEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/DefuseExplosiveEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DetonateExplosiveEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DetonateExplosiveEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DetonateExplosiveEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DetonateExplosiveEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_original_explosion(&self) -> ::sponge::world::explosion::Explosion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent", self.object, "getOriginalExplosion", "()Lorg/spongepowered/api/world/explosion/Explosion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent#getOriginalExplosion was null") }
+    ::sponge::world::explosion::Explosion { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_explosion_builder(&self) -> ::sponge::world::explosion::explosion::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent", self.object, "getExplosionBuilder", "()Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent#getExplosionBuilder was null") }
+    ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PrimeExplosiveEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PrimeExplosiveEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PrimeExplosiveEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PrimeExplosiveEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/PrimeExplosiveEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetExplosiveEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetExplosiveEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetExplosiveEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetExplosiveEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/TargetExplosiveEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::explosive::Explosive {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/explosive/TargetExplosiveEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/explosive/Explosive;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/explosive/TargetExplosiveEvent#getTargetEntity was null") }
+    ::sponge::entity::explosive::Explosive { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetFusedExplosiveEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetFusedExplosiveEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetFusedExplosiveEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetFusedExplosiveEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/TargetFusedExplosiveEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::explosive::FusedExplosive {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/explosive/TargetFusedExplosiveEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/explosive/FusedExplosive;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/explosive/TargetFusedExplosiveEvent#getTargetEntity was null") }
+    ::sponge::entity::explosive::FusedExplosive { env: self.env, object: ret }
+  }
+
+}
+
+pub mod defuse_explosive_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/DefuseExplosiveEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/DefuseExplosiveEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod prime_explosive_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/PrimeExplosiveEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/explosive/PrimeExplosiveEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,61 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ItemMergeItemEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemMergeItemEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemMergeItemEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemMergeItemEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/item/ItemMergeItemEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_item_to_merge(&self) -> ::sponge::entity::Item {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/item/ItemMergeItemEvent", self.object, "getItemToMerge", "()Lorg/spongepowered/api/entity/Item;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/item/ItemMergeItemEvent#getItemToMerge was null") }
+    ::sponge::entity::Item { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetItemEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetItemEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetItemEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetItemEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/item/TargetItemEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::Item {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/item/TargetItemEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/Item;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/item/TargetItemEvent#getTargetEntity was null") }
+    ::sponge::entity::Item { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,164 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod player;
+
+#[derive(Debug)]
+pub struct ChangeGameModeEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeGameModeEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeGameModeEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeGameModeEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_original_game_mode(&self) -> ::sponge::entity::living::player::gamemode::GameMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent", self.object, "getOriginalGameMode", "()Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent#getOriginalGameMode was null") }
+    ::sponge::entity::living::player::gamemode::GameMode { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_game_mode(&self) -> ::sponge::entity::living::player::gamemode::GameMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent", self.object, "getGameMode", "()Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent#getGameMode was null") }
+    ::sponge::entity::living::player::gamemode::GameMode { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn set_game_mode(&self, game_mode: ::sponge::entity::living::player::gamemode::GameMode) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent", self.object, "setGameMode", "(Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;)V", CallVoidMethodA, game_mode.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeLevelEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeLevelEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeLevelEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeLevelEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_level(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent", self.object, "getOriginalLevel", "()I", CallIntMethod)
+  }
+
+  pub fn get_level(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent", self.object, "getLevel", "()I", CallIntMethod)
+  }
+
+  pub fn set_level(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent", self.object, "setLevel", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetHumanoidEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetHumanoidEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetHumanoidEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetHumanoidEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/TargetHumanoidEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::living::Humanoid {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/TargetHumanoidEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/living/Humanoid;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/TargetHumanoidEvent#getTargetEntity was null") }
+    ::sponge::entity::living::Humanoid { env: self.env, object: ret }
+  }
+
+}
+
+pub mod change_game_mode_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod change_level_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,239 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct KickPlayerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl KickPlayerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    KickPlayerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `KickPlayerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/player/KickPlayerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlayerChangeClientSettingsEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlayerChangeClientSettingsEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlayerChangeClientSettingsEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerChangeClientSettingsEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_locale(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", self.object, "getLocale", "()Ljava/util/Locale;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent#getLocale was null") }
+    ret
+  }
+
+  pub fn get_view_distance(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", self.object, "getViewDistance", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_chat_visibility(&self) -> ::sponge::text::chat::ChatVisibility {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", self.object, "getChatVisibility", "()Lorg/spongepowered/api/text/chat/ChatVisibility;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent#getChatVisibility was null") }
+    ::sponge::text::chat::ChatVisibility { env: self.env, object: ret }
+  }
+
+  pub fn is_chat_colors_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", self.object, "isChatColorsEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_displayed_skin_parts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent", self.object, "getDisplayedSkinParts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent#getDisplayedSkinParts was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ResourcePackStatusEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResourcePackStatusEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResourcePackStatusEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ResourcePackStatusEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_player(&self) -> ::sponge::entity::living::player::Player {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent", self.object, "getPlayer", "()Lorg/spongepowered/api/entity/living/player/Player;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent#getPlayer was null") }
+    ::sponge::entity::living::player::Player { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "resourcepack")]
+  pub fn get_pack(&self) -> ::sponge::resourcepack::ResourcePack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent", self.object, "getPack", "()Lorg/spongepowered/api/resourcepack/ResourcePack;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent#getPack was null") }
+    ::sponge::resourcepack::ResourcePack { env: self.env, object: ret }
+  }
+
+  pub fn get_status(&self) -> ::sponge::event::entity::living::humanoid::player::resource_pack_status_event::ResourcePackStatus {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent", self.object, "getStatus", "()Lorg/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent$ResourcePackStatus;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent#getStatus was null") }
+    <::sponge::event::entity::living::humanoid::player::resource_pack_status_event::ResourcePackStatus as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RespawnPlayerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RespawnPlayerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RespawnPlayerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RespawnPlayerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_from_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent", self.object, "getFromTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent#getFromTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_to_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent", self.object, "getToTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent#getToTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn set_to_transform(&self, transform: ::sponge::entity::Transform) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent", self.object, "setToTransform", "(Lorg/spongepowered/api/entity/Transform;)V", CallVoidMethodA, transform.object);
+  }
+
+  pub fn is_bed_spawn(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent", self.object, "isBedSpawn", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetPlayerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetPlayerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetPlayerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/humanoid/player/TargetPlayerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::living::player::Player {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/humanoid/player/TargetPlayerEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/living/player/Player;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/TargetPlayerEvent#getTargetEntity was null") }
+    ::sponge::entity::living::player::Player { env: self.env, object: ret }
+  }
+
+}
+
+pub mod resource_pack_status_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum ResourcePackStatus {
+    Accepted,
+    SuccessfullyLoaded,
+    Declined,
+    Failed,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static RESOURCE_PACK_STATUS_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent$ResourcePackStatus", &["ACCEPTED", "SUCCESSFULLY_LOADED", "DECLINED", "FAILED"]);
+
+  impl ResourcePackStatus {
+    pub const VALUES: &'static [ResourcePackStatus] = &[ResourcePackStatus::Accepted, ResourcePackStatus::SuccessfullyLoaded, ResourcePackStatus::Declined, ResourcePackStatus::Failed];
+
+    pub fn was_successful(&self, env: *mut JNIEnv) -> Option<jobject> {
+      let ret = bound_method!(env, "org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent$ResourcePackStatus", ::convert::ToJava::to_java(self, env), "wasSuccessful", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent$ResourcePackStatus#wasSuccessful was null") }
+      let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+  }
+
+  impl ::convert::FromJava for ResourcePackStatus {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match RESOURCE_PACK_STATUS_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => ResourcePackStatus::VALUES[ordinal],
+        None => ResourcePackStatus::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for ResourcePackStatus {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        ResourcePackStatus::Other(object) => object,
+        value => RESOURCE_PACK_STATUS_CONSTANTS.get(env, ResourcePackStatus::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
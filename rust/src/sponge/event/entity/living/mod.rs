@@ -0,0 +1,63 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod humanoid;
+
+#[derive(Debug)]
+pub struct TargetAgentEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetAgentEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetAgentEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetAgentEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/TargetAgentEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::living::Agent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/TargetAgentEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/living/Agent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/TargetAgentEvent#getTargetEntity was null") }
+    ::sponge::entity::living::Agent { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetLivingEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetLivingEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetLivingEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetLivingEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/living/TargetLivingEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::living::Living {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/living/TargetLivingEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/living/Living;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/living/TargetLivingEvent#getTargetEntity was null") }
+    ::sponge::entity::living::Living { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,1580 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod ai;
+pub mod explosive;
+pub mod item;
+pub mod living;
+pub mod projectile;
+
+#[derive(Debug)]
+pub struct AffectEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AffectEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AffectEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AffectEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/AffectEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_entities(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AffectEntityEvent", self.object, "getEntities", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AffectEntityEvent#getEntities was null") }
+    ret
+  }
+
+  pub fn filter_entity_locations(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AffectEntityEvent", self.object, "filterEntityLocations", "(Ljava/util/function/Predicate;)Ljava/util/List;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AffectEntityEvent#filterEntityLocations was null") }
+    ret
+  }
+
+  pub fn filter_entities(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AffectEntityEvent", self.object, "filterEntities", "(Ljava/util/function/Predicate;)Ljava/util/List;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AffectEntityEvent#filterEntities was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AttackEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AttackEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AttackEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AttackEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/AttackEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOriginalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOriginalFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_damages(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOriginalDamages", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AttackEntityEvent#getOriginalDamages was null") }
+    ret
+  }
+
+  pub fn get_original_modifier_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOriginalModifierDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn get_original_functions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOriginalFunctions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AttackEntityEvent#getOriginalFunctions was null") }
+    ret
+  }
+
+  pub fn get_base_output_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getBaseOutputDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_output_damage(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "setBaseOutputDamage", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_final_output_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getFinalOutputDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn is_modifier_applicable(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)Z", CallBooleanMethodA, damage_modifier.object) != 0
+  }
+
+  pub fn get_output_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getOutputDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn set_output_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "setOutputDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;)V", CallVoidMethodA, damage_modifier.object, function);
+  }
+
+  pub fn add_damage_modifier_before(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "addDamageModifierBefore", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn add_damage_modifier_after(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "addDamageModifierAfter", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn get_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getModifiers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/AttackEntityEvent#getModifiers was null") }
+    ret
+  }
+
+  pub fn get_knockback_modifier(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "getKnockbackModifier", "()I", CallIntMethod)
+  }
+
+  pub fn set_knockback_modifier(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/AttackEntityEvent", self.object, "setKnockbackModifier", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BreedEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BreedEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BreedEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BreedEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/BreedEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeEntityEquipmentEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeEntityEquipmentEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeEntityEquipmentEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeEntityEquipmentEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_original_item_stack(&self) -> Option<::sponge::item::inventory::ItemStackSnapshot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent", self.object, "getOriginalItemStack", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent#getOriginalItemStack was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_item_stack(&self) -> Option<::sponge::data::Transaction> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent", self.object, "getItemStack", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent#getItemStack was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::Transaction { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_target_inventory(&self) -> ::sponge::item::inventory::Slot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent", self.object, "getTargetInventory", "()Lorg/spongepowered/api/item/inventory/Slot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent#getTargetInventory was null") }
+    ::sponge::item::inventory::Slot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeEntityExperienceEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeEntityExperienceEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeEntityExperienceEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeEntityExperienceEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityExperienceEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_experience(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityExperienceEvent", self.object, "getOriginalExperience", "()I", CallIntMethod)
+  }
+
+  pub fn get_experience(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityExperienceEvent", self.object, "getExperience", "()I", CallIntMethod)
+  }
+
+  pub fn set_experience(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityExperienceEvent", self.object, "setExperience", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeEntityPotionEffectEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeEntityPotionEffectEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeEntityPotionEffectEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeEntityPotionEffectEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_current_effects(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent", self.object, "getCurrentEffects", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent#getCurrentEffects was null") }
+    ret
+  }
+
+  #[cfg(feature = "effect")]
+  pub fn get_potion_effect(&self) -> ::sponge::effect::potion::PotionEffect {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent", self.object, "getPotionEffect", "()Lorg/spongepowered/api/effect/potion/PotionEffect;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent#getPotionEffect was null") }
+    ::sponge::effect::potion::PotionEffect { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CollideEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollideEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollideEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CollideEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/CollideEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConstructEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConstructEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConstructEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConstructEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ConstructEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ConstructEntityEvent", self.object, "getTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ConstructEntityEvent#getTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_type(&self) -> ::sponge::entity::EntityType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/ConstructEntityEvent", self.object, "getTargetType", "()Lorg/spongepowered/api/entity/EntityType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/ConstructEntityEvent#getTargetType was null") }
+    ::sponge::entity::EntityType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DamageEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DamageEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DamageEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DamageEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/DamageEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getOriginalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getOriginalFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_damages(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getOriginalDamages", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/DamageEntityEvent#getOriginalDamages was null") }
+    ret
+  }
+
+  pub fn get_original_modifier_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getOriginalModifierDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn get_original_functions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getOriginalFunctions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/DamageEntityEvent#getOriginalFunctions was null") }
+    ret
+  }
+
+  pub fn get_base_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getBaseDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_damage(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "setBaseDamage", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn is_modifier_applicable(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)Z", CallBooleanMethodA, damage_modifier.object) != 0
+  }
+
+  pub fn get_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn set_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "setDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;)V", CallVoidMethodA, damage_modifier.object, function);
+  }
+
+  pub fn add_damage_modifier_before(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "addDamageModifierBefore", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn add_modifier_after(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "addModifierAfter", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn get_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "getModifiers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/DamageEntityEvent#getModifiers was null") }
+    ret
+  }
+
+  pub fn will_cause_death(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/DamageEntityEvent", self.object, "willCauseDeath", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DestructEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DestructEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DestructEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DestructEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/DestructEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DismountEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DismountEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DismountEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DismountEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/DismountEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExpireEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExpireEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExpireEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExpireEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ExpireEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HarvestEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HarvestEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HarvestEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HarvestEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/HarvestEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HealEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HealEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HealEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HealEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/HealEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getOriginalHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_final_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getOriginalFinalHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_healing_amounts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getOriginalHealingAmounts", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/HealEntityEvent#getOriginalHealingAmounts was null") }
+    ret
+  }
+
+  pub fn get_final_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getFinalHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_healing_modifier_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getOriginalHealingModifierAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)D", CallDoubleMethodA, health_modifier.object)
+  }
+
+  pub fn get_original_functions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getOriginalFunctions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/HealEntityEvent#getOriginalFunctions was null") }
+    ret
+  }
+
+  pub fn get_base_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getBaseHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_heal_amount(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "setBaseHealAmount", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn is_modifier_applicable(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)Z", CallBooleanMethodA, health_modifier.object) != 0
+  }
+
+  pub fn get_heal_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getHealAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)D", CallDoubleMethodA, health_modifier.object)
+  }
+
+  pub fn set_heal_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "setHealAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;Ljava/util/function/Function;)V", CallVoidMethodA, health_modifier.object, function);
+  }
+
+  pub fn get_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/HealEntityEvent", self.object, "getModifiers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/HealEntityEvent#getModifiers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IgniteEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IgniteEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IgniteEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IgniteEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/IgniteEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_fire_ticks(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/IgniteEntityEvent", self.object, "getOriginalFireTicks", "()I", CallIntMethod)
+  }
+
+  pub fn get_fire_ticks(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/IgniteEntityEvent", self.object, "getFireTicks", "()I", CallIntMethod)
+  }
+
+  pub fn set_fire_ticks(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/IgniteEntityEvent", self.object, "setFireTicks", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InteractEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InteractEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InteractEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InteractEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LeashEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LeashEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LeashEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LeashEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/LeashEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MountEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MountEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MountEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MountEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/MountEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MoveEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MoveEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MoveEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MoveEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/MoveEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_from_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent", self.object, "getFromTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/MoveEntityEvent#getFromTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_to_transform(&self) -> ::sponge::entity::Transform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent", self.object, "getToTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/MoveEntityEvent#getToTransform was null") }
+    ::sponge::entity::Transform { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn set_to_transform(&self, transform: ::sponge::entity::Transform) {
+    bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent", self.object, "setToTransform", "(Lorg/spongepowered/api/entity/Transform;)V", CallVoidMethodA, transform.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpawnEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpawnEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpawnEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpawnEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/SpawnEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TameEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TameEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TameEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TameEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/TameEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/TargetEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/TargetEntityEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/TargetEntityEvent#getTargetEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnleashEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnleashEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnleashEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnleashEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/UnleashEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod breed_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Breed {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Breed {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Breed {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Breed`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/BreedEntityEvent$Breed", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_offspring_entity(&self) -> ::sponge::entity::living::Ageable {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/BreedEntityEvent$Breed", self.object, "getOffspringEntity", "()Lorg/spongepowered/api/entity/living/Ageable;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/entity/BreedEntityEvent$Breed#getOffspringEntity was null") }
+      ::sponge::entity::living::Ageable { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct FindMate {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl FindMate {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      FindMate {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `FindMate`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/BreedEntityEvent$FindMate", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod change_entity_equipment_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetHumanoid {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetHumanoid {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetHumanoid {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetHumanoid`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetHumanoid", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TargetLiving {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetLiving {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetLiving {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetLiving`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetLiving", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod change_entity_potion_effect_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Expire {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Expire {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Expire {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Expire`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Expire", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Gain {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Gain {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Gain {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Gain`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Gain", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Remove {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Remove {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Remove {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Remove`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Remove", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod collide_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Impact {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Impact {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Impact {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Impact`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/CollideEntityEvent$Impact", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod construct_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ConstructEntityEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ConstructEntityEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod destruct_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Death {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Death {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Death {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Death`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/DestructEntityEvent$Death", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod expire_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetItem {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetItem {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetItem {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetItem`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/ExpireEntityEvent$TargetItem", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod harvest_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetHumanoid {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetHumanoid {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetHumanoid {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetHumanoid`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetHumanoid", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TargetLiving {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetLiving {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetLiving {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetLiving`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetLiving", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn keeps_inventory(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "keepsInventory", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn set_keeps_inventory(&self, value: bool) {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "setKeepsInventory", "(Z)V", CallVoidMethodA, value);
+    }
+
+    pub fn keeps_level(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "keepsLevel", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn set_keeps_level(&self, value: bool) {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "setKeepsLevel", "(Z)V", CallVoidMethodA, value);
+    }
+
+    pub fn get_level(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "getLevel", "()I", CallIntMethod)
+    }
+
+    pub fn set_level(&self, value: i32) {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer", self.object, "setLevel", "(I)V", CallVoidMethodA, value);
+    }
+
+  }
+}
+
+pub mod interact_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Primary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Primary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Primary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Primary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Secondary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Secondary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Secondary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Secondary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  pub mod primary {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct MainHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl MainHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        MainHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `MainHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Primary$MainHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct OffHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl OffHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        OffHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `OffHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Primary$OffHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+
+  pub mod secondary {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct MainHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl MainHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        MainHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `MainHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Secondary$MainHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct OffHand {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl OffHand {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        OffHand {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `OffHand`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/InteractEntityEvent$Secondary$OffHand", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+}
+
+pub mod move_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Teleport {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Teleport {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Teleport {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Teleport`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_keeps_velocity(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport", self.object, "getKeepsVelocity", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn set_keeps_velocity(&self, value: bool) {
+      bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport", self.object, "setKeepsVelocity", "(Z)V", CallVoidMethodA, value);
+    }
+
+  }
+
+  pub mod teleport {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Portal {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Portal {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Portal {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Portal`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn set_use_portal_agent(&self, value: bool) {
+        bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal", self.object, "setUsePortalAgent", "(Z)V", CallVoidMethodA, value);
+      }
+
+      pub fn get_use_portal_agent(&self) -> bool {
+        bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal", self.object, "getUsePortalAgent", "()Z", CallBooleanMethod) != 0
+      }
+
+      #[cfg(feature = "world")]
+      pub fn get_portal_agent(&self) -> ::sponge::world::PortalAgent {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal", self.object, "getPortalAgent", "()Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal#getPortalAgent was null") }
+        ::sponge::world::PortalAgent { env: self.env, object: ret }
+      }
+
+      #[cfg(feature = "world")]
+      pub fn set_portal_agent(&self, portal_agent: ::sponge::world::PortalAgent) {
+        bound_method!(self.env, "org/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal", self.object, "setPortalAgent", "(Lorg/spongepowered/api/world/PortalAgent;)V", CallVoidMethodA, portal_agent.object);
+      }
+
+    }
+  }
+}
+
+pub mod spawn_entity_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ChunkLoad {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ChunkLoad {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ChunkLoad {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkLoad`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/SpawnEntityEvent$ChunkLoad", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Custom {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Custom {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Custom {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Custom`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/SpawnEntityEvent$Custom", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Spawner {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Spawner {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Spawner {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Spawner`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/SpawnEntityEvent$Spawner", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
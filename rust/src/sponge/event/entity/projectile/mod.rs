@@ -0,0 +1,54 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct LaunchProjectileEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LaunchProjectileEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LaunchProjectileEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LaunchProjectileEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/projectile/LaunchProjectileEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetProjectileEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetProjectileEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetProjectileEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetProjectileEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/entity/projectile/TargetProjectileEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_entity(&self) -> ::sponge::entity::projectile::Projectile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/entity/projectile/TargetProjectileEvent", self.object, "getTargetEntity", "()Lorg/spongepowered/api/entity/projectile/Projectile;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/entity/projectile/TargetProjectileEvent#getTargetEntity was null") }
+    ::sponge::entity::projectile::Projectile { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,226 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct After {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl After {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    After {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `After`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/After", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/cause/After", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/cause/After#value was null") }
+    ret
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/After", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct All {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl All {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    All {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `All`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/All", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn ignore_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/All", self.object, "ignoreEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Before {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Before {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Before {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Before`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/Before", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Before", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/cause/Before#value was null") }
+    ret
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Before", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct First {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl First {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    First {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `First`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/First", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/First", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Last {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Last {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Last {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Last`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/Last", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Last", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Named {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Named {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Named {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Named`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/Named", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Named", self.object, "value", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/cause/Named#value was null") }
+    ret
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Named", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Root {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Root {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Root {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Root`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/cause/Root", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn type_filter(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/cause/Root", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
@@ -0,0 +1,67 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Has {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Has {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Has {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Has`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/data/Has", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/data/Has", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/data/Has#value was null") }
+    ret
+  }
+
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/data/Has", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Supports {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Supports {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Supports {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Supports`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/data/Supports", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/data/Supports", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/data/Supports#value was null") }
+    ret
+  }
+
+  pub fn inverse(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/filter/data/Supports", self.object, "inverse", "()Z", CallBooleanMethod) != 0
+  }
+
+}
@@ -0,0 +1,64 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod cause;
+pub mod data;
+pub mod type_;
+
+#[derive(Debug)]
+pub struct Getter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Getter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Getter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Getter`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/Getter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/Getter", self.object, "value", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/Getter#value was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IsCancelled {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IsCancelled {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IsCancelled {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IsCancelled`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/IsCancelled", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn value(&self) -> ::sponge::util::Tristate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/filter/IsCancelled", self.object, "value", "()Lorg/spongepowered/api/util/Tristate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/filter/IsCancelled#value was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
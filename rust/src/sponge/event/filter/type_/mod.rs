@@ -0,0 +1,53 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Exclude {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Exclude {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Exclude {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Exclude`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/type/Exclude", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct Include {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Include {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Include {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Include`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/filter/type/Include", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+}
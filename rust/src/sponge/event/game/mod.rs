@@ -0,0 +1,26 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod state;
+
+#[derive(Debug)]
+pub struct GameReloadEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameReloadEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameReloadEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameReloadEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/GameReloadEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
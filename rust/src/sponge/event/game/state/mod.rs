@@ -0,0 +1,306 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct GameAboutToStartServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameAboutToStartServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameAboutToStartServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameAboutToStartServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameAboutToStartServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameConstructionEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameConstructionEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameConstructionEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameConstructionEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameConstructionEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameInitializationEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameInitializationEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameInitializationEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameInitializationEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameInitializationEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameLoadCompleteEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameLoadCompleteEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameLoadCompleteEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameLoadCompleteEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameLoadCompleteEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GamePostInitializationEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GamePostInitializationEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GamePostInitializationEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GamePostInitializationEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GamePostInitializationEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GamePreInitializationEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GamePreInitializationEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GamePreInitializationEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GamePreInitializationEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GamePreInitializationEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStartedServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStartedServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStartedServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStartedServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStartedServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStartingServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStartingServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStartingServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStartingServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStartingServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStateEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStateEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStateEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStateEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStateEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_state(&self) -> ::sponge::GameState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/game/state/GameStateEvent", self.object, "getState", "()Lorg/spongepowered/api/GameState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/game/state/GameStateEvent#getState was null") }
+    <::sponge::GameState as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStoppedEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStoppedEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStoppedEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStoppedEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStoppedEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStoppedServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStoppedServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStoppedServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStoppedServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStoppedServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStoppingEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStoppingEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStoppingEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStoppingEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStoppingEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameStoppingServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameStoppingServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameStoppingServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameStoppingServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/game/state/GameStoppingServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
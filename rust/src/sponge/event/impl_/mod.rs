@@ -0,0 +1,332 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractAttackEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractAttackEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractAttackEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractAttackEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_modifier_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getOriginalModifierDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn get_original_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getOriginalFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_damages(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getOriginalDamages", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/impl/AbstractAttackEntityEvent#getOriginalDamages was null") }
+    ret
+  }
+
+  pub fn get_final_output_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getFinalOutputDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn is_modifier_applicable(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)Z", CallBooleanMethodA, damage_modifier.object) != 0
+  }
+
+  pub fn get_output_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getOutputDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn set_output_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "setOutputDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;)V", CallVoidMethodA, damage_modifier.object, function);
+  }
+
+  pub fn add_damage_modifier_before(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "addDamageModifierBefore", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn add_damage_modifier_after(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "addDamageModifierAfter", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn get_base_output_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "getBaseOutputDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_output_damage(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractAttackEntityEvent", self.object, "setBaseOutputDamage", "(D)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractDamageEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractDamageEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractDamageEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractDamageEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_modifier_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getOriginalModifierDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn get_original_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getOriginalFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_damages(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getOriginalDamages", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/impl/AbstractDamageEntityEvent#getOriginalDamages was null") }
+    ret
+  }
+
+  pub fn get_final_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getFinalDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn is_modifier_applicable(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)Z", CallBooleanMethodA, damage_modifier.object) != 0
+  }
+
+  pub fn get_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;)D", CallDoubleMethodA, damage_modifier.object)
+  }
+
+  pub fn set_damage(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "setDamage", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;)V", CallVoidMethodA, damage_modifier.object, function);
+  }
+
+  pub fn add_damage_modifier_before(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "addDamageModifierBefore", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn add_modifier_after(&self, damage_modifier: ::sponge::event::cause::entity::damage::DamageModifier, function: jobject, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "addModifierAfter", "(Lorg/spongepowered/api/event/cause/entity/damage/DamageModifier;Ljava/util/function/Function;Ljava/util/Set;)V", CallVoidMethodA, damage_modifier.object, function, set);
+  }
+
+  pub fn get_base_damage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "getBaseDamage", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_damage(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "setBaseDamage", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn will_cause_death(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractDamageEntityEvent", self.object, "willCauseDeath", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractHealEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractHealEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractHealEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractHealEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_healing_modifier_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getOriginalHealingModifierAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)D", CallDoubleMethodA, health_modifier.object)
+  }
+
+  pub fn get_original_final_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getOriginalFinalHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_original_healing_amounts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getOriginalHealingAmounts", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/impl/AbstractHealEntityEvent#getOriginalHealingAmounts was null") }
+    ret
+  }
+
+  pub fn get_final_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getFinalHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn is_modifier_applicable(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "isModifierApplicable", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)Z", CallBooleanMethodA, health_modifier.object) != 0
+  }
+
+  pub fn get_heal_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getHealAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;)D", CallDoubleMethodA, health_modifier.object)
+  }
+
+  pub fn set_heal_amount(&self, health_modifier: ::sponge::event::cause::entity::health::HealthModifier, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "setHealAmount", "(Lorg/spongepowered/api/event/cause/entity/health/HealthModifier;Ljava/util/function/Function;)V", CallVoidMethodA, health_modifier.object, function);
+  }
+
+  pub fn get_base_heal_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "getBaseHealAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_base_heal_amount(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractHealEntityEvent", self.object, "setBaseHealAmount", "(D)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractMessageEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractMessageEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractMessageEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractMessageEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractMessageEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractModifierEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractModifierEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractModifierEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractModifierEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractModifierEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractModifierEvent", self.object, "getModifiers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/impl/AbstractModifierEvent#getModifiers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractSpawnEntityEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractSpawnEntityEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractSpawnEntityEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractSpawnEntityEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractSpawnEntityEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AbstractValueChangeEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractValueChangeEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractValueChangeEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractValueChangeEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/impl/AbstractValueChangeEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn propose_changes(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::event::data::change_data_holder_event::ValueChange {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/impl/AbstractValueChangeEvent", self.object, "proposeChanges", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange;", CallObjectMethodA, data_transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/impl/AbstractValueChangeEvent#proposeChanges was null") }
+    ::sponge::event::data::change_data_holder_event::ValueChange { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,1113 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AffectItemStackEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AffectItemStackEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AffectItemStackEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AffectItemStackEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/AffectItemStackEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_transactions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/AffectItemStackEvent", self.object, "getTransactions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/AffectItemStackEvent#getTransactions was null") }
+    ret
+  }
+
+  pub fn filter(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/AffectItemStackEvent", self.object, "filter", "(Ljava/util/function/Predicate;)Ljava/util/List;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/AffectItemStackEvent#filter was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AffectSlotEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AffectSlotEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AffectSlotEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AffectSlotEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/AffectSlotEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_transactions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/AffectSlotEvent", self.object, "getTransactions", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/AffectSlotEvent#getTransactions was null") }
+    ret
+  }
+
+  pub fn filter(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/AffectSlotEvent", self.object, "filter", "(Ljava/util/function/Predicate;)Ljava/util/List;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/AffectSlotEvent#filter was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeInventoryEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeInventoryEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeInventoryEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeInventoryEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ChangeInventoryEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ClickInventoryEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClickInventoryEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClickInventoryEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ClickInventoryEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DropItemEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DropItemEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DropItemEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DropItemEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/DropItemEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InteractInventoryEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InteractInventoryEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InteractInventoryEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InteractInventoryEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/InteractInventoryEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_cursor_transaction(&self) -> ::sponge::data::Transaction {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/InteractInventoryEvent", self.object, "getCursorTransaction", "()Lorg/spongepowered/api/data/Transaction;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/InteractInventoryEvent#getCursorTransaction was null") }
+    ::sponge::data::Transaction { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetContainerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetContainerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetContainerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetContainerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/TargetContainerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_target_inventory(&self) -> ::sponge::item::inventory::Container {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/TargetContainerEvent", self.object, "getTargetInventory", "()Lorg/spongepowered/api/item/inventory/Container;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/TargetContainerEvent#getTargetInventory was null") }
+    ::sponge::item::inventory::Container { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetInventoryEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetInventoryEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetInventoryEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetInventoryEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/TargetInventoryEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_target_inventory(&self) -> ::sponge::item::inventory::Inventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/TargetInventoryEvent", self.object, "getTargetInventory", "()Lorg/spongepowered/api/item/inventory/Inventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/TargetInventoryEvent#getTargetInventory was null") }
+    ::sponge::item::inventory::Inventory { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UseItemStackEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UseItemStackEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UseItemStackEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UseItemStackEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_remaining_duration(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/item/inventory/UseItemStackEvent", self.object, "getOriginalRemainingDuration", "()I", CallIntMethod)
+  }
+
+  pub fn get_remaining_duration(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/item/inventory/UseItemStackEvent", self.object, "getRemainingDuration", "()I", CallIntMethod)
+  }
+
+  pub fn set_remaining_duration(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/item/inventory/UseItemStackEvent", self.object, "setRemainingDuration", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_item_stack_in_use(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/UseItemStackEvent", self.object, "getItemStackInUse", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/UseItemStackEvent#getItemStackInUse was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+pub mod change_inventory_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Equipment {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Equipment {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Equipment {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Equipment`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Equipment", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Held {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Held {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Held {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Held`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Held", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pickup {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pickup {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pickup {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pickup`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Pickup", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Transfer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Transfer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Transfer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Transfer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Transfer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod click_inventory_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Creative {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Creative {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Creative {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Creative`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Creative", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Double {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Double {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Double {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Double`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Double", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Drag {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Drag {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Drag {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Drag`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drag", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Drop {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Drop {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Drop {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Drop`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Middle {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Middle {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Middle {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Middle`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Middle", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct NumberPress {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl NumberPress {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      NumberPress {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `NumberPress`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$NumberPress", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_number(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$NumberPress", self.object, "getNumber", "()I", CallIntMethod)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Primary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Primary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Primary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Primary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Secondary {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Secondary {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Secondary {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Secondary", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Shift {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Shift {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Shift {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Shift`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Shift", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  pub mod drag {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Primary {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Primary {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Primary {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drag$Primary", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct Secondary {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Secondary {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Secondary {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drag$Secondary", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+
+  pub mod drop {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Full {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Full {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Full {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Full`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Full", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct Outside {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Outside {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Outside {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Outside`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Outside", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct Single {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Single {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Single {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Single`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Single", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    pub mod outside {
+      use jni_sys::{JNIEnv, jvalue, jobject};
+
+      #[derive(Debug)]
+      pub struct Primary {
+        pub env: *mut JNIEnv,
+        pub object: jobject
+      }
+
+      impl Primary {
+        pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+          Primary {
+            env: env,
+            object: object
+          }
+        }
+
+        /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+        /// the class of the object overrides it.
+        pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+          let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Outside$Primary", name, descriptor, args);
+          ::convert::FromJValue::from_jvalue(ret)
+        }
+
+      }
+
+      #[derive(Debug)]
+      pub struct Secondary {
+        pub env: *mut JNIEnv,
+        pub object: jobject
+      }
+
+      impl Secondary {
+        pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+          Secondary {
+            env: env,
+            object: object
+          }
+        }
+
+        /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+        /// the class of the object overrides it.
+        pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+          let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Outside$Secondary", name, descriptor, args);
+          ::convert::FromJValue::from_jvalue(ret)
+        }
+
+      }
+    }
+  }
+
+  pub mod shift {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Primary {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Primary {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Primary {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Primary`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Shift$Primary", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+
+    #[derive(Debug)]
+    pub struct Secondary {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Secondary {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Secondary {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Secondary`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/ClickInventoryEvent$Shift$Secondary", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+    }
+  }
+}
+
+pub mod drop_item_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Custom {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Custom {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Custom {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Custom`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/DropItemEvent$Custom", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Destruct {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Destruct {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Destruct {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Destruct`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/DropItemEvent$Destruct", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Dispense {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Dispense {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Dispense {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dispense`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/DropItemEvent$Dispense", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/DropItemEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_original_dropped_items(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/DropItemEvent$Pre", self.object, "getOriginalDroppedItems", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/DropItemEvent$Pre#getOriginalDroppedItems was null") }
+      ret
+    }
+
+    pub fn get_dropped_items(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/DropItemEvent$Pre", self.object, "getDroppedItems", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/DropItemEvent$Pre#getDroppedItems was null") }
+      ret
+    }
+
+  }
+}
+
+pub mod interact_inventory_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Close {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Close {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Close {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Close`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/InteractInventoryEvent$Close", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Open {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Open {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Open {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Open`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/InteractInventoryEvent$Open", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod use_item_stack_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Finish {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Finish {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Finish {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Finish`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Finish", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Replace {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Replace {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Replace {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Replace`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Replace", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn get_item_stack_result(&self) -> ::sponge::data::Transaction {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Replace", self.object, "getItemStackResult", "()Lorg/spongepowered/api/data/Transaction;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/item/inventory/UseItemStackEvent$Replace#getItemStackResult was null") }
+      ::sponge::data::Transaction { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Reset {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Reset {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Reset {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Reset`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Reset", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Start {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Start {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Start {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Start`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Start", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Stop {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Stop {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Stop {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Stop`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Stop", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Tick {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Tick {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Tick {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Tick`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/item/inventory/UseItemStackEvent$Tick", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
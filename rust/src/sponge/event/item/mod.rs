@@ -0,0 +1,3 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod inventory;
@@ -0,0 +1,293 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct MessageChannelEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MessageChannelEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MessageChannelEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MessageChannelEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageChannelEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_original_channel(&self) -> ::sponge::text::channel::MessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageChannelEvent", self.object, "getOriginalChannel", "()Lorg/spongepowered/api/text/channel/MessageChannel;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageChannelEvent#getOriginalChannel was null") }
+    ::sponge::text::channel::MessageChannel { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_channel(&self) -> Option<::sponge::text::channel::MessageChannel> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageChannelEvent", self.object, "getChannel", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageChannelEvent#getChannel was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::channel::MessageChannel { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_channel(&self, message_channel: ::sponge::text::channel::MessageChannel) {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageChannelEvent", self.object, "setChannel", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", CallVoidMethodA, message_channel.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MessageEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MessageEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MessageEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MessageEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_original_message(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "getOriginalMessage", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent#getOriginalMessage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn is_message_cancelled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "isMessageCancelled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_message_cancelled(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "setMessageCancelled", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_formatter(&self) -> ::sponge::event::message::message_event::MessageFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "getFormatter", "()Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent#getFormatter was null") }
+    ::sponge::event::message::message_event::MessageFormatter { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_message(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "getMessage", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent#getMessage was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn clear_message(&self) -> ::sponge::event::message::message_event::MessageFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "clearMessage", "()Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent#clearMessage was null") }
+    ::sponge::event::message::message_event::MessageFormatter { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_message_text_representable_text_representable_text_representable(&self, text_representable: ::sponge::text::TextRepresentable, text_representable_2: ::sponge::text::TextRepresentable, text_representable_3: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "setMessage", "(Lorg/spongepowered/api/text/TextRepresentable;Lorg/spongepowered/api/text/TextRepresentable;Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object, text_representable_2.object, text_representable_3.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_message_text_representable_text_representable(&self, text_representable: ::sponge::text::TextRepresentable, text_representable_2: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "setMessage", "(Lorg/spongepowered/api/text/TextRepresentable;Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object, text_representable_2.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_message_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent", self.object, "setMessage", "(Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object);
+  }
+
+}
+
+pub mod message_channel_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Chat {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Chat {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Chat {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Chat`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageChannelEvent$Chat", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_raw_message(&self) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageChannelEvent$Chat", self.object, "getRawMessage", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageChannelEvent$Chat#getRawMessage was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod message_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct DefaultBodyApplier {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl DefaultBodyApplier {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      DefaultBodyApplier {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `DefaultBodyApplier`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageEvent$DefaultBodyApplier", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn new(env: *mut JNIEnv, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::event::message::message_event::DefaultBodyApplier {
+      let ret = new_object!(env, "org/spongepowered/api/event/message/MessageEvent$DefaultBodyApplier", "(Lorg/spongepowered/api/text/TextRepresentable;)V", text_representable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$DefaultBodyApplier#<init> was null") }
+      ::sponge::event::message::message_event::DefaultBodyApplier { env: env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct DefaultHeaderApplier {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl DefaultHeaderApplier {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      DefaultHeaderApplier {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `DefaultHeaderApplier`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageEvent$DefaultHeaderApplier", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn new(env: *mut JNIEnv, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::event::message::message_event::DefaultHeaderApplier {
+      let ret = new_object!(env, "org/spongepowered/api/event/message/MessageEvent$DefaultHeaderApplier", "(Lorg/spongepowered/api/text/TextRepresentable;)V", text_representable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$DefaultHeaderApplier#<init> was null") }
+      ::sponge::event::message::message_event::DefaultHeaderApplier { env: env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct MessageFormatter {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl MessageFormatter {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      MessageFormatter {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `MessageFormatter`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv) -> ::sponge::event::message::message_event::MessageFormatter {
+      let ret = new_object!(env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", "()V");
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#<init> was null") }
+      ::sponge::event::message::message_event::MessageFormatter { env: env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn new_text_text(env: *mut JNIEnv, text: ::sponge::text::Text, text_2: ::sponge::text::Text) -> ::sponge::event::message::message_event::MessageFormatter {
+      let ret = new_object!(env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/Text;)V", text.object, text_2.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#<init> was null") }
+      ::sponge::event::message::message_event::MessageFormatter { env: env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn new_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::event::message::message_event::MessageFormatter {
+      let ret = new_object!(env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", "(Lorg/spongepowered/api/text/Text;)V", text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#<init> was null") }
+      ::sponge::event::message::message_event::MessageFormatter { env: env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_header(&self) -> ::sponge::text::transform::SimpleTextFormatter {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "getHeader", "()Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#getHeader was null") }
+      ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn set_header(&self, text_representable: ::sponge::text::TextRepresentable) {
+      bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "setHeader", "(Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object);
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_body(&self) -> ::sponge::text::transform::SimpleTextFormatter {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "getBody", "()Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#getBody was null") }
+      ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn set_body(&self, text_representable: ::sponge::text::TextRepresentable) {
+      bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "setBody", "(Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object);
+    }
+
+    #[cfg(feature = "text")]
+    pub fn get_footer(&self) -> ::sponge::text::transform::SimpleTextFormatter {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "getFooter", "()Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/message/MessageEvent$MessageFormatter#getFooter was null") }
+      ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn set_footer(&self, text_representable: ::sponge::text::TextRepresentable) {
+      bound_method!(self.env, "org/spongepowered/api/event/message/MessageEvent$MessageFormatter", self.object, "setFooter", "(Lorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, text_representable.object);
+    }
+
+  }
+}
@@ -0,0 +1,1811 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod achievement;
+pub mod action;
+pub mod block;
+pub mod cause;
+pub mod command;
+pub mod data;
+pub mod economy;
+pub mod entity;
+pub mod filter;
+pub mod game;
+pub mod impl_;
+pub mod item;
+pub mod message;
+pub mod network;
+pub mod server;
+pub mod service;
+pub mod statistic;
+pub mod user;
+pub mod world;
+
+#[derive(Debug)]
+pub struct Cancellable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Cancellable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Cancellable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Cancellable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/Cancellable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/Cancellable", self.object, "isCancelled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_cancelled(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/event/Cancellable", self.object, "setCancelled", "(Z)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Event {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Event {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Event {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Event`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/Event", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_cause(&self) -> ::sponge::event::cause::Cause {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/Event", self.object, "getCause", "()Lorg/spongepowered/api/event/cause/Cause;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/Event#getCause was null") }
+    ::sponge::event::cause::Cause { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EventListener {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EventListener {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EventListener {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EventListener`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/EventListener", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn handle(&self, event: ::sponge::event::Event) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventListener", self.object, "handle", "(Lorg/spongepowered/api/event/Event;)V", CallVoidMethodA, event.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EventManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EventManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EventManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EventManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/EventManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_listeners(&self, object: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "registerListeners", "(Ljava/lang/Object;Ljava/lang/Object;)V", CallVoidMethodA, object, object_2);
+  }
+
+  pub fn register_listener_object_class_event_listener(&self, object: jobject, class: jobject, event_listener: ::sponge::event::EventListener) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "registerListener", "(Ljava/lang/Object;Ljava/lang/Class;Lorg/spongepowered/api/event/EventListener;)V", CallVoidMethodA, object, class, event_listener.object);
+  }
+
+  pub fn register_listener_object_class_order_event_listener(&self, object: jobject, class: jobject, order: ::sponge::event::Order, event_listener: ::sponge::event::EventListener) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "registerListener", "(Ljava/lang/Object;Ljava/lang/Class;Lorg/spongepowered/api/event/Order;Lorg/spongepowered/api/event/EventListener;)V", CallVoidMethodA, object, class, ::convert::ToJava::to_java(&order, self.env), event_listener.object);
+  }
+
+  pub fn register_listener_object_class_order_bool_event_listener(&self, object: jobject, class: jobject, order: ::sponge::event::Order, value: bool, event_listener: ::sponge::event::EventListener) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "registerListener", "(Ljava/lang/Object;Ljava/lang/Class;Lorg/spongepowered/api/event/Order;ZLorg/spongepowered/api/event/EventListener;)V", CallVoidMethodA, object, class, ::convert::ToJava::to_java(&order, self.env), value, event_listener.object);
+  }
+
+  pub fn unregister_listeners(&self, object: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "unregisterListeners", "(Ljava/lang/Object;)V", CallVoidMethodA, object);
+  }
+
+  pub fn unregister_plugin_listeners(&self, object: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "unregisterPluginListeners", "(Ljava/lang/Object;)V", CallVoidMethodA, object);
+  }
+
+  pub fn post(&self, event: ::sponge::event::Event) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/EventManager", self.object, "post", "(Lorg/spongepowered/api/event/Event;)Z", CallBooleanMethodA, event.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Listener {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Listener {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Listener {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Listener`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/Listener", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn order(&self) -> ::sponge::event::Order {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/Listener", self.object, "order", "()Lorg/spongepowered/api/event/Order;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/Listener#order was null") }
+    <::sponge::event::Order as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn before_modifications(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/Listener", self.object, "beforeModifications", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+  Pre,
+  AfterPre,
+  First,
+  Early,
+  Default,
+  Late,
+  Last,
+  BeforePost,
+  Post,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static ORDER_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/event/Order", &["PRE", "AFTER_PRE", "FIRST", "EARLY", "DEFAULT", "LATE", "LAST", "BEFORE_POST", "POST"]);
+
+impl Order {
+  pub const VALUES: &'static [Order] = &[Order::Pre, Order::AfterPre, Order::First, Order::Early, Order::Default, Order::Late, Order::Last, Order::BeforePost, Order::Post];
+
+}
+
+impl ::convert::FromJava for Order {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match ORDER_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => Order::VALUES[ordinal],
+      None => Order::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for Order {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      Order::Other(object) => object,
+      value => ORDER_CONSTANTS.get(env, Order::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct SpongeEventFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpongeEventFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpongeEventFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SpongeEventFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/SpongeEventFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::event::SpongeEventFactory {
+    let ret = new_object!(env, "org/spongepowered/api/event/SpongeEventFactory", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#<init> was null") }
+    ::sponge::event::SpongeEventFactory { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "statistic", feature = "text"))]
+  pub fn create_grant_achievement_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, achievement: ::sponge::statistic::achievement::Achievement, message_formatter: ::sponge::event::message::message_event::MessageFormatter, player: ::sponge::entity::living::player::Player, value: bool) -> ::sponge::event::achievement::grant_achievement_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGrantAchievementEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/statistic/achievement/Achievement;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/living/player/Player;Z)Lorg/spongepowered/api/event/achievement/GrantAchievementEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, achievement.object, message_formatter.object, player.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGrantAchievementEventTargetPlayer was null") }
+    ::sponge::event::achievement::grant_achievement_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_collide_event_impact(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, location: ::sponge::world::Location) -> ::sponge::event::action::collide_event::Impact {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createCollideEventImpact", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/event/action/CollideEvent$Impact;", CallStaticObjectMethodA, cause.object, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createCollideEventImpact was null") }
+    ::sponge::event::action::collide_event::Impact { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_fishing_event_hook_entity(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity_snapshot: ::sponge::entity::EntitySnapshot, fish_hook: ::sponge::entity::projectile::FishHook, entity: ::sponge::entity::Entity) -> ::sponge::event::action::fishing_event::HookEntity {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createFishingEventHookEntity", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/EntitySnapshot;Lorg/spongepowered/api/entity/projectile/FishHook;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/action/FishingEvent$HookEntity;", CallStaticObjectMethodA, cause.object, entity_snapshot.object, fish_hook.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createFishingEventHookEntity was null") }
+    ::sponge::event::action::fishing_event::HookEntity { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_fishing_event_start(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity_snapshot: ::sponge::entity::EntitySnapshot, fish_hook: ::sponge::entity::projectile::FishHook) -> ::sponge::event::action::fishing_event::Start {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createFishingEventStart", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/EntitySnapshot;Lorg/spongepowered/api/entity/projectile/FishHook;)Lorg/spongepowered/api/event/action/FishingEvent$Start;", CallStaticObjectMethodA, cause.object, entity_snapshot.object, fish_hook.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createFishingEventStart was null") }
+    ::sponge::event::action::fishing_event::Start { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_fishing_event_stop(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, entity_snapshot: ::sponge::entity::EntitySnapshot, fish_hook: ::sponge::entity::projectile::FishHook, list: jobject, entity: ::sponge::entity::Entity) -> ::sponge::event::action::fishing_event::Stop {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createFishingEventStop", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/EntitySnapshot;Lorg/spongepowered/api/entity/projectile/FishHook;Ljava/util/List;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/action/FishingEvent$Stop;", CallStaticObjectMethodA, cause.object, value, value_2, entity_snapshot.object, fish_hook.object, list, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createFishingEventStop was null") }
+    ::sponge::event::action::fishing_event::Stop { env: env, object: ret }
+  }
+
+  pub fn create_interact_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject) -> ::sponge::event::action::InteractEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;)Lorg/spongepowered/api/event/action/InteractEvent;", CallStaticObjectMethodA, cause.object, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractEvent was null") }
+    ::sponge::event::action::InteractEvent { env: env, object: ret }
+  }
+
+  pub fn create_lightning_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause) -> ::sponge::event::action::lightning_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLightningEventPost", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/action/LightningEvent$Post;", CallStaticObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLightningEventPost was null") }
+    ::sponge::event::action::lightning_event::Post { env: env, object: ret }
+  }
+
+  pub fn create_lightning_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause) -> ::sponge::event::action::lightning_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLightningEventPre", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/action/LightningEvent$Pre;", CallStaticObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLightningEventPre was null") }
+    ::sponge::event::action::lightning_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_lightning_event_strike(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::action::lightning_event::Strike {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLightningEventStrike", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/action/LightningEvent$Strike;", CallStaticObjectMethodA, cause.object, list, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLightningEventStrike was null") }
+    ::sponge::event::action::lightning_event::Strike { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "entity"))]
+  pub fn create_sleeping_event_finish(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot, entity: ::sponge::entity::Entity) -> ::sponge::event::action::sleeping_event::Finish {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSleepingEventFinish", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/action/SleepingEvent$Finish;", CallStaticObjectMethodA, cause.object, block_snapshot.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSleepingEventFinish was null") }
+    ::sponge::event::action::sleeping_event::Finish { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "entity"))]
+  pub fn create_sleeping_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot, optional: jobject, entity: ::sponge::entity::Entity, value: bool) -> ::sponge::event::action::sleeping_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSleepingEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;Z)Lorg/spongepowered/api/event/action/SleepingEvent$Post;", CallStaticObjectMethodA, cause.object, block_snapshot.object, optional, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSleepingEventPost was null") }
+    ::sponge::event::action::sleeping_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "entity"))]
+  pub fn create_sleeping_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot, entity: ::sponge::entity::Entity) -> ::sponge::event::action::sleeping_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSleepingEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/action/SleepingEvent$Pre;", CallStaticObjectMethodA, cause.object, block_snapshot.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSleepingEventPre was null") }
+    ::sponge::event::action::sleeping_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "entity"))]
+  pub fn create_sleeping_event_tick(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot, entity: ::sponge::entity::Entity) -> ::sponge::event::action::sleeping_event::Tick {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSleepingEventTick", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/action/SleepingEvent$Tick;", CallStaticObjectMethodA, cause.object, block_snapshot.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSleepingEventTick was null") }
+    ::sponge::event::action::sleeping_event::Tick { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_break(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Break {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventBreak", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Break;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventBreak was null") }
+    ::sponge::event::block::change_block_event::Break { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_decay(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Decay {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventDecay", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Decay;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventDecay was null") }
+    ::sponge::event::block::change_block_event::Decay { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_grow(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Grow {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventGrow", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Grow;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventGrow was null") }
+    ::sponge::event::block::change_block_event::Grow { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_modify(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Modify {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventModify", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Modify;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventModify was null") }
+    ::sponge::event::block::change_block_event::Modify { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_place(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Place {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventPlace", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Place;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventPlace was null") }
+    ::sponge::event::block::change_block_event::Place { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World, list: jobject) -> ::sponge::event::block::change_block_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Post;", CallStaticObjectMethodA, cause.object, world.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventPost was null") }
+    ::sponge::event::block::change_block_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_block_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::block::change_block_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeBlockEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/block/ChangeBlockEvent$Pre;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeBlockEventPre was null") }
+    ::sponge::event::block::change_block_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util", feature = "world"))]
+  pub fn create_collide_block_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_state: ::sponge::block::BlockState, location: ::sponge::world::Location, direction: ::sponge::util::Direction) -> ::sponge::event::block::CollideBlockEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createCollideBlockEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/world/Location;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/CollideBlockEvent;", CallStaticObjectMethodA, cause.object, block_state.object, location.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createCollideBlockEvent was null") }
+    ::sponge::event::block::CollideBlockEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util", feature = "world"))]
+  pub fn create_collide_block_event_impact(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, location: ::sponge::world::Location, block_state: ::sponge::block::BlockState, location_2: ::sponge::world::Location, direction: ::sponge::util::Direction) -> ::sponge::event::block::collide_block_event::Impact {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createCollideBlockEventImpact", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Location;Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/world/Location;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/CollideBlockEvent$Impact;", CallStaticObjectMethodA, cause.object, location.object, block_state.object, location_2.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createCollideBlockEventImpact was null") }
+    ::sponge::event::block::collide_block_event::Impact { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util"))]
+  pub fn create_interact_block_event_primary_main_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, block_snapshot: ::sponge::block::BlockSnapshot, direction: ::sponge::util::Direction) -> ::sponge::event::block::interact_block_event::primary::MainHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractBlockEventPrimaryMainHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/InteractBlockEvent$Primary$MainHand;", CallStaticObjectMethodA, cause.object, optional, block_snapshot.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractBlockEventPrimaryMainHand was null") }
+    ::sponge::event::block::interact_block_event::primary::MainHand { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util"))]
+  pub fn create_interact_block_event_primary_off_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, block_snapshot: ::sponge::block::BlockSnapshot, direction: ::sponge::util::Direction) -> ::sponge::event::block::interact_block_event::primary::OffHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractBlockEventPrimaryOffHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/InteractBlockEvent$Primary$OffHand;", CallStaticObjectMethodA, cause.object, optional, block_snapshot.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractBlockEventPrimaryOffHand was null") }
+    ::sponge::event::block::interact_block_event::primary::OffHand { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util"))]
+  pub fn create_interact_block_event_secondary_main_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, tristate: ::sponge::util::Tristate, tristate_2: ::sponge::util::Tristate, tristate_3: ::sponge::util::Tristate, tristate_4: ::sponge::util::Tristate, optional: jobject, block_snapshot: ::sponge::block::BlockSnapshot, direction: ::sponge::util::Direction) -> ::sponge::event::block::interact_block_event::secondary::MainHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractBlockEventSecondaryMainHand", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Ljava/util/Optional;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/InteractBlockEvent$Secondary$MainHand;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&tristate, env), ::convert::ToJava::to_java(&tristate_2, env), ::convert::ToJava::to_java(&tristate_3, env), ::convert::ToJava::to_java(&tristate_4, env), optional, block_snapshot.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractBlockEventSecondaryMainHand was null") }
+    ::sponge::event::block::interact_block_event::secondary::MainHand { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "util"))]
+  pub fn create_interact_block_event_secondary_off_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, tristate: ::sponge::util::Tristate, tristate_2: ::sponge::util::Tristate, tristate_3: ::sponge::util::Tristate, tristate_4: ::sponge::util::Tristate, optional: jobject, block_snapshot: ::sponge::block::BlockSnapshot, direction: ::sponge::util::Direction) -> ::sponge::event::block::interact_block_event::secondary::OffHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractBlockEventSecondaryOffHand", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Lorg/spongepowered/api/util/Tristate;Ljava/util/Optional;Lorg/spongepowered/api/block/BlockSnapshot;Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/event/block/InteractBlockEvent$Secondary$OffHand;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&tristate, env), ::convert::ToJava::to_java(&tristate_2, env), ::convert::ToJava::to_java(&tristate_3, env), ::convert::ToJava::to_java(&tristate_4, env), optional, block_snapshot.object, ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractBlockEventSecondaryOffHand was null") }
+    ::sponge::event::block::interact_block_event::secondary::OffHand { env: env, object: ret }
+  }
+
+  pub fn create_notify_neighbor_block_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, map: jobject, map_2: jobject) -> ::sponge::event::block::NotifyNeighborBlockEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createNotifyNeighborBlockEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Map;Ljava/util/Map;)Lorg/spongepowered/api/event/block/NotifyNeighborBlockEvent;", CallStaticObjectMethodA, cause.object, map, map_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createNotifyNeighborBlockEvent was null") }
+    ::sponge::event::block::NotifyNeighborBlockEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_target_block_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::block::TargetBlockEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetBlockEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/block/TargetBlockEvent;", CallStaticObjectMethodA, cause.object, block_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetBlockEvent was null") }
+    ::sponge::event::block::TargetBlockEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_tick_block_event_random(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::block::tick_block_event::Random {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTickBlockEventRandom", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/block/TickBlockEvent$Random;", CallStaticObjectMethodA, cause.object, block_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTickBlockEventRandom was null") }
+    ::sponge::event::block::tick_block_event::Random { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_tick_block_event_scheduled(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::event::block::tick_block_event::Scheduled {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTickBlockEventScheduled", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/event/block/TickBlockEvent$Scheduled;", CallStaticObjectMethodA, cause.object, block_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTickBlockEventScheduled was null") }
+    ::sponge::event::block::tick_block_event::Scheduled { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_brewing_event_finish(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, brewing_stand: ::sponge::block::tileentity::carrier::BrewingStand) -> ::sponge::event::block::tileentity::brewing_event::Finish {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBrewingEventFinish", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/BrewingStand;)Lorg/spongepowered/api/event/block/tileentity/BrewingEvent$Finish;", CallStaticObjectMethodA, cause.object, list, item_stack_snapshot.object, brewing_stand.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBrewingEventFinish was null") }
+    ::sponge::event::block::tileentity::brewing_event::Finish { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_brewing_event_interrupt(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, brewing_stand: ::sponge::block::tileentity::carrier::BrewingStand) -> ::sponge::event::block::tileentity::brewing_event::Interrupt {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBrewingEventInterrupt", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/BrewingStand;)Lorg/spongepowered/api/event/block/tileentity/BrewingEvent$Interrupt;", CallStaticObjectMethodA, cause.object, list, item_stack_snapshot.object, brewing_stand.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBrewingEventInterrupt was null") }
+    ::sponge::event::block::tileentity::brewing_event::Interrupt { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_brewing_event_start(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, brewing_stand: ::sponge::block::tileentity::carrier::BrewingStand, list: jobject) -> ::sponge::event::block::tileentity::brewing_event::Start {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBrewingEventStart", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/BrewingStand;Ljava/util/List;)Lorg/spongepowered/api/event/block/tileentity/BrewingEvent$Start;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, brewing_stand.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBrewingEventStart was null") }
+    ::sponge::event::block::tileentity::brewing_event::Start { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_brewing_event_tick(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, brewing_stand: ::sponge::block::tileentity::carrier::BrewingStand, list: jobject) -> ::sponge::event::block::tileentity::brewing_event::Tick {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBrewingEventTick", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/BrewingStand;Ljava/util/List;)Lorg/spongepowered/api/event/block/tileentity/BrewingEvent$Tick;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, brewing_stand.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBrewingEventTick was null") }
+    ::sponge::event::block::tileentity::brewing_event::Tick { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "data"))]
+  pub fn create_change_sign_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, immutable_sign_data: ::sponge::data::manipulator::immutable::tileentity::ImmutableSignData, sign_data: ::sponge::data::manipulator::mutable::tileentity::SignData, sign: ::sponge::block::tileentity::Sign) -> ::sponge::event::block::tileentity::ChangeSignEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeSignEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/manipulator/immutable/tileentity/ImmutableSignData;Lorg/spongepowered/api/data/manipulator/mutable/tileentity/SignData;Lorg/spongepowered/api/block/tileentity/Sign;)Lorg/spongepowered/api/event/block/tileentity/ChangeSignEvent;", CallStaticObjectMethodA, cause.object, immutable_sign_data.object, sign_data.object, sign.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeSignEvent was null") }
+    ::sponge::event::block::tileentity::ChangeSignEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_smelt_event_consume_fuel(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, furnace: ::sponge::block::tileentity::carrier::Furnace, list: jobject) -> ::sponge::event::block::tileentity::smelt_event::ConsumeFuel {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSmeltEventConsumeFuel", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/Furnace;Ljava/util/List;)Lorg/spongepowered/api/event/block/tileentity/SmeltEvent$ConsumeFuel;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, furnace.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSmeltEventConsumeFuel was null") }
+    ::sponge::event::block::tileentity::smelt_event::ConsumeFuel { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_smelt_event_finish(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, list: jobject, furnace: ::sponge::block::tileentity::carrier::Furnace) -> ::sponge::event::block::tileentity::smelt_event::Finish {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSmeltEventFinish", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Ljava/util/List;Lorg/spongepowered/api/block/tileentity/carrier/Furnace;)Lorg/spongepowered/api/event/block/tileentity/SmeltEvent$Finish;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, list, furnace.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSmeltEventFinish was null") }
+    ::sponge::event::block::tileentity::smelt_event::Finish { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_smelt_event_interrupt(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, list: jobject, furnace: ::sponge::block::tileentity::carrier::Furnace) -> ::sponge::event::block::tileentity::smelt_event::Interrupt {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSmeltEventInterrupt", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Ljava/util/List;Lorg/spongepowered/api/block/tileentity/carrier/Furnace;)Lorg/spongepowered/api/event/block/tileentity/SmeltEvent$Interrupt;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, list, furnace.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSmeltEventInterrupt was null") }
+    ::sponge::event::block::tileentity::smelt_event::Interrupt { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_smelt_event_start(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, furnace: ::sponge::block::tileentity::carrier::Furnace, list: jobject) -> ::sponge::event::block::tileentity::smelt_event::Start {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSmeltEventStart", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/Furnace;Ljava/util/List;)Lorg/spongepowered/api/event/block/tileentity/SmeltEvent$Start;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, furnace.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSmeltEventStart was null") }
+    ::sponge::event::block::tileentity::smelt_event::Start { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "item"))]
+  pub fn create_smelt_event_tick(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, furnace: ::sponge::block::tileentity::carrier::Furnace, list: jobject) -> ::sponge::event::block::tileentity::smelt_event::Tick {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSmeltEventTick", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/block/tileentity/carrier/Furnace;Ljava/util/List;)Lorg/spongepowered/api/event/block/tileentity/SmeltEvent$Tick;", CallStaticObjectMethodA, cause.object, item_stack_snapshot.object, furnace.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSmeltEventTick was null") }
+    ::sponge::event::block::tileentity::smelt_event::Tick { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_target_tile_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, tile_entity: ::sponge::block::tileentity::TileEntity) -> ::sponge::event::block::tileentity::TargetTileEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetTileEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/block/tileentity/TileEntity;)Lorg/spongepowered/api/event/block/tileentity/TargetTileEntityEvent;", CallStaticObjectMethodA, cause.object, tile_entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetTileEntityEvent was null") }
+    ::sponge::event::block::tileentity::TargetTileEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn create_send_command_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, string: jobject, string_2: jobject, command_result: ::sponge::command::CommandResult) -> ::sponge::event::command::SendCommandEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSendCommandEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/lang/String;Ljava/lang/String;Lorg/spongepowered/api/command/CommandResult;)Lorg/spongepowered/api/event/command/SendCommandEvent;", CallStaticObjectMethodA, cause.object, string, string_2, command_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSendCommandEvent was null") }
+    ::sponge::event::command::SendCommandEvent { env: env, object: ret }
+  }
+
+  pub fn create_tab_complete_event_chat(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, list_2: jobject, string: jobject) -> ::sponge::event::command::tab_complete_event::Chat {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTabCompleteEventChat", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Ljava/util/List;Ljava/lang/String;)Lorg/spongepowered/api/event/command/TabCompleteEvent$Chat;", CallStaticObjectMethodA, cause.object, list, list_2, string);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTabCompleteEventChat was null") }
+    ::sponge::event::command::tab_complete_event::Chat { env: env, object: ret }
+  }
+
+  pub fn create_tab_complete_event_command(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, list_2: jobject, string: jobject, string_2: jobject, string_3: jobject) -> ::sponge::event::command::tab_complete_event::Command {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTabCompleteEventCommand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Ljava/util/List;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)Lorg/spongepowered/api/event/command/TabCompleteEvent$Command;", CallStaticObjectMethodA, cause.object, list, list_2, string, string_2, string_3);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTabCompleteEventCommand was null") }
+    ::sponge::event::command::tab_complete_event::Command { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn create_change_data_holder_event_value_change(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, data_transaction_result: ::sponge::data::DataTransactionResult, data_holder: ::sponge::data::DataHolder) -> ::sponge::event::data::change_data_holder_event::ValueChange {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeDataHolderEventValueChange", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/DataTransactionResult;Lorg/spongepowered/api/data/DataHolder;)Lorg/spongepowered/api/event/data/ChangeDataHolderEvent$ValueChange;", CallStaticObjectMethodA, cause.object, data_transaction_result.object, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeDataHolderEventValueChange was null") }
+    ::sponge::event::data::change_data_holder_event::ValueChange { env: env, object: ret }
+  }
+
+  #[cfg(feature = "service")]
+  pub fn create_economy_transaction_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction_result: ::sponge::service::economy::transaction::TransactionResult) -> ::sponge::event::economy::EconomyTransactionEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createEconomyTransactionEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/service/economy/transaction/TransactionResult;)Lorg/spongepowered/api/event/economy/EconomyTransactionEvent;", CallStaticObjectMethodA, cause.object, transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createEconomyTransactionEvent was null") }
+    ::sponge::event::economy::EconomyTransactionEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_affect_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::AffectEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAffectEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/AffectEntityEvent;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAffectEntityEvent was null") }
+    ::sponge::event::entity::AffectEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_attack_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, entity: ::sponge::entity::Entity, value: i32, value_2: f64) -> ::sponge::event::entity::AttackEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAttackEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/entity/Entity;ID)Lorg/spongepowered/api/event/entity/AttackEntityEvent;", CallStaticObjectMethodA, cause.object, list, entity.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAttackEntityEvent was null") }
+    ::sponge::event::entity::AttackEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_breed_entity_event_breed(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, ageable: ::sponge::entity::living::Ageable, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::breed_entity_event::Breed {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBreedEntityEventBreed", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/entity/living/Ageable;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/BreedEntityEvent$Breed;", CallStaticObjectMethodA, cause.object, optional, ageable.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBreedEntityEventBreed was null") }
+    ::sponge::event::entity::breed_entity_event::Breed { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_breed_entity_event_find_mate(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, result: ::sponge::event::tristate_result::Result, result_2: ::sponge::event::tristate_result::Result, optional: jobject, entity: ::sponge::entity::Entity, value: bool) -> ::sponge::event::entity::breed_entity_event::FindMate {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBreedEntityEventFindMate", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/event/TristateResult$Result;Lorg/spongepowered/api/event/TristateResult$Result;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;Z)Lorg/spongepowered/api/event/entity/BreedEntityEvent$FindMate;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&result, env), ::convert::ToJava::to_java(&result_2, env), optional, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBreedEntityEventFindMate was null") }
+    ::sponge::event::entity::breed_entity_event::FindMate { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "item"))]
+  pub fn create_change_entity_equipment_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, optional_2: jobject, entity: ::sponge::entity::Entity, slot: ::sponge::item::inventory::Slot) -> ::sponge::event::entity::ChangeEntityEquipmentEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityEquipmentEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;Lorg/spongepowered/api/item/inventory/Slot;)Lorg/spongepowered/api/event/entity/ChangeEntityEquipmentEvent;", CallStaticObjectMethodA, cause.object, optional, optional_2, entity.object, slot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityEquipmentEvent was null") }
+    ::sponge::event::entity::ChangeEntityEquipmentEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "item"))]
+  pub fn create_change_entity_equipment_event_target_humanoid(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, optional_2: jobject, humanoid: ::sponge::entity::living::Humanoid, slot: ::sponge::item::inventory::Slot) -> ::sponge::event::entity::change_entity_equipment_event::TargetHumanoid {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityEquipmentEventTargetHumanoid", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Ljava/util/Optional;Lorg/spongepowered/api/entity/living/Humanoid;Lorg/spongepowered/api/item/inventory/Slot;)Lorg/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetHumanoid;", CallStaticObjectMethodA, cause.object, optional, optional_2, humanoid.object, slot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityEquipmentEventTargetHumanoid was null") }
+    ::sponge::event::entity::change_entity_equipment_event::TargetHumanoid { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "item"))]
+  pub fn create_change_entity_equipment_event_target_living(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, optional_2: jobject, living: ::sponge::entity::living::Living, slot: ::sponge::item::inventory::Slot) -> ::sponge::event::entity::change_entity_equipment_event::TargetLiving {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityEquipmentEventTargetLiving", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Ljava/util/Optional;Lorg/spongepowered/api/entity/living/Living;Lorg/spongepowered/api/item/inventory/Slot;)Lorg/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetLiving;", CallStaticObjectMethodA, cause.object, optional, optional_2, living.object, slot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityEquipmentEventTargetLiving was null") }
+    ::sponge::event::entity::change_entity_equipment_event::TargetLiving { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "item"))]
+  pub fn create_change_entity_equipment_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, optional_2: jobject, player: ::sponge::entity::living::player::Player, slot: ::sponge::item::inventory::Slot) -> ::sponge::event::entity::change_entity_equipment_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityEquipmentEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Ljava/util/Optional;Lorg/spongepowered/api/entity/living/player/Player;Lorg/spongepowered/api/item/inventory/Slot;)Lorg/spongepowered/api/event/entity/ChangeEntityEquipmentEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, optional, optional_2, player.object, slot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityEquipmentEventTargetPlayer was null") }
+    ::sponge::event::entity::change_entity_equipment_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_change_entity_experience_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::ChangeEntityExperienceEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityExperienceEvent", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/ChangeEntityExperienceEvent;", CallStaticObjectMethodA, cause.object, value, value_2, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityExperienceEvent was null") }
+    ::sponge::event::entity::ChangeEntityExperienceEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "effect", feature = "entity"))]
+  pub fn create_change_entity_potion_effect_event_expire(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, potion_effect: ::sponge::effect::potion::PotionEffect, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::change_entity_potion_effect_event::Expire {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityPotionEffectEventExpire", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/effect/potion/PotionEffect;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Expire;", CallStaticObjectMethodA, cause.object, list, potion_effect.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityPotionEffectEventExpire was null") }
+    ::sponge::event::entity::change_entity_potion_effect_event::Expire { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "effect", feature = "entity"))]
+  pub fn create_change_entity_potion_effect_event_gain(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, potion_effect: ::sponge::effect::potion::PotionEffect, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::change_entity_potion_effect_event::Gain {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityPotionEffectEventGain", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/effect/potion/PotionEffect;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Gain;", CallStaticObjectMethodA, cause.object, list, potion_effect.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityPotionEffectEventGain was null") }
+    ::sponge::event::entity::change_entity_potion_effect_event::Gain { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "effect", feature = "entity"))]
+  pub fn create_change_entity_potion_effect_event_remove(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, potion_effect: ::sponge::effect::potion::PotionEffect, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::change_entity_potion_effect_event::Remove {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeEntityPotionEffectEventRemove", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/effect/potion/PotionEffect;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/ChangeEntityPotionEffectEvent$Remove;", CallStaticObjectMethodA, cause.object, list, potion_effect.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeEntityPotionEffectEventRemove was null") }
+    ::sponge::event::entity::change_entity_potion_effect_event::Remove { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_collide_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::CollideEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createCollideEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/CollideEntityEvent;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createCollideEntityEvent was null") }
+    ::sponge::event::entity::CollideEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_collide_entity_event_impact(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, location: ::sponge::world::Location, world: ::sponge::world::World) -> ::sponge::event::entity::collide_entity_event::Impact {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createCollideEntityEventImpact", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/Location;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/CollideEntityEvent$Impact;", CallStaticObjectMethodA, cause.object, list, location.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createCollideEntityEventImpact was null") }
+    ::sponge::event::entity::collide_entity_event::Impact { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_construct_entity_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity, entity_type: ::sponge::entity::EntityType, transform: ::sponge::entity::Transform) -> ::sponge::event::entity::construct_entity_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createConstructEntityEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;Lorg/spongepowered/api/entity/EntityType;Lorg/spongepowered/api/entity/Transform;)Lorg/spongepowered/api/event/entity/ConstructEntityEvent$Post;", CallStaticObjectMethodA, cause.object, entity.object, entity_type.object, transform.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createConstructEntityEventPost was null") }
+    ::sponge::event::entity::construct_entity_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_construct_entity_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity_type: ::sponge::entity::EntityType, transform: ::sponge::entity::Transform) -> ::sponge::event::entity::construct_entity_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createConstructEntityEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/EntityType;Lorg/spongepowered/api/entity/Transform;)Lorg/spongepowered/api/event/entity/ConstructEntityEvent$Pre;", CallStaticObjectMethodA, cause.object, entity_type.object, transform.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createConstructEntityEventPre was null") }
+    ::sponge::event::entity::construct_entity_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_damage_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, entity: ::sponge::entity::Entity, value: f64) -> ::sponge::event::entity::DamageEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDamageEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/entity/Entity;D)Lorg/spongepowered/api/event/entity/DamageEntityEvent;", CallStaticObjectMethodA, cause.object, list, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDamageEntityEvent was null") }
+    ::sponge::event::entity::DamageEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_destruct_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, entity: ::sponge::entity::Entity, value: bool) -> ::sponge::event::entity::DestructEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDestructEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/Entity;Z)Lorg/spongepowered/api/event/entity/DestructEntityEvent;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDestructEntityEvent was null") }
+    ::sponge::event::entity::DestructEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_destruct_entity_event_death(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, living: ::sponge::entity::living::Living, value: bool) -> ::sponge::event::entity::destruct_entity_event::Death {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDestructEntityEventDeath", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/living/Living;Z)Lorg/spongepowered/api/event/entity/DestructEntityEvent$Death;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, living.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDestructEntityEventDeath was null") }
+    ::sponge::event::entity::destruct_entity_event::Death { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_dismount_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::DismountEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDismountEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/DismountEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDismountEntityEvent was null") }
+    ::sponge::event::entity::DismountEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_expire_entity_event_target_item(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item: ::sponge::entity::Item) -> ::sponge::event::entity::expire_entity_event::TargetItem {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createExpireEntityEventTargetItem", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Item;)Lorg/spongepowered/api/event/entity/ExpireEntityEvent$TargetItem;", CallStaticObjectMethodA, cause.object, item.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createExpireEntityEventTargetItem was null") }
+    ::sponge::event::entity::expire_entity_event::TargetItem { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_harvest_entity_event_target_humanoid(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, humanoid: ::sponge::entity::living::Humanoid) -> ::sponge::event::entity::harvest_entity_event::TargetHumanoid {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createHarvestEntityEventTargetHumanoid", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/living/Humanoid;)Lorg/spongepowered/api/event/entity/HarvestEntityEvent$TargetHumanoid;", CallStaticObjectMethodA, cause.object, value, value_2, humanoid.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createHarvestEntityEventTargetHumanoid was null") }
+    ::sponge::event::entity::harvest_entity_event::TargetHumanoid { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_harvest_entity_event_target_living(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, living: ::sponge::entity::living::Living) -> ::sponge::event::entity::harvest_entity_event::TargetLiving {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createHarvestEntityEventTargetLiving", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/living/Living;)Lorg/spongepowered/api/event/entity/HarvestEntityEvent$TargetLiving;", CallStaticObjectMethodA, cause.object, value, value_2, living.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createHarvestEntityEventTargetLiving was null") }
+    ::sponge::event::entity::harvest_entity_event::TargetLiving { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_harvest_entity_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, player: ::sponge::entity::living::player::Player, value_3: bool, value_4: bool, value_5: i32) -> ::sponge::event::entity::harvest_entity_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createHarvestEntityEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/living/player/Player;ZZI)Lorg/spongepowered/api/event/entity/HarvestEntityEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, value, value_2, player.object, value_3, value_4, value_5);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createHarvestEntityEventTargetPlayer was null") }
+    ::sponge::event::entity::harvest_entity_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_heal_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, entity: ::sponge::entity::Entity, value: f64) -> ::sponge::event::entity::HealEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createHealEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/entity/Entity;D)Lorg/spongepowered/api/event/entity/HealEntityEvent;", CallStaticObjectMethodA, cause.object, list, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createHealEntityEvent was null") }
+    ::sponge::event::entity::HealEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_ignite_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::IgniteEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createIgniteEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/IgniteEntityEvent;", CallStaticObjectMethodA, cause.object, value, value_2, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createIgniteEntityEvent was null") }
+    ::sponge::event::entity::IgniteEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_interact_entity_event_primary_main_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::interact_entity_event::primary::MainHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractEntityEventPrimaryMainHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/InteractEntityEvent$Primary$MainHand;", CallStaticObjectMethodA, cause.object, optional, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractEntityEventPrimaryMainHand was null") }
+    ::sponge::event::entity::interact_entity_event::primary::MainHand { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_interact_entity_event_primary_off_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::interact_entity_event::primary::OffHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractEntityEventPrimaryOffHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/InteractEntityEvent$Primary$OffHand;", CallStaticObjectMethodA, cause.object, optional, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractEntityEventPrimaryOffHand was null") }
+    ::sponge::event::entity::interact_entity_event::primary::OffHand { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_interact_entity_event_secondary_main_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::interact_entity_event::secondary::MainHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractEntityEventSecondaryMainHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/InteractEntityEvent$Secondary$MainHand;", CallStaticObjectMethodA, cause.object, optional, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractEntityEventSecondaryMainHand was null") }
+    ::sponge::event::entity::interact_entity_event::secondary::MainHand { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_interact_entity_event_secondary_off_hand(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, optional: jobject, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::interact_entity_event::secondary::OffHand {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractEntityEventSecondaryOffHand", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Optional;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/InteractEntityEvent$Secondary$OffHand;", CallStaticObjectMethodA, cause.object, optional, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractEntityEventSecondaryOffHand was null") }
+    ::sponge::event::entity::interact_entity_event::secondary::OffHand { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_leash_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::LeashEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLeashEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/LeashEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLeashEntityEvent was null") }
+    ::sponge::event::entity::LeashEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_mount_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::MountEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMountEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/MountEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMountEntityEvent was null") }
+    ::sponge::event::entity::MountEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_move_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transform: ::sponge::entity::Transform, transform_2: ::sponge::entity::Transform, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::MoveEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMoveEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/MoveEntityEvent;", CallStaticObjectMethodA, cause.object, transform.object, transform_2.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMoveEntityEvent was null") }
+    ::sponge::event::entity::MoveEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_move_entity_event_teleport(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transform: ::sponge::entity::Transform, transform_2: ::sponge::entity::Transform, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::move_entity_event::Teleport {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMoveEntityEventTeleport", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/MoveEntityEvent$Teleport;", CallStaticObjectMethodA, cause.object, transform.object, transform_2.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMoveEntityEventTeleport was null") }
+    ::sponge::event::entity::move_entity_event::Teleport { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "world"))]
+  pub fn create_move_entity_event_teleport_portal(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transform: ::sponge::entity::Transform, transform_2: ::sponge::entity::Transform, portal_agent: ::sponge::world::PortalAgent, entity: ::sponge::entity::Entity, value: bool) -> ::sponge::event::entity::move_entity_event::teleport::Portal {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMoveEntityEventTeleportPortal", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/world/PortalAgent;Lorg/spongepowered/api/entity/Entity;Z)Lorg/spongepowered/api/event/entity/MoveEntityEvent$Teleport$Portal;", CallStaticObjectMethodA, cause.object, transform.object, transform_2.object, portal_agent.object, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMoveEntityEventTeleportPortal was null") }
+    ::sponge::event::entity::move_entity_event::teleport::Portal { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_spawn_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::SpawnEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSpawnEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/SpawnEntityEvent;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSpawnEntityEvent was null") }
+    ::sponge::event::entity::SpawnEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_spawn_entity_event_chunk_load(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::spawn_entity_event::ChunkLoad {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSpawnEntityEventChunkLoad", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/SpawnEntityEvent$ChunkLoad;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSpawnEntityEventChunkLoad was null") }
+    ::sponge::event::entity::spawn_entity_event::ChunkLoad { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_spawn_entity_event_custom(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::spawn_entity_event::Custom {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSpawnEntityEventCustom", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/SpawnEntityEvent$Custom;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSpawnEntityEventCustom was null") }
+    ::sponge::event::entity::spawn_entity_event::Custom { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_spawn_entity_event_spawner(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::entity::spawn_entity_event::Spawner {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSpawnEntityEventSpawner", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/entity/SpawnEntityEvent$Spawner;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSpawnEntityEventSpawner was null") }
+    ::sponge::event::entity::spawn_entity_event::Spawner { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_tame_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::TameEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTameEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/TameEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTameEntityEvent was null") }
+    ::sponge::event::entity::TameEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::TargetEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/TargetEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetEntityEvent was null") }
+    ::sponge::event::entity::TargetEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_unleash_entity_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, entity: ::sponge::entity::Entity) -> ::sponge::event::entity::UnleashEntityEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUnleashEntityEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/event/entity/UnleashEntityEvent;", CallStaticObjectMethodA, cause.object, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUnleashEntityEvent was null") }
+    ::sponge::event::entity::UnleashEntityEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_a_i_task_event_add(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, goal: ::sponge::entity::ai::Goal, agent: ::sponge::entity::living::Agent, ai_task: ::sponge::entity::ai::task::AITask) -> ::sponge::event::entity::ai::a_i_task_event::Add {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAITaskEventAdd", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/ai/Goal;Lorg/spongepowered/api/entity/living/Agent;Lorg/spongepowered/api/entity/ai/task/AITask;)Lorg/spongepowered/api/event/entity/ai/AITaskEvent$Add;", CallStaticObjectMethodA, cause.object, value, value_2, goal.object, agent.object, ai_task.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAITaskEventAdd was null") }
+    ::sponge::event::entity::ai::a_i_task_event::Add { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_a_i_task_event_remove(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, goal: ::sponge::entity::ai::Goal, agent: ::sponge::entity::living::Agent, ai_task: ::sponge::entity::ai::task::AITask, value: i32) -> ::sponge::event::entity::ai::a_i_task_event::Remove {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAITaskEventRemove", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/ai/Goal;Lorg/spongepowered/api/entity/living/Agent;Lorg/spongepowered/api/entity/ai/task/AITask;I)Lorg/spongepowered/api/event/entity/ai/AITaskEvent$Remove;", CallStaticObjectMethodA, cause.object, goal.object, agent.object, ai_task.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAITaskEventRemove was null") }
+    ::sponge::event::entity::ai::a_i_task_event::Remove { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_defuse_explosive_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, fused_explosive: ::sponge::entity::explosive::FusedExplosive) -> ::sponge::event::entity::explosive::defuse_explosive_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDefuseExplosiveEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/FusedExplosive;)Lorg/spongepowered/api/event/entity/explosive/DefuseExplosiveEvent$Post;", CallStaticObjectMethodA, cause.object, fused_explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDefuseExplosiveEventPost was null") }
+    ::sponge::event::entity::explosive::defuse_explosive_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_defuse_explosive_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, fused_explosive: ::sponge::entity::explosive::FusedExplosive) -> ::sponge::event::entity::explosive::defuse_explosive_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDefuseExplosiveEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/FusedExplosive;)Lorg/spongepowered/api/event/entity/explosive/DefuseExplosiveEvent$Pre;", CallStaticObjectMethodA, cause.object, fused_explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDefuseExplosiveEventPre was null") }
+    ::sponge::event::entity::explosive::defuse_explosive_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "world"))]
+  pub fn create_detonate_explosive_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, builder: ::sponge::world::explosion::explosion::Builder, explosion: ::sponge::world::explosion::Explosion, explosive: ::sponge::entity::explosive::Explosive) -> ::sponge::event::entity::explosive::DetonateExplosiveEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDetonateExplosiveEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/explosion/Explosion$Builder;Lorg/spongepowered/api/world/explosion/Explosion;Lorg/spongepowered/api/entity/explosive/Explosive;)Lorg/spongepowered/api/event/entity/explosive/DetonateExplosiveEvent;", CallStaticObjectMethodA, cause.object, builder.object, explosion.object, explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDetonateExplosiveEvent was null") }
+    ::sponge::event::entity::explosive::DetonateExplosiveEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_prime_explosive_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, fused_explosive: ::sponge::entity::explosive::FusedExplosive) -> ::sponge::event::entity::explosive::prime_explosive_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPrimeExplosiveEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/FusedExplosive;)Lorg/spongepowered/api/event/entity/explosive/PrimeExplosiveEvent$Post;", CallStaticObjectMethodA, cause.object, fused_explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPrimeExplosiveEventPost was null") }
+    ::sponge::event::entity::explosive::prime_explosive_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_prime_explosive_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, fused_explosive: ::sponge::entity::explosive::FusedExplosive) -> ::sponge::event::entity::explosive::prime_explosive_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPrimeExplosiveEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/FusedExplosive;)Lorg/spongepowered/api/event/entity/explosive/PrimeExplosiveEvent$Pre;", CallStaticObjectMethodA, cause.object, fused_explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPrimeExplosiveEventPre was null") }
+    ::sponge::event::entity::explosive::prime_explosive_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_explosive_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, explosive: ::sponge::entity::explosive::Explosive) -> ::sponge::event::entity::explosive::TargetExplosiveEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetExplosiveEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/Explosive;)Lorg/spongepowered/api/event/entity/explosive/TargetExplosiveEvent;", CallStaticObjectMethodA, cause.object, explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetExplosiveEvent was null") }
+    ::sponge::event::entity::explosive::TargetExplosiveEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_fused_explosive_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, fused_explosive: ::sponge::entity::explosive::FusedExplosive) -> ::sponge::event::entity::explosive::TargetFusedExplosiveEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetFusedExplosiveEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/explosive/FusedExplosive;)Lorg/spongepowered/api/event/entity/explosive/TargetFusedExplosiveEvent;", CallStaticObjectMethodA, cause.object, fused_explosive.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetFusedExplosiveEvent was null") }
+    ::sponge::event::entity::explosive::TargetFusedExplosiveEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_item_merge_item_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item: ::sponge::entity::Item, item_2: ::sponge::entity::Item) -> ::sponge::event::entity::item::ItemMergeItemEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createItemMergeItemEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Item;Lorg/spongepowered/api/entity/Item;)Lorg/spongepowered/api/event/entity/item/ItemMergeItemEvent;", CallStaticObjectMethodA, cause.object, item.object, item_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createItemMergeItemEvent was null") }
+    ::sponge::event::entity::item::ItemMergeItemEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_item_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item: ::sponge::entity::Item) -> ::sponge::event::entity::item::TargetItemEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetItemEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Item;)Lorg/spongepowered/api/event/entity/item/TargetItemEvent;", CallStaticObjectMethodA, cause.object, item.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetItemEvent was null") }
+    ::sponge::event::entity::item::TargetItemEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_agent_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, agent: ::sponge::entity::living::Agent) -> ::sponge::event::entity::living::TargetAgentEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetAgentEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/Agent;)Lorg/spongepowered/api/event/entity/living/TargetAgentEvent;", CallStaticObjectMethodA, cause.object, agent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetAgentEvent was null") }
+    ::sponge::event::entity::living::TargetAgentEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_living_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, living: ::sponge::entity::living::Living) -> ::sponge::event::entity::living::TargetLivingEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetLivingEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/Living;)Lorg/spongepowered/api/event/entity/living/TargetLivingEvent;", CallStaticObjectMethodA, cause.object, living.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetLivingEvent was null") }
+    ::sponge::event::entity::living::TargetLivingEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_change_game_mode_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_mode: ::sponge::entity::living::player::gamemode::GameMode, game_mode_2: ::sponge::entity::living::player::gamemode::GameMode, humanoid: ::sponge::entity::living::Humanoid) -> ::sponge::event::entity::living::humanoid::ChangeGameModeEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeGameModeEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;Lorg/spongepowered/api/entity/living/Humanoid;)Lorg/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent;", CallStaticObjectMethodA, cause.object, game_mode.object, game_mode_2.object, humanoid.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeGameModeEvent was null") }
+    ::sponge::event::entity::living::humanoid::ChangeGameModeEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_change_game_mode_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_mode: ::sponge::entity::living::player::gamemode::GameMode, game_mode_2: ::sponge::entity::living::player::gamemode::GameMode, player: ::sponge::entity::living::player::Player) -> ::sponge::event::entity::living::humanoid::change_game_mode_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeGameModeEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/entity/living/humanoid/ChangeGameModeEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, game_mode.object, game_mode_2.object, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeGameModeEventTargetPlayer was null") }
+    ::sponge::event::entity::living::humanoid::change_game_mode_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_change_level_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, humanoid: ::sponge::entity::living::Humanoid) -> ::sponge::event::entity::living::humanoid::ChangeLevelEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeLevelEvent", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/living/Humanoid;)Lorg/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent;", CallStaticObjectMethodA, cause.object, value, value_2, humanoid.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeLevelEvent was null") }
+    ::sponge::event::entity::living::humanoid::ChangeLevelEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_change_level_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, player: ::sponge::entity::living::player::Player) -> ::sponge::event::entity::living::humanoid::change_level_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeLevelEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/entity/living/humanoid/ChangeLevelEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, value, value_2, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeLevelEventTargetPlayer was null") }
+    ::sponge::event::entity::living::humanoid::change_level_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_humanoid_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, humanoid: ::sponge::entity::living::Humanoid) -> ::sponge::event::entity::living::humanoid::TargetHumanoidEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetHumanoidEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/Humanoid;)Lorg/spongepowered/api/event/entity/living/humanoid/TargetHumanoidEvent;", CallStaticObjectMethodA, cause.object, humanoid.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetHumanoidEvent was null") }
+    ::sponge::event::entity::living::humanoid::TargetHumanoidEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_kick_player_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, player: ::sponge::entity::living::player::Player, value: bool) -> ::sponge::event::entity::living::humanoid::player::KickPlayerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createKickPlayerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/living/player/Player;Z)Lorg/spongepowered/api/event/entity/living/humanoid/player/KickPlayerEvent;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, player.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createKickPlayerEvent was null") }
+    ::sponge::event::entity::living::humanoid::player::KickPlayerEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_player_change_client_settings_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chat_visibility: ::sponge::text::chat::ChatVisibility, set: jobject, locale: jobject, player: ::sponge::entity::living::player::Player, value: bool, value_2: i32) -> ::sponge::event::entity::living::humanoid::player::PlayerChangeClientSettingsEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPlayerChangeClientSettingsEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/chat/ChatVisibility;Ljava/util/Set;Ljava/util/Locale;Lorg/spongepowered/api/entity/living/player/Player;ZI)Lorg/spongepowered/api/event/entity/living/humanoid/player/PlayerChangeClientSettingsEvent;", CallStaticObjectMethodA, cause.object, chat_visibility.object, set, locale, player.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPlayerChangeClientSettingsEvent was null") }
+    ::sponge::event::entity::living::humanoid::player::PlayerChangeClientSettingsEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "resourcepack"))]
+  pub fn create_resource_pack_status_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, resource_pack: ::sponge::resourcepack::ResourcePack, player: ::sponge::entity::living::player::Player, resource_pack_status: ::sponge::event::entity::living::humanoid::player::resource_pack_status_event::ResourcePackStatus) -> ::sponge::event::entity::living::humanoid::player::ResourcePackStatusEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createResourcePackStatusEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/resourcepack/ResourcePack;Lorg/spongepowered/api/entity/living/player/Player;Lorg/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent$ResourcePackStatus;)Lorg/spongepowered/api/event/entity/living/humanoid/player/ResourcePackStatusEvent;", CallStaticObjectMethodA, cause.object, resource_pack.object, player.object, ::convert::ToJava::to_java(&resource_pack_status, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createResourcePackStatusEvent was null") }
+    ::sponge::event::entity::living::humanoid::player::ResourcePackStatusEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_respawn_player_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transform: ::sponge::entity::Transform, transform_2: ::sponge::entity::Transform, player: ::sponge::entity::living::player::Player, value: bool) -> ::sponge::event::entity::living::humanoid::player::RespawnPlayerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createRespawnPlayerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/living/player/Player;Z)Lorg/spongepowered/api/event/entity/living/humanoid/player/RespawnPlayerEvent;", CallStaticObjectMethodA, cause.object, transform.object, transform_2.object, player.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createRespawnPlayerEvent was null") }
+    ::sponge::event::entity::living::humanoid::player::RespawnPlayerEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_player_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, player: ::sponge::entity::living::player::Player) -> ::sponge::event::entity::living::humanoid::player::TargetPlayerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetPlayerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/entity/living/humanoid/player/TargetPlayerEvent;", CallStaticObjectMethodA, cause.object, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetPlayerEvent was null") }
+    ::sponge::event::entity::living::humanoid::player::TargetPlayerEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_launch_projectile_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, projectile: ::sponge::entity::projectile::Projectile) -> ::sponge::event::entity::projectile::LaunchProjectileEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLaunchProjectileEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/projectile/Projectile;)Lorg/spongepowered/api/event/entity/projectile/LaunchProjectileEvent;", CallStaticObjectMethodA, cause.object, projectile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLaunchProjectileEvent was null") }
+    ::sponge::event::entity::projectile::LaunchProjectileEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_projectile_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, projectile: ::sponge::entity::projectile::Projectile) -> ::sponge::event::entity::projectile::TargetProjectileEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetProjectileEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/projectile/Projectile;)Lorg/spongepowered/api/event/entity/projectile/TargetProjectileEvent;", CallStaticObjectMethodA, cause.object, projectile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetProjectileEvent was null") }
+    ::sponge::event::entity::projectile::TargetProjectileEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_reload_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause) -> ::sponge::event::game::GameReloadEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameReloadEvent", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/event/game/GameReloadEvent;", CallStaticObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameReloadEvent was null") }
+    ::sponge::event::game::GameReloadEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_about_to_start_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameAboutToStartServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameAboutToStartServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameAboutToStartServerEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameAboutToStartServerEvent was null") }
+    ::sponge::event::game::state::GameAboutToStartServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_construction_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameConstructionEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameConstructionEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameConstructionEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameConstructionEvent was null") }
+    ::sponge::event::game::state::GameConstructionEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_initialization_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameInitializationEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameInitializationEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameInitializationEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameInitializationEvent was null") }
+    ::sponge::event::game::state::GameInitializationEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_load_complete_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameLoadCompleteEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameLoadCompleteEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameLoadCompleteEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameLoadCompleteEvent was null") }
+    ::sponge::event::game::state::GameLoadCompleteEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_post_initialization_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GamePostInitializationEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGamePostInitializationEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GamePostInitializationEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGamePostInitializationEvent was null") }
+    ::sponge::event::game::state::GamePostInitializationEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_pre_initialization_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GamePreInitializationEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGamePreInitializationEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GamePreInitializationEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGamePreInitializationEvent was null") }
+    ::sponge::event::game::state::GamePreInitializationEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_started_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStartedServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStartedServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStartedServerEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStartedServerEvent was null") }
+    ::sponge::event::game::state::GameStartedServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_starting_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStartingServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStartingServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStartingServerEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStartingServerEvent was null") }
+    ::sponge::event::game::state::GameStartingServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_state_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStateEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStateEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStateEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStateEvent was null") }
+    ::sponge::event::game::state::GameStateEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_stopped_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStoppedEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStoppedEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStoppedEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStoppedEvent was null") }
+    ::sponge::event::game::state::GameStoppedEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_stopped_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStoppedServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStoppedServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStoppedServerEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStoppedServerEvent was null") }
+    ::sponge::event::game::state::GameStoppedServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_stopping_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStoppingEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStoppingEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStoppingEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStoppingEvent was null") }
+    ::sponge::event::game::state::GameStoppingEvent { env: env, object: ret }
+  }
+
+  pub fn create_game_stopping_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, game_state: ::sponge::GameState) -> ::sponge::event::game::state::GameStoppingServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGameStoppingServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/GameState;)Lorg/spongepowered/api/event/game/state/GameStoppingServerEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&game_state, env));
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGameStoppingServerEvent was null") }
+    ::sponge::event::game::state::GameStoppingServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_affect_item_stack_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject) -> ::sponge::event::item::inventory::AffectItemStackEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAffectItemStackEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/AffectItemStackEvent;", CallStaticObjectMethodA, cause.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAffectItemStackEvent was null") }
+    ::sponge::event::item::inventory::AffectItemStackEvent { env: env, object: ret }
+  }
+
+  pub fn create_affect_slot_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject) -> ::sponge::event::item::inventory::AffectSlotEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createAffectSlotEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/AffectSlotEvent;", CallStaticObjectMethodA, cause.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createAffectSlotEvent was null") }
+    ::sponge::event::item::inventory::AffectSlotEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_change_inventory_event_equipment(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inventory: ::sponge::item::inventory::Inventory, list: jobject) -> ::sponge::event::item::inventory::change_inventory_event::Equipment {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeInventoryEventEquipment", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/Inventory;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Equipment;", CallStaticObjectMethodA, cause.object, inventory.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeInventoryEventEquipment was null") }
+    ::sponge::event::item::inventory::change_inventory_event::Equipment { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_change_inventory_event_held(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inventory: ::sponge::item::inventory::Inventory, list: jobject) -> ::sponge::event::item::inventory::change_inventory_event::Held {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeInventoryEventHeld", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/Inventory;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Held;", CallStaticObjectMethodA, cause.object, inventory.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeInventoryEventHeld was null") }
+    ::sponge::event::item::inventory::change_inventory_event::Held { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "item"))]
+  pub fn create_change_inventory_event_pickup(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, item: ::sponge::entity::Item, inventory: ::sponge::item::inventory::Inventory, list: jobject) -> ::sponge::event::item::inventory::change_inventory_event::Pickup {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeInventoryEventPickup", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Item;Lorg/spongepowered/api/item/inventory/Inventory;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Pickup;", CallStaticObjectMethodA, cause.object, item.object, inventory.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeInventoryEventPickup was null") }
+    ::sponge::event::item::inventory::change_inventory_event::Pickup { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_change_inventory_event_transfer(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inventory: ::sponge::item::inventory::Inventory, list: jobject) -> ::sponge::event::item::inventory::change_inventory_event::Transfer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeInventoryEventTransfer", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/Inventory;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ChangeInventoryEvent$Transfer;", CallStaticObjectMethodA, cause.object, inventory.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeInventoryEventTransfer was null") }
+    ::sponge::event::item::inventory::change_inventory_event::Transfer { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_creative(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::Creative {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventCreative", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Creative;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventCreative was null") }
+    ::sponge::event::item::inventory::click_inventory_event::Creative { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_double(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::Double {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDouble", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Double;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDouble was null") }
+    ::sponge::event::item::inventory::click_inventory_event::Double { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_drag_primary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drag::Primary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDragPrimary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drag$Primary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDragPrimary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drag::Primary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_drag_secondary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drag::Secondary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDragSecondary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drag$Secondary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDragSecondary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drag::Secondary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item", feature = "world"))]
+  pub fn create_click_inventory_event_drop_full(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, list: jobject, container: ::sponge::item::inventory::Container, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drop::Full {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDropFull", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Ljava/util/List;Lorg/spongepowered/api/item/inventory/Container;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Full;", CallStaticObjectMethodA, cause.object, transaction.object, list, container.object, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDropFull was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drop::Full { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item", feature = "world"))]
+  pub fn create_click_inventory_event_drop_outside_primary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, list: jobject, container: ::sponge::item::inventory::Container, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drop::outside::Primary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDropOutsidePrimary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Ljava/util/List;Lorg/spongepowered/api/item/inventory/Container;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Outside$Primary;", CallStaticObjectMethodA, cause.object, transaction.object, list, container.object, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDropOutsidePrimary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drop::outside::Primary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item", feature = "world"))]
+  pub fn create_click_inventory_event_drop_outside_secondary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, list: jobject, container: ::sponge::item::inventory::Container, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drop::outside::Secondary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDropOutsideSecondary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Ljava/util/List;Lorg/spongepowered/api/item/inventory/Container;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Outside$Secondary;", CallStaticObjectMethodA, cause.object, transaction.object, list, container.object, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDropOutsideSecondary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drop::outside::Secondary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item", feature = "world"))]
+  pub fn create_click_inventory_event_drop_single(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, list: jobject, container: ::sponge::item::inventory::Container, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::item::inventory::click_inventory_event::drop::Single {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventDropSingle", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Ljava/util/List;Lorg/spongepowered/api/item/inventory/Container;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Drop$Single;", CallStaticObjectMethodA, cause.object, transaction.object, list, container.object, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventDropSingle was null") }
+    ::sponge::event::item::inventory::click_inventory_event::drop::Single { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_middle(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::Middle {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventMiddle", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Middle;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventMiddle was null") }
+    ::sponge::event::item::inventory::click_inventory_event::Middle { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_number_press(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject, value: i32) -> ::sponge::event::item::inventory::click_inventory_event::NumberPress {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventNumberPress", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;I)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$NumberPress;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventNumberPress was null") }
+    ::sponge::event::item::inventory::click_inventory_event::NumberPress { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_primary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::Primary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventPrimary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Primary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventPrimary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::Primary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_secondary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::Secondary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventSecondary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Secondary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventSecondary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::Secondary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_shift_primary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::shift::Primary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventShiftPrimary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Shift$Primary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventShiftPrimary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::shift::Primary { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_click_inventory_event_shift_secondary(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container, list: jobject) -> ::sponge::event::item::inventory::click_inventory_event::shift::Secondary {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClickInventoryEventShiftSecondary", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/ClickInventoryEvent$Shift$Secondary;", CallStaticObjectMethodA, cause.object, transaction.object, container.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClickInventoryEventShiftSecondary was null") }
+    ::sponge::event::item::inventory::click_inventory_event::shift::Secondary { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_drop_item_event_custom(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::item::inventory::drop_item_event::Custom {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDropItemEventCustom", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/item/inventory/DropItemEvent$Custom;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDropItemEventCustom was null") }
+    ::sponge::event::item::inventory::drop_item_event::Custom { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_drop_item_event_destruct(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::item::inventory::drop_item_event::Destruct {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDropItemEventDestruct", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/item/inventory/DropItemEvent$Destruct;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDropItemEventDestruct was null") }
+    ::sponge::event::item::inventory::drop_item_event::Destruct { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_drop_item_event_dispense(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, world: ::sponge::world::World) -> ::sponge::event::item::inventory::drop_item_event::Dispense {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDropItemEventDispense", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/item/inventory/DropItemEvent$Dispense;", CallStaticObjectMethodA, cause.object, list, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDropItemEventDispense was null") }
+    ::sponge::event::item::inventory::drop_item_event::Dispense { env: env, object: ret }
+  }
+
+  pub fn create_drop_item_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, list_2: jobject) -> ::sponge::event::item::inventory::drop_item_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createDropItemEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Ljava/util/List;)Lorg/spongepowered/api/event/item/inventory/DropItemEvent$Pre;", CallStaticObjectMethodA, cause.object, list, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createDropItemEventPre was null") }
+    ::sponge::event::item::inventory::drop_item_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_interact_inventory_event_close(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container) -> ::sponge::event::item::inventory::interact_inventory_event::Close {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractInventoryEventClose", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;)Lorg/spongepowered/api/event/item/inventory/InteractInventoryEvent$Close;", CallStaticObjectMethodA, cause.object, transaction.object, container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractInventoryEventClose was null") }
+    ::sponge::event::item::inventory::interact_inventory_event::Close { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_interact_inventory_event_open(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transaction: ::sponge::data::Transaction, container: ::sponge::item::inventory::Container) -> ::sponge::event::item::inventory::interact_inventory_event::Open {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createInteractInventoryEventOpen", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/data/Transaction;Lorg/spongepowered/api/item/inventory/Container;)Lorg/spongepowered/api/event/item/inventory/InteractInventoryEvent$Open;", CallStaticObjectMethodA, cause.object, transaction.object, container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createInteractInventoryEventOpen was null") }
+    ::sponge::event::item::inventory::interact_inventory_event::Open { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_target_container_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, container: ::sponge::item::inventory::Container) -> ::sponge::event::item::inventory::TargetContainerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetContainerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/Container;)Lorg/spongepowered/api/event/item/inventory/TargetContainerEvent;", CallStaticObjectMethodA, cause.object, container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetContainerEvent was null") }
+    ::sponge::event::item::inventory::TargetContainerEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_target_inventory_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inventory: ::sponge::item::inventory::Inventory) -> ::sponge::event::item::inventory::TargetInventoryEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetInventoryEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/item/inventory/Inventory;)Lorg/spongepowered/api/event/item/inventory/TargetInventoryEvent;", CallStaticObjectMethodA, cause.object, inventory.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetInventoryEvent was null") }
+    ::sponge::event::item::inventory::TargetInventoryEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_use_item_stack_event_finish(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::event::item::inventory::use_item_stack_event::Finish {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventFinish", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Finish;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventFinish was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Finish { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "item"))]
+  pub fn create_use_item_stack_event_replace(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, transaction: ::sponge::data::Transaction) -> ::sponge::event::item::inventory::use_item_stack_event::Replace {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventReplace", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/data/Transaction;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Replace;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object, transaction.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventReplace was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Replace { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_use_item_stack_event_reset(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::event::item::inventory::use_item_stack_event::Reset {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventReset", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Reset;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventReset was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Reset { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_use_item_stack_event_start(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::event::item::inventory::use_item_stack_event::Start {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventStart", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Start;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventStart was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Start { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_use_item_stack_event_stop(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::event::item::inventory::use_item_stack_event::Stop {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventStop", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Stop;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventStop was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Stop { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn create_use_item_stack_event_tick(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::event::item::inventory::use_item_stack_event::Tick {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUseItemStackEventTick", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/event/item/inventory/UseItemStackEvent$Tick;", CallStaticObjectMethodA, cause.object, value, value_2, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUseItemStackEventTick was null") }
+    ::sponge::event::item::inventory::use_item_stack_event::Tick { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn create_message_channel_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, value: bool) -> ::sponge::event::message::MessageChannelEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMessageChannelEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Z)Lorg/spongepowered/api/event/message/MessageChannelEvent;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMessageChannelEvent was null") }
+    ::sponge::event::message::MessageChannelEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn create_message_channel_event_chat(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, text: ::sponge::text::Text, value: bool) -> ::sponge::event::message::message_channel_event::Chat {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createMessageChannelEventChat", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/text/Text;Z)Lorg/spongepowered/api/event/message/MessageChannelEvent$Chat;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, text.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createMessageChannelEventChat was null") }
+    ::sponge::event::message::message_channel_event::Chat { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn create_ban_ip_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, ip: ::sponge::util::ban::ban::Ip) -> ::sponge::event::network::BanIpEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBanIpEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Ip;)Lorg/spongepowered/api/event/network/BanIpEvent;", CallStaticObjectMethodA, cause.object, ip.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBanIpEvent was null") }
+    ::sponge::event::network::BanIpEvent { env: env, object: ret }
+  }
+
+  pub fn create_channel_registration_event_register(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, string: jobject) -> ::sponge::event::network::channel_registration_event::Register {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChannelRegistrationEventRegister", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/lang/String;)Lorg/spongepowered/api/event/network/ChannelRegistrationEvent$Register;", CallStaticObjectMethodA, cause.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChannelRegistrationEventRegister was null") }
+    ::sponge::event::network::channel_registration_event::Register { env: env, object: ret }
+  }
+
+  pub fn create_channel_registration_event_unregister(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, string: jobject) -> ::sponge::event::network::channel_registration_event::Unregister {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChannelRegistrationEventUnregister", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/lang/String;)Lorg/spongepowered/api/event/network/ChannelRegistrationEvent$Unregister;", CallStaticObjectMethodA, cause.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChannelRegistrationEventUnregister was null") }
+    ::sponge::event::network::channel_registration_event::Unregister { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "network", feature = "profile"))]
+  pub fn create_client_connection_event_auth(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, remote_connection: ::sponge::network::RemoteConnection, message_formatter: ::sponge::event::message::message_event::MessageFormatter, game_profile: ::sponge::profile::GameProfile, value: bool) -> ::sponge::event::network::client_connection_event::Auth {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientConnectionEventAuth", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/network/RemoteConnection;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/profile/GameProfile;Z)Lorg/spongepowered/api/event/network/ClientConnectionEvent$Auth;", CallStaticObjectMethodA, cause.object, remote_connection.object, message_formatter.object, game_profile.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientConnectionEventAuth was null") }
+    ::sponge::event::network::client_connection_event::Auth { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_client_connection_event_disconnect(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, player: ::sponge::entity::living::player::Player, value: bool) -> ::sponge::event::network::client_connection_event::Disconnect {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientConnectionEventDisconnect", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/living/player/Player;Z)Lorg/spongepowered/api/event/network/ClientConnectionEvent$Disconnect;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, player.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientConnectionEventDisconnect was null") }
+    ::sponge::event::network::client_connection_event::Disconnect { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "text"))]
+  pub fn create_client_connection_event_join(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, message_channel: ::sponge::text::channel::MessageChannel, optional: jobject, message_formatter: ::sponge::event::message::message_event::MessageFormatter, player: ::sponge::entity::living::player::Player, value: bool) -> ::sponge::event::network::client_connection_event::Join {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientConnectionEventJoin", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/text/channel/MessageChannel;Ljava/util/Optional;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/entity/living/player/Player;Z)Lorg/spongepowered/api/event/network/ClientConnectionEvent$Join;", CallStaticObjectMethodA, cause.object, message_channel.object, optional, message_formatter.object, player.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientConnectionEventJoin was null") }
+    ::sponge::event::network::client_connection_event::Join { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "network", feature = "profile"))]
+  pub fn create_client_connection_event_login(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, transform: ::sponge::entity::Transform, transform_2: ::sponge::entity::Transform, remote_connection: ::sponge::network::RemoteConnection, message_formatter: ::sponge::event::message::message_event::MessageFormatter, game_profile: ::sponge::profile::GameProfile, user: ::sponge::entity::living::player::User, value: bool) -> ::sponge::event::network::client_connection_event::Login {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientConnectionEventLogin", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/entity/Transform;Lorg/spongepowered/api/network/RemoteConnection;Lorg/spongepowered/api/event/message/MessageEvent$MessageFormatter;Lorg/spongepowered/api/profile/GameProfile;Lorg/spongepowered/api/entity/living/player/User;Z)Lorg/spongepowered/api/event/network/ClientConnectionEvent$Login;", CallStaticObjectMethodA, cause.object, transform.object, transform_2.object, remote_connection.object, message_formatter.object, game_profile.object, user.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientConnectionEventLogin was null") }
+    ::sponge::event::network::client_connection_event::Login { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn create_pardon_ip_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, ip: ::sponge::util::ban::ban::Ip) -> ::sponge::event::network::PardonIpEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPardonIpEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Ip;)Lorg/spongepowered/api/event/network/PardonIpEvent;", CallStaticObjectMethodA, cause.object, ip.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPardonIpEvent was null") }
+    ::sponge::event::network::PardonIpEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn create_rcon_connection_event_connect(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, rcon_source: ::sponge::command::source::RconSource) -> ::sponge::event::network::rcon::rcon_connection_event::Connect {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createRconConnectionEventConnect", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/command/source/RconSource;)Lorg/spongepowered/api/event/network/rcon/RconConnectionEvent$Connect;", CallStaticObjectMethodA, cause.object, rcon_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createRconConnectionEventConnect was null") }
+    ::sponge::event::network::rcon::rcon_connection_event::Connect { env: env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn create_rcon_connection_event_disconnect(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, rcon_source: ::sponge::command::source::RconSource) -> ::sponge::event::network::rcon::rcon_connection_event::Disconnect {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createRconConnectionEventDisconnect", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/command/source/RconSource;)Lorg/spongepowered/api/event/network/rcon/RconConnectionEvent$Disconnect;", CallStaticObjectMethodA, cause.object, rcon_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createRconConnectionEventDisconnect was null") }
+    ::sponge::event::network::rcon::rcon_connection_event::Disconnect { env: env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn create_rcon_connection_event_login(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, rcon_source: ::sponge::command::source::RconSource) -> ::sponge::event::network::rcon::rcon_connection_event::Login {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createRconConnectionEventLogin", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/command/source/RconSource;)Lorg/spongepowered/api/event/network/rcon/RconConnectionEvent$Login;", CallStaticObjectMethodA, cause.object, rcon_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createRconConnectionEventLogin was null") }
+    ::sponge::event::network::rcon::rcon_connection_event::Login { env: env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn create_client_ping_server_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, status_client: ::sponge::network::status::StatusClient, response: ::sponge::event::server::client_ping_server_event::Response) -> ::sponge::event::server::ClientPingServerEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientPingServerEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/network/status/StatusClient;Lorg/spongepowered/api/event/server/ClientPingServerEvent$Response;)Lorg/spongepowered/api/event/server/ClientPingServerEvent;", CallStaticObjectMethodA, cause.object, status_client.object, response.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientPingServerEvent was null") }
+    ::sponge::event::server::ClientPingServerEvent { env: env, object: ret }
+  }
+
+  pub fn create_client_ping_server_event_response_players(env: *mut JNIEnv, list: jobject, value: i32, value_2: i32) -> ::sponge::event::server::client_ping_server_event::response::Players {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createClientPingServerEventResponsePlayers", "(Ljava/util/List;II)Lorg/spongepowered/api/event/server/ClientPingServerEvent$Response$Players;", CallStaticObjectMethodA, list, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createClientPingServerEventResponsePlayers was null") }
+    ::sponge::event::server::client_ping_server_event::response::Players { env: env, object: ret }
+  }
+
+  pub fn create_query_server_event_basic(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inet_socket_address: jobject, string: jobject, string_2: jobject, string_3: jobject, value: i32, value_2: i32, value_3: i32, value_4: i32) -> ::sponge::event::server::query::query_server_event::Basic {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createQueryServerEventBasic", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/net/InetSocketAddress;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;IIII)Lorg/spongepowered/api/event/server/query/QueryServerEvent$Basic;", CallStaticObjectMethodA, cause.object, inet_socket_address, string, string_2, string_3, value, value_2, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createQueryServerEventBasic was null") }
+    ::sponge::event::server::query::query_server_event::Basic { env: env, object: ret }
+  }
+
+  pub fn create_query_server_event_full(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, inet_socket_address: jobject, map: jobject, string: jobject, string_2: jobject, string_3: jobject, string_4: jobject, list: jobject, string_5: jobject, string_6: jobject, value: i32, value_2: i32, value_3: i32, value_4: i32) -> ::sponge::event::server::query::query_server_event::Full {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createQueryServerEventFull", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/net/InetSocketAddress;Ljava/util/Map;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Ljava/util/List;Ljava/lang/String;Ljava/lang/String;IIII)Lorg/spongepowered/api/event/server/query/QueryServerEvent$Full;", CallStaticObjectMethodA, cause.object, inet_socket_address, map, string, string_2, string_3, string_4, list, string_5, string_6, value, value_2, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createQueryServerEventFull was null") }
+    ::sponge::event::server::query::query_server_event::Full { env: env, object: ret }
+  }
+
+  #[cfg(feature = "service")]
+  pub fn create_change_service_provider_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, provider_registration: ::sponge::service::ProviderRegistration, optional: jobject) -> ::sponge::event::service::ChangeServiceProviderEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeServiceProviderEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/service/ProviderRegistration;Ljava/util/Optional;)Lorg/spongepowered/api/event/service/ChangeServiceProviderEvent;", CallStaticObjectMethodA, cause.object, provider_registration.object, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeServiceProviderEvent was null") }
+    ::sponge::event::service::ChangeServiceProviderEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "statistic"))]
+  pub fn create_change_statistic_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i64, value_2: i64, statistic: ::sponge::statistic::Statistic, player: ::sponge::entity::living::player::Player) -> ::sponge::event::statistic::change_statistic_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeStatisticEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;JJLorg/spongepowered/api/statistic/Statistic;Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/statistic/ChangeStatisticEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, value, value_2, statistic.object, player.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeStatisticEventTargetPlayer was null") }
+    ::sponge::event::statistic::change_statistic_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "util"))]
+  pub fn create_ban_user_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, profile: ::sponge::util::ban::ban::Profile, user: ::sponge::entity::living::player::User) -> ::sponge::event::user::BanUserEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBanUserEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Profile;Lorg/spongepowered/api/entity/living/player/User;)Lorg/spongepowered/api/event/user/BanUserEvent;", CallStaticObjectMethodA, cause.object, profile.object, user.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBanUserEvent was null") }
+    ::sponge::event::user::BanUserEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "util"))]
+  pub fn create_ban_user_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, profile: ::sponge::util::ban::ban::Profile, player: ::sponge::entity::living::player::Player, user: ::sponge::entity::living::player::User) -> ::sponge::event::user::ban_user_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createBanUserEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Profile;Lorg/spongepowered/api/entity/living/player/Player;Lorg/spongepowered/api/entity/living/player/User;)Lorg/spongepowered/api/event/user/BanUserEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, profile.object, player.object, user.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createBanUserEventTargetPlayer was null") }
+    ::sponge::event::user::ban_user_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "util"))]
+  pub fn create_pardon_user_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, profile: ::sponge::util::ban::ban::Profile, user: ::sponge::entity::living::player::User) -> ::sponge::event::user::PardonUserEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPardonUserEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Profile;Lorg/spongepowered/api/entity/living/player/User;)Lorg/spongepowered/api/event/user/PardonUserEvent;", CallStaticObjectMethodA, cause.object, profile.object, user.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPardonUserEvent was null") }
+    ::sponge::event::user::PardonUserEvent { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "entity", feature = "util"))]
+  pub fn create_pardon_user_event_target_player(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, profile: ::sponge::util::ban::ban::Profile, player: ::sponge::entity::living::player::Player, player_2: ::sponge::entity::living::player::Player) -> ::sponge::event::user::pardon_user_event::TargetPlayer {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPardonUserEventTargetPlayer", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/util/ban/Ban$Profile;Lorg/spongepowered/api/entity/living/player/Player;Lorg/spongepowered/api/entity/living/player/Player;)Lorg/spongepowered/api/event/user/PardonUserEvent$TargetPlayer;", CallStaticObjectMethodA, cause.object, profile.object, player.object, player_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPardonUserEventTargetPlayer was null") }
+    ::sponge::event::user::pardon_user_event::TargetPlayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_target_user_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, user: ::sponge::entity::living::player::User) -> ::sponge::event::user::TargetUserEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetUserEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/entity/living/player/User;)Lorg/spongepowered/api/event/user/TargetUserEvent;", CallStaticObjectMethodA, cause.object, user.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetUserEvent was null") }
+    ::sponge::event::user::TargetUserEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_world_game_rule_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, string: jobject, string_2: jobject, string_3: jobject, world: ::sponge::world::World) -> ::sponge::event::world::ChangeWorldGameRuleEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeWorldGameRuleEvent", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/ChangeWorldGameRuleEvent;", CallStaticObjectMethodA, cause.object, string, string_2, string_3, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeWorldGameRuleEvent was null") }
+    ::sponge::event::world::ChangeWorldGameRuleEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_change_world_weather_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, value: i32, value_2: i32, weather: ::sponge::world::weather::Weather, weather_2: ::sponge::world::weather::Weather, weather_3: ::sponge::world::weather::Weather, world: ::sponge::world::World) -> ::sponge::event::world::ChangeWorldWeatherEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createChangeWorldWeatherEvent", "(Lorg/spongepowered/api/event/cause/Cause;IILorg/spongepowered/api/world/weather/Weather;Lorg/spongepowered/api/world/weather/Weather;Lorg/spongepowered/api/world/weather/Weather;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/ChangeWorldWeatherEvent;", CallStaticObjectMethodA, cause.object, value, value_2, weather.object, weather_2.object, weather_3.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createChangeWorldWeatherEvent was null") }
+    ::sponge::event::world::ChangeWorldWeatherEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_construct_portal_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, location: ::sponge::world::Location) -> ::sponge::event::world::ConstructPortalEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createConstructPortalEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/event/world/ConstructPortalEvent;", CallStaticObjectMethodA, cause.object, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createConstructPortalEvent was null") }
+    ::sponge::event::world::ConstructPortalEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_construct_world_properties_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world_archetype: ::sponge::world::WorldArchetype, world_properties: ::sponge::world::storage::WorldProperties) -> ::sponge::event::world::ConstructWorldPropertiesEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createConstructWorldPropertiesEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/WorldArchetype;Lorg/spongepowered/api/world/storage/WorldProperties;)Lorg/spongepowered/api/event/world/ConstructWorldPropertiesEvent;", CallStaticObjectMethodA, cause.object, world_archetype.object, world_properties.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createConstructWorldPropertiesEvent was null") }
+    ::sponge::event::world::ConstructWorldPropertiesEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_explosion_event_detonate(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, explosion: ::sponge::world::explosion::Explosion, world: ::sponge::world::World, list_2: jobject) -> ::sponge::event::world::explosion_event::Detonate {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createExplosionEventDetonate", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/explosion/Explosion;Lorg/spongepowered/api/world/World;Ljava/util/List;)Lorg/spongepowered/api/event/world/ExplosionEvent$Detonate;", CallStaticObjectMethodA, cause.object, list, explosion.object, world.object, list_2);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createExplosionEventDetonate was null") }
+    ::sponge::event::world::explosion_event::Detonate { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_explosion_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, explosion: ::sponge::world::explosion::Explosion, world: ::sponge::world::World) -> ::sponge::event::world::explosion_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createExplosionEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/explosion/Explosion;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/ExplosionEvent$Post;", CallStaticObjectMethodA, cause.object, explosion.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createExplosionEventPost was null") }
+    ::sponge::event::world::explosion_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_explosion_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, explosion: ::sponge::world::explosion::Explosion, world: ::sponge::world::World) -> ::sponge::event::world::explosion_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createExplosionEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/explosion/Explosion;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/ExplosionEvent$Pre;", CallStaticObjectMethodA, cause.object, explosion.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createExplosionEventPre was null") }
+    ::sponge::event::world::explosion_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_generate_chunk_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::generate_chunk_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGenerateChunkEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/GenerateChunkEvent$Post;", CallStaticObjectMethodA, cause.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGenerateChunkEventPost was null") }
+    ::sponge::event::world::generate_chunk_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_generate_chunk_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::generate_chunk_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createGenerateChunkEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/GenerateChunkEvent$Pre;", CallStaticObjectMethodA, cause.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createGenerateChunkEventPre was null") }
+    ::sponge::event::world::generate_chunk_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_load_world_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::LoadWorldEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLoadWorldEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/LoadWorldEvent;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLoadWorldEvent was null") }
+    ::sponge::event::world::LoadWorldEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_save_world_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::SaveWorldEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSaveWorldEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/SaveWorldEvent;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSaveWorldEvent was null") }
+    ::sponge::event::world::SaveWorldEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_save_world_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::save_world_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSaveWorldEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/SaveWorldEvent$Post;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSaveWorldEventPost was null") }
+    ::sponge::event::world::save_world_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_save_world_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::save_world_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createSaveWorldEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/SaveWorldEvent$Pre;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createSaveWorldEventPre was null") }
+    ::sponge::event::world::save_world_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_target_world_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::TargetWorldEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetWorldEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/TargetWorldEvent;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetWorldEvent was null") }
+    ::sponge::event::world::TargetWorldEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_unload_world_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, world: ::sponge::world::World) -> ::sponge::event::world::UnloadWorldEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUnloadWorldEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/event/world/UnloadWorldEvent;", CallStaticObjectMethodA, cause.object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUnloadWorldEvent was null") }
+    ::sponge::event::world::UnloadWorldEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_forced_chunk_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, vector_3i: ::math::Vector3i, loading_ticket: ::sponge::world::chunk_ticket_manager::LoadingTicket) -> ::sponge::event::world::chunk::ForcedChunkEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createForcedChunkEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/world/ChunkTicketManager$LoadingTicket;)Lorg/spongepowered/api/event/world/chunk/ForcedChunkEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&vector_3i, env), loading_ticket.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createForcedChunkEvent was null") }
+    ::sponge::event::world::chunk::ForcedChunkEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_load_chunk_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::LoadChunkEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createLoadChunkEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/LoadChunkEvent;", CallStaticObjectMethodA, cause.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createLoadChunkEvent was null") }
+    ::sponge::event::world::chunk::LoadChunkEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_populate_chunk_event_populate(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, populator: ::sponge::world::gen::Populator, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::populate_chunk_event::Populate {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPopulateChunkEventPopulate", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/gen/Populator;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/PopulateChunkEvent$Populate;", CallStaticObjectMethodA, cause.object, populator.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPopulateChunkEventPopulate was null") }
+    ::sponge::event::world::chunk::populate_chunk_event::Populate { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_populate_chunk_event_post(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::populate_chunk_event::Post {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPopulateChunkEventPost", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/PopulateChunkEvent$Post;", CallStaticObjectMethodA, cause.object, list, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPopulateChunkEventPost was null") }
+    ::sponge::event::world::chunk::populate_chunk_event::Post { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_populate_chunk_event_pre(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, list: jobject, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::populate_chunk_event::Pre {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createPopulateChunkEventPre", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/List;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/PopulateChunkEvent$Pre;", CallStaticObjectMethodA, cause.object, list, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createPopulateChunkEventPre was null") }
+    ::sponge::event::world::chunk::populate_chunk_event::Pre { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_target_chunk_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::TargetChunkEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createTargetChunkEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/TargetChunkEvent;", CallStaticObjectMethodA, cause.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createTargetChunkEvent was null") }
+    ::sponge::event::world::chunk::TargetChunkEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_unforced_chunk_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, vector_3i: ::math::Vector3i, loading_ticket: ::sponge::world::chunk_ticket_manager::LoadingTicket) -> ::sponge::event::world::chunk::UnforcedChunkEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUnforcedChunkEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/world/ChunkTicketManager$LoadingTicket;)Lorg/spongepowered/api/event/world/chunk/UnforcedChunkEvent;", CallStaticObjectMethodA, cause.object, ::convert::ToJava::to_java(&vector_3i, env), loading_ticket.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUnforcedChunkEvent was null") }
+    ::sponge::event::world::chunk::UnforcedChunkEvent { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_unload_chunk_event(env: *mut JNIEnv, cause: ::sponge::event::cause::Cause, chunk: ::sponge::world::Chunk) -> ::sponge::event::world::chunk::UnloadChunkEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactory", "createUnloadChunkEvent", "(Lorg/spongepowered/api/event/cause/Cause;Lorg/spongepowered/api/world/Chunk;)Lorg/spongepowered/api/event/world/chunk/UnloadChunkEvent;", CallStaticObjectMethodA, cause.object, chunk.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactory#createUnloadChunkEvent was null") }
+    ::sponge::event::world::chunk::UnloadChunkEvent { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpongeEventFactoryUtils {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpongeEventFactoryUtils {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpongeEventFactoryUtils {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SpongeEventFactoryUtils`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/SpongeEventFactoryUtils", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::event::SpongeEventFactoryUtils {
+    let ret = new_object!(env, "org/spongepowered/api/event/SpongeEventFactoryUtils", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactoryUtils#<init> was null") }
+    ::sponge::event::SpongeEventFactoryUtils { env: env, object: ret }
+  }
+
+  pub fn create_event_impl(env: *mut JNIEnv, class: jobject, map: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactoryUtils", "createEventImpl", "(Ljava/lang/Class;Ljava/util/Map;)Ljava/lang/Object;", CallStaticObjectMethodA, class, map);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactoryUtils#createEventImpl was null") }
+    ret
+  }
+
+  pub fn create_state(env: *mut JNIEnv, class: jobject, game: ::sponge::Game) -> ::sponge::event::game::state::GameStateEvent {
+    let ret = static_java_method!(env, "org/spongepowered/api/event/SpongeEventFactoryUtils", "createState", "(Ljava/lang/Class;Lorg/spongepowered/api/Game;)Lorg/spongepowered/api/event/game/state/GameStateEvent;", CallStaticObjectMethodA, class, game.object);
+    if ret.is_null() { panic!("org/spongepowered/api/event/SpongeEventFactoryUtils#createState was null") }
+    ::sponge::event::game::state::GameStateEvent { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TristateResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TristateResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TristateResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TristateResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/TristateResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_allow_result(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/TristateResult", self.object, "hasAllowResult", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_original_result(&self) -> ::sponge::event::tristate_result::Result {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/TristateResult", self.object, "getOriginalResult", "()Lorg/spongepowered/api/event/TristateResult$Result;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/TristateResult#getOriginalResult was null") }
+    <::sponge::event::tristate_result::Result as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_result(&self) -> ::sponge::event::tristate_result::Result {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/TristateResult", self.object, "getResult", "()Lorg/spongepowered/api/event/TristateResult$Result;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/TristateResult#getResult was null") }
+    <::sponge::event::tristate_result::Result as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_result(&self, result: ::sponge::event::tristate_result::Result) {
+    bound_method!(self.env, "org/spongepowered/api/event/TristateResult", self.object, "setResult", "(Lorg/spongepowered/api/event/TristateResult$Result;)V", CallVoidMethodA, ::convert::ToJava::to_java(&result, self.env));
+  }
+
+}
+
+pub mod tristate_result {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Result {
+    Deny,
+    Default,
+    Allow,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static RESULT_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/event/TristateResult$Result", &["DENY", "DEFAULT", "ALLOW"]);
+
+  impl Result {
+    pub const VALUES: &'static [Result] = &[Result::Deny, Result::Default, Result::Allow];
+
+  }
+
+  impl ::convert::FromJava for Result {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match RESULT_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Result::VALUES[ordinal],
+        None => Result::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Result {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Result::Other(object) => object,
+        value => RESULT_CONSTANTS.get(env, Result::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
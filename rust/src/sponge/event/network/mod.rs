@@ -0,0 +1,308 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod rcon;
+
+#[derive(Debug)]
+pub struct BanIpEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BanIpEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BanIpEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BanIpEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/BanIpEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_ban(&self) -> ::sponge::util::ban::ban::Ip {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/network/BanIpEvent", self.object, "getBan", "()Lorg/spongepowered/api/util/ban/Ban$Ip;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/network/BanIpEvent#getBan was null") }
+    ::sponge::util::ban::ban::Ip { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChannelRegistrationEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChannelRegistrationEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChannelRegistrationEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChannelRegistrationEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ChannelRegistrationEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_channel(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ChannelRegistrationEvent", self.object, "getChannel", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/network/ChannelRegistrationEvent#getChannel was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ClientConnectionEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClientConnectionEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClientConnectionEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ClientConnectionEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ClientConnectionEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PardonIpEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PardonIpEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PardonIpEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PardonIpEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/PardonIpEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_ban(&self) -> ::sponge::util::ban::ban::Ip {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/network/PardonIpEvent", self.object, "getBan", "()Lorg/spongepowered/api/util/ban/Ban$Ip;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/network/PardonIpEvent#getBan was null") }
+    ::sponge::util::ban::ban::Ip { env: self.env, object: ret }
+  }
+
+}
+
+pub mod channel_registration_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Register {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Register {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Register {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Register`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ChannelRegistrationEvent$Register", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Unregister {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Unregister {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Unregister {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Unregister`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ChannelRegistrationEvent$Unregister", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod client_connection_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Auth {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Auth {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Auth {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Auth`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ClientConnectionEvent$Auth", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "network")]
+    pub fn get_connection(&self) -> ::sponge::network::RemoteConnection {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Auth", self.object, "getConnection", "()Lorg/spongepowered/api/network/RemoteConnection;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Auth#getConnection was null") }
+      ::sponge::network::RemoteConnection { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "profile")]
+    pub fn get_profile(&self) -> ::sponge::profile::GameProfile {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Auth", self.object, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Auth#getProfile was null") }
+      ::sponge::profile::GameProfile { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Disconnect {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Disconnect {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Disconnect {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Disconnect`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ClientConnectionEvent$Disconnect", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Join {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Join {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Join {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Join`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ClientConnectionEvent$Join", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Login {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Login {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Login {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Login`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "network")]
+    pub fn get_connection(&self) -> ::sponge::network::RemoteConnection {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", self.object, "getConnection", "()Lorg/spongepowered/api/network/RemoteConnection;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Login#getConnection was null") }
+      ::sponge::network::RemoteConnection { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "profile")]
+    pub fn get_profile(&self) -> ::sponge::profile::GameProfile {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", self.object, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Login#getProfile was null") }
+      ::sponge::profile::GameProfile { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_from_transform(&self) -> ::sponge::entity::Transform {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", self.object, "getFromTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Login#getFromTransform was null") }
+      ::sponge::entity::Transform { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_to_transform(&self) -> ::sponge::entity::Transform {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", self.object, "getToTransform", "()Lorg/spongepowered/api/entity/Transform;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/network/ClientConnectionEvent$Login#getToTransform was null") }
+      ::sponge::entity::Transform { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn set_to_transform(&self, transform: ::sponge::entity::Transform) {
+      bound_method!(self.env, "org/spongepowered/api/event/network/ClientConnectionEvent$Login", self.object, "setToTransform", "(Lorg/spongepowered/api/entity/Transform;)V", CallVoidMethodA, transform.object);
+    }
+
+  }
+}
@@ -0,0 +1,104 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct RconConnectionEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RconConnectionEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RconConnectionEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RconConnectionEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/rcon/RconConnectionEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_source(&self) -> ::sponge::command::source::RconSource {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/network/rcon/RconConnectionEvent", self.object, "getSource", "()Lorg/spongepowered/api/command/source/RconSource;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/network/rcon/RconConnectionEvent#getSource was null") }
+    ::sponge::command::source::RconSource { env: self.env, object: ret }
+  }
+
+}
+
+pub mod rcon_connection_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Connect {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Connect {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Connect {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Connect`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/rcon/RconConnectionEvent$Connect", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Disconnect {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Disconnect {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Disconnect {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Disconnect`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/rcon/RconConnectionEvent$Disconnect", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Login {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Login {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Login {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Login`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/network/rcon/RconConnectionEvent$Login", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,128 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod query;
+
+#[derive(Debug)]
+pub struct ClientPingServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClientPingServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClientPingServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ClientPingServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/ClientPingServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "network")]
+  pub fn get_client(&self) -> ::sponge::network::status::StatusClient {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent", self.object, "getClient", "()Lorg/spongepowered/api/network/status/StatusClient;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/server/ClientPingServerEvent#getClient was null") }
+    ::sponge::network::status::StatusClient { env: self.env, object: ret }
+  }
+
+  pub fn get_response(&self) -> ::sponge::event::server::client_ping_server_event::Response {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent", self.object, "getResponse", "()Lorg/spongepowered/api/event/server/ClientPingServerEvent$Response;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/server/ClientPingServerEvent#getResponse was null") }
+    ::sponge::event::server::client_ping_server_event::Response { env: self.env, object: ret }
+  }
+
+}
+
+pub mod client_ping_server_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Response {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Response {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Response {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Response`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/ClientPingServerEvent$Response", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn set_description(&self, text: ::sponge::text::Text) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response", self.object, "setDescription", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+    }
+
+    pub fn get_players(&self) -> Option<::sponge::event::server::client_ping_server_event::response::Players> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response", self.object, "getPlayers", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/ClientPingServerEvent$Response#getPlayers was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::event::server::client_ping_server_event::response::Players { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn set_hide_players(&self, value: bool) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response", self.object, "setHidePlayers", "(Z)V", CallVoidMethodA, value);
+    }
+
+    #[cfg(feature = "network")]
+    pub fn set_favicon(&self, favicon: ::sponge::network::status::Favicon) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response", self.object, "setFavicon", "(Lorg/spongepowered/api/network/status/Favicon;)V", CallVoidMethodA, favicon.object);
+    }
+
+  }
+
+  pub mod response {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Players {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Players {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Players {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Players`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/ClientPingServerEvent$Response$Players", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn set_online(&self, value: i32) {
+        bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response$Players", self.object, "setOnline", "(I)V", CallVoidMethodA, value);
+      }
+
+      pub fn set_max(&self, value: i32) {
+        bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response$Players", self.object, "setMax", "(I)V", CallVoidMethodA, value);
+      }
+
+      pub fn get_profiles(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/event/server/ClientPingServerEvent$Response$Players", self.object, "getProfiles", "()Ljava/util/List;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/event/server/ClientPingServerEvent$Response$Players#getProfiles was null") }
+        ret
+      }
+
+    }
+  }
+}
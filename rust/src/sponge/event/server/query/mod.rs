@@ -0,0 +1,176 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct QueryServerEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl QueryServerEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    QueryServerEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `QueryServerEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/query/QueryServerEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod query_server_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Basic {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Basic {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Basic {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Basic`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_motd(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getMotd", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Basic#getMotd was null") }
+      ret
+    }
+
+    pub fn set_motd(&self, string: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setMotd", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+    }
+
+    pub fn get_game_type(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getGameType", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Basic#getGameType was null") }
+      ret
+    }
+
+    pub fn set_game_type(&self, string: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setGameType", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+    }
+
+    pub fn get_map(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getMap", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Basic#getMap was null") }
+      ret
+    }
+
+    pub fn set_map(&self, string: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setMap", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+    }
+
+    pub fn get_player_count(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getPlayerCount", "()I", CallIntMethod)
+    }
+
+    pub fn set_player_count(&self, value: i32) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setPlayerCount", "(I)V", CallVoidMethodA, value);
+    }
+
+    pub fn get_max_player_count(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getMaxPlayerCount", "()I", CallIntMethod)
+    }
+
+    pub fn set_max_player_count(&self, value: i32) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setMaxPlayerCount", "(I)V", CallVoidMethodA, value);
+    }
+
+    pub fn get_address(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getAddress", "()Ljava/net/InetSocketAddress;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Basic#getAddress was null") }
+      ret
+    }
+
+    pub fn set_address(&self, inet_socket_address: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "setAddress", "(Ljava/net/InetSocketAddress;)V", CallVoidMethodA, inet_socket_address);
+    }
+
+    pub fn get_size(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getSize", "()I", CallIntMethod)
+    }
+
+    pub fn get_max_size(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Basic", self.object, "getMaxSize", "()I", CallIntMethod)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Full {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Full {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Full {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Full`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_game_id(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "getGameId", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Full#getGameId was null") }
+      ret
+    }
+
+    pub fn get_version(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "getVersion", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Full#getVersion was null") }
+      ret
+    }
+
+    pub fn set_version(&self, string: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "setVersion", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+    }
+
+    pub fn get_plugins(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "getPlugins", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Full#getPlugins was null") }
+      ret
+    }
+
+    pub fn set_plugins(&self, string: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "setPlugins", "(Ljava/lang/String;)V", CallVoidMethodA, string);
+    }
+
+    pub fn get_custom_values_map(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "getCustomValuesMap", "()Ljava/util/Map;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Full#getCustomValuesMap was null") }
+      ret
+    }
+
+    pub fn get_players(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/server/query/QueryServerEvent$Full", self.object, "getPlayers", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/server/query/QueryServerEvent$Full#getPlayers was null") }
+      ret
+    }
+
+  }
+}
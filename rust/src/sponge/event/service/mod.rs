@@ -0,0 +1,55 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChangeServiceProviderEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeServiceProviderEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeServiceProviderEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeServiceProviderEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "service")]
+  pub fn get_previous_provider_registration(&self) -> Option<::sponge::service::ProviderRegistration> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", self.object, "getPreviousProviderRegistration", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/service/ChangeServiceProviderEvent#getPreviousProviderRegistration was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::ProviderRegistration { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "service")]
+  pub fn get_new_provider_registration(&self) -> ::sponge::service::ProviderRegistration {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", self.object, "getNewProviderRegistration", "()Lorg/spongepowered/api/service/ProviderRegistration;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/service/ChangeServiceProviderEvent#getNewProviderRegistration was null") }
+    ::sponge::service::ProviderRegistration { env: self.env, object: ret }
+  }
+
+  pub fn is_replacement(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", self.object, "isReplacement", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_service(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", self.object, "getService", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/service/ChangeServiceProviderEvent#getService was null") }
+    ret
+  }
+
+  pub fn get_new_provider(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/service/ChangeServiceProviderEvent", self.object, "getNewProvider", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/service/ChangeServiceProviderEvent#getNewProvider was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,70 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChangeStatisticEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeStatisticEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeStatisticEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeStatisticEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/statistic/ChangeStatisticEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "statistic")]
+  pub fn get_statistic(&self) -> ::sponge::statistic::Statistic {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/statistic/ChangeStatisticEvent", self.object, "getStatistic", "()Lorg/spongepowered/api/statistic/Statistic;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/statistic/ChangeStatisticEvent#getStatistic was null") }
+    ::sponge::statistic::Statistic { env: self.env, object: ret }
+  }
+
+  pub fn get_original_value(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/event/statistic/ChangeStatisticEvent", self.object, "getOriginalValue", "()J", CallLongMethod)
+  }
+
+  pub fn get_value(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/event/statistic/ChangeStatisticEvent", self.object, "getValue", "()J", CallLongMethod)
+  }
+
+  pub fn set_value(&self, value: i64) {
+    bound_method!(self.env, "org/spongepowered/api/event/statistic/ChangeStatisticEvent", self.object, "setValue", "(J)V", CallVoidMethodA, value);
+  }
+
+}
+
+pub mod change_statistic_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/statistic/ChangeStatisticEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
@@ -0,0 +1,152 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BanUserEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BanUserEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BanUserEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BanUserEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/user/BanUserEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_ban(&self) -> ::sponge::util::ban::ban::Profile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/user/BanUserEvent", self.object, "getBan", "()Lorg/spongepowered/api/util/ban/Ban$Profile;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/user/BanUserEvent#getBan was null") }
+    ::sponge::util::ban::ban::Profile { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PardonUserEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PardonUserEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PardonUserEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PardonUserEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/user/PardonUserEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_ban(&self) -> ::sponge::util::ban::ban::Profile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/user/PardonUserEvent", self.object, "getBan", "()Lorg/spongepowered/api/util/ban/Ban$Profile;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/user/PardonUserEvent#getBan was null") }
+    ::sponge::util::ban::ban::Profile { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetUserEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetUserEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetUserEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetUserEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/user/TargetUserEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_target_user(&self) -> ::sponge::entity::living::player::User {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/user/TargetUserEvent", self.object, "getTargetUser", "()Lorg/spongepowered/api/entity/living/player/User;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/user/TargetUserEvent#getTargetUser was null") }
+    ::sponge::entity::living::player::User { env: self.env, object: ret }
+  }
+
+}
+
+pub mod ban_user_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/user/BanUserEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod pardon_user_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct TargetPlayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetPlayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetPlayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetPlayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/user/PardonUserEvent$TargetPlayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_target_user(&self) -> ::sponge::entity::living::player::Player {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/user/PardonUserEvent$TargetPlayer", self.object, "getTargetUser", "()Lorg/spongepowered/api/entity/living/player/Player;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/user/PardonUserEvent$TargetPlayer#getTargetUser was null") }
+      ::sponge::entity::living::player::Player { env: self.env, object: ret }
+    }
+
+  }
+}
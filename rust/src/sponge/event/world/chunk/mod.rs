@@ -0,0 +1,264 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ForcedChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ForcedChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ForcedChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ForcedChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/ForcedChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_ticket(&self) -> ::sponge::world::chunk_ticket_manager::LoadingTicket {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/ForcedChunkEvent", self.object, "getTicket", "()Lorg/spongepowered/api/world/ChunkTicketManager$LoadingTicket;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/ForcedChunkEvent#getTicket was null") }
+    ::sponge::world::chunk_ticket_manager::LoadingTicket { env: self.env, object: ret }
+  }
+
+  pub fn get_chunk_coords(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/ForcedChunkEvent", self.object, "getChunkCoords", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/ForcedChunkEvent#getChunkCoords was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LoadChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LoadChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LoadChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LoadChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/LoadChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PopulateChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PopulateChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PopulateChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PopulateChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/TargetChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_target_chunk(&self) -> ::sponge::world::Chunk {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/TargetChunkEvent", self.object, "getTargetChunk", "()Lorg/spongepowered/api/world/Chunk;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/TargetChunkEvent#getTargetChunk was null") }
+    ::sponge::world::Chunk { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnforcedChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnforcedChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnforcedChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnforcedChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/UnforcedChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_ticket(&self) -> ::sponge::world::chunk_ticket_manager::LoadingTicket {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/UnforcedChunkEvent", self.object, "getTicket", "()Lorg/spongepowered/api/world/ChunkTicketManager$LoadingTicket;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/UnforcedChunkEvent#getTicket was null") }
+    ::sponge::world::chunk_ticket_manager::LoadingTicket { env: self.env, object: ret }
+  }
+
+  pub fn get_chunk_coords(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/UnforcedChunkEvent", self.object, "getChunkCoords", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/UnforcedChunkEvent#getChunkCoords was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnloadChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnloadChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnloadChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnloadChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/UnloadChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod populate_chunk_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Populate {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Populate {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Populate {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Populate`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Populate", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn get_populator(&self) -> ::sponge::world::gen::Populator {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Populate", self.object, "getPopulator", "()Lorg/spongepowered/api/world/gen/Populator;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Populate#getPopulator was null") }
+      ::sponge::world::gen::Populator { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_applied_populators(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Post", self.object, "getAppliedPopulators", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Post#getAppliedPopulators was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_pending_populators(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Pre", self.object, "getPendingPopulators", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/event/world/chunk/PopulateChunkEvent$Pre#getPendingPopulators was null") }
+      ret
+    }
+
+  }
+}
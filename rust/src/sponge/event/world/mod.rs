@@ -0,0 +1,502 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod chunk;
+
+#[derive(Debug)]
+pub struct ChangeWorldGameRuleEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeWorldGameRuleEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeWorldGameRuleEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeWorldGameRuleEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ChangeWorldGameRuleEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_original_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldGameRuleEvent", self.object, "getOriginalValue", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldGameRuleEvent#getOriginalValue was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldGameRuleEvent", self.object, "getValue", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldGameRuleEvent#getValue was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldGameRuleEvent", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldGameRuleEvent#getName was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChangeWorldWeatherEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChangeWorldWeatherEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChangeWorldWeatherEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangeWorldWeatherEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_original_weather(&self) -> ::sponge::world::weather::Weather {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "getOriginalWeather", "()Lorg/spongepowered/api/world/weather/Weather;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldWeatherEvent#getOriginalWeather was null") }
+    ::sponge::world::weather::Weather { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_initial_weather(&self) -> ::sponge::world::weather::Weather {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "getInitialWeather", "()Lorg/spongepowered/api/world/weather/Weather;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldWeatherEvent#getInitialWeather was null") }
+    ::sponge::world::weather::Weather { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_weather(&self) -> ::sponge::world::weather::Weather {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "getWeather", "()Lorg/spongepowered/api/world/weather/Weather;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ChangeWorldWeatherEvent#getWeather was null") }
+    ::sponge::world::weather::Weather { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn set_weather(&self, weather: ::sponge::world::weather::Weather) {
+    bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "setWeather", "(Lorg/spongepowered/api/world/weather/Weather;)V", CallVoidMethodA, weather.object);
+  }
+
+  pub fn get_original_duration(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "getOriginalDuration", "()I", CallIntMethod)
+  }
+
+  pub fn get_duration(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "getDuration", "()I", CallIntMethod)
+  }
+
+  pub fn set_duration(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/event/world/ChangeWorldWeatherEvent", self.object, "setDuration", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConstructPortalEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConstructPortalEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConstructPortalEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConstructPortalEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ConstructPortalEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_portal_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ConstructPortalEvent", self.object, "getPortalLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ConstructPortalEvent#getPortalLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ConstructWorldPropertiesEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ConstructWorldPropertiesEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ConstructWorldPropertiesEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ConstructWorldPropertiesEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ConstructWorldPropertiesEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_properties(&self) -> ::sponge::world::storage::WorldProperties {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ConstructWorldPropertiesEvent", self.object, "getWorldProperties", "()Lorg/spongepowered/api/world/storage/WorldProperties;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ConstructWorldPropertiesEvent#getWorldProperties was null") }
+    ::sponge::world::storage::WorldProperties { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_archetype(&self) -> ::sponge::world::WorldArchetype {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ConstructWorldPropertiesEvent", self.object, "getWorldArchetype", "()Lorg/spongepowered/api/world/WorldArchetype;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ConstructWorldPropertiesEvent#getWorldArchetype was null") }
+    ::sponge::world::WorldArchetype { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExplosionEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExplosionEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExplosionEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExplosionEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ExplosionEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_explosion(&self) -> ::sponge::world::explosion::Explosion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/ExplosionEvent", self.object, "getExplosion", "()Lorg/spongepowered/api/world/explosion/Explosion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/ExplosionEvent#getExplosion was null") }
+    ::sponge::world::explosion::Explosion { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GenerateChunkEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GenerateChunkEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GenerateChunkEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GenerateChunkEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/GenerateChunkEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LoadWorldEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LoadWorldEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LoadWorldEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LoadWorldEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/LoadWorldEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SaveWorldEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SaveWorldEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SaveWorldEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SaveWorldEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/SaveWorldEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TargetWorldEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TargetWorldEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TargetWorldEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetWorldEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/TargetWorldEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_target_world(&self) -> ::sponge::world::World {
+    let ret = bound_method!(self.env, "org/spongepowered/api/event/world/TargetWorldEvent", self.object, "getTargetWorld", "()Lorg/spongepowered/api/world/World;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/event/world/TargetWorldEvent#getTargetWorld was null") }
+    ::sponge::world::World { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnloadWorldEvent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnloadWorldEvent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnloadWorldEvent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnloadWorldEvent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/UnloadWorldEvent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod explosion_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Detonate {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Detonate {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Detonate {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Detonate`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ExplosionEvent$Detonate", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ExplosionEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/ExplosionEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "world")]
+    pub fn set_explosion(&self, explosion: ::sponge::world::explosion::Explosion) {
+      bound_method!(self.env, "org/spongepowered/api/event/world/ExplosionEvent$Pre", self.object, "setExplosion", "(Lorg/spongepowered/api/world/explosion/Explosion;)V", CallVoidMethodA, explosion.object);
+    }
+
+  }
+}
+
+pub mod generate_chunk_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/GenerateChunkEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/GenerateChunkEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod save_world_event {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Post {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Post {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Post {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Post`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/SaveWorldEvent$Post", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Pre {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Pre {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Pre {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pre`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/event/world/SaveWorldEvent$Pre", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
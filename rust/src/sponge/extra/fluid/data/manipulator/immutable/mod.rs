@@ -0,0 +1,69 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ImmutableFluidItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFluidItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFluidItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFluidItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fluid(&self) -> ::sponge::data::value::immutable::ImmutableValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidItemData", self.object, "fluid", "()Lorg/spongepowered/api/data/value/immutable/ImmutableValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidItemData#fluid was null") }
+    ::sponge::data::value::immutable::ImmutableValue { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableFluidTankData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableFluidTankData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableFluidTankData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableFluidTankData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidTankData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fluids(&self) -> ::sponge::data::value::immutable::ImmutableMapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidTankData", self.object, "fluids", "()Lorg/spongepowered/api/data/value/immutable/ImmutableMapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidTankData#fluids was null") }
+    ::sponge::data::value::immutable::ImmutableMapValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn fluid_at_direction(&self, direction: ::sponge::util::Direction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidTankData", self.object, "fluidAtDirection", "(Lorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/immutable/ImmutableFluidTankData#fluidAtDirection was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
@@ -0,0 +1,69 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct FluidItemData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidItemData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidItemData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidItemData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidItemData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fluid(&self) -> ::sponge::data::value::mutable::Value {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidItemData", self.object, "fluid", "()Lorg/spongepowered/api/data/value/mutable/Value;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidItemData#fluid was null") }
+    ::sponge::data::value::mutable::Value { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidTankData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidTankData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidTankData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidTankData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidTankData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn fluids(&self) -> ::sponge::data::value::mutable::MapValue {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidTankData", self.object, "fluids", "()Lorg/spongepowered/api/data/value/mutable/MapValue;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidTankData#fluids was null") }
+    ::sponge::data::value::mutable::MapValue { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn fluid_at_direction(&self, direction: ::sponge::util::Direction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidTankData", self.object, "fluidAtDirection", "(Lorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/manipulator/mutable/FluidTankData#fluidAtDirection was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
@@ -0,0 +1,73 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct FluidTemperatureProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidTemperatureProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidTemperatureProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FluidTemperatureProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/property/FluidTemperatureProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::extra::fluid::data::property::FluidTemperatureProperty {
+    let ret = new_object!(env, "org/spongepowered/api/extra/fluid/data/property/FluidTemperatureProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/property/FluidTemperatureProperty#<init> was null") }
+    ::sponge::extra::fluid::data::property::FluidTemperatureProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::extra::fluid::data::property::FluidTemperatureProperty {
+    let ret = new_object!(env, "org/spongepowered/api/extra/fluid/data/property/FluidTemperatureProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/property/FluidTemperatureProperty#<init> was null") }
+    ::sponge::extra::fluid::data::property::FluidTemperatureProperty { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidViscosityProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidViscosityProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidViscosityProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FluidViscosityProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/data/property/FluidViscosityProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::extra::fluid::data::property::FluidViscosityProperty {
+    let ret = new_object!(env, "org/spongepowered/api/extra/fluid/data/property/FluidViscosityProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/property/FluidViscosityProperty#<init> was null") }
+    ::sponge::extra::fluid::data::property::FluidViscosityProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::extra::fluid::data::property::FluidViscosityProperty {
+    let ret = new_object!(env, "org/spongepowered/api/extra/fluid/data/property/FluidViscosityProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/data/property/FluidViscosityProperty#<init> was null") }
+    ::sponge::extra::fluid::data::property::FluidViscosityProperty { env: env, object: ret }
+  }
+
+}
@@ -0,0 +1,275 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod data;
+
+#[derive(Debug)]
+pub struct FluidStack {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidStack {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidStack {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidStack`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidStack", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::extra::fluid::fluid_stack::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/extra/fluid/FluidStack", "builder", "()Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack#builder was null") }
+    ::sponge::extra::fluid::fluid_stack::Builder { env: env, object: ret }
+  }
+
+  pub fn get_fluid(&self) -> ::sponge::extra::fluid::FluidType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack", self.object, "getFluid", "()Lorg/spongepowered/api/extra/fluid/FluidType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack#getFluid was null") }
+    ::sponge::extra::fluid::FluidType { env: self.env, object: ret }
+  }
+
+  pub fn get_volume(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack", self.object, "getVolume", "()I", CallIntMethod)
+  }
+
+  pub fn set_volume(&self, value: i32) -> ::sponge::extra::fluid::FluidStack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack", self.object, "setVolume", "(I)Lorg/spongepowered/api/extra/fluid/FluidStack;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack#setVolume was null") }
+    ::sponge::extra::fluid::FluidStack { env: self.env, object: ret }
+  }
+
+  pub fn create_snapshot(&self) -> ::sponge::extra::fluid::FluidStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack", self.object, "createSnapshot", "()Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack#createSnapshot was null") }
+    ::sponge::extra::fluid::FluidStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidStackSnapshot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidStackSnapshot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidStackSnapshot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidStackSnapshot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidStackSnapshot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::extra::fluid::fluid_stack_snapshot::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot", "builder", "()Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot#builder was null") }
+    ::sponge::extra::fluid::fluid_stack_snapshot::Builder { env: env, object: ret }
+  }
+
+  pub fn get_fluid(&self) -> ::sponge::extra::fluid::FluidType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot", self.object, "getFluid", "()Lorg/spongepowered/api/extra/fluid/FluidType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot#getFluid was null") }
+    ::sponge::extra::fluid::FluidType { env: self.env, object: ret }
+  }
+
+  pub fn get_volume(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot", self.object, "getVolume", "()I", CallIntMethod)
+  }
+
+  pub fn create_stack(&self) -> ::sponge::extra::fluid::FluidStack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot", self.object, "createStack", "()Lorg/spongepowered/api/extra/fluid/FluidStack;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot#createStack was null") }
+    ::sponge::extra::fluid::FluidStack { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_type_base(&self) -> Option<::sponge::block::BlockType> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidType", self.object, "getBlockTypeBase", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidType#getBlockTypeBase was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::BlockType { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FluidTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FluidTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FluidTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FluidTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod fluid_stack {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidStack$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn fluid(&self, fluid_type: ::sponge::extra::fluid::FluidType) -> ::sponge::extra::fluid::fluid_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "fluid", "(Lorg/spongepowered/api/extra/fluid/FluidType;)Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallObjectMethodA, fluid_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#fluid was null") }
+      ::sponge::extra::fluid::fluid_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn volume(&self, value: i32) -> ::sponge::extra::fluid::fluid_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "volume", "(I)Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#volume was null") }
+      ::sponge::extra::fluid::fluid_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_fluid_stack_snapshot(&self, fluid_stack_snapshot: ::sponge::extra::fluid::FluidStackSnapshot) -> ::sponge::extra::fluid::fluid_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "from", "(Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot;)Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallObjectMethodA, fluid_stack_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#from was null") }
+      ::sponge::extra::fluid::fluid_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::extra::fluid::FluidStack {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "build", "()Lorg/spongepowered/api/extra/fluid/FluidStack;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#build was null") }
+      ::sponge::extra::fluid::FluidStack { env: self.env, object: ret }
+    }
+
+    pub fn from_fluid_stack(&self, fluid_stack: ::sponge::extra::fluid::FluidStack) -> ::sponge::extra::fluid::fluid_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "from", "(Lorg/spongepowered/api/extra/fluid/FluidStack;)Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallObjectMethodA, fluid_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#from was null") }
+      ::sponge::extra::fluid::fluid_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::extra::fluid::fluid_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "reset", "()Lorg/spongepowered/api/extra/fluid/FluidStack$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#reset was null") }
+      ::sponge::extra::fluid::fluid_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#from was null") }
+      ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStack$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStack$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod fluid_stack_snapshot {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn fluid(&self, fluid_type: ::sponge::extra::fluid::FluidType) -> ::sponge::extra::fluid::fluid_stack_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder", self.object, "fluid", "(Lorg/spongepowered/api/extra/fluid/FluidType;)Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder;", CallObjectMethodA, fluid_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder#fluid was null") }
+      ::sponge::extra::fluid::fluid_stack_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn volume(&self, value: i32) -> ::sponge::extra::fluid::fluid_stack_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder", self.object, "volume", "(I)Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder#volume was null") }
+      ::sponge::extra::fluid::fluid_stack_snapshot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_fluid_stack(&self, fluid_stack: ::sponge::extra::fluid::FluidStack) -> ::sponge::extra::fluid::fluid_stack_snapshot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder", self.object, "from", "(Lorg/spongepowered/api/extra/fluid/FluidStack;)Lorg/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder;", CallObjectMethodA, fluid_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/extra/fluid/FluidStackSnapshot$Builder#from was null") }
+      ::sponge::extra::fluid::fluid_stack_snapshot::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,47 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct VoidWorldGeneratorModifier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VoidWorldGeneratorModifier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VoidWorldGeneratorModifier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `VoidWorldGeneratorModifier`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::empty::VoidWorldGeneratorModifier {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier#<init> was null") }
+    ::sponge::extra::modifier::empty::VoidWorldGeneratorModifier { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "world"))]
+  pub fn modify_world_generator(&self, world_properties: ::sponge::world::storage::WorldProperties, data_container: ::sponge::data::DataContainer, world_generator: ::sponge::world::gen::WorldGenerator) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier", self.object, "modifyWorldGenerator", "(Lorg/spongepowered/api/world/storage/WorldProperties;Lorg/spongepowered/api/data/DataContainer;Lorg/spongepowered/api/world/gen/WorldGenerator;)V", CallVoidMethodA, world_properties.object, data_container.object, world_generator.object);
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier#getId was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/empty/VoidWorldGeneratorModifier#getName was null") }
+    ret
+  }
+
+}
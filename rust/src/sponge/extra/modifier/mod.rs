@@ -0,0 +1,4 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod empty;
+pub mod skylands;
@@ -0,0 +1,395 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct SkylandsBiomeGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsBiomeGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsBiomeGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkylandsBiomeGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsBiomeGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::skylands::SkylandsBiomeGenerator {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/skylands/SkylandsBiomeGenerator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsBiomeGenerator#<init> was null") }
+    ::sponge::extra::modifier::skylands::SkylandsBiomeGenerator { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn generate_biomes(&self, mutable_biome_area: ::sponge::world::extent::MutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsBiomeGenerator", self.object, "generateBiomes", "(Lorg/spongepowered/api/world/extent/MutableBiomeArea;)V", CallVoidMethodA, mutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkylandsGrassPopulator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsGrassPopulator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsGrassPopulator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkylandsGrassPopulator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::skylands::SkylandsGrassPopulator {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator#<init> was null") }
+    ::sponge::extra::modifier::skylands::SkylandsGrassPopulator { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn populate(&self, world: ::sponge::world::World, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume, immutable_biome_area: ::sponge::world::extent::ImmutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator", self.object, "populate", "(Lorg/spongepowered/api/world/World;Lorg/spongepowered/api/world/extent/MutableBlockVolume;Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;)V", CallVoidMethodA, world.object, mutable_block_volume.object, immutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkylandsGroundCoverPopulator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsGroundCoverPopulator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsGroundCoverPopulator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkylandsGroundCoverPopulator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::skylands::SkylandsGroundCoverPopulator {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator#<init> was null") }
+    ::sponge::extra::modifier::skylands::SkylandsGroundCoverPopulator { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn populate(&self, world: ::sponge::world::World, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume, immutable_biome_area: ::sponge::world::extent::ImmutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator", self.object, "populate", "(Lorg/spongepowered/api/world/World;Lorg/spongepowered/api/world/extent/MutableBlockVolume;Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;)V", CallVoidMethodA, world.object, mutable_block_volume.object, immutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkylandsTerrainGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsTerrainGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsTerrainGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkylandsTerrainGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::skylands::SkylandsTerrainGenerator {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator#<init> was null") }
+    ::sponge::extra::modifier::skylands::SkylandsTerrainGenerator { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn populate(&self, world: ::sponge::world::World, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume, immutable_biome_area: ::sponge::world::extent::ImmutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator", self.object, "populate", "(Lorg/spongepowered/api/world/World;Lorg/spongepowered/api/world/extent/MutableBlockVolume;Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;)V", CallVoidMethodA, world.object, mutable_block_volume.object, immutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkylandsUtil {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsUtil {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsUtil {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SkylandsUtil`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsUtil", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SkylandsWorldGeneratorModifier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SkylandsWorldGeneratorModifier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SkylandsWorldGeneratorModifier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SkylandsWorldGeneratorModifier`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::extra::modifier::skylands::SkylandsWorldGeneratorModifier {
+    let ret = new_object!(env, "org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier#<init> was null") }
+    ::sponge::extra::modifier::skylands::SkylandsWorldGeneratorModifier { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "world"))]
+  pub fn modify_world_generator(&self, world_properties: ::sponge::world::storage::WorldProperties, data_container: ::sponge::data::DataContainer, world_generator: ::sponge::world::gen::WorldGenerator) {
+    bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier", self.object, "modifyWorldGenerator", "(Lorg/spongepowered/api/world/storage/WorldProperties;Lorg/spongepowered/api/data/DataContainer;Lorg/spongepowered/api/world/gen/WorldGenerator;)V", CallVoidMethodA, world_properties.object, data_container.object, world_generator.object);
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier#getId was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/extra/modifier/skylands/SkylandsWorldGeneratorModifier#getName was null") }
+    ret
+  }
+
+}
+
+pub mod skylands_grass_populator {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Flower {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Flower {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Flower {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Flower`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator$Flower", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct RarityCurve {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl RarityCurve {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      RarityCurve {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `RarityCurve`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator$RarityCurve", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_source_module_count(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator$RarityCurve", self.object, "getSourceModuleCount", "()I", CallIntMethod)
+    }
+
+    pub fn get_value(&self, value: f64, value_2: f64, value_3: f64) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsGrassPopulator$RarityCurve", self.object, "getValue", "(DDD)D", CallDoubleMethodA, value, value_2, value_3)
+    }
+
+  }
+}
+
+pub mod skylands_ground_cover_populator {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct GroundCoverLayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl GroundCoverLayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      GroundCoverLayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `GroundCoverLayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator$GroundCoverLayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct UniformGroundCoverLayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl UniformGroundCoverLayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      UniformGroundCoverLayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `UniformGroundCoverLayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator$UniformGroundCoverLayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct VariableGroundCoverLayer {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl VariableGroundCoverLayer {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      VariableGroundCoverLayer {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `VariableGroundCoverLayer`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsGroundCoverPopulator$VariableGroundCoverLayer", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod skylands_terrain_generator {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct OreNoise {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OreNoise {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OreNoise {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OreNoise`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator$OreNoise", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct VerticalScaling {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl VerticalScaling {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      VerticalScaling {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `VerticalScaling`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator$VerticalScaling", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_source_module_count(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator$VerticalScaling", self.object, "getSourceModuleCount", "()I", CallIntMethod)
+    }
+
+    pub fn get_value(&self, value: f64, value_2: f64, value_3: f64) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/extra/modifier/skylands/SkylandsTerrainGenerator$VerticalScaling", self.object, "getValue", "(DDD)D", CallDoubleMethodA, value, value_2, value_3)
+    }
+
+  }
+}
@@ -0,0 +1,66 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CraftingInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CraftingInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CraftingInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CraftingInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/crafting/CraftingInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_crafting_grid(&self) -> ::sponge::item::inventory::type_::GridInventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/crafting/CraftingInventory", self.object, "getCraftingGrid", "()Lorg/spongepowered/api/item/inventory/type/GridInventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/crafting/CraftingInventory#getCraftingGrid was null") }
+    ::sponge::item::inventory::type_::GridInventory { env: self.env, object: ret }
+  }
+
+  pub fn get_result(&self) -> ::sponge::item::inventory::crafting::CraftingOutput {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/crafting/CraftingInventory", self.object, "getResult", "()Lorg/spongepowered/api/item/inventory/crafting/CraftingOutput;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/crafting/CraftingInventory#getResult was null") }
+    ::sponge::item::inventory::crafting::CraftingOutput { env: self.env, object: ret }
+  }
+
+  pub fn get_recipe(&self) -> Option<::sponge::item::recipe::Recipe> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/crafting/CraftingInventory", self.object, "getRecipe", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/crafting/CraftingInventory#getRecipe was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::recipe::Recipe { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CraftingOutput {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CraftingOutput {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CraftingOutput {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CraftingOutput`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/crafting/CraftingOutput", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,76 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CustomInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CustomInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CustomInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CustomInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/custom/CustomInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::inventory::custom::custom_inventory::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/custom/CustomInventory", "builder", "()Lorg/spongepowered/api/item/inventory/custom/CustomInventory$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/custom/CustomInventory#builder was null") }
+    ::sponge::item::inventory::custom::custom_inventory::Builder { env: env, object: ret }
+  }
+
+}
+
+pub mod custom_inventory {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/custom/CustomInventory$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn name(&self, translation: ::sponge::text::translation::Translation) -> ::sponge::item::inventory::custom::custom_inventory::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/custom/CustomInventory$Builder", self.object, "name", "(Lorg/spongepowered/api/text/translation/Translation;)Lorg/spongepowered/api/item/inventory/custom/CustomInventory$Builder;", CallObjectMethodA, translation.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/custom/CustomInventory$Builder#name was null") }
+      ::sponge::item::inventory::custom::custom_inventory::Builder { env: self.env, object: ret }
+    }
+
+    pub fn size(&self, value: i32) -> ::sponge::item::inventory::custom::custom_inventory::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/custom/CustomInventory$Builder", self.object, "size", "(I)Lorg/spongepowered/api/item/inventory/custom/CustomInventory$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/custom/CustomInventory$Builder#size was null") }
+      ::sponge::item::inventory::custom::custom_inventory::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::inventory::custom::CustomInventory {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/custom/CustomInventory$Builder", self.object, "build", "()Lorg/spongepowered/api/item/inventory/custom/CustomInventory;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/custom/CustomInventory$Builder#build was null") }
+      ::sponge::item::inventory::custom::CustomInventory { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,61 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Hotbar {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Hotbar {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Hotbar {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Hotbar`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/entity/Hotbar", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_selected_slot_index(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/entity/Hotbar", self.object, "getSelectedSlotIndex", "()I", CallIntMethod)
+  }
+
+  pub fn set_selected_slot_index(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/entity/Hotbar", self.object, "setSelectedSlotIndex", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HumanInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HumanInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HumanInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HumanInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/entity/HumanInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_hotbar(&self) -> ::sponge::item::inventory::entity::Hotbar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/entity/HumanInventory", self.object, "getHotbar", "()Lorg/spongepowered/api/item/inventory/entity/Hotbar;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/entity/HumanInventory#getHotbar was null") }
+    ::sponge::item::inventory::entity::Hotbar { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,175 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct EquipmentInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EquipmentInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn poll_equipment_slot_type(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;)Ljava/util/Optional;", CallObjectMethodA, equipment_slot_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_equipment_slot_type_i32(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;I)Ljava/util/Optional;", CallObjectMethodA, equipment_slot_type.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_equipment_type(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_equipment_type_i32(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;I)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_equipment_slot_type(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;)Ljava/util/Optional;", CallObjectMethodA, equipment_slot_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_equipment_slot_type_i32(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;I)Ljava/util/Optional;", CallObjectMethodA, equipment_slot_type.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_equipment_type(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_equipment_type_i32(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;I)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set_equipment_slot_type_item_stack(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "set", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, equipment_slot_type.object, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn set_equipment_type_item_stack(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "set", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, equipment_type.object, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_slot_equipment_slot_type(&self, equipment_slot_type: ::sponge::item::inventory::property::EquipmentSlotType) -> Option<::sponge::item::inventory::Slot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "getSlot", "(Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;)Ljava/util/Optional;", CallObjectMethodA, equipment_slot_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#getSlot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Slot { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_slot_equipment_type(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> Option<::sponge::item::inventory::Slot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/equipment/EquipmentInventory", self.object, "getSlot", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Ljava/util/Optional;", CallObjectMethodA, equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/equipment/EquipmentInventory#getSlot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Slot { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EquipmentType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EquipmentType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/equipment/EquipmentType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EquipmentTypeWorn {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentTypeWorn {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentTypeWorn {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EquipmentTypeWorn`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/equipment/EquipmentTypeWorn", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EquipmentTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EquipmentTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/equipment/EquipmentTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,977 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod crafting;
+pub mod custom;
+pub mod entity;
+pub mod equipment;
+pub mod property;
+pub mod slot;
+pub mod transaction;
+pub mod type_;
+
+#[derive(Debug)]
+pub struct Carrier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Carrier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Carrier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Carrier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/Carrier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_inventory(&self) -> ::sponge::item::inventory::type_::CarriedInventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Carrier", self.object, "getInventory", "()Lorg/spongepowered/api/item/inventory/type/CarriedInventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Carrier#getInventory was null") }
+    ::sponge::item::inventory::type_::CarriedInventory { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Container {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Container {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Container {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Container`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/Container", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_viewers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Container", self.object, "getViewers", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Container#getViewers was null") }
+    ret
+  }
+
+  pub fn has_viewers(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Container", self.object, "hasViewers", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn open(&self, player: ::sponge::entity::living::player::Player) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Container", self.object, "open", "(Lorg/spongepowered/api/entity/living/player/Player;)V", CallVoidMethodA, player.object);
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn close(&self, player: ::sponge::entity::living::player::Player) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Container", self.object, "close", "(Lorg/spongepowered/api/entity/living/player/Player;)V", CallVoidMethodA, player.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EmptyInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EmptyInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EmptyInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EmptyInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/EmptyInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Inventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Inventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Inventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Inventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/Inventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn parent(&self) -> ::sponge::item::inventory::Inventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "parent", "()Lorg/spongepowered/api/item/inventory/Inventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#parent was null") }
+    ::sponge::item::inventory::Inventory { env: self.env, object: ret }
+  }
+
+  pub fn slots(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "slots", "()Ljava/lang/Iterable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#slots was null") }
+    ret
+  }
+
+  pub fn first(&self) -> ::sponge::item::inventory::Inventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "first", "()Lorg/spongepowered/api/item/inventory/Inventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#first was null") }
+    ::sponge::item::inventory::Inventory { env: self.env, object: ret }
+  }
+
+  pub fn next(&self) -> ::sponge::item::inventory::Inventory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "next", "()Lorg/spongepowered/api/item/inventory/Inventory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#next was null") }
+    ::sponge::item::inventory::Inventory { env: self.env, object: ret }
+  }
+
+  pub fn poll(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "poll", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_i32(&self, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "poll", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek(&self) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "peek", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_i32(&self, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "peek", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn offer(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "offer", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#offer was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn set(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "set", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn total_items(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "totalItems", "()I", CallIntMethod)
+  }
+
+  pub fn capacity(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "capacity", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn contains_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "contains", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn contains_item_type(&self, item_type: ::sponge::item::ItemType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "contains", "(Lorg/spongepowered/api/item/ItemType;)Z", CallBooleanMethodA, item_type.object) != 0
+  }
+
+  pub fn get_max_stack_size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "getMaxStackSize", "()I", CallIntMethod)
+  }
+
+  pub fn set_max_stack_size(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "setMaxStackSize", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_properties_inventory_class(&self, inventory: ::sponge::item::inventory::Inventory, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "getProperties", "(Lorg/spongepowered/api/item/inventory/Inventory;Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, inventory.object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#getProperties was null") }
+    ret
+  }
+
+  pub fn get_properties_class(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "getProperties", "(Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#getProperties was null") }
+    ret
+  }
+
+  pub fn get_property_inventory_class_object(&self, inventory: ::sponge::item::inventory::Inventory, class: jobject, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "getProperty", "(Lorg/spongepowered/api/item/inventory/Inventory;Ljava/lang/Class;Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, inventory.object, class, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_property_class_object(&self, class: jobject, object: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/Inventory", self.object, "getProperty", "(Ljava/lang/Class;Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, class, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/Inventory#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn query_object_array(&self, objects: Vec<jobject>) -> ::sponge::item::inventory::Inventory {
+    unimplemented!();
+  }
+  pub fn query_item_type_array(&self, item_types: Vec<::sponge::item::ItemType>) -> ::sponge::item::inventory::Inventory {
+    unimplemented!();
+  }
+  pub fn query_item_stack_array(&self, item_stacks: Vec<::sponge::item::inventory::ItemStack>) -> ::sponge::item::inventory::Inventory {
+    unimplemented!();
+  }
+  pub fn query_inventory_property_array(&self, inventory_properties: Vec<::sponge::item::inventory::InventoryProperty>) -> ::sponge::item::inventory::Inventory {
+    unimplemented!();
+  }
+  #[cfg(feature = "text")]
+  pub fn query_translation_array(&self, translations: Vec<::sponge::text::translation::Translation>) -> ::sponge::item::inventory::Inventory {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct InventoryProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventoryProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventoryProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InventoryProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/InventoryProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStack {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStack {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStack {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStack`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStack", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::inventory::item_stack::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStack", "builder", "()Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack#builder was null") }
+    ::sponge::item::inventory::item_stack::Builder { env: env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, item_type: ::sponge::item::ItemType, value: i32) -> ::sponge::item::inventory::ItemStack {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStack", "of", "(Lorg/spongepowered/api/item/ItemType;I)Lorg/spongepowered/api/item/inventory/ItemStack;", CallStaticObjectMethodA, item_type.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack#of was null") }
+    ::sponge::item::inventory::ItemStack { env: env, object: ret }
+  }
+
+  pub fn get_item(&self) -> ::sponge::item::ItemType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "getItem", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack#getItem was null") }
+    ::sponge::item::ItemType { env: self.env, object: ret }
+  }
+
+  pub fn get_quantity(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "getQuantity", "()I", CallIntMethod)
+  }
+
+  pub fn set_quantity(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "setQuantity", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_max_stack_quantity(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "getMaxStackQuantity", "()I", CallIntMethod)
+  }
+
+  pub fn create_snapshot(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "createSnapshot", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack#createSnapshot was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+  pub fn equal_to(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "equalTo", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn copy(&self) -> ::sponge::item::inventory::ItemStack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack", self.object, "copy", "()Lorg/spongepowered/api/item/inventory/ItemStack;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack#copy was null") }
+    ::sponge::item::inventory::ItemStack { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStackBuilderPopulators {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStackBuilderPopulators {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStackBuilderPopulators {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStackBuilderPopulators`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn item_stack(env: *mut JNIEnv, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "itemStack", "(Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, item_stack_snapshot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#itemStack was null") }
+    ret
+  }
+
+  pub fn item_stacks(env: *mut JNIEnv, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, item_stack_snapshots: Vec<::sponge::item::inventory::ItemStackSnapshot>) -> jobject {
+    unimplemented!();
+  }
+  pub fn item_item_type(env: *mut JNIEnv, item_type: ::sponge::item::ItemType) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "item", "(Lorg/spongepowered/api/item/ItemType;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, item_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#item was null") }
+    ret
+  }
+
+  pub fn item_supplier(env: *mut JNIEnv, supplier: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "item", "(Ljava/util/function/Supplier;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, supplier);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#item was null") }
+    ret
+  }
+
+  pub fn items_item_type_item_type_array(env: *mut JNIEnv, item_type: ::sponge::item::ItemType, item_types: Vec<::sponge::item::ItemType>) -> jobject {
+    unimplemented!();
+  }
+  pub fn items_collection(env: *mut JNIEnv, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "items", "(Ljava/util/Collection;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#items was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn quantity_variable_amount(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "quantity", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#quantity was null") }
+    ret
+  }
+
+  pub fn quantity_supplier(env: *mut JNIEnv, supplier: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "quantity", "(Ljava/util/function/Supplier;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, supplier);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#quantity was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn key_value(env: *mut JNIEnv, key: ::sponge::data::key::Key, object: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "keyValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#keyValue was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn key_values(env: *mut JNIEnv, key: ::sponge::data::key::Key, iterable: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "keyValues", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Iterable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#keyValues was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn list_values_key_list_variable_amount(env: *mut JNIEnv, key: ::sponge::data::key::Key, list: jobject, variable_amount: ::sponge::util::weighted::VariableAmount) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "listValues", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/List;Lorg/spongepowered/api/util/weighted/VariableAmount;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, list, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#listValues was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn list_values_key_list(env: *mut JNIEnv, key: ::sponge::data::key::Key, list: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "listValues", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/List;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, list);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#listValues was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn list_values_key_weighted_table(env: *mut JNIEnv, key: ::sponge::data::key::Key, weighted_table: ::sponge::util::weighted::WeightedTable) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "listValues", "(Lorg/spongepowered/api/data/key/Key;Lorg/spongepowered/api/util/weighted/WeightedTable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, weighted_table.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#listValues was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn list_value_suppliers(env: *mut JNIEnv, key: ::sponge::data::key::Key, weighted_table: ::sponge::util::weighted::WeightedTable) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "listValueSuppliers", "(Lorg/spongepowered/api/data/key/Key;Lorg/spongepowered/api/util/weighted/WeightedTable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, weighted_table.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#listValueSuppliers was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_values_key_set(env: *mut JNIEnv, key: ::sponge::data::key::Key, set: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "setValues", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Set;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#setValues was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn set_values_key_set_variable_amount(env: *mut JNIEnv, key: ::sponge::data::key::Key, set: jobject, variable_amount: ::sponge::util::weighted::VariableAmount) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "setValues", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/Set;Lorg/spongepowered/api/util/weighted/VariableAmount;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, set, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#setValues was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn set_values_key_weighted_table(env: *mut JNIEnv, key: ::sponge::data::key::Key, weighted_table: ::sponge::util::weighted::WeightedTable) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "setValues", "(Lorg/spongepowered/api/data/key/Key;Lorg/spongepowered/api/util/weighted/WeightedTable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, key.object, weighted_table.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#setValues was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn value(env: *mut JNIEnv, base_value: ::sponge::data::value::BaseValue) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "value", "(Lorg/spongepowered/api/data/value/BaseValue;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#value was null") }
+    ret
+  }
+
+  pub fn values(env: *mut JNIEnv, iterable: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "values", "(Ljava/lang/Iterable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#values was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn data_data_manipulator(env: *mut JNIEnv, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "data", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#data was null") }
+    ret
+  }
+
+  pub fn data_collection(env: *mut JNIEnv, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "data", "(Ljava/util/Collection;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#data was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn data_collection_variable_amount(env: *mut JNIEnv, collection: jobject, variable_amount: ::sponge::util::weighted::VariableAmount) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "data", "(Ljava/util/Collection;Lorg/spongepowered/api/util/weighted/VariableAmount;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, collection, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#data was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn data_weighted_table(env: *mut JNIEnv, weighted_table: ::sponge::util::weighted::WeightedTable) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "data", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, weighted_table.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#data was null") }
+    ret
+  }
+
+  pub fn enchantment_enchantment(env: *mut JNIEnv, enchantment: ::sponge::item::Enchantment) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "enchantment", "(Lorg/spongepowered/api/item/Enchantment;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, enchantment.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#enchantment was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn enchantment_variable_amount_enchantment(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount, enchantment: ::sponge::item::Enchantment) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "enchantment", "(Lorg/spongepowered/api/util/weighted/VariableAmount;Lorg/spongepowered/api/item/Enchantment;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, variable_amount.object, enchantment.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#enchantment was null") }
+    ret
+  }
+
+  pub fn enchantments_with_vanilla_level_variance_collection(env: *mut JNIEnv, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "enchantmentsWithVanillaLevelVariance", "(Ljava/util/Collection;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#enchantmentsWithVanillaLevelVariance was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn enchantments_with_vanilla_level_variance_variable_amount_enchantment_enchantment_array(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount, enchantment: ::sponge::item::Enchantment, enchantments: Vec<::sponge::item::Enchantment>) -> jobject {
+    unimplemented!();
+  }
+  #[cfg(feature = "util")]
+  pub fn enchantments_with_vanilla_level_variance_variable_amount_collection(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "enchantmentsWithVanillaLevelVariance", "(Lorg/spongepowered/api/util/weighted/VariableAmount;Ljava/util/Collection;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, variable_amount.object, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#enchantmentsWithVanillaLevelVariance was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn enchantments(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackBuilderPopulators", "enchantments", "(Lorg/spongepowered/api/util/weighted/VariableAmount;Ljava/util/Collection;)Ljava/util/function/BiConsumer;", CallStaticObjectMethodA, variable_amount.object, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackBuilderPopulators#enchantments was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStackComparators {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStackComparators {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStackComparators {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStackComparators`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackComparators", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStackGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStackGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStackGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStackGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::inventory::item_stack_generator::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/ItemStackGenerator", "builder", "()Lorg/spongepowered/api/item/inventory/ItemStackGenerator$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackGenerator#builder was null") }
+    ::sponge::item::inventory::item_stack_generator::Builder { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStackSnapshot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStackSnapshot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStackSnapshot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStackSnapshot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackSnapshot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::item::ItemType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackSnapshot", self.object, "getType", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackSnapshot#getType was null") }
+    ::sponge::item::ItemType { env: self.env, object: ret }
+  }
+
+  pub fn get_count(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackSnapshot", self.object, "getCount", "()I", CallIntMethod)
+  }
+
+  pub fn create_stack(&self) -> ::sponge::item::inventory::ItemStack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackSnapshot", self.object, "createStack", "()Lorg/spongepowered/api/item/inventory/ItemStack;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackSnapshot#createStack was null") }
+    ::sponge::item::inventory::ItemStack { env: self.env, object: ret }
+  }
+
+  pub fn create_game_dictionary_entry(&self) -> ::sponge::game_dictionary::Entry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackSnapshot", self.object, "createGameDictionaryEntry", "()Lorg/spongepowered/api/GameDictionary$Entry;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackSnapshot#createGameDictionaryEntry was null") }
+    ::sponge::game_dictionary::Entry { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Slot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Slot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Slot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Slot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/Slot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_stack_size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/Slot", self.object, "getStackSize", "()I", CallIntMethod)
+  }
+
+}
+
+pub mod item_stack {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStack$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn item_type(&self, item_type: ::sponge::item::ItemType) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "itemType", "(Lorg/spongepowered/api/item/ItemType;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, item_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#itemType was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn quantity(&self, value: i32) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "quantity", "(I)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#quantity was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn key_value(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "keyValue", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, key.object, object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#keyValue was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn item_data_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "itemData", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, data_manipulator.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#itemData was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn item_data_immutable_data_manipulator(&self, immutable_data_manipulator: ::sponge::data::manipulator::ImmutableDataManipulator) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "itemData", "(Lorg/spongepowered/api/data/manipulator/ImmutableDataManipulator;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, immutable_data_manipulator.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#itemData was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn add(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "add", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, key.object, object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#add was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "fromItemStack", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#fromItemStack was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "block")]
+    pub fn from_block_state(&self, block_state: ::sponge::block::BlockState) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "fromBlockState", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#fromBlockState was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn from_container(&self, data_view: ::sponge::data::DataView) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "fromContainer", "(Lorg/spongepowered/api/data/DataView;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, data_view.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#fromContainer was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_snapshot(&self, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "fromSnapshot", "(Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, item_stack_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#fromSnapshot was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "block")]
+    pub fn from_block_snapshot(&self, block_snapshot: ::sponge::block::BlockSnapshot) -> ::sponge::item::inventory::item_stack::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "fromBlockSnapshot", "(Lorg/spongepowered/api/block/BlockSnapshot;)Lorg/spongepowered/api/item/inventory/ItemStack$Builder;", CallObjectMethodA, block_snapshot.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#fromBlockSnapshot was null") }
+      ::sponge::item::inventory::item_stack::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::inventory::ItemStack {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStack$Builder", self.object, "build", "()Lorg/spongepowered/api/item/inventory/ItemStack;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStack$Builder#build was null") }
+      ::sponge::item::inventory::ItemStack { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod item_stack_comparators {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ItemDataComparator {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ItemDataComparator {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ItemDataComparator {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemDataComparator`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackComparators$ItemDataComparator", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn compare_item_stack_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack, item_stack_2: ::sponge::item::inventory::ItemStack) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$ItemDataComparator", self.object, "compare", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/item/inventory/ItemStack;)I", CallIntMethodA, item_stack.object, item_stack_2.object)
+    }
+
+    pub fn compare_object_object(&self, object: jobject, object_2: jobject) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$ItemDataComparator", self.object, "compare", "(Ljava/lang/Object;Ljava/lang/Object;)I", CallIntMethodA, object, object_2)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Properties {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Properties {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Properties {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Properties`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackComparators$Properties", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn compare_item_stack_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack, item_stack_2: ::sponge::item::inventory::ItemStack) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Properties", self.object, "compare", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/item/inventory/ItemStack;)I", CallIntMethodA, item_stack.object, item_stack_2.object)
+    }
+
+    pub fn compare_object_object(&self, object: jobject, object_2: jobject) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Properties", self.object, "compare", "(Ljava/lang/Object;Ljava/lang/Object;)I", CallIntMethodA, object, object_2)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Size {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Size {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Size {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Size`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackComparators$Size", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn compare_item_stack_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack, item_stack_2: ::sponge::item::inventory::ItemStack) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Size", self.object, "compare", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/item/inventory/ItemStack;)I", CallIntMethodA, item_stack.object, item_stack_2.object)
+    }
+
+    pub fn compare_object_object(&self, object: jobject, object_2: jobject) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Size", self.object, "compare", "(Ljava/lang/Object;Ljava/lang/Object;)I", CallIntMethodA, object, object_2)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Type {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Type {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Type {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Type`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackComparators$Type", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn compare_item_stack_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack, item_stack_2: ::sponge::item::inventory::ItemStack) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Type", self.object, "compare", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/item/inventory/ItemStack;)I", CallIntMethodA, item_stack.object, item_stack_2.object)
+    }
+
+    pub fn compare_object_object(&self, object: jobject, object_2: jobject) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackComparators$Type", self.object, "compare", "(Ljava/lang/Object;Ljava/lang/Object;)I", CallIntMethodA, object, object_2)
+    }
+
+  }
+}
+
+pub mod item_stack_generator {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/ItemStackGenerator$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn add(&self, bi_consumer: jobject) -> ::sponge::item::inventory::item_stack_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackGenerator$Builder", self.object, "add", "(Ljava/util/function/BiConsumer;)Lorg/spongepowered/api/item/inventory/ItemStackGenerator$Builder;", CallObjectMethodA, bi_consumer);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackGenerator$Builder#add was null") }
+      ::sponge::item::inventory::item_stack_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_all(&self, collection: jobject) -> ::sponge::item::inventory::item_stack_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackGenerator$Builder", self.object, "addAll", "(Ljava/util/Collection;)Lorg/spongepowered/api/item/inventory/ItemStackGenerator$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackGenerator$Builder#addAll was null") }
+      ::sponge::item::inventory::item_stack_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn base_item(&self, item_type: ::sponge::item::ItemType) -> ::sponge::item::inventory::item_stack_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackGenerator$Builder", self.object, "baseItem", "(Lorg/spongepowered/api/item/ItemType;)Lorg/spongepowered/api/item/inventory/ItemStackGenerator$Builder;", CallObjectMethodA, item_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackGenerator$Builder#baseItem was null") }
+      ::sponge::item::inventory::item_stack_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::inventory::ItemStackGenerator {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/ItemStackGenerator$Builder", self.object, "build", "()Lorg/spongepowered/api/item/inventory/ItemStackGenerator;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/ItemStackGenerator$Builder#build was null") }
+      ::sponge::item::inventory::ItemStackGenerator { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,872 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AbstractInventoryProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractInventoryProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractInventoryProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractInventoryProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "getKey", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AbstractInventoryProperty#getKey was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "getValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AbstractInventoryProperty#getValue was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_operator(&self) -> ::sponge::data::property_::Operator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "getOperator", "()Lorg/spongepowered/api/data/Property$Operator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AbstractInventoryProperty#getOperator was null") }
+    <::sponge::data::property_::Operator as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn matches(&self, property: ::sponge::data::Property) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "matches", "(Lorg/spongepowered/api/data/Property;)Z", CallBooleanMethodA, property.object) != 0
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AbstractInventoryProperty", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AcceptsItems {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AcceptsItems {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AcceptsItems {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AcceptsItems`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/AcceptsItems", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::item::inventory::property::AcceptsItems {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/AcceptsItems", "(Ljava/util/Collection;)V", collection);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AcceptsItems#<init> was null") }
+    ::sponge::item::inventory::property::AcceptsItems { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_collection_operator(env: *mut JNIEnv, collection: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::AcceptsItems {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/AcceptsItems", "(Ljava/util/Collection;Lorg/spongepowered/api/data/Property$Operator;)V", collection, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AcceptsItems#<init> was null") }
+    ::sponge::item::inventory::property::AcceptsItems { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::AcceptsItems {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/AcceptsItems", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/AcceptsItems#<init> was null") }
+    ::sponge::item::inventory::property::AcceptsItems { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AcceptsItems", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AcceptsItems", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn of(env: *mut JNIEnv, objects: Vec<jobject>) -> ::sponge::item::inventory::property::AcceptsItems {
+    unimplemented!();
+  }
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/AcceptsItems", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArmorSlotType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArmorSlotType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArmorSlotType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ArmorSlotType`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/ArmorSlotType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_equipment_type_worn(env: *mut JNIEnv, equipment_type_worn: ::sponge::item::inventory::equipment::EquipmentTypeWorn) -> ::sponge::item::inventory::property::ArmorSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/ArmorSlotType", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentTypeWorn;)V", equipment_type_worn.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/ArmorSlotType#<init> was null") }
+    ::sponge::item::inventory::property::ArmorSlotType { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_equipment_type_worn_operator(env: *mut JNIEnv, equipment_type_worn: ::sponge::item::inventory::equipment::EquipmentTypeWorn, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::ArmorSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/ArmorSlotType", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentTypeWorn;Lorg/spongepowered/api/data/Property$Operator;)V", equipment_type_worn.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/ArmorSlotType#<init> was null") }
+    ::sponge::item::inventory::property::ArmorSlotType { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::ArmorSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/ArmorSlotType", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/ArmorSlotType#<init> was null") }
+    ::sponge::item::inventory::property::ArmorSlotType { env: env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::ArmorSlotType {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/ArmorSlotType", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/ArmorSlotType;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/ArmorSlotType#of was null") }
+    ::sponge::item::inventory::property::ArmorSlotType { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::ArmorSlotType {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/ArmorSlotType", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/ArmorSlotType;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/ArmorSlotType#not was null") }
+    ::sponge::item::inventory::property::ArmorSlotType { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EquipmentSlotType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentSlotType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentSlotType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EquipmentSlotType`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_equipment_type(env: *mut JNIEnv, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> ::sponge::item::inventory::property::EquipmentSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)V", equipment_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/EquipmentSlotType#<init> was null") }
+    ::sponge::item::inventory::property::EquipmentSlotType { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_equipment_type_operator(env: *mut JNIEnv, equipment_type: ::sponge::item::inventory::equipment::EquipmentType, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::EquipmentSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;Lorg/spongepowered/api/data/Property$Operator;)V", equipment_type.object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/EquipmentSlotType#<init> was null") }
+    ::sponge::item::inventory::property::EquipmentSlotType { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::EquipmentSlotType {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/EquipmentSlotType#<init> was null") }
+    ::sponge::item::inventory::property::EquipmentSlotType { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::EquipmentSlotType {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/EquipmentSlotType#of was null") }
+    ::sponge::item::inventory::property::EquipmentSlotType { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::EquipmentSlotType {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/EquipmentSlotType;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/EquipmentSlotType#not was null") }
+    ::sponge::item::inventory::property::EquipmentSlotType { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/EquipmentSlotType", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IntProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IntProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IntProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `IntProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/IntProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#<init> was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#<init> was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#<init> was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/IntProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#of was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#not was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#greaterThan was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#lessThan was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::IntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/IntProperty", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/IntProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/IntProperty#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::IntProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/IntProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InventorySize {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventorySize {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventorySize {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `InventorySize`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/InventorySize", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_vector_2i(env: *mut JNIEnv, vector_2i: ::math::Vector2i) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "(Lcom/flowpowered/math/vector/Vector2i;)V", ::convert::ToJava::to_java(&vector_2i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#<init> was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn new_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "(II)V", value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#<init> was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_vector_2i_operator(env: *mut JNIEnv, vector_2i: ::math::Vector2i, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "(Lcom/flowpowered/math/vector/Vector2i;Lorg/spongepowered/api/data/Property$Operator;)V", ::convert::ToJava::to_java(&vector_2i, env), ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#<init> was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_i32_operator(env: *mut JNIEnv, value: i32, value_2: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "(IILorg/spongepowered/api/data/Property$Operator;)V", value, value_2, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#<init> was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#<init> was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn get_columns(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/InventorySize", self.object, "getColumns", "()I", CallIntMethod)
+  }
+
+  pub fn get_rows(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/InventorySize", self.object, "getRows", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/InventorySize", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of_object(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#of was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn of_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "of", "(II)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#of was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#not was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#greaterThan was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#lessThan was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::InventorySize {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/InventorySize", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/InventorySize;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/InventorySize#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::InventorySize { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/InventorySize", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MappedIntProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MappedIntProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MappedIntProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `MappedIntProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/MappedIntProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_integer_integer(env: *mut JNIEnv, integer: jobject, integer_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "(Ljava/lang/Integer;Ljava/lang/Integer;)V", integer, integer_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#<init> was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_integer_integer_operator(env: *mut JNIEnv, integer: jobject, integer_2: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "(Ljava/lang/Integer;Ljava/lang/Integer;Lorg/spongepowered/api/data/Property$Operator;)V", integer, integer_2, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#<init> was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_object_operator(env: *mut JNIEnv, object: jobject, object_2: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "(Ljava/lang/Object;Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, object_2, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#<init> was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "of", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#of was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "not", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#not was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "greaterThan", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#greaterThan was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "greaterThanOrEqual", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "lessThan", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#lessThan was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::item::inventory::property::MappedIntProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", "lessThanOrEqual", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/MappedIntProperty;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/MappedIntProperty#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::MappedIntProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/MappedIntProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlotIndex {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlotIndex {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlotIndex {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SlotIndex`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/SlotIndex", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#<init> was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_operator(env: *mut JNIEnv, value: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "(ILorg/spongepowered/api/data/Property$Operator;)V", value, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#<init> was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#<init> was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#of was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#not was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#greaterThan was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#lessThan was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotIndex {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotIndex", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotIndex;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotIndex#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::SlotIndex { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlotPos {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlotPos {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlotPos {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SlotPos`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/SlotPos", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_vector_2i(env: *mut JNIEnv, vector_2i: ::math::Vector2i) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "(Lcom/flowpowered/math/vector/Vector2i;)V", ::convert::ToJava::to_java(&vector_2i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#<init> was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn new_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "(II)V", value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#<init> was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_vector_2i_operator(env: *mut JNIEnv, vector_2i: ::math::Vector2i, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "(Lcom/flowpowered/math/vector/Vector2i;Lorg/spongepowered/api/data/Property$Operator;)V", ::convert::ToJava::to_java(&vector_2i, env), ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#<init> was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_i32_i32_operator(env: *mut JNIEnv, value: i32, value_2: i32, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "(IILorg/spongepowered/api/data/Property$Operator;)V", value, value_2, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#<init> was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#<init> was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn get_x(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotPos", self.object, "getX", "()I", CallIntMethod)
+  }
+
+  pub fn get_y(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotPos", self.object, "getY", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotPos", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of_object(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#of was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn of_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "of", "(II)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#of was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#not was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#greaterThan was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#lessThan was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotPos {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotPos", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotPos;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotPos#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::SlotPos { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotPos", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlotSide {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlotSide {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlotSide {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SlotSide`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/SlotSide", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn new_direction(env: *mut JNIEnv, direction: ::sponge::util::Direction) -> ::sponge::item::inventory::property::SlotSide {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotSide", "(Lorg/spongepowered/api/util/Direction;)V", ::convert::ToJava::to_java(&direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotSide#<init> was null") }
+    ::sponge::item::inventory::property::SlotSide { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "util"))]
+  pub fn new_direction_operator(env: *mut JNIEnv, direction: ::sponge::util::Direction, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotSide {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotSide", "(Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/data/Property$Operator;)V", ::convert::ToJava::to_java(&direction, env), ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotSide#<init> was null") }
+    ::sponge::item::inventory::property::SlotSide { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::SlotSide {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/SlotSide", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotSide#<init> was null") }
+    ::sponge::item::inventory::property::SlotSide { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotSide", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotSide {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotSide", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotSide;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotSide#of was null") }
+    ::sponge::item::inventory::property::SlotSide { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::SlotSide {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/SlotSide", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/SlotSide;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/SlotSide#not was null") }
+    ::sponge::item::inventory::property::SlotSide { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/SlotSide", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StringProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StringProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StringProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `StringProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/property/StringProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#<init> was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_string_operator(env: *mut JNIEnv, string: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "(Ljava/lang/String;Lorg/spongepowered/api/data/Property$Operator;)V", string, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#<init> was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new_object_operator(env: *mut JNIEnv, object: jobject, operator: ::sponge::data::property_::Operator) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "(Ljava/lang/Object;Lorg/spongepowered/api/data/Property$Operator;)V", object, ::convert::ToJava::to_java(&operator, env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#<init> was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn compare_to_property(&self, property: ::sponge::data::Property) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/StringProperty", self.object, "compareTo", "(Lorg/spongepowered/api/data/Property;)I", CallIntMethodA, property.object)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "of", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#of was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn not(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "not", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#not was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "greaterThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#greaterThan was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn greater_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "greaterThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#greaterThanOrEqual was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn less_than(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "lessThan", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#lessThan was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn less_than_or_equal(env: *mut JNIEnv, object: jobject) -> ::sponge::item::inventory::property::StringProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/property/StringProperty", "lessThanOrEqual", "(Ljava/lang/Object;)Lorg/spongepowered/api/item/inventory/property/StringProperty;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/property/StringProperty#lessThanOrEqual was null") }
+    ::sponge::item::inventory::property::StringProperty { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/property/StringProperty", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
@@ -0,0 +1,166 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct EquipmentSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EquipmentSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EquipmentSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EquipmentSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/EquipmentSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_valid_item(&self, equipment_type: ::sponge::item::inventory::equipment::EquipmentType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/EquipmentSlot", self.object, "isValidItem", "(Lorg/spongepowered/api/item/inventory/equipment/EquipmentType;)Z", CallBooleanMethodA, equipment_type.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FilteringSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FilteringSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FilteringSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FilteringSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/FilteringSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_valid_item_item_stack(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/FilteringSlot", self.object, "isValidItem", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn is_valid_item_item_type(&self, item_type: ::sponge::item::ItemType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/FilteringSlot", self.object, "isValidItem", "(Lorg/spongepowered/api/item/ItemType;)Z", CallBooleanMethodA, item_type.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FuelSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FuelSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FuelSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FuelSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/FuelSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InputSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InputSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InputSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InputSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/InputSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OutputSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OutputSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OutputSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OutputSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/OutputSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SidedSlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SidedSlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SidedSlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SidedSlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/slot/SidedSlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn can_accept(&self, item_stack: ::sponge::item::inventory::ItemStack, direction: ::sponge::util::Direction) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/SidedSlot", self.object, "canAccept", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/util/Direction;)Z", CallBooleanMethodA, item_stack.object, ::convert::ToJava::to_java(&direction, self.env)) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn offer(&self, item_stack: ::sponge::item::inventory::ItemStack, direction: ::sponge::util::Direction) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/SidedSlot", self.object, "offer", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/util/Direction;)Z", CallBooleanMethodA, item_stack.object, ::convert::ToJava::to_java(&direction, self.env)) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn can_get(&self, item_stack: ::sponge::item::inventory::ItemStack, direction: ::sponge::util::Direction) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/slot/SidedSlot", self.object, "canGet", "(Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/util/Direction;)Z", CallBooleanMethodA, item_stack.object, ::convert::ToJava::to_java(&direction, self.env)) != 0
+  }
+
+}
@@ -0,0 +1,205 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct InventoryTransactionResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventoryTransactionResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventoryTransactionResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InventoryTransactionResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", "builder", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#builder was null") }
+    ::sponge::item::inventory::transaction::inventory_transaction_result::Builder { env: env, object: ret }
+  }
+
+  pub fn success_no_transactions(env: *mut JNIEnv) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", "successNoTransactions", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#successNoTransactions was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: env, object: ret }
+  }
+
+  pub fn fail_no_transactions(env: *mut JNIEnv) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", "failNoTransactions", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#failNoTransactions was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Type {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", self.object, "getType", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Type;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#getType was null") }
+    <::sponge::item::inventory::transaction::inventory_transaction_result::Type as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_rejected_items(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", self.object, "getRejectedItems", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#getRejectedItems was null") }
+    ret
+  }
+
+  pub fn get_replaced_items(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult", self.object, "getReplacedItems", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult#getReplacedItems was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SlotTransaction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SlotTransaction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SlotTransaction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SlotTransaction`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/transaction/SlotTransaction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, slot: ::sponge::item::inventory::Slot, item_stack_snapshot: ::sponge::item::inventory::ItemStackSnapshot, item_stack_snapshot_2: ::sponge::item::inventory::ItemStackSnapshot) -> ::sponge::item::inventory::transaction::SlotTransaction {
+    let ret = new_object!(env, "org/spongepowered/api/item/inventory/transaction/SlotTransaction", "(Lorg/spongepowered/api/item/inventory/Slot;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;)V", slot.object, item_stack_snapshot.object, item_stack_snapshot_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/SlotTransaction#<init> was null") }
+    ::sponge::item::inventory::transaction::SlotTransaction { env: env, object: ret }
+  }
+
+  pub fn set_custom(&self, item_stack: ::sponge::item::inventory::ItemStack) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/SlotTransaction", self.object, "setCustom", "(Lorg/spongepowered/api/item/inventory/ItemStack;)V", CallVoidMethodA, item_stack.object);
+  }
+
+  pub fn get_slot(&self) -> ::sponge::item::inventory::Slot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/SlotTransaction", self.object, "getSlot", "()Lorg/spongepowered/api/item/inventory/Slot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/SlotTransaction#getSlot was null") }
+    ::sponge::item::inventory::Slot { env: self.env, object: ret }
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/SlotTransaction", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/SlotTransaction#toString was null") }
+    ret
+  }
+
+}
+
+pub mod inventory_transaction_result {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, type_: ::sponge::item::inventory::transaction::inventory_transaction_result::Type) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", self.object, "type", "(Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Type;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&type_, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder#type was null") }
+      ::sponge::item::inventory::transaction::inventory_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reject(&self, item_stacks: Vec<::sponge::item::inventory::ItemStack>) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+      unimplemented!();
+    }
+    pub fn replace(&self, item_stacks: Vec<::sponge::item::inventory::ItemStack>) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+      unimplemented!();
+    }
+    pub fn build(&self) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", self.object, "build", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder#build was null") }
+      ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+    }
+
+    pub fn from_inventory_transaction_result(&self, inventory_transaction_result: ::sponge::item::inventory::transaction::InventoryTransactionResult) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", self.object, "from", "(Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder;", CallObjectMethodA, inventory_transaction_result.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder#from was null") }
+      ::sponge::item::inventory::transaction::inventory_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::item::inventory::transaction::inventory_transaction_result::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", self.object, "reset", "()Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder#reset was null") }
+      ::sponge::item::inventory::transaction::inventory_transaction_result::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Type {
+    Success,
+    Failure,
+    Error,
+    Cancelled,
+    Undefined,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static TYPE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/item/inventory/transaction/InventoryTransactionResult$Type", &["SUCCESS", "FAILURE", "ERROR", "CANCELLED", "UNDEFINED"]);
+
+  impl Type {
+    pub const VALUES: &'static [Type] = &[Type::Success, Type::Failure, Type::Error, Type::Cancelled, Type::Undefined];
+
+  }
+
+  impl ::convert::FromJava for Type {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match TYPE_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Type::VALUES[ordinal],
+        None => Type::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Type {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Type::Other(object) => object,
+        value => TYPE_CONSTANTS.get(env, Type::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
@@ -0,0 +1,382 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CarriedInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CarriedInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CarriedInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CarriedInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/CarriedInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_carrier(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/CarriedInventory", self.object, "getCarrier", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/CarriedInventory#getCarrier was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GridInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GridInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GridInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GridInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/GridInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_columns(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getColumns", "()I", CallIntMethod)
+  }
+
+  pub fn get_rows(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getRows", "()I", CallIntMethod)
+  }
+
+  pub fn get_dimensions(&self) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getDimensions", "()Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#getDimensions was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn poll_i32_i32(&self, value: i32, value_2: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "poll", "(II)Ljava/util/Optional;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "poll", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_i32_i32(&self, value: i32, value_2: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "peek", "(II)Ljava/util/Optional;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "peek", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set(&self, value: i32, value_2: i32, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "set", "(IILorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, value, value_2, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_slot(&self, value: i32, value_2: i32) -> Option<::sponge::item::inventory::Slot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getSlot", "(II)Ljava/util/Optional;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#getSlot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Slot { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_row(&self, value: i32) -> Option<::sponge::item::inventory::type_::InventoryRow> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getRow", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#getRow was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::type_::InventoryRow { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_column(&self, value: i32) -> Option<::sponge::item::inventory::type_::InventoryColumn> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/GridInventory", self.object, "getColumn", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/GridInventory#getColumn was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::type_::InventoryColumn { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Interactable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Interactable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Interactable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Interactable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/Interactable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn can_interact_with(&self, player: ::sponge::entity::living::player::Player) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Interactable", self.object, "canInteractWith", "(Lorg/spongepowered/api/entity/living/player/Player;)Z", CallBooleanMethodA, player.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Inventory2D {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Inventory2D {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Inventory2D {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Inventory2D`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/Inventory2D", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn poll_slot_pos(&self, slot_pos: ::sponge::item::inventory::property::SlotPos) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;)Ljava/util/Optional;", CallObjectMethodA, slot_pos.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_slot_pos_i32(&self, slot_pos: ::sponge::item::inventory::property::SlotPos, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;I)Ljava/util/Optional;", CallObjectMethodA, slot_pos.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_slot_pos(&self, slot_pos: ::sponge::item::inventory::property::SlotPos) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;)Ljava/util/Optional;", CallObjectMethodA, slot_pos.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_slot_pos_i32(&self, slot_pos: ::sponge::item::inventory::property::SlotPos, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;I)Ljava/util/Optional;", CallObjectMethodA, slot_pos.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set(&self, slot_pos: ::sponge::item::inventory::property::SlotPos, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "set", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, slot_pos.object, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_slot(&self, slot_pos: ::sponge::item::inventory::property::SlotPos) -> Option<::sponge::item::inventory::Slot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/Inventory2D", self.object, "getSlot", "(Lorg/spongepowered/api/item/inventory/property/SlotPos;)Ljava/util/Optional;", CallObjectMethodA, slot_pos.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/Inventory2D#getSlot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Slot { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InventoryColumn {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventoryColumn {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventoryColumn {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InventoryColumn`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/InventoryColumn", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InventoryRow {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InventoryRow {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InventoryRow {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InventoryRow`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/InventoryRow", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OrderedInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OrderedInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OrderedInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OrderedInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/OrderedInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn poll_slot_index(&self, slot_index: ::sponge::item::inventory::property::SlotIndex) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;)Ljava/util/Optional;", CallObjectMethodA, slot_index.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn poll_slot_index_i32(&self, slot_index: ::sponge::item::inventory::property::SlotIndex, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "poll", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;I)Ljava/util/Optional;", CallObjectMethodA, slot_index.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#poll was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_slot_index(&self, slot_index: ::sponge::item::inventory::property::SlotIndex) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;)Ljava/util/Optional;", CallObjectMethodA, slot_index.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn peek_slot_index_i32(&self, slot_index: ::sponge::item::inventory::property::SlotIndex, value: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "peek", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;I)Ljava/util/Optional;", CallObjectMethodA, slot_index.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#peek was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set(&self, slot_index: ::sponge::item::inventory::property::SlotIndex, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::inventory::transaction::InventoryTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "set", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/inventory/transaction/InventoryTransactionResult;", CallObjectMethodA, slot_index.object, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#set was null") }
+    ::sponge::item::inventory::transaction::InventoryTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_slot(&self, slot_index: ::sponge::item::inventory::property::SlotIndex) -> Option<::sponge::item::inventory::Slot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/OrderedInventory", self.object, "getSlot", "(Lorg/spongepowered/api/item/inventory/property/SlotIndex;)Ljava/util/Optional;", CallObjectMethodA, slot_index.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/OrderedInventory#getSlot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::Slot { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PersistentInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PersistentInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PersistentInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PersistentInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/PersistentInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn mark_dirty(&self) {
+    bound_method!(self.env, "org/spongepowered/api/item/inventory/type/PersistentInventory", self.object, "markDirty", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TileEntityInventory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntityInventory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntityInventory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntityInventory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/inventory/type/TileEntityInventory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_tile_entity(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/inventory/type/TileEntityInventory", self.object, "getTileEntity", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/inventory/type/TileEntityInventory#getTileEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
@@ -0,0 +1,422 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Merchant {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Merchant {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Merchant {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Merchant`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/Merchant", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_customer(&self) -> Option<::sponge::entity::living::Humanoid> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/Merchant", self.object, "getCustomer", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/Merchant#getCustomer was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::Humanoid { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn set_customer(&self, humanoid: ::sponge::entity::living::Humanoid) {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/Merchant", self.object, "setCustomer", "(Lorg/spongepowered/api/entity/living/Humanoid;)V", CallVoidMethodA, humanoid.object);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_trade_offer_data(&self) -> ::sponge::data::manipulator::mutable::entity::TradeOfferData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/Merchant", self.object, "getTradeOfferData", "()Lorg/spongepowered/api/data/manipulator/mutable/entity/TradeOfferData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/Merchant#getTradeOfferData was null") }
+    ::sponge::data::manipulator::mutable::entity::TradeOfferData { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TradeOffer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TradeOffer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TradeOffer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TradeOffer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/TradeOffer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::merchant::trade_offer::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/merchant/TradeOffer", "builder", "()Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer#builder was null") }
+    ::sponge::item::merchant::trade_offer::Builder { env: env, object: ret }
+  }
+
+  pub fn get_first_buying_item(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "getFirstBuyingItem", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer#getFirstBuyingItem was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+  pub fn has_second_item(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "hasSecondItem", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_second_buying_item(&self) -> Option<::sponge::item::inventory::ItemStackSnapshot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "getSecondBuyingItem", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer#getSecondBuyingItem was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_selling_item(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "getSellingItem", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer#getSellingItem was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+  pub fn get_uses(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "getUses", "()I", CallIntMethod)
+  }
+
+  pub fn get_max_uses(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "getMaxUses", "()I", CallIntMethod)
+  }
+
+  pub fn has_expired(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "hasExpired", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_grant_experience(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer", self.object, "doesGrantExperience", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TradeOfferGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TradeOfferGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TradeOfferGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TradeOfferGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/TradeOfferGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/merchant/TradeOfferGenerator", "builder", "()Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator#builder was null") }
+    ::sponge::item::merchant::trade_offer_generator::Builder { env: env, object: ret }
+  }
+
+  pub fn accept_list_random(&self, list: jobject, random: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator", self.object, "accept", "(Ljava/util/List;Ljava/util/Random;)V", CallVoidMethodA, list, random);
+  }
+
+  pub fn accept_object_object(&self, object: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator", self.object, "accept", "(Ljava/lang/Object;Ljava/lang/Object;)V", CallVoidMethodA, object, object_2);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TradeOfferListMutator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TradeOfferListMutator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TradeOfferListMutator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TradeOfferListMutator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/TradeOfferListMutator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn accept_list_random(&self, list: jobject, random: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferListMutator", self.object, "accept", "(Ljava/util/List;Ljava/util/Random;)V", CallVoidMethodA, list, random);
+  }
+
+  pub fn accept_object_object(&self, object: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferListMutator", self.object, "accept", "(Ljava/lang/Object;Ljava/lang/Object;)V", CallVoidMethodA, object, object_2);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VillagerRegistry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VillagerRegistry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VillagerRegistry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VillagerRegistry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/VillagerRegistry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_trade_offer_level_map(&self, career: ::sponge::data::type_::Career) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "getTradeOfferLevelMap", "(Lorg/spongepowered/api/data/type/Career;)Lcom/google/common/collect/Multimap;", CallObjectMethodA, career.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#getTradeOfferLevelMap was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_mutators_for_career(&self, career: ::sponge::data::type_::Career, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "getMutatorsForCareer", "(Lorg/spongepowered/api/data/type/Career;I)Ljava/util/Collection;", CallObjectMethodA, career.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#getMutatorsForCareer was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn add_mutator(&self, career: ::sponge::data::type_::Career, value: i32, trade_offer_list_mutator: ::sponge::item::merchant::TradeOfferListMutator) -> ::sponge::item::merchant::VillagerRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "addMutator", "(Lorg/spongepowered/api/data/type/Career;ILorg/spongepowered/api/item/merchant/TradeOfferListMutator;)Lorg/spongepowered/api/item/merchant/VillagerRegistry;", CallObjectMethodA, career.object, value, trade_offer_list_mutator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#addMutator was null") }
+    ::sponge::item::merchant::VillagerRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn add_mutators(&self, career: ::sponge::data::type_::Career, value: i32, trade_offer_list_mutator: ::sponge::item::merchant::TradeOfferListMutator, trade_offer_list_mutators: Vec<::sponge::item::merchant::TradeOfferListMutator>) -> ::sponge::item::merchant::VillagerRegistry {
+    unimplemented!();
+  }
+  #[cfg(feature = "data")]
+  pub fn set_mutators_career_i32_list(&self, career: ::sponge::data::type_::Career, value: i32, list: jobject) -> ::sponge::item::merchant::VillagerRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "setMutators", "(Lorg/spongepowered/api/data/type/Career;ILjava/util/List;)Lorg/spongepowered/api/item/merchant/VillagerRegistry;", CallObjectMethodA, career.object, value, list);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#setMutators was null") }
+    ::sponge::item::merchant::VillagerRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_mutators_career_multimap(&self, career: ::sponge::data::type_::Career, multimap: jobject) -> ::sponge::item::merchant::VillagerRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "setMutators", "(Lorg/spongepowered/api/data/type/Career;Lcom/google/common/collect/Multimap;)Lorg/spongepowered/api/item/merchant/VillagerRegistry;", CallObjectMethodA, career.object, multimap);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#setMutators was null") }
+    ::sponge::item::merchant::VillagerRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn generate_trade_offers(&self, career: ::sponge::data::type_::Career, value: i32, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "generateTradeOffers", "(Lorg/spongepowered/api/data/type/Career;ILjava/util/Random;)Ljava/util/Collection;", CallObjectMethodA, career.object, value, random);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#generateTradeOffers was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn populate_offers(&self, list: jobject, career: ::sponge::data::type_::Career, value: i32, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/VillagerRegistry", self.object, "populateOffers", "(Ljava/util/List;Lorg/spongepowered/api/data/type/Career;ILjava/util/Random;)Ljava/util/List;", CallObjectMethodA, list, career.object, value, random);
+    if ret.is_null() { panic!("org/spongepowered/api/item/merchant/VillagerRegistry#populateOffers was null") }
+    ret
+  }
+
+}
+
+pub mod trade_offer {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/TradeOffer$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn first_buying_item(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "firstBuyingItem", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#firstBuyingItem was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn second_buying_item(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "secondBuyingItem", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#secondBuyingItem was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn selling_item(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "sellingItem", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#sellingItem was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn uses(&self, value: i32) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "uses", "(I)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#uses was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn max_uses(&self, value: i32) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "maxUses", "(I)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#maxUses was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn can_grant_experience(&self, value: bool) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "canGrantExperience", "(Z)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#canGrantExperience was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::merchant::TradeOffer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "build", "()Lorg/spongepowered/api/item/merchant/TradeOffer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#build was null") }
+      ::sponge::item::merchant::TradeOffer { env: self.env, object: ret }
+    }
+
+    pub fn from_trade_offer(&self, trade_offer: ::sponge::item::merchant::TradeOffer) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "from", "(Lorg/spongepowered/api/item/merchant/TradeOffer;)Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethodA, trade_offer.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#from was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::item::merchant::trade_offer::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "reset", "()Lorg/spongepowered/api/item/merchant/TradeOffer$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#reset was null") }
+      ::sponge::item::merchant::trade_offer::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#from was null") }
+      ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOffer$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOffer$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod trade_offer_generator {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn set_primary_item_generator(&self, item_stack_generator: ::sponge::item::inventory::ItemStackGenerator) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "setPrimaryItemGenerator", "(Lorg/spongepowered/api/item/inventory/ItemStackGenerator;)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, item_stack_generator.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#setPrimaryItemGenerator was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn set_second_item_generator(&self, item_stack_generator: ::sponge::item::inventory::ItemStackGenerator) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "setSecondItemGenerator", "(Lorg/spongepowered/api/item/inventory/ItemStackGenerator;)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, item_stack_generator.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#setSecondItemGenerator was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn set_selling_generator(&self, item_stack_generator: ::sponge::item::inventory::ItemStackGenerator) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "setSellingGenerator", "(Lorg/spongepowered/api/item/inventory/ItemStackGenerator;)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, item_stack_generator.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#setSellingGenerator was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn experience_chance(&self, value: f64) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "experienceChance", "(D)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#experienceChance was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn starting_uses(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "startingUses", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#startingUses was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn max_uses(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::item::merchant::trade_offer_generator::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "maxUses", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/item/merchant/TradeOfferGenerator$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#maxUses was null") }
+      ::sponge::item::merchant::trade_offer_generator::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::merchant::TradeOfferGenerator {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder", self.object, "build", "()Lorg/spongepowered/api/item/merchant/TradeOfferGenerator;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/merchant/TradeOfferGenerator$Builder#build was null") }
+      ::sponge::item::merchant::TradeOfferGenerator { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,366 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod inventory;
+pub mod merchant;
+pub mod recipe;
+
+#[derive(Debug)]
+pub struct Enchantment {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Enchantment {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Enchantment {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Enchantment`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/Enchantment", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/Enchantment#getName was null") }
+    ret
+  }
+
+  pub fn get_weight(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getWeight", "()I", CallIntMethod)
+  }
+
+  pub fn get_minimum_level(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getMinimumLevel", "()I", CallIntMethod)
+  }
+
+  pub fn get_maximum_level(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getMaximumLevel", "()I", CallIntMethod)
+  }
+
+  pub fn get_minimum_enchantability_for_level(&self, value: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getMinimumEnchantabilityForLevel", "(I)I", CallIntMethodA, value)
+  }
+
+  pub fn get_maximum_enchantability_for_level(&self, value: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "getMaximumEnchantabilityForLevel", "(I)I", CallIntMethodA, value)
+  }
+
+  pub fn can_be_applied_to_stack(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "canBeAppliedToStack", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn can_be_applied_by_table(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "canBeAppliedByTable", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn is_compatible_with(&self, enchantment: ::sponge::item::Enchantment) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/Enchantment", self.object, "isCompatibleWith", "(Lorg/spongepowered/api/item/Enchantment;)Z", CallBooleanMethodA, enchantment.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Enchantments {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Enchantments {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Enchantments {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Enchantments`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/Enchantments", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FireworkEffect {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FireworkEffect {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FireworkEffect {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FireworkEffect`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/FireworkEffect", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::firework_effect::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/FireworkEffect", "builder", "()Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect#builder was null") }
+    ::sponge::item::firework_effect::Builder { env: env, object: ret }
+  }
+
+  pub fn flickers(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect", self.object, "flickers", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn has_trail(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect", self.object, "hasTrail", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_colors(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect", self.object, "getColors", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect#getColors was null") }
+    ret
+  }
+
+  pub fn get_fade_colors(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect", self.object, "getFadeColors", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect#getFadeColors was null") }
+    ret
+  }
+
+  pub fn get_shape(&self) -> ::sponge::item::FireworkShape {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect", self.object, "getShape", "()Lorg/spongepowered/api/item/FireworkShape;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect#getShape was null") }
+    ::sponge::item::FireworkShape { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FireworkShape {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FireworkShape {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FireworkShape {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FireworkShape`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/FireworkShape", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FireworkShapes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FireworkShapes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FireworkShapes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FireworkShapes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/FireworkShapes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/ItemType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> Option<::sponge::block::BlockType> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getBlock", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/ItemType#getBlock was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::BlockType { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/ItemType#getName was null") }
+    ret
+  }
+
+  pub fn get_max_stack_quantity(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getMaxStackQuantity", "()I", CallIntMethod)
+  }
+
+  pub fn get_default_property(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getDefaultProperty", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/item/ItemType#getDefaultProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_type(&self) -> ::sponge::item::ItemType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getType", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/ItemType#getType was null") }
+    ::sponge::item::ItemType { env: self.env, object: ret }
+  }
+
+  pub fn matches(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "matches", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+  }
+
+  pub fn is_specific(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "isSpecific", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_template(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/ItemType", self.object, "getTemplate", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/ItemType#getTemplate was null") }
+    ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/ItemTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod firework_effect {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/FireworkEffect$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn trail(&self, value: bool) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "trail", "(Z)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#trail was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn flicker(&self, value: bool) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "flicker", "(Z)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#flicker was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn color(&self, color: ::sponge::util::Color) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "color", "(Lorg/spongepowered/api/util/Color;)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#color was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn colors_color_array(&self, colors: Vec<::sponge::util::Color>) -> ::sponge::item::firework_effect::Builder {
+      unimplemented!();
+    }
+    pub fn colors_iterable(&self, iterable: jobject) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "colors", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#colors was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn fade(&self, color: ::sponge::util::Color) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "fade", "(Lorg/spongepowered/api/util/Color;)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#fade was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn fades_color_array(&self, colors: Vec<::sponge::util::Color>) -> ::sponge::item::firework_effect::Builder {
+      unimplemented!();
+    }
+    pub fn fades_iterable(&self, iterable: jobject) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "fades", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#fades was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn shape(&self, firework_shape: ::sponge::item::FireworkShape) -> ::sponge::item::firework_effect::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "shape", "(Lorg/spongepowered/api/item/FireworkShape;)Lorg/spongepowered/api/item/FireworkEffect$Builder;", CallObjectMethodA, firework_shape.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#shape was null") }
+      ::sponge::item::firework_effect::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::FireworkEffect {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/FireworkEffect$Builder", self.object, "build", "()Lorg/spongepowered/api/item/FireworkEffect;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/FireworkEffect$Builder#build was null") }
+      ::sponge::item::FireworkEffect { env: self.env, object: ret }
+    }
+
+  }
+}
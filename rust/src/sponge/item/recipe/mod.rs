@@ -0,0 +1,275 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Recipe {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Recipe {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Recipe {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Recipe`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/Recipe", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_result_types(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/Recipe", self.object, "getResultTypes", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/Recipe#getResultTypes was null") }
+    ret
+  }
+
+  pub fn is_valid(&self, grid_inventory: ::sponge::item::inventory::type_::GridInventory) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/item/recipe/Recipe", self.object, "isValid", "(Lorg/spongepowered/api/item/inventory/type/GridInventory;)Z", CallBooleanMethodA, grid_inventory.object) != 0
+  }
+
+  pub fn get_results(&self, grid_inventory: ::sponge::item::inventory::type_::GridInventory) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/Recipe", self.object, "getResults", "(Lorg/spongepowered/api/item/inventory/type/GridInventory;)Ljava/util/Optional;", CallObjectMethodA, grid_inventory.object);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/Recipe#getResults was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RecipeRegistry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RecipeRegistry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RecipeRegistry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RecipeRegistry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/RecipeRegistry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register(&self, recipe: ::sponge::item::recipe::Recipe) {
+    bound_method!(self.env, "org/spongepowered/api/item/recipe/RecipeRegistry", self.object, "register", "(Lorg/spongepowered/api/item/recipe/Recipe;)V", CallVoidMethodA, recipe.object);
+  }
+
+  pub fn remove(&self, recipe: ::sponge::item::recipe::Recipe) {
+    bound_method!(self.env, "org/spongepowered/api/item/recipe/RecipeRegistry", self.object, "remove", "(Lorg/spongepowered/api/item/recipe/Recipe;)V", CallVoidMethodA, recipe.object);
+  }
+
+  pub fn get_recipes(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/RecipeRegistry", self.object, "getRecipes", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/RecipeRegistry#getRecipes was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShapedRecipe {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShapedRecipe {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShapedRecipe {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShapedRecipe`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/ShapedRecipe", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_width(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe", self.object, "getWidth", "()I", CallIntMethod)
+  }
+
+  pub fn get_height(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe", self.object, "getHeight", "()I", CallIntMethod)
+  }
+
+  pub fn get_ingredient_i32_i32(&self, value: i32, value_2: i32) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe", self.object, "getIngredient", "(II)Ljava/util/Optional;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe#getIngredient was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_ingredient_vector_2i(&self, vector_2i: ::math::Vector2i) -> Option<::sponge::item::inventory::ItemStack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe", self.object, "getIngredient", "(Lcom/flowpowered/math/vector/Vector2i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe#getIngredient was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::item::inventory::ItemStack { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShapelessRecipe {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShapelessRecipe {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShapelessRecipe {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShapelessRecipe`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/ShapelessRecipe", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::item::recipe::shapeless_recipe::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/item/recipe/ShapelessRecipe", "builder", "()Lorg/spongepowered/api/item/recipe/ShapelessRecipe$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapelessRecipe#builder was null") }
+    ::sponge::item::recipe::shapeless_recipe::Builder { env: env, object: ret }
+  }
+
+  pub fn get_ingredients(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapelessRecipe", self.object, "getIngredients", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapelessRecipe#getIngredients was null") }
+    ret
+  }
+
+}
+
+pub mod shaped_recipe {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn width(&self, value: i32) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "width", "(I)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#width was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn height(&self, value: i32) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "height", "(I)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#height was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn dimensions(&self, vector_2i: ::math::Vector2i) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "dimensions", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#dimensions was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn ingredient_i32_i32_item_stack(&self, value: i32, value_2: i32, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "ingredient", "(IILorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, value, value_2, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#ingredient was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn ingredient_vector_2i_item_stack(&self, vector_2i: ::math::Vector2i, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "ingredient", "(Lcom/flowpowered/math/vector/Vector2i;Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#ingredient was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn row(&self, value: i32, item_stacks: Vec<::sponge::item::inventory::ItemStack>) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      unimplemented!();
+    }
+    pub fn add_result(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::recipe::shaped_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "addResult", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/recipe/ShapedRecipe$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#addResult was null") }
+      ::sponge::item::recipe::shaped_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::recipe::ShapedRecipe {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapedRecipe$Builder", self.object, "build", "()Lorg/spongepowered/api/item/recipe/ShapedRecipe;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapedRecipe$Builder#build was null") }
+      ::sponge::item::recipe::ShapedRecipe { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod shapeless_recipe {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/item/recipe/ShapelessRecipe$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn add_ingredient(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::recipe::shapeless_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapelessRecipe$Builder", self.object, "addIngredient", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/recipe/ShapelessRecipe$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapelessRecipe$Builder#addIngredient was null") }
+      ::sponge::item::recipe::shapeless_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_result(&self, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::item::recipe::shapeless_recipe::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapelessRecipe$Builder", self.object, "addResult", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/item/recipe/ShapelessRecipe$Builder;", CallObjectMethodA, item_stack.object);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapelessRecipe$Builder#addResult was null") }
+      ::sponge::item::recipe::shapeless_recipe::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::item::recipe::ShapelessRecipe {
+      let ret = bound_method!(self.env, "org/spongepowered/api/item/recipe/ShapelessRecipe$Builder", self.object, "build", "()Lorg/spongepowered/api/item/recipe/ShapelessRecipe;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/item/recipe/ShapelessRecipe$Builder#build was null") }
+      ::sponge::item::recipe::ShapelessRecipe { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,1216 @@
+#![allow(non_snake_case, non_camel_case_types, unused_variables, unused_imports, clippy::wrong_self_convention, clippy::too_many_arguments, clippy::redundant_field_names, clippy::missing_safety_doc, clippy::module_inception, clippy::self_named_constructors, clippy::not_unsafe_ptr_arg_deref, clippy::macro_metavars_in_unsafe)]
+
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[cfg(feature = "asset")]
+pub mod asset;
+#[cfg(feature = "block")]
+pub mod block;
+#[cfg(feature = "boss")]
+pub mod boss;
+#[cfg(feature = "command")]
+pub mod command;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(feature = "data")]
+pub mod data;
+#[cfg(feature = "effect")]
+pub mod effect;
+#[cfg(feature = "entity")]
+pub mod entity;
+#[cfg(feature = "event")]
+pub mod event;
+#[cfg(feature = "extra")]
+pub mod extra;
+#[cfg(feature = "item")]
+pub mod item;
+#[cfg(feature = "network")]
+pub mod network;
+#[cfg(feature = "plugin")]
+pub mod plugin;
+#[cfg(feature = "profile")]
+pub mod profile;
+#[cfg(feature = "registry")]
+pub mod registry;
+#[cfg(feature = "resourcepack")]
+pub mod resourcepack;
+#[cfg(feature = "scheduler")]
+pub mod scheduler;
+#[cfg(feature = "scoreboard")]
+pub mod scoreboard;
+#[cfg(feature = "service")]
+pub mod service;
+#[cfg(feature = "statistic")]
+pub mod statistic;
+#[cfg(feature = "text")]
+pub mod text;
+#[cfg(feature = "util")]
+pub mod util;
+#[cfg(feature = "world")]
+pub mod world;
+
+#[derive(Debug)]
+pub struct CatalogType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/CatalogType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/CatalogType", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/CatalogType#getId was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/CatalogType", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/CatalogType#getName was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/CatalogTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Game {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Game {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Game {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Game`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/Game", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_platform(&self) -> ::sponge::Platform {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getPlatform", "()Lorg/spongepowered/api/Platform;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getPlatform was null") }
+    ::sponge::Platform { env: self.env, object: ret }
+  }
+
+  pub fn is_server_available(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/Game", self.object, "isServerAvailable", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_server(&self) -> ::sponge::Server {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getServer", "()Lorg/spongepowered/api/Server;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getServer was null") }
+    ::sponge::Server { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_plugin_manager(&self) -> ::sponge::plugin::PluginManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getPluginManager", "()Lorg/spongepowered/api/plugin/PluginManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getPluginManager was null") }
+    ::sponge::plugin::PluginManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_event_manager(&self) -> ::sponge::event::EventManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getEventManager", "()Lorg/spongepowered/api/event/EventManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getEventManager was null") }
+    ::sponge::event::EventManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "asset")]
+  pub fn get_asset_manager(&self) -> ::sponge::asset::AssetManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getAssetManager", "()Lorg/spongepowered/api/asset/AssetManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getAssetManager was null") }
+    ::sponge::asset::AssetManager { env: self.env, object: ret }
+  }
+
+  pub fn get_registry(&self) -> ::sponge::GameRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getRegistry", "()Lorg/spongepowered/api/GameRegistry;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getRegistry was null") }
+    ::sponge::GameRegistry { env: self.env, object: ret }
+  }
+
+  pub fn get_game_dictionary(&self) -> ::sponge::GameDictionary {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getGameDictionary", "()Lorg/spongepowered/api/GameDictionary;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getGameDictionary was null") }
+    ::sponge::GameDictionary { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "service")]
+  pub fn get_service_manager(&self) -> ::sponge::service::ServiceManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getServiceManager", "()Lorg/spongepowered/api/service/ServiceManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getServiceManager was null") }
+    ::sponge::service::ServiceManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "scheduler")]
+  pub fn get_scheduler(&self) -> ::sponge::scheduler::Scheduler {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getScheduler", "()Lorg/spongepowered/api/scheduler/Scheduler;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getScheduler was null") }
+    ::sponge::scheduler::Scheduler { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_data_manager(&self) -> ::sponge::data::DataManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getDataManager", "()Lorg/spongepowered/api/data/DataManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getDataManager was null") }
+    ::sponge::data::DataManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_property_registry(&self) -> ::sponge::data::property::PropertyRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getPropertyRegistry", "()Lorg/spongepowered/api/data/property/PropertyRegistry;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getPropertyRegistry was null") }
+    ::sponge::data::property::PropertyRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_command_manager(&self) -> ::sponge::command::CommandManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getCommandManager", "()Lorg/spongepowered/api/command/CommandManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getCommandManager was null") }
+    ::sponge::command::CommandManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_teleport_helper(&self) -> ::sponge::world::TeleportHelper {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getTeleportHelper", "()Lorg/spongepowered/api/world/TeleportHelper;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getTeleportHelper was null") }
+    ::sponge::world::TeleportHelper { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "config")]
+  pub fn get_config_manager(&self) -> ::sponge::config::ConfigManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getConfigManager", "()Lorg/spongepowered/api/config/ConfigManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getConfigManager was null") }
+    ::sponge::config::ConfigManager { env: self.env, object: ret }
+  }
+
+  pub fn get_game_directory(&self) -> ::std::path::PathBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getGameDirectory", "()Ljava/nio/file/Path;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getGameDirectory was null") }
+    <::std::path::PathBuf as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_saves_directory(&self) -> ::std::path::PathBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getSavesDirectory", "()Ljava/nio/file/Path;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getSavesDirectory was null") }
+    <::std::path::PathBuf as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_state(&self) -> ::sponge::GameState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getState", "()Lorg/spongepowered/api/GameState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getState was null") }
+    <::sponge::GameState as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "network")]
+  pub fn get_channel_registrar(&self) -> ::sponge::network::ChannelRegistrar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Game", self.object, "getChannelRegistrar", "()Lorg/spongepowered/api/network/ChannelRegistrar;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Game#getChannelRegistrar was null") }
+    ::sponge::network::ChannelRegistrar { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameDictionary {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameDictionary {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameDictionary {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameDictionary`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/GameDictionary", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register(&self, string: jobject, entry: ::sponge::game_dictionary::Entry) {
+    bound_method!(self.env, "org/spongepowered/api/GameDictionary", self.object, "register", "(Ljava/lang/String;Lorg/spongepowered/api/GameDictionary$Entry;)V", CallVoidMethodA, string, entry.object);
+  }
+
+  pub fn get(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameDictionary", self.object, "get", "(Ljava/lang/String;)Ljava/util/Set;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameDictionary#get was null") }
+    ret
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameDictionary", self.object, "getAll", "()Lcom/google/common/collect/SetMultimap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameDictionary#getAll was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameRegistry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameRegistry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameRegistry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameRegistry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/GameRegistry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self, class: jobject, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getType", "(Ljava/lang/Class;Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, class, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getType was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_all_of(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getAllOf", "(Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getAllOf was null") }
+    ret
+  }
+
+  pub fn get_all_for(&self, string: jobject, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getAllFor", "(Ljava/lang/String;Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, string, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getAllFor was null") }
+    ret
+  }
+
+  pub fn get_all_for_minecraft(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getAllForMinecraft", "(Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getAllForMinecraft was null") }
+    ret
+  }
+
+  pub fn get_all_for_sponge(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getAllForSponge", "(Ljava/lang/Class;)Ljava/util/Collection;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getAllForSponge was null") }
+    ret
+  }
+
+  #[cfg(feature = "registry")]
+  pub fn register_module_class_catalog_registry_module(&self, class: jobject, catalog_registry_module: ::sponge::registry::CatalogRegistryModule) -> ::sponge::GameRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "registerModule", "(Ljava/lang/Class;Lorg/spongepowered/api/registry/CatalogRegistryModule;)Lorg/spongepowered/api/GameRegistry;", CallObjectMethodA, class, catalog_registry_module.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#registerModule was null") }
+    ::sponge::GameRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "registry")]
+  pub fn register_module_registry_module(&self, registry_module: ::sponge::registry::RegistryModule) -> ::sponge::GameRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "registerModule", "(Lorg/spongepowered/api/registry/RegistryModule;)Lorg/spongepowered/api/GameRegistry;", CallObjectMethodA, registry_module.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#registerModule was null") }
+    ::sponge::GameRegistry { env: self.env, object: ret }
+  }
+
+  pub fn register_builder_supplier(&self, class: jobject, supplier: jobject) -> ::sponge::GameRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "registerBuilderSupplier", "(Ljava/lang/Class;Ljava/util/function/Supplier;)Lorg/spongepowered/api/GameRegistry;", CallObjectMethodA, class, supplier);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#registerBuilderSupplier was null") }
+    ::sponge::GameRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn create_builder(&self, class: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "createBuilder", "(Ljava/lang/Class;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#createBuilder was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+  pub fn register(&self, class: jobject, catalog_type: ::sponge::CatalogType) -> ::sponge::CatalogType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "register", "(Ljava/lang/Class;Lorg/spongepowered/api/CatalogType;)Lorg/spongepowered/api/CatalogType;", CallObjectMethodA, class, catalog_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#register was null") }
+    ::sponge::CatalogType { env: self.env, object: ret }
+  }
+
+  pub fn get_default_game_rules(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getDefaultGameRules", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getDefaultGameRules was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "entity", feature = "statistic"))]
+  pub fn get_entity_statistic(&self, statistic_group: ::sponge::statistic::StatisticGroup, entity_type: ::sponge::entity::EntityType) -> Option<::sponge::statistic::EntityStatistic> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getEntityStatistic", "(Lorg/spongepowered/api/statistic/StatisticGroup;Lorg/spongepowered/api/entity/EntityType;)Ljava/util/Optional;", CallObjectMethodA, statistic_group.object, entity_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getEntityStatistic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::EntityStatistic { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "item", feature = "statistic"))]
+  pub fn get_item_statistic(&self, statistic_group: ::sponge::statistic::StatisticGroup, item_type: ::sponge::item::ItemType) -> Option<::sponge::statistic::ItemStatistic> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getItemStatistic", "(Lorg/spongepowered/api/statistic/StatisticGroup;Lorg/spongepowered/api/item/ItemType;)Ljava/util/Optional;", CallObjectMethodA, statistic_group.object, item_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getItemStatistic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::ItemStatistic { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "block", feature = "statistic"))]
+  pub fn get_block_statistic(&self, statistic_group: ::sponge::statistic::StatisticGroup, block_type: ::sponge::block::BlockType) -> Option<::sponge::statistic::BlockStatistic> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getBlockStatistic", "(Lorg/spongepowered/api/statistic/StatisticGroup;Lorg/spongepowered/api/block/BlockType;)Ljava/util/Optional;", CallObjectMethodA, statistic_group.object, block_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getBlockStatistic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::BlockStatistic { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "statistic", feature = "text"))]
+  pub fn get_team_statistic(&self, statistic_group: ::sponge::statistic::StatisticGroup, text_color: ::sponge::text::format::TextColor) -> Option<::sponge::statistic::TeamStatistic> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getTeamStatistic", "(Lorg/spongepowered/api/statistic/StatisticGroup;Lorg/spongepowered/api/text/format/TextColor;)Ljava/util/Optional;", CallObjectMethodA, statistic_group.object, text_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getTeamStatistic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::TeamStatistic { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "statistic")]
+  pub fn get_statistics(&self, statistic_group: ::sponge::statistic::StatisticGroup) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getStatistics", "(Lorg/spongepowered/api/statistic/StatisticGroup;)Ljava/util/Collection;", CallObjectMethodA, statistic_group.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getStatistics was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_rotation_from_degree(&self, value: i32) -> Option<::sponge::util::rotation::Rotation> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getRotationFromDegree", "(I)Ljava/util/Optional;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getRotationFromDegree was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::rotation::Rotation { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn load_favicon_string(&self, string: jobject) -> ::sponge::network::status::Favicon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "loadFavicon", "(Ljava/lang/String;)Lorg/spongepowered/api/network/status/Favicon;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#loadFavicon was null") }
+    ::sponge::network::status::Favicon { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn load_favicon_path(&self, path: ::std::path::PathBuf) -> ::sponge::network::status::Favicon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "loadFavicon", "(Ljava/nio/file/Path;)Lorg/spongepowered/api/network/status/Favicon;", CallObjectMethodA, ::convert::ToJava::to_java(&path, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#loadFavicon was null") }
+    ::sponge::network::status::Favicon { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn load_favicon_url(&self, url: ::url::Url) -> ::sponge::network::status::Favicon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "loadFavicon", "(Ljava/net/URL;)Lorg/spongepowered/api/network/status/Favicon;", CallObjectMethodA, ::convert::ToJava::to_java(&url, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#loadFavicon was null") }
+    ::sponge::network::status::Favicon { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn load_favicon_input_stream(&self, input_stream: jobject) -> ::sponge::network::status::Favicon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "loadFavicon", "(Ljava/io/InputStream;)Lorg/spongepowered/api/network/status/Favicon;", CallObjectMethodA, input_stream);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#loadFavicon was null") }
+    ::sponge::network::status::Favicon { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn load_favicon_buffered_image(&self, buffered_image: jobject) -> ::sponge::network::status::Favicon {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "loadFavicon", "(Ljava/awt/image/BufferedImage;)Lorg/spongepowered/api/network/status/Favicon;", CallObjectMethodA, buffered_image);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#loadFavicon was null") }
+    ::sponge::network::status::Favicon { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_recipe_registry(&self) -> ::sponge::item::recipe::RecipeRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getRecipeRegistry", "()Lorg/spongepowered/api/item/recipe/RecipeRegistry;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getRecipeRegistry was null") }
+    ::sponge::item::recipe::RecipeRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "resourcepack")]
+  pub fn get_resource_pack_by_id(&self, string: jobject) -> Option<::sponge::resourcepack::ResourcePack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getResourcePackById", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getResourcePackById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::resourcepack::ResourcePack { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "scoreboard", feature = "text"))]
+  pub fn get_display_slot_for_color(&self, text_color: ::sponge::text::format::TextColor) -> Option<::sponge::scoreboard::displayslot::DisplaySlot> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getDisplaySlotForColor", "(Lorg/spongepowered/api/text/format/TextColor;)Ljava/util/Optional;", CallObjectMethodA, text_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getDisplaySlotForColor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::displayslot::DisplaySlot { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn register_a_i_task_type(&self, object: jobject, string: jobject, string_2: jobject, class: jobject) -> ::sponge::entity::ai::task::AITaskType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "registerAITaskType", "(Ljava/lang/Object;Ljava/lang/String;Ljava/lang/String;Ljava/lang/Class;)Lorg/spongepowered/api/entity/ai/task/AITaskType;", CallObjectMethodA, object, string, string_2, class);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#registerAITaskType was null") }
+    ::sponge::entity::ai::task::AITaskType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_extent_buffer_factory(&self) -> ::sponge::world::extent::ExtentBufferFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getExtentBufferFactory", "()Lorg/spongepowered/api/world/extent/ExtentBufferFactory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getExtentBufferFactory was null") }
+    ::sponge::world::extent::ExtentBufferFactory { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_value_factory(&self) -> ::sponge::data::value::ValueFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getValueFactory", "()Lorg/spongepowered/api/data/value/ValueFactory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getValueFactory was null") }
+    ::sponge::data::value::ValueFactory { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_villager_registry(&self) -> ::sponge::item::merchant::VillagerRegistry {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getVillagerRegistry", "()Lorg/spongepowered/api/item/merchant/VillagerRegistry;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getVillagerRegistry was null") }
+    ::sponge::item::merchant::VillagerRegistry { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_text_serializer_factory(&self) -> ::sponge::text::serializer::TextSerializerFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getTextSerializerFactory", "()Lorg/spongepowered/api/text/serializer/TextSerializerFactory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getTextSerializerFactory was null") }
+    ::sponge::text::serializer::TextSerializerFactory { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_selector_factory(&self) -> ::sponge::text::selector::SelectorFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getSelectorFactory", "()Lorg/spongepowered/api/text/selector/SelectorFactory;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getSelectorFactory was null") }
+    ::sponge::text::selector::SelectorFactory { env: self.env, object: ret }
+  }
+
+  pub fn get_locale(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getLocale", "(Ljava/lang/String;)Ljava/util/Locale;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getLocale was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_translation_by_id(&self, string: jobject) -> Option<::sponge::text::translation::Translation> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/GameRegistry", self.object, "getTranslationById", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/GameRegistry#getTranslationById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::translation::Translation { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameState {
+  Construction,
+  PreInitialization,
+  Initialization,
+  PostInitialization,
+  LoadComplete,
+  ServerAboutToStart,
+  ServerStarting,
+  ServerStarted,
+  ServerStopping,
+  ServerStopped,
+  GameStopping,
+  GameStopped,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static GAME_STATE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/GameState", &["CONSTRUCTION", "PRE_INITIALIZATION", "INITIALIZATION", "POST_INITIALIZATION", "LOAD_COMPLETE", "SERVER_ABOUT_TO_START", "SERVER_STARTING", "SERVER_STARTED", "SERVER_STOPPING", "SERVER_STOPPED", "GAME_STOPPING", "GAME_STOPPED"]);
+
+impl GameState {
+  pub const VALUES: &'static [GameState] = &[GameState::Construction, GameState::PreInitialization, GameState::Initialization, GameState::PostInitialization, GameState::LoadComplete, GameState::ServerAboutToStart, GameState::ServerStarting, GameState::ServerStarted, GameState::ServerStopping, GameState::ServerStopped, GameState::GameStopping, GameState::GameStopped];
+
+}
+
+impl ::convert::FromJava for GameState {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match GAME_STATE_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => GameState::VALUES[ordinal],
+      None => GameState::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for GameState {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      GameState::Other(object) => object,
+      value => GAME_STATE_CONSTANTS.get(env, GameState::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct MinecraftVersion {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MinecraftVersion {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MinecraftVersion {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MinecraftVersion`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/MinecraftVersion", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/MinecraftVersion", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/MinecraftVersion#getName was null") }
+    ret
+  }
+
+  pub fn is_legacy(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/MinecraftVersion", self.object, "isLegacy", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Nameable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Nameable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Nameable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Nameable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/Nameable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_name(&self) -> ::sponge::text::translation::Translation {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Nameable", self.object, "getName", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Nameable#getName was null") }
+    ::sponge::text::translation::Translation { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Platform {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Platform {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Platform {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Platform`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/Platform", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::platform::Type {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "getType", "()Lorg/spongepowered/api/Platform$Type;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#getType was null") }
+    <::sponge::platform::Type as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_execution_type(&self) -> ::sponge::platform::Type {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "getExecutionType", "()Lorg/spongepowered/api/Platform$Type;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#getExecutionType was null") }
+    <::sponge::platform::Type as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_api(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "getApi", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#getApi was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_implementation(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "getImplementation", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#getImplementation was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_minecraft_version(&self) -> ::sponge::MinecraftVersion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "getMinecraftVersion", "()Lorg/spongepowered/api/MinecraftVersion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#getMinecraftVersion was null") }
+    ::sponge::MinecraftVersion { env: self.env, object: ret }
+  }
+
+  pub fn as_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Platform", self.object, "asMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Platform#asMap was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Server {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Server {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Server {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Server`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/Server", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_online_players(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getOnlinePlayers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getOnlinePlayers was null") }
+    ret
+  }
+
+  pub fn get_max_players(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getMaxPlayers", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_player_uuid(&self, uuid: jobject) -> Option<::sponge::entity::living::player::Player> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getPlayer", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getPlayer was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::Player { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_player_string(&self, string: jobject) -> Option<::sponge::entity::living::player::Player> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getPlayer", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getPlayer was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::Player { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_worlds(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getWorlds", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getWorlds was null") }
+    ret
+  }
+
+  pub fn get_unloaded_worlds(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getUnloadedWorlds", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getUnloadedWorlds was null") }
+    ret
+  }
+
+  pub fn get_all_world_properties(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getAllWorldProperties", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getAllWorldProperties was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_uuid(&self, uuid: jobject) -> Option<::sponge::world::World> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getWorld", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::World { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_string(&self, string: jobject) -> Option<::sponge::world::World> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getWorld", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::World { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_default_world(&self) -> Option<::sponge::world::storage::WorldProperties> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getDefaultWorld", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getDefaultWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::storage::WorldProperties { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_default_world_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getDefaultWorldName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getDefaultWorldName was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn load_world_string(&self, string: jobject) -> Option<::sponge::world::World> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "loadWorld", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#loadWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::World { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn load_world_uuid(&self, uuid: jobject) -> Option<::sponge::world::World> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "loadWorld", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#loadWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::World { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn load_world_world_properties(&self, world_properties: ::sponge::world::storage::WorldProperties) -> Option<::sponge::world::World> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "loadWorld", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Ljava/util/Optional;", CallObjectMethodA, world_properties.object);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#loadWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::World { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_properties_string(&self, string: jobject) -> Option<::sponge::world::storage::WorldProperties> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getWorldProperties", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getWorldProperties was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::storage::WorldProperties { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_world_properties_uuid(&self, uuid: jobject) -> Option<::sponge::world::storage::WorldProperties> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getWorldProperties", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getWorldProperties was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::storage::WorldProperties { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn unload_world(&self, world: ::sponge::world::World) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "unloadWorld", "(Lorg/spongepowered/api/world/World;)Z", CallBooleanMethodA, world.object) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn create_world_properties(&self, string: jobject, world_archetype: ::sponge::world::WorldArchetype) -> ::sponge::world::storage::WorldProperties {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "createWorldProperties", "(Ljava/lang/String;Lorg/spongepowered/api/world/WorldArchetype;)Lorg/spongepowered/api/world/storage/WorldProperties;", CallObjectMethodA, string, world_archetype.object);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#createWorldProperties was null") }
+    ::sponge::world::storage::WorldProperties { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn copy_world(&self, world_properties: ::sponge::world::storage::WorldProperties, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "copyWorld", "(Lorg/spongepowered/api/world/storage/WorldProperties;Ljava/lang/String;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, world_properties.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#copyWorld was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn rename_world(&self, world_properties: ::sponge::world::storage::WorldProperties, string: jobject) -> Option<::sponge::world::storage::WorldProperties> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "renameWorld", "(Lorg/spongepowered/api/world/storage/WorldProperties;Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, world_properties.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#renameWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::storage::WorldProperties { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn delete_world(&self, world_properties: ::sponge::world::storage::WorldProperties) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "deleteWorld", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, world_properties.object);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#deleteWorld was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn save_world_properties(&self, world_properties: ::sponge::world::storage::WorldProperties) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "saveWorldProperties", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Z", CallBooleanMethodA, world_properties.object) != 0
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn get_server_scoreboard(&self) -> Option<::sponge::scoreboard::Scoreboard> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getServerScoreboard", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getServerScoreboard was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::Scoreboard { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_chunk_layout(&self) -> ::sponge::world::storage::ChunkLayout {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getChunkLayout", "()Lorg/spongepowered/api/world/storage/ChunkLayout;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getChunkLayout was null") }
+    ::sponge::world::storage::ChunkLayout { env: self.env, object: ret }
+  }
+
+  pub fn get_running_time_ticks(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getRunningTimeTicks", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_broadcast_channel(&self) -> ::sponge::text::channel::MessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getBroadcastChannel", "()Lorg/spongepowered/api/text/channel/MessageChannel;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getBroadcastChannel was null") }
+    ::sponge::text::channel::MessageChannel { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_broadcast_channel(&self, message_channel: ::sponge::text::channel::MessageChannel) {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "setBroadcastChannel", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", CallVoidMethodA, message_channel.object);
+  }
+
+  pub fn get_bound_address(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getBoundAddress", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getBoundAddress was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn has_whitelist(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "hasWhitelist", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_has_whitelist(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "setHasWhitelist", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_online_mode(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getOnlineMode", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_motd(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getMotd", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getMotd was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn shutdown(&self) {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "shutdown", "()V", CallVoidMethod);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn shutdown_text(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "shutdown", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_console(&self) -> ::sponge::command::source::ConsoleSource {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getConsole", "()Lorg/spongepowered/api/command/source/ConsoleSource;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getConsole was null") }
+    ::sponge::command::source::ConsoleSource { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_chunk_ticket_manager(&self) -> ::sponge::world::ChunkTicketManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getChunkTicketManager", "()Lorg/spongepowered/api/world/ChunkTicketManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getChunkTicketManager was null") }
+    ::sponge::world::ChunkTicketManager { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn get_game_profile_manager(&self) -> ::sponge::profile::GameProfileManager {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getGameProfileManager", "()Lorg/spongepowered/api/profile/GameProfileManager;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getGameProfileManager was null") }
+    ::sponge::profile::GameProfileManager { env: self.env, object: ret }
+  }
+
+  pub fn get_ticks_per_second(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getTicksPerSecond", "()D", CallDoubleMethod)
+  }
+
+  #[cfg(feature = "resourcepack")]
+  pub fn get_default_resource_pack(&self) -> Option<::sponge::resourcepack::ResourcePack> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getDefaultResourcePack", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Server#getDefaultResourcePack was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::resourcepack::ResourcePack { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_player_idle_timeout(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "getPlayerIdleTimeout", "()I", CallIntMethod)
+  }
+
+  pub fn set_player_idle_timeout(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/Server", self.object, "setPlayerIdleTimeout", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Sponge {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Sponge {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Sponge {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Sponge`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/Sponge", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::Sponge {
+    let ret = new_object!(env, "org/spongepowered/api/Sponge", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#<init> was null") }
+    ::sponge::Sponge { env: env, object: ret }
+  }
+
+  pub fn get_game(env: *mut JNIEnv) -> ::sponge::Game {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getGame", "()Lorg/spongepowered/api/Game;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getGame was null") }
+    ::sponge::Game { env: env, object: ret }
+  }
+
+  pub fn get_registry(env: *mut JNIEnv) -> ::sponge::GameRegistry {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getRegistry", "()Lorg/spongepowered/api/GameRegistry;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getRegistry was null") }
+    ::sponge::GameRegistry { env: env, object: ret }
+  }
+
+  #[cfg(feature = "service")]
+  pub fn get_service_manager(env: *mut JNIEnv) -> ::sponge::service::ServiceManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getServiceManager", "()Lorg/spongepowered/api/service/ServiceManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getServiceManager was null") }
+    ::sponge::service::ServiceManager { env: env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_event_manager(env: *mut JNIEnv) -> ::sponge::event::EventManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getEventManager", "()Lorg/spongepowered/api/event/EventManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getEventManager was null") }
+    ::sponge::event::EventManager { env: env, object: ret }
+  }
+
+  #[cfg(feature = "asset")]
+  pub fn get_asset_manager(env: *mut JNIEnv) -> ::sponge::asset::AssetManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getAssetManager", "()Lorg/spongepowered/api/asset/AssetManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getAssetManager was null") }
+    ::sponge::asset::AssetManager { env: env, object: ret }
+  }
+
+  #[cfg(feature = "scheduler")]
+  pub fn get_scheduler(env: *mut JNIEnv) -> ::sponge::scheduler::Scheduler {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getScheduler", "()Lorg/spongepowered/api/scheduler/Scheduler;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getScheduler was null") }
+    ::sponge::scheduler::Scheduler { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_data_manager(env: *mut JNIEnv) -> ::sponge::data::DataManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getDataManager", "()Lorg/spongepowered/api/data/DataManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getDataManager was null") }
+    ::sponge::data::DataManager { env: env, object: ret }
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_plugin_manager(env: *mut JNIEnv) -> ::sponge::plugin::PluginManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getPluginManager", "()Lorg/spongepowered/api/plugin/PluginManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getPluginManager was null") }
+    ::sponge::plugin::PluginManager { env: env, object: ret }
+  }
+
+  pub fn get_platform(env: *mut JNIEnv) -> ::sponge::Platform {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getPlatform", "()Lorg/spongepowered/api/Platform;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getPlatform was null") }
+    ::sponge::Platform { env: env, object: ret }
+  }
+
+  pub fn is_server_available(env: *mut JNIEnv) -> bool {
+    static_java_method!(env, "org/spongepowered/api/Sponge", "isServerAvailable", "()Z", CallStaticBooleanMethod) != 0
+  }
+
+  pub fn get_server(env: *mut JNIEnv) -> ::sponge::Server {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getServer", "()Lorg/spongepowered/api/Server;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getServer was null") }
+    ::sponge::Server { env: env, object: ret }
+  }
+
+  pub fn get_dictionary(env: *mut JNIEnv) -> ::sponge::GameDictionary {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getDictionary", "()Lorg/spongepowered/api/GameDictionary;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getDictionary was null") }
+    ::sponge::GameDictionary { env: env, object: ret }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_command_manager(env: *mut JNIEnv) -> ::sponge::command::CommandManager {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getCommandManager", "()Lorg/spongepowered/api/command/CommandManager;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getCommandManager was null") }
+    ::sponge::command::CommandManager { env: env, object: ret }
+  }
+
+  #[cfg(feature = "network")]
+  pub fn get_channel_registrar(env: *mut JNIEnv) -> ::sponge::network::ChannelRegistrar {
+    let ret = static_java_method!(env, "org/spongepowered/api/Sponge", "getChannelRegistrar", "()Lorg/spongepowered/api/network/ChannelRegistrar;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/Sponge#getChannelRegistrar was null") }
+    ::sponge::network::ChannelRegistrar { env: env, object: ret }
+  }
+
+}
+
+pub mod game_dictionary {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Entry {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Entry {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Entry {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Entry`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/GameDictionary$Entry", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "item")]
+    pub fn get_type(&self) -> ::sponge::item::ItemType {
+      let ret = bound_method!(self.env, "org/spongepowered/api/GameDictionary$Entry", self.object, "getType", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/GameDictionary$Entry#getType was null") }
+      ::sponge::item::ItemType { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "item")]
+    pub fn matches(&self, item_stack: ::sponge::item::inventory::ItemStack) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/GameDictionary$Entry", self.object, "matches", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Z", CallBooleanMethodA, item_stack.object) != 0
+    }
+
+    pub fn is_specific(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/GameDictionary$Entry", self.object, "isSpecific", "()Z", CallBooleanMethod) != 0
+    }
+
+    #[cfg(feature = "item")]
+    pub fn get_template(&self) -> ::sponge::item::inventory::ItemStackSnapshot {
+      let ret = bound_method!(self.env, "org/spongepowered/api/GameDictionary$Entry", self.object, "getTemplate", "()Lorg/spongepowered/api/item/inventory/ItemStackSnapshot;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/GameDictionary$Entry#getTemplate was null") }
+      ::sponge::item::inventory::ItemStackSnapshot { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod platform {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Type {
+    Client,
+    Server,
+    Unknown,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static TYPE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/Platform$Type", &["CLIENT", "SERVER", "UNKNOWN"]);
+
+  impl Type {
+    pub const VALUES: &'static [Type] = &[Type::Client, Type::Server, Type::Unknown];
+
+    pub fn is_server(&self, env: *mut JNIEnv) -> bool {
+      bound_method!(env, "org/spongepowered/api/Platform$Type", ::convert::ToJava::to_java(self, env), "isServer", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn is_client(&self, env: *mut JNIEnv) -> bool {
+      bound_method!(env, "org/spongepowered/api/Platform$Type", ::convert::ToJava::to_java(self, env), "isClient", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn is_known(&self, env: *mut JNIEnv) -> bool {
+      bound_method!(env, "org/spongepowered/api/Platform$Type", ::convert::ToJava::to_java(self, env), "isKnown", "()Z", CallBooleanMethod) != 0
+    }
+
+  }
+
+  impl ::convert::FromJava for Type {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match TYPE_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Type::VALUES[ordinal],
+        None => Type::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Type {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Type::Other(object) => object,
+        value => TYPE_CONSTANTS.get(env, Type::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
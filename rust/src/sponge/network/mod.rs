@@ -0,0 +1,833 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod status;
+
+#[derive(Debug)]
+pub struct ChannelBinding {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChannelBinding {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChannelBinding {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChannelBinding`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelBinding", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_registrar(&self) -> ::sponge::network::ChannelRegistrar {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding", self.object, "getRegistrar", "()Lorg/spongepowered/api/network/ChannelRegistrar;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBinding#getRegistrar was null") }
+    ::sponge::network::ChannelRegistrar { env: self.env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBinding#getName was null") }
+    ret
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_owner(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding", self.object, "getOwner", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBinding#getOwner was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChannelBuf {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChannelBuf {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChannelBuf {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChannelBuf`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelBuf", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_capacity(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getCapacity", "()I", CallIntMethod)
+  }
+
+  pub fn available(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "available", "()I", CallIntMethod)
+  }
+
+  pub fn order(&self, byte_order: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "order", "(Ljava/nio/ByteOrder;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, byte_order);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#order was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn get_byte_order(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getByteOrder", "()Ljava/nio/ByteOrder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#getByteOrder was null") }
+    ret
+  }
+
+  pub fn reader_index(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readerIndex", "()I", CallIntMethod)
+  }
+
+  pub fn set_read_index(&self, value: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setReadIndex", "(I)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setReadIndex was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn writer_index(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writerIndex", "()I", CallIntMethod)
+  }
+
+  pub fn set_write_index(&self, value: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setWriteIndex", "(I)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setWriteIndex was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_index(&self, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setIndex", "(II)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setIndex was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn clear(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "clear", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#clear was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn mark_read(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "markRead", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#markRead was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn mark_write(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "markWrite", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#markWrite was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn reset_read(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "resetRead", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#resetRead was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn reset_write(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "resetWrite", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#resetWrite was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn slice(&self) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "slice", "()Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#slice was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn slice_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "slice", "(II)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#slice was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn array(&self) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn write_boolean(&self, value: bool) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeBoolean", "(Z)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeBoolean was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_boolean(&self, value: i32, value_2: bool) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setBoolean", "(IZ)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setBoolean was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_boolean(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readBoolean", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_boolean(&self, value: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getBoolean", "(I)Z", CallBooleanMethodA, value) != 0
+  }
+
+  pub fn write_byte(&self, value: i8) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeByte", "(B)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeByte was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_byte(&self, value: i32, value_2: i8) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setByte", "(IB)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setByte was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_byte(&self) -> i8 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readByte", "()B", CallByteMethod)
+  }
+
+  pub fn get_byte(&self, value: i32) -> i8 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getByte", "(I)B", CallByteMethodA, value)
+  }
+
+  pub fn write_byte_array_i8_array(&self, values: Vec<i8>) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn write_byte_array_i8_array_i32_i32(&self, values: Vec<i8>, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn set_byte_array_i32_i8_array(&self, value: i32, values: Vec<i8>) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn set_byte_array_i32_i8_array_i32_i32(&self, value: i32, values: Vec<i8>, value_2: i32, value_3: i32) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn read_byte_array(&self) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn read_byte_array_i32(&self, value: i32) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn write_bytes_i8_array(&self, values: Vec<i8>) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn write_bytes_i8_array_i32_i32(&self, values: Vec<i8>, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn set_bytes_i32_i8_array(&self, value: i32, values: Vec<i8>) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn set_bytes_i32_i8_array_i32_i32(&self, value: i32, values: Vec<i8>, value_2: i32, value_3: i32) -> ::sponge::network::ChannelBuf {
+    unimplemented!();
+  }
+  pub fn read_bytes_i32(&self, value: i32) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn read_bytes_i32_i32(&self, value: i32, value_2: i32) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn write_short(&self, value: i16) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeShort", "(S)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeShort was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_short(&self, value: i32, value_2: i16) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setShort", "(IS)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setShort was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_short(&self) -> i16 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readShort", "()S", CallShortMethod)
+  }
+
+  pub fn get_short(&self, value: i32) -> i16 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getShort", "(I)S", CallShortMethodA, value)
+  }
+
+  pub fn write_char(&self, value: char) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeChar", "(C)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeChar was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_char(&self, value: i32, value_2: char) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setChar", "(IC)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setChar was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_char(&self) -> char {
+    ::convert::char_from_java(bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readChar", "()C", CallCharMethod))
+  }
+
+  pub fn get_char(&self, value: i32) -> char {
+    ::convert::char_from_java(bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getChar", "(I)C", CallCharMethodA, value))
+  }
+
+  pub fn write_integer(&self, value: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeInteger", "(I)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeInteger was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_integer(&self, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setInteger", "(II)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setInteger was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_integer(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readInteger", "()I", CallIntMethod)
+  }
+
+  pub fn get_integer(&self, value: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getInteger", "(I)I", CallIntMethodA, value)
+  }
+
+  pub fn write_long(&self, value: i64) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeLong", "(J)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeLong was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_long(&self, value: i32, value_2: i64) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setLong", "(IJ)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setLong was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_long(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readLong", "()J", CallLongMethod)
+  }
+
+  pub fn get_long(&self, value: i32) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getLong", "(I)J", CallLongMethodA, value)
+  }
+
+  pub fn write_float(&self, value: f32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeFloat", "(F)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeFloat was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_float(&self, value: i32, value_2: f32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setFloat", "(IF)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setFloat was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_float(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readFloat", "()F", CallFloatMethod)
+  }
+
+  pub fn get_float(&self, value: i32) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getFloat", "(I)F", CallFloatMethodA, value)
+  }
+
+  pub fn write_double(&self, value: f64) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeDouble", "(D)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeDouble was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_double(&self, value: i32, value_2: f64) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setDouble", "(ID)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setDouble was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_double(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readDouble", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_double(&self, value: i32) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getDouble", "(I)D", CallDoubleMethodA, value)
+  }
+
+  pub fn write_var_int(&self, value: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeVarInt", "(I)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeVarInt was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_var_int(&self, value: i32, value_2: i32) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setVarInt", "(II)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setVarInt was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_var_int(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readVarInt", "()I", CallIntMethod)
+  }
+
+  pub fn get_var_int(&self, value: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getVarInt", "(I)I", CallIntMethodA, value)
+  }
+
+  pub fn write_string(&self, string: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeString", "(Ljava/lang/String;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeString was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_string(&self, value: i32, string: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setString", "(ILjava/lang/String;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setString was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#readString was null") }
+    ret
+  }
+
+  pub fn get_string(&self, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getString", "(I)Ljava/lang/String;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#getString was null") }
+    ret
+  }
+
+  pub fn write_u_t_f(&self, string: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeUTF", "(Ljava/lang/String;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeUTF was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_u_t_f(&self, value: i32, string: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setUTF", "(ILjava/lang/String;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setUTF was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_u_t_f(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readUTF", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#readUTF was null") }
+    ret
+  }
+
+  pub fn get_u_t_f(&self, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getUTF", "(I)Ljava/lang/String;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#getUTF was null") }
+    ret
+  }
+
+  pub fn write_unique_id(&self, uuid: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeUniqueId", "(Ljava/util/UUID;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeUniqueId was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn set_unique_id(&self, value: i32, uuid: jobject) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setUniqueId", "(ILjava/util/UUID;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setUniqueId was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  pub fn read_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#readUniqueId was null") }
+    ret
+  }
+
+  pub fn get_unique_id(&self, value: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getUniqueId", "(I)Ljava/util/UUID;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#getUniqueId was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn write_data_view(&self, data_view: ::sponge::data::DataView) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "writeDataView", "(Lorg/spongepowered/api/data/DataView;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#writeDataView was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_data_view(&self, value: i32, data_view: ::sponge::data::DataView) -> ::sponge::network::ChannelBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "setDataView", "(ILorg/spongepowered/api/data/DataView;)Lorg/spongepowered/api/network/ChannelBuf;", CallObjectMethodA, value, data_view.object);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#setDataView was null") }
+    ::sponge::network::ChannelBuf { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn read_data_view(&self) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "readDataView", "()Lorg/spongepowered/api/data/DataView;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#readDataView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_data_view(&self, value: i32) -> ::sponge::data::DataView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelBuf", self.object, "getDataView", "(I)Lorg/spongepowered/api/data/DataView;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelBuf#getDataView was null") }
+    ::sponge::data::DataView { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChannelRegistrar {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChannelRegistrar {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChannelRegistrar {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChannelRegistrar`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelRegistrar", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_channel(&self, object: jobject, string: jobject) -> ::sponge::network::channel_binding::IndexedMessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelRegistrar", self.object, "createChannel", "(Ljava/lang/Object;Ljava/lang/String;)Lorg/spongepowered/api/network/ChannelBinding$IndexedMessageChannel;", CallObjectMethodA, object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrar#createChannel was null") }
+    ::sponge::network::channel_binding::IndexedMessageChannel { env: self.env, object: ret }
+  }
+
+  pub fn create_raw_channel(&self, object: jobject, string: jobject) -> ::sponge::network::channel_binding::RawDataChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelRegistrar", self.object, "createRawChannel", "(Ljava/lang/Object;Ljava/lang/String;)Lorg/spongepowered/api/network/ChannelBinding$RawDataChannel;", CallObjectMethodA, object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrar#createRawChannel was null") }
+    ::sponge::network::channel_binding::RawDataChannel { env: self.env, object: ret }
+  }
+
+  pub fn unbind_channel(&self, channel_binding: ::sponge::network::ChannelBinding) {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelRegistrar", self.object, "unbindChannel", "(Lorg/spongepowered/api/network/ChannelBinding;)V", CallVoidMethodA, channel_binding.object);
+  }
+
+  pub fn get_registered_channels(&self, type_: ::sponge::platform::Type) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/ChannelRegistrar", self.object, "getRegisteredChannels", "(Lorg/spongepowered/api/Platform$Type;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&type_, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrar#getRegisteredChannels was null") }
+    ret
+  }
+
+  pub fn is_channel_available(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/network/ChannelRegistrar", self.object, "isChannelAvailable", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChannelRegistrationException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChannelRegistrationException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChannelRegistrationException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ChannelRegistrationException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelRegistrationException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::network::ChannelRegistrationException {
+    let ret = new_object!(env, "org/spongepowered/api/network/ChannelRegistrationException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrationException#<init> was null") }
+    ::sponge::network::ChannelRegistrationException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::network::ChannelRegistrationException {
+    let ret = new_object!(env, "org/spongepowered/api/network/ChannelRegistrationException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrationException#<init> was null") }
+    ::sponge::network::ChannelRegistrationException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::network::ChannelRegistrationException {
+    let ret = new_object!(env, "org/spongepowered/api/network/ChannelRegistrationException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrationException#<init> was null") }
+    ::sponge::network::ChannelRegistrationException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::network::ChannelRegistrationException {
+    let ret = new_object!(env, "org/spongepowered/api/network/ChannelRegistrationException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/network/ChannelRegistrationException#<init> was null") }
+    ::sponge::network::ChannelRegistrationException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Message {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Message {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Message {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Message`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/Message", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn read_from(&self, channel_buf: ::sponge::network::ChannelBuf) {
+    bound_method!(self.env, "org/spongepowered/api/network/Message", self.object, "readFrom", "(Lorg/spongepowered/api/network/ChannelBuf;)V", CallVoidMethodA, channel_buf.object);
+  }
+
+  pub fn write_to(&self, channel_buf: ::sponge::network::ChannelBuf) {
+    bound_method!(self.env, "org/spongepowered/api/network/Message", self.object, "writeTo", "(Lorg/spongepowered/api/network/ChannelBuf;)V", CallVoidMethodA, channel_buf.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MessageHandler {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MessageHandler {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MessageHandler {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MessageHandler`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/MessageHandler", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn handle_message(&self, message: ::sponge::network::Message, remote_connection: ::sponge::network::RemoteConnection, type_: ::sponge::platform::Type) {
+    bound_method!(self.env, "org/spongepowered/api/network/MessageHandler", self.object, "handleMessage", "(Lorg/spongepowered/api/network/Message;Lorg/spongepowered/api/network/RemoteConnection;Lorg/spongepowered/api/Platform$Type;)V", CallVoidMethodA, message.object, remote_connection.object, ::convert::ToJava::to_java(&type_, self.env));
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PlayerConnection {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PlayerConnection {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PlayerConnection {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerConnection`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/PlayerConnection", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_player(&self) -> ::sponge::entity::living::player::Player {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/PlayerConnection", self.object, "getPlayer", "()Lorg/spongepowered/api/entity/living/player/Player;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/PlayerConnection#getPlayer was null") }
+    ::sponge::entity::living::player::Player { env: self.env, object: ret }
+  }
+
+  pub fn get_latency(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/network/PlayerConnection", self.object, "getLatency", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RawDataListener {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RawDataListener {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RawDataListener {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RawDataListener`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/RawDataListener", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn handle_payload(&self, channel_buf: ::sponge::network::ChannelBuf, remote_connection: ::sponge::network::RemoteConnection, type_: ::sponge::platform::Type) {
+    bound_method!(self.env, "org/spongepowered/api/network/RawDataListener", self.object, "handlePayload", "(Lorg/spongepowered/api/network/ChannelBuf;Lorg/spongepowered/api/network/RemoteConnection;Lorg/spongepowered/api/Platform$Type;)V", CallVoidMethodA, channel_buf.object, remote_connection.object, ::convert::ToJava::to_java(&type_, self.env));
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RemoteConnection {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RemoteConnection {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RemoteConnection {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RemoteConnection`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/RemoteConnection", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_address(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/RemoteConnection", self.object, "getAddress", "()Ljava/net/InetSocketAddress;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/RemoteConnection#getAddress was null") }
+    ret
+  }
+
+  pub fn get_virtual_host(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/RemoteConnection", self.object, "getVirtualHost", "()Ljava/net/InetSocketAddress;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/RemoteConnection#getVirtualHost was null") }
+    ret
+  }
+
+}
+
+pub mod channel_binding {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct IndexedMessageChannel {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl IndexedMessageChannel {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      IndexedMessageChannel {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `IndexedMessageChannel`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn register_message_class_i32(&self, class: jobject, value: i32) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "registerMessage", "(Ljava/lang/Class;I)V", CallVoidMethodA, class, value);
+    }
+
+    pub fn register_message_class_i32_message_handler(&self, class: jobject, value: i32, message_handler: ::sponge::network::MessageHandler) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "registerMessage", "(Ljava/lang/Class;ILorg/spongepowered/api/network/MessageHandler;)V", CallVoidMethodA, class, value, message_handler.object);
+    }
+
+    pub fn register_message_class_i32_type_message_handler(&self, class: jobject, value: i32, type_: ::sponge::platform::Type, message_handler: ::sponge::network::MessageHandler) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "registerMessage", "(Ljava/lang/Class;ILorg/spongepowered/api/Platform$Type;Lorg/spongepowered/api/network/MessageHandler;)V", CallVoidMethodA, class, value, ::convert::ToJava::to_java(&type_, self.env), message_handler.object);
+    }
+
+    pub fn add_handler_class_type_message_handler(&self, class: jobject, type_: ::sponge::platform::Type, message_handler: ::sponge::network::MessageHandler) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "addHandler", "(Ljava/lang/Class;Lorg/spongepowered/api/Platform$Type;Lorg/spongepowered/api/network/MessageHandler;)V", CallVoidMethodA, class, ::convert::ToJava::to_java(&type_, self.env), message_handler.object);
+    }
+
+    pub fn add_handler_class_message_handler(&self, class: jobject, message_handler: ::sponge::network::MessageHandler) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "addHandler", "(Ljava/lang/Class;Lorg/spongepowered/api/network/MessageHandler;)V", CallVoidMethodA, class, message_handler.object);
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn send_to(&self, player: ::sponge::entity::living::player::Player, message: ::sponge::network::Message) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "sendTo", "(Lorg/spongepowered/api/entity/living/player/Player;Lorg/spongepowered/api/network/Message;)V", CallVoidMethodA, player.object, message.object);
+    }
+
+    pub fn send_to_server(&self, message: ::sponge::network::Message) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "sendToServer", "(Lorg/spongepowered/api/network/Message;)V", CallVoidMethodA, message.object);
+    }
+
+    pub fn send_to_all(&self, message: ::sponge::network::Message) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$IndexedMessageChannel", self.object, "sendToAll", "(Lorg/spongepowered/api/network/Message;)V", CallVoidMethodA, message.object);
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct RawDataChannel {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl RawDataChannel {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      RawDataChannel {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `RawDataChannel`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn add_listener_raw_data_listener(&self, raw_data_listener: ::sponge::network::RawDataListener) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "addListener", "(Lorg/spongepowered/api/network/RawDataListener;)V", CallVoidMethodA, raw_data_listener.object);
+    }
+
+    pub fn add_listener_type_raw_data_listener(&self, type_: ::sponge::platform::Type, raw_data_listener: ::sponge::network::RawDataListener) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "addListener", "(Lorg/spongepowered/api/Platform$Type;Lorg/spongepowered/api/network/RawDataListener;)V", CallVoidMethodA, ::convert::ToJava::to_java(&type_, self.env), raw_data_listener.object);
+    }
+
+    pub fn remove_listener(&self, raw_data_listener: ::sponge::network::RawDataListener) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "removeListener", "(Lorg/spongepowered/api/network/RawDataListener;)V", CallVoidMethodA, raw_data_listener.object);
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn send_to(&self, player: ::sponge::entity::living::player::Player, consumer: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "sendTo", "(Lorg/spongepowered/api/entity/living/player/Player;Ljava/util/function/Consumer;)V", CallVoidMethodA, player.object, consumer);
+    }
+
+    pub fn send_to_server(&self, consumer: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "sendToServer", "(Ljava/util/function/Consumer;)V", CallVoidMethodA, consumer);
+    }
+
+    pub fn send_to_all(&self, consumer: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/network/ChannelBinding$RawDataChannel", self.object, "sendToAll", "(Ljava/util/function/Consumer;)V", CallVoidMethodA, consumer);
+    }
+
+  }
+}
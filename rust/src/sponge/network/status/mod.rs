@@ -0,0 +1,163 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Favicon {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Favicon {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Favicon {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Favicon`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/status/Favicon", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_image(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/Favicon", self.object, "getImage", "()Ljava/awt/image/BufferedImage;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/Favicon#getImage was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatusClient {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatusClient {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatusClient {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatusClient`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/status/StatusClient", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_address(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusClient", self.object, "getAddress", "()Ljava/net/InetSocketAddress;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusClient#getAddress was null") }
+    ret
+  }
+
+  pub fn get_version(&self) -> ::sponge::MinecraftVersion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusClient", self.object, "getVersion", "()Lorg/spongepowered/api/MinecraftVersion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusClient#getVersion was null") }
+    ::sponge::MinecraftVersion { env: self.env, object: ret }
+  }
+
+  pub fn get_virtual_host(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusClient", self.object, "getVirtualHost", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusClient#getVirtualHost was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatusResponse {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatusResponse {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatusResponse {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatusResponse`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/status/StatusResponse", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_description(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse", self.object, "getDescription", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusResponse#getDescription was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_players(&self) -> Option<::sponge::network::status::status_response::Players> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse", self.object, "getPlayers", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusResponse#getPlayers was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::network::status::status_response::Players { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_version(&self) -> ::sponge::MinecraftVersion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse", self.object, "getVersion", "()Lorg/spongepowered/api/MinecraftVersion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusResponse#getVersion was null") }
+    ::sponge::MinecraftVersion { env: self.env, object: ret }
+  }
+
+  pub fn get_favicon(&self) -> Option<::sponge::network::status::Favicon> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse", self.object, "getFavicon", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusResponse#getFavicon was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::network::status::Favicon { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+pub mod status_response {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Players {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Players {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Players {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Players`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/network/status/StatusResponse$Players", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_online(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse$Players", self.object, "getOnline", "()I", CallIntMethod)
+    }
+
+    pub fn get_max(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse$Players", self.object, "getMax", "()I", CallIntMethod)
+    }
+
+    pub fn get_profiles(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/network/status/StatusResponse$Players", self.object, "getProfiles", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/network/status/StatusResponse$Players#getProfiles was null") }
+      ret
+    }
+
+  }
+}
@@ -0,0 +1,249 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Dependency {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dependency {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dependency {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dependency`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/plugin/Dependency", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Dependency", self.object, "id", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Dependency#id was null") }
+    ret
+  }
+
+  pub fn version(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Dependency", self.object, "version", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Dependency#version was null") }
+    ret
+  }
+
+  pub fn optional(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/plugin/Dependency", self.object, "optional", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Plugin {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Plugin {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Plugin {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Plugin`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/plugin/Plugin", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "id", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#id was null") }
+    ret
+  }
+
+  pub fn name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "name", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#name was null") }
+    ret
+  }
+
+  pub fn version(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "version", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#version was null") }
+    ret
+  }
+
+  pub fn dependencies(&self) -> Vec<::sponge::plugin::Dependency> {
+    unimplemented!();
+  }
+  pub fn description(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "description", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#description was null") }
+    ret
+  }
+
+  pub fn url(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "url", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#url was null") }
+    ret
+  }
+
+  pub fn authors(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn assets(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/Plugin", self.object, "assets", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/Plugin#assets was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PluginContainer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PluginContainer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PluginContainer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PluginContainer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/plugin/PluginContainer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getId was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getName was null") }
+    ret
+  }
+
+  pub fn get_version(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getVersion", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getVersion was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_description(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getDescription", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getDescription was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_url(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getUrl", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getUrl was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_authors(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getAuthors", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getAuthors was null") }
+    ret
+  }
+
+  pub fn get_asset_directory(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getAssetDirectory", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getAssetDirectory was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "asset")]
+  pub fn get_asset(&self, string: jobject) -> Option<::sponge::asset::Asset> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getAsset", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getAsset was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::asset::Asset { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_source(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getSource", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getSource was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_instance(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getInstance", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getInstance was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_logger(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginContainer", self.object, "getLogger", "()Lorg/slf4j/Logger;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginContainer#getLogger was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PluginManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PluginManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PluginManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PluginManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/plugin/PluginManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn from_instance(&self, object: jobject) -> Option<::sponge::plugin::PluginContainer> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginManager", self.object, "fromInstance", "(Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginManager#fromInstance was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::plugin::PluginContainer { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_plugin(&self, string: jobject) -> Option<::sponge::plugin::PluginContainer> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginManager", self.object, "getPlugin", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginManager#getPlugin was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::plugin::PluginContainer { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_plugins(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/plugin/PluginManager", self.object, "getPlugins", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/plugin/PluginManager#getPlugins was null") }
+    ret
+  }
+
+  pub fn is_loaded(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/plugin/PluginManager", self.object, "isLoaded", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+}
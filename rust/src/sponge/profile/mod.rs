@@ -0,0 +1,366 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod property;
+
+#[derive(Debug)]
+pub struct GameProfile {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameProfile {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameProfile {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameProfile`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/profile/GameProfile", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv, uuid: jobject, string: jobject) -> ::sponge::profile::GameProfile {
+    let ret = static_java_method!(env, "org/spongepowered/api/profile/GameProfile", "of", "(Ljava/util/UUID;Ljava/lang/String;)Lorg/spongepowered/api/profile/GameProfile;", CallStaticObjectMethodA, uuid, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfile#of was null") }
+    ::sponge::profile::GameProfile { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfile", self.object, "getName", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfile#getName was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_property_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfile", self.object, "getPropertyMap", "()Lcom/google/common/collect/Multimap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfile#getPropertyMap was null") }
+    ret
+  }
+
+  pub fn is_filled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfile", self.object, "isFilled", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameProfileCache {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameProfileCache {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameProfileCache {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameProfileCache`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/profile/GameProfileCache", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn add_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "add", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  pub fn add_game_profile_date(&self, game_profile: ::sponge::profile::GameProfile, date: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "add", "(Lorg/spongepowered/api/profile/GameProfile;Ljava/util/Date;)Z", CallBooleanMethodA, game_profile.object, date) != 0
+  }
+
+  pub fn add_game_profile_bool_date(&self, game_profile: ::sponge::profile::GameProfile, value: bool, date: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "add", "(Lorg/spongepowered/api/profile/GameProfile;ZLjava/util/Date;)Z", CallBooleanMethodA, game_profile.object, value, date) != 0
+  }
+
+  pub fn remove_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "remove", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  pub fn remove_iterable(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "remove", "(Ljava/lang/Iterable;)Ljava/util/Collection;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#remove was null") }
+    ret
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn get_by_id(&self, uuid: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getById", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_by_ids(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getByIds", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getByIds was null") }
+    ret
+  }
+
+  pub fn lookup_by_id(&self, uuid: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "lookupById", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#lookupById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn lookup_by_ids(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "lookupByIds", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#lookupByIds was null") }
+    ret
+  }
+
+  pub fn get_or_lookup_by_id(&self, uuid: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getOrLookupById", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getOrLookupById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_or_lookup_by_ids(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getOrLookupByIds", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getOrLookupByIds was null") }
+    ret
+  }
+
+  pub fn get_by_name(&self, string: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getByName", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getByName was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_by_names(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getByNames", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getByNames was null") }
+    ret
+  }
+
+  pub fn lookup_by_name(&self, string: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "lookupByName", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#lookupByName was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn lookup_by_names(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "lookupByNames", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#lookupByNames was null") }
+    ret
+  }
+
+  pub fn get_or_lookup_by_name(&self, string: jobject) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getOrLookupByName", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getOrLookupByName was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_or_lookup_by_names(&self, iterable: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getOrLookupByNames", "(Ljava/lang/Iterable;)Ljava/util/Map;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getOrLookupByNames was null") }
+    ret
+  }
+
+  pub fn fill_profile_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "fillProfile", "(Lorg/spongepowered/api/profile/GameProfile;)Ljava/util/Optional;", CallObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#fillProfile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn fill_profile_game_profile_bool(&self, game_profile: ::sponge::profile::GameProfile, value: bool) -> Option<::sponge::profile::GameProfile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "fillProfile", "(Lorg/spongepowered/api/profile/GameProfile;Z)Ljava/util/Optional;", CallObjectMethodA, game_profile.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#fillProfile was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::profile::GameProfile { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_profiles(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "getProfiles", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#getProfiles was null") }
+    ret
+  }
+
+  pub fn match_(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileCache", self.object, "match", "(Ljava/lang/String;)Ljava/util/Collection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileCache#match was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GameProfileManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GameProfileManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GameProfileManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GameProfileManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/profile/GameProfileManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_profile(&self, uuid: jobject, string: jobject) -> ::sponge::profile::GameProfile {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "createProfile", "(Ljava/util/UUID;Ljava/lang/String;)Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethodA, uuid, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#createProfile was null") }
+    ::sponge::profile::GameProfile { env: self.env, object: ret }
+  }
+
+  pub fn create_profile_property(&self, string: jobject, string_2: jobject, string_3: jobject) -> ::sponge::profile::property::ProfileProperty {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "createProfileProperty", "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)Lorg/spongepowered/api/profile/property/ProfileProperty;", CallObjectMethodA, string, string_2, string_3);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#createProfileProperty was null") }
+    ::sponge::profile::property::ProfileProperty { env: self.env, object: ret }
+  }
+
+  pub fn get_uuid(&self, uuid: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "get", "(Ljava/util/UUID;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#get was null") }
+    ret
+  }
+
+  pub fn get_uuid_bool(&self, uuid: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "get", "(Ljava/util/UUID;Z)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, uuid, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#get was null") }
+    ret
+  }
+
+  pub fn get_all_by_id(&self, iterable: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "getAllById", "(Ljava/lang/Iterable;Z)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, iterable, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#getAllById was null") }
+    ret
+  }
+
+  pub fn get_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "get", "(Ljava/lang/String;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#get was null") }
+    ret
+  }
+
+  pub fn get_string_bool(&self, string: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "get", "(Ljava/lang/String;Z)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#get was null") }
+    ret
+  }
+
+  pub fn get_all_by_name(&self, iterable: jobject, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "getAllByName", "(Ljava/lang/Iterable;Z)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, iterable, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#getAllByName was null") }
+    ret
+  }
+
+  pub fn fill_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "fill", "(Lorg/spongepowered/api/profile/GameProfile;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#fill was null") }
+    ret
+  }
+
+  pub fn fill_game_profile_bool(&self, game_profile: ::sponge::profile::GameProfile, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "fill", "(Lorg/spongepowered/api/profile/GameProfile;Z)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, game_profile.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#fill was null") }
+    ret
+  }
+
+  pub fn fill_game_profile_bool_bool(&self, game_profile: ::sponge::profile::GameProfile, value: bool, value_2: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "fill", "(Lorg/spongepowered/api/profile/GameProfile;ZZ)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, game_profile.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#fill was null") }
+    ret
+  }
+
+  pub fn get_cache(&self) -> ::sponge::profile::GameProfileCache {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "getCache", "()Lorg/spongepowered/api/profile/GameProfileCache;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#getCache was null") }
+    ::sponge::profile::GameProfileCache { env: self.env, object: ret }
+  }
+
+  pub fn set_cache(&self, game_profile_cache: ::sponge::profile::GameProfileCache) {
+    bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "setCache", "(Lorg/spongepowered/api/profile/GameProfileCache;)V", CallVoidMethodA, game_profile_cache.object);
+  }
+
+  pub fn get_default_cache(&self) -> ::sponge::profile::GameProfileCache {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "getDefaultCache", "()Lorg/spongepowered/api/profile/GameProfileCache;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#getDefaultCache was null") }
+    ::sponge::profile::GameProfileCache { env: self.env, object: ret }
+  }
+
+  pub fn get_cached_profiles(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "getCachedProfiles", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#getCachedProfiles was null") }
+    ret
+  }
+
+  pub fn match_(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/GameProfileManager", self.object, "match", "(Ljava/lang/String;)Ljava/util/Collection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/GameProfileManager#match was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ProfileNotFoundException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProfileNotFoundException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProfileNotFoundException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ProfileNotFoundException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/profile/ProfileNotFoundException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::profile::ProfileNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/profile/ProfileNotFoundException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/profile/ProfileNotFoundException#<init> was null") }
+    ::sponge::profile::ProfileNotFoundException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::profile::ProfileNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/profile/ProfileNotFoundException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/ProfileNotFoundException#<init> was null") }
+    ::sponge::profile::ProfileNotFoundException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::profile::ProfileNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/profile/ProfileNotFoundException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/ProfileNotFoundException#<init> was null") }
+    ::sponge::profile::ProfileNotFoundException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::profile::ProfileNotFoundException {
+    let ret = new_object!(env, "org/spongepowered/api/profile/ProfileNotFoundException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/ProfileNotFoundException#<init> was null") }
+    ::sponge::profile::ProfileNotFoundException { env: env, object: ret }
+  }
+
+}
@@ -0,0 +1,59 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ProfileProperty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProfileProperty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProfileProperty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ProfileProperty`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/profile/property/ProfileProperty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of_string_string(env: *mut JNIEnv, string: jobject, string_2: jobject) -> ::sponge::profile::property::ProfileProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/profile/property/ProfileProperty", "of", "(Ljava/lang/String;Ljava/lang/String;)Lorg/spongepowered/api/profile/property/ProfileProperty;", CallStaticObjectMethodA, string, string_2);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/property/ProfileProperty#of was null") }
+    ::sponge::profile::property::ProfileProperty { env: env, object: ret }
+  }
+
+  pub fn of_string_string_string(env: *mut JNIEnv, string: jobject, string_2: jobject, string_3: jobject) -> ::sponge::profile::property::ProfileProperty {
+    let ret = static_java_method!(env, "org/spongepowered/api/profile/property/ProfileProperty", "of", "(Ljava/lang/String;Ljava/lang/String;Ljava/lang/String;)Lorg/spongepowered/api/profile/property/ProfileProperty;", CallStaticObjectMethodA, string, string_2, string_3);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/property/ProfileProperty#of was null") }
+    ::sponge::profile::property::ProfileProperty { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/property/ProfileProperty", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/property/ProfileProperty#getName was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/property/ProfileProperty", self.object, "getValue", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/property/ProfileProperty#getValue was null") }
+    ret
+  }
+
+  pub fn get_signature(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/profile/property/ProfileProperty", self.object, "getSignature", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/profile/property/ProfileProperty#getSignature was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn has_signature(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/profile/property/ProfileProperty", self.object, "hasSignature", "()Z", CallBooleanMethod) != 0
+  }
+
+}
@@ -0,0 +1,347 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod util;
+
+#[derive(Debug)]
+pub struct AdditionalCatalogRegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AdditionalCatalogRegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AdditionalCatalogRegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AdditionalCatalogRegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/AdditionalCatalogRegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_additional_catalog(&self, catalog_type: ::sponge::CatalogType) {
+    bound_method!(self.env, "org/spongepowered/api/registry/AdditionalCatalogRegistryModule", self.object, "registerAdditionalCatalog", "(Lorg/spongepowered/api/CatalogType;)V", CallVoidMethodA, catalog_type.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct AlternateCatalogRegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AlternateCatalogRegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AlternateCatalogRegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AlternateCatalogRegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/AlternateCatalogRegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn provide_catalog_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/AlternateCatalogRegistryModule", self.object, "provideCatalogMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/AlternateCatalogRegistryModule#provideCatalogMap was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogRegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogRegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogRegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogRegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/CatalogRegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_by_id(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/CatalogRegistryModule", self.object, "getById", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/CatalogRegistryModule#getById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/CatalogRegistryModule", self.object, "getAll", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/CatalogRegistryModule#getAll was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CatalogTypeAlreadyRegisteredException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogTypeAlreadyRegisteredException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogTypeAlreadyRegisteredException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CatalogTypeAlreadyRegisteredException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/CatalogTypeAlreadyRegisteredException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::registry::CatalogTypeAlreadyRegisteredException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/CatalogTypeAlreadyRegisteredException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/CatalogTypeAlreadyRegisteredException#<init> was null") }
+    ::sponge::registry::CatalogTypeAlreadyRegisteredException { env: env, object: ret }
+  }
+
+  pub fn get_already_registered_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/CatalogTypeAlreadyRegisteredException", self.object, "getAlreadyRegisteredId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/CatalogTypeAlreadyRegisteredException#getAlreadyRegisteredId was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExtraClassCatalogRegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExtraClassCatalogRegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExtraClassCatalogRegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExtraClassCatalogRegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/ExtraClassCatalogRegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_registration_for(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/registry/ExtraClassCatalogRegistryModule", self.object, "hasRegistrationFor", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  pub fn get_for_class(&self, class: jobject) -> ::sponge::CatalogType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/ExtraClassCatalogRegistryModule", self.object, "getForClass", "(Ljava/lang/Class;)Lorg/spongepowered/api/CatalogType;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/ExtraClassCatalogRegistryModule#getForClass was null") }
+    ::sponge::CatalogType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FactoryRegistry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FactoryRegistry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FactoryRegistry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FactoryRegistry`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/FactoryRegistry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_factory_owner(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/FactoryRegistry", self.object, "getFactoryOwner", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/FactoryRegistry#getFactoryOwner was null") }
+    ret
+  }
+
+  pub fn provide_factory(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/FactoryRegistry", self.object, "provideFactory", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/FactoryRegistry#provideFactory was null") }
+    ret
+  }
+
+  pub fn initialize(&self) {
+    bound_method!(self.env, "org/spongepowered/api/registry/FactoryRegistry", self.object, "initialize", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegistrationPhase {
+  PreRegistry,
+  PreInit,
+  Init,
+  PostInit,
+  Loaded,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static REGISTRATION_PHASE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/registry/RegistrationPhase", &["PRE_REGISTRY", "PRE_INIT", "INIT", "POST_INIT", "LOADED"]);
+
+impl RegistrationPhase {
+  pub const VALUES: &'static [RegistrationPhase] = &[RegistrationPhase::PreRegistry, RegistrationPhase::PreInit, RegistrationPhase::Init, RegistrationPhase::PostInit, RegistrationPhase::Loaded];
+
+}
+
+impl ::convert::FromJava for RegistrationPhase {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match REGISTRATION_PHASE_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => RegistrationPhase::VALUES[ordinal],
+      None => RegistrationPhase::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for RegistrationPhase {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      RegistrationPhase::Other(object) => object,
+      value => REGISTRATION_PHASE_CONSTANTS.get(env, RegistrationPhase::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct RegistryException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RegistryException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RegistryException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `RegistryException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/RegistryException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::registry::RegistryException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/RegistryException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryException#<init> was null") }
+    ::sponge::registry::RegistryException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::registry::RegistryException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/RegistryException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryException#<init> was null") }
+    ::sponge::registry::RegistryException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::registry::RegistryException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/RegistryException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryException#<init> was null") }
+    ::sponge::registry::RegistryException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::registry::RegistryException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/RegistryException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryException#<init> was null") }
+    ::sponge::registry::RegistryException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/RegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_defaults(&self) {
+    bound_method!(self.env, "org/spongepowered/api/registry/RegistryModule", self.object, "registerDefaults", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RegistryModuleAlreadyRegisteredException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RegistryModuleAlreadyRegisteredException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RegistryModuleAlreadyRegisteredException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `RegistryModuleAlreadyRegisteredException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/RegistryModuleAlreadyRegisteredException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, registry_module: ::sponge::registry::RegistryModule) -> ::sponge::registry::RegistryModuleAlreadyRegisteredException {
+    let ret = new_object!(env, "org/spongepowered/api/registry/RegistryModuleAlreadyRegisteredException", "(Ljava/lang/String;Lorg/spongepowered/api/registry/RegistryModule;)V", string, registry_module.object);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryModuleAlreadyRegisteredException#<init> was null") }
+    ::sponge::registry::RegistryModuleAlreadyRegisteredException { env: env, object: ret }
+  }
+
+  pub fn get_module(&self) -> ::sponge::registry::RegistryModule {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/RegistryModuleAlreadyRegisteredException", self.object, "getModule", "()Lorg/spongepowered/api/registry/RegistryModule;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/RegistryModuleAlreadyRegisteredException#getModule was null") }
+    ::sponge::registry::RegistryModule { env: self.env, object: ret }
+  }
+
+}
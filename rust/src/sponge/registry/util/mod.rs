@@ -0,0 +1,192 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AdditionalRegistration {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AdditionalRegistration {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AdditionalRegistration {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AdditionalRegistration`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/AdditionalRegistration", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> ::sponge::registry::RegistrationPhase {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/util/AdditionalRegistration", self.object, "value", "()Lorg/spongepowered/api/registry/RegistrationPhase;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/util/AdditionalRegistration#value was null") }
+    <::sponge::registry::RegistrationPhase as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CustomCatalogRegistration {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CustomCatalogRegistration {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CustomCatalogRegistration {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CustomCatalogRegistration`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/CustomCatalogRegistration", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DelayedRegistration {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DelayedRegistration {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DelayedRegistration {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DelayedRegistration`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/DelayedRegistration", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> ::sponge::registry::RegistrationPhase {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/util/DelayedRegistration", self.object, "value", "()Lorg/spongepowered/api/registry/RegistrationPhase;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/util/DelayedRegistration#value was null") }
+    <::sponge::registry::RegistrationPhase as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct OverrideRegistration {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OverrideRegistration {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OverrideRegistration {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OverrideRegistration`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/OverrideRegistration", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/util/OverrideRegistration", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/util/OverrideRegistration#value was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PluginProvidedRegistryModule {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PluginProvidedRegistryModule {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PluginProvidedRegistryModule {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PluginProvidedRegistryModule`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/PluginProvidedRegistryModule", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RegisterCatalog {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RegisterCatalog {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RegisterCatalog {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RegisterCatalog`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/RegisterCatalog", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/registry/util/RegisterCatalog", self.object, "value", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/registry/util/RegisterCatalog#value was null") }
+    ret
+  }
+
+  pub fn ignored_fields(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct RegistrationDependency {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RegistrationDependency {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RegistrationDependency {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RegistrationDependency`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/registry/util/RegistrationDependency", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+}
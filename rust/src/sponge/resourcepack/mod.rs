@@ -0,0 +1,125 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ResourcePack {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResourcePack {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResourcePack {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ResourcePack`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/resourcepack/ResourcePack", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_uri(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePack", self.object, "getUri", "()Ljava/net/URI;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePack#getUri was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePack", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePack#getName was null") }
+    ret
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePack", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePack#getId was null") }
+    ret
+  }
+
+  pub fn get_hash(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePack", self.object, "getHash", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePack#getHash was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ResourcePackFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResourcePackFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResourcePackFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ResourcePackFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/resourcepack/ResourcePackFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn from_uri(&self, uri: jobject) -> ::sponge::resourcepack::ResourcePack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePackFactory", self.object, "fromUri", "(Ljava/net/URI;)Lorg/spongepowered/api/resourcepack/ResourcePack;", CallObjectMethodA, uri);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePackFactory#fromUri was null") }
+    ::sponge::resourcepack::ResourcePack { env: self.env, object: ret }
+  }
+
+  pub fn from_uri_unchecked(&self, uri: jobject) -> ::sponge::resourcepack::ResourcePack {
+    let ret = bound_method!(self.env, "org/spongepowered/api/resourcepack/ResourcePackFactory", self.object, "fromUriUnchecked", "(Ljava/net/URI;)Lorg/spongepowered/api/resourcepack/ResourcePack;", CallObjectMethodA, uri);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePackFactory#fromUriUnchecked was null") }
+    ::sponge::resourcepack::ResourcePack { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ResourcePacks {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResourcePacks {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResourcePacks {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ResourcePacks`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/resourcepack/ResourcePacks", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::resourcepack::ResourcePacks {
+    let ret = new_object!(env, "org/spongepowered/api/resourcepack/ResourcePacks", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePacks#<init> was null") }
+    ::sponge::resourcepack::ResourcePacks { env: env, object: ret }
+  }
+
+  pub fn from_uri(env: *mut JNIEnv, uri: jobject) -> ::sponge::resourcepack::ResourcePack {
+    let ret = static_java_method!(env, "org/spongepowered/api/resourcepack/ResourcePacks", "fromUri", "(Ljava/net/URI;)Lorg/spongepowered/api/resourcepack/ResourcePack;", CallStaticObjectMethodA, uri);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePacks#fromUri was null") }
+    ::sponge::resourcepack::ResourcePack { env: env, object: ret }
+  }
+
+  pub fn from_uri_unchecked(env: *mut JNIEnv, uri: jobject) -> ::sponge::resourcepack::ResourcePack {
+    let ret = static_java_method!(env, "org/spongepowered/api/resourcepack/ResourcePacks", "fromUriUnchecked", "(Ljava/net/URI;)Lorg/spongepowered/api/resourcepack/ResourcePack;", CallStaticObjectMethodA, uri);
+    if ret.is_null() { panic!("org/spongepowered/api/resourcepack/ResourcePacks#fromUriUnchecked was null") }
+    ::sponge::resourcepack::ResourcePack { env: env, object: ret }
+  }
+
+}
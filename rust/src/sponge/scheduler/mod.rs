@@ -0,0 +1,348 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AsynchronousExecutor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AsynchronousExecutor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AsynchronousExecutor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `AsynchronousExecutor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/AsynchronousExecutor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Scheduler {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Scheduler {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Scheduler {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Scheduler`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/Scheduler", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_task_builder(&self) -> ::sponge::scheduler::task::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "createTaskBuilder", "()Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#createTaskBuilder was null") }
+    ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+  }
+
+  pub fn get_task_by_id(&self, uuid: jobject) -> Option<::sponge::scheduler::Task> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getTaskById", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#getTaskById was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scheduler::Task { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_tasks_by_name(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getTasksByName", "(Ljava/lang/String;)Ljava/util/Set;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#getTasksByName was null") }
+    ret
+  }
+
+  pub fn get_scheduled_tasks(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getScheduledTasks", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#getScheduledTasks was null") }
+    ret
+  }
+
+  pub fn get_scheduled_tasks_bool(&self, value: bool) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getScheduledTasks", "(Z)Ljava/util/Set;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#getScheduledTasks was null") }
+    ret
+  }
+
+  pub fn get_scheduled_tasks_object(&self, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getScheduledTasks", "(Ljava/lang/Object;)Ljava/util/Set;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#getScheduledTasks was null") }
+    ret
+  }
+
+  pub fn get_preferred_tick_interval(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "getPreferredTickInterval", "()I", CallIntMethod)
+  }
+
+  pub fn create_sync_executor(&self, object: jobject) -> ::sponge::scheduler::SpongeExecutorService {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "createSyncExecutor", "(Ljava/lang/Object;)Lorg/spongepowered/api/scheduler/SpongeExecutorService;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#createSyncExecutor was null") }
+    ::sponge::scheduler::SpongeExecutorService { env: self.env, object: ret }
+  }
+
+  pub fn create_async_executor(&self, object: jobject) -> ::sponge::scheduler::SpongeExecutorService {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Scheduler", self.object, "createAsyncExecutor", "(Ljava/lang/Object;)Lorg/spongepowered/api/scheduler/SpongeExecutorService;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Scheduler#createAsyncExecutor was null") }
+    ::sponge::scheduler::SpongeExecutorService { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpongeExecutorService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpongeExecutorService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpongeExecutorService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpongeExecutorService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/SpongeExecutorService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn schedule_runnable_i64_time_unit(&self, runnable: jobject, value: i64, time_unit: jobject) -> ::sponge::scheduler::sponge_executor_service::SpongeFuture {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/SpongeExecutorService", self.object, "schedule", "(Ljava/lang/Runnable;JLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture;", CallObjectMethodA, runnable, value, time_unit);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/SpongeExecutorService#schedule was null") }
+    ::sponge::scheduler::sponge_executor_service::SpongeFuture { env: self.env, object: ret }
+  }
+
+  pub fn schedule_callable_i64_time_unit(&self, callable: jobject, value: i64, time_unit: jobject) -> ::sponge::scheduler::sponge_executor_service::SpongeFuture {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/SpongeExecutorService", self.object, "schedule", "(Ljava/util/concurrent/Callable;JLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture;", CallObjectMethodA, callable, value, time_unit);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/SpongeExecutorService#schedule was null") }
+    ::sponge::scheduler::sponge_executor_service::SpongeFuture { env: self.env, object: ret }
+  }
+
+  pub fn schedule_at_fixed_rate(&self, runnable: jobject, value: i64, value_2: i64, time_unit: jobject) -> ::sponge::scheduler::sponge_executor_service::SpongeFuture {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/SpongeExecutorService", self.object, "scheduleAtFixedRate", "(Ljava/lang/Runnable;JJLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture;", CallObjectMethodA, runnable, value, value_2, time_unit);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/SpongeExecutorService#scheduleAtFixedRate was null") }
+    ::sponge::scheduler::sponge_executor_service::SpongeFuture { env: self.env, object: ret }
+  }
+
+  pub fn schedule_with_fixed_delay(&self, runnable: jobject, value: i64, value_2: i64, time_unit: jobject) -> ::sponge::scheduler::sponge_executor_service::SpongeFuture {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/SpongeExecutorService", self.object, "scheduleWithFixedDelay", "(Ljava/lang/Runnable;JJLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture;", CallObjectMethodA, runnable, value, value_2, time_unit);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/SpongeExecutorService#scheduleWithFixedDelay was null") }
+    ::sponge::scheduler::sponge_executor_service::SpongeFuture { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SynchronousExecutor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SynchronousExecutor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SynchronousExecutor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SynchronousExecutor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/SynchronousExecutor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Task {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Task {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Task {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Task`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/Task", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::scheduler::task::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/scheduler/Task", "builder", "()Lorg/spongepowered/api/scheduler/Task$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task#builder was null") }
+    ::sponge::scheduler::task::Builder { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task#getName was null") }
+    ret
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_owner(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "getOwner", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task#getOwner was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_delay(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "getDelay", "()J", CallLongMethod)
+  }
+
+  pub fn get_interval(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "getInterval", "()J", CallLongMethod)
+  }
+
+  pub fn cancel(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "cancel", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_consumer(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "getConsumer", "()Ljava/util/function/Consumer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task#getConsumer was null") }
+    ret
+  }
+
+  pub fn is_asynchronous(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scheduler/Task", self.object, "isAsynchronous", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+pub mod sponge_executor_service {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct SpongeFuture {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl SpongeFuture {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      SpongeFuture {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpongeFuture`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_task(&self) -> ::sponge::scheduler::Task {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture", self.object, "getTask", "()Lorg/spongepowered/api/scheduler/Task;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/SpongeExecutorService$SpongeFuture#getTask was null") }
+      ::sponge::scheduler::Task { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod task {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scheduler/Task$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn async(&self) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "async", "()Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#async was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn execute_runnable(&self, runnable: jobject) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "execute", "(Ljava/lang/Runnable;)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, runnable);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#execute was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn execute_consumer(&self, consumer: jobject) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "execute", "(Ljava/util/function/Consumer;)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, consumer);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#execute was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn delay(&self, value: i64, time_unit: jobject) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "delay", "(JLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, value, time_unit);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#delay was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn delay_ticks(&self, value: i64) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "delayTicks", "(J)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#delayTicks was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn interval(&self, value: i64, time_unit: jobject) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "interval", "(JLjava/util/concurrent/TimeUnit;)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, value, time_unit);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#interval was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn interval_ticks(&self, value: i64) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "intervalTicks", "(J)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#intervalTicks was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn name(&self, string: jobject) -> ::sponge::scheduler::task::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "name", "(Ljava/lang/String;)Lorg/spongepowered/api/scheduler/Task$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#name was null") }
+      ::sponge::scheduler::task::Builder { env: self.env, object: ret }
+    }
+
+    pub fn submit(&self, object: jobject) -> ::sponge::scheduler::Task {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scheduler/Task$Builder", self.object, "submit", "(Ljava/lang/Object;)Lorg/spongepowered/api/scheduler/Task;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/scheduler/Task$Builder#submit was null") }
+      ::sponge::scheduler::Task { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,53 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Criteria {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Criteria {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Criteria {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Criteria`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/critieria/Criteria", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Criterion {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Criterion {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Criterion {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Criterion`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/critieria/Criterion", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/critieria/Criterion", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/critieria/Criterion#getName was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,95 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DisplaySlot {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisplaySlot {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisplaySlot {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisplaySlot`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/displayslot/DisplaySlot", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_team_color(&self) -> Option<::sponge::text::format::TextColor> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/displayslot/DisplaySlot", self.object, "getTeamColor", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/displayslot/DisplaySlot#getTeamColor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::format::TextColor { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DisplaySlots {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DisplaySlots {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DisplaySlots {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DisplaySlots`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/displayslot/DisplaySlots", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod display_slot {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn sidebar_team_color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::scoreboard::displayslot::display_slot::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder", self.object, "sidebarTeamColor", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder#sidebarTeamColor was null") }
+      ::sponge::scoreboard::displayslot::display_slot::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::scoreboard::displayslot::DisplaySlot {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder", self.object, "build", "()Lorg/spongepowered/api/scoreboard/displayslot/DisplaySlot;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/displayslot/DisplaySlot$Builder#build was null") }
+      ::sponge::scoreboard::displayslot::DisplaySlot { env: self.env, object: ret }
+    }
+
+  }
+}
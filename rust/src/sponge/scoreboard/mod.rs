@@ -0,0 +1,527 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod critieria;
+pub mod displayslot;
+pub mod objective;
+
+#[derive(Debug)]
+pub struct Score {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Score {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Score {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Score`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Score", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Score", self.object, "getName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Score#getName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_score(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Score", self.object, "getScore", "()I", CallIntMethod)
+  }
+
+  pub fn set_score(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Score", self.object, "setScore", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_objectives(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Score", self.object, "getObjectives", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Score#getObjectives was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Scoreboard {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Scoreboard {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Scoreboard {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Scoreboard`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Scoreboard", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::scoreboard::scoreboard::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/scoreboard/Scoreboard", "builder", "()Lorg/spongepowered/api/scoreboard/Scoreboard$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#builder was null") }
+    ::sponge::scoreboard::scoreboard::Builder { env: env, object: ret }
+  }
+
+  pub fn get_objective_string(&self, string: jobject) -> Option<::sponge::scoreboard::objective::Objective> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getObjective", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getObjective was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::objective::Objective { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_objective_display_slot(&self, display_slot: ::sponge::scoreboard::displayslot::DisplaySlot) -> Option<::sponge::scoreboard::objective::Objective> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getObjective", "(Lorg/spongepowered/api/scoreboard/displayslot/DisplaySlot;)Ljava/util/Optional;", CallObjectMethodA, display_slot.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getObjective was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::objective::Objective { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn add_objective(&self, objective: ::sponge::scoreboard::objective::Objective) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "addObjective", "(Lorg/spongepowered/api/scoreboard/objective/Objective;)V", CallVoidMethodA, objective.object);
+  }
+
+  pub fn update_display_slot(&self, objective: ::sponge::scoreboard::objective::Objective, display_slot: ::sponge::scoreboard::displayslot::DisplaySlot) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "updateDisplaySlot", "(Lorg/spongepowered/api/scoreboard/objective/Objective;Lorg/spongepowered/api/scoreboard/displayslot/DisplaySlot;)V", CallVoidMethodA, objective.object, display_slot.object);
+  }
+
+  pub fn clear_slot(&self, display_slot: ::sponge::scoreboard::displayslot::DisplaySlot) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "clearSlot", "(Lorg/spongepowered/api/scoreboard/displayslot/DisplaySlot;)V", CallVoidMethodA, display_slot.object);
+  }
+
+  pub fn get_objectives_by_criteria(&self, criterion: ::sponge::scoreboard::critieria::Criterion) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getObjectivesByCriteria", "(Lorg/spongepowered/api/scoreboard/critieria/Criterion;)Ljava/util/Set;", CallObjectMethodA, criterion.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getObjectivesByCriteria was null") }
+    ret
+  }
+
+  pub fn get_objectives(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getObjectives", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getObjectives was null") }
+    ret
+  }
+
+  pub fn remove_objective(&self, objective: ::sponge::scoreboard::objective::Objective) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "removeObjective", "(Lorg/spongepowered/api/scoreboard/objective/Objective;)V", CallVoidMethodA, objective.object);
+  }
+
+  pub fn get_scores(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getScores", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getScores was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_scores_text(&self, text: ::sponge::text::Text) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getScores", "(Lorg/spongepowered/api/text/Text;)Ljava/util/Set;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getScores was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn remove_scores(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "removeScores", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  pub fn get_team(&self, string: jobject) -> Option<::sponge::scoreboard::Team> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getTeam", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getTeam was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::Team { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn register_team(&self, team: ::sponge::scoreboard::Team) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "registerTeam", "(Lorg/spongepowered/api/scoreboard/Team;)V", CallVoidMethodA, team.object);
+  }
+
+  pub fn get_teams(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getTeams", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getTeams was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_member_team(&self, text: ::sponge::text::Text) -> Option<::sponge::scoreboard::Team> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard", self.object, "getMemberTeam", "(Lorg/spongepowered/api/text/Text;)Ljava/util/Optional;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard#getMemberTeam was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::Team { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Team {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Team {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Team {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Team`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Team", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::scoreboard::team::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/scoreboard/Team", "builder", "()Lorg/spongepowered/api/scoreboard/Team$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#builder was null") }
+    ::sponge::scoreboard::team::Builder { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getName was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_display_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getDisplayName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getDisplayName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_display_name(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setDisplayName", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_color(&self) -> ::sponge::text::format::TextColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getColor", "()Lorg/spongepowered/api/text/format/TextColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getColor was null") }
+    ::sponge::text::format::TextColor { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_color(&self, text_color: ::sponge::text::format::TextColor) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setColor", "(Lorg/spongepowered/api/text/format/TextColor;)V", CallVoidMethodA, text_color.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_prefix(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getPrefix", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getPrefix was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_prefix(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setPrefix", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_suffix(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getSuffix", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getSuffix was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_suffix(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setSuffix", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  pub fn allow_friendly_fire(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "allowFriendlyFire", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_allow_friendly_fire(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setAllowFriendlyFire", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn can_see_friendly_invisibles(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "canSeeFriendlyInvisibles", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_can_see_friendly_invisibles(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setCanSeeFriendlyInvisibles", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_name_tag_visibility(&self) -> ::sponge::scoreboard::Visibility {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getNameTagVisibility", "()Lorg/spongepowered/api/scoreboard/Visibility;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getNameTagVisibility was null") }
+    ::sponge::scoreboard::Visibility { env: self.env, object: ret }
+  }
+
+  pub fn set_name_tag_visibility(&self, visibility: ::sponge::scoreboard::Visibility) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setNameTagVisibility", "(Lorg/spongepowered/api/scoreboard/Visibility;)V", CallVoidMethodA, visibility.object);
+  }
+
+  pub fn get_death_message_visibility(&self) -> ::sponge::scoreboard::Visibility {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getDeathMessageVisibility", "()Lorg/spongepowered/api/scoreboard/Visibility;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getDeathMessageVisibility was null") }
+    ::sponge::scoreboard::Visibility { env: self.env, object: ret }
+  }
+
+  pub fn set_death_message_visibility(&self, visibility: ::sponge::scoreboard::Visibility) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "setDeathMessageVisibility", "(Lorg/spongepowered/api/scoreboard/Visibility;)V", CallVoidMethodA, visibility.object);
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getMembers", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getMembers was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn add_member(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "addMember", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn remove_member(&self, text: ::sponge::text::Text) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "removeMember", "(Lorg/spongepowered/api/text/Text;)Z", CallBooleanMethodA, text.object) != 0
+  }
+
+  pub fn get_scoreboard(&self) -> Option<::sponge::scoreboard::Scoreboard> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "getScoreboard", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team#getScoreboard was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::Scoreboard { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn unregister(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/Team", self.object, "unregister", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeamMember {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeamMember {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeamMember {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeamMember`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/TeamMember", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_team_representation(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/TeamMember", self.object, "getTeamRepresentation", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/TeamMember#getTeamRepresentation was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Visibilities {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Visibilities {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Visibilities {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Visibilities`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Visibilities", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Visibility {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Visibility {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Visibility {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Visibility`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Visibility", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod scoreboard {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Scoreboard$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn objectives(&self, list: jobject) -> ::sponge::scoreboard::scoreboard::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard$Builder", self.object, "objectives", "(Ljava/util/List;)Lorg/spongepowered/api/scoreboard/Scoreboard$Builder;", CallObjectMethodA, list);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard$Builder#objectives was null") }
+      ::sponge::scoreboard::scoreboard::Builder { env: self.env, object: ret }
+    }
+
+    pub fn teams(&self, list: jobject) -> ::sponge::scoreboard::scoreboard::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard$Builder", self.object, "teams", "(Ljava/util/List;)Lorg/spongepowered/api/scoreboard/Scoreboard$Builder;", CallObjectMethodA, list);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard$Builder#teams was null") }
+      ::sponge::scoreboard::scoreboard::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::scoreboard::Scoreboard {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Scoreboard$Builder", self.object, "build", "()Lorg/spongepowered/api/scoreboard/Scoreboard;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Scoreboard$Builder#build was null") }
+      ::sponge::scoreboard::Scoreboard { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod team {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/Team$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn name(&self, string: jobject) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "name", "(Ljava/lang/String;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#name was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#color was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn display_name(&self, text: ::sponge::text::Text) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "displayName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#displayName was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn prefix(&self, text: ::sponge::text::Text) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "prefix", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#prefix was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn suffix(&self, text: ::sponge::text::Text) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "suffix", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#suffix was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn allow_friendly_fire(&self, value: bool) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "allowFriendlyFire", "(Z)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#allowFriendlyFire was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn can_see_friendly_invisibles(&self, value: bool) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "canSeeFriendlyInvisibles", "(Z)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#canSeeFriendlyInvisibles was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn name_tag_visibility(&self, visibility: ::sponge::scoreboard::Visibility) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "nameTagVisibility", "(Lorg/spongepowered/api/scoreboard/Visibility;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, visibility.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#nameTagVisibility was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn death_text_visibility(&self, visibility: ::sponge::scoreboard::Visibility) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "deathTextVisibility", "(Lorg/spongepowered/api/scoreboard/Visibility;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, visibility.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#deathTextVisibility was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn members(&self, set: jobject) -> ::sponge::scoreboard::team::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "members", "(Ljava/util/Set;)Lorg/spongepowered/api/scoreboard/Team$Builder;", CallObjectMethodA, set);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#members was null") }
+      ::sponge::scoreboard::team::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::scoreboard::Team {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/Team$Builder", self.object, "build", "()Lorg/spongepowered/api/scoreboard/Team;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/Team$Builder#build was null") }
+      ::sponge::scoreboard::Team { env: self.env, object: ret }
+    }
+
+  }
+}
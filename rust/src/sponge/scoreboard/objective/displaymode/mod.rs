@@ -0,0 +1,47 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ObjectiveDisplayMode {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ObjectiveDisplayMode {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ObjectiveDisplayMode {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ObjectiveDisplayMode`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/objective/displaymode/ObjectiveDisplayMode", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ObjectiveDisplayModes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ObjectiveDisplayModes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ObjectiveDisplayModes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ObjectiveDisplayModes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/objective/displaymode/ObjectiveDisplayModes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
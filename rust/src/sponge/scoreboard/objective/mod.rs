@@ -0,0 +1,169 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod displaymode;
+
+#[derive(Debug)]
+pub struct Objective {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Objective {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Objective {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Objective`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/objective/Objective", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::scoreboard::objective::objective::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/scoreboard/objective/Objective", "builder", "()Lorg/spongepowered/api/scoreboard/objective/Objective$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#builder was null") }
+    ::sponge::scoreboard::objective::objective::Builder { env: env, object: ret }
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getName was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_display_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getDisplayName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getDisplayName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn set_display_name(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "setDisplayName", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  pub fn get_criterion(&self) -> ::sponge::scoreboard::critieria::Criterion {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getCriterion", "()Lorg/spongepowered/api/scoreboard/critieria/Criterion;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getCriterion was null") }
+    ::sponge::scoreboard::critieria::Criterion { env: self.env, object: ret }
+  }
+
+  pub fn get_display_mode(&self) -> ::sponge::scoreboard::objective::displaymode::ObjectiveDisplayMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getDisplayMode", "()Lorg/spongepowered/api/scoreboard/objective/displaymode/ObjectiveDisplayMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getDisplayMode was null") }
+    ::sponge::scoreboard::objective::displaymode::ObjectiveDisplayMode { env: self.env, object: ret }
+  }
+
+  pub fn set_display_mode(&self, objective_display_mode: ::sponge::scoreboard::objective::displaymode::ObjectiveDisplayMode) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "setDisplayMode", "(Lorg/spongepowered/api/scoreboard/objective/displaymode/ObjectiveDisplayMode;)V", CallVoidMethodA, objective_display_mode.object);
+  }
+
+  pub fn get_scores(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getScores", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getScores was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn has_score(&self, text: ::sponge::text::Text) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "hasScore", "(Lorg/spongepowered/api/text/Text;)Z", CallBooleanMethodA, text.object) != 0
+  }
+
+  pub fn add_score(&self, score: ::sponge::scoreboard::Score) {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "addScore", "(Lorg/spongepowered/api/scoreboard/Score;)V", CallVoidMethodA, score.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_score(&self, text: ::sponge::text::Text) -> Option<::sponge::scoreboard::Score> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getScore", "(Lorg/spongepowered/api/text/Text;)Ljava/util/Optional;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getScore was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::scoreboard::Score { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_or_create_score(&self, text: ::sponge::text::Text) -> ::sponge::scoreboard::Score {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getOrCreateScore", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/scoreboard/Score;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getOrCreateScore was null") }
+    ::sponge::scoreboard::Score { env: self.env, object: ret }
+  }
+
+  pub fn remove_score_score(&self, score: ::sponge::scoreboard::Score) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "removeScore", "(Lorg/spongepowered/api/scoreboard/Score;)Z", CallBooleanMethodA, score.object) != 0
+  }
+
+  #[cfg(feature = "text")]
+  pub fn remove_score_text(&self, text: ::sponge::text::Text) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "removeScore", "(Lorg/spongepowered/api/text/Text;)Z", CallBooleanMethodA, text.object) != 0
+  }
+
+  pub fn get_scoreboards(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective", self.object, "getScoreboards", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective#getScoreboards was null") }
+    ret
+  }
+
+}
+
+pub mod objective {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/scoreboard/objective/Objective$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn name(&self, string: jobject) -> ::sponge::scoreboard::objective::objective::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective$Builder", self.object, "name", "(Ljava/lang/String;)Lorg/spongepowered/api/scoreboard/objective/Objective$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective$Builder#name was null") }
+      ::sponge::scoreboard::objective::objective::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn display_name(&self, text: ::sponge::text::Text) -> ::sponge::scoreboard::objective::objective::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective$Builder", self.object, "displayName", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/scoreboard/objective/Objective$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective$Builder#displayName was null") }
+      ::sponge::scoreboard::objective::objective::Builder { env: self.env, object: ret }
+    }
+
+    pub fn criterion(&self, criterion: ::sponge::scoreboard::critieria::Criterion) -> ::sponge::scoreboard::objective::objective::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective$Builder", self.object, "criterion", "(Lorg/spongepowered/api/scoreboard/critieria/Criterion;)Lorg/spongepowered/api/scoreboard/objective/Objective$Builder;", CallObjectMethodA, criterion.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective$Builder#criterion was null") }
+      ::sponge::scoreboard::objective::objective::Builder { env: self.env, object: ret }
+    }
+
+    pub fn objective_display_mode(&self, objective_display_mode: ::sponge::scoreboard::objective::displaymode::ObjectiveDisplayMode) -> ::sponge::scoreboard::objective::objective::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective$Builder", self.object, "objectiveDisplayMode", "(Lorg/spongepowered/api/scoreboard/objective/displaymode/ObjectiveDisplayMode;)Lorg/spongepowered/api/scoreboard/objective/Objective$Builder;", CallObjectMethodA, objective_display_mode.object);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective$Builder#objectiveDisplayMode was null") }
+      ::sponge::scoreboard::objective::objective::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::scoreboard::objective::Objective {
+      let ret = bound_method!(self.env, "org/spongepowered/api/scoreboard/objective/Objective$Builder", self.object, "build", "()Lorg/spongepowered/api/scoreboard/objective/Objective;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/scoreboard/objective/Objective$Builder#build was null") }
+      ::sponge::scoreboard::objective::Objective { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,94 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BanService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BanService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BanService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BanService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/ban/BanService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_bans(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "getBans", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#getBans was null") }
+    ret
+  }
+
+  pub fn get_profile_bans(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "getProfileBans", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#getProfileBans was null") }
+    ret
+  }
+
+  pub fn get_ip_bans(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "getIpBans", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#getIpBans was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "profile", feature = "util"))]
+  pub fn get_ban_for_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> Option<::sponge::util::ban::ban::Profile> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "getBanFor", "(Lorg/spongepowered/api/profile/GameProfile;)Ljava/util/Optional;", CallObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#getBanFor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::ban::ban::Profile { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_ban_for_inet_address(&self, inet_address: jobject) -> Option<::sponge::util::ban::ban::Ip> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "getBanFor", "(Ljava/net/InetAddress;)Ljava/util/Optional;", CallObjectMethodA, inet_address);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#getBanFor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::ban::ban::Ip { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn is_banned_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "isBanned", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  pub fn is_banned_inet_address(&self, inet_address: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "isBanned", "(Ljava/net/InetAddress;)Z", CallBooleanMethodA, inet_address) != 0
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn pardon_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "pardon", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  pub fn pardon_inet_address(&self, inet_address: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "pardon", "(Ljava/net/InetAddress;)Z", CallBooleanMethodA, inet_address) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn remove_ban(&self, ban: ::sponge::util::ban::Ban) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "removeBan", "(Lorg/spongepowered/api/util/ban/Ban;)Z", CallBooleanMethodA, ban.object) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn add_ban(&self, ban: ::sponge::util::ban::Ban) -> Option<::sponge::util::ban::Ban> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "addBan", "(Lorg/spongepowered/api/util/ban/Ban;)Ljava/util/Optional;", CallObjectMethodA, ban.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ban/BanService#addBan was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::ban::Ban { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn has_ban(&self, ban: ::sponge::util::ban::Ban) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ban/BanService", self.object, "hasBan", "(Lorg/spongepowered/api/util/ban/Ban;)Z", CallBooleanMethodA, ban.object) != 0
+  }
+
+}
@@ -0,0 +1,202 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Context {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Context {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Context {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Context`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/context/Context", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, string_2: jobject) -> ::sponge::service::context::Context {
+    let ret = new_object!(env, "org/spongepowered/api/service/context/Context", "(Ljava/lang/String;Ljava/lang/String;)V", string, string_2);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#<init> was null") }
+    ::sponge::service::context::Context { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "getType", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#getType was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#getName was null") }
+    ret
+  }
+
+  pub fn get_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "getKey", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#getKey was null") }
+    ret
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "getValue", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#getValue was null") }
+    ret
+  }
+
+  pub fn set_value_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "setValue", "(Ljava/lang/String;)Ljava/lang/String;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#setValue was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#toString was null") }
+    ret
+  }
+
+  pub fn set_value_object(&self, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Context", self.object, "setValue", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Context#setValue was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ContextCalculator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ContextCalculator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ContextCalculator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ContextCalculator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/context/ContextCalculator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn accumulate_contexts(&self, contextual: ::sponge::service::context::Contextual, set: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/service/context/ContextCalculator", self.object, "accumulateContexts", "(Lorg/spongepowered/api/service/context/Contextual;Ljava/util/Set;)V", CallVoidMethodA, contextual.object, set);
+  }
+
+  pub fn matches(&self, context: ::sponge::service::context::Context, contextual: ::sponge::service::context::Contextual) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/context/ContextCalculator", self.object, "matches", "(Lorg/spongepowered/api/service/context/Context;Lorg/spongepowered/api/service/context/Contextual;)Z", CallBooleanMethodA, context.object, contextual.object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ContextSource {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ContextSource {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ContextSource {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ContextSource`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/context/ContextSource", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_context(&self) -> ::sponge::service::context::Context {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/ContextSource", self.object, "getContext", "()Lorg/spongepowered/api/service/context/Context;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/ContextSource#getContext was null") }
+    ::sponge::service::context::Context { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Contextual {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Contextual {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Contextual {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Contextual`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/context/Contextual", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_identifier(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Contextual", self.object, "getIdentifier", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Contextual#getIdentifier was null") }
+    ret
+  }
+
+  pub fn get_active_contexts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/context/Contextual", self.object, "getActiveContexts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/context/Contextual#getActiveContexts was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ContextualService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ContextualService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ContextualService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ContextualService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/context/ContextualService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_context_calculator(&self, context_calculator: ::sponge::service::context::ContextCalculator) {
+    bound_method!(self.env, "org/spongepowered/api/service/context/ContextualService", self.object, "registerContextCalculator", "(Lorg/spongepowered/api/service/context/ContextCalculator;)V", CallVoidMethodA, context_calculator.object);
+  }
+
+}
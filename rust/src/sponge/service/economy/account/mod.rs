@@ -0,0 +1,199 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Account {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Account {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Account {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Account`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/account/Account", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_display_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getDisplayName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getDisplayName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_default_balance(&self, currency: ::sponge::service::economy::Currency) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getDefaultBalance", "(Lorg/spongepowered/api/service/economy/Currency;)Ljava/math/BigDecimal;", CallObjectMethodA, currency.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getDefaultBalance was null") }
+    ret
+  }
+
+  pub fn has_balance_currency_set(&self, currency: ::sponge::service::economy::Currency, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "hasBalance", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/util/Set;)Z", CallBooleanMethodA, currency.object, set) != 0
+  }
+
+  pub fn has_balance_currency(&self, currency: ::sponge::service::economy::Currency) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "hasBalance", "(Lorg/spongepowered/api/service/economy/Currency;)Z", CallBooleanMethodA, currency.object) != 0
+  }
+
+  pub fn get_balance_currency_set(&self, currency: ::sponge::service::economy::Currency, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getBalance", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/util/Set;)Ljava/math/BigDecimal;", CallObjectMethodA, currency.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getBalance was null") }
+    ret
+  }
+
+  pub fn get_balance_currency(&self, currency: ::sponge::service::economy::Currency) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getBalance", "(Lorg/spongepowered/api/service/economy/Currency;)Ljava/math/BigDecimal;", CallObjectMethodA, currency.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getBalance was null") }
+    ret
+  }
+
+  pub fn get_balances_set(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getBalances", "(Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getBalances was null") }
+    ret
+  }
+
+  pub fn get_balances(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "getBalances", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#getBalances was null") }
+    ret
+  }
+
+  #[cfg(feature = "event")]
+  pub fn set_balance_currency_big_decimal_cause_set(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause, set: jobject) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "setBalance", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#setBalance was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn set_balance_currency_big_decimal_cause(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "setBalance", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#setBalance was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn reset_balances_cause_set(&self, cause: ::sponge::event::cause::Cause, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "resetBalances", "(Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#resetBalances was null") }
+    ret
+  }
+
+  #[cfg(feature = "event")]
+  pub fn reset_balances_cause(&self, cause: ::sponge::event::cause::Cause) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "resetBalances", "(Lorg/spongepowered/api/event/cause/Cause;)Ljava/util/Map;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#resetBalances was null") }
+    ret
+  }
+
+  #[cfg(feature = "event")]
+  pub fn reset_balance_currency_cause_set(&self, currency: ::sponge::service::economy::Currency, cause: ::sponge::event::cause::Cause, set: jobject) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "resetBalance", "(Lorg/spongepowered/api/service/economy/Currency;Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#resetBalance was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn reset_balance_currency_cause(&self, currency: ::sponge::service::economy::Currency, cause: ::sponge::event::cause::Cause) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "resetBalance", "(Lorg/spongepowered/api/service/economy/Currency;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#resetBalance was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn deposit_currency_big_decimal_cause_set(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause, set: jobject) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "deposit", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#deposit was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn deposit_currency_big_decimal_cause(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "deposit", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#deposit was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn withdraw_currency_big_decimal_cause_set(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause, set: jobject) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "withdraw", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#withdraw was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn withdraw_currency_big_decimal_cause(&self, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::service::economy::transaction::TransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "withdraw", "(Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/service/economy/transaction/TransactionResult;", CallObjectMethodA, currency.object, big_decimal, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#withdraw was null") }
+    ::sponge::service::economy::transaction::TransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn transfer_account_currency_big_decimal_cause_set(&self, account: ::sponge::service::economy::account::Account, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause, set: jobject) -> ::sponge::service::economy::transaction::TransferResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "transfer", "(Lorg/spongepowered/api/service/economy/account/Account;Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;Ljava/util/Set;)Lorg/spongepowered/api/service/economy/transaction/TransferResult;", CallObjectMethodA, account.object, currency.object, big_decimal, cause.object, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#transfer was null") }
+    ::sponge::service::economy::transaction::TransferResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn transfer_account_currency_big_decimal_cause(&self, account: ::sponge::service::economy::account::Account, currency: ::sponge::service::economy::Currency, big_decimal: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::service::economy::transaction::TransferResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/account/Account", self.object, "transfer", "(Lorg/spongepowered/api/service/economy/account/Account;Lorg/spongepowered/api/service/economy/Currency;Ljava/math/BigDecimal;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/service/economy/transaction/TransferResult;", CallObjectMethodA, account.object, currency.object, big_decimal, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/account/Account#transfer was null") }
+    ::sponge::service::economy::transaction::TransferResult { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UniqueAccount {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UniqueAccount {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UniqueAccount {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UniqueAccount`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/account/UniqueAccount", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VirtualAccount {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VirtualAccount {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VirtualAccount {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VirtualAccount`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/account/VirtualAccount", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
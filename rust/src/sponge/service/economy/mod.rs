@@ -0,0 +1,127 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod account;
+pub mod transaction;
+
+#[derive(Debug)]
+pub struct Currency {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Currency {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Currency {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Currency`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/Currency", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_display_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "getDisplayName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/Currency#getDisplayName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_plural_display_name(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "getPluralDisplayName", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/Currency#getPluralDisplayName was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_symbol(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "getSymbol", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/Currency#getSymbol was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn format_big_decimal(&self, big_decimal: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "format", "(Ljava/math/BigDecimal;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, big_decimal);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/Currency#format was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn format_big_decimal_i32(&self, big_decimal: jobject, value: i32) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "format", "(Ljava/math/BigDecimal;I)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, big_decimal, value);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/Currency#format was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_default_fraction_digits(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "getDefaultFractionDigits", "()I", CallIntMethod)
+  }
+
+  pub fn is_default(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/Currency", self.object, "isDefault", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EconomyService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EconomyService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EconomyService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EconomyService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/EconomyService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_default_currency(&self) -> ::sponge::service::economy::Currency {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "getDefaultCurrency", "()Lorg/spongepowered/api/service/economy/Currency;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/EconomyService#getDefaultCurrency was null") }
+    ::sponge::service::economy::Currency { env: self.env, object: ret }
+  }
+
+  pub fn get_currencies(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "getCurrencies", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/EconomyService#getCurrencies was null") }
+    ret
+  }
+
+  pub fn has_account_uuid(&self, uuid: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "hasAccount", "(Ljava/util/UUID;)Z", CallBooleanMethodA, uuid) != 0
+  }
+
+  pub fn has_account_string(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "hasAccount", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn get_or_create_account_uuid(&self, uuid: jobject) -> Option<::sponge::service::economy::account::UniqueAccount> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "getOrCreateAccount", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/EconomyService#getOrCreateAccount was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::economy::account::UniqueAccount { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_or_create_account_string(&self, string: jobject) -> Option<::sponge::service::economy::account::Account> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/EconomyService", self.object, "getOrCreateAccount", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/EconomyService#getOrCreateAccount was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::economy::account::Account { env: self.env, object: unwrapped }) }
+  }
+
+}
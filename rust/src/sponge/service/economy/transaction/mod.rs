@@ -0,0 +1,171 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultType {
+  Success,
+  Failed,
+  AccountNoFunds,
+  ContextMismatch,
+  AccountNoSpace,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static RESULT_TYPE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/service/economy/transaction/ResultType", &["SUCCESS", "FAILED", "ACCOUNT_NO_FUNDS", "CONTEXT_MISMATCH", "ACCOUNT_NO_SPACE"]);
+
+impl ResultType {
+  pub const VALUES: &'static [ResultType] = &[ResultType::Success, ResultType::Failed, ResultType::AccountNoFunds, ResultType::ContextMismatch, ResultType::AccountNoSpace];
+
+}
+
+impl ::convert::FromJava for ResultType {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match RESULT_TYPE_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => ResultType::VALUES[ordinal],
+      None => ResultType::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for ResultType {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      ResultType::Other(object) => object,
+      value => RESULT_TYPE_CONSTANTS.get(env, ResultType::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct TransactionResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransactionResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransactionResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransactionResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/transaction/TransactionResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_account(&self) -> ::sponge::service::economy::account::Account {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getAccount", "()Lorg/spongepowered/api/service/economy/account/Account;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getAccount was null") }
+    ::sponge::service::economy::account::Account { env: self.env, object: ret }
+  }
+
+  pub fn get_currency(&self) -> ::sponge::service::economy::Currency {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getCurrency", "()Lorg/spongepowered/api/service/economy/Currency;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getCurrency was null") }
+    ::sponge::service::economy::Currency { env: self.env, object: ret }
+  }
+
+  pub fn get_amount(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getAmount", "()Ljava/math/BigDecimal;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getAmount was null") }
+    ret
+  }
+
+  pub fn get_contexts(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getContexts", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getContexts was null") }
+    ret
+  }
+
+  pub fn get_result(&self) -> ::sponge::service::economy::transaction::ResultType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getResult", "()Lorg/spongepowered/api/service/economy/transaction/ResultType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getResult was null") }
+    <::sponge::service::economy::transaction::ResultType as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::service::economy::transaction::TransactionType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransactionResult", self.object, "getType", "()Lorg/spongepowered/api/service/economy/transaction/TransactionType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransactionResult#getType was null") }
+    ::sponge::service::economy::transaction::TransactionType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TransactionType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransactionType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransactionType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransactionType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/transaction/TransactionType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TransactionTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransactionTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransactionTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransactionTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/transaction/TransactionTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TransferResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransferResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransferResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransferResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/economy/transaction/TransferResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_account_to(&self) -> ::sponge::service::economy::account::Account {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/economy/transaction/TransferResult", self.object, "getAccountTo", "()Lorg/spongepowered/api/service/economy/account/Account;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/economy/transaction/TransferResult#getAccountTo was null") }
+    ::sponge::service::economy::account::Account { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,257 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod ban;
+pub mod context;
+pub mod economy;
+pub mod pagination;
+pub mod permission;
+pub mod rcon;
+pub mod sql;
+pub mod user;
+pub mod whitelist;
+
+#[derive(Debug)]
+pub struct ProviderRegistration {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProviderRegistration {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProviderRegistration {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ProviderRegistration`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/ProviderRegistration", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_service(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ProviderRegistration", self.object, "getService", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProviderRegistration#getService was null") }
+    ret
+  }
+
+  pub fn get_provider(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ProviderRegistration", self.object, "getProvider", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProviderRegistration#getProvider was null") }
+    ret
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_plugin(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ProviderRegistration", self.object, "getPlugin", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProviderRegistration#getPlugin was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ProvisioningException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ProvisioningException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ProvisioningException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ProvisioningException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/ProvisioningException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_class(env: *mut JNIEnv, class: jobject) -> ::sponge::service::ProvisioningException {
+    let ret = new_object!(env, "org/spongepowered/api/service/ProvisioningException", "(Ljava/lang/Class;)V", class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProvisioningException#<init> was null") }
+    ::sponge::service::ProvisioningException { env: env, object: ret }
+  }
+
+  pub fn new_string_class(env: *mut JNIEnv, string: jobject, class: jobject) -> ::sponge::service::ProvisioningException {
+    let ret = new_object!(env, "org/spongepowered/api/service/ProvisioningException", "(Ljava/lang/String;Ljava/lang/Class;)V", string, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProvisioningException#<init> was null") }
+    ::sponge::service::ProvisioningException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable_class(env: *mut JNIEnv, string: jobject, throwable: jobject, class: jobject) -> ::sponge::service::ProvisioningException {
+    let ret = new_object!(env, "org/spongepowered/api/service/ProvisioningException", "(Ljava/lang/String;Ljava/lang/Throwable;Ljava/lang/Class;)V", string, throwable, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProvisioningException#<init> was null") }
+    ::sponge::service::ProvisioningException { env: env, object: ret }
+  }
+
+  pub fn new_throwable_class(env: *mut JNIEnv, throwable: jobject, class: jobject) -> ::sponge::service::ProvisioningException {
+    let ret = new_object!(env, "org/spongepowered/api/service/ProvisioningException", "(Ljava/lang/Throwable;Ljava/lang/Class;)V", throwable, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProvisioningException#<init> was null") }
+    ::sponge::service::ProvisioningException { env: env, object: ret }
+  }
+
+  pub fn get_service(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ProvisioningException", self.object, "getService", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ProvisioningException#getService was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ServiceManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ServiceManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ServiceManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ServiceManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/ServiceManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_provider(&self, object: jobject, class: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/service/ServiceManager", self.object, "setProvider", "(Ljava/lang/Object;Ljava/lang/Class;Ljava/lang/Object;)V", CallVoidMethodA, object, class, object_2);
+  }
+
+  pub fn provide(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ServiceManager", self.object, "provide", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ServiceManager#provide was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_registration(&self, class: jobject) -> Option<::sponge::service::ProviderRegistration> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ServiceManager", self.object, "getRegistration", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ServiceManager#getRegistration was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::ProviderRegistration { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn is_registered(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/ServiceManager", self.object, "isRegistered", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  pub fn provide_unchecked(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/ServiceManager", self.object, "provideUnchecked", "(Ljava/lang/Class;)Ljava/lang/Object;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/ServiceManager#provideUnchecked was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SimpleServiceManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SimpleServiceManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SimpleServiceManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SimpleServiceManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/SimpleServiceManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn new(env: *mut JNIEnv, plugin_manager: ::sponge::plugin::PluginManager) -> ::sponge::service::SimpleServiceManager {
+    let ret = new_object!(env, "org/spongepowered/api/service/SimpleServiceManager", "(Lorg/spongepowered/api/plugin/PluginManager;)V", plugin_manager.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager#<init> was null") }
+    ::sponge::service::SimpleServiceManager { env: env, object: ret }
+  }
+
+  pub fn set_provider(&self, object: jobject, class: jobject, object_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager", self.object, "setProvider", "(Ljava/lang/Object;Ljava/lang/Class;Ljava/lang/Object;)V", CallVoidMethodA, object, class, object_2);
+  }
+
+  pub fn provide(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager", self.object, "provide", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager#provide was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_registration(&self, class: jobject) -> Option<::sponge::service::ProviderRegistration> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager", self.object, "getRegistration", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager#getRegistration was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::ProviderRegistration { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn provide_unchecked(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager", self.object, "provideUnchecked", "(Ljava/lang/Class;)Ljava/lang/Object;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager#provideUnchecked was null") }
+    ret
+  }
+
+}
+
+pub mod simple_service_manager {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Provider {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Provider {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Provider {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Provider`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/SimpleServiceManager$Provider", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_service(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager$Provider", self.object, "getService", "()Ljava/lang/Class;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager$Provider#getService was null") }
+      ret
+    }
+
+    pub fn get_provider(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager$Provider", self.object, "getProvider", "()Ljava/lang/Object;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager$Provider#getProvider was null") }
+      ret
+    }
+
+    #[cfg(feature = "plugin")]
+    pub fn get_plugin(&self) -> ::sponge::plugin::PluginContainer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/SimpleServiceManager$Provider", self.object, "getPlugin", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/service/SimpleServiceManager$Provider#getPlugin was null") }
+      ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+    }
+
+  }
+}
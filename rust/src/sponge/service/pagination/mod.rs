@@ -0,0 +1,201 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct PaginationList {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PaginationList {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PaginationList {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PaginationList`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/pagination/PaginationList", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::service::pagination::pagination_list::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/service/pagination/PaginationList", "builder", "()Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#builder was null") }
+    ::sponge::service::pagination::pagination_list::Builder { env: env, object: ret }
+  }
+
+  pub fn get_contents(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getContents", "()Ljava/lang/Iterable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#getContents was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_title(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getTitle", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#getTitle was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_header(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getHeader", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#getHeader was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_footer(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getFooter", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#getFooter was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_padding(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getPadding", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList#getPadding was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_lines_per_page(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "getLinesPerPage", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn send_to_message_receiver(&self, message_receiver: ::sponge::text::channel::MessageReceiver) {
+    bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "sendTo", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)V", CallVoidMethodA, message_receiver.object);
+  }
+
+  #[cfg(feature = "text")]
+  pub fn send_to_message_channel(&self, message_channel: ::sponge::text::channel::MessageChannel) {
+    bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList", self.object, "sendTo", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", CallVoidMethodA, message_channel.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PaginationService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PaginationService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PaginationService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PaginationService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/pagination/PaginationService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(&self) -> ::sponge::service::pagination::pagination_list::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationService", self.object, "builder", "()Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationService#builder was null") }
+    ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+  }
+
+}
+
+pub mod pagination_list {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/pagination/PaginationList$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn contents_iterable(&self, iterable: jobject) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "contents", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#contents was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn contents_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::service::pagination::pagination_list::Builder {
+      unimplemented!();
+    }
+    #[cfg(feature = "text")]
+    pub fn title(&self, text: ::sponge::text::Text) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "title", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#title was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn header(&self, text: ::sponge::text::Text) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "header", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#header was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn footer(&self, text: ::sponge::text::Text) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "footer", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#footer was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn padding(&self, text: ::sponge::text::Text) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "padding", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#padding was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    pub fn lines_per_page(&self, value: i32) -> ::sponge::service::pagination::pagination_list::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "linesPerPage", "(I)Lorg/spongepowered/api/service/pagination/PaginationList$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#linesPerPage was null") }
+      ::sponge::service::pagination::pagination_list::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::service::pagination::PaginationList {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "build", "()Lorg/spongepowered/api/service/pagination/PaginationList;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/service/pagination/PaginationList$Builder#build was null") }
+      ::sponge::service::pagination::PaginationList { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn send_to_message_receiver(&self, message_receiver: ::sponge::text::channel::MessageReceiver) {
+      bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "sendTo", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)V", CallVoidMethodA, message_receiver.object);
+    }
+
+    #[cfg(feature = "text")]
+    pub fn send_to_message_channel(&self, message_channel: ::sponge::text::channel::MessageChannel) {
+      bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "sendTo", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", CallVoidMethodA, message_channel.object);
+    }
+
+    pub fn send_to_iterable(&self, iterable: jobject) {
+      bound_method!(self.env, "org/spongepowered/api/service/pagination/PaginationList$Builder", self.object, "sendTo", "(Ljava/lang/Iterable;)V", CallVoidMethodA, iterable);
+    }
+
+  }
+}
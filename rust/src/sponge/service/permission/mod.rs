@@ -0,0 +1,637 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct MemorySubjectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MemorySubjectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MemorySubjectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `MemorySubjectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/MemorySubjectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, permission_service: ::sponge::service::permission::PermissionService) -> ::sponge::service::permission::MemorySubjectData {
+    let ret = new_object!(env, "org/spongepowered/api/service/permission/MemorySubjectData", "(Lorg/spongepowered/api/service/permission/PermissionService;)V", permission_service.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#<init> was null") }
+    ::sponge::service::permission::MemorySubjectData { env: env, object: ret }
+  }
+
+  pub fn get_all_permissions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getAllPermissions", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getAllPermissions was null") }
+    ret
+  }
+
+  pub fn get_node_tree(&self, set: jobject) -> ::sponge::service::permission::NodeTree {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getNodeTree", "(Ljava/util/Set;)Lorg/spongepowered/api/service/permission/NodeTree;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getNodeTree was null") }
+    ::sponge::service::permission::NodeTree { env: self.env, object: ret }
+  }
+
+  pub fn get_permissions(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getPermissions", "(Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getPermissions was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_permission(&self, set: jobject, string: jobject, tristate: ::sponge::util::Tristate) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "setPermission", "(Ljava/util/Set;Ljava/lang/String;Lorg/spongepowered/api/util/Tristate;)Z", CallBooleanMethodA, set, string, ::convert::ToJava::to_java(&tristate, self.env)) != 0
+  }
+
+  pub fn clear_permissions(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearPermissions", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn clear_permissions_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearPermissions", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn get_all_parents(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getAllParents", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getAllParents was null") }
+    ret
+  }
+
+  pub fn get_parents(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getParents", "(Ljava/util/Set;)Ljava/util/List;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getParents was null") }
+    ret
+  }
+
+  pub fn add_parent(&self, set: jobject, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "addParent", "(Ljava/util/Set;Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, set, subject.object) != 0
+  }
+
+  pub fn remove_parent(&self, set: jobject, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "removeParent", "(Ljava/util/Set;Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, set, subject.object) != 0
+  }
+
+  pub fn clear_parents(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearParents", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn clear_parents_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearParents", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn get_all_options(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getAllOptions", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getAllOptions was null") }
+    ret
+  }
+
+  pub fn get_options(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "getOptions", "(Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/MemorySubjectData#getOptions was null") }
+    ret
+  }
+
+  pub fn set_option(&self, set: jobject, string: jobject, string_2: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "setOption", "(Ljava/util/Set;Ljava/lang/String;Ljava/lang/String;)Z", CallBooleanMethodA, set, string, string_2) != 0
+  }
+
+  pub fn clear_options_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearOptions", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn clear_options(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/MemorySubjectData", self.object, "clearOptions", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NodeTree {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NodeTree {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NodeTree {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NodeTree`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/NodeTree", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of_map(env: *mut JNIEnv, map: jobject) -> ::sponge::service::permission::NodeTree {
+    let ret = static_java_method!(env, "org/spongepowered/api/service/permission/NodeTree", "of", "(Ljava/util/Map;)Lorg/spongepowered/api/service/permission/NodeTree;", CallStaticObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#of was null") }
+    ::sponge::service::permission::NodeTree { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn of_map_tristate(env: *mut JNIEnv, map: jobject, tristate: ::sponge::util::Tristate) -> ::sponge::service::permission::NodeTree {
+    let ret = static_java_method!(env, "org/spongepowered/api/service/permission/NodeTree", "of", "(Ljava/util/Map;Lorg/spongepowered/api/util/Tristate;)Lorg/spongepowered/api/service/permission/NodeTree;", CallStaticObjectMethodA, map, ::convert::ToJava::to_java(&tristate, env));
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#of was null") }
+    ::sponge::service::permission::NodeTree { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get(&self, string: jobject) -> ::sponge::util::Tristate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/NodeTree", self.object, "get", "(Ljava/lang/String;)Lorg/spongepowered/api/util/Tristate;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#get was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn as_map(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/NodeTree", self.object, "asMap", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#asMap was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn with_value(&self, string: jobject, tristate: ::sponge::util::Tristate) -> ::sponge::service::permission::NodeTree {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/NodeTree", self.object, "withValue", "(Ljava/lang/String;Lorg/spongepowered/api/util/Tristate;)Lorg/spongepowered/api/service/permission/NodeTree;", CallObjectMethodA, string, ::convert::ToJava::to_java(&tristate, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#withValue was null") }
+    ::sponge::service::permission::NodeTree { env: self.env, object: ret }
+  }
+
+  pub fn with_all(&self, map: jobject) -> ::sponge::service::permission::NodeTree {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/NodeTree", self.object, "withAll", "(Ljava/util/Map;)Lorg/spongepowered/api/service/permission/NodeTree;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/NodeTree#withAll was null") }
+    ::sponge::service::permission::NodeTree { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PermissionDescription {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PermissionDescription {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PermissionDescription {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PermissionDescription`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/PermissionDescription", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription#getId was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_description(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription", self.object, "getDescription", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription#getDescription was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_assigned_subjects(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription", self.object, "getAssignedSubjects", "(Ljava/lang/String;)Ljava/util/Map;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription#getAssignedSubjects was null") }
+    ret
+  }
+
+  #[cfg(feature = "plugin")]
+  pub fn get_owner(&self) -> ::sponge::plugin::PluginContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription", self.object, "getOwner", "()Lorg/spongepowered/api/plugin/PluginContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription#getOwner was null") }
+    ::sponge::plugin::PluginContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PermissionService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PermissionService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PermissionService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PermissionService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/PermissionService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_user_subjects(&self) -> ::sponge::service::permission::SubjectCollection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getUserSubjects", "()Lorg/spongepowered/api/service/permission/SubjectCollection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getUserSubjects was null") }
+    ::sponge::service::permission::SubjectCollection { env: self.env, object: ret }
+  }
+
+  pub fn get_group_subjects(&self) -> ::sponge::service::permission::SubjectCollection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getGroupSubjects", "()Lorg/spongepowered/api/service/permission/SubjectCollection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getGroupSubjects was null") }
+    ::sponge::service::permission::SubjectCollection { env: self.env, object: ret }
+  }
+
+  pub fn get_defaults(&self) -> ::sponge::service::permission::Subject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getDefaults", "()Lorg/spongepowered/api/service/permission/Subject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getDefaults was null") }
+    ::sponge::service::permission::Subject { env: self.env, object: ret }
+  }
+
+  pub fn get_subjects(&self, string: jobject) -> ::sponge::service::permission::SubjectCollection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getSubjects", "(Ljava/lang/String;)Lorg/spongepowered/api/service/permission/SubjectCollection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getSubjects was null") }
+    ::sponge::service::permission::SubjectCollection { env: self.env, object: ret }
+  }
+
+  pub fn get_known_subjects(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getKnownSubjects", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getKnownSubjects was null") }
+    ret
+  }
+
+  pub fn new_description_builder(&self, object: jobject) -> Option<::sponge::service::permission::permission_description::Builder> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "newDescriptionBuilder", "(Ljava/lang/Object;)Ljava/util/Optional;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#newDescriptionBuilder was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::permission::permission_description::Builder { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_description(&self, string: jobject) -> Option<::sponge::service::permission::PermissionDescription> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getDescription", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getDescription was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::service::permission::PermissionDescription { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_descriptions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionService", self.object, "getDescriptions", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionService#getDescriptions was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Subject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Subject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Subject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Subject`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/Subject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_command_source(&self) -> Option<::sponge::command::CommandSource> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getCommandSource", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getCommandSource was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandSource { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_containing_collection(&self) -> ::sponge::service::permission::SubjectCollection {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getContainingCollection", "()Lorg/spongepowered/api/service/permission/SubjectCollection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getContainingCollection was null") }
+    ::sponge::service::permission::SubjectCollection { env: self.env, object: ret }
+  }
+
+  pub fn get_subject_data(&self) -> ::sponge::service::permission::SubjectData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getSubjectData", "()Lorg/spongepowered/api/service/permission/SubjectData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getSubjectData was null") }
+    ::sponge::service::permission::SubjectData { env: self.env, object: ret }
+  }
+
+  pub fn get_transient_subject_data(&self) -> ::sponge::service::permission::SubjectData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getTransientSubjectData", "()Lorg/spongepowered/api/service/permission/SubjectData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getTransientSubjectData was null") }
+    ::sponge::service::permission::SubjectData { env: self.env, object: ret }
+  }
+
+  pub fn has_permission_set_string(&self, set: jobject, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "hasPermission", "(Ljava/util/Set;Ljava/lang/String;)Z", CallBooleanMethodA, set, string) != 0
+  }
+
+  pub fn has_permission_string(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "hasPermission", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_permission_value(&self, set: jobject, string: jobject) -> ::sponge::util::Tristate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getPermissionValue", "(Ljava/util/Set;Ljava/lang/String;)Lorg/spongepowered/api/util/Tristate;", CallObjectMethodA, set, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getPermissionValue was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn is_child_of_subject(&self, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "isChildOf", "(Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, subject.object) != 0
+  }
+
+  pub fn is_child_of_set_subject(&self, set: jobject, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "isChildOf", "(Ljava/util/Set;Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, set, subject.object) != 0
+  }
+
+  pub fn get_parents(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getParents", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getParents was null") }
+    ret
+  }
+
+  pub fn get_parents_set(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getParents", "(Ljava/util/Set;)Ljava/util/List;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getParents was null") }
+    ret
+  }
+
+  pub fn get_option_set_string(&self, set: jobject, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getOption", "(Ljava/util/Set;Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, set, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getOption was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_option_string(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/Subject", self.object, "getOption", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/Subject#getOption was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SubjectCollection {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SubjectCollection {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SubjectCollection {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SubjectCollection`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/SubjectCollection", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_identifier(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "getIdentifier", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#getIdentifier was null") }
+    ret
+  }
+
+  pub fn get(&self, string: jobject) -> ::sponge::service::permission::Subject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "get", "(Ljava/lang/String;)Lorg/spongepowered/api/service/permission/Subject;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#get was null") }
+    ::sponge::service::permission::Subject { env: self.env, object: ret }
+  }
+
+  pub fn has_registered(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "hasRegistered", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn get_all_subjects(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "getAllSubjects", "()Ljava/lang/Iterable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#getAllSubjects was null") }
+    ret
+  }
+
+  pub fn get_all_with_permission_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "getAllWithPermission", "(Ljava/lang/String;)Ljava/util/Map;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#getAllWithPermission was null") }
+    ret
+  }
+
+  pub fn get_all_with_permission_set_string(&self, set: jobject, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "getAllWithPermission", "(Ljava/util/Set;Ljava/lang/String;)Ljava/util/Map;", CallObjectMethodA, set, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#getAllWithPermission was null") }
+    ret
+  }
+
+  pub fn get_defaults(&self) -> ::sponge::service::permission::Subject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectCollection", self.object, "getDefaults", "()Lorg/spongepowered/api/service/permission/Subject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectCollection#getDefaults was null") }
+    ::sponge::service::permission::Subject { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SubjectData {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SubjectData {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SubjectData {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SubjectData`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/SubjectData", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_all_permissions(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getAllPermissions", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getAllPermissions was null") }
+    ret
+  }
+
+  pub fn get_permissions(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getPermissions", "(Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getPermissions was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_permission(&self, set: jobject, string: jobject, tristate: ::sponge::util::Tristate) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "setPermission", "(Ljava/util/Set;Ljava/lang/String;Lorg/spongepowered/api/util/Tristate;)Z", CallBooleanMethodA, set, string, ::convert::ToJava::to_java(&tristate, self.env)) != 0
+  }
+
+  pub fn clear_permissions(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearPermissions", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn clear_permissions_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearPermissions", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn get_all_parents(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getAllParents", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getAllParents was null") }
+    ret
+  }
+
+  pub fn get_parents(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getParents", "(Ljava/util/Set;)Ljava/util/List;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getParents was null") }
+    ret
+  }
+
+  pub fn add_parent(&self, set: jobject, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "addParent", "(Ljava/util/Set;Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, set, subject.object) != 0
+  }
+
+  pub fn remove_parent(&self, set: jobject, subject: ::sponge::service::permission::Subject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "removeParent", "(Ljava/util/Set;Lorg/spongepowered/api/service/permission/Subject;)Z", CallBooleanMethodA, set, subject.object) != 0
+  }
+
+  pub fn clear_parents(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearParents", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn clear_parents_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearParents", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn get_all_options(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getAllOptions", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getAllOptions was null") }
+    ret
+  }
+
+  pub fn get_options(&self, set: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "getOptions", "(Ljava/util/Set;)Ljava/util/Map;", CallObjectMethodA, set);
+    if ret.is_null() { panic!("org/spongepowered/api/service/permission/SubjectData#getOptions was null") }
+    ret
+  }
+
+  pub fn set_option(&self, set: jobject, string: jobject, string_2: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "setOption", "(Ljava/util/Set;Ljava/lang/String;Ljava/lang/String;)Z", CallBooleanMethodA, set, string, string_2) != 0
+  }
+
+  pub fn clear_options_set(&self, set: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearOptions", "(Ljava/util/Set;)Z", CallBooleanMethodA, set) != 0
+  }
+
+  pub fn clear_options(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/permission/SubjectData", self.object, "clearOptions", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+pub mod node_tree {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Node {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Node {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Node {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Node`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/NodeTree$Node", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod permission_description {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/permission/PermissionDescription$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn id(&self, string: jobject) -> ::sponge::service::permission::permission_description::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription$Builder", self.object, "id", "(Ljava/lang/String;)Lorg/spongepowered/api/service/permission/PermissionDescription$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription$Builder#id was null") }
+      ::sponge::service::permission::permission_description::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn description(&self, text: ::sponge::text::Text) -> ::sponge::service::permission::permission_description::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription$Builder", self.object, "description", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/service/permission/PermissionDescription$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription$Builder#description was null") }
+      ::sponge::service::permission::permission_description::Builder { env: self.env, object: ret }
+    }
+
+    pub fn assign(&self, string: jobject, value: bool) -> ::sponge::service::permission::permission_description::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription$Builder", self.object, "assign", "(Ljava/lang/String;Z)Lorg/spongepowered/api/service/permission/PermissionDescription$Builder;", CallObjectMethodA, string, value);
+      if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription$Builder#assign was null") }
+      ::sponge::service::permission::permission_description::Builder { env: self.env, object: ret }
+    }
+
+    pub fn register(&self) -> ::sponge::service::permission::PermissionDescription {
+      let ret = bound_method!(self.env, "org/spongepowered/api/service/permission/PermissionDescription$Builder", self.object, "register", "()Lorg/spongepowered/api/service/permission/PermissionDescription;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/service/permission/PermissionDescription$Builder#register was null") }
+      ::sponge::service::permission::PermissionDescription { env: self.env, object: ret }
+    }
+
+  }
+}
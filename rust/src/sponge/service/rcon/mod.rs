@@ -0,0 +1,34 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct RconService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RconService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RconService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RconService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/rcon/RconService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_rcon_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/rcon/RconService", self.object, "isRconEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_rcon_password(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/rcon/RconService", self.object, "getRconPassword", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/rcon/RconService#getRconPassword was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,43 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct SqlService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SqlService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SqlService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SqlService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/sql/SqlService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_data_source_string(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/sql/SqlService", self.object, "getDataSource", "(Ljava/lang/String;)Ljavax/sql/DataSource;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/sql/SqlService#getDataSource was null") }
+    ret
+  }
+
+  pub fn get_data_source_object_string(&self, object: jobject, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/sql/SqlService", self.object, "getDataSource", "(Ljava/lang/Object;Ljava/lang/String;)Ljavax/sql/DataSource;", CallObjectMethodA, object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/sql/SqlService#getDataSource was null") }
+    ret
+  }
+
+  pub fn get_connection_url_from_alias(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/sql/SqlService", self.object, "getConnectionUrlFromAlias", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/sql/SqlService#getConnectionUrlFromAlias was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
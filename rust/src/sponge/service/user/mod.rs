@@ -0,0 +1,77 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct UserStorageService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UserStorageService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UserStorageService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UserStorageService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/user/UserStorageService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_uuid(&self, uuid: jobject) -> Option<::sponge::entity::living::player::User> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "get", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::User { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_string(&self, string: jobject) -> Option<::sponge::entity::living::player::User> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "get", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::User { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "entity", feature = "profile"))]
+  pub fn get_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> Option<::sponge::entity::living::player::User> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "get", "(Lorg/spongepowered/api/profile/GameProfile;)Ljava/util/Optional;", CallObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::living::player::User { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "entity", feature = "profile"))]
+  pub fn get_or_create(&self, game_profile: ::sponge::profile::GameProfile) -> ::sponge::entity::living::player::User {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "getOrCreate", "(Lorg/spongepowered/api/profile/GameProfile;)Lorg/spongepowered/api/entity/living/player/User;", CallObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#getOrCreate was null") }
+    ::sponge::entity::living::player::User { env: self.env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "getAll", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#getAll was null") }
+    ret
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn delete_game_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "delete", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn delete_user(&self, user: ::sponge::entity::living::player::User) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "delete", "(Lorg/spongepowered/api/entity/living/player/User;)Z", CallBooleanMethodA, user.object) != 0
+  }
+
+  pub fn match_(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/user/UserStorageService", self.object, "match", "(Ljava/lang/String;)Ljava/util/Collection;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/service/user/UserStorageService#match was null") }
+    ret
+  }
+
+}
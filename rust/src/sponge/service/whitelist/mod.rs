@@ -0,0 +1,45 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct WhitelistService {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WhitelistService {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WhitelistService {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WhitelistService`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/service/whitelist/WhitelistService", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_whitelisted_profiles(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/service/whitelist/WhitelistService", self.object, "getWhitelistedProfiles", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/service/whitelist/WhitelistService#getWhitelistedProfiles was null") }
+    ret
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn is_whitelisted(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/whitelist/WhitelistService", self.object, "isWhitelisted", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn add_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/whitelist/WhitelistService", self.object, "addProfile", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn remove_profile(&self, game_profile: ::sponge::profile::GameProfile) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/service/whitelist/WhitelistService", self.object, "removeProfile", "(Lorg/spongepowered/api/profile/GameProfile;)Z", CallBooleanMethodA, game_profile.object) != 0
+  }
+
+}
@@ -0,0 +1,158 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Achievement {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Achievement {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Achievement {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Achievement`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/achievement/Achievement", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::statistic::achievement::achievement::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/statistic/achievement/Achievement", "builder", "()Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#builder was null") }
+    ::sponge::statistic::achievement::achievement::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_description(&self) -> ::sponge::text::translation::Translation {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement", self.object, "getDescription", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#getDescription was null") }
+    ::sponge::text::translation::Translation { env: self.env, object: ret }
+  }
+
+  pub fn get_parent(&self) -> Option<::sponge::statistic::achievement::Achievement> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement", self.object, "getParent", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#getParent was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::achievement::Achievement { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_children(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement", self.object, "getChildren", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#getChildren was null") }
+    ret
+  }
+
+  pub fn get_source_statistic(&self) -> Option<::sponge::statistic::Statistic> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement", self.object, "getSourceStatistic", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#getSourceStatistic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::Statistic { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_statistic_target_value(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement", self.object, "getStatisticTargetValue", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement#getStatisticTargetValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Achievements {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Achievements {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Achievements {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Achievements`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/achievement/Achievements", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod achievement {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/achievement/Achievement$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn name(&self, string: jobject) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "name", "(Ljava/lang/String;)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#name was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn translation(&self, translation: ::sponge::text::translation::Translation) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "translation", "(Lorg/spongepowered/api/text/translation/Translation;)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, translation.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#translation was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn description(&self, translation: ::sponge::text::translation::Translation) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "description", "(Lorg/spongepowered/api/text/translation/Translation;)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, translation.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#description was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    pub fn parent(&self, achievement: ::sponge::statistic::achievement::Achievement) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "parent", "(Lorg/spongepowered/api/statistic/achievement/Achievement;)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, achievement.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#parent was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    pub fn source_statistic(&self, statistic: ::sponge::statistic::Statistic) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "sourceStatistic", "(Lorg/spongepowered/api/statistic/Statistic;)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, statistic.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#sourceStatistic was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    pub fn target_value(&self, value: i64) -> ::sponge::statistic::achievement::achievement::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "targetValue", "(J)Lorg/spongepowered/api/statistic/achievement/Achievement$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#targetValue was null") }
+      ::sponge::statistic::achievement::achievement::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build_and_register(&self) -> ::sponge::statistic::achievement::Achievement {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/achievement/Achievement$Builder", self.object, "buildAndRegister", "()Lorg/spongepowered/api/statistic/achievement/Achievement;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/achievement/Achievement$Builder#buildAndRegister was null") }
+      ::sponge::statistic::achievement::Achievement { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,528 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod achievement;
+
+#[derive(Debug)]
+pub struct BlockStatistic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockStatistic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockStatistic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockStatistic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/BlockStatistic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_type(&self) -> ::sponge::block::BlockType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/BlockStatistic", self.object, "getBlockType", "()Lorg/spongepowered/api/block/BlockType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/BlockStatistic#getBlockType was null") }
+    ::sponge::block::BlockType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityStatistic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityStatistic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityStatistic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityStatistic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/EntityStatistic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_entity_type(&self) -> ::sponge::entity::EntityType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/EntityStatistic", self.object, "getEntityType", "()Lorg/spongepowered/api/entity/EntityType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/EntityStatistic#getEntityType was null") }
+    ::sponge::entity::EntityType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ItemStatistic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ItemStatistic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ItemStatistic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ItemStatistic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/ItemStatistic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "item")]
+  pub fn get_item_type(&self) -> ::sponge::item::ItemType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/ItemStatistic", self.object, "getItemType", "()Lorg/spongepowered/api/item/ItemType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/ItemStatistic#getItemType was null") }
+    ::sponge::item::ItemType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Statistic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Statistic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Statistic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Statistic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/Statistic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::statistic::statistic::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/statistic/Statistic", "builder", "()Lorg/spongepowered/api/statistic/Statistic$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic#builder was null") }
+    ::sponge::statistic::statistic::Builder { env: env, object: ret }
+  }
+
+  pub fn get_statistic_format(&self) -> Option<::sponge::statistic::StatisticFormat> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic", self.object, "getStatisticFormat", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic#getStatisticFormat was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::statistic::StatisticFormat { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_group(&self) -> ::sponge::statistic::StatisticGroup {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic", self.object, "getGroup", "()Lorg/spongepowered/api/statistic/StatisticGroup;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic#getGroup was null") }
+    ::sponge::statistic::StatisticGroup { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticFormat {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticFormat {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticFormat {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticFormat`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/StatisticFormat", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn format(&self, value: i64) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/StatisticFormat", self.object, "format", "(J)Ljava/lang/String;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/StatisticFormat#format was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticFormats {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticFormats {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticFormats {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticFormats`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/StatisticFormats", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticGroup {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticGroup {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticGroup {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticGroup`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/StatisticGroup", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_default_statistic_format(&self) -> ::sponge::statistic::StatisticFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/StatisticGroup", self.object, "getDefaultStatisticFormat", "()Lorg/spongepowered/api/statistic/StatisticFormat;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/StatisticGroup#getDefaultStatisticFormat was null") }
+    ::sponge::statistic::StatisticFormat { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct StatisticGroups {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StatisticGroups {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StatisticGroups {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticGroups`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/StatisticGroups", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Statistics {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Statistics {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Statistics {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Statistics`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/Statistics", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeamStatistic {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeamStatistic {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeamStatistic {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeamStatistic`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/TeamStatistic", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_team_color(&self) -> ::sponge::text::format::TextColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/statistic/TeamStatistic", self.object, "getTeamColor", "()Lorg/spongepowered/api/text/format/TextColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/statistic/TeamStatistic#getTeamColor was null") }
+    ::sponge::text::format::TextColor { env: self.env, object: ret }
+  }
+
+}
+
+pub mod block_statistic {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/BlockStatistic$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_type: ::sponge::block::BlockType) -> ::sponge::statistic::block_statistic::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/BlockStatistic$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockType;)Lorg/spongepowered/api/statistic/BlockStatistic$Builder;", CallObjectMethodA, block_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/BlockStatistic$Builder#block was null") }
+      ::sponge::statistic::block_statistic::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod entity_statistic {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/EntityStatistic$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn entity(&self, entity_type: ::sponge::entity::EntityType) -> ::sponge::statistic::entity_statistic::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/EntityStatistic$Builder", self.object, "entity", "(Lorg/spongepowered/api/entity/EntityType;)Lorg/spongepowered/api/statistic/EntityStatistic$Builder;", CallObjectMethodA, entity_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/EntityStatistic$Builder#entity was null") }
+      ::sponge::statistic::entity_statistic::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod item_statistic {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/ItemStatistic$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "item")]
+    pub fn item(&self, item_type: ::sponge::item::ItemType) -> ::sponge::statistic::item_statistic::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/ItemStatistic$Builder", self.object, "item", "(Lorg/spongepowered/api/item/ItemType;)Lorg/spongepowered/api/statistic/ItemStatistic$Builder;", CallObjectMethodA, item_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/ItemStatistic$Builder#item was null") }
+      ::sponge::statistic::item_statistic::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod statistic {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/Statistic$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct StatisticBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl StatisticBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      StatisticBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `StatisticBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn name(&self, string: jobject) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "name", "(Ljava/lang/String;)Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#name was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn translation(&self, translation: ::sponge::text::translation::Translation) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "translation", "(Lorg/spongepowered/api/text/translation/Translation;)Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethodA, translation.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#translation was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    pub fn format(&self, statistic_format: ::sponge::statistic::StatisticFormat) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "format", "(Lorg/spongepowered/api/statistic/StatisticFormat;)Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethodA, statistic_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#format was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    pub fn group(&self, statistic_group: ::sponge::statistic::StatisticGroup) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "group", "(Lorg/spongepowered/api/statistic/StatisticGroup;)Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethodA, statistic_group.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#group was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    pub fn build_and_register(&self) -> ::sponge::statistic::Statistic {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "buildAndRegister", "()Lorg/spongepowered/api/statistic/Statistic;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#buildAndRegister was null") }
+      ::sponge::statistic::Statistic { env: self.env, object: ret }
+    }
+
+    pub fn from_statistic(&self, statistic: ::sponge::statistic::Statistic) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "from", "(Lorg/spongepowered/api/statistic/Statistic;)Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethodA, statistic.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#from was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::statistic::statistic::StatisticBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "reset", "()Lorg/spongepowered/api/statistic/Statistic$StatisticBuilder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#reset was null") }
+      ::sponge::statistic::statistic::StatisticBuilder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/Statistic$StatisticBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/Statistic$StatisticBuilder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod team_statistic {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/statistic/TeamStatistic$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "text")]
+    pub fn team_color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::statistic::team_statistic::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/statistic/TeamStatistic$Builder", self.object, "teamColor", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/statistic/TeamStatistic$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/statistic/TeamStatistic$Builder#teamColor was null") }
+      ::sponge::statistic::team_statistic::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
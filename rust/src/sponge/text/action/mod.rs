@@ -0,0 +1,553 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ClickAction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClickAction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClickAction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ClickAction`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/action/ClickAction", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct HoverAction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl HoverAction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    HoverAction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `HoverAction`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ShiftClickAction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ShiftClickAction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ShiftClickAction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShiftClickAction`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ShiftClickAction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/action/ShiftClickAction", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextAction {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextAction {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextAction {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextAction`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/TextAction", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_result(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/action/TextAction", self.object, "getResult", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextAction#getResult was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/action/TextAction", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/action/TextAction", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/action/TextAction", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextAction#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextActions {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextActions {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextActions {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextActions`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/TextActions", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn open_url(env: *mut JNIEnv, url: ::url::Url) -> ::sponge::text::action::click_action::OpenUrl {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "openUrl", "(Ljava/net/URL;)Lorg/spongepowered/api/text/action/ClickAction$OpenUrl;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&url, env));
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#openUrl was null") }
+    ::sponge::text::action::click_action::OpenUrl { env: env, object: ret }
+  }
+
+  pub fn run_command(env: *mut JNIEnv, string: jobject) -> ::sponge::text::action::click_action::RunCommand {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "runCommand", "(Ljava/lang/String;)Lorg/spongepowered/api/text/action/ClickAction$RunCommand;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#runCommand was null") }
+    ::sponge::text::action::click_action::RunCommand { env: env, object: ret }
+  }
+
+  pub fn change_page(env: *mut JNIEnv, value: i32) -> ::sponge::text::action::click_action::ChangePage {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "changePage", "(I)Lorg/spongepowered/api/text/action/ClickAction$ChangePage;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#changePage was null") }
+    ::sponge::text::action::click_action::ChangePage { env: env, object: ret }
+  }
+
+  pub fn suggest_command(env: *mut JNIEnv, string: jobject) -> ::sponge::text::action::click_action::SuggestCommand {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "suggestCommand", "(Ljava/lang/String;)Lorg/spongepowered/api/text/action/ClickAction$SuggestCommand;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#suggestCommand was null") }
+    ::sponge::text::action::click_action::SuggestCommand { env: env, object: ret }
+  }
+
+  pub fn execute_callback(env: *mut JNIEnv, consumer: jobject) -> ::sponge::text::action::click_action::ExecuteCallback {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "executeCallback", "(Ljava/util/function/Consumer;)Lorg/spongepowered/api/text/action/ClickAction$ExecuteCallback;", CallStaticObjectMethodA, consumer);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#executeCallback was null") }
+    ::sponge::text::action::click_action::ExecuteCallback { env: env, object: ret }
+  }
+
+  pub fn show_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::text::action::hover_action::ShowText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showText", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/action/HoverAction$ShowText;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showText was null") }
+    ::sponge::text::action::hover_action::ShowText { env: env, object: ret }
+  }
+
+  #[cfg(feature = "item")]
+  pub fn show_item(env: *mut JNIEnv, item_stack: ::sponge::item::inventory::ItemStack) -> ::sponge::text::action::hover_action::ShowItem {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showItem", "(Lorg/spongepowered/api/item/inventory/ItemStack;)Lorg/spongepowered/api/text/action/HoverAction$ShowItem;", CallStaticObjectMethodA, item_stack.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showItem was null") }
+    ::sponge::text::action::hover_action::ShowItem { env: env, object: ret }
+  }
+
+  #[cfg(feature = "statistic")]
+  pub fn show_achievement(env: *mut JNIEnv, achievement: ::sponge::statistic::achievement::Achievement) -> ::sponge::text::action::hover_action::ShowAchievement {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showAchievement", "(Lorg/spongepowered/api/statistic/achievement/Achievement;)Lorg/spongepowered/api/text/action/HoverAction$ShowAchievement;", CallStaticObjectMethodA, achievement.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showAchievement was null") }
+    ::sponge::text::action::hover_action::ShowAchievement { env: env, object: ret }
+  }
+
+  pub fn show_entity_ref(env: *mut JNIEnv, ref_: ::sponge::text::action::hover_action::show_entity::Ref) -> ::sponge::text::action::hover_action::ShowEntity {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showEntity", "(Lorg/spongepowered/api/text/action/HoverAction$ShowEntity$Ref;)Lorg/spongepowered/api/text/action/HoverAction$ShowEntity;", CallStaticObjectMethodA, ref_.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showEntity was null") }
+    ::sponge::text::action::hover_action::ShowEntity { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn show_entity_uuid_string_entity_type(env: *mut JNIEnv, uuid: jobject, string: jobject, entity_type: ::sponge::entity::EntityType) -> ::sponge::text::action::hover_action::ShowEntity {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showEntity", "(Ljava/util/UUID;Ljava/lang/String;Lorg/spongepowered/api/entity/EntityType;)Lorg/spongepowered/api/text/action/HoverAction$ShowEntity;", CallStaticObjectMethodA, uuid, string, entity_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showEntity was null") }
+    ::sponge::text::action::hover_action::ShowEntity { env: env, object: ret }
+  }
+
+  pub fn show_entity_uuid_string(env: *mut JNIEnv, uuid: jobject, string: jobject) -> ::sponge::text::action::hover_action::ShowEntity {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showEntity", "(Ljava/util/UUID;Ljava/lang/String;)Lorg/spongepowered/api/text/action/HoverAction$ShowEntity;", CallStaticObjectMethodA, uuid, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showEntity was null") }
+    ::sponge::text::action::hover_action::ShowEntity { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn show_entity_entity_string(env: *mut JNIEnv, entity: ::sponge::entity::Entity, string: jobject) -> ::sponge::text::action::hover_action::ShowEntity {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "showEntity", "(Lorg/spongepowered/api/entity/Entity;Ljava/lang/String;)Lorg/spongepowered/api/text/action/HoverAction$ShowEntity;", CallStaticObjectMethodA, entity.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#showEntity was null") }
+    ::sponge::text::action::hover_action::ShowEntity { env: env, object: ret }
+  }
+
+  pub fn insert_text(env: *mut JNIEnv, string: jobject) -> ::sponge::text::action::shift_click_action::InsertText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/action/TextActions", "insertText", "(Ljava/lang/String;)Lorg/spongepowered/api/text/action/ShiftClickAction$InsertText;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/action/TextActions#insertText was null") }
+    ::sponge::text::action::shift_click_action::InsertText { env: env, object: ret }
+  }
+
+}
+
+pub mod click_action {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ChangePage {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ChangePage {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ChangePage {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChangePage`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction$ChangePage", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ExecuteCallback {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ExecuteCallback {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ExecuteCallback {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExecuteCallback`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction$ExecuteCallback", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct OpenUrl {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OpenUrl {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OpenUrl {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OpenUrl`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction$OpenUrl", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct RunCommand {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl RunCommand {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      RunCommand {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `RunCommand`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction$RunCommand", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct SuggestCommand {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl SuggestCommand {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      SuggestCommand {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `SuggestCommand`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ClickAction$SuggestCommand", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod hover_action {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ShowAchievement {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ShowAchievement {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ShowAchievement {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShowAchievement`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction$ShowAchievement", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ShowEntity {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ShowEntity {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ShowEntity {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShowEntity`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction$ShowEntity", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ShowItem {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ShowItem {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ShowItem {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShowItem`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction$ShowItem", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ShowText {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ShowText {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ShowText {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ShowText`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction$ShowText", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  pub mod show_entity {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Ref {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Ref {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Ref {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the implementation in `Ref`, even if
+      /// the class of the object overrides it.
+      pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      #[cfg(feature = "entity")]
+      pub fn new_uuid_string_entity_type(env: *mut JNIEnv, uuid: jobject, string: jobject, entity_type: ::sponge::entity::EntityType) -> ::sponge::text::action::hover_action::show_entity::Ref {
+        let ret = new_object!(env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", "(Ljava/util/UUID;Ljava/lang/String;Lorg/spongepowered/api/entity/EntityType;)V", uuid, string, entity_type.object);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#<init> was null") }
+        ::sponge::text::action::hover_action::show_entity::Ref { env: env, object: ret }
+      }
+
+      pub fn new_uuid_string(env: *mut JNIEnv, uuid: jobject, string: jobject) -> ::sponge::text::action::hover_action::show_entity::Ref {
+        let ret = new_object!(env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", "(Ljava/util/UUID;Ljava/lang/String;)V", uuid, string);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#<init> was null") }
+        ::sponge::text::action::hover_action::show_entity::Ref { env: env, object: ret }
+      }
+
+      #[cfg(feature = "entity")]
+      pub fn new_entity_string(env: *mut JNIEnv, entity: ::sponge::entity::Entity, string: jobject) -> ::sponge::text::action::hover_action::show_entity::Ref {
+        let ret = new_object!(env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", "(Lorg/spongepowered/api/entity/Entity;Ljava/lang/String;)V", entity.object, string);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#<init> was null") }
+        ::sponge::text::action::hover_action::show_entity::Ref { env: env, object: ret }
+      }
+
+      pub fn get_unique_id(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "getUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#getUniqueId was null") }
+        ret
+      }
+
+      pub fn get_name(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#getName was null") }
+        ret
+      }
+
+      #[cfg(feature = "entity")]
+      pub fn get_type(&self) -> Option<::sponge::entity::EntityType> {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "getType", "()Ljava/util/Optional;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#getType was null") }
+        let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+        if unwrapped.is_null() { None } else { Some(::sponge::entity::EntityType { env: self.env, object: unwrapped }) }
+      }
+
+      pub fn equals(&self, object: jobject) -> bool {
+        bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+      }
+
+      pub fn hash_code(&self) -> i32 {
+        bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "hashCode", "()I", CallIntMethod)
+      }
+
+      pub fn to_string(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/action/HoverAction$ShowEntity$Ref#toString was null") }
+        ret
+      }
+
+    }
+  }
+}
+
+pub mod shift_click_action {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct InsertText {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl InsertText {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      InsertText {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `InsertText`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/action/ShiftClickAction$InsertText", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
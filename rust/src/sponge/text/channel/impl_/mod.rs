@@ -0,0 +1,114 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DelegateMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DelegateMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DelegateMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DelegateMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/impl/DelegateMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, message_channel: ::sponge::text::channel::MessageChannel) -> ::sponge::text::channel::impl_::DelegateMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/impl/DelegateMessageChannel", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", message_channel.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/DelegateMessageChannel#<init> was null") }
+    ::sponge::text::channel::impl_::DelegateMessageChannel { env: env, object: ret }
+  }
+
+  pub fn transform_message(&self, object: jobject, message_receiver: ::sponge::text::channel::MessageReceiver, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/impl/DelegateMessageChannel", self.object, "transformMessage", "(Ljava/lang/Object;Lorg/spongepowered/api/text/channel/MessageReceiver;Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)Ljava/util/Optional;", CallObjectMethodA, object, message_receiver.object, text.object, chat_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/DelegateMessageChannel#transformMessage was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/impl/DelegateMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/DelegateMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DelegateMutableMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DelegateMutableMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DelegateMutableMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DelegateMutableMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/impl/DelegateMutableMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, message_channel: ::sponge::text::channel::MessageChannel) -> ::sponge::text::channel::impl_::DelegateMutableMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/impl/DelegateMutableMessageChannel", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", message_channel.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/DelegateMutableMessageChannel#<init> was null") }
+    ::sponge::text::channel::impl_::DelegateMutableMessageChannel { env: env, object: ret }
+  }
+
+  pub fn transform_message(&self, object: jobject, message_receiver: ::sponge::text::channel::MessageReceiver, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/impl/DelegateMutableMessageChannel", self.object, "transformMessage", "(Ljava/lang/Object;Lorg/spongepowered/api/text/channel/MessageReceiver;Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)Ljava/util/Optional;", CallObjectMethodA, object, message_receiver.object, text.object, chat_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/DelegateMutableMessageChannel#transformMessage was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SimpleMutableMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SimpleMutableMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SimpleMutableMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SimpleMutableMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/impl/SimpleMutableMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::channel::impl_::SimpleMutableMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/impl/SimpleMutableMessageChannel", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/SimpleMutableMessageChannel#<init> was null") }
+    ::sponge::text::channel::impl_::SimpleMutableMessageChannel { env: env, object: ret }
+  }
+
+  pub fn new_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::text::channel::impl_::SimpleMutableMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/impl/SimpleMutableMessageChannel", "(Ljava/util/Collection;)V", collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/impl/SimpleMutableMessageChannel#<init> was null") }
+    ::sponge::text::channel::impl_::SimpleMutableMessageChannel { env: env, object: ret }
+  }
+
+}
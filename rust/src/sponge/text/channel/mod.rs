@@ -0,0 +1,269 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod impl_;
+pub mod type_;
+
+#[derive(Debug)]
+pub struct AbstractMutableMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AbstractMutableMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AbstractMutableMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AbstractMutableMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/AbstractMutableMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn add_member(&self, message_receiver: ::sponge::text::channel::MessageReceiver) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/AbstractMutableMessageChannel", self.object, "addMember", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)Z", CallBooleanMethodA, message_receiver.object) != 0
+  }
+
+  pub fn remove_member(&self, message_receiver: ::sponge::text::channel::MessageReceiver) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/AbstractMutableMessageChannel", self.object, "removeMember", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)Z", CallBooleanMethodA, message_receiver.object) != 0
+  }
+
+  pub fn clear_members(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/AbstractMutableMessageChannel", self.object, "clearMembers", "()V", CallVoidMethod);
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/AbstractMutableMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/AbstractMutableMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChatTypeMessageReceiver {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChatTypeMessageReceiver {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChatTypeMessageReceiver {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChatTypeMessageReceiver`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/ChatTypeMessageReceiver", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn send_message_chat_type_text(&self, chat_type: ::sponge::text::chat::ChatType, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/ChatTypeMessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/chat/ChatType;Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, chat_type.object, text.object);
+  }
+
+  pub fn send_messages_chat_type_text_array(&self, chat_type: ::sponge::text::chat::ChatType, texts: Vec<::sponge::text::Text>) {
+    unimplemented!();
+  }
+  pub fn send_messages_chat_type_iterable(&self, chat_type: ::sponge::text::chat::ChatType, iterable: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/ChatTypeMessageReceiver", self.object, "sendMessages", "(Lorg/spongepowered/api/text/chat/ChatType;Ljava/lang/Iterable;)V", CallVoidMethodA, chat_type.object, iterable);
+  }
+
+  pub fn send_message_chat_type_text_template(&self, chat_type: ::sponge::text::chat::ChatType, text_template: ::sponge::text::TextTemplate) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/ChatTypeMessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/chat/ChatType;Lorg/spongepowered/api/text/TextTemplate;)V", CallVoidMethodA, chat_type.object, text_template.object);
+  }
+
+  pub fn send_message_chat_type_text_template_map(&self, chat_type: ::sponge::text::chat::ChatType, text_template: ::sponge::text::TextTemplate, map: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/ChatTypeMessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/chat/ChatType;Lorg/spongepowered/api/text/TextTemplate;Ljava/util/Map;)V", CallVoidMethodA, chat_type.object, text_template.object, map);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/MessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn permission(env: *mut JNIEnv, string: jobject) -> ::sponge::text::channel::MessageChannel {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/channel/MessageChannel", "permission", "(Ljava/lang/String;)Lorg/spongepowered/api/text/channel/MessageChannel;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#permission was null") }
+    ::sponge::text::channel::MessageChannel { env: env, object: ret }
+  }
+
+  pub fn combined_message_channel_array(env: *mut JNIEnv, message_channels: Vec<::sponge::text::channel::MessageChannel>) -> ::sponge::text::channel::MessageChannel {
+    unimplemented!();
+  }
+  pub fn combined_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::text::channel::MessageChannel {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/channel/MessageChannel", "combined", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/channel/MessageChannel;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#combined was null") }
+    ::sponge::text::channel::MessageChannel { env: env, object: ret }
+  }
+
+  pub fn fixed_message_receiver_array(env: *mut JNIEnv, message_receivers: Vec<::sponge::text::channel::MessageReceiver>) -> ::sponge::text::channel::MessageChannel {
+    unimplemented!();
+  }
+  pub fn fixed_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::text::channel::MessageChannel {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/channel/MessageChannel", "fixed", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/channel/MessageChannel;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#fixed was null") }
+    ::sponge::text::channel::MessageChannel { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn world(env: *mut JNIEnv, world: ::sponge::world::World) -> ::sponge::text::channel::MessageChannel {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/channel/MessageChannel", "world", "(Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/text/channel/MessageChannel;", CallStaticObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#world was null") }
+    ::sponge::text::channel::MessageChannel { env: env, object: ret }
+  }
+
+  pub fn send_text(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "send", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  pub fn send_text_chat_type(&self, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "send", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)V", CallVoidMethodA, text.object, chat_type.object);
+  }
+
+  pub fn send_object_text(&self, object: jobject, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "send", "(Ljava/lang/Object;Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, object, text.object);
+  }
+
+  pub fn send_object_text_chat_type(&self, object: jobject, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "send", "(Ljava/lang/Object;Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)V", CallVoidMethodA, object, text.object, chat_type.object);
+  }
+
+  pub fn transform_message(&self, object: jobject, message_receiver: ::sponge::text::channel::MessageReceiver, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "transformMessage", "(Ljava/lang/Object;Lorg/spongepowered/api/text/channel/MessageReceiver;Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)Ljava/util/Optional;", CallObjectMethodA, object, message_receiver.object, text.object, chat_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#transformMessage was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#getMembers was null") }
+    ret
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::text::channel::MutableMessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/MessageChannel", self.object, "asMutable", "()Lorg/spongepowered/api/text/channel/MutableMessageChannel;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageChannel#asMutable was null") }
+    ::sponge::text::channel::MutableMessageChannel { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MessageReceiver {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MessageReceiver {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MessageReceiver {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MessageReceiver`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/MessageReceiver", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn send_message_text(&self, text: ::sponge::text::Text) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/Text;)V", CallVoidMethodA, text.object);
+  }
+
+  pub fn send_messages_text_array(&self, texts: Vec<::sponge::text::Text>) {
+    unimplemented!();
+  }
+  pub fn send_messages_iterable(&self, iterable: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "sendMessages", "(Ljava/lang/Iterable;)V", CallVoidMethodA, iterable);
+  }
+
+  pub fn send_message_text_template(&self, text_template: ::sponge::text::TextTemplate) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/TextTemplate;)V", CallVoidMethodA, text_template.object);
+  }
+
+  pub fn send_message_text_template_map(&self, text_template: ::sponge::text::TextTemplate, map: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "sendMessage", "(Lorg/spongepowered/api/text/TextTemplate;Ljava/util/Map;)V", CallVoidMethodA, text_template.object, map);
+  }
+
+  pub fn get_message_channel(&self) -> ::sponge::text::channel::MessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "getMessageChannel", "()Lorg/spongepowered/api/text/channel/MessageChannel;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MessageReceiver#getMessageChannel was null") }
+    ::sponge::text::channel::MessageChannel { env: self.env, object: ret }
+  }
+
+  pub fn set_message_channel(&self, message_channel: ::sponge::text::channel::MessageChannel) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MessageReceiver", self.object, "setMessageChannel", "(Lorg/spongepowered/api/text/channel/MessageChannel;)V", CallVoidMethodA, message_channel.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/MutableMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn add_member(&self, message_receiver: ::sponge::text::channel::MessageReceiver) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MutableMessageChannel", self.object, "addMember", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)Z", CallBooleanMethodA, message_receiver.object) != 0
+  }
+
+  pub fn remove_member(&self, message_receiver: ::sponge::text::channel::MessageReceiver) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MutableMessageChannel", self.object, "removeMember", "(Lorg/spongepowered/api/text/channel/MessageReceiver;)Z", CallBooleanMethodA, message_receiver.object) != 0
+  }
+
+  pub fn clear_members(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/channel/MutableMessageChannel", self.object, "clearMembers", "()V", CallVoidMethod);
+  }
+
+  pub fn as_mutable(&self) -> ::sponge::text::channel::MutableMessageChannel {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/MutableMessageChannel", self.object, "asMutable", "()Lorg/spongepowered/api/text/channel/MutableMessageChannel;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/MutableMessageChannel#asMutable was null") }
+    ::sponge::text::channel::MutableMessageChannel { env: self.env, object: ret }
+  }
+
+}
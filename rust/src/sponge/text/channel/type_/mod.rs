@@ -0,0 +1,161 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CombinedMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CombinedMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CombinedMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `CombinedMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/type/CombinedMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_message_channel_array(env: *mut JNIEnv, message_channels: Vec<::sponge::text::channel::MessageChannel>) -> ::sponge::text::channel::type_::CombinedMessageChannel {
+    unimplemented!();
+  }
+  pub fn new_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::text::channel::type_::CombinedMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/type/CombinedMessageChannel", "(Ljava/util/Collection;)V", collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/CombinedMessageChannel#<init> was null") }
+    ::sponge::text::channel::type_::CombinedMessageChannel { env: env, object: ret }
+  }
+
+  pub fn transform_message(&self, object: jobject, message_receiver: ::sponge::text::channel::MessageReceiver, text: ::sponge::text::Text, chat_type: ::sponge::text::chat::ChatType) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/CombinedMessageChannel", self.object, "transformMessage", "(Ljava/lang/Object;Lorg/spongepowered/api/text/channel/MessageReceiver;Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/chat/ChatType;)Ljava/util/Optional;", CallObjectMethodA, object, message_receiver.object, text.object, chat_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/CombinedMessageChannel#transformMessage was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/CombinedMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/CombinedMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FixedMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FixedMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FixedMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FixedMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/type/FixedMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_message_receiver_array(env: *mut JNIEnv, message_receivers: Vec<::sponge::text::channel::MessageReceiver>) -> ::sponge::text::channel::type_::FixedMessageChannel {
+    unimplemented!();
+  }
+  pub fn new_collection(env: *mut JNIEnv, collection: jobject) -> ::sponge::text::channel::type_::FixedMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/type/FixedMessageChannel", "(Ljava/util/Collection;)V", collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/FixedMessageChannel#<init> was null") }
+    ::sponge::text::channel::type_::FixedMessageChannel { env: env, object: ret }
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/FixedMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/FixedMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PermissionMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PermissionMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PermissionMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `PermissionMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/type/PermissionMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::text::channel::type_::PermissionMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/type/PermissionMessageChannel", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/PermissionMessageChannel#<init> was null") }
+    ::sponge::text::channel::type_::PermissionMessageChannel { env: env, object: ret }
+  }
+
+  pub fn get_permission(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/PermissionMessageChannel", self.object, "getPermission", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/PermissionMessageChannel#getPermission was null") }
+    ret
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/PermissionMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/PermissionMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldMessageChannel {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldMessageChannel {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldMessageChannel {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `WorldMessageChannel`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/channel/type/WorldMessageChannel", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new(env: *mut JNIEnv, world: ::sponge::world::World) -> ::sponge::text::channel::type_::WorldMessageChannel {
+    let ret = new_object!(env, "org/spongepowered/api/text/channel/type/WorldMessageChannel", "(Lorg/spongepowered/api/world/World;)V", world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/WorldMessageChannel#<init> was null") }
+    ::sponge::text::channel::type_::WorldMessageChannel { env: env, object: ret }
+  }
+
+  pub fn get_members(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/channel/type/WorldMessageChannel", self.object, "getMembers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/channel/type/WorldMessageChannel#getMembers was null") }
+    ret
+  }
+
+}
@@ -0,0 +1,97 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChatType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChatType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChatType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChatType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/chat/ChatType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChatTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChatTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChatTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChatTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/chat/ChatTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChatVisibilities {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChatVisibilities {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChatVisibilities {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChatVisibilities`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/chat/ChatVisibilities", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChatVisibility {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChatVisibility {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChatVisibility {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChatVisibility`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/chat/ChatVisibility", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_visible(&self, chat_type: ::sponge::text::chat::ChatType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/chat/ChatVisibility", self.object, "isVisible", "(Lorg/spongepowered/api/text/chat/ChatType;)Z", CallBooleanMethodA, chat_type.object) != 0
+  }
+
+}
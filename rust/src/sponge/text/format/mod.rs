@@ -0,0 +1,521 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct TextColor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextColor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextColor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextColor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextColor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_color(&self) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextColor", self.object, "getColor", "()Lorg/spongepowered/api/util/Color;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextColor#getColor was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextColor", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextColors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextColors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextColors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextColors`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextColors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextFormat {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextFormat {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextFormat {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextFormat`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextFormat", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv) -> ::sponge::text::format::TextFormat {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/format/TextFormat", "of", "()Lorg/spongepowered/api/text/format/TextFormat;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#of was null") }
+    ::sponge::text::format::TextFormat { env: env, object: ret }
+  }
+
+  pub fn of_text_style(env: *mut JNIEnv, text_style: ::sponge::text::format::TextStyle) -> ::sponge::text::format::TextFormat {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/format/TextFormat", "of", "(Lorg/spongepowered/api/text/format/TextStyle;)Lorg/spongepowered/api/text/format/TextFormat;", CallStaticObjectMethodA, text_style.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#of was null") }
+    ::sponge::text::format::TextFormat { env: env, object: ret }
+  }
+
+  pub fn of_text_color(env: *mut JNIEnv, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::format::TextFormat {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/format/TextFormat", "of", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/format/TextFormat;", CallStaticObjectMethodA, text_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#of was null") }
+    ::sponge::text::format::TextFormat { env: env, object: ret }
+  }
+
+  pub fn of_text_color_text_style(env: *mut JNIEnv, text_color: ::sponge::text::format::TextColor, text_style: ::sponge::text::format::TextStyle) -> ::sponge::text::format::TextFormat {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/format/TextFormat", "of", "(Lorg/spongepowered/api/text/format/TextColor;Lorg/spongepowered/api/text/format/TextStyle;)Lorg/spongepowered/api/text/format/TextFormat;", CallStaticObjectMethodA, text_color.object, text_style.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#of was null") }
+    ::sponge::text::format::TextFormat { env: env, object: ret }
+  }
+
+  pub fn get_color(&self) -> ::sponge::text::format::TextColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "getColor", "()Lorg/spongepowered/api/text/format/TextColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#getColor was null") }
+    ::sponge::text::format::TextColor { env: self.env, object: ret }
+  }
+
+  pub fn get_style(&self) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "getStyle", "()Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#getStyle was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::format::TextFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethodA, text_color.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#color was null") }
+    ::sponge::text::format::TextFormat { env: self.env, object: ret }
+  }
+
+  pub fn style(&self, text_style: ::sponge::text::format::TextStyle) -> ::sponge::text::format::TextFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "style", "(Lorg/spongepowered/api/text/format/TextStyle;)Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethodA, text_style.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#style was null") }
+    ::sponge::text::format::TextFormat { env: self.env, object: ret }
+  }
+
+  pub fn merge(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::format::TextFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "merge", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethodA, text_format.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#merge was null") }
+    ::sponge::text::format::TextFormat { env: self.env, object: ret }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextFormat", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextFormat#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextStyle {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextStyle {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextStyle {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextStyle`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextStyle", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, boolean: jobject, boolean_2: jobject, boolean_3: jobject, boolean_4: jobject, boolean_5: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = new_object!(env, "org/spongepowered/api/text/format/TextStyle", "(Ljava/lang/Boolean;Ljava/lang/Boolean;Ljava/lang/Boolean;Ljava/lang/Boolean;Ljava/lang/Boolean;)V", boolean, boolean_2, boolean_3, boolean_4, boolean_5);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#<init> was null") }
+    ::sponge::text::format::TextStyle { env: env, object: ret }
+  }
+
+  pub fn is_composite(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "isComposite", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn bold(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "bold", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#bold was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn italic(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "italic", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#italic was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn underline(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "underline", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#underline was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn strikethrough(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "strikethrough", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#strikethrough was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn obfuscated(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "obfuscated", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#obfuscated was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn is_bold(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "isBold", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#isBold was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn is_italic(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "isItalic", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#isItalic was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn has_underline(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "hasUnderline", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#hasUnderline was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn has_strikethrough(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "hasStrikethrough", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#hasStrikethrough was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn is_obfuscated(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "isObfuscated", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#isObfuscated was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn contains(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> bool {
+    unimplemented!();
+  }
+  pub fn negate(&self) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "negate", "()Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#negate was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn and(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::format::TextStyle {
+    unimplemented!();
+  }
+  pub fn and_not(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::format::TextStyle {
+    unimplemented!();
+  }
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyle#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextStyles {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextStyles {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextStyles {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextStyles`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextStyles", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv) -> ::sponge::text::format::TextStyle {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/format/TextStyles", "of", "()Lorg/spongepowered/api/text/format/TextStyle;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles#of was null") }
+    ::sponge::text::format::TextStyle { env: env, object: ret }
+  }
+
+  pub fn of_text_style_array(env: *mut JNIEnv, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::format::TextStyle {
+    unimplemented!();
+  }
+}
+
+pub mod text_style {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Base {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Base {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Base {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Base`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextStyle$Base", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn is_composite(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/format/TextStyle$Base", self.object, "isComposite", "()Z", CallBooleanMethod) != 0
+    }
+
+  }
+}
+
+pub mod text_styles {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct DummyTextStyle {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl DummyTextStyle {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      DummyTextStyle {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `DummyTextStyle`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn is_composite(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "isComposite", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn bold(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "bold", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#bold was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn italic(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "italic", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#italic was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn underline(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "underline", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#underline was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn strikethrough(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "strikethrough", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#strikethrough was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn obfuscated(&self, boolean: jobject) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "obfuscated", "(Ljava/lang/Boolean;)Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethodA, boolean);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#obfuscated was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn is_bold(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "isBold", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#isBold was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn is_italic(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "isItalic", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#isItalic was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn has_underline(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "hasUnderline", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#hasUnderline was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn has_strikethrough(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "hasStrikethrough", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#hasStrikethrough was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn is_obfuscated(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "isObfuscated", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#isObfuscated was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn contains(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> bool {
+      unimplemented!();
+    }
+    pub fn negate(&self) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "negate", "()Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#negate was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn and(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::format::TextStyle {
+      unimplemented!();
+    }
+    pub fn and_not(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::format::TextStyle {
+      unimplemented!();
+    }
+    pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+      bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+    }
+
+    pub fn get_id(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#getId was null") }
+      ret
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$DummyTextStyle", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$DummyTextStyle#getName was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct NoneTextStyle {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl NoneTextStyle {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      NoneTextStyle {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `NoneTextStyle`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/format/TextStyles$NoneTextStyle", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_id(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$NoneTextStyle", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$NoneTextStyle#getId was null") }
+      ret
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/format/TextStyles$NoneTextStyle", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/format/TextStyles$NoneTextStyle#getName was null") }
+      ret
+    }
+
+  }
+}
@@ -0,0 +1,1904 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod action;
+pub mod channel;
+pub mod chat;
+pub mod format;
+pub mod selector;
+pub mod serializer;
+pub mod title;
+pub mod transform;
+pub mod translation;
+
+#[derive(Debug)]
+pub struct BookView {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BookView {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BookView {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BookView`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/BookView", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_title(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "getTitle", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#getTitle was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_author(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "getAuthor", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#getAuthor was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_pages(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "getPages", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#getPages was null") }
+    ret
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::text::book_view::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/BookView", "builder", "()Lorg/spongepowered/api/text/BookView$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#builder was null") }
+    ::sponge::text::book_view::Builder { env: env, object: ret }
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/BookView#toString was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/BookView", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LiteralText {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LiteralText {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LiteralText {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LiteralText`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/LiteralText", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_content(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText", self.object, "getContent", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText#getContent was null") }
+    ret
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::literal_text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText", self.object, "toBuilder", "()Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText#toBuilder was null") }
+    ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/LiteralText", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/LiteralText", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ScoreText {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ScoreText {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ScoreText {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ScoreText`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/ScoreText", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn get_score(&self) -> ::sponge::scoreboard::Score {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText", self.object, "getScore", "()Lorg/spongepowered/api/scoreboard/Score;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText#getScore was null") }
+    ::sponge::scoreboard::Score { env: self.env, object: ret }
+  }
+
+  pub fn get_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText", self.object, "getOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText#getOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::score_text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText", self.object, "toBuilder", "()Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText#toBuilder was null") }
+    ::sponge::text::score_text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/ScoreText", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/ScoreText", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SelectorText {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SelectorText {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SelectorText {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SelectorText`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/SelectorText", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_selector(&self) -> ::sponge::text::selector::Selector {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText", self.object, "getSelector", "()Lorg/spongepowered/api/text/selector/Selector;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText#getSelector was null") }
+    ::sponge::text::selector::Selector { env: self.env, object: ret }
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::selector_text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText", self.object, "toBuilder", "()Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText#toBuilder was null") }
+    ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/SelectorText", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/SelectorText", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Text {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Text {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Text {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Text`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/Text", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_format(&self) -> ::sponge::text::format::TextFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getFormat", "()Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getFormat was null") }
+    ::sponge::text::format::TextFormat { env: self.env, object: ret }
+  }
+
+  pub fn get_color(&self) -> ::sponge::text::format::TextColor {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getColor", "()Lorg/spongepowered/api/text/format/TextColor;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getColor was null") }
+    ::sponge::text::format::TextColor { env: self.env, object: ret }
+  }
+
+  pub fn get_style(&self) -> ::sponge::text::format::TextStyle {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getStyle", "()Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getStyle was null") }
+    ::sponge::text::format::TextStyle { env: self.env, object: ret }
+  }
+
+  pub fn get_children(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getChildren", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getChildren was null") }
+    ret
+  }
+
+  pub fn with_children(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "withChildren", "()Ljava/lang/Iterable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#withChildren was null") }
+    ret
+  }
+
+  pub fn get_click_action(&self) -> Option<::sponge::text::action::ClickAction> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getClickAction", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getClickAction was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::action::ClickAction { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_hover_action(&self) -> Option<::sponge::text::action::HoverAction> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getHoverAction", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getHoverAction was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::action::HoverAction { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_shift_click_action(&self) -> Option<::sponge::text::action::ShiftClickAction> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getShiftClickAction", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#getShiftClickAction was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::action::ShiftClickAction { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "toBuilder", "()Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#toBuilder was null") }
+    ::sponge::text::text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn to_plain(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "toPlain", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#toPlain was null") }
+    ret
+  }
+
+  pub fn concat(&self, text: ::sponge::text::Text) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "concat", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#concat was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn trim(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "trim", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#trim was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn compare_to_text(&self, text: ::sponge::text::Text) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "compareTo", "(Lorg/spongepowered/api/text/Text;)I", CallIntMethodA, text.object)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#toString was null") }
+    ret
+  }
+
+  pub fn to_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#toText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "of", "()Lorg/spongepowered/api/text/Text;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#of was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  pub fn of_string(env: *mut JNIEnv, string: jobject) -> ::sponge::text::LiteralText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "of", "(Ljava/lang/String;)Lorg/spongepowered/api/text/LiteralText;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#of was null") }
+    ::sponge::text::LiteralText { env: env, object: ret }
+  }
+
+  pub fn of_char(env: *mut JNIEnv, value: char) -> ::sponge::text::LiteralText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "of", "(C)Lorg/spongepowered/api/text/LiteralText;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#of was null") }
+    ::sponge::text::LiteralText { env: env, object: ret }
+  }
+
+  pub fn of_translation_object_array(env: *mut JNIEnv, translation: ::sponge::text::translation::Translation, objects: Vec<jobject>) -> ::sponge::text::TranslatableText {
+    unimplemented!();
+  }
+  pub fn of_translatable_object_array(env: *mut JNIEnv, translatable: ::sponge::text::translation::Translatable, objects: Vec<jobject>) -> ::sponge::text::TranslatableText {
+    unimplemented!();
+  }
+  pub fn of_selector(env: *mut JNIEnv, selector: ::sponge::text::selector::Selector) -> ::sponge::text::SelectorText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "of", "(Lorg/spongepowered/api/text/selector/Selector;)Lorg/spongepowered/api/text/SelectorText;", CallStaticObjectMethodA, selector.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#of was null") }
+    ::sponge::text::SelectorText { env: env, object: ret }
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn of_score(env: *mut JNIEnv, score: ::sponge::scoreboard::Score) -> ::sponge::text::ScoreText {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "of", "(Lorg/spongepowered/api/scoreboard/Score;)Lorg/spongepowered/api/text/ScoreText;", CallStaticObjectMethodA, score.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#of was null") }
+    ::sponge::text::ScoreText { env: env, object: ret }
+  }
+
+  pub fn of_object_array(env: *mut JNIEnv, objects: Vec<jobject>) -> ::sponge::text::Text {
+    unimplemented!();
+  }
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::text::text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "()Lorg/spongepowered/api/text/Text$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::text::Builder { env: env, object: ret }
+  }
+
+  pub fn builder_string(env: *mut JNIEnv, string: jobject) -> ::sponge::text::literal_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Ljava/lang/String;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::literal_text::Builder { env: env, object: ret }
+  }
+
+  pub fn builder_char(env: *mut JNIEnv, value: char) -> ::sponge::text::literal_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(C)Lorg/spongepowered/api/text/LiteralText$Builder;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::literal_text::Builder { env: env, object: ret }
+  }
+
+  pub fn builder_text_string(env: *mut JNIEnv, text: ::sponge::text::Text, string: jobject) -> ::sponge::text::literal_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Lorg/spongepowered/api/text/Text;Ljava/lang/String;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallStaticObjectMethodA, text.object, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::literal_text::Builder { env: env, object: ret }
+  }
+
+  pub fn builder_translation_object_array(env: *mut JNIEnv, translation: ::sponge::text::translation::Translation, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+    unimplemented!();
+  }
+  pub fn builder_translatable_object_array(env: *mut JNIEnv, translatable: ::sponge::text::translation::Translatable, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+    unimplemented!();
+  }
+  pub fn builder_text_translation_object_array(env: *mut JNIEnv, text: ::sponge::text::Text, translation: ::sponge::text::translation::Translation, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+    unimplemented!();
+  }
+  pub fn builder_text_translatable_object_array(env: *mut JNIEnv, text: ::sponge::text::Text, translatable: ::sponge::text::translation::Translatable, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+    unimplemented!();
+  }
+  pub fn builder_selector(env: *mut JNIEnv, selector: ::sponge::text::selector::Selector) -> ::sponge::text::selector_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Lorg/spongepowered/api/text/selector/Selector;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallStaticObjectMethodA, selector.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::selector_text::Builder { env: env, object: ret }
+  }
+
+  pub fn builder_text_selector(env: *mut JNIEnv, text: ::sponge::text::Text, selector: ::sponge::text::selector::Selector) -> ::sponge::text::selector_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/selector/Selector;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallStaticObjectMethodA, text.object, selector.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::selector_text::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn builder_score(env: *mut JNIEnv, score: ::sponge::scoreboard::Score) -> ::sponge::text::score_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Lorg/spongepowered/api/scoreboard/Score;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallStaticObjectMethodA, score.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::score_text::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "scoreboard")]
+  pub fn builder_text_score(env: *mut JNIEnv, text: ::sponge::text::Text, score: ::sponge::scoreboard::Score) -> ::sponge::text::score_text::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "builder", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/scoreboard/Score;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallStaticObjectMethodA, text.object, score.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#builder was null") }
+    ::sponge::text::score_text::Builder { env: env, object: ret }
+  }
+
+  pub fn join_text_array(env: *mut JNIEnv, texts: Vec<::sponge::text::Text>) -> ::sponge::text::Text {
+    unimplemented!();
+  }
+  pub fn join_iterable(env: *mut JNIEnv, iterable: jobject) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "join", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#join was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  pub fn join_iterator(env: *mut JNIEnv, iterator: jobject) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "join", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, iterator);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#join was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  pub fn join_with_text_text_array(env: *mut JNIEnv, text: ::sponge::text::Text, texts: Vec<::sponge::text::Text>) -> ::sponge::text::Text {
+    unimplemented!();
+  }
+  pub fn join_with_text_iterable(env: *mut JNIEnv, text: ::sponge::text::Text, iterable: jobject) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "joinWith", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Iterable;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, text.object, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#joinWith was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  pub fn join_with_text_iterator(env: *mut JNIEnv, text: ::sponge::text::Text, iterator: jobject) -> ::sponge::text::Text {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/Text", "joinWith", "(Lorg/spongepowered/api/text/Text;Ljava/util/Iterator;)Lorg/spongepowered/api/text/Text;", CallStaticObjectMethodA, text.object, iterator);
+    if ret.is_null() { panic!("org/spongepowered/api/text/Text#joinWith was null") }
+    ::sponge::text::Text { env: env, object: ret }
+  }
+
+  pub fn compare_to_object(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/Text", self.object, "compareTo", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextElement {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextElement {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextElement {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextElement`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextElement", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/TextElement", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextIterator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextIterator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextIterator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextIterator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextIterator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn has_next(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/TextIterator", self.object, "hasNext", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn next(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextIterator", self.object, "next", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextIterator#next was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextRepresentable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextRepresentable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextRepresentable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextRepresentable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextRepresentable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn to_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextRepresentable", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextRepresentable#toText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn apply_to(&self, builder: ::sponge::text::text::Builder) {
+    bound_method!(self.env, "org/spongepowered/api/text/TextRepresentable", self.object, "applyTo", "(Lorg/spongepowered/api/text/Text$Builder;)V", CallVoidMethodA, builder.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextTemplate {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextTemplate {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextTemplate {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextTemplate`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextTemplate", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_elements(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "getElements", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#getElements was null") }
+    ret
+  }
+
+  pub fn get_arguments(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "getArguments", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#getArguments was null") }
+    ret
+  }
+
+  pub fn get_open_arg_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "getOpenArgString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#getOpenArgString was null") }
+    ret
+  }
+
+  pub fn get_close_arg_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "getCloseArgString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#getCloseArgString was null") }
+    ret
+  }
+
+  pub fn concat(&self, text_template: ::sponge::text::TextTemplate) -> ::sponge::text::TextTemplate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "concat", "(Lorg/spongepowered/api/text/TextTemplate;)Lorg/spongepowered/api/text/TextTemplate;", CallObjectMethodA, text_template.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#concat was null") }
+    ::sponge::text::TextTemplate { env: self.env, object: ret }
+  }
+
+  pub fn apply(&self) -> ::sponge::text::text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "apply", "()Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#apply was null") }
+    ::sponge::text::text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn apply_map(&self, map: jobject) -> ::sponge::text::text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "apply", "(Ljava/util/Map;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#apply was null") }
+    ::sponge::text::text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn of_object_object_object_array(env: *mut JNIEnv, object: jobject, object_2: jobject, objects: Vec<jobject>) -> ::sponge::text::TextTemplate {
+    unimplemented!();
+  }
+  pub fn of_object_array(env: *mut JNIEnv, objects: Vec<jobject>) -> ::sponge::text::TextTemplate {
+    unimplemented!();
+  }
+  pub fn of(env: *mut JNIEnv) -> ::sponge::text::TextTemplate {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/TextTemplate", "of", "()Lorg/spongepowered/api/text/TextTemplate;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#of was null") }
+    ::sponge::text::TextTemplate { env: env, object: ret }
+  }
+
+  pub fn arg(env: *mut JNIEnv, string: jobject) -> ::sponge::text::text_template::arg::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/TextTemplate", "arg", "(Ljava/lang/String;)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#arg was null") }
+    ::sponge::text::text_template::arg::Builder { env: env, object: ret }
+  }
+
+  pub fn to_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#toText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn iterator(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "iterator", "()Ljava/util/Iterator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#iterator was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate#toString was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/TextTemplate", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextTemplateArgumentException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextTemplateArgumentException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextTemplateArgumentException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextTemplateArgumentException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextTemplateArgumentException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::text::TextTemplateArgumentException {
+    let ret = new_object!(env, "org/spongepowered/api/text/TextTemplateArgumentException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplateArgumentException#<init> was null") }
+    ::sponge::text::TextTemplateArgumentException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TranslatableText {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TranslatableText {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TranslatableText {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TranslatableText`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TranslatableText", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_translation(&self) -> ::sponge::text::translation::Translation {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText", self.object, "getTranslation", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText#getTranslation was null") }
+    ::sponge::text::translation::Translation { env: self.env, object: ret }
+  }
+
+  pub fn get_arguments(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText", self.object, "getArguments", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText#getArguments was null") }
+    ret
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::translatable_text::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText", self.object, "toBuilder", "()Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText#toBuilder was null") }
+    ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/TranslatableText", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/TranslatableText", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
+
+pub mod book_view {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/BookView$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv) -> ::sponge::text::book_view::Builder {
+      let ret = new_object!(env, "org/spongepowered/api/text/BookView$Builder", "()V");
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#<init> was null") }
+      ::sponge::text::book_view::Builder { env: env, object: ret }
+    }
+
+    pub fn title(&self, text: ::sponge::text::Text) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "title", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#title was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn author(&self, text: ::sponge::text::Text) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "author", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#author was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_page(&self, text: ::sponge::text::Text) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "addPage", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#addPage was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_pages_collection(&self, collection: jobject) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "addPages", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#addPages was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_pages_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::book_view::Builder {
+      unimplemented!();
+    }
+    pub fn insert_page(&self, value: i32, text: ::sponge::text::Text) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "insertPage", "(ILorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, value, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#insertPage was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_pages_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "insertPages", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#insertPages was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_pages_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::book_view::Builder {
+      unimplemented!();
+    }
+    pub fn remove_page_text(&self, text: ::sponge::text::Text) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "removePage", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#removePage was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_page_i32(&self, value: i32) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "removePage", "(I)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#removePage was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_pages_collection(&self, collection: jobject) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "removePages", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#removePages was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_pages_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::book_view::Builder {
+      unimplemented!();
+    }
+    pub fn clear_pages(&self) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "clearPages", "()Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#clearPages was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::BookView {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "build", "()Lorg/spongepowered/api/text/BookView;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#build was null") }
+      ::sponge::text::BookView { env: self.env, object: ret }
+    }
+
+    pub fn from_book_view(&self, book_view: ::sponge::text::BookView) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "from", "(Lorg/spongepowered/api/text/BookView;)Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethodA, book_view.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#from was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::text::book_view::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "reset", "()Lorg/spongepowered/api/text/BookView$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#reset was null") }
+      ::sponge::text::book_view::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/BookView$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/BookView$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod literal_text {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/LiteralText$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_content(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "getContent", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#getContent was null") }
+      ret
+    }
+
+    pub fn content(&self, string: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "content", "(Ljava/lang/String;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#content was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::LiteralText {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "build", "()Lorg/spongepowered/api/text/LiteralText;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#build was null") }
+      ::sponge::text::LiteralText { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, text_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#format was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#color was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn style(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::literal_text::Builder {
+      unimplemented!();
+    }
+    pub fn on_click(&self, click_action: ::sponge::text::action::ClickAction) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "onClick", "(Lorg/spongepowered/api/text/action/ClickAction;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#onClick was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_hover(&self, hover_action: ::sponge::text::action::HoverAction) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "onHover", "(Lorg/spongepowered/api/text/action/HoverAction;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, hover_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#onHover was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_shift_click(&self, shift_click_action: ::sponge::text::action::ShiftClickAction) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "onShiftClick", "(Lorg/spongepowered/api/text/action/ShiftClickAction;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, shift_click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#onShiftClick was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::literal_text::Builder {
+      unimplemented!();
+    }
+    pub fn append_collection(&self, collection: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "append", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#append was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterable(&self, iterable: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "append", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#append was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterator(&self, iterator: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "append", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#append was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::literal_text::Builder {
+      unimplemented!();
+    }
+    pub fn insert_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "insert", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#insert was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "insert", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, value, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#insert was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "insert", "(ILjava/util/Iterator;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, value, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#insert was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::literal_text::Builder {
+      unimplemented!();
+    }
+    pub fn remove_collection(&self, collection: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "remove", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#remove was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#remove was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterator(&self, iterator: jobject) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "remove", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#remove was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_all(&self) -> ::sponge::text::literal_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/LiteralText$Builder", self.object, "removeAll", "()Lorg/spongepowered/api/text/LiteralText$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/LiteralText$Builder#removeAll was null") }
+      ::sponge::text::literal_text::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod score_text {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/ScoreText$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "scoreboard")]
+    pub fn get_score(&self) -> ::sponge::scoreboard::Score {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "getScore", "()Lorg/spongepowered/api/scoreboard/Score;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#getScore was null") }
+      ::sponge::scoreboard::Score { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "scoreboard")]
+    pub fn score(&self, score: ::sponge::scoreboard::Score) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "score", "(Lorg/spongepowered/api/scoreboard/Score;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, score.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#score was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_override(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "getOverride", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#getOverride was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn override_(&self, string: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "override", "(Ljava/lang/String;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, string);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#override was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::ScoreText {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "build", "()Lorg/spongepowered/api/text/ScoreText;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#build was null") }
+      ::sponge::text::ScoreText { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, text_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#format was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#color was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn style(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::score_text::Builder {
+      unimplemented!();
+    }
+    pub fn on_click(&self, click_action: ::sponge::text::action::ClickAction) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "onClick", "(Lorg/spongepowered/api/text/action/ClickAction;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#onClick was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_hover(&self, hover_action: ::sponge::text::action::HoverAction) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "onHover", "(Lorg/spongepowered/api/text/action/HoverAction;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, hover_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#onHover was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_shift_click(&self, shift_click_action: ::sponge::text::action::ShiftClickAction) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "onShiftClick", "(Lorg/spongepowered/api/text/action/ShiftClickAction;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, shift_click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#onShiftClick was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::score_text::Builder {
+      unimplemented!();
+    }
+    pub fn append_collection(&self, collection: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "append", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#append was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterable(&self, iterable: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "append", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#append was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterator(&self, iterator: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "append", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#append was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::score_text::Builder {
+      unimplemented!();
+    }
+    pub fn insert_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "insert", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#insert was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "insert", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, value, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#insert was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "insert", "(ILjava/util/Iterator;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, value, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#insert was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::score_text::Builder {
+      unimplemented!();
+    }
+    pub fn remove_collection(&self, collection: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "remove", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#remove was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#remove was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterator(&self, iterator: jobject) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "remove", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#remove was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_all(&self) -> ::sponge::text::score_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/ScoreText$Builder", self.object, "removeAll", "()Lorg/spongepowered/api/text/ScoreText$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/ScoreText$Builder#removeAll was null") }
+      ::sponge::text::score_text::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod selector_text {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/SelectorText$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_selector(&self) -> ::sponge::text::selector::Selector {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "getSelector", "()Lorg/spongepowered/api/text/selector/Selector;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#getSelector was null") }
+      ::sponge::text::selector::Selector { env: self.env, object: ret }
+    }
+
+    pub fn selector(&self, selector: ::sponge::text::selector::Selector) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "selector", "(Lorg/spongepowered/api/text/selector/Selector;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, selector.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#selector was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::SelectorText {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "build", "()Lorg/spongepowered/api/text/SelectorText;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#build was null") }
+      ::sponge::text::SelectorText { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, text_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#format was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#color was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn style(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::selector_text::Builder {
+      unimplemented!();
+    }
+    pub fn on_click(&self, click_action: ::sponge::text::action::ClickAction) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "onClick", "(Lorg/spongepowered/api/text/action/ClickAction;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#onClick was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_hover(&self, hover_action: ::sponge::text::action::HoverAction) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "onHover", "(Lorg/spongepowered/api/text/action/HoverAction;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, hover_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#onHover was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_shift_click(&self, shift_click_action: ::sponge::text::action::ShiftClickAction) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "onShiftClick", "(Lorg/spongepowered/api/text/action/ShiftClickAction;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, shift_click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#onShiftClick was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::selector_text::Builder {
+      unimplemented!();
+    }
+    pub fn append_collection(&self, collection: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "append", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#append was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterable(&self, iterable: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "append", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#append was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterator(&self, iterator: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "append", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#append was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::selector_text::Builder {
+      unimplemented!();
+    }
+    pub fn insert_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "insert", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#insert was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "insert", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, value, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#insert was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "insert", "(ILjava/util/Iterator;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, value, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#insert was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::selector_text::Builder {
+      unimplemented!();
+    }
+    pub fn remove_collection(&self, collection: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "remove", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#remove was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#remove was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterator(&self, iterator: jobject) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "remove", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#remove was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_all(&self) -> ::sponge::text::selector_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/SelectorText$Builder", self.object, "removeAll", "()Lorg/spongepowered/api/text/SelectorText$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/SelectorText$Builder#removeAll was null") }
+      ::sponge::text::selector_text::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod text {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/Text$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_format(&self) -> ::sponge::text::format::TextFormat {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getFormat", "()Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getFormat was null") }
+      ::sponge::text::format::TextFormat { env: self.env, object: ret }
+    }
+
+    pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, text_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#format was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_color(&self) -> ::sponge::text::format::TextColor {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getColor", "()Lorg/spongepowered/api/text/format/TextColor;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getColor was null") }
+      ::sponge::text::format::TextColor { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#color was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_style(&self) -> ::sponge::text::format::TextStyle {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getStyle", "()Lorg/spongepowered/api/text/format/TextStyle;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getStyle was null") }
+      ::sponge::text::format::TextStyle { env: self.env, object: ret }
+    }
+
+    pub fn style(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::text::Builder {
+      unimplemented!();
+    }
+    pub fn get_click_action(&self) -> Option<::sponge::text::action::ClickAction> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getClickAction", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getClickAction was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::action::ClickAction { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn on_click(&self, click_action: ::sponge::text::action::ClickAction) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "onClick", "(Lorg/spongepowered/api/text/action/ClickAction;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#onClick was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_hover_action(&self) -> Option<::sponge::text::action::HoverAction> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getHoverAction", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getHoverAction was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::action::HoverAction { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn on_hover(&self, hover_action: ::sponge::text::action::HoverAction) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "onHover", "(Lorg/spongepowered/api/text/action/HoverAction;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, hover_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#onHover was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_shift_click_action(&self) -> Option<::sponge::text::action::ShiftClickAction> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getShiftClickAction", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getShiftClickAction was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::action::ShiftClickAction { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn on_shift_click(&self, shift_click_action: ::sponge::text::action::ShiftClickAction) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "onShiftClick", "(Lorg/spongepowered/api/text/action/ShiftClickAction;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, shift_click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#onShiftClick was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_children(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "getChildren", "()Ljava/util/List;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#getChildren was null") }
+      ret
+    }
+
+    pub fn append_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::text::Builder {
+      unimplemented!();
+    }
+    pub fn append_collection(&self, collection: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "append", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#append was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterable(&self, iterable: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "append", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#append was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterator(&self, iterator: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "append", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#append was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::text::Builder {
+      unimplemented!();
+    }
+    pub fn insert_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "insert", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#insert was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "insert", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, value, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#insert was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "insert", "(ILjava/util/Iterator;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, value, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#insert was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::text::Builder {
+      unimplemented!();
+    }
+    pub fn remove_collection(&self, collection: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "remove", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#remove was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#remove was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterator(&self, iterator: jobject) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "remove", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#remove was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_all(&self) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "removeAll", "()Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#removeAll was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn trim(&self) -> ::sponge::text::text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "trim", "()Lorg/spongepowered/api/text/Text$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#trim was null") }
+      ::sponge::text::text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "build", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#build was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#toString was null") }
+      ret
+    }
+
+    pub fn to_text(&self) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/Text$Builder", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/Text$Builder#toText was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod text_template {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Arg {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Arg {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Arg {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Arg`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextTemplate$Arg", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#getName was null") }
+      ret
+    }
+
+    pub fn is_optional(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "isOptional", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn get_default_value(&self) -> Option<::sponge::text::Text> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "getDefaultValue", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#getDefaultValue was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn get_format(&self) -> ::sponge::text::format::TextFormat {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "getFormat", "()Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#getFormat was null") }
+      ::sponge::text::format::TextFormat { env: self.env, object: ret }
+    }
+
+    pub fn get_open_arg_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "getOpenArgString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#getOpenArgString was null") }
+      ret
+    }
+
+    pub fn get_close_arg_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "getCloseArgString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#getCloseArgString was null") }
+      ret
+    }
+
+    pub fn to_text(&self) -> ::sponge::text::Text {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#toText was null") }
+      ::sponge::text::Text { env: self.env, object: ret }
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg#toString was null") }
+      ret
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+  }
+
+  pub mod arg {
+    use jni_sys::{JNIEnv, jvalue, jobject};
+
+    #[derive(Debug)]
+    pub struct Builder {
+      pub env: *mut JNIEnv,
+      pub object: jobject
+    }
+
+    impl Builder {
+      pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+        Builder {
+          env: env,
+          object: object
+        }
+      }
+
+      /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+      /// the class of the object overrides it.
+      pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+        let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TextTemplate$Arg$Builder", name, descriptor, args);
+        ::convert::FromJValue::from_jvalue(ret)
+      }
+
+      pub fn build(&self) -> ::sponge::text::text_template::Arg {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "build", "()Lorg/spongepowered/api/text/TextTemplate$Arg;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#build was null") }
+        ::sponge::text::text_template::Arg { env: self.env, object: ret }
+      }
+
+      pub fn optional_bool(&self, value: bool) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "optional", "(Z)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethodA, value);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#optional was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn optional(&self) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "optional", "()Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#optional was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn default_value(&self, text: ::sponge::text::Text) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "defaultValue", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethodA, text.object);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#defaultValue was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethodA, text_format.object);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#format was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethodA, text_color.object);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#color was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn style(&self, text_style: ::sponge::text::format::TextStyle) -> ::sponge::text::text_template::arg::Builder {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "style", "(Lorg/spongepowered/api/text/format/TextStyle;)Lorg/spongepowered/api/text/TextTemplate$Arg$Builder;", CallObjectMethodA, text_style.object);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#style was null") }
+        ::sponge::text::text_template::arg::Builder { env: self.env, object: ret }
+      }
+
+      pub fn to_string(&self) -> jobject {
+        let ret = bound_method!(self.env, "org/spongepowered/api/text/TextTemplate$Arg$Builder", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+        if ret.is_null() { panic!("org/spongepowered/api/text/TextTemplate$Arg$Builder#toString was null") }
+        ret
+      }
+
+    }
+  }
+}
+
+pub mod translatable_text {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/TranslatableText$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_translation(&self) -> ::sponge::text::translation::Translation {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "getTranslation", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#getTranslation was null") }
+      ::sponge::text::translation::Translation { env: self.env, object: ret }
+    }
+
+    pub fn get_arguments(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "getArguments", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#getArguments was null") }
+      ret
+    }
+
+    pub fn translation_translation_object_array(&self, translation: ::sponge::text::translation::Translation, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn translation_translatable_object_array(&self, translatable: ::sponge::text::translation::Translatable, objects: Vec<jobject>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn build(&self) -> ::sponge::text::TranslatableText {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "build", "()Lorg/spongepowered/api/text/TranslatableText;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#build was null") }
+      ::sponge::text::TranslatableText { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn format(&self, text_format: ::sponge::text::format::TextFormat) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "format", "(Lorg/spongepowered/api/text/format/TextFormat;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, text_format.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#format was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn color(&self, text_color: ::sponge::text::format::TextColor) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "color", "(Lorg/spongepowered/api/text/format/TextColor;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, text_color.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#color was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn style(&self, text_styles: Vec<::sponge::text::format::TextStyle>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn on_click(&self, click_action: ::sponge::text::action::ClickAction) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "onClick", "(Lorg/spongepowered/api/text/action/ClickAction;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#onClick was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_hover(&self, hover_action: ::sponge::text::action::HoverAction) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "onHover", "(Lorg/spongepowered/api/text/action/HoverAction;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, hover_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#onHover was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn on_shift_click(&self, shift_click_action: ::sponge::text::action::ShiftClickAction) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "onShiftClick", "(Lorg/spongepowered/api/text/action/ShiftClickAction;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, shift_click_action.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#onShiftClick was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn append_collection(&self, collection: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "append", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#append was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterable(&self, iterable: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "append", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#append was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn append_iterator(&self, iterator: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "append", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#append was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_text_array(&self, value: i32, texts: Vec<::sponge::text::Text>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn insert_i32_collection(&self, value: i32, collection: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "insert", "(ILjava/util/Collection;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, value, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#insert was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "insert", "(ILjava/lang/Iterable;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, value, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#insert was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "insert", "(ILjava/util/Iterator;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, value, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#insert was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_text_array(&self, texts: Vec<::sponge::text::Text>) -> ::sponge::text::translatable_text::Builder {
+      unimplemented!();
+    }
+    pub fn remove_collection(&self, collection: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "remove", "(Ljava/util/Collection;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, collection);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#remove was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#remove was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_iterator(&self, iterator: jobject) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "remove", "(Ljava/util/Iterator;)Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethodA, iterator);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#remove was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_all(&self) -> ::sponge::text::translatable_text::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/TranslatableText$Builder", self.object, "removeAll", "()Lorg/spongepowered/api/text/TranslatableText$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/TranslatableText$Builder#removeAll was null") }
+      ::sponge::text::translatable_text::Builder { env: self.env, object: ret }
+    }
+
+  }
+}
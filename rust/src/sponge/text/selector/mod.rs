@@ -0,0 +1,686 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Argument {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Argument {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Argument {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Argument`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/Argument", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_argument_type_object(env: *mut JNIEnv, argument_type: ::sponge::text::selector::ArgumentType, object: jobject) -> ::sponge::text::selector::Argument {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Argument", "create", "(Lorg/spongepowered/api/text/selector/ArgumentType;Ljava/lang/Object;)Lorg/spongepowered/api/text/selector/Argument;", CallStaticObjectMethodA, argument_type.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#create was null") }
+    ::sponge::text::selector::Argument { env: env, object: ret }
+  }
+
+  pub fn create_invertible_object_bool(env: *mut JNIEnv, invertible: ::sponge::text::selector::argument_type::Invertible, object: jobject, value: bool) -> ::sponge::text::selector::argument::Invertible {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Argument", "create", "(Lorg/spongepowered/api/text/selector/ArgumentType$Invertible;Ljava/lang/Object;Z)Lorg/spongepowered/api/text/selector/Argument$Invertible;", CallStaticObjectMethodA, invertible.object, object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#create was null") }
+    ::sponge::text::selector::argument::Invertible { env: env, object: ret }
+  }
+
+  pub fn create_set(env: *mut JNIEnv, argument_holder: ::sponge::text::selector::ArgumentHolder, object: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Argument", "createSet", "(Lorg/spongepowered/api/text/selector/ArgumentHolder;Ljava/lang/Object;)Ljava/util/Set;", CallStaticObjectMethodA, argument_holder.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#createSet was null") }
+    ret
+  }
+
+  pub fn parse(env: *mut JNIEnv, string: jobject) -> ::sponge::text::selector::Argument {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Argument", "parse", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/Argument;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#parse was null") }
+    ::sponge::text::selector::Argument { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::text::selector::ArgumentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Argument", self.object, "getType", "()Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#getType was null") }
+    ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+  }
+
+  pub fn get_value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Argument", self.object, "getValue", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#getValue was null") }
+    ret
+  }
+
+  pub fn to_plain(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Argument", self.object, "toPlain", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument#toPlain was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArgumentHolder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArgumentHolder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArgumentHolder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArgumentHolder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentHolder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_count(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder", self.object, "getCount", "()I", CallIntMethod)
+  }
+
+  pub fn get_types(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder", self.object, "getTypes", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder#getTypes was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArgumentType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArgumentType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArgumentType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArgumentType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_key(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentType", self.object, "getKey", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentType#getKey was null") }
+    ret
+  }
+
+  pub fn get_count(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentType", self.object, "getCount", "()I", CallIntMethod)
+  }
+
+  pub fn get_types(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentType", self.object, "getTypes", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentType#getTypes was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ArgumentTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ArgumentTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ArgumentTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ArgumentTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn score(env: *mut JNIEnv, string: jobject) -> ::sponge::text::selector::argument_holder::Limit {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/ArgumentTypes", "score", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/ArgumentHolder$Limit;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentTypes#score was null") }
+    ::sponge::text::selector::argument_holder::Limit { env: env, object: ret }
+  }
+
+  pub fn value_of(env: *mut JNIEnv, string: jobject) -> Option<::sponge::text::selector::ArgumentType> {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/ArgumentTypes", "valueOf", "(Ljava/lang/String;)Ljava/util/Optional;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentTypes#valueOf was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::selector::ArgumentType { env: env, object: unwrapped }) }
+  }
+
+  pub fn values(env: *mut JNIEnv) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/ArgumentTypes", "values", "()Ljava/util/Collection;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentTypes#values was null") }
+    ret
+  }
+
+  pub fn create_string(env: *mut JNIEnv, string: jobject) -> ::sponge::text::selector::ArgumentType {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/ArgumentTypes", "create", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/ArgumentType;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentTypes#create was null") }
+    ::sponge::text::selector::ArgumentType { env: env, object: ret }
+  }
+
+  pub fn create_string_class(env: *mut JNIEnv, string: jobject, class: jobject) -> ::sponge::text::selector::ArgumentType {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/ArgumentTypes", "create", "(Ljava/lang/String;Ljava/lang/Class;)Lorg/spongepowered/api/text/selector/ArgumentType;", CallStaticObjectMethodA, string, class);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentTypes#create was null") }
+    ::sponge::text::selector::ArgumentType { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Selector {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Selector {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Selector {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Selector`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/Selector", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::text::selector::selector::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Selector", "builder", "()Lorg/spongepowered/api/text/selector/Selector$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#builder was null") }
+    ::sponge::text::selector::selector::Builder { env: env, object: ret }
+  }
+
+  pub fn parse(env: *mut JNIEnv, string: jobject) -> ::sponge::text::selector::Selector {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Selector", "parse", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/Selector;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#parse was null") }
+    ::sponge::text::selector::Selector { env: env, object: ret }
+  }
+
+  pub fn complete(env: *mut JNIEnv, string: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/selector/Selector", "complete", "(Ljava/lang/String;)Ljava/util/List;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#complete was null") }
+    ret
+  }
+
+  pub fn get_type(&self) -> ::sponge::text::selector::SelectorType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "getType", "()Lorg/spongepowered/api/text/selector/SelectorType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#getType was null") }
+    ::sponge::text::selector::SelectorType { env: self.env, object: ret }
+  }
+
+  pub fn get(&self, argument_type: ::sponge::text::selector::ArgumentType) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "get", "(Lorg/spongepowered/api/text/selector/ArgumentType;)Ljava/util/Optional;", CallObjectMethodA, argument_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_argument_argument_type(&self, argument_type: ::sponge::text::selector::ArgumentType) -> Option<::sponge::text::selector::Argument> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "getArgument", "(Lorg/spongepowered/api/text/selector/ArgumentType;)Ljava/util/Optional;", CallObjectMethodA, argument_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#getArgument was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::selector::Argument { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_argument_invertible(&self, invertible: ::sponge::text::selector::argument_type::Invertible) -> Option<::sponge::text::selector::argument::Invertible> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "getArgument", "(Lorg/spongepowered/api/text/selector/ArgumentType$Invertible;)Ljava/util/Optional;", CallObjectMethodA, invertible.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#getArgument was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::selector::argument::Invertible { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_arguments(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "getArguments", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#getArguments was null") }
+    ret
+  }
+
+  pub fn has(&self, argument_type: ::sponge::text::selector::ArgumentType) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "has", "(Lorg/spongepowered/api/text/selector/ArgumentType;)Z", CallBooleanMethodA, argument_type.object) != 0
+  }
+
+  pub fn is_inverted(&self, invertible: ::sponge::text::selector::argument_type::Invertible) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "isInverted", "(Lorg/spongepowered/api/text/selector/ArgumentType$Invertible;)Z", CallBooleanMethodA, invertible.object) != 0
+  }
+
+  #[cfg(feature = "command")]
+  pub fn resolve_command_source(&self, command_source: ::sponge::command::CommandSource) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolve", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Set;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolve was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn resolve_extent_array(&self, extents: Vec<::sponge::world::extent::Extent>) -> jobject {
+    unimplemented!();
+  }
+  pub fn resolve_collection(&self, collection: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolve", "(Ljava/util/Collection;)Ljava/util/Set;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolve was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn resolve_location(&self, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolve", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Set;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolve was null") }
+    ret
+  }
+
+  #[cfg(feature = "command")]
+  pub fn resolve_force_command_source(&self, command_source: ::sponge::command::CommandSource) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolveForce", "(Lorg/spongepowered/api/command/CommandSource;)Ljava/util/Set;", CallObjectMethodA, command_source.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolveForce was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn resolve_force_extent_array(&self, extents: Vec<::sponge::world::extent::Extent>) -> jobject {
+    unimplemented!();
+  }
+  pub fn resolve_force_collection(&self, collection: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolveForce", "(Ljava/util/Collection;)Ljava/util/Set;", CallObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolveForce was null") }
+    ret
+  }
+
+  #[cfg(feature = "world")]
+  pub fn resolve_force_location(&self, location: ::sponge::world::Location) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "resolveForce", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Set;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#resolveForce was null") }
+    ret
+  }
+
+  pub fn to_plain(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "toPlain", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#toPlain was null") }
+    ret
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::selector::selector::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector", self.object, "toBuilder", "()Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector#toBuilder was null") }
+    ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SelectorFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SelectorFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SelectorFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SelectorFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/SelectorFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_builder(&self) -> ::sponge::text::selector::selector::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createBuilder", "()Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createBuilder was null") }
+    ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+  }
+
+  pub fn parse_raw_selector(&self, string: jobject) -> ::sponge::text::selector::Selector {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "parseRawSelector", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/Selector;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#parseRawSelector was null") }
+    ::sponge::text::selector::Selector { env: self.env, object: ret }
+  }
+
+  pub fn create_score_argument_type(&self, string: jobject) -> ::sponge::text::selector::argument_holder::Limit {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createScoreArgumentType", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/ArgumentHolder$Limit;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createScoreArgumentType was null") }
+    ::sponge::text::selector::argument_holder::Limit { env: self.env, object: ret }
+  }
+
+  pub fn get_argument_type(&self, string: jobject) -> Option<::sponge::text::selector::ArgumentType> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "getArgumentType", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#getArgumentType was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::selector::ArgumentType { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_argument_types(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "getArgumentTypes", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#getArgumentTypes was null") }
+    ret
+  }
+
+  pub fn create_argument_type_string(&self, string: jobject) -> ::sponge::text::selector::ArgumentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createArgumentType", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createArgumentType was null") }
+    ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+  }
+
+  pub fn create_argument_type_string_class(&self, string: jobject, class: jobject) -> ::sponge::text::selector::ArgumentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createArgumentType", "(Ljava/lang/String;Ljava/lang/Class;)Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethodA, string, class);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createArgumentType was null") }
+    ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+  }
+
+  pub fn create_argument_argument_type_object(&self, argument_type: ::sponge::text::selector::ArgumentType, object: jobject) -> ::sponge::text::selector::Argument {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createArgument", "(Lorg/spongepowered/api/text/selector/ArgumentType;Ljava/lang/Object;)Lorg/spongepowered/api/text/selector/Argument;", CallObjectMethodA, argument_type.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createArgument was null") }
+    ::sponge::text::selector::Argument { env: self.env, object: ret }
+  }
+
+  pub fn create_argument_invertible_object_bool(&self, invertible: ::sponge::text::selector::argument_type::Invertible, object: jobject, value: bool) -> ::sponge::text::selector::argument::Invertible {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createArgument", "(Lorg/spongepowered/api/text/selector/ArgumentType$Invertible;Ljava/lang/Object;Z)Lorg/spongepowered/api/text/selector/Argument$Invertible;", CallObjectMethodA, invertible.object, object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createArgument was null") }
+    ::sponge::text::selector::argument::Invertible { env: self.env, object: ret }
+  }
+
+  pub fn create_arguments(&self, argument_holder: ::sponge::text::selector::ArgumentHolder, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "createArguments", "(Lorg/spongepowered/api/text/selector/ArgumentHolder;Ljava/lang/Object;)Ljava/util/Set;", CallObjectMethodA, argument_holder.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#createArguments was null") }
+    ret
+  }
+
+  pub fn parse_argument(&self, string: jobject) -> ::sponge::text::selector::Argument {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "parseArgument", "(Ljava/lang/String;)Lorg/spongepowered/api/text/selector/Argument;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#parseArgument was null") }
+    ::sponge::text::selector::Argument { env: self.env, object: ret }
+  }
+
+  pub fn complete(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/SelectorFactory", self.object, "complete", "(Ljava/lang/String;)Ljava/util/List;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/selector/SelectorFactory#complete was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SelectorType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SelectorType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SelectorType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SelectorType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/SelectorType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SelectorTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SelectorTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SelectorTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SelectorTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/SelectorTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod argument {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Invertible {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Invertible {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Invertible {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Invertible`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/Argument$Invertible", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn is_inverted(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/selector/Argument$Invertible", self.object, "isInverted", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn invert(&self) -> ::sponge::text::selector::argument::Invertible {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Argument$Invertible", self.object, "invert", "()Lorg/spongepowered/api/text/selector/Argument$Invertible;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Argument$Invertible#invert was null") }
+      ::sponge::text::selector::argument::Invertible { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod argument_holder {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Limit {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Limit {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Limit {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Limit`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentHolder$Limit", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn minimum(&self) -> ::sponge::text::selector::ArgumentHolder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder$Limit", self.object, "minimum", "()Lorg/spongepowered/api/text/selector/ArgumentHolder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder$Limit#minimum was null") }
+      ::sponge::text::selector::ArgumentHolder { env: self.env, object: ret }
+    }
+
+    pub fn maximum(&self) -> ::sponge::text::selector::ArgumentHolder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder$Limit", self.object, "maximum", "()Lorg/spongepowered/api/text/selector/ArgumentHolder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder$Limit#maximum was null") }
+      ::sponge::text::selector::ArgumentHolder { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Vector3 {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Vector3 {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Vector3 {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Vector3`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentHolder$Vector3", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn x(&self) -> ::sponge::text::selector::ArgumentType {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder$Vector3", self.object, "x", "()Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder$Vector3#x was null") }
+      ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+    }
+
+    pub fn y(&self) -> ::sponge::text::selector::ArgumentType {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder$Vector3", self.object, "y", "()Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder$Vector3#y was null") }
+      ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+    }
+
+    pub fn z(&self) -> ::sponge::text::selector::ArgumentType {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/ArgumentHolder$Vector3", self.object, "z", "()Lorg/spongepowered/api/text/selector/ArgumentType;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/ArgumentHolder$Vector3#z was null") }
+      ::sponge::text::selector::ArgumentType { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod argument_type {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Invertible {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Invertible {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Invertible {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Invertible`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/ArgumentType$Invertible", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod selector {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/selector/Selector$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn type_(&self, selector_type: ::sponge::text::selector::SelectorType) -> ::sponge::text::selector::selector::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector$Builder", self.object, "type", "(Lorg/spongepowered/api/text/selector/SelectorType;)Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethodA, selector_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector$Builder#type was null") }
+      ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_argument_array(&self, arguments: Vec<::sponge::text::selector::Argument>) -> ::sponge::text::selector::selector::Builder {
+      unimplemented!();
+    }
+    pub fn add_iterable(&self, iterable: jobject) -> ::sponge::text::selector::selector::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector$Builder", self.object, "add", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector$Builder#add was null") }
+      ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+    }
+
+    pub fn add_argument_type_object(&self, argument_type: ::sponge::text::selector::ArgumentType, object: jobject) -> ::sponge::text::selector::selector::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector$Builder", self.object, "add", "(Lorg/spongepowered/api/text/selector/ArgumentType;Ljava/lang/Object;)Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethodA, argument_type.object, object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector$Builder#add was null") }
+      ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_argument_array(&self, arguments: Vec<::sponge::text::selector::Argument>) -> ::sponge::text::selector::selector::Builder {
+      unimplemented!();
+    }
+    pub fn remove_iterable(&self, iterable: jobject) -> ::sponge::text::selector::selector::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector$Builder", self.object, "remove", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/text/selector/Selector$Builder;", CallObjectMethodA, iterable);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector$Builder#remove was null") }
+      ::sponge::text::selector::selector::Builder { env: self.env, object: ret }
+    }
+
+    pub fn remove_argument_type_array(&self, argument_types: Vec<::sponge::text::selector::ArgumentType>) -> ::sponge::text::selector::selector::Builder {
+      unimplemented!();
+    }
+    pub fn build(&self) -> ::sponge::text::selector::Selector {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/selector/Selector$Builder", self.object, "build", "()Lorg/spongepowered/api/text/selector/Selector;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/selector/Selector$Builder#build was null") }
+      ::sponge::text::selector::Selector { env: self.env, object: ret }
+    }
+
+  }
+}
@@ -0,0 +1,399 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BookViewDataBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BookViewDataBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BookViewDataBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `BookViewDataBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/BookViewDataBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::serializer::BookViewDataBuilder {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/BookViewDataBuilder", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/BookViewDataBuilder#<init> was null") }
+    ::sponge::text::serializer::BookViewDataBuilder { env: env, object: ret }
+  }
+
+  pub fn deserialize(&self, type_token: jobject, configuration_node: jobject) -> ::sponge::text::BookView {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/BookViewDataBuilder", self.object, "deserialize", "(Lcom/google/common/reflect/TypeToken;Lninja/leaping/configurate/ConfigurationNode;)Lorg/spongepowered/api/text/BookView;", CallObjectMethodA, type_token, configuration_node);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/BookViewDataBuilder#deserialize was null") }
+    ::sponge::text::BookView { env: self.env, object: ret }
+  }
+
+  pub fn serialize_type_token_book_view_configuration_node(&self, type_token: jobject, book_view: ::sponge::text::BookView, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/BookViewDataBuilder", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Lorg/spongepowered/api/text/BookView;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, book_view.object, configuration_node);
+  }
+
+  pub fn serialize_type_token_object_configuration_node(&self, type_token: jobject, object: jobject, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/BookViewDataBuilder", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Ljava/lang/Object;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, object, configuration_node);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FormattingCodeTextSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FormattingCodeTextSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FormattingCodeTextSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FormattingCodeTextSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/FormattingCodeTextSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_character(&self) -> char {
+    ::convert::char_from_java(bound_method!(self.env, "org/spongepowered/api/text/serializer/FormattingCodeTextSerializer", self.object, "getCharacter", "()C", CallCharMethod))
+  }
+
+  pub fn strip_codes(&self, string: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/FormattingCodeTextSerializer", self.object, "stripCodes", "(Ljava/lang/String;)Ljava/lang/String;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/FormattingCodeTextSerializer#stripCodes was null") }
+    ret
+  }
+
+  pub fn replace_codes_string_char(&self, string: jobject, value: char) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/FormattingCodeTextSerializer", self.object, "replaceCodes", "(Ljava/lang/String;C)Ljava/lang/String;", CallObjectMethodA, string, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/FormattingCodeTextSerializer#replaceCodes was null") }
+    ret
+  }
+
+  pub fn replace_codes_string_formatting_code_text_serializer(&self, string: jobject, formatting_code_text_serializer: ::sponge::text::serializer::FormattingCodeTextSerializer) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/FormattingCodeTextSerializer", self.object, "replaceCodes", "(Ljava/lang/String;Lorg/spongepowered/api/text/serializer/FormattingCodeTextSerializer;)Ljava/lang/String;", CallObjectMethodA, string, formatting_code_text_serializer.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/FormattingCodeTextSerializer#replaceCodes was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SafeTextSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SafeTextSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SafeTextSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SafeTextSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/SafeTextSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn deserialize(&self, string: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/SafeTextSerializer", self.object, "deserialize", "(Ljava/lang/String;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/SafeTextSerializer#deserialize was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn deserialize_unchecked(&self, string: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/SafeTextSerializer", self.object, "deserializeUnchecked", "(Ljava/lang/String;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/SafeTextSerializer#deserializeUnchecked was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextConfigSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextConfigSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextConfigSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextConfigSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextConfigSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::serializer::TextConfigSerializer {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextConfigSerializer", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextConfigSerializer#<init> was null") }
+    ::sponge::text::serializer::TextConfigSerializer { env: env, object: ret }
+  }
+
+  pub fn deserialize(&self, type_token: jobject, configuration_node: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextConfigSerializer", self.object, "deserialize", "(Lcom/google/common/reflect/TypeToken;Lninja/leaping/configurate/ConfigurationNode;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, type_token, configuration_node);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextConfigSerializer#deserialize was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn serialize_type_token_text_configuration_node(&self, type_token: jobject, text: ::sponge::text::Text, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Lorg/spongepowered/api/text/Text;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, text.object, configuration_node);
+  }
+
+  pub fn serialize_type_token_object_configuration_node(&self, type_token: jobject, object: jobject, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Ljava/lang/Object;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, object, configuration_node);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextFormatConfigSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextFormatConfigSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextFormatConfigSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextFormatConfigSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextFormatConfigSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::serializer::TextFormatConfigSerializer {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextFormatConfigSerializer", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextFormatConfigSerializer#<init> was null") }
+    ::sponge::text::serializer::TextFormatConfigSerializer { env: env, object: ret }
+  }
+
+  pub fn deserialize(&self, type_token: jobject, configuration_node: jobject) -> ::sponge::text::format::TextFormat {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextFormatConfigSerializer", self.object, "deserialize", "(Lcom/google/common/reflect/TypeToken;Lninja/leaping/configurate/ConfigurationNode;)Lorg/spongepowered/api/text/format/TextFormat;", CallObjectMethodA, type_token, configuration_node);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextFormatConfigSerializer#deserialize was null") }
+    ::sponge::text::format::TextFormat { env: self.env, object: ret }
+  }
+
+  pub fn serialize_type_token_text_format_configuration_node(&self, type_token: jobject, text_format: ::sponge::text::format::TextFormat, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextFormatConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Lorg/spongepowered/api/text/format/TextFormat;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, text_format.object, configuration_node);
+  }
+
+  pub fn serialize_type_token_object_configuration_node(&self, type_token: jobject, object: jobject, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextFormatConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Ljava/lang/Object;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, object, configuration_node);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextParseException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextParseException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextParseException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextParseException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextParseException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::serializer::TextParseException {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextParseException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextParseException#<init> was null") }
+    ::sponge::text::serializer::TextParseException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::text::serializer::TextParseException {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextParseException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextParseException#<init> was null") }
+    ::sponge::text::serializer::TextParseException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::text::serializer::TextParseException {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextParseException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextParseException#<init> was null") }
+    ::sponge::text::serializer::TextParseException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::text::serializer::TextParseException {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextParseException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextParseException#<init> was null") }
+    ::sponge::text::serializer::TextParseException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn serialize(&self, text: ::sponge::text::Text) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextSerializer", self.object, "serialize", "(Lorg/spongepowered/api/text/Text;)Ljava/lang/String;", CallObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextSerializer#serialize was null") }
+    ret
+  }
+
+  pub fn deserialize(&self, string: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextSerializer", self.object, "deserialize", "(Ljava/lang/String;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextSerializer#deserialize was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn deserialize_unchecked(&self, string: jobject) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextSerializer", self.object, "deserializeUnchecked", "(Ljava/lang/String;)Lorg/spongepowered/api/text/Text;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextSerializer#deserializeUnchecked was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextSerializerFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextSerializerFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextSerializerFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextSerializerFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextSerializerFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_formatting_code_text_serializer(&self, value: char) -> ::sponge::text::serializer::FormattingCodeTextSerializer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextSerializerFactory", self.object, "getFormattingCodeTextSerializer", "(C)Lorg/spongepowered/api/text/serializer/FormattingCodeTextSerializer;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextSerializerFactory#getFormattingCodeTextSerializer was null") }
+    ::sponge::text::serializer::FormattingCodeTextSerializer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextSerializers {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextSerializers {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextSerializers {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextSerializers`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextSerializers", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn formatting_code(env: *mut JNIEnv, value: char) -> ::sponge::text::serializer::FormattingCodeTextSerializer {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/serializer/TextSerializers", "formattingCode", "(C)Lorg/spongepowered/api/text/serializer/FormattingCodeTextSerializer;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextSerializers#formattingCode was null") }
+    ::sponge::text::serializer::FormattingCodeTextSerializer { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextTemplateConfigSerializer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextTemplateConfigSerializer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextTemplateConfigSerializer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextTemplateConfigSerializer`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/serializer/TextTemplateConfigSerializer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::serializer::TextTemplateConfigSerializer {
+    let ret = new_object!(env, "org/spongepowered/api/text/serializer/TextTemplateConfigSerializer", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextTemplateConfigSerializer#<init> was null") }
+    ::sponge::text::serializer::TextTemplateConfigSerializer { env: env, object: ret }
+  }
+
+  pub fn deserialize(&self, type_token: jobject, configuration_node: jobject) -> ::sponge::text::TextTemplate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/serializer/TextTemplateConfigSerializer", self.object, "deserialize", "(Lcom/google/common/reflect/TypeToken;Lninja/leaping/configurate/ConfigurationNode;)Lorg/spongepowered/api/text/TextTemplate;", CallObjectMethodA, type_token, configuration_node);
+    if ret.is_null() { panic!("org/spongepowered/api/text/serializer/TextTemplateConfigSerializer#deserialize was null") }
+    ::sponge::text::TextTemplate { env: self.env, object: ret }
+  }
+
+  pub fn serialize_type_token_text_template_configuration_node(&self, type_token: jobject, text_template: ::sponge::text::TextTemplate, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextTemplateConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Lorg/spongepowered/api/text/TextTemplate;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, text_template.object, configuration_node);
+  }
+
+  pub fn serialize_type_token_object_configuration_node(&self, type_token: jobject, object: jobject, configuration_node: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/serializer/TextTemplateConfigSerializer", self.object, "serialize", "(Lcom/google/common/reflect/TypeToken;Ljava/lang/Object;Lninja/leaping/configurate/ConfigurationNode;)V", CallVoidMethodA, type_token, object, configuration_node);
+  }
+
+}
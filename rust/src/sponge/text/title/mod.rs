@@ -0,0 +1,273 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Title {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Title {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Title {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Title`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/title/Title", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_title(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "getTitle", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#getTitle was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_subtitle(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "getSubtitle", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#getSubtitle was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_fade_in(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "getFadeIn", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#getFadeIn was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_stay(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "getStay", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#getStay was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_fade_out(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "getFadeOut", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#getFadeOut was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn is_clear(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "isClear", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_reset(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "isReset", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn to_builder(&self) -> ::sponge::text::title::title::Builder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "toBuilder", "()Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#toBuilder was null") }
+    ::sponge::text::title::title::Builder { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#toString was null") }
+    ret
+  }
+
+  pub fn of(env: *mut JNIEnv) -> ::sponge::text::title::Title {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "of", "()Lorg/spongepowered/api/text/title/Title;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#of was null") }
+    ::sponge::text::title::Title { env: env, object: ret }
+  }
+
+  pub fn of_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::text::title::Title {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "of", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/title/Title;", CallStaticObjectMethodA, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#of was null") }
+    ::sponge::text::title::Title { env: env, object: ret }
+  }
+
+  pub fn of_text_text(env: *mut JNIEnv, text: ::sponge::text::Text, text_2: ::sponge::text::Text) -> ::sponge::text::title::Title {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "of", "(Lorg/spongepowered/api/text/Text;Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/title/Title;", CallStaticObjectMethodA, text.object, text_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#of was null") }
+    ::sponge::text::title::Title { env: env, object: ret }
+  }
+
+  pub fn clear(env: *mut JNIEnv) -> ::sponge::text::title::Title {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "clear", "()Lorg/spongepowered/api/text/title/Title;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#clear was null") }
+    ::sponge::text::title::Title { env: env, object: ret }
+  }
+
+  pub fn reset(env: *mut JNIEnv) -> ::sponge::text::title::Title {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "reset", "()Lorg/spongepowered/api/text/title/Title;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#reset was null") }
+    ::sponge::text::title::Title { env: env, object: ret }
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::text::title::title::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "builder", "()Lorg/spongepowered/api/text/title/Title$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#builder was null") }
+    ::sponge::text::title::title::Builder { env: env, object: ret }
+  }
+
+  pub fn update(env: *mut JNIEnv) -> ::sponge::text::title::title::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/title/Title", "update", "()Lorg/spongepowered/api/text/title/Title$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/title/Title#update was null") }
+    ::sponge::text::title::title::Builder { env: env, object: ret }
+  }
+
+}
+
+pub mod title {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/title/Title$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_title(&self) -> Option<::sponge::text::Text> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "getTitle", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#getTitle was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn title(&self, text: ::sponge::text::Text) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "title", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#title was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_subtitle(&self) -> Option<::sponge::text::Text> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "getSubtitle", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#getSubtitle was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+    }
+
+    pub fn subtitle(&self, text: ::sponge::text::Text) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "subtitle", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#subtitle was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_fade_in(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "getFadeIn", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#getFadeIn was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn fade_in(&self, integer: jobject) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "fadeIn", "(Ljava/lang/Integer;)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#fadeIn was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_stay(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "getStay", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#getStay was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn stay(&self, integer: jobject) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "stay", "(Ljava/lang/Integer;)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#stay was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn get_fade_out(&self) -> Option<jobject> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "getFadeOut", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#getFadeOut was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(unwrapped) }
+    }
+
+    pub fn fade_out(&self, integer: jobject) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "fadeOut", "(Ljava/lang/Integer;)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, integer);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#fadeOut was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn is_clear(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "isClear", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn clear(&self) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "clear", "()Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#clear was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn clear_bool(&self, value: bool) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "clear", "(Z)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#clear was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn is_reset(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "isReset", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn reset(&self) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "reset", "()Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#reset was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset_bool(&self, value: bool) -> ::sponge::text::title::title::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "reset", "(Z)Lorg/spongepowered/api/text/title/Title$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#reset was null") }
+      ::sponge::text::title::title::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::text::title::Title {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "build", "()Lorg/spongepowered/api/text/title/Title;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#build was null") }
+      ::sponge::text::title::Title { env: self.env, object: ret }
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/text/title/Title$Builder", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/text/title/Title$Builder#toString was null") }
+      ret
+    }
+
+  }
+}
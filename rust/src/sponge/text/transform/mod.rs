@@ -0,0 +1,648 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DynamicPartitionedTextFormatter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DynamicPartitionedTextFormatter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DynamicPartitionedTextFormatter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DynamicPartitionedTextFormatter`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::text::transform::DynamicPartitionedTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#<init> was null") }
+    ::sponge::text::transform::DynamicPartitionedTextFormatter { env: env, object: ret }
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::transform::DynamicPartitionedTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#<init> was null") }
+    ::sponge::text::transform::DynamicPartitionedTextFormatter { env: env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "getAll", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#getAll was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "get", "(I)Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#get was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_simple_text_formatter(&self, value: i32, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/transform/SimpleTextFormatter;)Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethodA, value, simple_text_formatter.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#set was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn contains_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn add_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn add_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "add", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn insert_i32_simple_text_formatter(&self, value: i32, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/transform/SimpleTextFormatter;)V", CallVoidMethodA, value, simple_text_formatter.object);
+  }
+
+  pub fn insert_i32_collection(&self, value: i32, collection: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "insert", "(ILjava/util/Collection;)V", CallVoidMethodA, value, collection);
+  }
+
+  pub fn remove_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn remove_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "remove", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "retain", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn insert_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, value, text_representable.object);
+  }
+
+  pub fn add_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn contains_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn set_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::text::TextRepresentable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/TextRepresentable;)Lorg/spongepowered/api/text/TextRepresentable;", CallObjectMethodA, value, text_representable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/DynamicPartitionedTextFormatter#set was null") }
+    ::sponge::text::TextRepresentable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FixedPartitionedTextFormatter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FixedPartitionedTextFormatter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FixedPartitionedTextFormatter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FixedPartitionedTextFormatter`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::text::transform::FixedPartitionedTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#<init> was null") }
+    ::sponge::text::transform::FixedPartitionedTextFormatter { env: env, object: ret }
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::transform::FixedPartitionedTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#<init> was null") }
+    ::sponge::text::transform::FixedPartitionedTextFormatter { env: env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "getAll", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#getAll was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "get", "(I)Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#get was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_simple_text_formatter(&self, value: i32, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/transform/SimpleTextFormatter;)Lorg/spongepowered/api/text/transform/SimpleTextFormatter;", CallObjectMethodA, value, simple_text_formatter.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#set was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: self.env, object: ret }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn contains_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn add_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn add_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "add", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn insert_i32_simple_text_formatter(&self, value: i32, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/transform/SimpleTextFormatter;)V", CallVoidMethodA, value, simple_text_formatter.object);
+  }
+
+  pub fn insert_i32_collection(&self, value: i32, collection: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "insert", "(ILjava/util/Collection;)V", CallVoidMethodA, value, collection);
+  }
+
+  pub fn remove_simple_text_formatter(&self, simple_text_formatter: ::sponge::text::transform::SimpleTextFormatter) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/transform/SimpleTextFormatter;)Z", CallBooleanMethodA, simple_text_formatter.object) != 0
+  }
+
+  pub fn remove_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "remove", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "retain", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn insert_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, value, text_representable.object);
+  }
+
+  pub fn add_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn contains_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn set_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::text::TextRepresentable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/FixedPartitionedTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/TextRepresentable;)Lorg/spongepowered/api/text/TextRepresentable;", CallObjectMethodA, value, text_representable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/FixedPartitionedTextFormatter#set was null") }
+    ::sponge::text::TextRepresentable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PartitionedTextFormatter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PartitionedTextFormatter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PartitionedTextFormatter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PartitionedTextFormatter`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/PartitionedTextFormatter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SimpleTextFormatter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SimpleTextFormatter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SimpleTextFormatter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SimpleTextFormatter`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/SimpleTextFormatter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/SimpleTextFormatter", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#<init> was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: env, object: ret }
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::transform::SimpleTextFormatter {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/SimpleTextFormatter", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#<init> was null") }
+    ::sponge::text::transform::SimpleTextFormatter { env: env, object: ret }
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "getAll", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#getAll was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> ::sponge::text::transform::SimpleTextTemplateApplier {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "get", "(I)Lorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#get was null") }
+    ::sponge::text::transform::SimpleTextTemplateApplier { env: self.env, object: ret }
+  }
+
+  pub fn set_i32_simple_text_template_applier(&self, value: i32, simple_text_template_applier: ::sponge::text::transform::SimpleTextTemplateApplier) -> ::sponge::text::transform::SimpleTextTemplateApplier {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;)Lorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;", CallObjectMethodA, value, simple_text_template_applier.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#set was null") }
+    ::sponge::text::transform::SimpleTextTemplateApplier { env: self.env, object: ret }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn contains_simple_text_template_applier(&self, simple_text_template_applier: ::sponge::text::transform::SimpleTextTemplateApplier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;)Z", CallBooleanMethodA, simple_text_template_applier.object) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn add_simple_text_template_applier(&self, simple_text_template_applier: ::sponge::text::transform::SimpleTextTemplateApplier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;)Z", CallBooleanMethodA, simple_text_template_applier.object) != 0
+  }
+
+  pub fn add_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "add", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn insert_i32_simple_text_template_applier(&self, value: i32, simple_text_template_applier: ::sponge::text::transform::SimpleTextTemplateApplier) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;)V", CallVoidMethodA, value, simple_text_template_applier.object);
+  }
+
+  pub fn insert_i32_collection(&self, value: i32, collection: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "insert", "(ILjava/util/Collection;)V", CallVoidMethodA, value, collection);
+  }
+
+  pub fn remove_simple_text_template_applier(&self, simple_text_template_applier: ::sponge::text::transform::SimpleTextTemplateApplier) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/transform/SimpleTextTemplateApplier;)Z", CallBooleanMethodA, simple_text_template_applier.object) != 0
+  }
+
+  pub fn remove_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "remove", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "retain", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn insert_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, value, text_representable.object);
+  }
+
+  pub fn add_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn contains_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn set_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::text::TextRepresentable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/TextRepresentable;)Lorg/spongepowered/api/text/TextRepresentable;", CallObjectMethodA, value, text_representable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextFormatter#set was null") }
+    ::sponge::text::TextRepresentable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SimpleTextTemplateApplier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SimpleTextTemplateApplier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SimpleTextTemplateApplier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SimpleTextTemplateApplier`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_text_template(env: *mut JNIEnv, text_template: ::sponge::text::TextTemplate) -> ::sponge::text::transform::SimpleTextTemplateApplier {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", "(Lorg/spongepowered/api/text/TextTemplate;)V", text_template.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextTemplateApplier#<init> was null") }
+    ::sponge::text::transform::SimpleTextTemplateApplier { env: env, object: ret }
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::text::transform::SimpleTextTemplateApplier {
+    let ret = new_object!(env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextTemplateApplier#<init> was null") }
+    ::sponge::text::transform::SimpleTextTemplateApplier { env: env, object: ret }
+  }
+
+  pub fn get_parameters(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", self.object, "getParameters", "()Lcom/google/common/collect/ImmutableMap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextTemplateApplier#getParameters was null") }
+    ret
+  }
+
+  pub fn set_parameter(&self, string: jobject, text_element: ::sponge::text::TextElement) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", self.object, "setParameter", "(Ljava/lang/String;Lorg/spongepowered/api/text/TextElement;)V", CallVoidMethodA, string, text_element.object);
+  }
+
+  pub fn get_template(&self) -> ::sponge::text::TextTemplate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", self.object, "getTemplate", "()Lorg/spongepowered/api/text/TextTemplate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/SimpleTextTemplateApplier#getTemplate was null") }
+    ::sponge::text::TextTemplate { env: self.env, object: ret }
+  }
+
+  pub fn set_template(&self, text_template: ::sponge::text::TextTemplate) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/SimpleTextTemplateApplier", self.object, "setTemplate", "(Lorg/spongepowered/api/text/TextTemplate;)V", CallVoidMethodA, text_template.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextFormatter {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextFormatter {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextFormatter {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextFormatter`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/TextFormatter", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_all(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "getAll", "()Lcom/google/common/collect/ImmutableList;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#getAll was null") }
+    ret
+  }
+
+  pub fn get(&self, value: i32) -> ::sponge::text::TextRepresentable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "get", "(I)Lorg/spongepowered/api/text/TextRepresentable;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#get was null") }
+    ::sponge::text::TextRepresentable { env: self.env, object: ret }
+  }
+
+  pub fn first_after(&self, value: i32, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "firstAfter", "(ILjava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, value, class);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#firstAfter was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn first(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "first", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#first was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn for_each_after(&self, value: i32, class: jobject, consumer: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "forEachAfter", "(ILjava/lang/Class;Ljava/util/function/Consumer;)V", CallVoidMethodA, value, class, consumer);
+  }
+
+  pub fn for_each(&self, class: jobject, consumer: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "forEach", "(Ljava/lang/Class;Ljava/util/function/Consumer;)V", CallVoidMethodA, class, consumer);
+  }
+
+  pub fn set(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) -> ::sponge::text::TextRepresentable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "set", "(ILorg/spongepowered/api/text/TextRepresentable;)Lorg/spongepowered/api/text/TextRepresentable;", CallObjectMethodA, value, text_representable.object);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#set was null") }
+    ::sponge::text::TextRepresentable { env: self.env, object: ret }
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn contains(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "contains", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn add_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "add", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn add_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "add", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn add_iterable(&self, iterable: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "add", "(Ljava/lang/Iterable;)Z", CallBooleanMethodA, iterable) != 0
+  }
+
+  pub fn add_iterator(&self, iterator: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "add", "(Ljava/util/Iterator;)Z", CallBooleanMethodA, iterator) != 0
+  }
+
+  pub fn insert_i32_text_representable(&self, value: i32, text_representable: ::sponge::text::TextRepresentable) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "insert", "(ILorg/spongepowered/api/text/TextRepresentable;)V", CallVoidMethodA, value, text_representable.object);
+  }
+
+  pub fn insert_i32_collection(&self, value: i32, collection: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "insert", "(ILjava/util/Collection;)V", CallVoidMethodA, value, collection);
+  }
+
+  pub fn insert_i32_iterable(&self, value: i32, iterable: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "insert", "(ILjava/lang/Iterable;)V", CallVoidMethodA, value, iterable);
+  }
+
+  pub fn insert_i32_iterator(&self, value: i32, iterator: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "insert", "(ILjava/util/Iterator;)V", CallVoidMethodA, value, iterator);
+  }
+
+  pub fn remove_text_representable(&self, text_representable: ::sponge::text::TextRepresentable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "remove", "(Lorg/spongepowered/api/text/TextRepresentable;)Z", CallBooleanMethodA, text_representable.object) != 0
+  }
+
+  pub fn remove_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "remove", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove_iterable(&self, iterable: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "remove", "(Ljava/lang/Iterable;)Z", CallBooleanMethodA, iterable) != 0
+  }
+
+  pub fn remove_iterator(&self, iterator: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "remove", "(Ljava/util/Iterator;)Z", CallBooleanMethodA, iterator) != 0
+  }
+
+  pub fn retain_collection(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "retain", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain_iterable(&self, iterable: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "retain", "(Ljava/lang/Iterable;)Z", CallBooleanMethodA, iterable) != 0
+  }
+
+  pub fn retain_iterator(&self, iterator: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "retain", "(Ljava/util/Iterator;)Z", CallBooleanMethodA, iterator) != 0
+  }
+
+  pub fn format(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "format", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#format was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn iterator(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "iterator", "()Ljava/util/Iterator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#iterator was null") }
+    ret
+  }
+
+  pub fn to_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextFormatter", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextFormatter#toText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextTemplateApplier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextTemplateApplier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextTemplateApplier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TextTemplateApplier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/transform/TextTemplateApplier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_parameters(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "getParameters", "()Lcom/google/common/collect/ImmutableMap;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextTemplateApplier#getParameters was null") }
+    ret
+  }
+
+  pub fn get_parameter(&self, string: jobject) -> ::sponge::text::TextElement {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "getParameter", "(Ljava/lang/String;)Lorg/spongepowered/api/text/TextElement;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextTemplateApplier#getParameter was null") }
+    ::sponge::text::TextElement { env: self.env, object: ret }
+  }
+
+  pub fn set_parameter(&self, string: jobject, text_element: ::sponge::text::TextElement) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "setParameter", "(Ljava/lang/String;Lorg/spongepowered/api/text/TextElement;)V", CallVoidMethodA, string, text_element.object);
+  }
+
+  pub fn get_template(&self) -> ::sponge::text::TextTemplate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "getTemplate", "()Lorg/spongepowered/api/text/TextTemplate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextTemplateApplier#getTemplate was null") }
+    ::sponge::text::TextTemplate { env: self.env, object: ret }
+  }
+
+  pub fn set_template(&self, text_template: ::sponge::text::TextTemplate) {
+    bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "setTemplate", "(Lorg/spongepowered/api/text/TextTemplate;)V", CallVoidMethodA, text_template.object);
+  }
+
+  pub fn to_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/transform/TextTemplateApplier", self.object, "toText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/transform/TextTemplateApplier#toText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,53 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Locales {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Locales {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Locales {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Locales`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/locale/Locales", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv, string: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/text/translation/locale/Locales", "of", "(Ljava/lang/String;)Ljava/util/Locale;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/locale/Locales#of was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NamedLocales {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NamedLocales {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NamedLocales {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NamedLocales`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/locale/NamedLocales", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,167 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod locale;
+
+#[derive(Debug)]
+pub struct FixedTranslation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FixedTranslation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FixedTranslation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `FixedTranslation`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/FixedTranslation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::text::translation::FixedTranslation {
+    let ret = new_object!(env, "org/spongepowered/api/text/translation/FixedTranslation", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/FixedTranslation#<init> was null") }
+    ::sponge::text::translation::FixedTranslation { env: env, object: ret }
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/FixedTranslation", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/FixedTranslation#getId was null") }
+    ret
+  }
+
+  pub fn get_locale(&self, locale: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/FixedTranslation", self.object, "get", "(Ljava/util/Locale;)Ljava/lang/String;", CallObjectMethodA, locale);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/FixedTranslation#get was null") }
+    ret
+  }
+
+  pub fn get_object_object_array(&self, object: jobject, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct ResourceBundleTranslation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResourceBundleTranslation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResourceBundleTranslation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ResourceBundleTranslation`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/ResourceBundleTranslation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject, function: jobject) -> ::sponge::text::translation::ResourceBundleTranslation {
+    let ret = new_object!(env, "org/spongepowered/api/text/translation/ResourceBundleTranslation", "(Ljava/lang/String;Ljava/util/function/Function;)V", string, function);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/ResourceBundleTranslation#<init> was null") }
+    ::sponge::text::translation::ResourceBundleTranslation { env: env, object: ret }
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/ResourceBundleTranslation", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/ResourceBundleTranslation#getId was null") }
+    ret
+  }
+
+  pub fn get_locale(&self, locale: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/ResourceBundleTranslation", self.object, "get", "(Ljava/util/Locale;)Ljava/lang/String;", CallObjectMethodA, locale);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/ResourceBundleTranslation#get was null") }
+    ret
+  }
+
+  pub fn get_object_object_array(&self, object: jobject, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct Translatable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Translatable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Translatable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Translatable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/Translatable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_translation(&self) -> ::sponge::text::translation::Translation {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/Translatable", self.object, "getTranslation", "()Lorg/spongepowered/api/text/translation/Translation;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/Translatable#getTranslation was null") }
+    ::sponge::text::translation::Translation { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Translation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Translation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Translation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Translation`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/text/translation/Translation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/Translation", self.object, "getId", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/Translation#getId was null") }
+    ret
+  }
+
+  pub fn get(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/Translation", self.object, "get", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/Translation#get was null") }
+    ret
+  }
+
+  pub fn get_object_array(&self, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+  pub fn get_locale(&self, locale: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/text/translation/Translation", self.object, "get", "(Ljava/util/Locale;)Ljava/lang/String;", CallObjectMethodA, locale);
+    if ret.is_null() { panic!("org/spongepowered/api/text/translation/Translation#get was null") }
+    ret
+  }
+
+  pub fn get_object_object_array(&self, object: jobject, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+}
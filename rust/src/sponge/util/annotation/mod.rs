@@ -0,0 +1,108 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct CatalogedBy {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CatalogedBy {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CatalogedBy {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CatalogedBy`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/annotation/CatalogedBy", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct NonnullByDefault {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NonnullByDefault {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NonnullByDefault {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NonnullByDefault`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/annotation/NonnullByDefault", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TransformResult {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransformResult {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransformResult {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransformResult`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/annotation/TransformResult", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/annotation/TransformResult", self.object, "value", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/annotation/TransformResult#value was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TransformWith {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TransformWith {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TransformWith {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TransformWith`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/annotation/TransformWith", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn value(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/annotation/TransformWith", self.object, "value", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/annotation/TransformWith#value was null") }
+    ret
+  }
+
+}
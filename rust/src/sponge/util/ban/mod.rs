@@ -0,0 +1,287 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Ban {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ban {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ban {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ban`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/Ban", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::util::ban::ban::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/ban/Ban", "builder", "()Lorg/spongepowered/api/util/ban/Ban$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#builder was null") }
+    ::sponge::util::ban::ban::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "profile")]
+  pub fn of_game_profile(env: *mut JNIEnv, game_profile: ::sponge::profile::GameProfile) -> ::sponge::util::ban::Ban {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/ban/Ban", "of", "(Lorg/spongepowered/api/profile/GameProfile;)Lorg/spongepowered/api/util/ban/Ban;", CallStaticObjectMethodA, game_profile.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#of was null") }
+    ::sponge::util::ban::Ban { env: env, object: ret }
+  }
+
+  #[cfg(all(feature = "profile", feature = "text"))]
+  pub fn of_game_profile_text(env: *mut JNIEnv, game_profile: ::sponge::profile::GameProfile, text: ::sponge::text::Text) -> ::sponge::util::ban::Ban {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/ban/Ban", "of", "(Lorg/spongepowered/api/profile/GameProfile;Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/util/ban/Ban;", CallStaticObjectMethodA, game_profile.object, text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#of was null") }
+    ::sponge::util::ban::Ban { env: env, object: ret }
+  }
+
+  pub fn get_type(&self) -> ::sponge::util::ban::BanType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getType", "()Lorg/spongepowered/api/util/ban/BanType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getType was null") }
+    ::sponge::util::ban::BanType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_reason(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getReason", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getReason was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_creation_date(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getCreationDate", "()Ljava/time/Instant;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getCreationDate was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_ban_source(&self) -> Option<::sponge::text::Text> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getBanSource", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getBanSource was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::text::Text { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "command")]
+  pub fn get_ban_command_source(&self) -> Option<::sponge::command::CommandSource> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getBanCommandSource", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getBanCommandSource was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::command::CommandSource { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_expiration_date(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "getExpirationDate", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban#getExpirationDate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn is_indefinite(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/ban/Ban", self.object, "isIndefinite", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BanType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BanType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BanType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BanType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/BanType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_ban_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/BanType", self.object, "getBanClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ban/BanType#getBanClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BanTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BanTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BanTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BanTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/BanTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+pub mod ban {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/Ban$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "profile")]
+    pub fn profile(&self, game_profile: ::sponge::profile::GameProfile) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "profile", "(Lorg/spongepowered/api/profile/GameProfile;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, game_profile.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#profile was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    pub fn address(&self, inet_address: jobject) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "address", "(Ljava/net/InetAddress;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, inet_address);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#address was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, ban_type: ::sponge::util::ban::BanType) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "type", "(Lorg/spongepowered/api/util/ban/BanType;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, ban_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#type was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn reason(&self, text: ::sponge::text::Text) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "reason", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#reason was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    pub fn start_date(&self, instant: jobject) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "startDate", "(Ljava/time/Instant;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, instant);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#startDate was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    pub fn expiration_date(&self, instant: jobject) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "expirationDate", "(Ljava/time/Instant;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, instant);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#expirationDate was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "command")]
+    pub fn source_command_source(&self, command_source: ::sponge::command::CommandSource) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "source", "(Lorg/spongepowered/api/command/CommandSource;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, command_source.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#source was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "text")]
+    pub fn source_text(&self, text: ::sponge::text::Text) -> ::sponge::util::ban::ban::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "source", "(Lorg/spongepowered/api/text/Text;)Lorg/spongepowered/api/util/ban/Ban$Builder;", CallObjectMethodA, text.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#source was null") }
+      ::sponge::util::ban::ban::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::util::ban::Ban {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Builder", self.object, "build", "()Lorg/spongepowered/api/util/ban/Ban;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Builder#build was null") }
+      ::sponge::util::ban::Ban { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Ip {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Ip {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Ip {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ip`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/Ban$Ip", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_address(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Ip", self.object, "getAddress", "()Ljava/net/InetAddress;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Ip#getAddress was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Profile {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Profile {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Profile {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Profile`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ban/Ban$Profile", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "profile")]
+    pub fn get_profile(&self) -> ::sponge::profile::GameProfile {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/ban/Ban$Profile", self.object, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/ban/Ban$Profile#getProfile was null") }
+      ::sponge::profile::GameProfile { env: self.env, object: ret }
+    }
+
+  }
+}
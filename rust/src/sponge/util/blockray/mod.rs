@@ -0,0 +1,367 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BlockRay {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockRay {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockRay {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockRay`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/blockray/BlockRay", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_block_limit(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay", self.object, "setBlockLimit", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn reset(&self) {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay", self.object, "reset", "()V", CallVoidMethod);
+  }
+
+  pub fn has_next(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay", self.object, "hasNext", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn next(&self) -> ::sponge::util::blockray::BlockRayHit {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay", self.object, "next", "()Lorg/spongepowered/api/util/blockray/BlockRayHit;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#next was null") }
+    ::sponge::util::blockray::BlockRayHit { env: self.env, object: ret }
+  }
+
+  pub fn end(&self) -> Option<::sponge::util::blockray::BlockRayHit> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay", self.object, "end", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#end was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::blockray::BlockRayHit { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn from_location(env: *mut JNIEnv, location: ::sponge::world::Location) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "from", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallStaticObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#from was null") }
+    ::sponge::util::blockray::block_ray::BlockRayBuilder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn from_extent_vector_3d(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3d: ::math::Vector3d) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "from", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallStaticObjectMethodA, extent.object, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#from was null") }
+    ::sponge::util::blockray::block_ray::BlockRayBuilder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn from_entity(env: *mut JNIEnv, entity: ::sponge::entity::Entity) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "from", "(Lorg/spongepowered/api/entity/Entity;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallStaticObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#from was null") }
+    ::sponge::util::blockray::block_ray::BlockRayBuilder { env: env, object: ret }
+  }
+
+  pub fn all_filter(env: *mut JNIEnv) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "allFilter", "()Ljava/util/function/Predicate;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#allFilter was null") }
+    ret
+  }
+
+  pub fn only_air_filter(env: *mut JNIEnv) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "onlyAirFilter", "()Ljava/util/function/Predicate;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#onlyAirFilter was null") }
+    ret
+  }
+
+  #[cfg(feature = "block")]
+  pub fn block_type_filter(env: *mut JNIEnv, block_type: ::sponge::block::BlockType) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "blockTypeFilter", "(Lorg/spongepowered/api/block/BlockType;)Ljava/util/function/Predicate;", CallStaticObjectMethodA, block_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#blockTypeFilter was null") }
+    ret
+  }
+
+  pub fn max_distance_filter(env: *mut JNIEnv, vector_3d: ::math::Vector3d, value: f64) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "maxDistanceFilter", "(Lcom/flowpowered/math/vector/Vector3d;D)Ljava/util/function/Predicate;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#maxDistanceFilter was null") }
+    ret
+  }
+
+  pub fn continue_after_filter(env: *mut JNIEnv, predicate: jobject, value: i32) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/blockray/BlockRay", "continueAfterFilter", "(Ljava/util/function/Predicate;I)Ljava/util/function/Predicate;", CallStaticObjectMethodA, predicate, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay#continueAfterFilter was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockRayHit {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockRayHit {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockRayHit {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `BlockRayHit`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/blockray/BlockRayHit", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn new(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, value: f64, value_2: f64, value_3: f64, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> ::sponge::util::blockray::BlockRayHit {
+    let ret = new_object!(env, "org/spongepowered/api/util/blockray/BlockRayHit", "(Lorg/spongepowered/api/world/extent/Extent;DDDLcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)V", extent.object, value, value_2, value_3, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#<init> was null") }
+    ::sponge::util::blockray::BlockRayHit { env: env, object: ret }
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_extent(&self) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getExtent", "()Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getExtent was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  pub fn get_x(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getX", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_y(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getY", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_z(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getZ", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getPosition was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_block_x(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getBlockX", "()I", CallIntMethod)
+  }
+
+  pub fn get_block_y(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getBlockY", "()I", CallIntMethod)
+  }
+
+  pub fn get_block_z(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getBlockZ", "()I", CallIntMethod)
+  }
+
+  pub fn get_block_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getBlockPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getBlockPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  #[cfg(feature = "world")]
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_direction(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getDirection", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getDirection was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_normal(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "getNormal", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#getNormal was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_faces(&self) -> Vec<::sponge::util::Direction> {
+    unimplemented!();
+  }
+  pub fn map(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "map", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#map was null") }
+    ret
+  }
+
+  pub fn map_block(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "mapBlock", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#mapBlock was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRayHit", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRayHit#toString was null") }
+    ret
+  }
+
+}
+
+pub mod block_ray {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct BlockRayBuilder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl BlockRayBuilder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      BlockRayBuilder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockRayBuilder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn filter_predicate(&self, predicate: jobject) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "filter", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallObjectMethodA, predicate);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#filter was null") }
+      ::sponge::util::blockray::block_ray::BlockRayBuilder { env: self.env, object: ret }
+    }
+
+    pub fn filter_object_array(&self, objects: Vec<jobject>) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      unimplemented!();
+    }
+    pub fn to(&self, vector_3d: ::math::Vector3d) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "to", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#to was null") }
+      ::sponge::util::blockray::block_ray::BlockRayBuilder { env: self.env, object: ret }
+    }
+
+    pub fn direction(&self, vector_3d: ::math::Vector3d) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "direction", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#direction was null") }
+      ::sponge::util::blockray::block_ray::BlockRayBuilder { env: self.env, object: ret }
+    }
+
+    pub fn block_limit(&self, value: i32) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "blockLimit", "(I)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#blockLimit was null") }
+      ::sponge::util::blockray::block_ray::BlockRayBuilder { env: self.env, object: ret }
+    }
+
+    pub fn narrow_phase(&self, value: bool) -> ::sponge::util::blockray::block_ray::BlockRayBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "narrowPhase", "(Z)Lorg/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#narrowPhase was null") }
+      ::sponge::util::blockray::block_ray::BlockRayBuilder { env: self.env, object: ret }
+    }
+
+    pub fn position(&self) -> ::math::Vector3d {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "position", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#position was null") }
+      <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    pub fn build(&self) -> ::sponge::util::blockray::BlockRay {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "build", "()Lorg/spongepowered/api/util/blockray/BlockRay;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#build was null") }
+      ::sponge::util::blockray::BlockRay { env: self.env, object: ret }
+    }
+
+    pub fn iterator(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "iterator", "()Ljava/util/Iterator;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#iterator was null") }
+      ret
+    }
+
+    pub fn end(&self) -> Option<::sponge::util::blockray::BlockRayHit> {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder", self.object, "end", "()Ljava/util/Optional;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$BlockRayBuilder#end was null") }
+      let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+      if unwrapped.is_null() { None } else { Some(::sponge::util::blockray::BlockRayHit { env: self.env, object: unwrapped }) }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct ContinueAfterFilter {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ContinueAfterFilter {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ContinueAfterFilter {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `ContinueAfterFilter`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/blockray/BlockRay$ContinueAfterFilter", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv, predicate: jobject, value: i32) -> ::sponge::util::blockray::block_ray::ContinueAfterFilter {
+      let ret = new_object!(env, "org/spongepowered/api/util/blockray/BlockRay$ContinueAfterFilter", "(Ljava/util/function/Predicate;I)V", predicate, value);
+      if ret.is_null() { panic!("org/spongepowered/api/util/blockray/BlockRay$ContinueAfterFilter#<init> was null") }
+      ::sponge::util::blockray::block_ray::ContinueAfterFilter { env: env, object: ret }
+    }
+
+    pub fn test_block_ray_hit(&self, block_ray_hit: ::sponge::util::blockray::BlockRayHit) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$ContinueAfterFilter", self.object, "test", "(Lorg/spongepowered/api/util/blockray/BlockRayHit;)Z", CallBooleanMethodA, block_ray_hit.object) != 0
+    }
+
+    pub fn test_object(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$ContinueAfterFilter", self.object, "test", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct TargetBlockFilter {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl TargetBlockFilter {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      TargetBlockFilter {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `TargetBlockFilter`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/blockray/BlockRay$TargetBlockFilter", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn test_block_ray_hit(&self, block_ray_hit: ::sponge::util::blockray::BlockRayHit) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$TargetBlockFilter", self.object, "test", "(Lorg/spongepowered/api/util/blockray/BlockRayHit;)Z", CallBooleanMethodA, block_ray_hit.object) != 0
+    }
+
+    pub fn test_object(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/blockray/BlockRay$TargetBlockFilter", self.object, "test", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+  }
+}
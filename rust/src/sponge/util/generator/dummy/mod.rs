@@ -0,0 +1,103 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DummyClassGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DummyClassGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DummyClassGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DummyClassGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/dummy/DummyClassGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::generator::dummy::DummyClassGenerator {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/dummy/DummyClassGenerator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/dummy/DummyClassGenerator#<init> was null") }
+    ::sponge::util::generator::dummy::DummyClassGenerator { env: env, object: ret }
+  }
+
+  pub fn create_class(&self, object: jobject, object_2: jobject, object_3: jobject) -> Vec<i8> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct DummyClassGeneratorProvider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DummyClassGeneratorProvider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DummyClassGeneratorProvider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DummyClassGeneratorProvider`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/dummy/DummyClassGeneratorProvider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::util::generator::dummy::DummyClassGeneratorProvider {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/dummy/DummyClassGeneratorProvider", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/dummy/DummyClassGeneratorProvider#<init> was null") }
+    ::sponge::util::generator::dummy::DummyClassGeneratorProvider { env: env, object: ret }
+  }
+
+  pub fn create(&self, class: jobject, class_2: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/dummy/DummyClassGeneratorProvider", self.object, "create", "(Ljava/lang/Class;Ljava/lang/Class;)Ljava/lang/Class;", CallObjectMethodA, class, class_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/dummy/DummyClassGeneratorProvider#create was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DummyObjectProvider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DummyObjectProvider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DummyObjectProvider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DummyObjectProvider`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/dummy/DummyObjectProvider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::generator::dummy::DummyObjectProvider {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/dummy/DummyObjectProvider", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/dummy/DummyObjectProvider#<init> was null") }
+    ::sponge::util::generator::dummy::DummyObjectProvider { env: env, object: ret }
+  }
+
+  pub fn create_for(env: *mut JNIEnv, class: jobject, string: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/generator/dummy/DummyObjectProvider", "createFor", "(Ljava/lang/Class;Ljava/lang/String;)Ljava/lang/Object;", CallStaticObjectMethodA, class, string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/dummy/DummyObjectProvider#createFor was null") }
+    ret
+  }
+
+}
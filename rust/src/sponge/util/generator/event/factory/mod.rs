@@ -0,0 +1,220 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod plugin;
+
+#[derive(Debug)]
+pub struct ClassGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClassGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClassGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ClassGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::generator::event::factory::ClassGenerator {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGenerator#<init> was null") }
+    ::sponge::util::generator::event::factory::ClassGenerator { env: env, object: ret }
+  }
+
+  pub fn visit_boxing_method(env: *mut JNIEnv, method_visitor: jobject, type_: jobject) {
+    static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "visitBoxingMethod", "(Lorg/objectweb/asm/MethodVisitor;Lorg/objectweb/asm/Type;)V", CallStaticVoidMethodA, method_visitor, type_);
+  }
+
+  pub fn visit_unboxing_method(env: *mut JNIEnv, method_visitor: jobject, type_: jobject) {
+    static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "visitUnboxingMethod", "(Lorg/objectweb/asm/MethodVisitor;Lorg/objectweb/asm/Type;)V", CallStaticVoidMethodA, method_visitor, type_);
+  }
+
+  pub fn has_declared_method(env: *mut JNIEnv, object: jobject, object_2: jobject, objects: Vec<jobject>) -> bool {
+    unimplemented!();
+  }
+  pub fn get_field(env: *mut JNIEnv, class: jobject, string: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "getField", "(Ljava/lang/Class;Ljava/lang/String;)Ljava/lang/reflect/Field;", CallStaticObjectMethodA, class, string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGenerator#getField was null") }
+    ret
+  }
+
+  pub fn get_null_policy(&self) -> ::sponge::util::generator::event::factory::NullPolicy {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", self.object, "getNullPolicy", "()Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGenerator#getNullPolicy was null") }
+    <::sponge::util::generator::event::factory::NullPolicy as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_null_policy(&self, null_policy: ::sponge::util::generator::event::factory::NullPolicy) {
+    bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", self.object, "setNullPolicy", "(Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;)V", CallVoidMethodA, ::convert::ToJava::to_java(&null_policy, self.env));
+  }
+
+  pub fn generate_field(env: *mut JNIEnv, class_writer: jobject, property: jobject) {
+    static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "generateField", "(Lorg/objectweb/asm/ClassWriter;Lorg/spongepowered/api/eventgencore/Property;)V", CallStaticVoidMethodA, class_writer, property);
+  }
+
+  pub fn generate_mutator(env: *mut JNIEnv, class_writer: jobject, class: jobject, string: jobject, string_2: jobject, class_2: jobject, property: jobject) {
+    static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "generateMutator", "(Lorg/objectweb/asm/ClassWriter;Ljava/lang/Class;Ljava/lang/String;Ljava/lang/String;Ljava/lang/Class;Lorg/spongepowered/api/eventgencore/Property;)V", CallStaticVoidMethodA, class_writer, class, string, string_2, class_2, property);
+  }
+
+  pub fn get_internal_name(env: *mut JNIEnv, string: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/generator/event/factory/ClassGenerator", "getInternalName", "(Ljava/lang/String;)Ljava/lang/String;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGenerator#getInternalName was null") }
+    ret
+  }
+
+  pub fn create_class(&self, object: jobject, object_2: jobject, object_3: jobject, object_4: jobject) -> Vec<i8> {
+    unimplemented!();
+  }
+  pub fn create_factory(&self, object: jobject, object_2: jobject) -> Vec<i8> {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct ClassGeneratorProvider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ClassGeneratorProvider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ClassGeneratorProvider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ClassGeneratorProvider`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::util::generator::event::factory::ClassGeneratorProvider {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider#<init> was null") }
+    ::sponge::util::generator::event::factory::ClassGeneratorProvider { env: env, object: ret }
+  }
+
+  pub fn get_null_policy(&self) -> ::sponge::util::generator::event::factory::NullPolicy {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider", self.object, "getNullPolicy", "()Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider#getNullPolicy was null") }
+    <::sponge::util::generator::event::factory::NullPolicy as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_null_policy(&self, null_policy: ::sponge::util::generator::event::factory::NullPolicy) {
+    bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider", self.object, "setNullPolicy", "(Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;)V", CallVoidMethodA, ::convert::ToJava::to_java(&null_policy, self.env));
+  }
+
+  pub fn create(&self, class: jobject, class_2: jobject, list: jobject) -> ::sponge::util::generator::event::factory::EventFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider", self.object, "create", "(Ljava/lang/Class;Ljava/lang/Class;Ljava/util/List;)Lorg/spongepowered/api/util/generator/event/factory/EventFactory;", CallObjectMethodA, class, class_2, list);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/ClassGeneratorProvider#create was null") }
+    ::sponge::util::generator::event::factory::EventFactory { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EventFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EventFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EventFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EventFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/EventFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct FactoryProvider {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl FactoryProvider {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    FactoryProvider {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `FactoryProvider`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/FactoryProvider", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_null_policy(&self) -> ::sponge::util::generator::event::factory::NullPolicy {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/FactoryProvider", self.object, "getNullPolicy", "()Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/FactoryProvider#getNullPolicy was null") }
+    <::sponge::util::generator::event::factory::NullPolicy as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_null_policy(&self, null_policy: ::sponge::util::generator::event::factory::NullPolicy) {
+    bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/FactoryProvider", self.object, "setNullPolicy", "(Lorg/spongepowered/api/util/generator/event/factory/NullPolicy;)V", CallVoidMethodA, ::convert::ToJava::to_java(&null_policy, self.env));
+  }
+
+  pub fn create(&self, class: jobject, class_2: jobject, list: jobject) -> ::sponge::util::generator::event::factory::EventFactory {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/FactoryProvider", self.object, "create", "(Ljava/lang/Class;Ljava/lang/Class;Ljava/util/List;)Lorg/spongepowered/api/util/generator/event/factory/EventFactory;", CallObjectMethodA, class, class_2, list);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/FactoryProvider#create was null") }
+    ::sponge::util::generator::event::factory::EventFactory { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NullPolicy {
+  DisablePreconditions,
+  NonNullByDefault,
+  NullByDefault,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static NULL_POLICY_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/generator/event/factory/NullPolicy", &["DISABLE_PRECONDITIONS", "NON_NULL_BY_DEFAULT", "NULL_BY_DEFAULT"]);
+
+impl NullPolicy {
+  pub const VALUES: &'static [NullPolicy] = &[NullPolicy::DisablePreconditions, NullPolicy::NonNullByDefault, NullPolicy::NullByDefault];
+
+}
+
+impl ::convert::FromJava for NullPolicy {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match NULL_POLICY_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => NullPolicy::VALUES[ordinal],
+      None => NullPolicy::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for NullPolicy {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      NullPolicy::Other(object) => object,
+      value => NULL_POLICY_CONSTANTS.get(env, NullPolicy::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
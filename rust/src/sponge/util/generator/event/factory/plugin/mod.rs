@@ -0,0 +1,112 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct AccessorModifierEventFactoryPlugin {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AccessorModifierEventFactoryPlugin {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AccessorModifierEventFactoryPlugin {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AccessorModifierEventFactoryPlugin`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::generator::event::factory::plugin::AccessorModifierEventFactoryPlugin {
+    let ret = new_object!(env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin#<init> was null") }
+    ::sponge::util::generator::event::factory::plugin::AccessorModifierEventFactoryPlugin { env: env, object: ret }
+  }
+
+  pub fn contribute_property(&self, class: jobject, string: jobject, class_writer: jobject, property: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin", self.object, "contributeProperty", "(Ljava/lang/Class;Ljava/lang/String;Lorg/objectweb/asm/ClassWriter;Lorg/spongepowered/api/eventgencore/Property;)Z", CallBooleanMethodA, class, string, class_writer, property) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EventFactoryPlugin {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EventFactoryPlugin {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EventFactoryPlugin {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EventFactoryPlugin`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/plugin/EventFactoryPlugin", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn contribute_property(&self, class: jobject, string: jobject, class_writer: jobject, property: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/plugin/EventFactoryPlugin", self.object, "contributeProperty", "(Ljava/lang/Class;Ljava/lang/String;Lorg/objectweb/asm/ClassWriter;Lorg/spongepowered/api/eventgencore/Property;)Z", CallBooleanMethodA, class, string, class_writer, property) != 0
+  }
+
+}
+
+pub mod accessor_modifier_event_factory_plugin {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct MethodPair {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl MethodPair {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      MethodPair {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `MethodPair`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv, string: jobject, method: jobject, method_2: jobject, property: jobject) -> ::sponge::util::generator::event::factory::plugin::accessor_modifier_event_factory_plugin::MethodPair {
+      let ret = new_object!(env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair", "(Ljava/lang/String;Ljava/lang/reflect/Method;Ljava/lang/reflect/Method;Lorg/spongepowered/api/eventgencore/Property;)V", string, method, method_2, property);
+      if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair#<init> was null") }
+      ::sponge::util::generator::event::factory::plugin::accessor_modifier_event_factory_plugin::MethodPair { env: env, object: ret }
+    }
+
+    pub fn get_name(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair#getName was null") }
+      ret
+    }
+
+    pub fn get_transformer_method(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair", self.object, "getTransformerMethod", "()Ljava/lang/reflect/Method;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair#getTransformerMethod was null") }
+      ret
+    }
+
+    pub fn get_property(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair", self.object, "getProperty", "()Lorg/spongepowered/api/eventgencore/Property;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/generator/event/factory/plugin/AccessorModifierEventFactoryPlugin$MethodPair#getProperty was null") }
+      ret
+    }
+
+  }
+}
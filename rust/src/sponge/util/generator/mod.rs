@@ -0,0 +1,69 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod dummy;
+pub mod event;
+
+#[derive(Debug)]
+pub struct GeneratorUtils {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GeneratorUtils {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GeneratorUtils {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GeneratorUtils`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/GeneratorUtils", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_class_name(env: *mut JNIEnv, string: jobject, class: jobject, string_2: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/generator/GeneratorUtils", "getClassName", "(Ljava/lang/String;Ljava/lang/Class;Ljava/lang/String;)Ljava/lang/String;", CallStaticObjectMethodA, string, class, string_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/generator/GeneratorUtils#getClassName was null") }
+    ret
+  }
+
+}
+
+pub mod generator_utils {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct LocalClassLoader {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl LocalClassLoader {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      LocalClassLoader {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `LocalClassLoader`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/generator/GeneratorUtils$LocalClassLoader", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv, class_loader: jobject) -> ::sponge::util::generator::generator_utils::LocalClassLoader {
+      let ret = new_object!(env, "org/spongepowered/api/util/generator/GeneratorUtils$LocalClassLoader", "(Ljava/lang/ClassLoader;)V", class_loader);
+      if ret.is_null() { panic!("org/spongepowered/api/util/generator/GeneratorUtils$LocalClassLoader#<init> was null") }
+      ::sponge::util::generator::generator_utils::LocalClassLoader { env: env, object: ret }
+    }
+
+    pub fn define_class(&self, string: jobject, values: Vec<i8>) -> jobject {
+      unimplemented!();
+    }
+  }
+}
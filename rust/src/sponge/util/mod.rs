@@ -0,0 +1,2068 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod annotation;
+pub mod ban;
+pub mod blockray;
+pub mod generator;
+pub mod rotation;
+pub mod weighted;
+
+#[derive(Debug)]
+pub struct AABB {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl AABB {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    AABB {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `AABB`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/AABB", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_vector_3i_vector_3i(env: *mut JNIEnv, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::util::AABB {
+    let ret = new_object!(env, "org/spongepowered/api/util/AABB", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)V", ::convert::ToJava::to_java(&vector_3i, env), ::convert::ToJava::to_java(&vector_3i_2, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#<init> was null") }
+    ::sponge::util::AABB { env: env, object: ret }
+  }
+
+  pub fn new_f64_f64_f64_f64_f64_f64(env: *mut JNIEnv, value: f64, value_2: f64, value_3: f64, value_4: f64, value_5: f64, value_6: f64) -> ::sponge::util::AABB {
+    let ret = new_object!(env, "org/spongepowered/api/util/AABB", "(DDDDDD)V", value, value_2, value_3, value_4, value_5, value_6);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#<init> was null") }
+    ::sponge::util::AABB { env: env, object: ret }
+  }
+
+  pub fn new_vector_3d_vector_3d(env: *mut JNIEnv, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> ::sponge::util::AABB {
+    let ret = new_object!(env, "org/spongepowered/api/util/AABB", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)V", ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#<init> was null") }
+    ::sponge::util::AABB { env: env, object: ret }
+  }
+
+  pub fn get_min(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "getMin", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#getMin was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_max(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "getMax", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#getMax was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_center(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "getCenter", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#getCenter was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_size(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "getSize", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#getSize was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn contains_vector_3i(&self, vector_3i: ::math::Vector3i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "contains", "(Lcom/flowpowered/math/vector/Vector3i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env)) != 0
+  }
+
+  pub fn contains_vector_3d(&self, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "contains", "(Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3d, self.env)) != 0
+  }
+
+  pub fn contains_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "contains", "(DDD)Z", CallBooleanMethodA, value, value_2, value_3) != 0
+  }
+
+  pub fn intersects_aabb(&self, aabb: ::sponge::util::AABB) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "intersects", "(Lorg/spongepowered/api/util/AABB;)Z", CallBooleanMethodA, aabb.object) != 0
+  }
+
+  pub fn intersects_vector_3d_vector_3d(&self, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> Option<::sponge::util::Tuple> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "intersects", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), ::convert::ToJava::to_java(&vector_3d_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#intersects was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::Tuple { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn offset_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "offset", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#offset was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn offset_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "offset", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#offset was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn offset_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "offset", "(DDD)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#offset was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn expand_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "expand", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#expand was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn expand_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "expand", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#expand was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn expand_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::util::AABB {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "expand", "(DDD)Lorg/spongepowered/api/util/AABB;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#expand was null") }
+    ::sponge::util::AABB { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/AABB", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/AABB#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+  X,
+  Y,
+  Z,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static AXIS_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/Axis", &["X", "Y", "Z"]);
+
+impl Axis {
+  pub const VALUES: &'static [Axis] = &[Axis::X, Axis::Y, Axis::Z];
+
+  pub fn get_closest(env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::util::Axis {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Axis", "getClosest", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/Axis;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Axis#getClosest was null") }
+    <::sponge::util::Axis as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn is_vector_along_axis(&self, env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "isVectorAlongAxis", "(Lcom/flowpowered/math/vector/Vector3d;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3d, env)) != 0
+  }
+
+  pub fn get_component(&self, env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> f64 {
+    bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "getComponent", "(Lcom/flowpowered/math/vector/Vector3d;)D", CallDoubleMethodA, ::convert::ToJava::to_java(&vector_3d, env))
+  }
+
+  pub fn get_direction(&self, env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::util::AxisDirection {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "getDirection", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/AxisDirection;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Axis#getDirection was null") }
+    <::sponge::util::AxisDirection as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn to_vector_3d(&self, env: *mut JNIEnv) -> ::math::Vector3d {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "toVector3d", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Axis#toVector3d was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn to_vector_3d_with_direction(&self, env: *mut JNIEnv, axis_direction: ::sponge::util::AxisDirection) -> ::math::Vector3d {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "toVector3dWithDirection", "(Lorg/spongepowered/api/util/AxisDirection;)Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethodA, ::convert::ToJava::to_java(&axis_direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Axis#toVector3dWithDirection was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn cycle_next(&self, env: *mut JNIEnv) -> ::sponge::util::Axis {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Axis", ::convert::ToJava::to_java(self, env), "cycleNext", "()Lorg/spongepowered/api/util/Axis;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Axis#cycleNext was null") }
+    <::sponge::util::Axis as ::convert::FromJava>::from_java(env, ret)
+  }
+
+}
+
+impl ::convert::FromJava for Axis {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match AXIS_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => Axis::VALUES[ordinal],
+      None => Axis::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for Axis {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      Axis::Other(object) => object,
+      value => AXIS_CONSTANTS.get(env, Axis::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AxisDirection {
+  Plus,
+  Zero,
+  Minus,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static AXIS_DIRECTION_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/AxisDirection", &["PLUS", "ZERO", "MINUS"]);
+
+impl AxisDirection {
+  pub const VALUES: &'static [AxisDirection] = &[AxisDirection::Plus, AxisDirection::Zero, AxisDirection::Minus];
+
+  pub fn get_signum(&self, env: *mut JNIEnv) -> i32 {
+    bound_method!(env, "org/spongepowered/api/util/AxisDirection", ::convert::ToJava::to_java(self, env), "getSignum", "()I", CallIntMethod)
+  }
+
+}
+
+impl ::convert::FromJava for AxisDirection {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match AXIS_DIRECTION_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => AxisDirection::VALUES[ordinal],
+      None => AxisDirection::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for AxisDirection {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      AxisDirection::Other(object) => object,
+      value => AXIS_DIRECTION_CONSTANTS.get(env, AxisDirection::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Coerce {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Coerce {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Coerce {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Coerce`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Coerce", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn to_string(env: *mut JNIEnv, object: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toString", "(Ljava/lang/Object;)Ljava/lang/String;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toString was null") }
+    ret
+  }
+
+  pub fn as_string(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asString", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asString was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_list(env: *mut JNIEnv, object: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toList", "(Ljava/lang/Object;)Ljava/util/List;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toList was null") }
+    ret
+  }
+
+  pub fn as_list(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asList", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asList was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_list_of(env: *mut JNIEnv, object: jobject, class: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toListOf", "(Ljava/lang/Object;Ljava/lang/Class;)Ljava/util/List;", CallStaticObjectMethodA, object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toListOf was null") }
+    ret
+  }
+
+  pub fn to_boolean(env: *mut JNIEnv, object: jobject) -> bool {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toBoolean", "(Ljava/lang/Object;)Z", CallStaticBooleanMethodA, object) != 0
+  }
+
+  pub fn as_boolean(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asBoolean", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asBoolean was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_integer(env: *mut JNIEnv, object: jobject) -> i32 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toInteger", "(Ljava/lang/Object;)I", CallStaticIntMethodA, object)
+  }
+
+  pub fn as_integer(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asInteger", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asInteger was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_double(env: *mut JNIEnv, object: jobject) -> f64 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toDouble", "(Ljava/lang/Object;)D", CallStaticDoubleMethodA, object)
+  }
+
+  pub fn as_double(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asDouble", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asDouble was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_float(env: *mut JNIEnv, object: jobject) -> f32 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toFloat", "(Ljava/lang/Object;)F", CallStaticFloatMethodA, object)
+  }
+
+  pub fn as_float(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asFloat", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asFloat was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_short(env: *mut JNIEnv, object: jobject) -> i16 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toShort", "(Ljava/lang/Object;)S", CallStaticShortMethodA, object)
+  }
+
+  pub fn as_short(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asShort", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asShort was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_byte(env: *mut JNIEnv, object: jobject) -> i8 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toByte", "(Ljava/lang/Object;)B", CallStaticByteMethodA, object)
+  }
+
+  pub fn as_byte(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asByte", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asByte was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_long(env: *mut JNIEnv, object: jobject) -> i64 {
+    static_java_method!(env, "org/spongepowered/api/util/Coerce", "toLong", "(Ljava/lang/Object;)J", CallStaticLongMethodA, object)
+  }
+
+  pub fn as_long(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asLong", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asLong was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_char(env: *mut JNIEnv, object: jobject) -> char {
+    ::convert::char_from_java(static_java_method!(env, "org/spongepowered/api/util/Coerce", "toChar", "(Ljava/lang/Object;)C", CallStaticCharMethodA, object))
+  }
+
+  pub fn as_char(env: *mut JNIEnv, object: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "asChar", "(Ljava/lang/Object;)Ljava/util/Optional;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#asChar was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_enum_object_class(env: *mut JNIEnv, object: jobject, class: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toEnum", "(Ljava/lang/Object;Ljava/lang/Class;)Ljava/lang/Enum;", CallStaticObjectMethodA, object, class);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toEnum was null") }
+    ret
+  }
+
+  pub fn to_enum_object_class_enum(env: *mut JNIEnv, object: jobject, class: jobject, enum_: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toEnum", "(Ljava/lang/Object;Ljava/lang/Class;Ljava/lang/Enum;)Ljava/lang/Enum;", CallStaticObjectMethodA, object, class, enum_);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toEnum was null") }
+    ret
+  }
+
+  pub fn to_pseudo_enum(env: *mut JNIEnv, object: jobject, class: jobject, class_2: jobject, object_2: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toPseudoEnum", "(Ljava/lang/Object;Ljava/lang/Class;Ljava/lang/Class;Ljava/lang/Object;)Ljava/lang/Object;", CallStaticObjectMethodA, object, class, class_2, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toPseudoEnum was null") }
+    ret
+  }
+
+  pub fn to_vector_2i(env: *mut JNIEnv, object: jobject) -> ::math::Vector2i {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Coerce", "toVector2i", "(Ljava/lang/Object;)Lcom/flowpowered/math/vector/Vector2i;", CallStaticObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Coerce#toVector2i was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(env, ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct CollectionUtils {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl CollectionUtils {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    CollectionUtils {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `CollectionUtils`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/CollectionUtils", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn copy_map(env: *mut JNIEnv, map: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/CollectionUtils", "copyMap", "(Ljava/util/Map;)Ljava/util/Map;", CallStaticObjectMethodA, map);
+    if ret.is_null() { panic!("org/spongepowered/api/util/CollectionUtils#copyMap was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Color {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Color {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Color {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Color`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Color", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of_rgb_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "ofRgb", "(I)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#ofRgb was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  pub fn of_rgb_i32_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32, value_3: i32) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "ofRgb", "(III)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#ofRgb was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  pub fn of_color(env: *mut JNIEnv, color: jobject) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "of", "(Ljava/awt/Color;)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, color);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#of was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  pub fn of_vector_3i(env: *mut JNIEnv, vector_3i: ::math::Vector3i) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "of", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#of was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  pub fn of_vector_3f(env: *mut JNIEnv, vector_3f: jobject) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "of", "(Lcom/flowpowered/math/vector/Vector3f;)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, vector_3f);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#of was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  pub fn of_vector_3d(env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::util::Color {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Color", "of", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/Color;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#of was null") }
+    ::sponge::util::Color { env: env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn mix_dye_colors(env: *mut JNIEnv, dye_colors: Vec<::sponge::data::type_::DyeColor>) -> ::sponge::util::Color {
+    unimplemented!();
+  }
+  pub fn mix_colors(env: *mut JNIEnv, colors: Vec<::sponge::util::Color>) -> ::sponge::util::Color {
+    unimplemented!();
+  }
+  pub fn get_red(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "getRed", "()I", CallIntMethod)
+  }
+
+  pub fn with_red(&self, value: i32) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "withRed", "(I)Lorg/spongepowered/api/util/Color;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#withRed was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+  pub fn get_green(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "getGreen", "()I", CallIntMethod)
+  }
+
+  pub fn with_green(&self, value: i32) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "withGreen", "(I)Lorg/spongepowered/api/util/Color;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#withGreen was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+  pub fn get_blue(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "getBlue", "()I", CallIntMethod)
+  }
+
+  pub fn with_blue(&self, value: i32) -> ::sponge::util::Color {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "withBlue", "(I)Lorg/spongepowered/api/util/Color;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#withBlue was null") }
+    ::sponge::util::Color { env: self.env, object: ret }
+  }
+
+  pub fn as_java_color(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "asJavaColor", "()Ljava/awt/Color;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#asJavaColor was null") }
+    ret
+  }
+
+  pub fn get_rgb(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "getRgb", "()I", CallIntMethod)
+  }
+
+  pub fn mix_with_colors(&self, colors: Vec<::sponge::util::Color>) -> ::sponge::util::Color {
+    unimplemented!();
+  }
+  #[cfg(feature = "data")]
+  pub fn mix_with_dyes(&self, dye_colors: Vec<::sponge::data::type_::DyeColor>) -> ::sponge::util::Color {
+    unimplemented!();
+  }
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Color", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Color#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Cycleable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Cycleable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Cycleable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Cycleable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Cycleable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn cycle_next(&self) -> ::sponge::util::Cycleable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Cycleable", self.object, "cycleNext", "()Lorg/spongepowered/api/util/Cycleable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Cycleable#cycleNext was null") }
+    ::sponge::util::Cycleable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+  North,
+  NorthNortheast,
+  Northeast,
+  EastNortheast,
+  East,
+  EastSoutheast,
+  Southeast,
+  SouthSoutheast,
+  South,
+  SouthSouthwest,
+  Southwest,
+  WestSouthwest,
+  West,
+  WestNorthwest,
+  Northwest,
+  NorthNorthwest,
+  Up,
+  Down,
+  None,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static DIRECTION_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/Direction", &["NORTH", "NORTH_NORTHEAST", "NORTHEAST", "EAST_NORTHEAST", "EAST", "EAST_SOUTHEAST", "SOUTHEAST", "SOUTH_SOUTHEAST", "SOUTH", "SOUTH_SOUTHWEST", "SOUTHWEST", "WEST_SOUTHWEST", "WEST", "WEST_NORTHWEST", "NORTHWEST", "NORTH_NORTHWEST", "UP", "DOWN", "NONE"]);
+
+impl Direction {
+  pub const VALUES: &'static [Direction] = &[Direction::North, Direction::NorthNortheast, Direction::Northeast, Direction::EastNortheast, Direction::East, Direction::EastSoutheast, Direction::Southeast, Direction::SouthSoutheast, Direction::South, Direction::SouthSouthwest, Direction::Southwest, Direction::WestSouthwest, Direction::West, Direction::WestNorthwest, Direction::Northwest, Direction::NorthNorthwest, Direction::Up, Direction::Down, Direction::None];
+
+  pub fn get_closest_vector_3d(env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getClosest", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getClosest was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_closest_vector_3d_division(env: *mut JNIEnv, vector_3d: ::math::Vector3d, division: ::sponge::util::direction::Division) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getClosest", "(Lcom/flowpowered/math/vector/Vector3d;Lorg/spongepowered/api/util/Direction$Division;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&division, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getClosest was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_closest_horizontal_vector_3d(env: *mut JNIEnv, vector_3d: ::math::Vector3d) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getClosestHorizontal", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getClosestHorizontal was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_closest_horizontal_vector_3d_division(env: *mut JNIEnv, vector_3d: ::math::Vector3d, division: ::sponge::util::direction::Division) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getClosestHorizontal", "(Lcom/flowpowered/math/vector/Vector3d;Lorg/spongepowered/api/util/Direction$Division;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&division, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getClosestHorizontal was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_from_axis_axis(env: *mut JNIEnv, axis: ::sponge::util::Axis) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getFromAxis", "(Lorg/spongepowered/api/util/Axis;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&axis, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getFromAxis was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_from_axis_axis_axis_direction(env: *mut JNIEnv, axis: ::sponge::util::Axis, axis_direction: ::sponge::util::AxisDirection) -> ::sponge::util::Direction {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Direction", "getFromAxis", "(Lorg/spongepowered/api/util/Axis;Lorg/spongepowered/api/util/AxisDirection;)Lorg/spongepowered/api/util/Direction;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&axis, env), ::convert::ToJava::to_java(&axis_direction, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getFromAxis was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn get_opposite(&self, env: *mut JNIEnv) -> ::sponge::util::Direction {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "getOpposite", "()Lorg/spongepowered/api/util/Direction;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#getOpposite was null") }
+    <::sponge::util::Direction as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn is_opposite(&self, env: *mut JNIEnv, direction: ::sponge::util::Direction) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "isOpposite", "(Lorg/spongepowered/api/util/Direction;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&direction, env)) != 0
+  }
+
+  pub fn is_cardinal(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "isCardinal", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_ordinal(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "isOrdinal", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_secondary_ordinal(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "isSecondaryOrdinal", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_upright(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "isUpright", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn to_vector_3d(&self, env: *mut JNIEnv) -> ::math::Vector3d {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "toVector3d", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#toVector3d was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn as_offset(&self, env: *mut JNIEnv) -> ::math::Vector3d {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "asOffset", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#asOffset was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn as_block_offset(&self, env: *mut JNIEnv) -> ::math::Vector3i {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Direction", ::convert::ToJava::to_java(self, env), "asBlockOffset", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Direction#asBlockOffset was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(env, ret)
+  }
+
+}
+
+impl ::convert::FromJava for Direction {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match DIRECTION_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => Direction::VALUES[ordinal],
+      None => Direction::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for Direction {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      Direction::Other(object) => object,
+      value => DIRECTION_CONSTANTS.get(env, Direction::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct DiscreteTransform2 {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DiscreteTransform2 {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DiscreteTransform2 {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DiscreteTransform2`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/DiscreteTransform2", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_matrix(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "getMatrix", "()Lcom/flowpowered/math/matrix/Matrix3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#getMatrix was null") }
+    ret
+  }
+
+  pub fn transform_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transform", "(Lcom/flowpowered/math/vector/Vector2i;)Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#transform was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn transform_i32_i32(&self, value: i32, value_2: i32) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transform", "(II)Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#transform was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn transform_x_vector_2i(&self, vector_2i: ::math::Vector2i) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transformX", "(Lcom/flowpowered/math/vector/Vector2i;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_2i, self.env))
+  }
+
+  pub fn transform_x_i32_i32(&self, value: i32, value_2: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transformX", "(II)I", CallIntMethodA, value, value_2)
+  }
+
+  pub fn transform_y_vector_2i(&self, vector_2i: ::math::Vector2i) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transformY", "(Lcom/flowpowered/math/vector/Vector2i;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_2i, self.env))
+  }
+
+  pub fn transform_y_i32_i32(&self, value: i32, value_2: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "transformY", "(II)I", CallIntMethodA, value, value_2)
+  }
+
+  pub fn invert(&self) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "invert", "()Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#invert was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn compose(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "compose", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#compose was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn and_then(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "andThen", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#andThen was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_translation_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withTranslation", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withTranslation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_translation_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withTranslation", "(II)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withTranslation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_i32(&self, value: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withScale", "(I)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withScale", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withScale", "(II)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32(&self, value: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withRotation", "(I)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32_vector_2i_bool(&self, value: i32, vector_2i: ::math::Vector2i, value_2: bool) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withRotation", "(ILcom/flowpowered/math/vector/Vector2i;Z)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value, ::convert::ToJava::to_java(&vector_2i, self.env), value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32_vector_2i_bool_bool(&self, value: i32, vector_2i: ::math::Vector2i, value_2: bool, value_3: bool) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withRotation", "(ILcom/flowpowered/math/vector/Vector2i;ZZ)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, value, ::convert::ToJava::to_java(&vector_2i, self.env), value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn with_transformation(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::util::DiscreteTransform2 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform2", self.object, "withTransformation", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#withTransformation was null") }
+    ::sponge::util::DiscreteTransform2 { env: self.env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, matrix_3d: jobject) -> Option<::sponge::util::DiscreteTransform2> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "of", "(Lcom/flowpowered/math/matrix/Matrix3d;)Ljava/util/Optional;", CallStaticObjectMethodA, matrix_3d);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#of was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::DiscreteTransform2 { env: env, object: unwrapped }) }
+  }
+
+  pub fn from_translation_vector_2i(env: *mut JNIEnv, vector_2i: ::math::Vector2i) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromTranslation", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_2i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromTranslation was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_translation_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromTranslation", "(II)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromTranslation was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_scale_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromScale", "(I)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_scale_vector_2i(env: *mut JNIEnv, vector_2i: ::math::Vector2i) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromScale", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_2i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_scale_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromScale", "(II)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromScale was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromRotation", "(I)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32_vector_2i_bool(env: *mut JNIEnv, value: i32, vector_2i: ::math::Vector2i, value_2: bool) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromRotation", "(ILcom/flowpowered/math/vector/Vector2i;Z)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&vector_2i, env), value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32_vector_2i_bool_bool(env: *mut JNIEnv, value: i32, vector_2i: ::math::Vector2i, value_2: bool, value_3: bool) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "fromRotation", "(ILcom/flowpowered/math/vector/Vector2i;ZZ)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&vector_2i, env), value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#fromRotation was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+  pub fn rotation_around_center(env: *mut JNIEnv, value: i32, vector_2i: ::math::Vector2i) -> ::sponge::util::DiscreteTransform2 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform2", "rotationAroundCenter", "(ILcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/util/DiscreteTransform2;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&vector_2i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform2#rotationAroundCenter was null") }
+    ::sponge::util::DiscreteTransform2 { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DiscreteTransform3 {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DiscreteTransform3 {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DiscreteTransform3 {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DiscreteTransform3`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/DiscreteTransform3", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_matrix(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "getMatrix", "()Lcom/flowpowered/math/matrix/Matrix4d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#getMatrix was null") }
+    ret
+  }
+
+  pub fn transform_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transform", "(Lcom/flowpowered/math/vector/Vector3i;)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#transform was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn transform_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transform", "(III)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#transform was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn transform_x_vector_3i(&self, vector_3i: ::math::Vector3i) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformX", "(Lcom/flowpowered/math/vector/Vector3i;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_3i, self.env))
+  }
+
+  pub fn transform_x_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformX", "(III)I", CallIntMethodA, value, value_2, value_3)
+  }
+
+  pub fn transform_y_vector_3i(&self, vector_3i: ::math::Vector3i) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformY", "(Lcom/flowpowered/math/vector/Vector3i;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_3i, self.env))
+  }
+
+  pub fn transform_y_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformY", "(III)I", CallIntMethodA, value, value_2, value_3)
+  }
+
+  pub fn transform_z_vector_3i(&self, vector_3i: ::math::Vector3i) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformZ", "(Lcom/flowpowered/math/vector/Vector3i;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_3i, self.env))
+  }
+
+  pub fn transform_z_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "transformZ", "(III)I", CallIntMethodA, value, value_2, value_3)
+  }
+
+  pub fn invert(&self) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "invert", "()Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#invert was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn compose(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "compose", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#compose was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn and_then(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "andThen", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#andThen was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_translation_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withTranslation", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withTranslation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_translation_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withTranslation", "(III)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withTranslation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_i32(&self, value: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withScale", "(I)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withScale", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_scale_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withScale", "(III)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32_axis(&self, value: i32, axis: ::sponge::util::Axis) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withRotation", "(ILorg/spongepowered/api/util/Axis;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value, ::convert::ToJava::to_java(&axis, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32_axis_vector_3i_bool(&self, value: i32, axis: ::sponge::util::Axis, vector_3i: ::math::Vector3i, value_2: bool) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withRotation", "(ILorg/spongepowered/api/util/Axis;Lcom/flowpowered/math/vector/Vector3i;Z)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value, ::convert::ToJava::to_java(&axis, self.env), ::convert::ToJava::to_java(&vector_3i, self.env), value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_rotation_i32_axis_vector_3i_bool_bool_bool(&self, value: i32, axis: ::sponge::util::Axis, vector_3i: ::math::Vector3i, value_2: bool, value_3: bool, value_4: bool) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withRotation", "(ILorg/spongepowered/api/util/Axis;Lcom/flowpowered/math/vector/Vector3i;ZZZ)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, value, ::convert::ToJava::to_java(&axis, self.env), ::convert::ToJava::to_java(&vector_3i, self.env), value_2, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn with_transformation(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::util::DiscreteTransform3 {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/DiscreteTransform3", self.object, "withTransformation", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#withTransformation was null") }
+    ::sponge::util::DiscreteTransform3 { env: self.env, object: ret }
+  }
+
+  pub fn of(env: *mut JNIEnv, matrix_4d: jobject) -> Option<::sponge::util::DiscreteTransform3> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "of", "(Lcom/flowpowered/math/matrix/Matrix4d;)Ljava/util/Optional;", CallStaticObjectMethodA, matrix_4d);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#of was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::DiscreteTransform3 { env: env, object: unwrapped }) }
+  }
+
+  pub fn from_translation_vector_3i(env: *mut JNIEnv, vector_3i: ::math::Vector3i) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromTranslation", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromTranslation was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_translation_i32_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32, value_3: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromTranslation", "(III)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromTranslation was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_scale_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromScale", "(I)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_scale_vector_3i(env: *mut JNIEnv, vector_3i: ::math::Vector3i) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromScale", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, ::convert::ToJava::to_java(&vector_3i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_scale_i32_i32_i32(env: *mut JNIEnv, value: i32, value_2: i32, value_3: i32) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromScale", "(III)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromScale was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32_axis(env: *mut JNIEnv, value: i32, axis: ::sponge::util::Axis) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromRotation", "(ILorg/spongepowered/api/util/Axis;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&axis, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32_axis_vector_3i_bool(env: *mut JNIEnv, value: i32, axis: ::sponge::util::Axis, vector_3i: ::math::Vector3i, value_2: bool) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromRotation", "(ILorg/spongepowered/api/util/Axis;Lcom/flowpowered/math/vector/Vector3i;Z)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&axis, env), ::convert::ToJava::to_java(&vector_3i, env), value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn from_rotation_i32_axis_vector_3i_bool_bool_bool(env: *mut JNIEnv, value: i32, axis: ::sponge::util::Axis, vector_3i: ::math::Vector3i, value_2: bool, value_3: bool, value_4: bool) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "fromRotation", "(ILorg/spongepowered/api/util/Axis;Lcom/flowpowered/math/vector/Vector3i;ZZZ)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&axis, env), ::convert::ToJava::to_java(&vector_3i, env), value_2, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#fromRotation was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+  pub fn rotation_around_center(env: *mut JNIEnv, value: i32, axis: ::sponge::util::Axis, vector_3i: ::math::Vector3i) -> ::sponge::util::DiscreteTransform3 {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/DiscreteTransform3", "rotationAroundCenter", "(ILorg/spongepowered/api/util/Axis;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/util/DiscreteTransform3;", CallStaticObjectMethodA, value, ::convert::ToJava::to_java(&axis, env), ::convert::ToJava::to_java(&vector_3i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/DiscreteTransform3#rotationAroundCenter was null") }
+    ::sponge::util::DiscreteTransform3 { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Functional {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Functional {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Functional {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Functional`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Functional", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn predicate_and_object_array(env: *mut JNIEnv, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+  pub fn predicate_and_iterable(env: *mut JNIEnv, iterable: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "predicateAnd", "(Ljava/lang/Iterable;)Ljava/util/function/Predicate;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#predicateAnd was null") }
+    ret
+  }
+
+  pub fn bi_predicate_and_object_array(env: *mut JNIEnv, objects: Vec<jobject>) -> jobject {
+    unimplemented!();
+  }
+  pub fn bi_predicate_and_iterable(env: *mut JNIEnv, iterable: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "biPredicateAnd", "(Ljava/lang/Iterable;)Ljava/util/function/BiPredicate;", CallStaticObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#biPredicateAnd was null") }
+    ret
+  }
+
+  pub fn predicate_in(env: *mut JNIEnv, collection: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "predicateIn", "(Ljava/util/Collection;)Ljava/util/function/Predicate;", CallStaticObjectMethodA, collection);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#predicateIn was null") }
+    ret
+  }
+
+  pub fn java_8_to_guava(env: *mut JNIEnv, predicate: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "java8ToGuava", "(Ljava/util/function/Predicate;)Lcom/google/common/base/Predicate;", CallStaticObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#java8ToGuava was null") }
+    ret
+  }
+
+  pub fn guava_to_java_8(env: *mut JNIEnv, predicate: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "guavaToJava8", "(Lcom/google/common/base/Predicate;)Ljava/util/function/Predicate;", CallStaticObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#guavaToJava8 was null") }
+    ret
+  }
+
+  pub fn optional_as_set(env: *mut JNIEnv, optional: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "optionalAsSet", "(Ljava/util/Optional;)Ljava/util/Set;", CallStaticObjectMethodA, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#optionalAsSet was null") }
+    ret
+  }
+
+  pub fn failable_future(env: *mut JNIEnv, callable: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "failableFuture", "(Ljava/util/concurrent/Callable;)Ljava/util/concurrent/CompletableFuture;", CallStaticObjectMethodA, callable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#failableFuture was null") }
+    ret
+  }
+
+  pub fn async_failable_future(env: *mut JNIEnv, callable: jobject, executor: jobject) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Functional", "asyncFailableFuture", "(Ljava/util/concurrent/Callable;Ljava/util/concurrent/Executor;)Ljava/util/concurrent/CompletableFuture;", CallStaticObjectMethodA, callable, executor);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Functional#asyncFailableFuture was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GuavaCollectors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GuavaCollectors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GuavaCollectors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GuavaCollectors`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/GuavaCollectors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn to_immutable_list(env: *mut JNIEnv) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/GuavaCollectors", "toImmutableList", "()Ljava/util/stream/Collector;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/GuavaCollectors#toImmutableList was null") }
+    ret
+  }
+
+  pub fn to_immutable_set(env: *mut JNIEnv) -> jobject {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/GuavaCollectors", "toImmutableSet", "()Ljava/util/stream/Collector;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/GuavaCollectors#toImmutableSet was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Identifiable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Identifiable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Identifiable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Identifiable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Identifiable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Identifiable", self.object, "getUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Identifiable#getUniqueId was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InformativeMain {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InformativeMain {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InformativeMain {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `InformativeMain`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/InformativeMain", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::InformativeMain {
+    let ret = new_object!(env, "org/spongepowered/api/util/InformativeMain", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/InformativeMain#<init> was null") }
+    ::sponge::util::InformativeMain { env: env, object: ret }
+  }
+
+  pub fn main(env: *mut JNIEnv, objects: Vec<jobject>) {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct OptBool {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl OptBool {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    OptBool {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `OptBool`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/OptBool", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of_bool(env: *mut JNIEnv, value: bool) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/OptBool", "of", "(Z)Ljava/util/Optional;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/OptBool#of was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn of_boolean(env: *mut JNIEnv, boolean: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/OptBool", "of", "(Ljava/lang/Boolean;)Ljava/util/Optional;", CallStaticObjectMethodA, boolean);
+    if ret.is_null() { panic!("org/spongepowered/api/util/OptBool#of was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn of_optional(env: *mut JNIEnv, optional: jobject) -> Option<jobject> {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/OptBool", "of", "(Ljava/util/Optional;)Ljava/util/Optional;", CallStaticObjectMethodA, optional);
+    if ret.is_null() { panic!("org/spongepowered/api/util/OptBool#of was null") }
+    let unwrapped = bound_method!(env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PEBKACException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PEBKACException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PEBKACException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `PEBKACException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/PEBKACException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::PEBKACException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PEBKACException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/PEBKACException#<init> was null") }
+    ::sponge::util::PEBKACException { env: env, object: ret }
+  }
+
+  pub fn new_string(env: *mut JNIEnv, string: jobject) -> ::sponge::util::PEBKACException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PEBKACException", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PEBKACException#<init> was null") }
+    ::sponge::util::PEBKACException { env: env, object: ret }
+  }
+
+  pub fn new_string_throwable(env: *mut JNIEnv, string: jobject, throwable: jobject) -> ::sponge::util::PEBKACException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PEBKACException", "(Ljava/lang/String;Ljava/lang/Throwable;)V", string, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PEBKACException#<init> was null") }
+    ::sponge::util::PEBKACException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::util::PEBKACException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PEBKACException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PEBKACException#<init> was null") }
+    ::sponge::util::PEBKACException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PositionOutOfBoundsException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PositionOutOfBoundsException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PositionOutOfBoundsException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `PositionOutOfBoundsException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/PositionOutOfBoundsException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_vectori_vectori_vectori(env: *mut JNIEnv, vectori: jobject, vectori_2: jobject, vectori_3: jobject) -> ::sponge::util::PositionOutOfBoundsException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PositionOutOfBoundsException", "(Lcom/flowpowered/math/vector/Vectori;Lcom/flowpowered/math/vector/Vectori;Lcom/flowpowered/math/vector/Vectori;)V", vectori, vectori_2, vectori_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PositionOutOfBoundsException#<init> was null") }
+    ::sponge::util::PositionOutOfBoundsException { env: env, object: ret }
+  }
+
+  pub fn new_vectorf_vectorf_vectorf(env: *mut JNIEnv, vectorf: jobject, vectorf_2: jobject, vectorf_3: jobject) -> ::sponge::util::PositionOutOfBoundsException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PositionOutOfBoundsException", "(Lcom/flowpowered/math/vector/Vectorf;Lcom/flowpowered/math/vector/Vectorf;Lcom/flowpowered/math/vector/Vectorf;)V", vectorf, vectorf_2, vectorf_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PositionOutOfBoundsException#<init> was null") }
+    ::sponge::util::PositionOutOfBoundsException { env: env, object: ret }
+  }
+
+  pub fn new_vectord_vectord_vectord(env: *mut JNIEnv, vectord: jobject, vectord_2: jobject, vectord_3: jobject) -> ::sponge::util::PositionOutOfBoundsException {
+    let ret = new_object!(env, "org/spongepowered/api/util/PositionOutOfBoundsException", "(Lcom/flowpowered/math/vector/Vectord;Lcom/flowpowered/math/vector/Vectord;Lcom/flowpowered/math/vector/Vectord;)V", vectord, vectord_2, vectord_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/PositionOutOfBoundsException#<init> was null") }
+    ::sponge::util::PositionOutOfBoundsException { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RelativePositions {
+  X,
+  Y,
+  Z,
+  Pitch,
+  Yaw,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static RELATIVE_POSITIONS_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/RelativePositions", &["X", "Y", "Z", "PITCH", "YAW"]);
+
+impl RelativePositions {
+  pub const VALUES: &'static [RelativePositions] = &[RelativePositions::X, RelativePositions::Y, RelativePositions::Z, RelativePositions::Pitch, RelativePositions::Yaw];
+
+}
+
+impl ::convert::FromJava for RelativePositions {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match RELATIVE_POSITIONS_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => RelativePositions::VALUES[ordinal],
+      None => RelativePositions::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for RelativePositions {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      RelativePositions::Other(object) => object,
+      value => RELATIVE_POSITIONS_CONSTANTS.get(env, RelativePositions::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct ResettableBuilder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ResettableBuilder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ResettableBuilder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ResettableBuilder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/ResettableBuilder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ResettableBuilder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ResettableBuilder#from was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+  pub fn reset(&self) -> ::sponge::util::ResettableBuilder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/ResettableBuilder", self.object, "reset", "()Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/ResettableBuilder#reset was null") }
+    ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RespawnLocation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RespawnLocation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RespawnLocation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RespawnLocation`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/RespawnLocation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::util::respawn_location::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/RespawnLocation", "builder", "()Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#builder was null") }
+    ::sponge::util::respawn_location::Builder { env: env, object: ret }
+  }
+
+  pub fn get_world_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "getWorldUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#getWorldUniqueId was null") }
+    ret
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#getPosition was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn is_forced(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "isForced", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "world")]
+  pub fn as_location(&self) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "asLocation", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#asLocation was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SpongeApiTranslationHelper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SpongeApiTranslationHelper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SpongeApiTranslationHelper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SpongeApiTranslationHelper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/SpongeApiTranslationHelper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "text")]
+  pub fn t(env: *mut JNIEnv, object: jobject, objects: Vec<jobject>) -> ::sponge::text::Text {
+    unimplemented!();
+  }
+}
+
+#[derive(Debug)]
+pub struct StartsWithPredicate {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl StartsWithPredicate {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    StartsWithPredicate {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `StartsWithPredicate`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/StartsWithPredicate", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, string: jobject) -> ::sponge::util::StartsWithPredicate {
+    let ret = new_object!(env, "org/spongepowered/api/util/StartsWithPredicate", "(Ljava/lang/String;)V", string);
+    if ret.is_null() { panic!("org/spongepowered/api/util/StartsWithPredicate#<init> was null") }
+    ::sponge::util::StartsWithPredicate { env: env, object: ret }
+  }
+
+  pub fn test_string(&self, string: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/StartsWithPredicate", self.object, "test", "(Ljava/lang/String;)Z", CallBooleanMethodA, string) != 0
+  }
+
+  pub fn test_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/StartsWithPredicate", self.object, "test", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TextMessageException {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TextMessageException {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TextMessageException {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TextMessageException`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/TextMessageException", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::TextMessageException {
+    let ret = new_object!(env, "org/spongepowered/api/util/TextMessageException", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#<init> was null") }
+    ::sponge::util::TextMessageException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text(env: *mut JNIEnv, text: ::sponge::text::Text) -> ::sponge::util::TextMessageException {
+    let ret = new_object!(env, "org/spongepowered/api/util/TextMessageException", "(Lorg/spongepowered/api/text/Text;)V", text.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#<init> was null") }
+    ::sponge::util::TextMessageException { env: env, object: ret }
+  }
+
+  #[cfg(feature = "text")]
+  pub fn new_text_throwable(env: *mut JNIEnv, text: ::sponge::text::Text, throwable: jobject) -> ::sponge::util::TextMessageException {
+    let ret = new_object!(env, "org/spongepowered/api/util/TextMessageException", "(Lorg/spongepowered/api/text/Text;Ljava/lang/Throwable;)V", text.object, throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#<init> was null") }
+    ::sponge::util::TextMessageException { env: env, object: ret }
+  }
+
+  pub fn new_throwable(env: *mut JNIEnv, throwable: jobject) -> ::sponge::util::TextMessageException {
+    let ret = new_object!(env, "org/spongepowered/api/util/TextMessageException", "(Ljava/lang/Throwable;)V", throwable);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#<init> was null") }
+    ::sponge::util::TextMessageException { env: env, object: ret }
+  }
+
+  pub fn get_message(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/TextMessageException", self.object, "getMessage", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#getMessage was null") }
+    ret
+  }
+
+  #[cfg(feature = "text")]
+  pub fn get_text(&self) -> ::sponge::text::Text {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/TextMessageException", self.object, "getText", "()Lorg/spongepowered/api/text/Text;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#getText was null") }
+    ::sponge::text::Text { env: self.env, object: ret }
+  }
+
+  pub fn get_localized_message(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/TextMessageException", self.object, "getLocalizedMessage", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/TextMessageException#getLocalizedMessage was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tristate {
+  True,
+  False,
+  Undefined,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static TRISTATE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/Tristate", &["TRUE", "FALSE", "UNDEFINED"]);
+
+impl Tristate {
+  pub const VALUES: &'static [Tristate] = &[Tristate::True, Tristate::False, Tristate::Undefined];
+
+  pub fn from_boolean(env: *mut JNIEnv, value: bool) -> ::sponge::util::Tristate {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Tristate", "fromBoolean", "(Z)Lorg/spongepowered/api/util/Tristate;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tristate#fromBoolean was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn and(&self, env: *mut JNIEnv, tristate: ::sponge::util::Tristate) -> ::sponge::util::Tristate {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Tristate", ::convert::ToJava::to_java(self, env), "and", "(Lorg/spongepowered/api/util/Tristate;)Lorg/spongepowered/api/util/Tristate;", CallObjectMethodA, ::convert::ToJava::to_java(&tristate, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tristate#and was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn or(&self, env: *mut JNIEnv, tristate: ::sponge::util::Tristate) -> ::sponge::util::Tristate {
+    let ret = bound_method!(env, "org/spongepowered/api/util/Tristate", ::convert::ToJava::to_java(self, env), "or", "(Lorg/spongepowered/api/util/Tristate;)Lorg/spongepowered/api/util/Tristate;", CallObjectMethodA, ::convert::ToJava::to_java(&tristate, env));
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tristate#or was null") }
+    <::sponge::util::Tristate as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn as_boolean(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/util/Tristate", ::convert::ToJava::to_java(self, env), "asBoolean", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+impl ::convert::FromJava for Tristate {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match TRISTATE_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => Tristate::VALUES[ordinal],
+      None => Tristate::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for Tristate {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      Tristate::Other(object) => object,
+      value => TRISTATE_CONSTANTS.get(env, Tristate::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Tuple {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Tuple {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Tuple {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Tuple`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Tuple", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn of(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::util::Tuple {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/Tuple", "of", "(Ljava/lang/Object;Ljava/lang/Object;)Lorg/spongepowered/api/util/Tuple;", CallStaticObjectMethodA, object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tuple#of was null") }
+    ::sponge::util::Tuple { env: env, object: ret }
+  }
+
+  pub fn new(env: *mut JNIEnv, object: jobject, object_2: jobject) -> ::sponge::util::Tuple {
+    let ret = new_object!(env, "org/spongepowered/api/util/Tuple", "(Ljava/lang/Object;Ljava/lang/Object;)V", object, object_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tuple#<init> was null") }
+    ::sponge::util::Tuple { env: env, object: ret }
+  }
+
+  pub fn get_first(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Tuple", self.object, "getFirst", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tuple#getFirst was null") }
+    ret
+  }
+
+  pub fn get_second(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Tuple", self.object, "getSecond", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tuple#getSecond was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Tuple", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Tuple#toString was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Tuple", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/Tuple", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Updater {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Updater {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Updater {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Updater`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Updater", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_input_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Updater", self.object, "getInputVersion", "()I", CallIntMethod)
+  }
+
+  pub fn get_output_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/Updater", self.object, "getOutputVersion", "()I", CallIntMethod)
+  }
+
+  pub fn update(&self, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/Updater", self.object, "update", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/Updater#update was null") }
+    ret
+  }
+
+}
+
+pub mod color {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Color$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv) -> ::sponge::util::color::Builder {
+      let ret = new_object!(env, "org/spongepowered/api/util/Color$Builder", "()V");
+      if ret.is_null() { panic!("org/spongepowered/api/util/Color$Builder#<init> was null") }
+      ::sponge::util::color::Builder { env: env, object: ret }
+    }
+
+  }
+}
+
+pub mod direction {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct C {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl C {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      C {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `C`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/Direction$C", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Division {
+    Cardinal,
+    Ordinal,
+    SecondaryOrdinal,
+    None,
+    /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+    Other(jobject)
+  }
+
+  static DIVISION_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/util/Direction$Division", &["CARDINAL", "ORDINAL", "SECONDARY_ORDINAL", "NONE"]);
+
+  impl Division {
+    pub const VALUES: &'static [Division] = &[Division::Cardinal, Division::Ordinal, Division::SecondaryOrdinal, Division::None];
+
+  }
+
+  impl ::convert::FromJava for Division {
+    fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+      match DIVISION_CONSTANTS.ordinal(env, object) {
+        Some(ordinal) => Division::VALUES[ordinal],
+        None => Division::Other(object)
+      }
+    }
+  }
+
+  impl ::convert::ToJava for Division {
+    fn to_java(&self, env: *mut JNIEnv) -> jobject {
+      match *self {
+        Division::Other(object) => object,
+        value => DIVISION_CONSTANTS.get(env, Division::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+      }
+    }
+  }
+}
+
+pub mod respawn_location {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/RespawnLocation$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv) -> ::sponge::util::respawn_location::Builder {
+      let ret = new_object!(env, "org/spongepowered/api/util/RespawnLocation$Builder", "()V");
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#<init> was null") }
+      ::sponge::util::respawn_location::Builder { env: env, object: ret }
+    }
+
+    #[cfg(feature = "world")]
+    pub fn world_world(&self, world: ::sponge::world::World) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "world", "(Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, world.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#world was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn world_uuid(&self, uuid: jobject) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "world", "(Ljava/util/UUID;)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, uuid);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#world was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "world")]
+    pub fn location(&self, location: ::sponge::world::Location) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "location", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#location was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn position(&self, vector_3d: ::math::Vector3d) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "position", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#position was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn force_spawn(&self, value: bool) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "forceSpawn", "(Z)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#forceSpawn was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn reset(&self) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "reset", "()Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#reset was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_respawn_location(&self, respawn_location: ::sponge::util::RespawnLocation) -> ::sponge::util::respawn_location::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "from", "(Lorg/spongepowered/api/util/RespawnLocation;)Lorg/spongepowered/api/util/RespawnLocation$Builder;", CallObjectMethodA, respawn_location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#from was null") }
+      ::sponge::util::respawn_location::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::util::RespawnLocation {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "build", "()Lorg/spongepowered/api/util/RespawnLocation;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#build was null") }
+      ::sponge::util::RespawnLocation { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn from_data_serializable(&self, data_serializable: ::sponge::data::DataSerializable) -> ::sponge::data::persistence::DataBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "from", "(Lorg/spongepowered/api/data/DataSerializable;)Lorg/spongepowered/api/data/persistence/DataBuilder;", CallObjectMethodA, data_serializable.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#from was null") }
+      ::sponge::data::persistence::DataBuilder { env: self.env, object: ret }
+    }
+
+    pub fn from_object(&self, object: jobject) -> ::sponge::util::ResettableBuilder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/RespawnLocation$Builder", self.object, "from", "(Ljava/lang/Object;)Lorg/spongepowered/api/util/ResettableBuilder;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/RespawnLocation$Builder#from was null") }
+      ::sponge::util::ResettableBuilder { env: self.env, object: ret }
+    }
+
+  }
+}
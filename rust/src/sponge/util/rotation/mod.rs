@@ -0,0 +1,51 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Rotation {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Rotation {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Rotation {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Rotation`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/rotation/Rotation", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_angle(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/rotation/Rotation", self.object, "getAngle", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Rotations {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Rotations {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Rotations {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Rotations`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/rotation/Rotations", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,1042 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChanceTable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChanceTable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChanceTable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `ChanceTable`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/ChanceTable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::weighted::ChanceTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/ChanceTable", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/ChanceTable#<init> was null") }
+    ::sponge::util::weighted::ChanceTable { env: env, object: ret }
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::weighted::ChanceTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/ChanceTable", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/ChanceTable#<init> was null") }
+    ::sponge::util::weighted::ChanceTable { env: env, object: ret }
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/ChanceTable", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/ChanceTable#get was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/ChanceTable", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/ChanceTable", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/ChanceTable", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/ChanceTable#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EmptyObject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EmptyObject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EmptyObject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `EmptyObject`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/EmptyObject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, value: f64) -> ::sponge::util::weighted::EmptyObject {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/EmptyObject", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/EmptyObject#<init> was null") }
+    ::sponge::util::weighted::EmptyObject { env: env, object: ret }
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/EmptyObject", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/EmptyObject", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/EmptyObject#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/EmptyObject", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/EmptyObject", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/EmptyObject", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/EmptyObject#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LootTable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LootTable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LootTable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `LootTable`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/LootTable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::weighted::LootTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/LootTable", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/LootTable#<init> was null") }
+    ::sponge::util::weighted::LootTable { env: env, object: ret }
+  }
+
+  pub fn add_table(&self, random_object_table: ::sponge::util::weighted::RandomObjectTable) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "addTable", "(Lorg/spongepowered/api/util/weighted/RandomObjectTable;)V", CallVoidMethodA, random_object_table.object);
+  }
+
+  pub fn add_all(&self, loot_table: ::sponge::util::weighted::LootTable) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "addAll", "(Lorg/spongepowered/api/util/weighted/LootTable;)V", CallVoidMethodA, loot_table.object);
+  }
+
+  pub fn remove_table(&self, random_object_table: ::sponge::util::weighted::RandomObjectTable) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "removeTable", "(Lorg/spongepowered/api/util/weighted/RandomObjectTable;)Z", CallBooleanMethodA, random_object_table.object) != 0
+  }
+
+  pub fn get_tables(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "getTables", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/LootTable#getTables was null") }
+    ret
+  }
+
+  pub fn clear_pool(&self) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "clearPool", "()V", CallVoidMethod);
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/LootTable#get was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/LootTable", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/LootTable#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NestedTableEntry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NestedTableEntry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NestedTableEntry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `NestedTableEntry`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/NestedTableEntry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, value: f64, random_object_table: ::sponge::util::weighted::RandomObjectTable) -> ::sponge::util::weighted::NestedTableEntry {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/NestedTableEntry", "(DLorg/spongepowered/api/util/weighted/RandomObjectTable;)V", value, random_object_table.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/NestedTableEntry#<init> was null") }
+    ::sponge::util::weighted::NestedTableEntry { env: env, object: ret }
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/NestedTableEntry", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/NestedTableEntry#get was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/NestedTableEntry", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/NestedTableEntry", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/NestedTableEntry", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/NestedTableEntry#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RandomObjectTable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RandomObjectTable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RandomObjectTable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `RandomObjectTable`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/RandomObjectTable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::weighted::RandomObjectTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/RandomObjectTable", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#<init> was null") }
+    ::sponge::util::weighted::RandomObjectTable { env: env, object: ret }
+  }
+
+  pub fn new_variable_amount(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::RandomObjectTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/RandomObjectTable", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#<init> was null") }
+    ::sponge::util::weighted::RandomObjectTable { env: env, object: ret }
+  }
+
+  pub fn get_rolls(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "getRolls", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#getRolls was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  pub fn set_rolls_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "setRolls", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_rolls_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "setRolls", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn add_table_entry(&self, table_entry: ::sponge::util::weighted::TableEntry) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "add", "(Lorg/spongepowered/api/util/weighted/TableEntry;)Z", CallBooleanMethodA, table_entry.object) != 0
+  }
+
+  pub fn add_object_f64(&self, object: jobject, value: f64) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "add", "(Ljava/lang/Object;D)Z", CallBooleanMethodA, object, value) != 0
+  }
+
+  pub fn add_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "addAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn contains(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "contains", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "containsObject", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn contains_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "containsAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn contains_all_objects(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "containsAllObjects", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn is_empty(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "isEmpty", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn remove(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "remove", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn remove_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "removeObject", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn remove_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "removeAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "retainAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn size(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "size", "()I", CallIntMethod)
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#get was null") }
+    ret
+  }
+
+  pub fn get_entries(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "getEntries", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#getEntries was null") }
+    ret
+  }
+
+  pub fn iterator(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "iterator", "()Ljava/util/Iterator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/RandomObjectTable#iterator was null") }
+    ret
+  }
+
+  pub fn to_array(&self) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn to_array_object_array(&self, objects: Vec<jobject>) -> Vec<jobject> {
+    unimplemented!();
+  }
+  pub fn add_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/RandomObjectTable", self.object, "add", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SeededVariableAmount {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SeededVariableAmount {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SeededVariableAmount {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SeededVariableAmount`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/SeededVariableAmount", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fixed(env: *mut JNIEnv, value: f64) -> ::sponge::util::weighted::SeededVariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/SeededVariableAmount", "fixed", "(D)Lorg/spongepowered/api/util/weighted/SeededVariableAmount;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/SeededVariableAmount#fixed was null") }
+    ::sponge::util::weighted::SeededVariableAmount { env: env, object: ret }
+  }
+
+  pub fn wrapped(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::SeededVariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/SeededVariableAmount", "wrapped", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/util/weighted/SeededVariableAmount;", CallStaticObjectMethodA, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/SeededVariableAmount#wrapped was null") }
+    ::sponge::util::weighted::SeededVariableAmount { env: env, object: ret }
+  }
+
+  pub fn get_amount(&self, random: jobject, object: jobject) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount", self.object, "getAmount", "(Ljava/util/Random;Ljava/lang/Object;)D", CallDoubleMethodA, random, object)
+  }
+
+  pub fn get_floored_amount(&self, random: jobject, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount", self.object, "getFlooredAmount", "(Ljava/util/Random;Ljava/lang/Object;)I", CallIntMethodA, random, object)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TableEntry {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TableEntry {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TableEntry {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `TableEntry`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/TableEntry", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, value: f64) -> ::sponge::util::weighted::TableEntry {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/TableEntry", "(D)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/TableEntry#<init> was null") }
+    ::sponge::util::weighted::TableEntry { env: env, object: ret }
+  }
+
+  pub fn get_weight(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/TableEntry", self.object, "getWeight", "()D", CallDoubleMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct VariableAmount {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl VariableAmount {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    VariableAmount {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `VariableAmount`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/VariableAmount", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn fixed(env: *mut JNIEnv, value: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "fixed", "(D)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#fixed was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_variance_f64_f64(env: *mut JNIEnv, value: f64, value_2: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithVariance", "(DD)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithVariance was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_variance_f64_variable_amount(env: *mut JNIEnv, value: f64, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithVariance", "(DLorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithVariance was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_random_addition_f64_f64(env: *mut JNIEnv, value: f64, value_2: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithRandomAddition", "(DD)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithRandomAddition was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_random_addition_f64_variable_amount(env: *mut JNIEnv, value: f64, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithRandomAddition", "(DLorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithRandomAddition was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_optional_variance_f64_f64_f64(env: *mut JNIEnv, value: f64, value_2: f64, value_3: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithOptionalVariance", "(DDD)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithOptionalVariance was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_optional_variance_f64_variable_amount_f64(env: *mut JNIEnv, value: f64, variable_amount: ::sponge::util::weighted::VariableAmount, value_2: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithOptionalVariance", "(DLorg/spongepowered/api/util/weighted/VariableAmount;D)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, variable_amount.object, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithOptionalVariance was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_optional_addition_f64_f64_f64(env: *mut JNIEnv, value: f64, value_2: f64, value_3: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithOptionalAddition", "(DDD)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithOptionalAddition was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn base_with_optional_addition_f64_variable_amount_f64(env: *mut JNIEnv, value: f64, variable_amount: ::sponge::util::weighted::VariableAmount, value_2: f64) -> ::sponge::util::weighted::VariableAmount {
+    let ret = static_java_method!(env, "org/spongepowered/api/util/weighted/VariableAmount", "baseWithOptionalAddition", "(DLorg/spongepowered/api/util/weighted/VariableAmount;D)Lorg/spongepowered/api/util/weighted/VariableAmount;", CallStaticObjectMethodA, value, variable_amount.object, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#baseWithOptionalAddition was null") }
+    ::sponge::util::weighted::VariableAmount { env: env, object: ret }
+  }
+
+  pub fn get_amount(&self, random: jobject) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount", self.object, "getAmount", "(Ljava/util/Random;)D", CallDoubleMethodA, random)
+  }
+
+  pub fn get_floored_amount(&self, random: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount", self.object, "getFlooredAmount", "(Ljava/util/Random;)I", CallIntMethodA, random)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeightedObject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeightedObject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeightedObject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `WeightedObject`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/WeightedObject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv, object: jobject, value: f64) -> ::sponge::util::weighted::WeightedObject {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/WeightedObject", "(Ljava/lang/Object;D)V", object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedObject#<init> was null") }
+    ::sponge::util::weighted::WeightedObject { env: env, object: ret }
+  }
+
+  pub fn get(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedObject", self.object, "get", "()Ljava/lang/Object;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedObject#get was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedObject", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedObject", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedObject", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedObject#toString was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeightedSerializableObject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeightedSerializableObject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeightedSerializableObject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `WeightedSerializableObject`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/WeightedSerializableObject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn new(env: *mut JNIEnv, data_serializable: ::sponge::data::DataSerializable, value: i32) -> ::sponge::util::weighted::WeightedSerializableObject {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/WeightedSerializableObject", "(Lorg/spongepowered/api/data/DataSerializable;I)V", data_serializable.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedSerializableObject#<init> was null") }
+    ::sponge::util::weighted::WeightedSerializableObject { env: env, object: ret }
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedSerializableObject", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedSerializableObject#toString was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedSerializableObject", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedSerializableObject", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedSerializableObject", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedSerializableObject#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeightedTable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeightedTable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeightedTable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `WeightedTable`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/WeightedTable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::util::weighted::WeightedTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/WeightedTable", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#<init> was null") }
+    ::sponge::util::weighted::WeightedTable { env: env, object: ret }
+  }
+
+  pub fn new_i32(env: *mut JNIEnv, value: i32) -> ::sponge::util::weighted::WeightedTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/WeightedTable", "(I)V", value);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#<init> was null") }
+    ::sponge::util::weighted::WeightedTable { env: env, object: ret }
+  }
+
+  pub fn new_variable_amount(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::WeightedTable {
+    let ret = new_object!(env, "org/spongepowered/api/util/weighted/WeightedTable", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#<init> was null") }
+    ::sponge::util::weighted::WeightedTable { env: env, object: ret }
+  }
+
+  pub fn add_table_entry(&self, table_entry: ::sponge::util::weighted::TableEntry) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "add", "(Lorg/spongepowered/api/util/weighted/TableEntry;)Z", CallBooleanMethodA, table_entry.object) != 0
+  }
+
+  pub fn add_object_f64(&self, object: jobject, value: f64) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "add", "(Ljava/lang/Object;D)Z", CallBooleanMethodA, object, value) != 0
+  }
+
+  pub fn add_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "addAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn remove(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "remove", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn remove_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "removeObject", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn remove_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "removeAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn retain_all(&self, collection: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "retainAll", "(Ljava/util/Collection;)Z", CallBooleanMethodA, collection) != 0
+  }
+
+  pub fn clear(&self) {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "clear", "()V", CallVoidMethod);
+  }
+
+  pub fn get(&self, random: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "get", "(Ljava/util/Random;)Ljava/util/List;", CallObjectMethodA, random);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#get was null") }
+    ret
+  }
+
+  pub fn iterator(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "iterator", "()Ljava/util/Iterator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#iterator was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable#toString was null") }
+    ret
+  }
+
+  pub fn add_object(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable", self.object, "add", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+}
+
+pub mod seeded_variable_amount {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct WrappedVariableAmount {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl WrappedVariableAmount {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      WrappedVariableAmount {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `WrappedVariableAmount`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn new(env: *mut JNIEnv, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::util::weighted::seeded_variable_amount::WrappedVariableAmount {
+      let ret = new_object!(env, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount#<init> was null") }
+      ::sponge::util::weighted::seeded_variable_amount::WrappedVariableAmount { env: env, object: ret }
+    }
+
+    pub fn get_amount(&self, random: jobject, object: jobject) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", self.object, "getAmount", "(Ljava/util/Random;Ljava/lang/Object;)D", CallDoubleMethodA, random, object)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount#toString was null") }
+      ret
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/SeededVariableAmount$WrappedVariableAmount", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+  }
+}
+
+pub mod variable_amount {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct BaseAndAddition {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl BaseAndAddition {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      BaseAndAddition {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `BaseAndAddition`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_amount(&self, random: jobject) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "getAmount", "(Ljava/util/Random;)D", CallDoubleMethodA, random)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition#toString was null") }
+      ret
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn to_container(&self) -> ::sponge::data::DataContainer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition#toContainer was null") }
+      ::sponge::data::DataContainer { env: self.env, object: ret }
+    }
+
+    pub fn get_content_version(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndAddition", self.object, "getContentVersion", "()I", CallIntMethod)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct BaseAndVariance {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl BaseAndVariance {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      BaseAndVariance {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `BaseAndVariance`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_amount(&self, random: jobject) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "getAmount", "(Ljava/util/Random;)D", CallDoubleMethodA, random)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance#toString was null") }
+      ret
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn to_container(&self) -> ::sponge::data::DataContainer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance#toContainer was null") }
+      ::sponge::data::DataContainer { env: self.env, object: ret }
+    }
+
+    pub fn get_content_version(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$BaseAndVariance", self.object, "getContentVersion", "()I", CallIntMethod)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct Fixed {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Fixed {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Fixed {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Fixed`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_amount(&self, random: jobject) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "getAmount", "(Ljava/util/Random;)D", CallDoubleMethodA, random)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$Fixed#toString was null") }
+      ret
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn to_container(&self) -> ::sponge::data::DataContainer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$Fixed#toContainer was null") }
+      ::sponge::data::DataContainer { env: self.env, object: ret }
+    }
+
+    pub fn get_content_version(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$Fixed", self.object, "getContentVersion", "()I", CallIntMethod)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct OptionalAmount {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OptionalAmount {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OptionalAmount {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OptionalAmount`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_amount(&self, random: jobject) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "getAmount", "(Ljava/util/Random;)D", CallDoubleMethodA, random)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount#toString was null") }
+      ret
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    #[cfg(feature = "data")]
+    pub fn to_container(&self) -> ::sponge::data::DataContainer {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount#toContainer was null") }
+      ::sponge::data::DataContainer { env: self.env, object: ret }
+    }
+
+    pub fn get_content_version(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/VariableAmount$OptionalAmount", self.object, "getContentVersion", "()I", CallIntMethod)
+    }
+
+  }
+}
+
+pub mod weighted_table {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Itr {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Itr {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Itr {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Itr`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/util/weighted/WeightedTable$Itr", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn has_next(&self) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable$Itr", self.object, "hasNext", "()Z", CallBooleanMethod) != 0
+    }
+
+    pub fn next(&self) -> ::sponge::util::weighted::TableEntry {
+      let ret = bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable$Itr", self.object, "next", "()Lorg/spongepowered/api/util/weighted/TableEntry;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/util/weighted/WeightedTable$Itr#next was null") }
+      ::sponge::util::weighted::TableEntry { env: self.env, object: ret }
+    }
+
+    pub fn remove(&self) {
+      bound_method!(self.env, "org/spongepowered/api/util/weighted/WeightedTable$Itr", self.object, "remove", "()V", CallVoidMethod);
+    }
+
+  }
+}
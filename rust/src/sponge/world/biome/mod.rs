@@ -0,0 +1,211 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BiomeGenerationSettings {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeGenerationSettings {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeGenerationSettings {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeGenerationSettings`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/biome/BiomeGenerationSettings", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_min_height(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getMinHeight", "()F", CallFloatMethod)
+  }
+
+  pub fn set_min_height(&self, value: f32) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "setMinHeight", "(F)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_max_height(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getMaxHeight", "()F", CallFloatMethod)
+  }
+
+  pub fn set_max_height(&self, value: f32) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "setMaxHeight", "(F)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_ground_cover_layers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getGroundCoverLayers", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/BiomeGenerationSettings#getGroundCoverLayers was null") }
+    ret
+  }
+
+  pub fn get_generation_populators(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getGenerationPopulators", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/BiomeGenerationSettings#getGenerationPopulators was null") }
+    ret
+  }
+
+  pub fn get_generation_populators_class(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getGenerationPopulators", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/BiomeGenerationSettings#getGenerationPopulators was null") }
+    ret
+  }
+
+  pub fn get_populators(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getPopulators", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/BiomeGenerationSettings#getPopulators was null") }
+    ret
+  }
+
+  pub fn get_populators_class(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeGenerationSettings", self.object, "getPopulators", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/BiomeGenerationSettings#getPopulators was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/biome/BiomeType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_temperature(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeType", self.object, "getTemperature", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_humidity(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/BiomeType", self.object, "getHumidity", "()D", CallDoubleMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/biome/BiomeTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GroundCoverLayer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GroundCoverLayer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GroundCoverLayer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `GroundCoverLayer`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/biome/GroundCoverLayer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(all(feature = "block", feature = "util"))]
+  pub fn new_block_state_seeded_variable_amount(env: *mut JNIEnv, block_state: ::sponge::block::BlockState, seeded_variable_amount: ::sponge::util::weighted::SeededVariableAmount) -> ::sponge::world::biome::GroundCoverLayer {
+    let ret = new_object!(env, "org/spongepowered/api/world/biome/GroundCoverLayer", "(Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/util/weighted/SeededVariableAmount;)V", block_state.object, seeded_variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/GroundCoverLayer#<init> was null") }
+    ::sponge::world::biome::GroundCoverLayer { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn new_function_seeded_variable_amount(env: *mut JNIEnv, function: jobject, seeded_variable_amount: ::sponge::util::weighted::SeededVariableAmount) -> ::sponge::world::biome::GroundCoverLayer {
+    let ret = new_object!(env, "org/spongepowered/api/world/biome/GroundCoverLayer", "(Ljava/util/function/Function;Lorg/spongepowered/api/util/weighted/SeededVariableAmount;)V", function, seeded_variable_amount.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/GroundCoverLayer#<init> was null") }
+    ::sponge::world::biome::GroundCoverLayer { env: env, object: ret }
+  }
+
+  pub fn get_block_state(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "getBlockState", "()Ljava/util/function/Function;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/GroundCoverLayer#getBlockState was null") }
+    ret
+  }
+
+  pub fn set_block_state_function(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "setBlockState", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_block_state_block_state(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "setBlockState", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_depth(&self) -> ::sponge::util::weighted::SeededVariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "getDepth", "()Lorg/spongepowered/api/util/weighted/SeededVariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/GroundCoverLayer#getDepth was null") }
+    ::sponge::util::weighted::SeededVariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_depth_seeded_variable_amount(&self, seeded_variable_amount: ::sponge::util::weighted::SeededVariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "setDepth", "(Lorg/spongepowered/api/util/weighted/SeededVariableAmount;)V", CallVoidMethodA, seeded_variable_amount.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_depth_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "setDepth", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_depth_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "setDepth", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/biome/GroundCoverLayer#toString was null") }
+    ret
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/biome/GroundCoverLayer", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+}
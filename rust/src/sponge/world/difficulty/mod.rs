@@ -0,0 +1,47 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Difficulties {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Difficulties {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Difficulties {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Difficulties`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/difficulty/Difficulties", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Difficulty {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Difficulty {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Difficulty {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Difficulty`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/difficulty/Difficulty", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
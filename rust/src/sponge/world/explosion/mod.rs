@@ -0,0 +1,134 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Explosion {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Explosion {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Explosion {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Explosion`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/explosion/Explosion", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::explosion::explosion::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/explosion/Explosion", "builder", "()Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion#builder was null") }
+    ::sponge::world::explosion::explosion::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_source_explosive(&self) -> Option<::sponge::entity::explosive::Explosive> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "getSourceExplosive", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion#getSourceExplosive was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::explosive::Explosive { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_radius(&self) -> f32 {
+    bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "getRadius", "()F", CallFloatMethod)
+  }
+
+  pub fn can_cause_fire(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "canCauseFire", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn should_play_smoke(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "shouldPlaySmoke", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn should_break_blocks(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "shouldBreakBlocks", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn should_damage_entities(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion", self.object, "shouldDamageEntities", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+pub mod explosion {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/explosion/Explosion$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn location(&self, location: ::sponge::world::Location) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "location", "(Lorg/spongepowered/api/world/Location;)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, location.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#location was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn source_explosive(&self, explosive: ::sponge::entity::explosive::Explosive) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "sourceExplosive", "(Lorg/spongepowered/api/entity/explosive/Explosive;)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, explosive.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#sourceExplosive was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn radius(&self, value: f32) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "radius", "(F)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#radius was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn can_cause_fire(&self, value: bool) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "canCauseFire", "(Z)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#canCauseFire was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn should_damage_entities(&self, value: bool) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "shouldDamageEntities", "(Z)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#shouldDamageEntities was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn should_play_smoke(&self, value: bool) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "shouldPlaySmoke", "(Z)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#shouldPlaySmoke was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn should_break_blocks(&self, value: bool) -> ::sponge::world::explosion::explosion::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "shouldBreakBlocks", "(Z)Lorg/spongepowered/api/world/explosion/Explosion$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#shouldBreakBlocks was null") }
+      ::sponge::world::explosion::explosion::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::explosion::Explosion {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/explosion/Explosion$Builder", self.object, "build", "()Lorg/spongepowered/api/world/explosion/Explosion;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/explosion/Explosion$Builder#build was null") }
+      ::sponge::world::explosion::Explosion { env: self.env, object: ret }
+    }
+
+  }
+}
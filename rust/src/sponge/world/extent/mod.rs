@@ -0,0 +1,1758 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod worker;
+
+#[derive(Debug)]
+pub struct BiomeArea {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeArea {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeArea {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeArea`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/BiomeArea", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_biome_min(&self) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeMin", "()Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeMin was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_biome_max(&self) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeMax", "()Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeMax was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_biome_size(&self) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeSize", "()Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeSize was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn contains_biome_vector_2i(&self, vector_2i: ::math::Vector2i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "containsBiome", "(Lcom/flowpowered/math/vector/Vector2i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_2i, self.env)) != 0
+  }
+
+  pub fn contains_biome_i32_i32(&self, value: i32, value_2: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "containsBiome", "(II)Z", CallBooleanMethodA, value, value_2) != 0
+  }
+
+  pub fn get_biome_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiome", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiome was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiome", "(II)Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiome was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_view_vector_2i_vector_2i(&self, vector_2i: ::math::Vector2i, vector_2i_2: ::math::Vector2i) -> ::sponge::world::extent::BiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeView", "(Lcom/flowpowered/math/vector/Vector2i;Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/BiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), ::convert::ToJava::to_java(&vector_2i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::BiomeArea { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_biome_view_discrete_transform_2(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::world::extent::BiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeView", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/world/extent/BiomeArea;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::BiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_biome_view(&self) -> ::sponge::world::extent::BiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getRelativeBiomeView", "()Lorg/spongepowered/api/world/extent/BiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getRelativeBiomeView was null") }
+    ::sponge::world::extent::BiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_unmodifiable_biome_view(&self) -> ::sponge::world::extent::UnmodifiableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getUnmodifiableBiomeView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getUnmodifiableBiomeView was null") }
+    ::sponge::world::extent::UnmodifiableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_copy(&self) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeCopy", "()Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeCopy was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_copy_storage_type(&self, storage_type: ::sponge::world::extent::StorageType) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeCopy", "(Lorg/spongepowered/api/world/extent/StorageType;)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&storage_type, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeCopy was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_immutable_biome_copy(&self) -> ::sponge::world::extent::ImmutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getImmutableBiomeCopy", "()Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getImmutableBiomeCopy was null") }
+    ::sponge::world::extent::ImmutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::BiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BiomeArea", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/BiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BiomeArea#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::BiomeAreaWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/BlockVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_block_min(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockMin", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockMin was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_block_max(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockMax", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockMax was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_block_size(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockSize", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockSize was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn contains_block_vector_3i(&self, vector_3i: ::math::Vector3i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "containsBlock", "(Lcom/flowpowered/math/vector/Vector3i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env)) != 0
+  }
+
+  pub fn contains_block_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "containsBlock", "(III)Z", CallBooleanMethodA, value, value_2, value_3) != 0
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlock", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlock", "(III)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_type_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::block::BlockType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockType", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/block/BlockType;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockType was null") }
+    ::sponge::block::BlockType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_type_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::block::BlockType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockType", "(III)Lorg/spongepowered/api/block/BlockType;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockType was null") }
+    ::sponge::block::BlockType { env: self.env, object: ret }
+  }
+
+  pub fn get_block_view_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::world::extent::BlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockView", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/BlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockView was null") }
+    ::sponge::world::extent::BlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_view_discrete_transform_3(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::world::extent::BlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockView", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/world/extent/BlockVolume;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockView was null") }
+    ::sponge::world::extent::BlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_block_view(&self) -> ::sponge::world::extent::BlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getRelativeBlockView", "()Lorg/spongepowered/api/world/extent/BlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getRelativeBlockView was null") }
+    ::sponge::world::extent::BlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_unmodifiable_block_view(&self) -> ::sponge::world::extent::UnmodifiableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getUnmodifiableBlockView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getUnmodifiableBlockView was null") }
+    ::sponge::world::extent::UnmodifiableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_block_copy(&self) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockCopy", "()Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockCopy was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_block_copy_storage_type(&self, storage_type: ::sponge::world::extent::StorageType) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockCopy", "(Lorg/spongepowered/api/world/extent/StorageType;)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&storage_type, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockCopy was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_immutable_block_copy(&self) -> ::sponge::world::extent::ImmutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getImmutableBlockCopy", "()Lorg/spongepowered/api/world/extent/ImmutableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getImmutableBlockCopy was null") }
+    ::sponge::world::extent::ImmutableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::BlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/BlockVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/BlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/BlockVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::BlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EntityUniverse {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EntityUniverse {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EntityUniverse {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityUniverse`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/EntityUniverse", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_entity(&self, uuid: jobject) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getEntity", "(Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_entities(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getEntities", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getEntities was null") }
+    ret
+  }
+
+  pub fn get_entities_predicate(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getEntities", "(Ljava/util/function/Predicate;)Ljava/util/Collection;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getEntities was null") }
+    ret
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_entity_entity_type_vector_3d(&self, entity_type: ::sponge::entity::EntityType, vector_3d: ::math::Vector3d) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "createEntity", "(Lorg/spongepowered/api/entity/EntityType;Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/entity/Entity;", CallObjectMethodA, entity_type.object, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#createEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn create_entity_entity_type_vector_3i(&self, entity_type: ::sponge::entity::EntityType, vector_3i: ::math::Vector3i) -> ::sponge::entity::Entity {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "createEntity", "(Lorg/spongepowered/api/entity/EntityType;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/entity/Entity;", CallObjectMethodA, entity_type.object, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#createEntity was null") }
+    ::sponge::entity::Entity { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "entity"))]
+  pub fn create_entity_data_container(&self, data_container: ::sponge::data::DataContainer) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "createEntity", "(Lorg/spongepowered/api/data/DataContainer;)Ljava/util/Optional;", CallObjectMethodA, data_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#createEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "data", feature = "entity"))]
+  pub fn create_entity_data_container_vector_3d(&self, data_container: ::sponge::data::DataContainer, vector_3d: ::math::Vector3d) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "createEntity", "(Lorg/spongepowered/api/data/DataContainer;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Optional;", CallObjectMethodA, data_container.object, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#createEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn restore_snapshot(&self, entity_snapshot: ::sponge::entity::EntitySnapshot, vector_3d: ::math::Vector3d) -> Option<::sponge::entity::Entity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "restoreSnapshot", "(Lorg/spongepowered/api/entity/EntitySnapshot;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Optional;", CallObjectMethodA, entity_snapshot.object, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#restoreSnapshot was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::entity::Entity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "entity", feature = "event"))]
+  pub fn spawn_entity(&self, entity: ::sponge::entity::Entity, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "spawnEntity", "(Lorg/spongepowered/api/entity/Entity;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, entity.object, cause.object) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn spawn_entities(&self, iterable: jobject, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "spawnEntities", "(Ljava/lang/Iterable;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, iterable, cause.object) != 0
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_intersecting_entities_aabb(&self, aabb: ::sponge::util::AABB) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lorg/spongepowered/api/util/AABB;)Ljava/util/Set;", CallObjectMethodA, aabb.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_intersecting_entities_aabb_predicate(&self, aabb: ::sponge::util::AABB, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lorg/spongepowered/api/util/AABB;Ljava/util/function/Predicate;)Ljava/util/Set;", CallObjectMethodA, aabb.object, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  pub fn get_intersecting_entities_vector_3d_vector_3d(&self, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), ::convert::ToJava::to_java(&vector_3d_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  pub fn get_intersecting_entities_vector_3d_vector_3d_predicate(&self, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;Ljava/util/function/Predicate;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), ::convert::ToJava::to_java(&vector_3d_2, self.env), predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_intersecting_entities_entity_f64(&self, entity: ::sponge::entity::Entity, value: f64) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lorg/spongepowered/api/entity/Entity;D)Ljava/util/Set;", CallObjectMethodA, entity.object, value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_intersecting_entities_entity_f64_predicate(&self, entity: ::sponge::entity::Entity, value: f64, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lorg/spongepowered/api/entity/Entity;DLjava/util/function/Predicate;)Ljava/util/Set;", CallObjectMethodA, entity.object, value, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  pub fn get_intersecting_entities_vector_3d_vector_3d_f64(&self, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d, value: f64) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;D)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), ::convert::ToJava::to_java(&vector_3d_2, self.env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+  pub fn get_intersecting_entities_vector_3d_vector_3d_f64_predicate(&self, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d, value: f64, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse", self.object, "getIntersectingEntities", "(Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;DLjava/util/function/Predicate;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), ::convert::ToJava::to_java(&vector_3d_2, self.env), value, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse#getIntersectingEntities was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Extent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Extent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Extent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Extent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/Extent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_location_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getLocation", "(III)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getLocation", "(DDD)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_vector_3i_block_state_block_change_flag_cause(&self, vector_3i: ::math::Vector3i, block_state: ::sponge::block::BlockState, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_state.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_i32_i32_i32_block_state_block_change_flag_cause(&self, value: i32, value_2: i32, value_3: i32, block_state: ::sponge::block::BlockState, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setBlock", "(IIILorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_state.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_vector_3i_block_type_block_change_flag_cause(&self, vector_3i: ::math::Vector3i, block_type: ::sponge::block::BlockType, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setBlockType", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_type.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_i32_i32_i32_block_type_block_change_flag_cause(&self, value: i32, value_2: i32, value_3: i32, block_type: ::sponge::block::BlockType, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setBlockType", "(IIILorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_type.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_snapshot_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "createSnapshot", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#createSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_snapshot_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "createSnapshot", "(III)Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#createSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn restore_snapshot_block_snapshot_bool_block_change_flag_cause(&self, block_snapshot: ::sponge::block::BlockSnapshot, value: bool, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "restoreSnapshot", "(Lorg/spongepowered/api/block/BlockSnapshot;ZLorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_snapshot.object, value, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn restore_snapshot_vector_3i_block_snapshot_bool_block_change_flag_cause(&self, vector_3i: ::math::Vector3i, block_snapshot: ::sponge::block::BlockSnapshot, value: bool, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "restoreSnapshot", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockSnapshot;ZLorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_snapshot.object, value, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn restore_snapshot_i32_i32_i32_block_snapshot_bool_block_change_flag_cause(&self, value: i32, value_2: i32, value_3: i32, block_snapshot: ::sponge::block::BlockSnapshot, value_4: bool, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "restoreSnapshot", "(IIILorg/spongepowered/api/block/BlockSnapshot;ZLorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_snapshot.object, value_4, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  pub fn get_scheduled_updates_vector_3i(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getScheduledUpdates", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Collection;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getScheduledUpdates was null") }
+    ret
+  }
+
+  pub fn get_scheduled_updates_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getScheduledUpdates", "(III)Ljava/util/Collection;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getScheduledUpdates was null") }
+    ret
+  }
+
+  #[cfg(feature = "block")]
+  pub fn add_scheduled_update_vector_3i_i32_i32(&self, vector_3i: ::math::Vector3i, value: i32, value_2: i32) -> ::sponge::block::ScheduledBlockUpdate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "addScheduledUpdate", "(Lcom/flowpowered/math/vector/Vector3i;II)Lorg/spongepowered/api/block/ScheduledBlockUpdate;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#addScheduledUpdate was null") }
+    ::sponge::block::ScheduledBlockUpdate { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn add_scheduled_update_i32_i32_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32, value_4: i32, value_5: i32) -> ::sponge::block::ScheduledBlockUpdate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "addScheduledUpdate", "(IIIII)Lorg/spongepowered/api/block/ScheduledBlockUpdate;", CallObjectMethodA, value, value_2, value_3, value_4, value_5);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#addScheduledUpdate was null") }
+    ::sponge::block::ScheduledBlockUpdate { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn remove_scheduled_update_vector_3i_scheduled_block_update(&self, vector_3i: ::math::Vector3i, scheduled_block_update: ::sponge::block::ScheduledBlockUpdate) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "removeScheduledUpdate", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/ScheduledBlockUpdate;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), scheduled_block_update.object);
+  }
+
+  #[cfg(feature = "block")]
+  pub fn remove_scheduled_update_i32_i32_i32_scheduled_block_update(&self, value: i32, value_2: i32, value_3: i32, scheduled_block_update: ::sponge::block::ScheduledBlockUpdate) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "removeScheduledUpdate", "(IIILorg/spongepowered/api/block/ScheduledBlockUpdate;)V", CallVoidMethodA, value, value_2, value_3, scheduled_block_update.object);
+  }
+
+  pub fn is_loaded(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "isLoaded", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_extent_view_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getExtentView", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getExtentView was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_extent_view_discrete_transform_3(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getExtentView", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getExtentView was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_extent_view(&self) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getRelativeExtentView", "()Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getRelativeExtentView was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::MutableBiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::MutableBiomeAreaWorker { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+  pub fn get_creator_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getCreator", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getCreator was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_creator_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getCreator", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getCreator was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_notifier_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getNotifier", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getNotifier was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_notifier_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getNotifier", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getNotifier was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_creator_vector_3i_uuid(&self, vector_3i: ::math::Vector3i, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setCreator", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/util/UUID;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), uuid);
+  }
+
+  pub fn set_creator_i32_i32_i32_uuid(&self, value: i32, value_2: i32, value_3: i32, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setCreator", "(IIILjava/util/UUID;)V", CallVoidMethodA, value, value_2, value_3, uuid);
+  }
+
+  pub fn set_notifier_vector_3i_uuid(&self, vector_3i: ::math::Vector3i, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setNotifier", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/util/UUID;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), uuid);
+  }
+
+  pub fn set_notifier_i32_i32_i32_uuid(&self, value: i32, value_2: i32, value_3: i32, uuid: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "setNotifier", "(IIILjava/util/UUID;)V", CallVoidMethodA, value, value_2, value_3, uuid);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_selection_box_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<::sponge::util::AABB> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getBlockSelectionBox", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getBlockSelectionBox was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::AABB { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_selection_box_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::util::AABB> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getBlockSelectionBox", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getBlockSelectionBox was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::util::AABB { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_intersecting_block_collision_boxes(&self, aabb: ::sponge::util::AABB) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getIntersectingBlockCollisionBoxes", "(Lorg/spongepowered/api/util/AABB;)Ljava/util/Set;", CallObjectMethodA, aabb.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getIntersectingBlockCollisionBoxes was null") }
+    ret
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_intersecting_collision_boxes_entity(&self, entity: ::sponge::entity::Entity) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getIntersectingCollisionBoxes", "(Lorg/spongepowered/api/entity/Entity;)Ljava/util/Set;", CallObjectMethodA, entity.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getIntersectingCollisionBoxes was null") }
+    ret
+  }
+
+  #[cfg(all(feature = "entity", feature = "util"))]
+  pub fn get_intersecting_collision_boxes_entity_aabb(&self, entity: ::sponge::entity::Entity, aabb: ::sponge::util::AABB) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/Extent", self.object, "getIntersectingCollisionBoxes", "(Lorg/spongepowered/api/entity/Entity;Lorg/spongepowered/api/util/AABB;)Ljava/util/Set;", CallObjectMethodA, entity.object, aabb.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/Extent#getIntersectingCollisionBoxes was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ExtentBufferFactory {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ExtentBufferFactory {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ExtentBufferFactory {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ExtentBufferFactory`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/ExtentBufferFactory", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn create_biome_buffer_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createBiomeBuffer", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createBiomeBuffer was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn create_biome_buffer_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createBiomeBuffer", "(II)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createBiomeBuffer was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn create_thread_safe_biome_buffer_vector_2i(&self, vector_2i: ::math::Vector2i) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createThreadSafeBiomeBuffer", "(Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createThreadSafeBiomeBuffer was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn create_thread_safe_biome_buffer_i32_i32(&self, value: i32, value_2: i32) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createThreadSafeBiomeBuffer", "(II)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createThreadSafeBiomeBuffer was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn create_block_buffer_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createBlockBuffer", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createBlockBuffer was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn create_block_buffer_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createBlockBuffer", "(III)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createBlockBuffer was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn create_thread_safe_block_buffer_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createThreadSafeBlockBuffer", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createThreadSafeBlockBuffer was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn create_thread_safe_block_buffer_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ExtentBufferFactory", self.object, "createThreadSafeBlockBuffer", "(III)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ExtentBufferFactory#createThreadSafeBlockBuffer was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBiomeArea {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBiomeArea {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBiomeArea {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBiomeArea`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/ImmutableBiomeArea", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_biome_view_vector_2i_vector_2i(&self, vector_2i: ::math::Vector2i, vector_2i_2: ::math::Vector2i) -> ::sponge::world::extent::ImmutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBiomeArea", self.object, "getBiomeView", "(Lcom/flowpowered/math/vector/Vector2i;Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), ::convert::ToJava::to_java(&vector_2i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::ImmutableBiomeArea { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_biome_view_discrete_transform_2(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::world::extent::ImmutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBiomeArea", self.object, "getBiomeView", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::ImmutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_biome_view(&self) -> ::sponge::world::extent::ImmutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBiomeArea", self.object, "getRelativeBiomeView", "()Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBiomeArea#getRelativeBiomeView was null") }
+    ::sponge::world::extent::ImmutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_immutable_biome_copy(&self) -> ::sponge::world::extent::ImmutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBiomeArea", self.object, "getImmutableBiomeCopy", "()Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBiomeArea#getImmutableBiomeCopy was null") }
+    ::sponge::world::extent::ImmutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::BiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBiomeArea", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/BiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBiomeArea#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::BiomeAreaWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ImmutableBlockVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ImmutableBlockVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ImmutableBlockVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ImmutableBlockVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/ImmutableBlockVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_block_view_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::world::extent::ImmutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBlockVolume", self.object, "getBlockView", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/ImmutableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::ImmutableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_view_discrete_transform_3(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::world::extent::ImmutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBlockVolume", self.object, "getBlockView", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/world/extent/ImmutableBlockVolume;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::ImmutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_block_view(&self) -> ::sponge::world::extent::ImmutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBlockVolume", self.object, "getRelativeBlockView", "()Lorg/spongepowered/api/world/extent/ImmutableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBlockVolume#getRelativeBlockView was null") }
+    ::sponge::world::extent::ImmutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_immutable_block_copy(&self) -> ::sponge::world::extent::ImmutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBlockVolume", self.object, "getImmutableBlockCopy", "()Lorg/spongepowered/api/world/extent/ImmutableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBlockVolume#getImmutableBlockCopy was null") }
+    ::sponge::world::extent::ImmutableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::BlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/ImmutableBlockVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/BlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/ImmutableBlockVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::BlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct InteractableVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl InteractableVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    InteractableVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `InteractableVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/InteractableVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(all(feature = "event", feature = "util"))]
+  pub fn hit_block_vector_3i_direction_cause(&self, vector_3i: ::math::Vector3i, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "hitBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "util"))]
+  pub fn hit_block_i32_i32_i32_direction_cause(&self, value: i32, value_2: i32, value_3: i32, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "hitBlock", "(IIILorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "util"))]
+  pub fn interact_block_vector_3i_direction_cause(&self, vector_3i: ::math::Vector3i, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "interactBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "util"))]
+  pub fn interact_block_i32_i32_i32_direction_cause(&self, value: i32, value_2: i32, value_3: i32, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "interactBlock", "(IIILorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "item", feature = "util"))]
+  pub fn interact_block_with_vector_3i_item_stack_direction_cause(&self, vector_3i: ::math::Vector3i, item_stack: ::sponge::item::inventory::ItemStack, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "interactBlockWith", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), item_stack.object, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "item", feature = "util"))]
+  pub fn interact_block_with_i32_i32_i32_item_stack_direction_cause(&self, value: i32, value_2: i32, value_3: i32, item_stack: ::sponge::item::inventory::ItemStack, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "interactBlockWith", "(IIILorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, item_stack.object, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event", feature = "util"))]
+  pub fn place_block_vector_3i_block_state_direction_cause(&self, vector_3i: ::math::Vector3i, block_state: ::sponge::block::BlockState, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "placeBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_state.object, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event", feature = "util"))]
+  pub fn place_block_i32_i32_i32_block_state_direction_cause(&self, value: i32, value_2: i32, value_3: i32, block_state: ::sponge::block::BlockState, direction: ::sponge::util::Direction, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "placeBlock", "(IIILorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/util/Direction;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_state.object, ::convert::ToJava::to_java(&direction, self.env), cause.object) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn dig_block_vector_3i_cause(&self, vector_3i: ::math::Vector3i, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "digBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), cause.object) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn dig_block_i32_i32_i32_cause(&self, value: i32, value_2: i32, value_3: i32, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "digBlock", "(IIILorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "item"))]
+  pub fn dig_block_with_vector_3i_item_stack_cause(&self, vector_3i: ::math::Vector3i, item_stack: ::sponge::item::inventory::ItemStack, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "digBlockWith", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), item_stack.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "item"))]
+  pub fn dig_block_with_i32_i32_i32_item_stack_cause(&self, value: i32, value_2: i32, value_3: i32, item_stack: ::sponge::item::inventory::ItemStack, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "digBlockWith", "(IIILorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, item_stack.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "event", feature = "item"))]
+  pub fn get_block_dig_time_with_vector_3i_item_stack_cause(&self, vector_3i: ::math::Vector3i, item_stack: ::sponge::item::inventory::ItemStack, cause: ::sponge::event::cause::Cause) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "getBlockDigTimeWith", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/event/cause/Cause;)I", CallIntMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), item_stack.object, cause.object)
+  }
+
+  #[cfg(all(feature = "event", feature = "item"))]
+  pub fn get_block_dig_time_with_i32_i32_i32_item_stack_cause(&self, value: i32, value_2: i32, value_3: i32, item_stack: ::sponge::item::inventory::ItemStack, cause: ::sponge::event::cause::Cause) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "getBlockDigTimeWith", "(IIILorg/spongepowered/api/item/inventory/ItemStack;Lorg/spongepowered/api/event/cause/Cause;)I", CallIntMethodA, value, value_2, value_3, item_stack.object, cause.object)
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/InteractableVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/InteractableVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct LocationCompositeValueStore {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl LocationCompositeValueStore {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    LocationCompositeValueStore {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `LocationCompositeValueStore`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/LocationCompositeValueStore", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_vector_3i_key(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "get", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_i32_i32_i32_key(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "get", "(IIILorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "get", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "get", "(IIILjava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_create_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrCreate", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrCreate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_create_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrCreate", "(IIILjava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrCreate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_or_null_vector_3i_key(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrNull", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;)Ljava/lang/Object;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrNull was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_or_null_i32_i32_i32_key(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrNull", "(IIILorg/spongepowered/api/data/key/Key;)Ljava/lang/Object;", CallObjectMethodA, value, value_2, value_3, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrNull was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_or_else_vector_3i_key_object(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrElse", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrElse was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_or_else_i32_i32_i32_key_object(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getOrElse", "(IIILorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, value, value_2, value_3, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getOrElse was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_value_vector_3i_key(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getValue", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_value_i32_i32_i32_key(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getValue", "(IIILorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_vector_3i_key(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_i32_i32_i32_key(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(IIILorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, value, value_2, value_3, key.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_vector_3i_base_value(&self, vector_3i: ::math::Vector3i, base_value: ::sponge::data::value::BaseValue) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/value/BaseValue;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), base_value.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_i32_i32_i32_base_value(&self, value: i32, value_2: i32, value_3: i32, base_value: ::sponge::data::value::BaseValue) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(IIILorg/spongepowered/api/data/value/BaseValue;)Z", CallBooleanMethodA, value, value_2, value_3, base_value.object) != 0
+  }
+
+  pub fn supports_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class) != 0
+  }
+
+  pub fn supports_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(IIILjava/lang/Class;)Z", CallBooleanMethodA, value, value_2, value_3, class) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_vector_3i_data_manipulator(&self, vector_3i: ::math::Vector3i, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/manipulator/DataManipulator;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_manipulator.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_i32_i32_i32_data_manipulator(&self, value: i32, value_2: i32, value_3: i32, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "supports", "(IIILorg/spongepowered/api/data/manipulator/DataManipulator;)Z", CallBooleanMethodA, value, value_2, value_3, data_manipulator.object) != 0
+  }
+
+  pub fn get_keys_vector_3i(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getKeys", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getKeys was null") }
+    ret
+  }
+
+  pub fn get_keys_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getKeys", "(III)Ljava/util/Set;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values_vector_3i(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getValues", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Set;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getValues was null") }
+    ret
+  }
+
+  pub fn get_values_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getValues", "(III)Ljava/util/Set;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getValues was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn transform_vector_3i_key_function(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "transform", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#transform was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn transform_i32_i32_i32_key_function(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "transform", "(IIILorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#transform was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_key_object(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_i32_i32_i32_key_object(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_vector_3i_key_object_cause(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key, object: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object, object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_i32_i32_i32_key_object_cause(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key, object: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, key.object, object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_base_value(&self, vector_3i: ::math::Vector3i, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_i32_i32_i32_base_value(&self, value: i32, value_2: i32, value_3: i32, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_vector_3i_base_value_cause(&self, vector_3i: ::math::Vector3i, base_value: ::sponge::data::value::BaseValue, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/value/BaseValue;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), base_value.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_i32_i32_i32_base_value_cause(&self, value: i32, value_2: i32, value_3: i32, base_value: ::sponge::data::value::BaseValue, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/value/BaseValue;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, base_value.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_data_manipulator(&self, vector_3i: ::math::Vector3i, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/manipulator/DataManipulator;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_i32_i32_i32_data_manipulator(&self, value: i32, value_2: i32, value_3: i32, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/manipulator/DataManipulator;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_vector_3i_data_manipulator_cause(&self, vector_3i: ::math::Vector3i, data_manipulator: ::sponge::data::manipulator::DataManipulator, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_manipulator.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_i32_i32_i32_data_manipulator_cause(&self, value: i32, value_2: i32, value_3: i32, data_manipulator: ::sponge::data::manipulator::DataManipulator, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_manipulator.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_data_manipulator_merge_function(&self, vector_3i: ::math::Vector3i, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_manipulator.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_i32_i32_i32_data_manipulator_merge_function(&self, value: i32, value_2: i32, value_3: i32, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_manipulator.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_vector_3i_data_manipulator_merge_function_cause(&self, vector_3i: ::math::Vector3i, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_manipulator.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_i32_i32_i32_data_manipulator_merge_function_cause(&self, value: i32, value_2: i32, value_3: i32, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_manipulator.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_iterable(&self, vector_3i: ::math::Vector3i, iterable: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_i32_i32_i32_iterable(&self, value: i32, value_2: i32, value_3: i32, iterable: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(IIILjava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_vector_3i_iterable_merge_function(&self, vector_3i: ::math::Vector3i, iterable: jobject, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "offer", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Iterable;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), iterable, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_vector_3i_class(&self, vector_3i: ::math::Vector3i, class: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "remove", "(Lcom/flowpowered/math/vector/Vector3i;Ljava/lang/Class;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_i32_i32_i32_class(&self, value: i32, value_2: i32, value_3: i32, class: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "remove", "(IIILjava/lang/Class;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_vector_3i_key(&self, vector_3i: ::math::Vector3i, key: ::sponge::data::key::Key) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "remove", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_i32_i32_i32_key(&self, value: i32, value_2: i32, value_3: i32, key: ::sponge::data::key::Key) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "remove", "(IIILorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn undo_vector_3i_data_transaction_result(&self, vector_3i: ::math::Vector3i, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "undo", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#undo was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn undo_i32_i32_i32_data_transaction_result(&self, value: i32, value_2: i32, value_3: i32, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "undo", "(IIILorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#undo was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_vector_3i_data_holder(&self, vector_3i: ::math::Vector3i, data_holder: ::sponge::data::DataHolder) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/DataHolder;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_i32_i32_i32_data_holder(&self, value: i32, value_2: i32, value_3: i32, data_holder: ::sponge::data::DataHolder) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(IIILorg/spongepowered/api/data/DataHolder;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_i32_i32_i32_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32, value_4: i32, value_5: i32, value_6: i32) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(IIIIII)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, value_4, value_5, value_6);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_vector_3i_data_holder_merge_function(&self, vector_3i: ::math::Vector3i, data_holder: ::sponge::data::DataHolder, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/DataHolder;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_holder.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_i32_i32_i32_data_holder_merge_function(&self, value: i32, value_2: i32, value_3: i32, data_holder: ::sponge::data::DataHolder, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(IIILorg/spongepowered/api/data/DataHolder;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, data_holder.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_vector_3i_vector_3i_merge_function(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env), merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_i32_i32_i32_i32_i32_i32_merge_function(&self, value: i32, value_2: i32, value_3: i32, value_4: i32, value_5: i32, value_6: i32, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "copyFrom", "(IIIIIILorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value, value_2, value_3, value_4, value_5, value_6, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_manipulators_vector_3i(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getManipulators", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Collection;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getManipulators was null") }
+    ret
+  }
+
+  pub fn get_manipulators_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "getManipulators", "(III)Ljava/util/Collection;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/LocationCompositeValueStore#getManipulators was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn validate_raw_data_vector_3i_data_view(&self, vector_3i: ::math::Vector3i, data_view: ::sponge::data::DataView) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "validateRawData", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/DataView;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_view.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn validate_raw_data_i32_i32_i32_data_view(&self, value: i32, value_2: i32, value_3: i32, data_view: ::sponge::data::DataView) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "validateRawData", "(IIILorg/spongepowered/api/data/DataView;)Z", CallBooleanMethodA, value, value_2, value_3, data_view.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_raw_data_vector_3i_data_view(&self, vector_3i: ::math::Vector3i, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "setRawData", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), data_view.object);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_raw_data_i32_i32_i32_data_view(&self, value: i32, value_2: i32, value_3: i32, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/LocationCompositeValueStore", self.object, "setRawData", "(IIILorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, value, value_2, value_3, data_view.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableBiomeArea {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableBiomeArea {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableBiomeArea {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableBiomeArea`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/MutableBiomeArea", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn set_biome_vector_2i_biome_type(&self, vector_2i: ::math::Vector2i, biome_type: ::sponge::world::biome::BiomeType) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "setBiome", "(Lcom/flowpowered/math/vector/Vector2i;Lorg/spongepowered/api/world/biome/BiomeType;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), biome_type.object);
+  }
+
+  pub fn set_biome_i32_i32_biome_type(&self, value: i32, value_2: i32, biome_type: ::sponge::world::biome::BiomeType) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "setBiome", "(IILorg/spongepowered/api/world/biome/BiomeType;)V", CallVoidMethodA, value, value_2, biome_type.object);
+  }
+
+  pub fn get_biome_view_vector_2i_vector_2i(&self, vector_2i: ::math::Vector2i, vector_2i_2: ::math::Vector2i) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "getBiomeView", "(Lcom/flowpowered/math/vector/Vector2i;Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), ::convert::ToJava::to_java(&vector_2i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_biome_view_discrete_transform_2(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "getBiomeView", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_biome_view(&self) -> ::sponge::world::extent::MutableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "getRelativeBiomeView", "()Lorg/spongepowered/api/world/extent/MutableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBiomeArea#getRelativeBiomeView was null") }
+    ::sponge::world::extent::MutableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::MutableBiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBiomeArea", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBiomeArea#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::MutableBiomeAreaWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableBlockVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableBlockVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableBlockVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableBlockVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/MutableBlockVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_vector_3i_block_state_cause(&self, vector_3i: ::math::Vector3i, block_state: ::sponge::block::BlockState, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "setBlock", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_state.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_i32_i32_i32_block_state_cause(&self, value: i32, value_2: i32, value_3: i32, block_state: ::sponge::block::BlockState, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "setBlock", "(IIILorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_state.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_vector_3i_block_type_cause(&self, vector_3i: ::math::Vector3i, block_type: ::sponge::block::BlockType, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "setBlockType", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), block_type.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_i32_i32_i32_block_type_cause(&self, value: i32, value_2: i32, value_3: i32, block_type: ::sponge::block::BlockType, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "setBlockType", "(IIILorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, value, value_2, value_3, block_type.object, cause.object) != 0
+  }
+
+  pub fn get_block_view_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "getBlockView", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_view_discrete_transform_3(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "getBlockView", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_block_view(&self) -> ::sponge::world::extent::MutableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "getRelativeBlockView", "()Lorg/spongepowered/api/world/extent/MutableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBlockVolume#getRelativeBlockView was null") }
+    ::sponge::world::extent::MutableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/MutableBlockVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/MutableBlockVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageType {
+  Standard,
+  ThreadSafe,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static STORAGE_TYPE_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/world/extent/StorageType", &["STANDARD", "THREAD_SAFE"]);
+
+impl StorageType {
+  pub const VALUES: &'static [StorageType] = &[StorageType::Standard, StorageType::ThreadSafe];
+
+}
+
+impl ::convert::FromJava for StorageType {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match STORAGE_TYPE_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => StorageType::VALUES[ordinal],
+      None => StorageType::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for StorageType {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      StorageType::Other(object) => object,
+      value => STORAGE_TYPE_CONSTANTS.get(env, StorageType::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct TileEntityVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TileEntityVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TileEntityVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TileEntityVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/TileEntityVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_tile_entities(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/TileEntityVolume", self.object, "getTileEntities", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/TileEntityVolume#getTileEntities was null") }
+    ret
+  }
+
+  pub fn get_tile_entities_predicate(&self, predicate: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/TileEntityVolume", self.object, "getTileEntities", "(Ljava/util/function/Predicate;)Ljava/util/Collection;", CallObjectMethodA, predicate);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/TileEntityVolume#getTileEntities was null") }
+    ret
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_tile_entity_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<::sponge::block::tileentity::TileEntity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/TileEntityVolume", self.object, "getTileEntity", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/TileEntityVolume#getTileEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::tileentity::TileEntity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_tile_entity_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::block::tileentity::TileEntity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/TileEntityVolume", self.object, "getTileEntity", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/TileEntityVolume#getTileEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::tileentity::TileEntity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/TileEntityVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/TileEntityVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnmodifiableBiomeArea {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnmodifiableBiomeArea {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnmodifiableBiomeArea {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnmodifiableBiomeArea`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_biome_view_vector_2i_vector_2i(&self, vector_2i: ::math::Vector2i, vector_2i_2: ::math::Vector2i) -> ::sponge::world::extent::UnmodifiableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", self.object, "getBiomeView", "(Lcom/flowpowered/math/vector/Vector2i;Lcom/flowpowered/math/vector/Vector2i;)Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_2i, self.env), ::convert::ToJava::to_java(&vector_2i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::UnmodifiableBiomeArea { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_biome_view_discrete_transform_2(&self, discrete_transform_2: ::sponge::util::DiscreteTransform2) -> ::sponge::world::extent::UnmodifiableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", self.object, "getBiomeView", "(Lorg/spongepowered/api/util/DiscreteTransform2;)Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;", CallObjectMethodA, discrete_transform_2.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBiomeArea#getBiomeView was null") }
+    ::sponge::world::extent::UnmodifiableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_biome_view(&self) -> ::sponge::world::extent::UnmodifiableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", self.object, "getRelativeBiomeView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBiomeArea#getRelativeBiomeView was null") }
+    ::sponge::world::extent::UnmodifiableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_unmodifiable_biome_view(&self) -> ::sponge::world::extent::UnmodifiableBiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", self.object, "getUnmodifiableBiomeView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBiomeArea#getUnmodifiableBiomeView was null") }
+    ::sponge::world::extent::UnmodifiableBiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::BiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBiomeArea", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/BiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBiomeArea#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::BiomeAreaWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct UnmodifiableBlockVolume {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl UnmodifiableBlockVolume {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    UnmodifiableBlockVolume {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `UnmodifiableBlockVolume`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_block_view_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> ::sponge::world::extent::UnmodifiableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", self.object, "getBlockView", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::UnmodifiableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_view_discrete_transform_3(&self, discrete_transform_3: ::sponge::util::DiscreteTransform3) -> ::sponge::world::extent::UnmodifiableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", self.object, "getBlockView", "(Lorg/spongepowered/api/util/DiscreteTransform3;)Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;", CallObjectMethodA, discrete_transform_3.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBlockVolume#getBlockView was null") }
+    ::sponge::world::extent::UnmodifiableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_relative_block_view(&self) -> ::sponge::world::extent::UnmodifiableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", self.object, "getRelativeBlockView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBlockVolume#getRelativeBlockView was null") }
+    ::sponge::world::extent::UnmodifiableBlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn get_unmodifiable_block_view(&self) -> ::sponge::world::extent::UnmodifiableBlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", self.object, "getUnmodifiableBlockView", "()Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBlockVolume#getUnmodifiableBlockView was null") }
+    ::sponge::world::extent::UnmodifiableBlockVolume { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::BlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/UnmodifiableBlockVolume", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/BlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/UnmodifiableBlockVolume#getBlockWorker was null") }
+    ::sponge::world::extent::worker::BlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+pub mod entity_universe {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct EntityHit {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityHit {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityHit {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the implementation in `EntityHit`, even if
+    /// the class of the object overrides it.
+    pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn new(env: *mut JNIEnv, entity: ::sponge::entity::Entity, vector_3d: ::math::Vector3d, vector_3d_2: ::math::Vector3d, value: f64) -> ::sponge::world::extent::entity_universe::EntityHit {
+      let ret = new_object!(env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", "(Lorg/spongepowered/api/entity/Entity;Lcom/flowpowered/math/vector/Vector3d;Lcom/flowpowered/math/vector/Vector3d;D)V", entity.object, ::convert::ToJava::to_java(&vector_3d, env), ::convert::ToJava::to_java(&vector_3d_2, env), value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse$EntityHit#<init> was null") }
+      ::sponge::world::extent::entity_universe::EntityHit { env: env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_entity(&self) -> ::sponge::entity::Entity {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "getEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse$EntityHit#getEntity was null") }
+      ::sponge::entity::Entity { env: self.env, object: ret }
+    }
+
+    pub fn get_intersection(&self) -> ::math::Vector3d {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "getIntersection", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse$EntityHit#getIntersection was null") }
+      <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    pub fn get_normal(&self) -> ::math::Vector3d {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "getNormal", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse$EntityHit#getNormal was null") }
+      <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+    }
+
+    pub fn get_distance(&self) -> f64 {
+      bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "getDistance", "()D", CallDoubleMethod)
+    }
+
+    pub fn equals(&self, object: jobject) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+    }
+
+    pub fn hash_code(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "hashCode", "()I", CallIntMethod)
+    }
+
+    pub fn to_string(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/EntityUniverse$EntityHit", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/extent/EntityUniverse$EntityHit#toString was null") }
+      ret
+    }
+
+  }
+}
@@ -0,0 +1,168 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod procedure;
+
+#[derive(Debug)]
+pub struct BiomeAreaWorker {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaWorker {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaWorker {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaWorker`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_area(&self) -> ::sponge::world::extent::BiomeArea {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", self.object, "getArea", "()Lorg/spongepowered/api/world/extent/BiomeArea;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/BiomeAreaWorker#getArea was null") }
+    ::sponge::world::extent::BiomeArea { env: self.env, object: ret }
+  }
+
+  pub fn map(&self, biome_area_mapper: ::sponge::world::extent::worker::procedure::BiomeAreaMapper, mutable_biome_area: ::sponge::world::extent::MutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", self.object, "map", "(Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaMapper;Lorg/spongepowered/api/world/extent/MutableBiomeArea;)V", CallVoidMethodA, biome_area_mapper.object, mutable_biome_area.object);
+  }
+
+  pub fn merge(&self, biome_area: ::sponge::world::extent::BiomeArea, biome_area_merger: ::sponge::world::extent::worker::procedure::BiomeAreaMerger, mutable_biome_area: ::sponge::world::extent::MutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", self.object, "merge", "(Lorg/spongepowered/api/world/extent/BiomeArea;Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaMerger;Lorg/spongepowered/api/world/extent/MutableBiomeArea;)V", CallVoidMethodA, biome_area.object, biome_area_merger.object, mutable_biome_area.object);
+  }
+
+  pub fn iterate(&self, biome_area_visitor: ::sponge::world::extent::worker::procedure::BiomeAreaVisitor) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", self.object, "iterate", "(Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaVisitor;)V", CallVoidMethodA, biome_area_visitor.object);
+  }
+
+  pub fn reduce(&self, biome_area_reducer: ::sponge::world::extent::worker::procedure::BiomeAreaReducer, bi_function: jobject, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BiomeAreaWorker", self.object, "reduce", "(Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaReducer;Ljava/util/function/BiFunction;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, biome_area_reducer.object, bi_function, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/BiomeAreaWorker#reduce was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeWorker {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeWorker {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeWorker {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeWorker`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_volume(&self) -> ::sponge::world::extent::BlockVolume {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", self.object, "getVolume", "()Lorg/spongepowered/api/world/extent/BlockVolume;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/BlockVolumeWorker#getVolume was null") }
+    ::sponge::world::extent::BlockVolume { env: self.env, object: ret }
+  }
+
+  pub fn map(&self, block_volume_mapper: ::sponge::world::extent::worker::procedure::BlockVolumeMapper, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", self.object, "map", "(Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeMapper;Lorg/spongepowered/api/world/extent/MutableBlockVolume;)V", CallVoidMethodA, block_volume_mapper.object, mutable_block_volume.object);
+  }
+
+  pub fn merge(&self, block_volume: ::sponge::world::extent::BlockVolume, block_volume_merger: ::sponge::world::extent::worker::procedure::BlockVolumeMerger, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", self.object, "merge", "(Lorg/spongepowered/api/world/extent/BlockVolume;Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeMerger;Lorg/spongepowered/api/world/extent/MutableBlockVolume;)V", CallVoidMethodA, block_volume.object, block_volume_merger.object, mutable_block_volume.object);
+  }
+
+  pub fn iterate(&self, block_volume_visitor: ::sponge::world::extent::worker::procedure::BlockVolumeVisitor) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", self.object, "iterate", "(Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeVisitor;)V", CallVoidMethodA, block_volume_visitor.object);
+  }
+
+  pub fn reduce(&self, block_volume_reducer: ::sponge::world::extent::worker::procedure::BlockVolumeReducer, bi_function: jobject, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/BlockVolumeWorker", self.object, "reduce", "(Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeReducer;Ljava/util/function/BiFunction;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, block_volume_reducer.object, bi_function, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/BlockVolumeWorker#reduce was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableBiomeAreaWorker {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableBiomeAreaWorker {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableBiomeAreaWorker {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableBiomeAreaWorker`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn map(&self, biome_area_mapper: ::sponge::world::extent::worker::procedure::BiomeAreaMapper) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker", self.object, "map", "(Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaMapper;)V", CallVoidMethodA, biome_area_mapper.object);
+  }
+
+  pub fn merge(&self, biome_area: ::sponge::world::extent::BiomeArea, biome_area_merger: ::sponge::world::extent::worker::procedure::BiomeAreaMerger) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker", self.object, "merge", "(Lorg/spongepowered/api/world/extent/BiomeArea;Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaMerger;)V", CallVoidMethodA, biome_area.object, biome_area_merger.object);
+  }
+
+  pub fn fill(&self, biome_area_filler: ::sponge::world::extent::worker::procedure::BiomeAreaFiller) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker", self.object, "fill", "(Lorg/spongepowered/api/world/extent/worker/procedure/BiomeAreaFiller;)V", CallVoidMethodA, biome_area_filler.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MutableBlockVolumeWorker {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MutableBlockVolumeWorker {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MutableBlockVolumeWorker {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MutableBlockVolumeWorker`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn map(&self, block_volume_mapper: ::sponge::world::extent::worker::procedure::BlockVolumeMapper) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker", self.object, "map", "(Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeMapper;)V", CallVoidMethodA, block_volume_mapper.object);
+  }
+
+  pub fn merge(&self, block_volume: ::sponge::world::extent::BlockVolume, block_volume_merger: ::sponge::world::extent::worker::procedure::BlockVolumeMerger) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker", self.object, "merge", "(Lorg/spongepowered/api/world/extent/BlockVolume;Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeMerger;)V", CallVoidMethodA, block_volume.object, block_volume_merger.object);
+  }
+
+  #[cfg(feature = "event")]
+  pub fn fill(&self, block_volume_filler: ::sponge::world::extent::worker::procedure::BlockVolumeFiller, cause: ::sponge::event::cause::Cause) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker", self.object, "fill", "(Lorg/spongepowered/api/world/extent/worker/procedure/BlockVolumeFiller;Lorg/spongepowered/api/event/cause/Cause;)V", CallVoidMethodA, block_volume_filler.object, cause.object);
+  }
+
+}
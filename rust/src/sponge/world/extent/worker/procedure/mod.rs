@@ -0,0 +1,290 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BiomeAreaFiller {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaFiller {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaFiller {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaFiller`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaFiller", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn produce(&self, value: i32, value_2: i32) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaFiller", self.object, "produce", "(II)Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BiomeAreaFiller#produce was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeAreaMapper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaMapper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaMapper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaMapper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMapper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn map(&self, unmodifiable_biome_area: ::sponge::world::extent::UnmodifiableBiomeArea, value: i32, value_2: i32) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMapper", self.object, "map", "(Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;II)Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethodA, unmodifiable_biome_area.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMapper#map was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeAreaMerger {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaMerger {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaMerger {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaMerger`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMerger", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn merge(&self, unmodifiable_biome_area: ::sponge::world::extent::UnmodifiableBiomeArea, value: i32, value_2: i32, unmodifiable_biome_area_2: ::sponge::world::extent::UnmodifiableBiomeArea, value_3: i32, value_4: i32) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMerger", self.object, "merge", "(Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;IILorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;II)Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethodA, unmodifiable_biome_area.object, value, value_2, unmodifiable_biome_area_2.object, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BiomeAreaMerger#merge was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeAreaReducer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaReducer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaReducer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaReducer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaReducer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn reduce(&self, unmodifiable_biome_area: ::sponge::world::extent::UnmodifiableBiomeArea, value: i32, value_2: i32, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaReducer", self.object, "reduce", "(Lorg/spongepowered/api/world/extent/UnmodifiableBiomeArea;IILjava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, unmodifiable_biome_area.object, value, value_2, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BiomeAreaReducer#reduce was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeAreaVisitor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeAreaVisitor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeAreaVisitor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeAreaVisitor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaVisitor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn visit(&self, biome_area: ::sponge::world::extent::BiomeArea, value: i32, value_2: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BiomeAreaVisitor", self.object, "visit", "(Lorg/spongepowered/api/world/extent/BiomeArea;II)V", CallVoidMethodA, biome_area.object, value, value_2);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeFiller {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeFiller {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeFiller {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeFiller`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeFiller", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn produce(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeFiller", self.object, "produce", "(III)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BlockVolumeFiller#produce was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeMapper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeMapper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeMapper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeMapper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMapper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn map(&self, unmodifiable_block_volume: ::sponge::world::extent::UnmodifiableBlockVolume, value: i32, value_2: i32, value_3: i32) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMapper", self.object, "map", "(Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;III)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, unmodifiable_block_volume.object, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMapper#map was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeMerger {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeMerger {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeMerger {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeMerger`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMerger", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "block")]
+  pub fn merge(&self, unmodifiable_block_volume: ::sponge::world::extent::UnmodifiableBlockVolume, value: i32, value_2: i32, value_3: i32, unmodifiable_block_volume_2: ::sponge::world::extent::UnmodifiableBlockVolume, value_4: i32, value_5: i32, value_6: i32) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMerger", self.object, "merge", "(Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;IIILorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;III)Lorg/spongepowered/api/block/BlockState;", CallObjectMethodA, unmodifiable_block_volume.object, value, value_2, value_3, unmodifiable_block_volume_2.object, value_4, value_5, value_6);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BlockVolumeMerger#merge was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeReducer {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeReducer {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeReducer {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeReducer`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeReducer", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn reduce(&self, unmodifiable_block_volume: ::sponge::world::extent::UnmodifiableBlockVolume, value: i32, value_2: i32, value_3: i32, object: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeReducer", self.object, "reduce", "(Lorg/spongepowered/api/world/extent/UnmodifiableBlockVolume;IIILjava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, unmodifiable_block_volume.object, value, value_2, value_3, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/extent/worker/procedure/BlockVolumeReducer#reduce was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockVolumeVisitor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockVolumeVisitor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockVolumeVisitor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockVolumeVisitor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeVisitor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn visit(&self, block_volume: ::sponge::world::extent::BlockVolume, value: i32, value_2: i32, value_3: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/extent/worker/procedure/BlockVolumeVisitor", self.object, "visit", "(Lorg/spongepowered/api/world/extent/BlockVolume;III)V", CallVoidMethodA, block_volume.object, value, value_2, value_3);
+  }
+
+}
@@ -0,0 +1,30 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct DefaultGameRules {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DefaultGameRules {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DefaultGameRules {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `DefaultGameRules`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gamerule/DefaultGameRules", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::world::gamerule::DefaultGameRules {
+    let ret = new_object!(env, "org/spongepowered/api/world/gamerule/DefaultGameRules", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/world/gamerule/DefaultGameRules#<init> was null") }
+    ::sponge::world::gamerule::DefaultGameRules { env: env, object: ret }
+  }
+
+}
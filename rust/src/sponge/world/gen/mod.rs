@@ -0,0 +1,315 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod populator;
+pub mod type_;
+
+#[derive(Debug)]
+pub struct BiomeGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/BiomeGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn generate_biomes(&self, mutable_biome_area: ::sponge::world::extent::MutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/BiomeGenerator", self.object, "generateBiomes", "(Lorg/spongepowered/api/world/extent/MutableBiomeArea;)V", CallVoidMethodA, mutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GenerationPopulator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GenerationPopulator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GenerationPopulator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GenerationPopulator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/GenerationPopulator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn populate(&self, world: ::sponge::world::World, mutable_block_volume: ::sponge::world::extent::MutableBlockVolume, immutable_biome_area: ::sponge::world::extent::ImmutableBiomeArea) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/GenerationPopulator", self.object, "populate", "(Lorg/spongepowered/api/world/World;Lorg/spongepowered/api/world/extent/MutableBlockVolume;Lorg/spongepowered/api/world/extent/ImmutableBiomeArea;)V", CallVoidMethodA, world.object, mutable_block_volume.object, immutable_biome_area.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Populator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Populator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Populator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Populator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/Populator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::world::gen::PopulatorType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/Populator", self.object, "getType", "()Lorg/spongepowered/api/world/gen/PopulatorType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/Populator#getType was null") }
+    ::sponge::world::gen::PopulatorType { env: self.env, object: ret }
+  }
+
+  pub fn populate(&self, world: ::sponge::world::World, extent: ::sponge::world::extent::Extent, random: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/Populator", self.object, "populate", "(Lorg/spongepowered/api/world/World;Lorg/spongepowered/api/world/extent/Extent;Ljava/util/Random;)V", CallVoidMethodA, world.object, extent.object, random);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PopulatorObject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PopulatorObject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PopulatorObject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PopulatorObject`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/PopulatorObject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn can_place_at(&self, world: ::sponge::world::World, value: i32, value_2: i32, value_3: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/PopulatorObject", self.object, "canPlaceAt", "(Lorg/spongepowered/api/world/World;III)Z", CallBooleanMethodA, world.object, value, value_2, value_3) != 0
+  }
+
+  pub fn place_object(&self, world: ::sponge::world::World, random: jobject, value: i32, value_2: i32, value_3: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/PopulatorObject", self.object, "placeObject", "(Lorg/spongepowered/api/world/World;Ljava/util/Random;III)V", CallVoidMethodA, world.object, random, value, value_2, value_3);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PopulatorObjects {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PopulatorObjects {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PopulatorObjects {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PopulatorObjects`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/PopulatorObjects", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PopulatorType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PopulatorType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PopulatorType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PopulatorType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/PopulatorType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PopulatorTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PopulatorTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PopulatorTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PopulatorTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/PopulatorTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldGenerator {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldGenerator {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldGenerator {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldGenerator`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/WorldGenerator", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_base_generation_populator(&self) -> ::sponge::world::gen::GenerationPopulator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getBaseGenerationPopulator", "()Lorg/spongepowered/api/world/gen/GenerationPopulator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getBaseGenerationPopulator was null") }
+    ::sponge::world::gen::GenerationPopulator { env: self.env, object: ret }
+  }
+
+  pub fn set_base_generation_populator(&self, generation_populator: ::sponge::world::gen::GenerationPopulator) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "setBaseGenerationPopulator", "(Lorg/spongepowered/api/world/gen/GenerationPopulator;)V", CallVoidMethodA, generation_populator.object);
+  }
+
+  pub fn get_generation_populators(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getGenerationPopulators", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getGenerationPopulators was null") }
+    ret
+  }
+
+  pub fn get_generation_populators_class(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getGenerationPopulators", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getGenerationPopulators was null") }
+    ret
+  }
+
+  pub fn get_populators(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getPopulators", "()Ljava/util/List;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getPopulators was null") }
+    ret
+  }
+
+  pub fn get_populators_class(&self, class: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getPopulators", "(Ljava/lang/Class;)Ljava/util/List;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getPopulators was null") }
+    ret
+  }
+
+  pub fn get_biome_generator(&self) -> ::sponge::world::gen::BiomeGenerator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getBiomeGenerator", "()Lorg/spongepowered/api/world/gen/BiomeGenerator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getBiomeGenerator was null") }
+    ::sponge::world::gen::BiomeGenerator { env: self.env, object: ret }
+  }
+
+  pub fn set_biome_generator(&self, biome_generator: ::sponge::world::gen::BiomeGenerator) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "setBiomeGenerator", "(Lorg/spongepowered/api/world/gen/BiomeGenerator;)V", CallVoidMethodA, biome_generator.object);
+  }
+
+  pub fn get_biome_settings(&self, biome_type: ::sponge::world::biome::BiomeType) -> ::sponge::world::biome::BiomeGenerationSettings {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGenerator", self.object, "getBiomeSettings", "(Lorg/spongepowered/api/world/biome/BiomeType;)Lorg/spongepowered/api/world/biome/BiomeGenerationSettings;", CallObjectMethodA, biome_type.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/WorldGenerator#getBiomeSettings was null") }
+    ::sponge::world::biome::BiomeGenerationSettings { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldGeneratorModifier {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldGeneratorModifier {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldGeneratorModifier {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldGeneratorModifier`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/WorldGeneratorModifier", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn modify_world_generator(&self, world_properties: ::sponge::world::storage::WorldProperties, data_container: ::sponge::data::DataContainer, world_generator: ::sponge::world::gen::WorldGenerator) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/WorldGeneratorModifier", self.object, "modifyWorldGenerator", "(Lorg/spongepowered/api/world/storage/WorldProperties;Lorg/spongepowered/api/data/DataContainer;Lorg/spongepowered/api/world/gen/WorldGenerator;)V", CallVoidMethodA, world_properties.object, data_container.object, world_generator.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldGeneratorModifiers {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldGeneratorModifiers {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldGeneratorModifiers {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldGeneratorModifiers`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/WorldGeneratorModifiers", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,3445 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BigMushroom {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BigMushroom {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BigMushroom {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BigMushroom`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/BigMushroom", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/BigMushroom", "builder", "()Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom#builder was null") }
+    ::sponge::world::gen::populator::big_mushroom::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_types(&self) -> ::sponge::util::weighted::WeightedTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "getTypes", "()Lorg/spongepowered/api/util/weighted/WeightedTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom#getTypes was null") }
+    ::sponge::util::weighted::WeightedTable { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_mushrooms_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "getMushroomsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom#getMushroomsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_mushrooms_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "setMushroomsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_mushrooms_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "setMushroomsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BlockBlob {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BlockBlob {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BlockBlob {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BlockBlob`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/BlockBlob", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::block_blob::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/BlockBlob", "builder", "()Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob#builder was null") }
+    ::sponge::world::gen::populator::block_blob::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_block(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "setBlock", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_radius(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "getRadius", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob#getRadius was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "setRadius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_radius_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "setRadius", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_count(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "getCount", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob#getCount was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_count_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "setCount", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_count_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob", self.object, "setCount", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Cactus {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Cactus {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Cactus {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Cactus`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Cactus", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::cactus::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Cactus", "builder", "()Lorg/spongepowered/api/world/gen/populator/Cactus$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus#builder was null") }
+    ::sponge::world::gen::populator::cactus::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_cacti_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "getCactiPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus#getCactiPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_cacti_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "setCactiPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_cacti_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "setCactiPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "getHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus#getHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "setHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_height_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus", self.object, "setHeight", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChorusFlower {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChorusFlower {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChorusFlower {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChorusFlower`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/ChorusFlower", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::chorus_flower::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/ChorusFlower", "builder", "()Lorg/spongepowered/api/world/gen/populator/ChorusFlower$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/ChorusFlower#builder was null") }
+    ::sponge::world::gen::populator::chorus_flower::Builder { env: env, object: ret }
+  }
+
+  pub fn get_exclusion_radius(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/ChorusFlower", self.object, "getExclusionRadius", "()I", CallIntMethod)
+  }
+
+  pub fn set_exclusion_radius(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/ChorusFlower", self.object, "setExclusionRadius", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DeadBush {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DeadBush {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DeadBush {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DeadBush`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DeadBush", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::dead_bush::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/DeadBush", "builder", "()Lorg/spongepowered/api/world/gen/populator/DeadBush$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DeadBush#builder was null") }
+    ::sponge::world::gen::populator::dead_bush::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_shrubs_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush", self.object, "getShrubsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DeadBush#getShrubsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_shrubs_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush", self.object, "setShrubsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_shrubs_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush", self.object, "setShrubsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DesertWell {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DesertWell {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DesertWell {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DesertWell`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DesertWell", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::desert_well::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/DesertWell", "builder", "()Lorg/spongepowered/api/world/gen/populator/DesertWell$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DesertWell#builder was null") }
+    ::sponge::world::gen::populator::desert_well::Builder { env: env, object: ret }
+  }
+
+  pub fn get_spawn_probability(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell", self.object, "getSpawnProbability", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_spawn_probability(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell", self.object, "setSpawnProbability", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_well_object(&self) -> ::sponge::world::gen::PopulatorObject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell", self.object, "getWellObject", "()Lorg/spongepowered/api/world/gen/PopulatorObject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DesertWell#getWellObject was null") }
+    ::sponge::world::gen::PopulatorObject { env: self.env, object: ret }
+  }
+
+  pub fn set_well_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell", self.object, "setWellObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)V", CallVoidMethodA, populator_object.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DoublePlant {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DoublePlant {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DoublePlant {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DoublePlant`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DoublePlant", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::double_plant::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/DoublePlant", "builder", "()Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant#builder was null") }
+    ::sponge::world::gen::populator::double_plant::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_possible_types(&self) -> ::sponge::util::weighted::WeightedTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "getPossibleTypes", "()Lorg/spongepowered/api/util/weighted/WeightedTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant#getPossibleTypes was null") }
+    ::sponge::util::weighted::WeightedTable { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_plants_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "getPlantsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant#getPlantsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_plants_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "setPlantsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_plants_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "setPlantsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Dungeon {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dungeon {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dungeon {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dungeon`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Dungeon", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::dungeon::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Dungeon", "builder", "()Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon#builder was null") }
+    ::sponge::world::gen::populator::dungeon::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_attempts_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon", self.object, "getAttemptsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon#getAttemptsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_attempts_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon", self.object, "setAttemptsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_attempts_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon", self.object, "setAttemptsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_spawner_data(&self) -> ::sponge::data::manipulator::mutable::MobSpawnerData {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon", self.object, "getSpawnerData", "()Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon#getSpawnerData was null") }
+    ::sponge::data::manipulator::mutable::MobSpawnerData { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_possible_contents(&self) -> ::sponge::util::weighted::LootTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon", self.object, "getPossibleContents", "()Lorg/spongepowered/api/util/weighted/LootTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon#getPossibleContents was null") }
+    ::sponge::util::weighted::LootTable { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct EndIsland {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl EndIsland {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    EndIsland {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `EndIsland`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/EndIsland", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::end_island::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/EndIsland", "builder", "()Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland#builder was null") }
+    ::sponge::world::gen::populator::end_island::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_starting_radius(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "getStartingRadius", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland#getStartingRadius was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_starting_radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setStartingRadius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_starting_radius_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setStartingRadius", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_radius_decrement(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "getRadiusDecrement", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland#getRadiusDecrement was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_radius_decrement_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setRadiusDecrement", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_radius_decrement_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setRadiusDecrement", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_island_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "getIslandBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland#getIslandBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_island_block(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setIslandBlock", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  pub fn get_exclusion_radius(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "getExclusionRadius", "()I", CallIntMethod)
+  }
+
+  pub fn set_exclusion_radius(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland", self.object, "setExclusionRadius", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Flower {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Flower {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Flower {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Flower`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Flower", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::flower::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Flower", "builder", "()Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower#builder was null") }
+    ::sponge::world::gen::populator::flower::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_flowers_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "getFlowersPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower#getFlowersPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_flowers_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "setFlowersPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_flowers_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "setFlowersPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_flower_types(&self) -> ::sponge::util::weighted::WeightedTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "getFlowerTypes", "()Lorg/spongepowered/api/util/weighted/WeightedTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower#getFlowerTypes was null") }
+    ::sponge::util::weighted::WeightedTable { env: self.env, object: ret }
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Forest {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Forest {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Forest {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Forest`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Forest", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::forest::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Forest", "builder", "()Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest#builder was null") }
+    ::sponge::world::gen::populator::forest::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_trees_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "getTreesPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest#getTreesPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_trees_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "setTreesPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_trees_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "setTreesPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_types(&self) -> ::sponge::util::weighted::WeightedTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "getTypes", "()Lorg/spongepowered/api/util/weighted/WeightedTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest#getTypes was null") }
+    ::sponge::util::weighted::WeightedTable { env: self.env, object: ret }
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Glowstone {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Glowstone {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Glowstone {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Glowstone`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Glowstone", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::glowstone::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Glowstone", "builder", "()Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone#builder was null") }
+    ::sponge::world::gen::populator::glowstone::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_clusters_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "getClustersPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone#getClustersPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_clusters_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setClustersPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_clusters_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setClustersPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_attempts_per_cluster(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "getAttemptsPerCluster", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone#getAttemptsPerCluster was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_attempts_per_cluster_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setAttemptsPerCluster", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_attempts_per_cluster_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setAttemptsPerCluster", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_cluster_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "getClusterHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone#getClusterHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_cluster_height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setClusterHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_cluster_height_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setClusterHeight", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "getHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone#getHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone", self.object, "setHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IcePath {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IcePath {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IcePath {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IcePath`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/IcePath", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::ice_path::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/IcePath", "builder", "()Lorg/spongepowered/api/world/gen/populator/IcePath$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath#builder was null") }
+    ::sponge::world::gen::populator::ice_path::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_radius(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "getRadius", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath#getRadius was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "setRadius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_radius_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "setRadius", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_sections_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "getSectionsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath#getSectionsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_sections_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "setSectionsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_sections_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath", self.object, "setSectionsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct IceSpike {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl IceSpike {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    IceSpike {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `IceSpike`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/IceSpike", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::ice_spike::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/IceSpike", "builder", "()Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike#builder was null") }
+    ::sponge::world::gen::populator::ice_spike::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_spikes_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "getSpikesPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike#getSpikesPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_spikes_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setSpikesPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_spikes_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setSpikesPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "getHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike#getHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_height_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setHeight", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_extreme_spike_probability(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "getExtremeSpikeProbability", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_extreme_spike_probability(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setExtremeSpikeProbability", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_extreme_spike_increase(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "getExtremeSpikeIncrease", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike#getExtremeSpikeIncrease was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_extreme_spike_increase_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setExtremeSpikeIncrease", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_extreme_spike_increase_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike", self.object, "setExtremeSpikeIncrease", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Lake {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Lake {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Lake {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Lake`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Lake", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::lake::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Lake", "builder", "()Lorg/spongepowered/api/world/gen/populator/Lake$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake#builder was null") }
+    ::sponge::world::gen::populator::lake::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_liquid_type(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "getLiquidType", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake#getLiquidType was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_liquid_type(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "setLiquidType", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  pub fn get_lake_probability(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "getLakeProbability", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_lake_probability(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "setLakeProbability", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "getHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake#getHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake", self.object, "setHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Melon {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Melon {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Melon {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Melon`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Melon", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::melon::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Melon", "builder", "()Lorg/spongepowered/api/world/gen/populator/Melon$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Melon#builder was null") }
+    ::sponge::world::gen::populator::melon::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_melons_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon", self.object, "getMelonsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Melon#getMelonsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_melons_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon", self.object, "setMelonsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_melons_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon", self.object, "setMelonsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Mushroom {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Mushroom {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Mushroom {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Mushroom`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Mushroom", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::mushroom::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Mushroom", "builder", "()Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom#builder was null") }
+    ::sponge::world::gen::populator::mushroom::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_types(&self) -> ::sponge::util::weighted::ChanceTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "getTypes", "()Lorg/spongepowered/api/util/weighted/ChanceTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom#getTypes was null") }
+    ::sponge::util::weighted::ChanceTable { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_mushrooms_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "getMushroomsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom#getMushroomsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_mushrooms_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "setMushroomsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_mushrooms_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "setMushroomsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct NetherFire {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl NetherFire {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    NetherFire {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `NetherFire`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/NetherFire", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::nether_fire::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/NetherFire", "builder", "()Lorg/spongepowered/api/world/gen/populator/NetherFire$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire#builder was null") }
+    ::sponge::world::gen::populator::nether_fire::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_clusters_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "getClustersPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire#getClustersPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_clusters_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "setClustersPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_clusters_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "setClustersPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_fire_per_cluster(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "getFirePerCluster", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire#getFirePerCluster was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_fire_per_cluster_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "setFirePerCluster", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_fire_per_cluster_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire", self.object, "setFirePerCluster", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Ore {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Ore {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Ore {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Ore`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Ore", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::ore::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Ore", "builder", "()Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#builder was null") }
+    ::sponge::world::gen::populator::ore::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_ore_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "getOreBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#getOreBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_ore_block(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setOreBlock", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_deposit_size(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "getDepositSize", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#getDepositSize was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_deposit_size_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setDepositSize", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_deposit_size_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setDepositSize", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_deposits_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "getDepositsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#getDepositsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_deposits_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setDepositsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_deposits_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setDepositsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "getHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#getHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn get_placement_condition(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "getPlacementCondition", "()Ljava/util/function/Predicate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore#getPlacementCondition was null") }
+    ret
+  }
+
+  pub fn set_placement_condition(&self, predicate: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore", self.object, "setPlacementCondition", "(Ljava/util/function/Predicate;)V", CallVoidMethodA, predicate);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Pumpkin {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Pumpkin {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Pumpkin {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Pumpkin`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Pumpkin", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::pumpkin::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Pumpkin", "builder", "()Lorg/spongepowered/api/world/gen/populator/Pumpkin$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin#builder was null") }
+    ::sponge::world::gen::populator::pumpkin::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_pumpkins_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin", self.object, "getPumpkinsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin#getPumpkinsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_pumpkins_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin", self.object, "setPumpkinsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_pumpkins_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin", self.object, "setPumpkinsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_pumpkin_chance(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin", self.object, "getPumpkinChance", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_pumpkin_chance(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin", self.object, "setPumpkinChance", "(D)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RandomBlock {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RandomBlock {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RandomBlock {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RandomBlock`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/RandomBlock", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::random_block::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/RandomBlock", "builder", "()Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock#builder was null") }
+    ::sponge::world::gen::populator::random_block::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_block(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "setBlock", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_attempts_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "getAttemptsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock#getAttemptsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_attempts_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "setAttemptsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_attempts_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "setAttemptsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height_range(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "getHeightRange", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock#getHeightRange was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height_range(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "setHeightRange", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn get_placement_target(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "getPlacementTarget", "()Ljava/util/function/Predicate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock#getPlacementTarget was null") }
+    ret
+  }
+
+  pub fn get_placement_target_predicate(&self, predicate: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock", self.object, "getPlacementTarget", "(Ljava/util/function/Predicate;)V", CallVoidMethodA, predicate);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct RandomObject {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl RandomObject {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    RandomObject {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `RandomObject`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/RandomObject", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::random_object::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/RandomObject", "builder", "()Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject#builder was null") }
+    ::sponge::world::gen::populator::random_object::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_attempts_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "getAttemptsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject#getAttemptsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_attempts_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "setAttemptsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_attempts_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "setAttemptsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_height_range(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "getHeightRange", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject#getHeightRange was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_height_range(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "setHeightRange", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn get_object(&self) -> ::sponge::world::gen::PopulatorObject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "getObject", "()Lorg/spongepowered/api/world/gen/PopulatorObject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject#getObject was null") }
+    ::sponge::world::gen::PopulatorObject { env: self.env, object: ret }
+  }
+
+  pub fn set_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "setObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)V", CallVoidMethodA, populator_object.object);
+  }
+
+  pub fn get_spawn_chance(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "getSpawnChance", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_spawn_chance(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject", self.object, "setSpawnChance", "(D)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Reed {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Reed {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Reed {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Reed`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Reed", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::reed::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Reed", "builder", "()Lorg/spongepowered/api/world/gen/populator/Reed$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed#builder was null") }
+    ::sponge::world::gen::populator::reed::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_reeds_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed", self.object, "getReedsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed#getReedsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_reeds_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed", self.object, "setReedsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_reeds_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed", self.object, "setReedsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_reed_height(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed", self.object, "getReedHeight", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed#getReedHeight was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_reed_height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed", self.object, "setReedHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SeaFloor {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SeaFloor {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SeaFloor {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SeaFloor`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/SeaFloor", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::sea_floor::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/SeaFloor", "builder", "()Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#builder was null") }
+    ::sponge::world::gen::populator::sea_floor::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn set_block(&self, block_state: ::sponge::block::BlockState) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setBlock", "(Lorg/spongepowered/api/block/BlockState;)V", CallVoidMethodA, block_state.object);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_discs_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "getDiscsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#getDiscsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_discs_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setDiscsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_discs_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setDiscsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_radius(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "getRadius", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#getRadius was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setRadius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_radius_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setRadius", "(D)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_depth(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "getDepth", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#getDepth was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_depth_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setDepth", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_depth_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setDepth", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_valid_blocks_to_replace(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "getValidBlocksToReplace", "()Ljava/util/function/Predicate;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor#getValidBlocksToReplace was null") }
+    ret
+  }
+
+  pub fn set_valid_blocks_to_replace(&self, predicate: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor", self.object, "setValidBlocksToReplace", "(Ljava/util/function/Predicate;)V", CallVoidMethodA, predicate);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Shrub {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Shrub {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Shrub {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Shrub`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Shrub", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::shrub::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Shrub", "builder", "()Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub#builder was null") }
+    ::sponge::world::gen::populator::shrub::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_types(&self) -> ::sponge::util::weighted::WeightedTable {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "getTypes", "()Lorg/spongepowered/api/util/weighted/WeightedTable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub#getTypes was null") }
+    ::sponge::util::weighted::WeightedTable { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_shrubs_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "getShrubsPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub#getShrubsPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_shrubs_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "setShrubsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_shrubs_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "setShrubsPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_supplier_override(&self) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "getSupplierOverride", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub#getSupplierOverride was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn set_supplier_override(&self, function: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "setSupplierOverride", "(Ljava/util/function/Function;)V", CallVoidMethodA, function);
+  }
+
+  pub fn clear_supplier_override(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub", self.object, "clearSupplierOverride", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Vine {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Vine {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Vine {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Vine`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Vine", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::vine::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/Vine", "builder", "()Lorg/spongepowered/api/world/gen/populator/Vine$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Vine#builder was null") }
+    ::sponge::world::gen::populator::vine::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_vines_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine", self.object, "getVinesPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Vine#getVinesPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_vines_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine", self.object, "setVinesPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_vines_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine", self.object, "setVinesPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WaterLily {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WaterLily {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WaterLily {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WaterLily`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/WaterLily", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::gen::populator::water_lily::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/gen/populator/WaterLily", "builder", "()Lorg/spongepowered/api/world/gen/populator/WaterLily$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/WaterLily#builder was null") }
+    ::sponge::world::gen::populator::water_lily::Builder { env: env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_water_lily_per_chunk(&self) -> ::sponge::util::weighted::VariableAmount {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily", self.object, "getWaterLilyPerChunk", "()Lorg/spongepowered/api/util/weighted/VariableAmount;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/WaterLily#getWaterLilyPerChunk was null") }
+    ::sponge::util::weighted::VariableAmount { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn set_water_lily_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily", self.object, "setWaterLilyPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)V", CallVoidMethodA, variable_amount.object);
+  }
+
+  pub fn set_water_lily_per_chunk_i32(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily", self.object, "setWaterLilyPerChunk", "(I)V", CallVoidMethodA, value);
+  }
+
+}
+
+pub mod big_mushroom {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#types was null") }
+      ::sponge::world::gen::populator::big_mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, populator_object: ::sponge::world::gen::PopulatorObject, value: f64) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "type", "(Lorg/spongepowered/api/world/gen/PopulatorObject;D)Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallObjectMethodA, populator_object.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#type was null") }
+      ::sponge::world::gen::populator::big_mushroom::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn mushrooms_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "mushroomsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#mushroomsPerChunk was null") }
+      ::sponge::world::gen::populator::big_mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn mushrooms_per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "mushroomsPerChunk", "(I)Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#mushroomsPerChunk was null") }
+      ::sponge::world::gen::populator::big_mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::big_mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/BigMushroom$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#supplier was null") }
+      ::sponge::world::gen::populator::big_mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::BigMushroom {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BigMushroom$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/BigMushroom;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BigMushroom$Builder#build was null") }
+      ::sponge::world::gen::populator::BigMushroom { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod block_blob {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::block_blob::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#block was null") }
+      ::sponge::world::gen::populator::block_blob::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::block_blob::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "radius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#radius was null") }
+      ::sponge::world::gen::populator::block_blob::Builder { env: self.env, object: ret }
+    }
+
+    pub fn radius_f64(&self, value: f64) -> ::sponge::world::gen::populator::block_blob::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "radius", "(D)Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#radius was null") }
+      ::sponge::world::gen::populator::block_blob::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn blob_count_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::block_blob::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "blobCount", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#blobCount was null") }
+      ::sponge::world::gen::populator::block_blob::Builder { env: self.env, object: ret }
+    }
+
+    pub fn blob_count_i32(&self, value: i32) -> ::sponge::world::gen::populator::block_blob::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "blobCount", "(I)Lorg/spongepowered/api/world/gen/populator/BlockBlob$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#blobCount was null") }
+      ::sponge::world::gen::populator::block_blob::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::BlockBlob {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/BlockBlob$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/BlockBlob;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/BlockBlob$Builder#build was null") }
+      ::sponge::world::gen::populator::BlockBlob { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod cactus {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Cactus$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn cacti_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::cactus::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus$Builder", self.object, "cactiPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Cactus$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus$Builder#cactiPerChunk was null") }
+      ::sponge::world::gen::populator::cactus::Builder { env: self.env, object: ret }
+    }
+
+    pub fn cacti_per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::cactus::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus$Builder", self.object, "cactiPerChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Cactus$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus$Builder#cactiPerChunk was null") }
+      ::sponge::world::gen::populator::cactus::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::cactus::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Cactus$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus$Builder#height was null") }
+      ::sponge::world::gen::populator::cactus::Builder { env: self.env, object: ret }
+    }
+
+    pub fn height_i32(&self, value: i32) -> ::sponge::world::gen::populator::cactus::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus$Builder", self.object, "height", "(I)Lorg/spongepowered/api/world/gen/populator/Cactus$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus$Builder#height was null") }
+      ::sponge::world::gen::populator::cactus::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Cactus {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Cactus$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Cactus;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Cactus$Builder#build was null") }
+      ::sponge::world::gen::populator::Cactus { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod chorus_flower {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/ChorusFlower$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn exclusion_radius(&self, value: i32) -> ::sponge::world::gen::populator::chorus_flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/ChorusFlower$Builder", self.object, "exclusionRadius", "(I)Lorg/spongepowered/api/world/gen/populator/ChorusFlower$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/ChorusFlower$Builder#exclusionRadius was null") }
+      ::sponge::world::gen::populator::chorus_flower::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::ChorusFlower {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/ChorusFlower$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/ChorusFlower;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/ChorusFlower$Builder#build was null") }
+      ::sponge::world::gen::populator::ChorusFlower { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod dead_bush {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DeadBush$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::dead_bush::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/DeadBush$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DeadBush$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::dead_bush::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::dead_bush::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/DeadBush$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DeadBush$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::dead_bush::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::DeadBush {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DeadBush$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/DeadBush;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DeadBush$Builder#build was null") }
+      ::sponge::world::gen::populator::DeadBush { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod desert_well {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DesertWell$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn probability(&self, value: f64) -> ::sponge::world::gen::populator::desert_well::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell$Builder", self.object, "probability", "(D)Lorg/spongepowered/api/world/gen/populator/DesertWell$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DesertWell$Builder#probability was null") }
+      ::sponge::world::gen::populator::desert_well::Builder { env: self.env, object: ret }
+    }
+
+    pub fn well_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) -> ::sponge::world::gen::populator::desert_well::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell$Builder", self.object, "wellObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)Lorg/spongepowered/api/world/gen/populator/DesertWell$Builder;", CallObjectMethodA, populator_object.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DesertWell$Builder#wellObject was null") }
+      ::sponge::world::gen::populator::desert_well::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::DesertWell {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DesertWell$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/DesertWell;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DesertWell$Builder#build was null") }
+      ::sponge::world::gen::populator::DesertWell { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod double_plant {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::double_plant::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#types was null") }
+      ::sponge::world::gen::populator::double_plant::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn type_(&self, double_plant_type: ::sponge::data::type_::DoublePlantType, value: f64) -> ::sponge::world::gen::populator::double_plant::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "type", "(Lorg/spongepowered/api/data/type/DoublePlantType;D)Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallObjectMethodA, double_plant_type.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#type was null") }
+      ::sponge::world::gen::populator::double_plant::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::double_plant::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::double_plant::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::double_plant::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::double_plant::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::double_plant::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/DoublePlant$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#supplier was null") }
+      ::sponge::world::gen::populator::double_plant::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::DoublePlant {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/DoublePlant$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/DoublePlant;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/DoublePlant$Builder#build was null") }
+      ::sponge::world::gen::populator::DoublePlant { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod dungeon {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn attempts_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "attempts", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#attempts was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn attempts_i32(&self, value: i32) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "attempts", "(I)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#attempts was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn mob_spawner_data(&self, mob_spawner_data: ::sponge::data::manipulator::mutable::MobSpawnerData) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "mobSpawnerData", "(Lorg/spongepowered/api/data/manipulator/mutable/MobSpawnerData;)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, mob_spawner_data.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#mobSpawnerData was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn minimum_spawn_delay(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "minimumSpawnDelay", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#minimumSpawnDelay was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn maximum_spawn_delay(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "maximumSpawnDelay", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#maximumSpawnDelay was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn spawn_count(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "spawnCount", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#spawnCount was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn maximum_nearby_entities(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "maximumNearbyEntities", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#maximumNearbyEntities was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn required_player_range(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "requiredPlayerRange", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#requiredPlayerRange was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn spawn_range(&self, value: i16) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "spawnRange", "(S)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#spawnRange was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn possible_entities(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "possibleEntities", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#possibleEntities was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn possible_items(&self, loot_table: ::sponge::util::weighted::LootTable) -> ::sponge::world::gen::populator::dungeon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "possibleItems", "(Lorg/spongepowered/api/util/weighted/LootTable;)Lorg/spongepowered/api/world/gen/populator/Dungeon$Builder;", CallObjectMethodA, loot_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#possibleItems was null") }
+      ::sponge::world::gen::populator::dungeon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Dungeon {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Dungeon$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Dungeon;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Dungeon$Builder#build was null") }
+      ::sponge::world::gen::populator::Dungeon { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod end_island {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn starting_radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "startingRadius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#startingRadius was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    pub fn starting_radius_f64(&self, value: f64) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "startingRadius", "(D)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#startingRadius was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn radius_decrement_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "radiusDecrement", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#radiusDecrement was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    pub fn radius_decrement_f64(&self, value: f64) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "radiusDecrement", "(D)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#radiusDecrement was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "block")]
+    pub fn island_block(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "islandBlock", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#islandBlock was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    pub fn exclusion_radius(&self, value: i32) -> ::sponge::world::gen::populator::end_island::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "exclusionRadius", "(I)Lorg/spongepowered/api/world/gen/populator/EndIsland$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#exclusionRadius was null") }
+      ::sponge::world::gen::populator::end_island::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::EndIsland {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/EndIsland$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/EndIsland;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/EndIsland$Builder#build was null") }
+      ::sponge::world::gen::populator::EndIsland { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod flower {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Flower$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::flower::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::flower::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#types was null") }
+      ::sponge::world::gen::populator::flower::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn type_(&self, plant_type: ::sponge::data::type_::PlantType, value: f64) -> ::sponge::world::gen::populator::flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "type", "(Lorg/spongepowered/api/data/type/PlantType;D)Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallObjectMethodA, plant_type.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#type was null") }
+      ::sponge::world::gen::populator::flower::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::flower::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/Flower$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#supplier was null") }
+      ::sponge::world::gen::populator::flower::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Flower {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Flower$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Flower;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Flower$Builder#build was null") }
+      ::sponge::world::gen::populator::Flower { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod forest {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Forest$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::forest::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::forest::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::forest::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::forest::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::forest::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#types was null") }
+      ::sponge::world::gen::populator::forest::Builder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, populator_object: ::sponge::world::gen::PopulatorObject, value: f64) -> ::sponge::world::gen::populator::forest::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "type", "(Lorg/spongepowered/api/world/gen/PopulatorObject;D)Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallObjectMethodA, populator_object.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#type was null") }
+      ::sponge::world::gen::populator::forest::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::forest::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/Forest$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#supplier was null") }
+      ::sponge::world::gen::populator::forest::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Forest {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Forest$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Forest;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Forest$Builder#build was null") }
+      ::sponge::world::gen::populator::Forest { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod glowstone {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn blocks_per_cluster_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "blocksPerCluster", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#blocksPerCluster was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    pub fn blocks_per_cluster_i32(&self, value: i32) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "blocksPerCluster", "(I)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#blocksPerCluster was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn cluster_height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "clusterHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#clusterHeight was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    pub fn cluster_height_i32(&self, value: i32) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "clusterHeight", "(I)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#clusterHeight was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::glowstone::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Glowstone$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#height was null") }
+      ::sponge::world::gen::populator::glowstone::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Glowstone {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Glowstone$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Glowstone;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Glowstone$Builder#build was null") }
+      ::sponge::world::gen::populator::Glowstone { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod ice_path {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/IcePath$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ice_path::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath$Builder", self.object, "radius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/IcePath$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath$Builder#radius was null") }
+      ::sponge::world::gen::populator::ice_path::Builder { env: self.env, object: ret }
+    }
+
+    pub fn radius_f64(&self, value: f64) -> ::sponge::world::gen::populator::ice_path::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath$Builder", self.object, "radius", "(D)Lorg/spongepowered/api/world/gen/populator/IcePath$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath$Builder#radius was null") }
+      ::sponge::world::gen::populator::ice_path::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ice_path::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/IcePath$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::ice_path::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::ice_path::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/IcePath$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::ice_path::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::IcePath {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IcePath$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/IcePath;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IcePath$Builder#build was null") }
+      ::sponge::world::gen::populator::IcePath { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod ice_spike {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn spikes_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "spikesPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#spikesPerChunk was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    pub fn spikes_per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "spikesPerChunk", "(I)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#spikesPerChunk was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#height was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    pub fn height_i32(&self, value: i32) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "height", "(I)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#height was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    pub fn extreme_spike_probability(&self, value: f64) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "extremeSpikeProbability", "(D)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#extremeSpikeProbability was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn extreme_spike_increase_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "extremeSpikeIncrease", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#extremeSpikeIncrease was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    pub fn extreme_spike_increase_i32(&self, value: i32) -> ::sponge::world::gen::populator::ice_spike::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "extremeSpikeIncrease", "(I)Lorg/spongepowered/api/world/gen/populator/IceSpike$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#extremeSpikeIncrease was null") }
+      ::sponge::world::gen::populator::ice_spike::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::IceSpike {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/IceSpike$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/IceSpike;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/IceSpike$Builder#build was null") }
+      ::sponge::world::gen::populator::IceSpike { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod lake {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Lake$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn liquid_type(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::lake::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake$Builder", self.object, "liquidType", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/Lake$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake$Builder#liquidType was null") }
+      ::sponge::world::gen::populator::lake::Builder { env: self.env, object: ret }
+    }
+
+    pub fn chance(&self, value: f64) -> ::sponge::world::gen::populator::lake::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake$Builder", self.object, "chance", "(D)Lorg/spongepowered/api/world/gen/populator/Lake$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake$Builder#chance was null") }
+      ::sponge::world::gen::populator::lake::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::lake::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Lake$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake$Builder#height was null") }
+      ::sponge::world::gen::populator::lake::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Lake {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Lake$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Lake;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Lake$Builder#build was null") }
+      ::sponge::world::gen::populator::Lake { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod melon {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Melon$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::melon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Melon$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Melon$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::melon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::melon::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Melon$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Melon$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::melon::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Melon {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Melon$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Melon;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Melon$Builder#build was null") }
+      ::sponge::world::gen::populator::Melon { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod mushroom {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, chance_table: ::sponge::util::weighted::ChanceTable) -> ::sponge::world::gen::populator::mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/ChanceTable;)Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallObjectMethodA, chance_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#types was null") }
+      ::sponge::world::gen::populator::mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn type_(&self, mushroom_type: ::sponge::world::gen::type_::MushroomType, value: f64) -> ::sponge::world::gen::populator::mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "type", "(Lorg/spongepowered/api/world/gen/type/MushroomType;D)Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallObjectMethodA, mushroom_type.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#type was null") }
+      ::sponge::world::gen::populator::mushroom::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn mushrooms_per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "mushroomsPerChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#mushroomsPerChunk was null") }
+      ::sponge::world::gen::populator::mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn mushrooms_per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "mushroomsPerChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#mushroomsPerChunk was null") }
+      ::sponge::world::gen::populator::mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::mushroom::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/Mushroom$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#supplier was null") }
+      ::sponge::world::gen::populator::mushroom::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Mushroom {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Mushroom$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Mushroom;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Mushroom$Builder#build was null") }
+      ::sponge::world::gen::populator::Mushroom { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod nether_fire {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::nether_fire::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/NetherFire$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::nether_fire::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::nether_fire::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/NetherFire$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::nether_fire::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_cluster_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::nether_fire::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", self.object, "perCluster", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/NetherFire$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire$Builder#perCluster was null") }
+      ::sponge::world::gen::populator::nether_fire::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_cluster_i32(&self, value: i32) -> ::sponge::world::gen::populator::nether_fire::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", self.object, "perCluster", "(I)Lorg/spongepowered/api/world/gen/populator/NetherFire$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire$Builder#perCluster was null") }
+      ::sponge::world::gen::populator::nether_fire::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::NetherFire {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/NetherFire$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/NetherFire;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/NetherFire$Builder#build was null") }
+      ::sponge::world::gen::populator::NetherFire { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod ore {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Ore$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn ore(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "ore", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#ore was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn size_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "size", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#size was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    pub fn size_i32(&self, value: i32) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "size", "(I)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#size was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#height was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    pub fn placement_condition(&self, predicate: jobject) -> ::sponge::world::gen::populator::ore::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "placementCondition", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/world/gen/populator/Ore$Builder;", CallObjectMethodA, predicate);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#placementCondition was null") }
+      ::sponge::world::gen::populator::ore::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Ore {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Ore$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Ore;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Ore$Builder#build was null") }
+      ::sponge::world::gen::populator::Ore { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod pumpkin {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Pumpkin$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::pumpkin::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Pumpkin$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::pumpkin::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::pumpkin::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Pumpkin$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::pumpkin::Builder { env: self.env, object: ret }
+    }
+
+    pub fn chance(&self, value: f64) -> ::sponge::world::gen::populator::pumpkin::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin$Builder", self.object, "chance", "(D)Lorg/spongepowered/api/world/gen/populator/Pumpkin$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin$Builder#chance was null") }
+      ::sponge::world::gen::populator::pumpkin::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Pumpkin {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Pumpkin$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Pumpkin;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Pumpkin$Builder#build was null") }
+      ::sponge::world::gen::populator::Pumpkin { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod random_block {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::random_block::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#block was null") }
+      ::sponge::world::gen::populator::random_block::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::random_block::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::random_block::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::random_block::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::random_block::Builder { env: self.env, object: ret }
+    }
+
+    pub fn placement_target(&self, predicate: jobject) -> ::sponge::world::gen::populator::random_block::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "placementTarget", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallObjectMethodA, predicate);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#placementTarget was null") }
+      ::sponge::world::gen::populator::random_block::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::random_block::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/RandomBlock$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#height was null") }
+      ::sponge::world::gen::populator::random_block::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::RandomBlock {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomBlock$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/RandomBlock;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomBlock$Builder#build was null") }
+      ::sponge::world::gen::populator::RandomBlock { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod random_object {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::random_object::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::random_object::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::random_object::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::random_object::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::random_object::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "height", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#height was null") }
+      ::sponge::world::gen::populator::random_object::Builder { env: self.env, object: ret }
+    }
+
+    pub fn object(&self, populator_object: ::sponge::world::gen::PopulatorObject) -> ::sponge::world::gen::populator::random_object::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "object", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallObjectMethodA, populator_object.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#object was null") }
+      ::sponge::world::gen::populator::random_object::Builder { env: self.env, object: ret }
+    }
+
+    pub fn spawn_chance(&self, value: f64) -> ::sponge::world::gen::populator::random_object::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "spawnChance", "(D)Lorg/spongepowered/api/world/gen/populator/RandomObject$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#spawnChance was null") }
+      ::sponge::world::gen::populator::random_object::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::RandomObject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/RandomObject$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/RandomObject;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/RandomObject$Builder#build was null") }
+      ::sponge::world::gen::populator::RandomObject { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod reed {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Reed$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::reed::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Reed$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::reed::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::reed::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Reed$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::reed::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn reed_height(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::reed::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed$Builder", self.object, "reedHeight", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Reed$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed$Builder#reedHeight was null") }
+      ::sponge::world::gen::populator::reed::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Reed {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Reed$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Reed;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Reed$Builder#build was null") }
+      ::sponge::world::gen::populator::Reed { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod sea_floor {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "block")]
+    pub fn block(&self, block_state: ::sponge::block::BlockState) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "block", "(Lorg/spongepowered/api/block/BlockState;)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, block_state.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#block was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn radius_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "radius", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#radius was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    pub fn radius_f64(&self, value: f64) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "radius", "(D)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#radius was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn depth_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "depth", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#depth was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    pub fn depth_i32(&self, value: i32) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "depth", "(I)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#depth was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    pub fn replace(&self, predicate: jobject) -> ::sponge::world::gen::populator::sea_floor::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "replace", "(Ljava/util/function/Predicate;)Lorg/spongepowered/api/world/gen/populator/SeaFloor$Builder;", CallObjectMethodA, predicate);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#replace was null") }
+      ::sponge::world::gen::populator::sea_floor::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::SeaFloor {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/SeaFloor$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/SeaFloor;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/SeaFloor$Builder#build was null") }
+      ::sponge::world::gen::populator::SeaFloor { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod shrub {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Shrub$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::shrub::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::shrub::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::shrub::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::shrub::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "util")]
+    pub fn types(&self, weighted_table: ::sponge::util::weighted::WeightedTable) -> ::sponge::world::gen::populator::shrub::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "types", "(Lorg/spongepowered/api/util/weighted/WeightedTable;)Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallObjectMethodA, weighted_table.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#types was null") }
+      ::sponge::world::gen::populator::shrub::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn type_(&self, shrub_type: ::sponge::data::type_::ShrubType, value: i32) -> ::sponge::world::gen::populator::shrub::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "type", "(Lorg/spongepowered/api/data/type/ShrubType;I)Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallObjectMethodA, shrub_type.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#type was null") }
+      ::sponge::world::gen::populator::shrub::Builder { env: self.env, object: ret }
+    }
+
+    pub fn supplier(&self, function: jobject) -> ::sponge::world::gen::populator::shrub::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "supplier", "(Ljava/util/function/Function;)Lorg/spongepowered/api/world/gen/populator/Shrub$Builder;", CallObjectMethodA, function);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#supplier was null") }
+      ::sponge::world::gen::populator::shrub::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Shrub {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Shrub$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Shrub;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Shrub$Builder#build was null") }
+      ::sponge::world::gen::populator::Shrub { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod vine {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/Vine$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::vine::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/Vine$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Vine$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::vine::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::vine::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/Vine$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Vine$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::vine::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::Vine {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/Vine$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/Vine;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/Vine$Builder#build was null") }
+      ::sponge::world::gen::populator::Vine { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod water_lily {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/populator/WaterLily$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "util")]
+    pub fn per_chunk_variable_amount(&self, variable_amount: ::sponge::util::weighted::VariableAmount) -> ::sponge::world::gen::populator::water_lily::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily$Builder", self.object, "perChunk", "(Lorg/spongepowered/api/util/weighted/VariableAmount;)Lorg/spongepowered/api/world/gen/populator/WaterLily$Builder;", CallObjectMethodA, variable_amount.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/WaterLily$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::water_lily::Builder { env: self.env, object: ret }
+    }
+
+    pub fn per_chunk_i32(&self, value: i32) -> ::sponge::world::gen::populator::water_lily::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily$Builder", self.object, "perChunk", "(I)Lorg/spongepowered/api/world/gen/populator/WaterLily$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/WaterLily$Builder#perChunk was null") }
+      ::sponge::world::gen::populator::water_lily::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self) -> ::sponge::world::gen::populator::WaterLily {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/populator/WaterLily$Builder", self.object, "build", "()Lorg/spongepowered/api/world/gen/populator/WaterLily;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/gen/populator/WaterLily$Builder#build was null") }
+      ::sponge::world::gen::populator::WaterLily { env: self.env, object: ret }
+    }
+
+  }
+}
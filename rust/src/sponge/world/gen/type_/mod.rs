@@ -0,0 +1,128 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct BiomeTreeType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeTreeType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeTreeType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeTreeType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/type/BiomeTreeType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_populator_object(&self) -> ::sponge::world::gen::PopulatorObject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/type/BiomeTreeType", self.object, "getPopulatorObject", "()Lorg/spongepowered/api/world/gen/PopulatorObject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/type/BiomeTreeType#getPopulatorObject was null") }
+    ::sponge::world::gen::PopulatorObject { env: self.env, object: ret }
+  }
+
+  pub fn set_populator_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/type/BiomeTreeType", self.object, "setPopulatorObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)V", CallVoidMethodA, populator_object.object);
+  }
+
+  pub fn has_large_equivalent(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/type/BiomeTreeType", self.object, "hasLargeEquivalent", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_large_populator_object(&self) -> Option<::sponge::world::gen::PopulatorObject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/type/BiomeTreeType", self.object, "getLargePopulatorObject", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/type/BiomeTreeType#getLargePopulatorObject was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::gen::PopulatorObject { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn set_large_populator_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/type/BiomeTreeType", self.object, "setLargePopulatorObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)V", CallVoidMethodA, populator_object.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct BiomeTreeTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl BiomeTreeTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    BiomeTreeTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `BiomeTreeTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/type/BiomeTreeTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MushroomType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MushroomType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MushroomType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MushroomType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/type/MushroomType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_populator_object(&self) -> ::sponge::world::gen::PopulatorObject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/gen/type/MushroomType", self.object, "getPopulatorObject", "()Lorg/spongepowered/api/world/gen/PopulatorObject;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/gen/type/MushroomType#getPopulatorObject was null") }
+    ::sponge::world::gen::PopulatorObject { env: self.env, object: ret }
+  }
+
+  pub fn set_populator_object(&self, populator_object: ::sponge::world::gen::PopulatorObject) {
+    bound_method!(self.env, "org/spongepowered/api/world/gen/type/MushroomType", self.object, "setPopulatorObject", "(Lorg/spongepowered/api/world/gen/PopulatorObject;)V", CallVoidMethodA, populator_object.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct MushroomTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl MushroomTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    MushroomTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `MushroomTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/gen/type/MushroomTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
@@ -0,0 +1,2184 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+pub mod biome;
+pub mod difficulty;
+pub mod explosion;
+pub mod extent;
+pub mod gamerule;
+pub mod gen;
+pub mod storage;
+pub mod weather;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlockChangeFlag {
+  All,
+  Neighbor,
+  Physics,
+  None,
+  /// A constant the bindings do not list, such as one added to the Java enum after they were generated.
+  Other(jobject)
+}
+
+static BLOCK_CHANGE_FLAG_CONSTANTS: ::convert::EnumConstants = ::convert::EnumConstants::new("org/spongepowered/api/world/BlockChangeFlag", &["ALL", "NEIGHBOR", "PHYSICS", "NONE"]);
+
+impl BlockChangeFlag {
+  pub const VALUES: &'static [BlockChangeFlag] = &[BlockChangeFlag::All, BlockChangeFlag::Neighbor, BlockChangeFlag::Physics, BlockChangeFlag::None];
+
+  pub fn update_neighbors(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/world/BlockChangeFlag", ::convert::ToJava::to_java(self, env), "updateNeighbors", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn perform_block_physics(&self, env: *mut JNIEnv) -> bool {
+    bound_method!(env, "org/spongepowered/api/world/BlockChangeFlag", ::convert::ToJava::to_java(self, env), "performBlockPhysics", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_update_neighbors(&self, env: *mut JNIEnv, value: bool) -> ::sponge::world::BlockChangeFlag {
+    let ret = bound_method!(env, "org/spongepowered/api/world/BlockChangeFlag", ::convert::ToJava::to_java(self, env), "setUpdateNeighbors", "(Z)Lorg/spongepowered/api/world/BlockChangeFlag;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/BlockChangeFlag#setUpdateNeighbors was null") }
+    <::sponge::world::BlockChangeFlag as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn set_perform_block_physics(&self, env: *mut JNIEnv, value: bool) -> ::sponge::world::BlockChangeFlag {
+    let ret = bound_method!(env, "org/spongepowered/api/world/BlockChangeFlag", ::convert::ToJava::to_java(self, env), "setPerformBlockPhysics", "(Z)Lorg/spongepowered/api/world/BlockChangeFlag;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/BlockChangeFlag#setPerformBlockPhysics was null") }
+    <::sponge::world::BlockChangeFlag as ::convert::FromJava>::from_java(env, ret)
+  }
+
+  pub fn to_string(&self, env: *mut JNIEnv) -> jobject {
+    let ret = bound_method!(env, "org/spongepowered/api/world/BlockChangeFlag", ::convert::ToJava::to_java(self, env), "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/BlockChangeFlag#toString was null") }
+    ret
+  }
+
+}
+
+impl ::convert::FromJava for BlockChangeFlag {
+  fn from_java(env: *mut JNIEnv, object: jobject) -> Self {
+    match BLOCK_CHANGE_FLAG_CONSTANTS.ordinal(env, object) {
+      Some(ordinal) => BlockChangeFlag::VALUES[ordinal],
+      None => BlockChangeFlag::Other(object)
+    }
+  }
+}
+
+impl ::convert::ToJava for BlockChangeFlag {
+  fn to_java(&self, env: *mut JNIEnv) -> jobject {
+    match *self {
+      BlockChangeFlag::Other(object) => object,
+      value => BLOCK_CHANGE_FLAG_CONSTANTS.get(env, BlockChangeFlag::VALUES.iter().position(|x| *x == value).expect("no constant for variant"))
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Chunk {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Chunk {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Chunk {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Chunk`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/Chunk", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_location_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getLocation", "(III)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getLocation", "(DDD)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_world(&self) -> ::sponge::world::World {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getWorld", "()Lorg/spongepowered/api/world/World;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getWorld was null") }
+    ::sponge::world::World { env: self.env, object: ret }
+  }
+
+  pub fn is_populated(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "isPopulated", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn load_chunk(&self, value: bool) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "loadChunk", "(Z)Z", CallBooleanMethodA, value) != 0
+  }
+
+  pub fn unload_chunk(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "unloadChunk", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_inhabitted_time(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getInhabittedTime", "()I", CallIntMethod)
+  }
+
+  pub fn get_regional_difficulty_factor(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getRegionalDifficultyFactor", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_regional_difficulty_percentage(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getRegionalDifficultyPercentage", "()D", CallDoubleMethod)
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_neighbor_direction(&self, direction: ::sponge::util::Direction) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getNeighbor", "(Lorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getNeighbor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_neighbor_direction_bool(&self, direction: ::sponge::util::Direction, value: bool) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getNeighbor", "(Lorg/spongepowered/api/util/Direction;Z)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getNeighbor was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::MutableBiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::MutableBiomeAreaWorker { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Chunk", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Chunk#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChunkState {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChunkState {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChunkState {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkState`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkState", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn values(env: *mut JNIEnv) -> Vec<::sponge::world::ChunkState> {
+    unimplemented!();
+  }
+  pub fn value_of(env: *mut JNIEnv, string: jobject) -> ::sponge::world::ChunkState {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/ChunkState", "valueOf", "(Ljava/lang/String;)Lorg/spongepowered/api/world/ChunkState;", CallStaticObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkState#valueOf was null") }
+    ::sponge::world::ChunkState { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChunkTicketManager {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChunkTicketManager {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChunkTicketManager {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkTicketManager`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn register_callback(&self, object: jobject, callback: ::sponge::world::chunk_ticket_manager::Callback) {
+    bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "registerCallback", "(Ljava/lang/Object;Lorg/spongepowered/api/world/ChunkTicketManager$Callback;)V", CallVoidMethodA, object, callback.object);
+  }
+
+  pub fn create_ticket(&self, object: jobject, world: ::sponge::world::World) -> Option<::sponge::world::chunk_ticket_manager::LoadingTicket> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "createTicket", "(Ljava/lang/Object;Lorg/spongepowered/api/world/World;)Ljava/util/Optional;", CallObjectMethodA, object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager#createTicket was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::chunk_ticket_manager::LoadingTicket { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn create_entity_ticket(&self, object: jobject, world: ::sponge::world::World) -> Option<::sponge::world::chunk_ticket_manager::EntityLoadingTicket> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "createEntityTicket", "(Ljava/lang/Object;Lorg/spongepowered/api/world/World;)Ljava/util/Optional;", CallObjectMethodA, object, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager#createEntityTicket was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::chunk_ticket_manager::EntityLoadingTicket { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn create_player_ticket(&self, object: jobject, world: ::sponge::world::World, uuid: jobject) -> Option<::sponge::world::chunk_ticket_manager::PlayerLoadingTicket> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "createPlayerTicket", "(Ljava/lang/Object;Lorg/spongepowered/api/world/World;Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, object, world.object, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager#createPlayerTicket was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::chunk_ticket_manager::PlayerLoadingTicket { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn create_player_entity_ticket(&self, object: jobject, world: ::sponge::world::World, uuid: jobject) -> Option<::sponge::world::chunk_ticket_manager::PlayerEntityLoadingTicket> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "createPlayerEntityTicket", "(Ljava/lang/Object;Lorg/spongepowered/api/world/World;Ljava/util/UUID;)Ljava/util/Optional;", CallObjectMethodA, object, world.object, uuid);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager#createPlayerEntityTicket was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::chunk_ticket_manager::PlayerEntityLoadingTicket { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_max_tickets(&self, object: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "getMaxTickets", "(Ljava/lang/Object;)I", CallIntMethodA, object)
+  }
+
+  pub fn get_available_tickets_object_world(&self, object: jobject, world: ::sponge::world::World) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "getAvailableTickets", "(Ljava/lang/Object;Lorg/spongepowered/api/world/World;)I", CallIntMethodA, object, world.object)
+  }
+
+  pub fn get_available_tickets_uuid(&self, uuid: jobject) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "getAvailableTickets", "(Ljava/util/UUID;)I", CallIntMethodA, uuid)
+  }
+
+  pub fn get_forced_chunks(&self, world: ::sponge::world::World) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager", self.object, "getForcedChunks", "(Lorg/spongepowered/api/world/World;)Lcom/google/common/collect/ImmutableSetMultimap;", CallObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager#getForcedChunks was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Dimension {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Dimension {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Dimension {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Dimension`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/Dimension", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_type(&self) -> ::sponge::world::DimensionType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "getType", "()Lorg/spongepowered/api/world/DimensionType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Dimension#getType was null") }
+    ::sponge::world::DimensionType { env: self.env, object: ret }
+  }
+
+  pub fn get_generator_type(&self) -> ::sponge::world::GeneratorType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "getGeneratorType", "()Lorg/spongepowered/api/world/GeneratorType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Dimension#getGeneratorType was null") }
+    ::sponge::world::GeneratorType { env: self.env, object: ret }
+  }
+
+  pub fn allows_player_respawns(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "allowsPlayerRespawns", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_minimum_spawn_height(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "getMinimumSpawnHeight", "()I", CallIntMethod)
+  }
+
+  pub fn does_water_evaporate(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "doesWaterEvaporate", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn has_sky(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "hasSky", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_height(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "getHeight", "()I", CallIntMethod)
+  }
+
+  pub fn get_build_height(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Dimension", self.object, "getBuildHeight", "()I", CallIntMethod)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DimensionType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DimensionType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DimensionType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DimensionType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/DimensionType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_dimension_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/DimensionType", self.object, "getDimensionClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/DimensionType#getDimensionClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct DimensionTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl DimensionTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    DimensionTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `DimensionTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/DimensionTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GeneratorType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GeneratorType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GeneratorType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GeneratorType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/GeneratorType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_generator_settings(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/GeneratorType", self.object, "getGeneratorSettings", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/GeneratorType#getGeneratorSettings was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn create_generator(&self, world: ::sponge::world::World) -> ::sponge::world::gen::WorldGenerator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/GeneratorType", self.object, "createGenerator", "(Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/world/gen/WorldGenerator;", CallObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/GeneratorType#createGenerator was null") }
+    ::sponge::world::gen::WorldGenerator { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct GeneratorTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl GeneratorTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    GeneratorTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `GeneratorTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/GeneratorTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Locatable {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Locatable {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Locatable {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Locatable`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/Locatable", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Locatable", self.object, "getLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Locatable#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_world(&self) -> ::sponge::world::World {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Locatable", self.object, "getWorld", "()Lorg/spongepowered/api/world/World;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Locatable#getWorld was null") }
+    ::sponge::world::World { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Location {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Location {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Location {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `Location`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/Location", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new_extent_vector_3d(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = new_object!(env, "org/spongepowered/api/world/Location", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3d;)V", extent.object, ::convert::ToJava::to_java(&vector_3d, env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#<init> was null") }
+    ::sponge::world::Location { env: env, object: ret }
+  }
+
+  pub fn new_extent_f64_f64_f64(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = new_object!(env, "org/spongepowered/api/world/Location", "(Lorg/spongepowered/api/world/extent/Extent;DDD)V", extent.object, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#<init> was null") }
+    ::sponge::world::Location { env: env, object: ret }
+  }
+
+  pub fn new_extent_vector_3i(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = new_object!(env, "org/spongepowered/api/world/Location", "(Lorg/spongepowered/api/world/extent/Extent;Lcom/flowpowered/math/vector/Vector3i;)V", extent.object, ::convert::ToJava::to_java(&vector_3i, env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#<init> was null") }
+    ::sponge::world::Location { env: env, object: ret }
+  }
+
+  pub fn new_extent_i32_i32_i32(env: *mut JNIEnv, extent: ::sponge::world::extent::Extent, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::Location {
+    let ret = new_object!(env, "org/spongepowered/api/world/Location", "(Lorg/spongepowered/api/world/extent/Extent;III)V", extent.object, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#<init> was null") }
+    ::sponge::world::Location { env: env, object: ret }
+  }
+
+  pub fn get_extent(&self) -> ::sponge::world::extent::Extent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getExtent", "()Lorg/spongepowered/api/world/extent/Extent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getExtent was null") }
+    ::sponge::world::extent::Extent { env: self.env, object: ret }
+  }
+
+  pub fn get_position(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getPosition", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getPosition was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_block_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBlockPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_chunk_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getChunkPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getChunkPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_biome_position(&self) -> ::math::Vector2i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBiomePosition", "()Lcom/flowpowered/math/vector/Vector2i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBiomePosition was null") }
+    <::math::Vector2i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_x(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getX", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_y(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getY", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_z(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getZ", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_block_x(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockX", "()I", CallIntMethod)
+  }
+
+  pub fn get_block_y(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockY", "()I", CallIntMethod)
+  }
+
+  pub fn get_block_z(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockZ", "()I", CallIntMethod)
+  }
+
+  pub fn in_extent(&self, extent: ::sponge::world::extent::Extent) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "inExtent", "(Lorg/spongepowered/api/world/extent/Extent;)Z", CallBooleanMethodA, extent.object) != 0
+  }
+
+  pub fn has_biome(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "hasBiome", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn has_block(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "hasBlock", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_extent(&self, extent: ::sponge::world::extent::Extent) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setExtent", "(Lorg/spongepowered/api/world/extent/Extent;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, extent.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#setExtent was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn set_position(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setPosition", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#setPosition was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn sub_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "sub", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#sub was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn sub_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "sub", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#sub was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn sub_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "sub", "(DDD)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#sub was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn add_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "add", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#add was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn add_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "add", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#add was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn add_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "add", "(DDD)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#add was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn map(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "map", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#map was null") }
+    ret
+  }
+
+  pub fn map_block(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "mapBlock", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#mapBlock was null") }
+    ret
+  }
+
+  pub fn map_chunk(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "mapChunk", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#mapChunk was null") }
+    ret
+  }
+
+  pub fn map_biome(&self, bi_function: jobject) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "mapBiome", "(Ljava/util/function/BiFunction;)Ljava/lang/Object;", CallObjectMethodA, bi_function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#mapBiome was null") }
+    ret
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_relative(&self, direction: ::sponge::util::Direction) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getRelative", "(Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getRelative was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn get_block_relative(&self, direction: ::sponge::util::Direction) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockRelative", "(Lorg/spongepowered/api/util/Direction;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBlockRelative was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_biome(&self) -> ::sponge::world::biome::BiomeType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBiome", "()Lorg/spongepowered/api/world/biome/BiomeType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBiome was null") }
+    ::sponge::world::biome::BiomeType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block_type(&self) -> ::sponge::block::BlockType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlockType", "()Lorg/spongepowered/api/block/BlockType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBlockType was null") }
+    ::sponge::block::BlockType { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_block(&self) -> ::sponge::block::BlockState {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getBlock", "()Lorg/spongepowered/api/block/BlockState;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getBlock was null") }
+    ::sponge::block::BlockState { env: self.env, object: ret }
+  }
+
+  pub fn has_tile_entity(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "hasTileEntity", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "block")]
+  pub fn get_tile_entity(&self) -> Option<::sponge::block::tileentity::TileEntity> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getTileEntity", "()Ljava/util/Optional;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getTileEntity was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::block::tileentity::TileEntity { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_block_state_cause(&self, block_state: ::sponge::block::BlockState, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setBlock", "(Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_state.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_block_state_block_change_flag_cause(&self, block_state: ::sponge::block::BlockState, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setBlock", "(Lorg/spongepowered/api/block/BlockState;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_state.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_block_type_cause(&self, block_type: ::sponge::block::BlockType, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setBlockType", "(Lorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_type.object, cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn set_block_type_block_type_block_change_flag_cause(&self, block_type: ::sponge::block::BlockType, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setBlockType", "(Lorg/spongepowered/api/block/BlockType;Lorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_type.object, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(all(feature = "block", feature = "event"))]
+  pub fn restore_snapshot(&self, block_snapshot: ::sponge::block::BlockSnapshot, value: bool, block_change_flag: ::sponge::world::BlockChangeFlag, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "restoreSnapshot", "(Lorg/spongepowered/api/block/BlockSnapshot;ZLorg/spongepowered/api/world/BlockChangeFlag;Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, block_snapshot.object, value, ::convert::ToJava::to_java(&block_change_flag, self.env), cause.object) != 0
+  }
+
+  #[cfg(feature = "event")]
+  pub fn remove_block(&self, cause: ::sponge::event::cause::Cause) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "removeBlock", "(Lorg/spongepowered/api/event/cause/Cause;)Z", CallBooleanMethodA, cause.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_class(&self, class: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "remove", "(Ljava/lang/Class;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "remove", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn remove_key(&self, key: ::sponge::data::key::Key) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "remove", "(Lorg/spongepowered/api/data/key/Key;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#remove was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn create_snapshot(&self) -> ::sponge::block::BlockSnapshot {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "createSnapshot", "()Lorg/spongepowered/api/block/BlockSnapshot;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#createSnapshot was null") }
+    ::sponge::block::BlockSnapshot { env: self.env, object: ret }
+  }
+
+  pub fn get_scheduled_updates(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getScheduledUpdates", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getScheduledUpdates was null") }
+    ret
+  }
+
+  #[cfg(feature = "block")]
+  pub fn add_scheduled_update(&self, value: i32, value_2: i32) -> ::sponge::block::ScheduledBlockUpdate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "addScheduledUpdate", "(II)Lorg/spongepowered/api/block/ScheduledBlockUpdate;", CallObjectMethodA, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#addScheduledUpdate was null") }
+    ::sponge::block::ScheduledBlockUpdate { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "block")]
+  pub fn remove_scheduled_update(&self, scheduled_block_update: ::sponge::block::ScheduledBlockUpdate) {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "removeScheduledUpdate", "(Lorg/spongepowered/api/block/ScheduledBlockUpdate;)V", CallVoidMethodA, scheduled_block_update.object);
+  }
+
+  pub fn get_property(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getProperty", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getProperty was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_applicable_properties(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getApplicableProperties", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getApplicableProperties was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn validate_raw_data(&self, data_container: ::sponge::data::DataContainer) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "validateRawData", "(Lorg/spongepowered/api/data/DataContainer;)Z", CallBooleanMethodA, data_container.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_raw_data(&self, data_container: ::sponge::data::DataContainer) {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "setRawData", "(Lorg/spongepowered/api/data/DataContainer;)V", CallVoidMethodA, data_container.object);
+  }
+
+  pub fn get_content_version(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getContentVersion", "()I", CallIntMethod)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn to_container(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "toContainer", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#toContainer was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_class(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "get", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_key(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "get", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#get was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_or_create(&self, class: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getOrCreate", "(Ljava/lang/Class;)Ljava/util/Optional;", CallObjectMethodA, class);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getOrCreate was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_key_object(&self, key: ::sponge::data::key::Key, object: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_key_object_cause(&self, key: ::sponge::data::key::Key, object: jobject, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/key/Key;Ljava/lang/Object;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_iterable(&self, iterable: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Ljava/lang/Iterable;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_iterable_merge_function(&self, iterable: jobject, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Ljava/lang/Iterable;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, iterable, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_base_value(&self, base_value: ::sponge::data::value::BaseValue) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/value/BaseValue;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, base_value.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_data_manipulator(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_manipulator.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_data_manipulator_merge_function(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_manipulator.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_data_manipulator_merge_function_cause(&self, data_manipulator: ::sponge::data::manipulator::DataManipulator, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/manipulator/DataManipulator;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_manipulator.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn undo(&self, data_transaction_result: ::sponge::data::DataTransactionResult) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "undo", "(Lorg/spongepowered/api/data/DataTransactionResult;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_transaction_result.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#undo was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn supports_class(&self, class: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "supports", "(Ljava/lang/Class;)Z", CallBooleanMethodA, class) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn supports_key(&self, key: ::sponge::data::key::Key) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "supports", "(Lorg/spongepowered/api/data/key/Key;)Z", CallBooleanMethodA, key.object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn transform(&self, key: ::sponge::data::key::Key, function: jobject) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "transform", "(Lorg/spongepowered/api/data/key/Key;Ljava/util/function/Function;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, key.object, function);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#transform was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_data_holder(&self, data_holder: ::sponge::data::DataHolder) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "copyFrom", "(Lorg/spongepowered/api/data/DataHolder;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_holder.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_data_holder_merge_function(&self, data_holder: ::sponge::data::DataHolder, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "copyFrom", "(Lorg/spongepowered/api/data/DataHolder;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, data_holder.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  pub fn get_containers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getContainers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getContainers was null") }
+    ret
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_value(&self, key: ::sponge::data::key::Key) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getValue", "(Lorg/spongepowered/api/data/key/Key;)Ljava/util/Optional;", CallObjectMethodA, key.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getValue was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy(&self) -> ::sponge::data::DataHolder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "copy", "()Lorg/spongepowered/api/data/DataHolder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#copy was null") }
+    ::sponge::data::DataHolder { env: self.env, object: ret }
+  }
+
+  pub fn get_keys(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getKeys", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getKeys was null") }
+    ret
+  }
+
+  pub fn get_values(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "getValues", "()Ljava/util/Set;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#getValues was null") }
+    ret
+  }
+
+  pub fn to_string(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "toString", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#toString was null") }
+    ret
+  }
+
+  pub fn hash_code(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "hashCode", "()I", CallIntMethod)
+  }
+
+  pub fn equals(&self, object: jobject) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "equals", "(Ljava/lang/Object;)Z", CallBooleanMethodA, object) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_composite_value_store_merge_function(&self, composite_value_store: ::sponge::data::value::mutable::CompositeValueStore, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "copyFrom", "(Lorg/spongepowered/api/data/value/mutable/CompositeValueStore;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, composite_value_store.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn copy_from_composite_value_store(&self, composite_value_store: ::sponge::data::value::mutable::CompositeValueStore) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "copyFrom", "(Lorg/spongepowered/api/data/value/mutable/CompositeValueStore;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, composite_value_store.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#copyFrom was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(all(feature = "data", feature = "event"))]
+  pub fn offer_value_container_merge_function_cause(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction, cause: ::sponge::event::cause::Cause) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_value_container_merge_function(&self, value_container: ::sponge::data::value::ValueContainer, merge_function: ::sponge::data::merge::MergeFunction) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;Lorg/spongepowered/api/data/merge/MergeFunction;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object, merge_function.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn offer_value_container(&self, value_container: ::sponge::data::value::ValueContainer) -> ::sponge::data::DataTransactionResult {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/Location", self.object, "offer", "(Lorg/spongepowered/api/data/value/ValueContainer;)Lorg/spongepowered/api/data/DataTransactionResult;", CallObjectMethodA, value_container.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/Location#offer was null") }
+    ::sponge::data::DataTransactionResult { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortalAgent {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortalAgent {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortalAgent {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortalAgent`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/PortalAgent", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_search_radius(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "getSearchRadius", "()I", CallIntMethod)
+  }
+
+  pub fn set_search_radius(&self, value: i32) -> ::sponge::world::PortalAgent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "setSearchRadius", "(I)Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#setSearchRadius was null") }
+    ::sponge::world::PortalAgent { env: self.env, object: ret }
+  }
+
+  pub fn get_creation_radius(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "getCreationRadius", "()I", CallIntMethod)
+  }
+
+  pub fn set_creation_radius(&self, value: i32) -> ::sponge::world::PortalAgent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "setCreationRadius", "(I)Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethodA, value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#setCreationRadius was null") }
+    ::sponge::world::PortalAgent { env: self.env, object: ret }
+  }
+
+  pub fn find_or_create_portal(&self, location: ::sponge::world::Location) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "findOrCreatePortal", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Optional;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#findOrCreatePortal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn find_portal(&self, location: ::sponge::world::Location) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "findPortal", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Optional;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#findPortal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn create_portal(&self, location: ::sponge::world::Location) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "createPortal", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Optional;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#createPortal was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_type(&self) -> ::sponge::world::PortalAgentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgent", self.object, "getType", "()Lorg/spongepowered/api/world/PortalAgentType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgent#getType was null") }
+    ::sponge::world::PortalAgentType { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortalAgentType {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortalAgentType {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortalAgentType {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortalAgentType`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/PortalAgentType", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_portal_agent_class(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/PortalAgentType", self.object, "getPortalAgentClass", "()Ljava/lang/Class;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/PortalAgentType#getPortalAgentClass was null") }
+    ret
+  }
+
+}
+
+#[derive(Debug)]
+pub struct PortalAgentTypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl PortalAgentTypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    PortalAgentTypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `PortalAgentTypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/PortalAgentTypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SerializationBehavior {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SerializationBehavior {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SerializationBehavior {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `SerializationBehavior`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/SerializationBehavior", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct SerializationBehaviors {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl SerializationBehaviors {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    SerializationBehaviors {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the implementation in `SerializationBehaviors`, even if
+  /// the class of the object overrides it.
+  pub fn call_super<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/SerializationBehaviors", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn new(env: *mut JNIEnv) -> ::sponge::world::SerializationBehaviors {
+    let ret = new_object!(env, "org/spongepowered/api/world/SerializationBehaviors", "()V");
+    if ret.is_null() { panic!("org/spongepowered/api/world/SerializationBehaviors#<init> was null") }
+    ::sponge::world::SerializationBehaviors { env: env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct TeleportHelper {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl TeleportHelper {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    TeleportHelper {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `TeleportHelper`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/TeleportHelper", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_safe_location_location(&self, location: ::sponge::world::Location) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/TeleportHelper", self.object, "getSafeLocation", "(Lorg/spongepowered/api/world/Location;)Ljava/util/Optional;", CallObjectMethodA, location.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/TeleportHelper#getSafeLocation was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_safe_location_location_i32_i32(&self, location: ::sponge::world::Location, value: i32, value_2: i32) -> Option<::sponge::world::Location> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/TeleportHelper", self.object, "getSafeLocation", "(Lorg/spongepowered/api/world/Location;II)Ljava/util/Optional;", CallObjectMethodA, location.object, value, value_2);
+    if ret.is_null() { panic!("org/spongepowered/api/world/TeleportHelper#getSafeLocation was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Location { env: self.env, object: unwrapped }) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct World {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl World {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    World {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `World`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/World", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_players(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getPlayers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getPlayers was null") }
+    ret
+  }
+
+  pub fn get_location_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3i;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getLocation", "(III)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_vector_3d(&self, vector_3d: ::math::Vector3d) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getLocation", "(Lcom/flowpowered/math/vector/Vector3d;)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_location_f64_f64_f64(&self, value: f64, value_2: f64, value_3: f64) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getLocation", "(DDD)Lorg/spongepowered/api/world/Location;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_chunk_at_block_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getChunkAtBlock", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getChunkAtBlock was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_chunk_at_block_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getChunkAtBlock", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getChunkAtBlock was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_chunk_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getChunk", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn get_chunk_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getChunk", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn load_chunk_vector_3i_bool(&self, vector_3i: ::math::Vector3i, value: bool) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "loadChunk", "(Lcom/flowpowered/math/vector/Vector3i;Z)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#loadChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn load_chunk_i32_i32_i32_bool(&self, value: i32, value_2: i32, value_3: i32, value_4: bool) -> Option<::sponge::world::Chunk> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "loadChunk", "(IIIZ)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#loadChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::world::Chunk { env: self.env, object: unwrapped }) }
+  }
+
+  pub fn unload_chunk(&self, chunk: ::sponge::world::Chunk) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "unloadChunk", "(Lorg/spongepowered/api/world/Chunk;)Z", CallBooleanMethodA, chunk.object) != 0
+  }
+
+  pub fn get_loaded_chunks(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getLoadedChunks", "()Ljava/lang/Iterable;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getLoadedChunks was null") }
+    ret
+  }
+
+  pub fn get_world_border(&self) -> ::sponge::world::WorldBorder {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getWorldBorder", "()Lorg/spongepowered/api/world/WorldBorder;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getWorldBorder was null") }
+    ::sponge::world::WorldBorder { env: self.env, object: ret }
+  }
+
+  pub fn new_chunk_pre_generate(&self, vector_3d: ::math::Vector3d, value: f64) -> ::sponge::world::world_border::ChunkPreGenerate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "newChunkPreGenerate", "(Lcom/flowpowered/math/vector/Vector3d;D)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3d, self.env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#newChunkPreGenerate was null") }
+    ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+  }
+
+  pub fn get_dimension(&self) -> ::sponge::world::Dimension {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getDimension", "()Lorg/spongepowered/api/world/Dimension;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getDimension was null") }
+    ::sponge::world::Dimension { env: self.env, object: ret }
+  }
+
+  pub fn get_world_generator(&self) -> ::sponge::world::gen::WorldGenerator {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getWorldGenerator", "()Lorg/spongepowered/api/world/gen/WorldGenerator;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getWorldGenerator was null") }
+    ::sponge::world::gen::WorldGenerator { env: self.env, object: ret }
+  }
+
+  pub fn get_properties(&self) -> ::sponge::world::storage::WorldProperties {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getProperties", "()Lorg/spongepowered/api/world/storage/WorldProperties;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getProperties was null") }
+    ::sponge::world::storage::WorldProperties { env: self.env, object: ret }
+  }
+
+  pub fn get_directory(&self) -> ::std::path::PathBuf {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getDirectory", "()Ljava/nio/file/Path;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getDirectory was null") }
+    <::std::path::PathBuf as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getUniqueId was null") }
+    ret
+  }
+
+  pub fn get_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getName was null") }
+    ret
+  }
+
+  pub fn get_difficulty(&self) -> ::sponge::world::difficulty::Difficulty {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getDifficulty", "()Lorg/spongepowered/api/world/difficulty/Difficulty;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getDifficulty was null") }
+    ::sponge::world::difficulty::Difficulty { env: self.env, object: ret }
+  }
+
+  pub fn get_game_rule(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getGameRule", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getGameRule was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_game_rules(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getGameRules", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getGameRules was null") }
+    ret
+  }
+
+  pub fn does_keep_spawn_loaded(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "doesKeepSpawnLoaded", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_keep_spawn_loaded(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "setKeepSpawnLoaded", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_spawn_location(&self) -> ::sponge::world::Location {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getSpawnLocation", "()Lorg/spongepowered/api/world/Location;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getSpawnLocation was null") }
+    ::sponge::world::Location { env: self.env, object: ret }
+  }
+
+  pub fn get_serialization_behavior(&self) -> ::sponge::world::SerializationBehavior {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getSerializationBehavior", "()Lorg/spongepowered/api/world/SerializationBehavior;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getSerializationBehavior was null") }
+    ::sponge::world::SerializationBehavior { env: self.env, object: ret }
+  }
+
+  pub fn set_serialization_behavior(&self, serialization_behavior: ::sponge::world::SerializationBehavior) {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "setSerializationBehavior", "(Lorg/spongepowered/api/world/SerializationBehavior;)V", CallVoidMethodA, serialization_behavior.object);
+  }
+
+  pub fn get_world_storage(&self) -> ::sponge::world::storage::WorldStorage {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getWorldStorage", "()Lorg/spongepowered/api/world/storage/WorldStorage;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getWorldStorage was null") }
+    ::sponge::world::storage::WorldStorage { env: self.env, object: ret }
+  }
+
+  pub fn trigger_explosion(&self, explosion: ::sponge::world::explosion::Explosion) {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "triggerExplosion", "(Lorg/spongepowered/api/world/explosion/Explosion;)V", CallVoidMethodA, explosion.object);
+  }
+
+  pub fn get_portal_agent(&self) -> ::sponge::world::PortalAgent {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getPortalAgent", "()Lorg/spongepowered/api/world/PortalAgent;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getPortalAgent was null") }
+    ::sponge::world::PortalAgent { env: self.env, object: ret }
+  }
+
+  pub fn get_biome_worker(&self) -> ::sponge::world::extent::worker::MutableBiomeAreaWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getBiomeWorker", "()Lorg/spongepowered/api/world/extent/worker/MutableBiomeAreaWorker;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getBiomeWorker was null") }
+    ::sponge::world::extent::worker::MutableBiomeAreaWorker { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "event")]
+  pub fn get_block_worker(&self, cause: ::sponge::event::cause::Cause) -> ::sponge::world::extent::worker::MutableBlockVolumeWorker {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "getBlockWorker", "(Lorg/spongepowered/api/event/cause/Cause;)Lorg/spongepowered/api/world/extent/worker/MutableBlockVolumeWorker;", CallObjectMethodA, cause.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/World#getBlockWorker was null") }
+    ::sponge::world::extent::worker::MutableBlockVolumeWorker { env: self.env, object: ret }
+  }
+
+  pub fn save(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/World", self.object, "save", "()Z", CallBooleanMethod) != 0
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldArchetype {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldArchetype {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldArchetype {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldArchetype`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/WorldArchetype", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn builder(env: *mut JNIEnv) -> ::sponge::world::world_archetype::Builder {
+    let ret = static_java_method!(env, "org/spongepowered/api/world/WorldArchetype", "builder", "()Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallStaticObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#builder was null") }
+    ::sponge::world::world_archetype::Builder { env: env, object: ret }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "isEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn load_on_startup(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "loadOnStartup", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_keep_spawn_loaded(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "doesKeepSpawnLoaded", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_generate_spawn_on_load(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "doesGenerateSpawnOnLoad", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_seed(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getSeed", "()J", CallLongMethod)
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_game_mode(&self) -> ::sponge::entity::living::player::gamemode::GameMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getGameMode", "()Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getGameMode was null") }
+    ::sponge::entity::living::player::gamemode::GameMode { env: self.env, object: ret }
+  }
+
+  pub fn get_generator_type(&self) -> ::sponge::world::GeneratorType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getGeneratorType", "()Lorg/spongepowered/api/world/GeneratorType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getGeneratorType was null") }
+    ::sponge::world::GeneratorType { env: self.env, object: ret }
+  }
+
+  pub fn get_generator_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getGeneratorModifiers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getGeneratorModifiers was null") }
+    ret
+  }
+
+  pub fn uses_map_features(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "usesMapFeatures", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn is_hardcore(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "isHardcore", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn are_commands_allowed(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "areCommandsAllowed", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn does_generate_bonus_chest(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "doesGenerateBonusChest", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_dimension_type(&self) -> ::sponge::world::DimensionType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getDimensionType", "()Lorg/spongepowered/api/world/DimensionType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getDimensionType was null") }
+    ::sponge::world::DimensionType { env: self.env, object: ret }
+  }
+
+  pub fn get_portal_agent_type(&self) -> ::sponge::world::PortalAgentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getPortalAgentType", "()Lorg/spongepowered/api/world/PortalAgentType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getPortalAgentType was null") }
+    ::sponge::world::PortalAgentType { env: self.env, object: ret }
+  }
+
+  pub fn get_difficulty(&self) -> ::sponge::world::difficulty::Difficulty {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getDifficulty", "()Lorg/spongepowered/api/world/difficulty/Difficulty;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getDifficulty was null") }
+    ::sponge::world::difficulty::Difficulty { env: self.env, object: ret }
+  }
+
+  pub fn is_p_v_p_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "isPVPEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_generator_settings(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getGeneratorSettings", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getGeneratorSettings was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_serialization_behavior(&self) -> ::sponge::world::SerializationBehavior {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype", self.object, "getSerializationBehavior", "()Lorg/spongepowered/api/world/SerializationBehavior;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype#getSerializationBehavior was null") }
+    ::sponge::world::SerializationBehavior { env: self.env, object: ret }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldArchetypes {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldArchetypes {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldArchetypes {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldArchetypes`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/WorldArchetypes", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldBorder {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldBorder {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldBorder {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldBorder`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/WorldBorder", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_new_diameter(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getNewDiameter", "()D", CallDoubleMethod)
+  }
+
+  pub fn get_diameter(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getDiameter", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_diameter_f64(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setDiameter", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn set_diameter_f64_i64(&self, value: f64, value_2: i64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setDiameter", "(DJ)V", CallVoidMethodA, value, value_2);
+  }
+
+  pub fn set_diameter_f64_f64_i64(&self, value: f64, value_2: f64, value_3: i64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setDiameter", "(DDJ)V", CallVoidMethodA, value, value_2, value_3);
+  }
+
+  pub fn get_time_remaining(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getTimeRemaining", "()J", CallLongMethod)
+  }
+
+  pub fn set_center(&self, value: f64, value_2: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setCenter", "(DD)V", CallVoidMethodA, value, value_2);
+  }
+
+  pub fn get_center(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getCenter", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder#getCenter was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_warning_time(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getWarningTime", "()I", CallIntMethod)
+  }
+
+  pub fn set_warning_time(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setWarningTime", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_warning_distance(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getWarningDistance", "()I", CallIntMethod)
+  }
+
+  pub fn set_warning_distance(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setWarningDistance", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_damage_threshold(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getDamageThreshold", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_damage_threshold(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setDamageThreshold", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_damage_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "getDamageAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_damage_amount(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "setDamageAmount", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn new_chunk_pre_generate(&self, world: ::sponge::world::World) -> ::sponge::world::world_border::ChunkPreGenerate {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder", self.object, "newChunkPreGenerate", "(Lorg/spongepowered/api/world/World;)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, world.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder#newChunkPreGenerate was null") }
+    ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+  }
+
+}
+
+pub mod block_change_flag {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Flags {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Flags {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Flags {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Flags`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/BlockChangeFlag$Flags", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+}
+
+pub mod chunk_ticket_manager {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Callback {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Callback {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Callback {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Callback`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$Callback", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn on_loaded(&self, immutable_list: jobject, world: ::sponge::world::World) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$Callback", self.object, "onLoaded", "(Lcom/google/common/collect/ImmutableList;Lorg/spongepowered/api/world/World;)V", CallVoidMethodA, immutable_list, world.object);
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct EntityLoadingTicket {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl EntityLoadingTicket {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      EntityLoadingTicket {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `EntityLoadingTicket`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$EntityLoadingTicket", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn bind_to_entity(&self, entity: ::sponge::entity::Entity) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$EntityLoadingTicket", self.object, "bindToEntity", "(Lorg/spongepowered/api/entity/Entity;)V", CallVoidMethodA, entity.object);
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn get_bound_entity(&self) -> ::sponge::entity::Entity {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$EntityLoadingTicket", self.object, "getBoundEntity", "()Lorg/spongepowered/api/entity/Entity;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$EntityLoadingTicket#getBoundEntity was null") }
+      ::sponge::entity::Entity { env: self.env, object: ret }
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct LoadingTicket {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl LoadingTicket {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      LoadingTicket {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `LoadingTicket`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn set_num_chunks(&self, value: i32) -> bool {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "setNumChunks", "(I)Z", CallBooleanMethodA, value) != 0
+    }
+
+    pub fn get_num_chunks(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "getNumChunks", "()I", CallIntMethod)
+    }
+
+    pub fn get_max_num_chunks(&self) -> i32 {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "getMaxNumChunks", "()I", CallIntMethod)
+    }
+
+    pub fn get_plugin(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "getPlugin", "()Ljava/lang/String;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$LoadingTicket#getPlugin was null") }
+      ret
+    }
+
+    pub fn get_chunk_list(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "getChunkList", "()Lcom/google/common/collect/ImmutableSet;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$LoadingTicket#getChunkList was null") }
+      ret
+    }
+
+    pub fn force_chunk(&self, vector_3i: ::math::Vector3i) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "forceChunk", "(Lcom/flowpowered/math/vector/Vector3i;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    }
+
+    pub fn unforce_chunk(&self, vector_3i: ::math::Vector3i) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "unforceChunk", "(Lcom/flowpowered/math/vector/Vector3i;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    }
+
+    pub fn prioritize_chunk(&self, vector_3i: ::math::Vector3i) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "prioritizeChunk", "(Lcom/flowpowered/math/vector/Vector3i;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    }
+
+    pub fn release(&self) {
+      bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$LoadingTicket", self.object, "release", "()V", CallVoidMethod);
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct OrderedCallback {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl OrderedCallback {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      OrderedCallback {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `OrderedCallback`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$OrderedCallback", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn on_loaded(&self, immutable_list: jobject, world: ::sponge::world::World, value: i32) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$OrderedCallback", self.object, "onLoaded", "(Lcom/google/common/collect/ImmutableList;Lorg/spongepowered/api/world/World;I)Ljava/util/List;", CallObjectMethodA, immutable_list, world.object, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$OrderedCallback#onLoaded was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PlayerEntityLoadingTicket {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PlayerEntityLoadingTicket {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PlayerEntityLoadingTicket {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerEntityLoadingTicket`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$PlayerEntityLoadingTicket", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PlayerLoadingTicket {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PlayerLoadingTicket {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PlayerLoadingTicket {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerLoadingTicket`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$PlayerLoadingTicket", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn get_player_unique_id(&self) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$PlayerLoadingTicket", self.object, "getPlayerUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$PlayerLoadingTicket#getPlayerUniqueId was null") }
+      ret
+    }
+
+  }
+
+  #[derive(Debug)]
+  pub struct PlayerOrderedCallback {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl PlayerOrderedCallback {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      PlayerOrderedCallback {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `PlayerOrderedCallback`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/ChunkTicketManager$PlayerOrderedCallback", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn on_player_loaded(&self, immutable_list_multimap: jobject, world: ::sponge::world::World) -> jobject {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/ChunkTicketManager$PlayerOrderedCallback", self.object, "onPlayerLoaded", "(Lcom/google/common/collect/ImmutableListMultimap;Lorg/spongepowered/api/world/World;)Lcom/google/common/collect/ListMultimap;", CallObjectMethodA, immutable_list_multimap, world.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/ChunkTicketManager$PlayerOrderedCallback#onPlayerLoaded was null") }
+      ret
+    }
+
+  }
+}
+
+pub mod world_archetype {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct Builder {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl Builder {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      Builder {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `Builder`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/WorldArchetype$Builder", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn enabled(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "enabled", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#enabled was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn loads_on_startup(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "loadsOnStartup", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#loadsOnStartup was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn keeps_spawn_loaded(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "keepsSpawnLoaded", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#keepsSpawnLoaded was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn generate_spawn_on_load(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "generateSpawnOnLoad", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#generateSpawnOnLoad was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn seed(&self, value: i64) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "seed", "(J)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#seed was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "entity")]
+    pub fn game_mode(&self, game_mode: ::sponge::entity::living::player::gamemode::GameMode) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "gameMode", "(Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, game_mode.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#gameMode was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn generator(&self, generator_type: ::sponge::world::GeneratorType) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "generator", "(Lorg/spongepowered/api/world/GeneratorType;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, generator_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#generator was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn generator_modifiers(&self, world_generator_modifiers: Vec<::sponge::world::gen::WorldGeneratorModifier>) -> ::sponge::world::world_archetype::Builder {
+      unimplemented!();
+    }
+    pub fn dimension(&self, dimension_type: ::sponge::world::DimensionType) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "dimension", "(Lorg/spongepowered/api/world/DimensionType;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, dimension_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#dimension was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn difficulty(&self, difficulty: ::sponge::world::difficulty::Difficulty) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "difficulty", "(Lorg/spongepowered/api/world/difficulty/Difficulty;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, difficulty.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#difficulty was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn uses_map_features(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "usesMapFeatures", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#usesMapFeatures was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn hardcore(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "hardcore", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#hardcore was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "data")]
+    pub fn generator_settings(&self, data_container: ::sponge::data::DataContainer) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "generatorSettings", "(Lorg/spongepowered/api/data/DataContainer;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, data_container.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#generatorSettings was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn portal_agent(&self, portal_agent_type: ::sponge::world::PortalAgentType) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "portalAgent", "(Lorg/spongepowered/api/world/PortalAgentType;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, portal_agent_type.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#portalAgent was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn pvp(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "pvp", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#pvp was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn commands_allowed(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "commandsAllowed", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#commandsAllowed was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn generate_bonus_chest(&self, value: bool) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "generateBonusChest", "(Z)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#generateBonusChest was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn serialization_behavior(&self, serialization_behavior: ::sponge::world::SerializationBehavior) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "serializationBehavior", "(Lorg/spongepowered/api/world/SerializationBehavior;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, serialization_behavior.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#serializationBehavior was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn from_world_properties(&self, world_properties: ::sponge::world::storage::WorldProperties) -> ::sponge::world::world_archetype::Builder {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "from", "(Lorg/spongepowered/api/world/storage/WorldProperties;)Lorg/spongepowered/api/world/WorldArchetype$Builder;", CallObjectMethodA, world_properties.object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#from was null") }
+      ::sponge::world::world_archetype::Builder { env: self.env, object: ret }
+    }
+
+    pub fn build(&self, string: jobject, string_2: jobject) -> ::sponge::world::WorldArchetype {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldArchetype$Builder", self.object, "build", "(Ljava/lang/String;Ljava/lang/String;)Lorg/spongepowered/api/world/WorldArchetype;", CallObjectMethodA, string, string_2);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldArchetype$Builder#build was null") }
+      ::sponge::world::WorldArchetype { env: self.env, object: ret }
+    }
+
+  }
+}
+
+pub mod world_border {
+  use jni_sys::{JNIEnv, jvalue, jobject};
+
+  #[derive(Debug)]
+  pub struct ChunkPreGenerate {
+    pub env: *mut JNIEnv,
+    pub object: jobject
+  }
+
+  impl ChunkPreGenerate {
+    pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+      ChunkPreGenerate {
+        env: env,
+        object: object
+      }
+    }
+
+    /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkPreGenerate`, even if
+    /// the class of the object overrides it.
+    pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+      let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", name, descriptor, args);
+      ::convert::FromJValue::from_jvalue(ret)
+    }
+
+    pub fn owner(&self, object: jobject) -> ::sponge::world::world_border::ChunkPreGenerate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "owner", "(Ljava/lang/Object;)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, object);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#owner was null") }
+      ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+    }
+
+    pub fn logger(&self, logger: jobject) -> ::sponge::world::world_border::ChunkPreGenerate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "logger", "(Lorg/slf4j/Logger;)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, logger);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#logger was null") }
+      ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+    }
+
+    pub fn tick_interval(&self, value: i32) -> ::sponge::world::world_border::ChunkPreGenerate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "tickInterval", "(I)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#tickInterval was null") }
+      ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+    }
+
+    pub fn chunks_per_tick(&self, value: i32) -> ::sponge::world::world_border::ChunkPreGenerate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "chunksPerTick", "(I)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#chunksPerTick was null") }
+      ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+    }
+
+    pub fn tick_percent_limit(&self, value: f32) -> ::sponge::world::world_border::ChunkPreGenerate {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "tickPercentLimit", "(F)Lorg/spongepowered/api/world/WorldBorder$ChunkPreGenerate;", CallObjectMethodA, value);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#tickPercentLimit was null") }
+      ::sponge::world::world_border::ChunkPreGenerate { env: self.env, object: ret }
+    }
+
+    #[cfg(feature = "scheduler")]
+    pub fn start(&self) -> ::sponge::scheduler::Task {
+      let ret = bound_method!(self.env, "org/spongepowered/api/world/WorldBorder$ChunkPreGenerate", self.object, "start", "()Lorg/spongepowered/api/scheduler/Task;", CallObjectMethod);
+      if ret.is_null() { panic!("org/spongepowered/api/world/WorldBorder$ChunkPreGenerate#start was null") }
+      ::sponge::scheduler::Task { env: self.env, object: ret }
+    }
+
+  }
+}
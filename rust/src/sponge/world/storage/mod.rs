@@ -0,0 +1,604 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct ChunkDataStream {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChunkDataStream {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChunkDataStream {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkDataStream`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/storage/ChunkDataStream", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  #[cfg(feature = "data")]
+  pub fn next(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkDataStream", self.object, "next", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkDataStream#next was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn has_next(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkDataStream", self.object, "hasNext", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn available(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkDataStream", self.object, "available", "()I", CallIntMethod)
+  }
+
+  pub fn reset(&self) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkDataStream", self.object, "reset", "()V", CallVoidMethod);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct ChunkLayout {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl ChunkLayout {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    ChunkLayout {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `ChunkLayout`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/storage/ChunkLayout", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_chunk_size(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "getChunkSize", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#getChunkSize was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_space_max(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "getSpaceMax", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#getSpaceMax was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_space_min(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "getSpaceMin", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#getSpaceMin was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_space_size(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "getSpaceSize", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#getSpaceSize was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn get_space_origin(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "getSpaceOrigin", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#getSpaceOrigin was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn is_valid_chunk_vector_3i(&self, vector_3i: ::math::Vector3i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isValidChunk", "(Lcom/flowpowered/math/vector/Vector3i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env)) != 0
+  }
+
+  pub fn is_valid_chunk_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isValidChunk", "(III)Z", CallBooleanMethodA, value, value_2, value_3) != 0
+  }
+
+  pub fn is_in_chunk_vector_3i(&self, vector_3i: ::math::Vector3i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isInChunk", "(Lcom/flowpowered/math/vector/Vector3i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env)) != 0
+  }
+
+  pub fn is_in_chunk_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isInChunk", "(III)Z", CallBooleanMethodA, value, value_2, value_3) != 0
+  }
+
+  pub fn is_in_chunk_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isInChunk", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Z", CallBooleanMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env)) != 0
+  }
+
+  pub fn is_in_chunk_i32_i32_i32_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32, value_4: i32, value_5: i32, value_6: i32) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "isInChunk", "(IIIIII)Z", CallBooleanMethodA, value, value_2, value_3, value_4, value_5, value_6) != 0
+  }
+
+  pub fn to_chunk_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "toChunk", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#toChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_chunk_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "toChunk", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#toChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_world_vector_3i(&self, vector_3i: ::math::Vector3i) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "toWorld", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#toWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn to_world_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "toWorld", "(III)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#toWorld was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn force_to_chunk_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "forceToChunk", "(Lcom/flowpowered/math/vector/Vector3i;)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#forceToChunk was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn force_to_chunk_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "forceToChunk", "(III)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#forceToChunk was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn force_to_world_vector_3i(&self, vector_3i: ::math::Vector3i) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "forceToWorld", "(Lcom/flowpowered/math/vector/Vector3i;)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#forceToWorld was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn force_to_world_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "forceToWorld", "(III)Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethodA, value, value_2, value_3);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#forceToWorld was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn add_to_chunk_vector_3i_vector_3i(&self, vector_3i: ::math::Vector3i, vector_3i_2: ::math::Vector3i) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "addToChunk", "(Lcom/flowpowered/math/vector/Vector3i;Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&vector_3i_2, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#addToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn add_to_chunk_i32_i32_i32_i32_i32_i32(&self, value: i32, value_2: i32, value_3: i32, value_4: i32, value_5: i32, value_6: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "addToChunk", "(IIIIII)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, value_4, value_5, value_6);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#addToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn move_to_chunk_vector_3i_direction(&self, vector_3i: ::math::Vector3i, direction: ::sponge::util::Direction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "moveToChunk", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#moveToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn move_to_chunk_i32_i32_i32_direction(&self, value: i32, value_2: i32, value_3: i32, direction: ::sponge::util::Direction) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "moveToChunk", "(IIILorg/spongepowered/api/util/Direction;)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, ::convert::ToJava::to_java(&direction, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#moveToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn move_to_chunk_vector_3i_direction_i32(&self, vector_3i: ::math::Vector3i, direction: ::sponge::util::Direction, value: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "moveToChunk", "(Lcom/flowpowered/math/vector/Vector3i;Lorg/spongepowered/api/util/Direction;I)Ljava/util/Optional;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env), ::convert::ToJava::to_java(&direction, self.env), value);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#moveToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  #[cfg(feature = "util")]
+  pub fn move_to_chunk_i32_i32_i32_direction_i32(&self, value: i32, value_2: i32, value_3: i32, direction: ::sponge::util::Direction, value_4: i32) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/ChunkLayout", self.object, "moveToChunk", "(IIILorg/spongepowered/api/util/Direction;I)Ljava/util/Optional;", CallObjectMethodA, value, value_2, value_3, ::convert::ToJava::to_java(&direction, self.env), value_4);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/ChunkLayout#moveToChunk was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldProperties {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldProperties {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldProperties {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldProperties`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/storage/WorldProperties", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn is_initialized(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isInitialized", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_world_name(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldName", "()Ljava/lang/String;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getWorldName was null") }
+    ret
+  }
+
+  pub fn get_unique_id(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getUniqueId was null") }
+    ret
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_enabled(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setEnabled", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn load_on_startup(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "loadOnStartup", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_load_on_startup(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setLoadOnStartup", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn does_keep_spawn_loaded(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "doesKeepSpawnLoaded", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_keep_spawn_loaded(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setKeepSpawnLoaded", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn does_generate_spawn_on_load(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "doesGenerateSpawnOnLoad", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_generate_spawn_on_load(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setGenerateSpawnOnLoad", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_spawn_position(&self) -> ::math::Vector3i {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getSpawnPosition", "()Lcom/flowpowered/math/vector/Vector3i;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getSpawnPosition was null") }
+    <::math::Vector3i as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_spawn_position(&self, vector_3i: ::math::Vector3i) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setSpawnPosition", "(Lcom/flowpowered/math/vector/Vector3i;)V", CallVoidMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+  }
+
+  pub fn get_generator_type(&self) -> ::sponge::world::GeneratorType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGeneratorType", "()Lorg/spongepowered/api/world/GeneratorType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGeneratorType was null") }
+    ::sponge::world::GeneratorType { env: self.env, object: ret }
+  }
+
+  pub fn set_generator_type(&self, generator_type: ::sponge::world::GeneratorType) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setGeneratorType", "(Lorg/spongepowered/api/world/GeneratorType;)V", CallVoidMethodA, generator_type.object);
+  }
+
+  pub fn get_seed(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getSeed", "()J", CallLongMethod)
+  }
+
+  pub fn get_total_time(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getTotalTime", "()J", CallLongMethod)
+  }
+
+  pub fn get_world_time(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldTime", "()J", CallLongMethod)
+  }
+
+  pub fn set_world_time(&self, value: i64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldTime", "(J)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_dimension_type(&self) -> ::sponge::world::DimensionType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getDimensionType", "()Lorg/spongepowered/api/world/DimensionType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getDimensionType was null") }
+    ::sponge::world::DimensionType { env: self.env, object: ret }
+  }
+
+  pub fn get_portal_agent_type(&self) -> ::sponge::world::PortalAgentType {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getPortalAgentType", "()Lorg/spongepowered/api/world/PortalAgentType;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getPortalAgentType was null") }
+    ::sponge::world::PortalAgentType { env: self.env, object: ret }
+  }
+
+  pub fn is_p_v_p_enabled(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isPVPEnabled", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_p_v_p_enabled(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setPVPEnabled", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn is_raining(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isRaining", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_raining(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setRaining", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_rain_time(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getRainTime", "()I", CallIntMethod)
+  }
+
+  pub fn set_rain_time(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setRainTime", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn is_thundering(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isThundering", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_thundering(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setThundering", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_thunder_time(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getThunderTime", "()I", CallIntMethod)
+  }
+
+  pub fn set_thunder_time(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setThunderTime", "(I)V", CallVoidMethodA, value);
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn get_game_mode(&self) -> ::sponge::entity::living::player::gamemode::GameMode {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGameMode", "()Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGameMode was null") }
+    ::sponge::entity::living::player::gamemode::GameMode { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "entity")]
+  pub fn set_game_mode(&self, game_mode: ::sponge::entity::living::player::gamemode::GameMode) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setGameMode", "(Lorg/spongepowered/api/entity/living/player/gamemode/GameMode;)V", CallVoidMethodA, game_mode.object);
+  }
+
+  pub fn uses_map_features(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "usesMapFeatures", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_map_features_enabled(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setMapFeaturesEnabled", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn is_hardcore(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "isHardcore", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_hardcore(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setHardcore", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn are_commands_allowed(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "areCommandsAllowed", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn set_commands_allowed(&self, value: bool) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setCommandsAllowed", "(Z)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_difficulty(&self) -> ::sponge::world::difficulty::Difficulty {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getDifficulty", "()Lorg/spongepowered/api/world/difficulty/Difficulty;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getDifficulty was null") }
+    ::sponge::world::difficulty::Difficulty { env: self.env, object: ret }
+  }
+
+  pub fn set_difficulty(&self, difficulty: ::sponge::world::difficulty::Difficulty) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setDifficulty", "(Lorg/spongepowered/api/world/difficulty/Difficulty;)V", CallVoidMethodA, difficulty.object);
+  }
+
+  pub fn does_generate_bonus_chest(&self) -> bool {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "doesGenerateBonusChest", "()Z", CallBooleanMethod) != 0
+  }
+
+  pub fn get_world_border_center(&self) -> ::math::Vector3d {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderCenter", "()Lcom/flowpowered/math/vector/Vector3d;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getWorldBorderCenter was null") }
+    <::math::Vector3d as ::convert::FromJava>::from_java(self.env, ret)
+  }
+
+  pub fn set_world_border_center(&self, value: f64, value_2: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderCenter", "(DD)V", CallVoidMethodA, value, value_2);
+  }
+
+  pub fn get_world_border_diameter(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderDiameter", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_world_border_diameter(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderDiameter", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_time_remaining(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderTimeRemaining", "()J", CallLongMethod)
+  }
+
+  pub fn set_world_border_time_remaining(&self, value: i64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderTimeRemaining", "(J)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_target_diameter(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderTargetDiameter", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_world_border_target_diameter(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderTargetDiameter", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_damage_threshold(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderDamageThreshold", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_world_border_damage_threshold(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderDamageThreshold", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_damage_amount(&self) -> f64 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderDamageAmount", "()D", CallDoubleMethod)
+  }
+
+  pub fn set_world_border_damage_amount(&self, value: f64) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderDamageAmount", "(D)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_warning_time(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderWarningTime", "()I", CallIntMethod)
+  }
+
+  pub fn set_world_border_warning_time(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderWarningTime", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_world_border_warning_distance(&self) -> i32 {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getWorldBorderWarningDistance", "()I", CallIntMethod)
+  }
+
+  pub fn set_world_border_warning_distance(&self, value: i32) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setWorldBorderWarningDistance", "(I)V", CallVoidMethodA, value);
+  }
+
+  pub fn get_game_rule(&self, string: jobject) -> Option<jobject> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGameRule", "(Ljava/lang/String;)Ljava/util/Optional;", CallObjectMethodA, string);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGameRule was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(unwrapped) }
+  }
+
+  pub fn get_game_rules(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGameRules", "()Ljava/util/Map;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGameRules was null") }
+    ret
+  }
+
+  pub fn set_game_rule(&self, string: jobject, string_2: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setGameRule", "(Ljava/lang/String;Ljava/lang/String;)V", CallVoidMethodA, string, string_2);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_additional_properties(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getAdditionalProperties", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getAdditionalProperties was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_property_section(&self, data_query: ::sponge::data::DataQuery) -> Option<::sponge::data::DataView> {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getPropertySection", "(Lorg/spongepowered/api/data/DataQuery;)Ljava/util/Optional;", CallObjectMethodA, data_query.object);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getPropertySection was null") }
+    let unwrapped = bound_method!(self.env, "java/util/Optional", ret, "orElse", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ::std::ptr::null_mut() as jobject);
+    if unwrapped.is_null() { None } else { Some(::sponge::data::DataView { env: self.env, object: unwrapped }) }
+  }
+
+  #[cfg(feature = "data")]
+  pub fn set_property_section(&self, data_query: ::sponge::data::DataQuery, data_view: ::sponge::data::DataView) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setPropertySection", "(Lorg/spongepowered/api/data/DataQuery;Lorg/spongepowered/api/data/DataView;)V", CallVoidMethodA, data_query.object, data_view.object);
+  }
+
+  pub fn get_generator_modifiers(&self) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGeneratorModifiers", "()Ljava/util/Collection;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGeneratorModifiers was null") }
+    ret
+  }
+
+  pub fn set_generator_modifiers(&self, collection: jobject) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setGeneratorModifiers", "(Ljava/util/Collection;)V", CallVoidMethodA, collection);
+  }
+
+  #[cfg(feature = "data")]
+  pub fn get_generator_settings(&self) -> ::sponge::data::DataContainer {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getGeneratorSettings", "()Lorg/spongepowered/api/data/DataContainer;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getGeneratorSettings was null") }
+    ::sponge::data::DataContainer { env: self.env, object: ret }
+  }
+
+  pub fn get_serialization_behavior(&self) -> ::sponge::world::SerializationBehavior {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "getSerializationBehavior", "()Lorg/spongepowered/api/world/SerializationBehavior;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldProperties#getSerializationBehavior was null") }
+    ::sponge::world::SerializationBehavior { env: self.env, object: ret }
+  }
+
+  pub fn set_serialization_behavior(&self, serialization_behavior: ::sponge::world::SerializationBehavior) {
+    bound_method!(self.env, "org/spongepowered/api/world/storage/WorldProperties", self.object, "setSerializationBehavior", "(Lorg/spongepowered/api/world/SerializationBehavior;)V", CallVoidMethodA, serialization_behavior.object);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WorldStorage {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WorldStorage {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WorldStorage {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WorldStorage`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/storage/WorldStorage", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_generated_chunks(&self) -> ::sponge::world::storage::ChunkDataStream {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldStorage", self.object, "getGeneratedChunks", "()Lorg/spongepowered/api/world/storage/ChunkDataStream;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldStorage#getGeneratedChunks was null") }
+    ::sponge::world::storage::ChunkDataStream { env: self.env, object: ret }
+  }
+
+  pub fn does_chunk_exist(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldStorage", self.object, "doesChunkExist", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldStorage#doesChunkExist was null") }
+    ret
+  }
+
+  pub fn get_chunk_data(&self, vector_3i: ::math::Vector3i) -> jobject {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldStorage", self.object, "getChunkData", "(Lcom/flowpowered/math/vector/Vector3i;)Ljava/util/concurrent/CompletableFuture;", CallObjectMethodA, ::convert::ToJava::to_java(&vector_3i, self.env));
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldStorage#getChunkData was null") }
+    ret
+  }
+
+  pub fn get_world_properties(&self) -> ::sponge::world::storage::WorldProperties {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/storage/WorldStorage", self.object, "getWorldProperties", "()Lorg/spongepowered/api/world/storage/WorldProperties;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/storage/WorldStorage#getWorldProperties was null") }
+    ::sponge::world::storage::WorldProperties { env: self.env, object: ret }
+  }
+
+}
@@ -0,0 +1,92 @@
+use jni_sys::{JNIEnv, jvalue, jobject};
+
+#[derive(Debug)]
+pub struct Weather {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Weather {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Weather {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Weather`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/weather/Weather", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
+
+#[derive(Debug)]
+pub struct WeatherUniverse {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl WeatherUniverse {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    WeatherUniverse {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `WeatherUniverse`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/weather/WeatherUniverse", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+  pub fn get_weather(&self) -> ::sponge::world::weather::Weather {
+    let ret = bound_method!(self.env, "org/spongepowered/api/world/weather/WeatherUniverse", self.object, "getWeather", "()Lorg/spongepowered/api/world/weather/Weather;", CallObjectMethod);
+    if ret.is_null() { panic!("org/spongepowered/api/world/weather/WeatherUniverse#getWeather was null") }
+    ::sponge::world::weather::Weather { env: self.env, object: ret }
+  }
+
+  pub fn get_remaining_duration(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/weather/WeatherUniverse", self.object, "getRemainingDuration", "()J", CallLongMethod)
+  }
+
+  pub fn get_running_duration(&self) -> i64 {
+    bound_method!(self.env, "org/spongepowered/api/world/weather/WeatherUniverse", self.object, "getRunningDuration", "()J", CallLongMethod)
+  }
+
+  pub fn set_weather_weather(&self, weather: ::sponge::world::weather::Weather) {
+    bound_method!(self.env, "org/spongepowered/api/world/weather/WeatherUniverse", self.object, "setWeather", "(Lorg/spongepowered/api/world/weather/Weather;)V", CallVoidMethodA, weather.object);
+  }
+
+  pub fn set_weather_weather_i64(&self, weather: ::sponge::world::weather::Weather, value: i64) {
+    bound_method!(self.env, "org/spongepowered/api/world/weather/WeatherUniverse", self.object, "setWeather", "(Lorg/spongepowered/api/world/weather/Weather;J)V", CallVoidMethodA, weather.object, value);
+  }
+
+}
+
+#[derive(Debug)]
+pub struct Weathers {
+  pub env: *mut JNIEnv,
+  pub object: jobject
+}
+
+impl Weathers {
+  pub unsafe fn from(env: *mut JNIEnv, object: jobject) -> Self {
+    Weathers {
+      env: env,
+      object: object
+    }
+  }
+
+  /// Calls the method `name` with `descriptor` on this object through the default implementation in `Weathers`, even if
+  /// the class of the object overrides it.
+  pub fn call_default<T: ::convert::FromJValue>(&self, name: &str, descriptor: &str, args: &[jvalue]) -> T {
+    let ret = ::convert::call_nonvirtual(self.env, self.object, "org/spongepowered/api/world/weather/Weathers", name, descriptor, args);
+    ::convert::FromJValue::from_jvalue(ret)
+  }
+
+}
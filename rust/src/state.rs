@@ -0,0 +1,309 @@
+//! Typed state of the native plugin, kept on the server thread.
+//!
+//! A plugin registers one value of each of its state types, usually in `init`, with
+//! [`Plugin::register_state`](../plugin/struct.Plugin.html#method.register_state), and gets it back anywhere on the
+//! server thread with [`Plugin::state`](../plugin/struct.Plugin.html#method.state). Nothing is locked: states live
+//! on the thread the library was initialized on, which is checked on every access, and the `Rc` they are handed out
+//! in cannot leave it. Other threads, like async tasks, pass changes over with [`hand_off`](fn.hand_off.html)
+//! instead, which queues them until the server thread next gets a state.
+//!
+//! States registered with
+//! [`Plugin::register_persistent_state`](../plugin/struct.Plugin.html#method.register_persistent_state) are saved
+//! as JSON in the plugin's data directory when the game stops and before a dev reload, which also hands them straight
+//! to the new library, and are loaded back when they are registered.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Map, Value};
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::mem;
+use std::rc::Rc;
+use std::sync::{Mutex, OnceLock};
+use std::thread::{self, ThreadId};
+
+use plugin::Plugin;
+use reload;
+
+// applies a change from another thread, returning false to be tried again later
+type Handoff = Box<dyn FnMut(&States) -> bool + Send>;
+// serializes a persistent state
+type Save = Box<dyn Fn() -> Value>;
+
+/// The states of a plugin, which can only be used on the thread that owns the container.
+pub struct States {
+  // set once, by new or when the library is initialized
+  main: OnceLock<ThreadId>,
+  states: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+  // the persistent states, by name
+  persistent: RefCell<Vec<(String, Save)>>,
+  handoffs: Mutex<Vec<Handoff>>
+}
+
+// the RefCells and Rcs are only touched on the main thread, which every method using them checks first
+unsafe impl Sync for States {}
+
+impl States {
+  /// An empty container for the current thread.
+  #[allow(clippy::new_without_default)]
+  pub fn new() -> States {
+    let states = States::unowned();
+    states.claim();
+    states
+  }
+
+  // an empty container that no thread can use until one claims it
+  fn unowned() -> States {
+    States {
+      main: OnceLock::new(),
+      states: RefCell::new(HashMap::new()),
+      persistent: RefCell::new(Vec::new()),
+      handoffs: Mutex::new(Vec::new())
+    }
+  }
+
+  // makes the current thread the one the states are used on, which cannot change once set
+  fn claim(&self) {
+    let current = thread::current().id();
+    let main = *self.main.get_or_init(|| current);
+    assert!(main == current, "plugin state was already claimed by another thread");
+  }
+
+  /// Whether the current thread is the one the states can be used on.
+  pub fn is_main_thread(&self) -> bool {
+    self.main.get() == Some(&thread::current().id())
+  }
+
+  fn check_thread(&self) {
+    assert!(self.main.get().is_some(), "plugin state was used before the library was initialized");
+    assert!(self.is_main_thread(), "plugin state can only be used on the server thread, use hand_off to change it from others");
+  }
+
+  /// Adds `state`, replacing the state of the same type registered before, and returns it.
+  pub fn register<T: Any>(&self, state: T) -> Rc<RefCell<T>> {
+    self.check_thread();
+    let state = Rc::new(RefCell::new(state));
+    self.states.borrow_mut().insert(TypeId::of::<T>(), state.clone());
+    state
+  }
+
+  /// Adds `state` like `register`, serializing it under `name` whenever the states are saved.
+  pub fn register_persistent<T: Any + Serialize>(&self, name: &str, state: T) -> Rc<RefCell<T>> {
+    let state = self.register(state);
+    let saved = state.clone();
+    let mut persistent = self.persistent.borrow_mut();
+    persistent.retain(|(x, _)| x != name);
+    persistent.push((name.to_owned(), Box::new(move || serde_json::to_value(&*saved.borrow()).unwrap_or(Value::Null))));
+    state
+  }
+
+  /// The state of type `T`, after applying the changes handed off to it.
+  ///
+  /// Panics off the main thread, or if no state of type `T` was registered.
+  pub fn get<T: Any>(&self) -> Rc<RefCell<T>> {
+    self.check_thread();
+    self.apply_handoffs();
+    self.find().unwrap_or_else(|| panic!("no state of type {} was registered", ::std::any::type_name::<T>()))
+  }
+
+  /// The state of type `T` like `get`, or `None` if none was registered.
+  pub fn try_get<T: Any>(&self) -> Option<Rc<RefCell<T>>> {
+    self.check_thread();
+    self.apply_handoffs();
+    self.find()
+  }
+
+  fn find<T: Any>(&self) -> Option<Rc<RefCell<T>>> {
+    let state = self.states.borrow().get(&TypeId::of::<T>())?.clone();
+    state.downcast().ok()
+  }
+
+  /// Queues `update` to be applied to the state of type `T` on the main thread, the next time a state is got there.
+  ///
+  /// Can be called from any thread. The update waits while the state is not registered yet or is borrowed.
+  pub fn hand_off<T: Any, F: FnOnce(&mut T) + Send + 'static>(&self, update: F) {
+    let mut update = Some(update);
+    self.handoffs.lock().unwrap().push(Box::new(move |states: &States| {
+      let state = match states.find::<T>() {
+        Some(state) => state,
+        None => return false
+      };
+      let mut state = match state.try_borrow_mut() {
+        Ok(state) => state,
+        Err(_) => return false
+      };
+      if let Some(update) = update.take() {
+        update(&mut state);
+      }
+      true
+    }));
+  }
+
+  fn apply_handoffs(&self) {
+    // not locked while applying, so updates can hand off more
+    let handoffs = mem::take(&mut *self.handoffs.lock().unwrap());
+    let waiting: Vec<Handoff> = handoffs.into_iter()
+      .filter_map(|mut handoff| if handoff(self) { None } else { Some(handoff) })
+      .collect();
+    self.handoffs.lock().unwrap().splice(0..0, waiting);
+  }
+
+  /// The persistent states, serialized by name.
+  pub fn save(&self) -> Map<String, Value> {
+    self.check_thread();
+    self.apply_handoffs();
+    self.persistent.borrow().iter()
+      .map(|(name, save)| (name.clone(), save()))
+      .collect()
+  }
+}
+
+lazy_static! {
+  // owned by the thread that initializes the library, not by whichever first touches it
+  static ref STATES: States = States::unowned();
+}
+
+/// Makes the current thread the one states are used on. Called when the library is initialized, on the server thread.
+pub fn init() {
+  STATES.claim();
+  reload::save_state_with(handover);
+}
+
+/// The states of the plugin, for code that takes them as a `States`.
+///
+/// Panics before the library is initialized.
+pub fn states() -> &'static States {
+  assert!(STATES.main.get().is_some(), "plugin state was used before the library was initialized");
+  &STATES
+}
+
+/// Registers `state` for the plugin. See [`States::register`](struct.States.html#method.register).
+pub fn register<T: Any>(state: T) -> Rc<RefCell<T>> {
+  states().register(state)
+}
+
+/// Registers the persistent `default` for the plugin, unless a state was saved under `name` by the library this one
+/// replaced or in the plugin's data directory, which is then loaded instead.
+pub fn register_persistent<T: Any + Serialize + DeserializeOwned>(plugin: &Plugin, name: &str, default: T) -> Rc<RefCell<T>> {
+  let handed_over = plugin.get_previous_state()
+    .and_then(|x| serde_json::from_slice::<Map<String, Value>>(&x).ok())
+    .and_then(|mut x| x.remove(name));
+  let saved = handed_over.or_else(|| {
+    let bytes = fs::read(plugin.get_data_dir().join(format!("{}.json", name))).ok()?;
+    serde_json::from_slice(&bytes).ok()
+  });
+  let state = saved.and_then(|value| match serde_json::from_value(value) {
+    Ok(state) => Some(state),
+    Err(e) => {
+      warn!(error:% = e; "The saved state {} could not be loaded, starting from its default", name);
+      None
+    }
+  });
+  states().register_persistent(name, state.unwrap_or(default))
+}
+
+/// The state of type `T` of the plugin. See [`States::get`](struct.States.html#method.get).
+pub fn get<T: Any>() -> Rc<RefCell<T>> {
+  states().get()
+}
+
+/// The state of type `T` of the plugin, if there is one. See [`States::try_get`](struct.States.html#method.try_get).
+pub fn try_get<T: Any>() -> Option<Rc<RefCell<T>>> {
+  states().try_get()
+}
+
+/// Changes the state of type `T` of the plugin from any thread. See
+/// [`States::hand_off`](struct.States.html#method.hand_off).
+pub fn hand_off<T: Any, F: FnOnce(&mut T) + Send + 'static>(update: F) {
+  states().hand_off(update)
+}
+
+/// Writes the persistent states to `<name>.json` in the data directory of `plugin`.
+pub fn save(plugin: &Plugin) {
+  let dir = plugin.get_data_dir();
+  for (name, value) in states().save() {
+    let path = dir.join(format!("{}.json", name));
+    let written = serde_json::to_vec_pretty(&value).map_err(|e| e.to_string())
+      .and_then(|bytes| fs::write(&path, bytes).map_err(|e| e.to_string()));
+    if let Err(e) = written {
+      error!(error:% = e; "The state {} could not be saved to {}", name, path.display());
+    }
+  }
+}
+
+// the persistent states, handed to the library replacing this one
+fn handover() -> Vec<u8> {
+  serde_json::to_vec(&states().save()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use serde_json;
+  use std::thread;
+
+  use super::States;
+
+  #[derive(Debug, Default, PartialEq, Serialize)]
+  struct Counter {
+    count: u32
+  }
+
+  #[test]
+  fn hands_off_changes_to_the_main_thread() {
+    let states = States::new();
+    states.register(Counter::default());
+    thread::scope(|scope| {
+      scope.spawn(|| {
+        assert!(!states.is_main_thread());
+        states.hand_off(|counter: &mut Counter| counter.count += 2);
+      });
+    });
+    // waits while the state is borrowed
+    let counter = states.get::<Counter>();
+    {
+      let _borrowed = counter.borrow();
+      states.hand_off(|counter: &mut Counter| counter.count += 1);
+      states.get::<Counter>();
+    }
+    assert_eq!(counter.borrow().count, 2);
+    assert_eq!(states.get::<Counter>().borrow().count, 3);
+  }
+
+  #[test]
+  fn saves_persistent_states_by_name() {
+    let states = States::new();
+    states.register(String::from("not saved"));
+    states.register_persistent("counter", Counter { count: 1 }).borrow_mut().count += 1;
+    assert_eq!(states.save().get("counter"), Some(&serde_json::to_value(Counter { count: 2 }).unwrap()));
+    assert_eq!(states.save().len(), 1);
+  }
+
+  #[test]
+  #[should_panic(expected = "before the library was initialized")]
+  fn cannot_be_used_before_they_are_claimed() {
+    States::unowned().register(Counter::default());
+  }
+
+  #[test]
+  fn belong_to_the_thread_that_claims_them() {
+    let states = States::unowned();
+    thread::scope(|scope| {
+      // touching them on another thread first does not take them over
+      scope.spawn(|| assert!(!states.is_main_thread()));
+    });
+    states.claim();
+    assert!(states.is_main_thread());
+    states.register(Counter::default());
+  }
+
+  #[test]
+  #[should_panic(expected = "server thread")]
+  fn cannot_be_used_off_the_main_thread() {
+    let states = States::new();
+    thread::scope(|scope| {
+      scope.spawn(|| { states.register(Counter::default()); }).join().unwrap_or_else(|e| ::std::panic::resume_unwind(e));
+    });
+  }
+}
@@ -0,0 +1,478 @@
+//! Runs the plugin in a JVM started in this process through the JNI Invocation API.
+//!
+//! The JVM loads copies of the `spongejni` dylib and the stubbed SpongeAPI in `tests/jvm`, which keeps just enough of
+//! the API for the plugin to register its command and listeners, and lets the tests run them. The JVM is found through `JAVA_HOME`
+//! or the `java` on the `PATH`; without one, the tests pass without running anything.
+
+extern crate jni_sys;
+#[macro_use]
+extern crate lazy_static;
+extern crate libc;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+#[macro_use]
+extern crate spongejni;
+
+use jni_sys::{JNIEnv, JavaVM, JavaVMInitArgs, JavaVMOption, JNI_FALSE, JNI_OK, jint, jvalue, jobject, jclass, jmethodID};
+use spongejni::convert::ToJValue;
+use spongejni::extensions::{ConvertStringToJava, ConvertStringToRust};
+use spongejni::plugin::JavaUtils;
+use spongejni::sponge::text::Text;
+use spongejni::sponge::text::channel::MessageReceiver;
+use std::env;
+use std::ffi::CString;
+use std::fs;
+use std::os::raw::c_void;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const JNI_VERSION_1_8: jint = 0x0001_0008;
+
+type CreateJavaVM = unsafe extern "C" fn(*mut *mut JavaVM, *mut *mut c_void, *mut c_void) -> jint;
+
+struct Jvm {
+  // JavaVM pointers are valid on every thread
+  vm: usize
+}
+
+lazy_static! {
+  static ref JVM: Option<Jvm> = Jvm::start();
+}
+
+impl Jvm {
+  fn start() -> Option<Jvm> {
+    let java_home = match java_home() {
+      Some(java_home) => java_home,
+      None => {
+        eprintln!("no JVM found, skipping the JVM tests");
+        return None;
+      }
+    };
+    let classes = compile_stubs(&java_home);
+    // copies and files of earlier runs
+    for dir in &[libraries_dir(), data_dir()] {
+      if dir.exists() {
+        fs::remove_dir_all(dir).expect("could not remove the files of earlier runs");
+      }
+    }
+    let library = ["lib/server/libjvm.so", "jre/lib/amd64/server/libjvm.so", "lib/server/libjvm.dylib"].iter()
+      .map(|x| java_home.join(x))
+      .find(|x| x.exists())
+      .expect("no libjvm in the java home");
+    unsafe {
+      let library = CString::new(library.to_str().unwrap()).unwrap();
+      let handle = libc::dlopen(library.as_ptr(), libc::RTLD_NOW | libc::RTLD_GLOBAL);
+      if handle.is_null() { panic!("could not load libjvm"); }
+      let create = libc::dlsym(handle, CString::new("JNI_CreateJavaVM").unwrap().as_ptr());
+      if create.is_null() { panic!("libjvm has no JNI_CreateJavaVM"); }
+      let create: CreateJavaVM = std::mem::transmute(create);
+      let options = [
+        CString::new(format!("-Djava.class.path={}", classes.display())).unwrap(),
+        CString::new(format!("-Dspongejni.data={}", data_dir().display())).unwrap()
+      ];
+      let mut options: Vec<JavaVMOption> = options.iter()
+        .map(|x| JavaVMOption { optionString: x.as_ptr() as *mut _, extraInfo: ptr::null_mut() })
+        .collect();
+      let mut args = JavaVMInitArgs {
+        version: JNI_VERSION_1_8,
+        nOptions: options.len() as jint,
+        options: options.as_mut_ptr(),
+        ignoreUnrecognized: JNI_FALSE
+      };
+      let mut vm: *mut JavaVM = ptr::null_mut();
+      let mut env: *mut c_void = ptr::null_mut();
+      if create(&mut vm, &mut env, &mut args as *mut _ as *mut c_void) != JNI_OK { panic!("could not create the JVM"); }
+      Some(Jvm { vm: vm as usize })
+    }
+  }
+
+  /// The `JNIEnv` of the current thread, attaching it to the JVM.
+  fn env(&self) -> *mut JNIEnv {
+    let vm = self.vm as *mut JavaVM;
+    let mut env: *mut c_void = ptr::null_mut();
+    unsafe {
+      if ((**vm).AttachCurrentThread)(vm, &mut env, ptr::null_mut()) != JNI_OK { panic!("could not attach to the JVM"); }
+    }
+    env as *mut JNIEnv
+  }
+}
+
+fn java_home() -> Option<PathBuf> {
+  if let Some(java_home) = env::var_os("JAVA_HOME") {
+    return Some(PathBuf::from(java_home));
+  }
+  let output = Command::new("java").args(["-XshowSettings:properties", "-version"]).output().ok()?;
+  String::from_utf8_lossy(&output.stderr).lines()
+    .filter_map(|x| x.trim().strip_prefix("java.home = "))
+    .map(PathBuf::from)
+    .next()
+}
+
+/// Compiles the stubs in `tests/jvm`, returning the directory of the class files.
+fn compile_stubs(java_home: &Path) -> PathBuf {
+  let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("jvm-classes");
+  // stale classes of removed stubs would still be found on the class path
+  if out.exists() {
+    fs::remove_dir_all(&out).expect("could not remove old stub classes");
+  }
+  let mut sources = Vec::new();
+  find_sources(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/jvm"), &mut sources);
+  let status = Command::new(java_home.join("bin/javac"))
+    .arg("-d")
+    .arg(&out)
+    .args(&sources)
+    .status()
+    .expect("could not run javac");
+  if !status.success() { panic!("could not compile the stubs"); }
+  out
+}
+
+fn find_sources(dir: &Path, sources: &mut Vec<PathBuf>) {
+  for entry in fs::read_dir(dir).expect("could not read stub directory") {
+    let path = entry.expect("could not read stub directory entry").path();
+    if path.is_dir() {
+      find_sources(&path, sources);
+    } else if path.extension().map(|x| x == "java").unwrap_or(false) {
+      sources.push(path);
+    }
+  }
+}
+
+/// The `spongejni` dylib cargo built for the tests, next to or above the test binary.
+fn library_path() -> PathBuf {
+  let name = format!("{}spongejni{}", env::consts::DLL_PREFIX, env::consts::DLL_SUFFIX);
+  let exe = env::current_exe().expect("no test executable");
+  exe.ancestors()
+    .skip(1)
+    .take(2)
+    .map(|x| x.join(&name))
+    .find(|x| x.exists())
+    .expect("no spongejni dylib was built")
+}
+
+fn libraries_dir() -> PathBuf {
+  Path::new(env!("CARGO_TARGET_TMPDIR")).join("jvm-libraries")
+}
+
+/// Where the plugin stubs get their config directories, one for each server.
+fn data_dir() -> PathBuf {
+  Path::new(env!("CARGO_TARGET_TMPDIR")).join("jvm-data")
+}
+
+/// A new copy of the `spongejni` dylib. A library can only be loaded by one class loader, and every copy is loaded
+/// with its own statics, like separate plugins would be.
+fn library_copy() -> PathBuf {
+  static COPIES: AtomicUsize = AtomicUsize::new(0);
+  fs::create_dir_all(libraries_dir()).expect("could not create the library directory");
+  let name = format!("{}spongejni-{}{}", env::consts::DLL_PREFIX, COPIES.fetch_add(1, Ordering::SeqCst), env::consts::DLL_SUFFIX);
+  let copy = libraries_dir().join(name);
+  fs::copy(library_path(), &copy).expect("could not copy the spongejni dylib");
+  copy
+}
+
+/// Panics with the pending Java exception, if there is one.
+fn check(env: *mut JNIEnv) {
+  unsafe {
+    if ((**env).ExceptionCheck)(env) != JNI_FALSE {
+      ((**env).ExceptionDescribe)(env);
+      ((**env).ExceptionClear)(env);
+      panic!("a Java exception was thrown");
+    }
+  }
+}
+
+/// A game with the plugin in it.
+struct Server {
+  env: *mut JNIEnv,
+  game: jobject,
+  plugin: jobject
+}
+
+impl Server {
+  fn new(env: *mut JNIEnv) -> Server {
+    let game = new_object!(env, "me/kyleclemens/spongejni/harness/TestGame", "()V");
+    let plugin = new_object!(env, "me/kyleclemens/spongejni/SpongeJNI", "(Lorg/spongepowered/api/Game;)V", game);
+    check(env);
+    Server { env, game, plugin }
+  }
+
+  /// A server with one library initialized in it.
+  fn start(env: *mut JNIEnv) -> Server {
+    let server = Server::new(env);
+    server.load("rusty");
+    server
+  }
+
+  /// Loads a copy of the library as the native plugin `id` through its own class loader, initializes it and takes it
+  /// through the initialization of the game, returning its shim.
+  fn load(&self, id: &str) -> jobject {
+    self.load_with_state(id, ptr::null_mut())
+  }
+
+  /// Loads a copy of the library like `load`, handing it the state saved by the library it replaces.
+  fn load_with_state(&self, id: &str, state: jobject) -> jobject {
+    let parent = static_java_method!(self.env, "java/lang/ClassLoader", "getSystemClassLoader", "()Ljava/lang/ClassLoader;", CallStaticObjectMethod);
+    let loader = new_object!(self.env, "me/kyleclemens/spongejni/LibraryClassLoader", "(Ljava/lang/ClassLoader;)V", parent);
+    let id = id.into_java_string(self.env);
+    let path = library_copy().to_str().unwrap().into_java_string(self.env);
+    let shim = java_method!(self.env, loader, "load", "(Lme/kyleclemens/spongejni/SpongeJNI;Ljava/lang/String;Ljava/lang/String;)Ljava/lang/Object;", CallObjectMethodA, self.plugin, id, path);
+    check(self.env);
+    java_method!(self.env, shim, "setPreviousState", "([B)V", CallVoidMethodA, state);
+    let initialized = java_method!(self.env, shim, "init", "()Z", CallBooleanMethod);
+    check(self.env);
+    assert!(initialized != 0, "init returned false");
+    // the plugin registers its command and listeners once the game initializes
+    for state in &["pre_init", "init"] {
+      let state = state.into_java_string(self.env);
+      java_method!(self.env, shim, "lifecycle", "(Ljava/lang/String;)V", CallVoidMethodA, state);
+      check(self.env);
+    }
+    shim
+  }
+
+  fn command_manager(&self) -> jobject {
+    java_method!(self.env, self.game, "getCommandManager", "()Lorg/spongepowered/api/command/CommandManager;", CallObjectMethod)
+  }
+
+  fn event_manager(&self) -> jobject {
+    java_method!(self.env, self.game, "getEventManager", "()Lorg/spongepowered/api/event/EventManager;", CallObjectMethod)
+  }
+
+  fn player(&self, name: &str) -> jobject {
+    let name = name.into_java_string(self.env);
+    new_object!(self.env, "me/kyleclemens/spongejni/harness/TestPlayer", "(Ljava/lang/String;)V", name)
+  }
+
+  fn run_command(&self, player: jobject, alias: &str) {
+    let alias = alias.into_java_string(self.env);
+    java_method!(self.env, self.command_manager(), "process", "(Lorg/spongepowered/api/command/CommandSource;Ljava/lang/String;)Lorg/spongepowered/api/command/CommandResult;", CallObjectMethodA, player, alias);
+    check(self.env);
+  }
+
+  /// The file the greetings of `player` are kept in by the library of `shim`.
+  fn player_file(&self, shim: jobject, player: jobject) -> PathBuf {
+    let dir = java_method!(self.env, shim, "getDataDirectory", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(self.env);
+    let profile = java_method!(self.env, player, "getProfile", "()Lorg/spongepowered/api/profile/GameProfile;", CallObjectMethod);
+    let uuid = java_method!(self.env, profile, "getUniqueId", "()Ljava/util/UUID;", CallObjectMethod);
+    let uuid = java_method!(self.env, uuid, "toString", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(self.env);
+    Path::new(&dir).join("players").join("greetings").join(format!("{}.json", uuid))
+  }
+
+  fn messages(&self, player: jobject) -> String {
+    java_method!(self.env, player, "getMessages", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(self.env)
+  }
+}
+
+#[test]
+fn init_registers_command_and_listeners() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::start(env);
+  let rusty = "rusty".into_java_string(env);
+  assert!(java_method!(env, server.command_manager(), "containsAlias", "(Ljava/lang/String;)Z", CallBooleanMethodA, rusty) != 0);
+  // the player data listener comes first, registered with the greetings
+  assert_eq!(java_method!(env, server.event_manager(), "getListenerCount", "()I", CallIntMethod), 2);
+}
+
+#[test]
+fn libraries_are_loaded_apart() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  server.load("first");
+  server.load("second");
+  let event_manager = server.event_manager();
+  assert_eq!(java_method!(env, event_manager, "getListenerCount", "()I", CallIntMethod), 4);
+  // each library defines its listeners under its own id, in its own class loader
+  let names: Vec<String> = (0..4)
+    .map(|i| JavaUtils::get_class_name(env, java_method!(env, event_manager, "getListener", "(I)Ljava/lang/Object;", CallObjectMethodA, i)))
+    .collect();
+  assert_eq!(names, vec![
+    "me.kyleclemens.spongejni.rust.first.PlayerDataListener",
+    "me.kyleclemens.spongejni.rust.first.RustyListener",
+    "me.kyleclemens.spongejni.rust.second.PlayerDataListener",
+    "me.kyleclemens.spongejni.rust.second.RustyListener"
+  ]);
+}
+
+#[test]
+fn reload_replaces_the_library_and_keeps_its_state() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  let shim = server.load("rusty");
+  let player = server.player("Notch");
+  server.run_command(player, "rusty");
+
+  let state = java_method!(env, shim, "unload", "()[B", CallObjectMethod);
+  check(env);
+  let rusty = "rusty".into_java_string(env);
+  assert!(java_method!(env, server.command_manager(), "containsAlias", "(Ljava/lang/String;)Z", CallBooleanMethodA, rusty) == 0);
+  assert_eq!(java_method!(env, server.event_manager(), "getListenerCount", "()I", CallIntMethod), 0);
+
+  server.load_with_state("rusty", state);
+  server.run_command(player, "rusty");
+  assert_eq!(server.messages(player), "Hello, Notch! I've never said hello to you before.\nHello, Notch! I've said hello to you 1 time before.");
+}
+
+#[test]
+fn stopping_saves_player_data() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  let shim = server.load("rusty");
+  let player = server.player("Notch");
+  server.run_command(player, "rusty");
+  server.run_command(player, "rusty");
+
+  let stopping = "stopping".into_java_string(env);
+  java_method!(env, shim, "lifecycle", "(Ljava/lang/String;)V", CallVoidMethodA, stopping);
+  check(env);
+  let saved = fs::read(server.player_file(shim, player)).expect("the greetings were not saved");
+  let saved: serde_json::Value = serde_json::from_slice(&saved).unwrap();
+  assert_eq!(saved["count"], 2);
+}
+
+#[test]
+fn player_data_is_saved_on_quit_and_read_back() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  let shim = server.load("rusty");
+  let player = server.player("Notch");
+  server.run_command(player, "rusty");
+  assert!(!server.player_file(shim, player).exists());
+
+  let event = new_object!(env, "me/kyleclemens/spongejni/harness/TestDisconnectEvent", "(Lorg/spongepowered/api/entity/living/player/Player;)V", player);
+  let called = java_method!(env, server.event_manager(), "post", "(Lorg/spongepowered/api/event/Event;)I", CallIntMethodA, event);
+  check(env);
+  assert_eq!(called, 1);
+  assert!(server.player_file(shim, player).exists());
+
+  // the greetings were dropped when the player quit, and are read back from their file
+  server.run_command(player, "rusty");
+  assert_eq!(server.messages(player), "Hello, Notch! I've never said hello to you before.\nHello, Notch! I've said hello to you 1 time before.");
+}
+
+#[test]
+fn logs_go_to_the_plugin_logger() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  let shim = server.load("rusty");
+  let logger = java_method!(env, shim, "getLogger", "()Lorg/slf4j/Logger;", CallObjectMethod);
+  let lines = java_method!(env, logger, "getLines", "()Ljava/lang/String;", CallObjectMethod).into_rust_string(env);
+  assert_eq!(lines, "INFO [spongejni::rusty] Registering the rusty command and listeners");
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Settings {
+  greeting: String,
+  max_greetings: u32,
+  ranks: Vec<String>,
+  limits: Limits
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Limits {
+  per_player: f64
+}
+
+#[test]
+fn config_is_filled_in_and_read_back() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::new(env);
+  let id = "configured".into_java_string(env);
+  let shim = new_object!(env, "me/kyleclemens/spongejni/SpongeJNIShim", "(Lme/kyleclemens/spongejni/SpongeJNI;Ljava/lang/String;)V", server.plugin, id);
+  let plugin = JavaUtils::get_plugin(env, shim);
+  let defaults = Settings {
+    greeting: "Hello".to_owned(),
+    max_greetings: 3,
+    ranks: vec!["admin".to_owned(), "member".to_owned()],
+    limits: Limits { per_player: 1.5 }
+  };
+  let comments = [("greeting", "What players are greeted with"), ("limits.per_player", "Greetings per minute")];
+  assert_eq!(plugin.load_config(&defaults, &comments).unwrap(), defaults);
+  check(env);
+  let comments = java_method!(env, shim, "getComments", "()Ljava/util/Map;", CallObjectMethod);
+  let path = "limits.per_player".into_java_string(env);
+  let comment = java_method!(env, comments, "get", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, path);
+  assert_eq!(comment.into_rust_string(env), "Greetings per minute");
+
+  // an edited file keeps its values, and has the keys it lost filled in again
+  let config = java_method!(env, shim, "getConfig", "()Ljava/lang/Object;", CallObjectMethod);
+  let (greeting, howdy, ranks) = ("greeting".into_java_string(env), "Howdy".into_java_string(env), "ranks".into_java_string(env));
+  java_method!(env, config, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, greeting, howdy);
+  java_method!(env, config, "remove", "(Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, ranks);
+  let settings: Settings = plugin.load_config(&defaults, &[]).unwrap();
+  assert_eq!(settings.greeting, "Howdy");
+  assert_eq!(settings.ranks, defaults.ranks);
+
+  let (max_greetings, lots) = ("max_greetings".into_java_string(env), "lots".into_java_string(env));
+  java_method!(env, config, "put", "(Ljava/lang/Object;Ljava/lang/Object;)Ljava/lang/Object;", CallObjectMethodA, max_greetings, lots);
+  assert!(plugin.load_config(&defaults, &[]).is_err());
+}
+
+#[test]
+fn rusty_greets_by_name() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::start(env);
+  let player = server.player("Notch");
+  server.run_command(player, "rusty");
+  server.run_command(player, "rusty");
+  assert_eq!(server.messages(player), "Hello, Notch! I've never said hello to you before.\nHello, Notch! I've said hello to you 1 time before.");
+}
+
+#[test]
+fn achievement_without_player_is_ignored() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::start(env);
+  // nothing caused it, so the listener finds no player to give hearts to
+  let causes = JavaUtils::make_array(env, "java/lang/Object", Vec::new());
+  let event = new_object!(env, "me/kyleclemens/spongejni/harness/TestAchievementEvent", "([Ljava/lang/Object;)V", causes);
+  check(env);
+  let called = java_method!(env, server.event_manager(), "post", "(Lorg/spongepowered/api/event/Event;)I", CallIntMethodA, event);
+  check(env);
+  assert_eq!(called, 1);
+}
+
+#[test]
+fn call_default_skips_overrides() {
+  let env = match *JVM {
+    Some(ref jvm) => jvm.env(),
+    None => return
+  };
+  let server = Server::start(env);
+  let player = server.player("Notch");
+  let receiver = unsafe { MessageReceiver::from(env, player) };
+  let text = Text::of_string(env, "Hello".into_java_string(env));
+  let texts = JavaUtils::make_array(env, "org/spongepowered/api/text/Text", vec![text.object, text.object]);
+  receiver.call_default::<()>("sendMessages", "([Lorg/spongepowered/api/text/Text;)V", &[texts.to_jvalue()]);
+  check(env);
+  // TestPlayer overrides sendMessages, but the default sends each message on its own
+  assert_eq!(server.messages(player), "Hello\nHello");
+}
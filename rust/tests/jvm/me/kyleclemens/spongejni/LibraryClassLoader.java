@@ -0,0 +1,50 @@
+package me.kyleclemens.spongejni;
+
+import java.io.IOException;
+import java.io.InputStream;
+
+/**
+ * Loads a native library with its own copy of {@link SpongeJNIShim}, like the plugin's loader.
+ */
+public class LibraryClassLoader extends ClassLoader {
+
+    public LibraryClassLoader(ClassLoader parent) {
+        super(parent);
+    }
+
+    @Override
+    protected Class<?> loadClass(String name, boolean resolve) throws ClassNotFoundException {
+        if (!name.equals(SpongeJNIShim.class.getName())) {
+            return super.loadClass(name, resolve);
+        }
+        synchronized (this.getClassLoadingLock(name)) {
+            Class<?> clazz = this.findLoadedClass(name);
+            if (clazz == null) {
+                clazz = this.define(name);
+            }
+            if (resolve) {
+                this.resolveClass(clazz);
+            }
+            return clazz;
+        }
+    }
+
+    private Class<?> define(String name) throws ClassNotFoundException {
+        try (InputStream stream = this.getParent().getResourceAsStream(name.replace('.', '/') + ".class")) {
+            if (stream == null) {
+                throw new ClassNotFoundException(name);
+            }
+            byte[] bytes = stream.readAllBytes();
+            return this.defineClass(name, bytes, 0, bytes.length);
+        } catch (IOException e) {
+            throw new ClassNotFoundException(name, e);
+        }
+    }
+
+    public Object load(SpongeJNI plugin, String id, String path) throws ReflectiveOperationException {
+        Class<?> shim = Class.forName(SpongeJNIShim.class.getName(), true, this);
+        shim.getMethod("load", String.class).invoke(null, path);
+        return shim.getConstructor(SpongeJNI.class, String.class).newInstance(plugin, id);
+    }
+
+}
@@ -0,0 +1,32 @@
+package me.kyleclemens.spongejni;
+
+import org.spongepowered.api.Game;
+
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.nio.file.Paths;
+
+/**
+ * Stands in for the plugin, holding the game and config directory for the native library.
+ */
+public class SpongeJNI {
+
+    @SuppressWarnings("unused") // used by rust
+    private final Game game;
+    private final Path configDir;
+
+    /**
+     * Gives the plugin a new config directory, so that servers do not see each other's files.
+     */
+    public SpongeJNI(Game game) throws IOException {
+        this.game = game;
+        Path root = Files.createDirectories(Paths.get(System.getProperty("spongejni.data")));
+        this.configDir = Files.createTempDirectory(root, "config");
+    }
+
+    public Path getConfigDir() {
+        return this.configDir;
+    }
+
+}
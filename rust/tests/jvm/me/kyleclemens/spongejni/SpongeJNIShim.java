@@ -0,0 +1,92 @@
+package me.kyleclemens.spongejni;
+
+import me.kyleclemens.spongejni.harness.TestLogger;
+import org.slf4j.Logger;
+
+import java.io.File;
+import java.util.HashMap;
+import java.util.Map;
+
+public class SpongeJNIShim {
+
+    private final SpongeJNI plugin;
+    private final String id;
+    private byte[] previousState;
+    private final Logger logger = new TestLogger();
+    private Object config;
+    private final Map<String, String> comments = new HashMap<>();
+
+    public SpongeJNIShim(SpongeJNI plugin, String id) {
+        this.plugin = plugin;
+        this.id = id;
+    }
+
+    public SpongeJNI getPlugin() {
+        return this.plugin;
+    }
+
+    public String getId() {
+        return this.id;
+    }
+
+    public Logger getLogger() {
+        return this.logger;
+    }
+
+    public byte[] getPreviousState() {
+        return this.previousState;
+    }
+
+    public void setPreviousState(byte[] previousState) {
+        this.previousState = previousState;
+    }
+
+    /**
+     * Keeps the config in memory rather than in a file, merging the defaults and comments into it like Configurate.
+     */
+    public Object loadConfig(Object defaults, Map<String, String> comments) {
+        this.config = merge(this.config, defaults);
+        comments.forEach(this.comments::putIfAbsent);
+        return this.config;
+    }
+
+    public String getDataDirectory() {
+        File directory = this.plugin.getConfigDir().resolve(this.id).toFile();
+        directory.mkdirs();
+        return directory.getAbsolutePath();
+    }
+
+    public Object getConfig() {
+        return this.config;
+    }
+
+    public Map<String, String> getComments() {
+        return this.comments;
+    }
+
+    @SuppressWarnings("unchecked")
+    private static Object merge(Object value, Object defaults) {
+        if (value == null) {
+            return defaults;
+        }
+        if (value instanceof Map && defaults instanceof Map) {
+            Map<Object, Object> map = (Map<Object, Object>) value;
+            ((Map<Object, Object>) defaults).forEach((key, child) -> map.put(key, merge(map.get(key), child)));
+        }
+        return value;
+    }
+
+    public native boolean init();
+
+    public native void lifecycle(String state);
+
+    public native byte[] unload();
+
+    /**
+     * Loads a native library by path, rather than by name like the plugin, so the harness can load copies of it.
+     */
+    public static void load(String path) {
+        System.load(path);
+    }
+
+}
@@ -0,0 +1,19 @@
+package me.kyleclemens.spongejni.harness;
+
+import org.spongepowered.api.event.achievement.GrantAchievementEvent;
+import org.spongepowered.api.event.cause.Cause;
+
+public class TestAchievementEvent implements GrantAchievementEvent {
+
+    private final Cause cause;
+
+    public TestAchievementEvent(Object... causes) {
+        this.cause = new Cause(causes);
+    }
+
+    @Override
+    public Cause getCause() {
+        return this.cause;
+    }
+
+}
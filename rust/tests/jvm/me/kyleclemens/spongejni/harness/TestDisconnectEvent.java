@@ -0,0 +1,25 @@
+package me.kyleclemens.spongejni.harness;
+
+import org.spongepowered.api.entity.living.player.Player;
+import org.spongepowered.api.event.cause.Cause;
+import org.spongepowered.api.event.network.ClientConnectionEvent;
+
+public class TestDisconnectEvent implements ClientConnectionEvent.Disconnect {
+
+    private final Player player;
+
+    public TestDisconnectEvent(Player player) {
+        this.player = player;
+    }
+
+    @Override
+    public Player getTargetEntity() {
+        return this.player;
+    }
+
+    @Override
+    public Cause getCause() {
+        return new Cause(this.player);
+    }
+
+}
@@ -0,0 +1,22 @@
+package me.kyleclemens.spongejni.harness;
+
+import org.spongepowered.api.Game;
+import org.spongepowered.api.command.CommandManager;
+import org.spongepowered.api.event.EventManager;
+
+public class TestGame implements Game {
+
+    private final CommandManager commandManager = new CommandManager();
+    private final EventManager eventManager = new EventManager();
+
+    @Override
+    public CommandManager getCommandManager() {
+        return this.commandManager;
+    }
+
+    @Override
+    public EventManager getEventManager() {
+        return this.eventManager;
+    }
+
+}
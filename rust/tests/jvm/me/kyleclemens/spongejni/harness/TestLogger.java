@@ -0,0 +1,99 @@
+package me.kyleclemens.spongejni.harness;
+
+import org.slf4j.Logger;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Keeps what is logged at info and above, as {@code LEVEL message} lines, with the message of the throwable if there
+ * is one.
+ */
+public class TestLogger implements Logger {
+
+    private final List<String> lines = new ArrayList<>();
+
+    public String getLines() {
+        return String.join("\n", this.lines);
+    }
+
+    private void log(String level, String message, Throwable throwable) {
+        this.lines.add(level + " " + message + (throwable == null ? "" : " (" + throwable.getMessage() + ")"));
+    }
+
+    @Override
+    public boolean isErrorEnabled() {
+        return true;
+    }
+
+    @Override
+    public boolean isWarnEnabled() {
+        return true;
+    }
+
+    @Override
+    public boolean isInfoEnabled() {
+        return true;
+    }
+
+    @Override
+    public boolean isDebugEnabled() {
+        return false;
+    }
+
+    @Override
+    public boolean isTraceEnabled() {
+        return false;
+    }
+
+    @Override
+    public void error(String message) {
+        this.log("ERROR", message, null);
+    }
+
+    @Override
+    public void error(String message, Throwable throwable) {
+        this.log("ERROR", message, throwable);
+    }
+
+    @Override
+    public void warn(String message) {
+        this.log("WARN", message, null);
+    }
+
+    @Override
+    public void warn(String message, Throwable throwable) {
+        this.log("WARN", message, throwable);
+    }
+
+    @Override
+    public void info(String message) {
+        this.log("INFO", message, null);
+    }
+
+    @Override
+    public void info(String message, Throwable throwable) {
+        this.log("INFO", message, throwable);
+    }
+
+    @Override
+    public void debug(String message) {
+        throw new IllegalStateException("debug is not enabled");
+    }
+
+    @Override
+    public void debug(String message, Throwable throwable) {
+        throw new IllegalStateException("debug is not enabled");
+    }
+
+    @Override
+    public void trace(String message) {
+        throw new IllegalStateException("trace is not enabled");
+    }
+
+    @Override
+    public void trace(String message, Throwable throwable) {
+        throw new IllegalStateException("trace is not enabled");
+    }
+
+}
@@ -0,0 +1,50 @@
+package me.kyleclemens.spongejni.harness;
+
+import org.spongepowered.api.entity.living.player.Player;
+import org.spongepowered.api.profile.GameProfile;
+import org.spongepowered.api.text.Text;
+
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.UUID;
+
+/**
+ * A player keeping the messages sent to it, whose profile's UUID is derived from its name.
+ */
+public class TestPlayer implements Player {
+
+    private final String name;
+    private final List<String> messages = new ArrayList<>();
+
+    public TestPlayer(String name) {
+        this.name = name;
+    }
+
+    @Override
+    public String getName() {
+        return this.name;
+    }
+
+    @Override
+    public GameProfile getProfile() {
+        UUID uuid = UUID.nameUUIDFromBytes(this.name.getBytes(StandardCharsets.UTF_8));
+        return () -> uuid;
+    }
+
+    @Override
+    public void sendMessage(Text message) {
+        this.messages.add(message.toPlain());
+    }
+
+    @Override
+    public void sendMessages(Text... messages) {
+        // differs from the default, so tests can tell which one was called
+        this.messages.add(messages.length + " messages");
+    }
+
+    public String getMessages() {
+        return String.join("\n", this.messages);
+    }
+
+}
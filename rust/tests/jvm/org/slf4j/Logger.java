@@ -0,0 +1,35 @@
+package org.slf4j;
+
+public interface Logger {
+
+    boolean isErrorEnabled();
+
+    boolean isWarnEnabled();
+
+    boolean isInfoEnabled();
+
+    boolean isDebugEnabled();
+
+    boolean isTraceEnabled();
+
+    void error(String message);
+
+    void error(String message, Throwable throwable);
+
+    void warn(String message);
+
+    void warn(String message, Throwable throwable);
+
+    void info(String message);
+
+    void info(String message, Throwable throwable);
+
+    void debug(String message);
+
+    void debug(String message, Throwable throwable);
+
+    void trace(String message);
+
+    void trace(String message, Throwable throwable);
+
+}
@@ -0,0 +1,12 @@
+package org.spongepowered.api;
+
+import org.spongepowered.api.command.CommandManager;
+import org.spongepowered.api.event.EventManager;
+
+public interface Game {
+
+    CommandManager getCommandManager();
+
+    EventManager getEventManager();
+
+}
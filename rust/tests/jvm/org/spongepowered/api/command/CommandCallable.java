@@ -0,0 +1,4 @@
+package org.spongepowered.api.command;
+
+public interface CommandCallable {
+}
@@ -0,0 +1,9 @@
+package org.spongepowered.api.command;
+
+public class CommandException extends Exception {
+
+    public CommandException(String message) {
+        super(message);
+    }
+
+}
@@ -0,0 +1,44 @@
+package org.spongepowered.api.command;
+
+import org.spongepowered.api.command.spec.CommandSpec;
+
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+
+/**
+ * Keeps registered commands so the harness can run them.
+ */
+public class CommandManager {
+
+    private final Map<String, CommandCallable> commands = new HashMap<>();
+
+    public Optional<CommandMapping> register(Object plugin, CommandCallable callable, List<String> aliases) {
+        for (String alias : aliases) {
+            this.commands.put(alias, callable);
+        }
+        return Optional.of(new CommandMapping(callable, aliases));
+    }
+
+    public Optional<CommandMapping> removeMapping(CommandMapping mapping) {
+        for (String alias : mapping.getAllAliases()) {
+            this.commands.remove(alias, mapping.getCallable());
+        }
+        return Optional.of(mapping);
+    }
+
+    public boolean containsAlias(String alias) {
+        return this.commands.containsKey(alias);
+    }
+
+    public CommandResult process(CommandSource source, String alias) {
+        CommandSpec spec = (CommandSpec) this.commands.get(alias);
+        try {
+            return spec.getExecutor().execute(source, null);
+        } catch (CommandException e) {
+            throw new IllegalStateException(e);
+        }
+    }
+
+}
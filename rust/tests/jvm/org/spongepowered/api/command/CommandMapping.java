@@ -0,0 +1,23 @@
+package org.spongepowered.api.command;
+
+import java.util.List;
+
+public class CommandMapping {
+
+    private final CommandCallable callable;
+    private final List<String> aliases;
+
+    public CommandMapping(CommandCallable callable, List<String> aliases) {
+        this.callable = callable;
+        this.aliases = aliases;
+    }
+
+    public CommandCallable getCallable() {
+        return this.callable;
+    }
+
+    public List<String> getAllAliases() {
+        return this.aliases;
+    }
+
+}
@@ -0,0 +1,11 @@
+package org.spongepowered.api.command;
+
+public class CommandResult {
+
+    private static final CommandResult SUCCESS = new CommandResult();
+
+    public static CommandResult success() {
+        return SUCCESS;
+    }
+
+}
@@ -0,0 +1,9 @@
+package org.spongepowered.api.command;
+
+import org.spongepowered.api.text.channel.MessageReceiver;
+
+public interface CommandSource extends MessageReceiver {
+
+    String getName();
+
+}
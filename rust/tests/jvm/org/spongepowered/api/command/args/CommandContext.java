@@ -0,0 +1,4 @@
+package org.spongepowered.api.command.args;
+
+public class CommandContext {
+}
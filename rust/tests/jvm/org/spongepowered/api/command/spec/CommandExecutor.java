@@ -0,0 +1,12 @@
+package org.spongepowered.api.command.spec;
+
+import org.spongepowered.api.command.CommandException;
+import org.spongepowered.api.command.CommandResult;
+import org.spongepowered.api.command.CommandSource;
+import org.spongepowered.api.command.args.CommandContext;
+
+public interface CommandExecutor {
+
+    CommandResult execute(CommandSource src, CommandContext args) throws CommandException;
+
+}
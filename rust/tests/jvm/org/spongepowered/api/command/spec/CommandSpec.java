@@ -0,0 +1,36 @@
+package org.spongepowered.api.command.spec;
+
+import org.spongepowered.api.command.CommandCallable;
+
+public final class CommandSpec implements CommandCallable {
+
+    private final CommandExecutor executor;
+
+    private CommandSpec(CommandExecutor executor) {
+        this.executor = executor;
+    }
+
+    public static Builder builder() {
+        return new Builder();
+    }
+
+    public CommandExecutor getExecutor() {
+        return this.executor;
+    }
+
+    public static final class Builder {
+
+        private CommandExecutor executor;
+
+        public Builder executor(CommandExecutor executor) {
+            this.executor = executor;
+            return this;
+        }
+
+        public CommandSpec build() {
+            return new CommandSpec(this.executor);
+        }
+
+    }
+
+}
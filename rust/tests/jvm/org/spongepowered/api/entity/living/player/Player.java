@@ -0,0 +1,6 @@
+package org.spongepowered.api.entity.living.player;
+
+import org.spongepowered.api.command.CommandSource;
+
+public interface Player extends User, CommandSource {
+}
@@ -0,0 +1,9 @@
+package org.spongepowered.api.entity.living.player;
+
+import org.spongepowered.api.profile.GameProfile;
+
+public interface User {
+
+    GameProfile getProfile();
+
+}
@@ -0,0 +1,9 @@
+package org.spongepowered.api.event;
+
+import org.spongepowered.api.event.cause.Cause;
+
+public interface Event {
+
+    Cause getCause();
+
+}
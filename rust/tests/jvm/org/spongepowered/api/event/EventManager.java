@@ -0,0 +1,45 @@
+package org.spongepowered.api.event;
+
+import java.lang.reflect.InvocationTargetException;
+import java.lang.reflect.Method;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Keeps registered listeners and posts events to their {@link Listener} methods whose parameter accepts them.
+ */
+public class EventManager {
+
+    private final List<Object> listeners = new ArrayList<>();
+
+    public void registerListeners(Object plugin, Object listener) {
+        this.listeners.add(listener);
+    }
+
+    public void unregisterListeners(Object listener) {
+        this.listeners.remove(listener);
+    }
+
+    public int getListenerCount() {
+        return this.listeners.size();
+    }
+
+    public Object getListener(int index) {
+        return this.listeners.get(index);
+    }
+
+    public int post(Event event) throws IllegalAccessException, InvocationTargetException {
+        int called = 0;
+        for (Object listener : this.listeners) {
+            for (Method method : listener.getClass().getDeclaredMethods()) {
+                if (method.isAnnotationPresent(Listener.class) && method.getParameterCount() == 1
+                        && method.getParameterTypes()[0].isInstance(event)) {
+                    method.invoke(listener, event);
+                    called++;
+                }
+            }
+        }
+        return called;
+    }
+
+}
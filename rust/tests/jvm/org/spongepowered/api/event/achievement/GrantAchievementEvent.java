@@ -0,0 +1,6 @@
+package org.spongepowered.api.event.achievement;
+
+import org.spongepowered.api.event.Event;
+
+public interface GrantAchievementEvent extends Event {
+}
@@ -0,0 +1,19 @@
+package org.spongepowered.api.event.cause;
+
+import java.util.Arrays;
+import java.util.List;
+import java.util.Optional;
+
+public final class Cause {
+
+    private final List<Object> causes;
+
+    public Cause(Object... causes) {
+        this.causes = Arrays.asList(causes);
+    }
+
+    public <T> Optional<T> first(Class<T> target) {
+        return this.causes.stream().filter(target::isInstance).map(target::cast).findFirst();
+    }
+
+}
@@ -0,0 +1,10 @@
+package org.spongepowered.api.event.entity.living.humanoid.player;
+
+import org.spongepowered.api.entity.living.player.Player;
+import org.spongepowered.api.event.Event;
+
+public interface TargetPlayerEvent extends Event {
+
+    Player getTargetEntity();
+
+}
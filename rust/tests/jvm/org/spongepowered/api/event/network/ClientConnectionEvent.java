@@ -0,0 +1,14 @@
+package org.spongepowered.api.event.network;
+
+import org.spongepowered.api.event.Event;
+import org.spongepowered.api.event.entity.living.humanoid.player.TargetPlayerEvent;
+
+public interface ClientConnectionEvent extends Event {
+
+    interface Join extends ClientConnectionEvent, TargetPlayerEvent {
+    }
+
+    interface Disconnect extends ClientConnectionEvent, TargetPlayerEvent {
+    }
+
+}
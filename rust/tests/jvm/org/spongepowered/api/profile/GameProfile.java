@@ -0,0 +1,6 @@
+package org.spongepowered.api.profile;
+
+import org.spongepowered.api.util.Identifiable;
+
+public interface GameProfile extends Identifiable {
+}
@@ -0,0 +1,16 @@
+package org.spongepowered.api.text;
+
+public class LiteralText extends Text {
+
+    private final String content;
+
+    LiteralText(String content) {
+        this.content = content;
+    }
+
+    @Override
+    public String toPlain() {
+        return this.content;
+    }
+
+}
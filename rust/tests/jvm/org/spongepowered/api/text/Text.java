@@ -0,0 +1,11 @@
+package org.spongepowered.api.text;
+
+public abstract class Text {
+
+    public static LiteralText of(String content) {
+        return new LiteralText(content);
+    }
+
+    public abstract String toPlain();
+
+}
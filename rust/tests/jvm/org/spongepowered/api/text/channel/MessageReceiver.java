@@ -0,0 +1,15 @@
+package org.spongepowered.api.text.channel;
+
+import org.spongepowered.api.text.Text;
+
+public interface MessageReceiver {
+
+    void sendMessage(Text message);
+
+    default void sendMessages(Text... messages) {
+        for (Text message : messages) {
+            this.sendMessage(message);
+        }
+    }
+
+}
@@ -0,0 +1,9 @@
+package org.spongepowered.api.util;
+
+import java.util.UUID;
+
+public interface Identifiable {
+
+    UUID getUniqueId();
+
+}
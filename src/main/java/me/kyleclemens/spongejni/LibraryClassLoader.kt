@@ -0,0 +1,52 @@
+package me.kyleclemens.spongejni
+
+/**
+ * A class loader for one native library, keeping it apart from the others.
+ *
+ * Java binds native methods to the libraries loaded by the class loader of their class, so this loader defines its own
+ * copies of [SpongeJNIShim] and [RustInvocationHandler] from the plugin jar, and loads the library for them. Each library
+ * then has its own `init` entry point, and the classes it defines live in its loader rather than the plugin's. Every
+ * other class, including SpongeAPI, comes from [parent].
+ *
+ * @param[parent] The plugin's class loader.
+ */
+class LibraryClassLoader(parent: ClassLoader) : ClassLoader(parent) {
+
+    override fun loadClass(name: String, resolve: Boolean): Class<*> {
+        if (ISOLATED.none { name == it || name.startsWith("$it$") }) {
+            return super.loadClass(name, resolve)
+        }
+        synchronized(this.getClassLoadingLock(name)) {
+            val clazz = this.findLoadedClass(name) ?: this.define(name)
+            if (resolve) {
+                this.resolveClass(clazz)
+            }
+            return clazz
+        }
+    }
+
+    private fun define(name: String): Class<*> {
+        val stream = this.parent.getResourceAsStream(name.replace('.', '/') + ".class") ?: throw ClassNotFoundException(name)
+        val bytes = stream.use { it.readBytes() }
+        return this.defineClass(name, bytes, 0, bytes.size)
+    }
+
+    /**
+     * Loads the native library [library] and creates the shim it is initialized through.
+     *
+     * The shim is an instance of this loader's copy of [SpongeJNIShim], so it can only be used reflectively.
+     *
+     * @param[plugin] The plugin loading the library.
+     * @param[id] The id of the native plugin in the library.
+     * @param[library] The name of the library, as given to [System.loadLibrary].
+     */
+    fun load(plugin: SpongeJNI, id: String, library: String): Any {
+        val shim = Class.forName(SpongeJNIShim::class.java.name, true, this)
+        shim.getMethod("load", String::class.java).invoke(null, library)
+        return shim.getConstructor(SpongeJNI::class.java, String::class.java).newInstance(plugin, id)
+    }
+
+    private companion object {
+        val ISOLATED = listOf(SpongeJNIShim::class.java.name, RustInvocationHandler::class.java.name)
+    }
+}
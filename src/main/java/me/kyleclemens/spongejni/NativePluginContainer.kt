@@ -0,0 +1,19 @@
+package me.kyleclemens.spongejni
+
+import org.spongepowered.api.plugin.PluginContainer
+import java.util.Optional
+
+/**
+ * The [PluginContainer] of a native plugin, giving it an id and a logger of its own.
+ *
+ * Sponge only knows the plugins it loaded itself, so commands and listeners are still registered for [SpongeJNI].
+ *
+ * @param[id] The id of the native plugin.
+ * @param[shim] The shim the native plugin is initialized through.
+ */
+class NativePluginContainer(private val id: String, private val shim: Any) : PluginContainer {
+
+    override fun getId(): String = this.id
+
+    override fun getInstance(): Optional<*> = Optional.of(this.shim)
+}
@@ -0,0 +1,57 @@
+package me.kyleclemens.spongejni
+
+import java.lang.reflect.InvocationHandler
+import java.lang.reflect.Method
+
+/**
+ * An [InvocationHandler] for proxies implemented in Rust.
+ *
+ * Every method of the proxied interfaces is passed to the native library by name and descriptor, along with
+ * [handle], which points to the Rust object implementing them. The `Object` methods are handled here, so that proxies
+ * can be used as map keys and printed.
+ *
+ * @param[handle] The Rust object, owned by this handler and dropped when it is finalized.
+ */
+class RustInvocationHandler(private val handle: Long) : InvocationHandler {
+
+    override fun invoke(proxy: Any, method: Method, args: Array<out Any?>?): Any? {
+        if (method.declaringClass == Any::class.java) {
+            return when (method.name) {
+                "equals" -> proxy === args!![0]
+                "hashCode" -> System.identityHashCode(proxy)
+                "toString" -> "RustProxy@${Integer.toHexString(System.identityHashCode(proxy))}"
+                else -> throw UnsupportedOperationException(method.name)
+            }
+        }
+        val descriptor = method.parameterTypes.joinToString("", "(", ")") { descriptor(it) } + descriptor(method.returnType)
+        return this.dispatch(this.handle, proxy, method.name, descriptor, args ?: arrayOf())
+    }
+
+    protected fun finalize() {
+        this.release(this.handle)
+    }
+
+    private external fun dispatch(handle: Long, proxy: Any, name: String, descriptor: String, args: Array<out Any?>): Any?
+
+    private external fun release(handle: Long)
+
+    private companion object {
+        /**
+         * The JVM type descriptor of [clazz], such as `I` or `Ljava/lang/String;`.
+         */
+        fun descriptor(clazz: Class<*>): String = when {
+            clazz == Void.TYPE -> "V"
+            clazz == java.lang.Boolean.TYPE -> "Z"
+            clazz == java.lang.Byte.TYPE -> "B"
+            clazz == java.lang.Character.TYPE -> "C"
+            clazz == java.lang.Short.TYPE -> "S"
+            clazz == java.lang.Integer.TYPE -> "I"
+            clazz == java.lang.Long.TYPE -> "J"
+            clazz == java.lang.Float.TYPE -> "F"
+            clazz == java.lang.Double.TYPE -> "D"
+            // array class names are already descriptors, with dots
+            clazz.isArray -> clazz.name.replace('.', '/')
+            else -> "L${clazz.name.replace('.', '/')};"
+        }
+    }
+}
@@ -1,26 +1,25 @@
 package me.kyleclemens.spongejni
 
 import com.google.inject.Inject
-import javassist.ClassPool
-import javassist.CtMethod
-import javassist.Modifier
-import javassist.bytecode.AnnotationsAttribute
-import javassist.bytecode.annotation.Annotation
 import ninja.leaping.configurate.commented.CommentedConfigurationNode
 import ninja.leaping.configurate.loader.ConfigurationLoader
 import org.slf4j.Logger
 import org.spongepowered.api.Game
-import org.spongepowered.api.command.CommandException
 import org.spongepowered.api.command.CommandResult
-import org.spongepowered.api.command.CommandSource
-import org.spongepowered.api.command.args.CommandContext
-import org.spongepowered.api.command.spec.CommandExecutor
+import org.spongepowered.api.command.spec.CommandSpec
 import org.spongepowered.api.config.DefaultConfig
-import org.spongepowered.api.event.Event
 import org.spongepowered.api.event.Listener
 import org.spongepowered.api.event.Order
+import org.spongepowered.api.event.game.GameReloadEvent
 import org.spongepowered.api.event.game.state.GameConstructionEvent
+import org.spongepowered.api.event.game.state.GameInitializationEvent
+import org.spongepowered.api.event.game.state.GamePreInitializationEvent
+import org.spongepowered.api.event.game.state.GameStartedServerEvent
+import org.spongepowered.api.event.game.state.GameStartingServerEvent
+import org.spongepowered.api.event.game.state.GameStoppingEvent
 import org.spongepowered.api.plugin.Plugin
+import org.spongepowered.api.text.Text
+import java.io.File
 import java.nio.file.Path
 
 /**
@@ -39,9 +38,20 @@ class SpongeJNI {
     @Inject
     @DefaultConfig(sharedRoot = true)
     private lateinit var configLoader: ConfigurationLoader<CommentedConfigurationNode>
-    @Suppress("unused") // used by rust
     @Inject
     private lateinit var game: Game
+    // the loaded libraries, whose shims keep their class loaders alive
+    private val libraries = mutableListOf<LoadedLibrary>()
+    // whether libraries are loaded from copies, so that they can be rebuilt and reloaded
+    private var dev = false
+    // the states the game went through, which libraries loaded later are taken through too
+    private val states = mutableListOf<String>()
+
+    /**
+     * The directory of SpongeJNI's config, where each native plugin has its own config file.
+     */
+    val configDir: Path
+        get() = this.configPath.parent
 
     @Listener(order = Order.FIRST)
     fun construction(event: GameConstructionEvent) {
@@ -55,91 +65,125 @@ class SpongeJNI {
             }
         }
         val config = this.configLoader.load()
-        val libName = config.getNode("lib").string
-        if (libName == null) {
-            this.logger.error("No library was set in the config. Set `lib` equal to the library name to load.")
-        } else {
-            System.loadLibrary(libName)
-            val shim = SpongeJNIShim(this)
-            if (!shim.init()) {
-                this.logger.warn("Library returned false during setup, which is indicative of an error.")
+        this.dev = config.getNode("dev").boolean
+        // native plugins by id, with the library each is in, falling back to the single library of older configs
+        val libraries = config.getNode("libs").childrenMap.map { it.key.toString() to it.value.string }.toMap() +
+            (config.getNode("lib").string?.let { mapOf(it to it) } ?: mapOf())
+        if (libraries.isEmpty()) {
+            this.logger.error("No libraries were set in the config. Set `libs` to a map of plugin ids to the library names to load.")
+        }
+        for ((id, libName) in libraries) {
+            if (libName == null) {
+                this.logger.error("No library was set for $id.")
+                continue
             }
+            this.load(id, libName, null)
+        }
+        if (this.dev) {
+            val reload = CommandSpec.builder()
+                .description(Text.of("Reloads the native libraries"))
+                .permission("spongejni.reload")
+                .executor { src, args ->
+                    this.reload()
+                    src.sendMessage(Text.of("Reloaded ${this.libraries.size} native libraries."))
+                    CommandResult.success()
+                }
+                .build()
+            this.game.commandManager.register(this, reload, "spongejni-reload")
+        }
+    }
+
+    @Listener
+    fun preInitialization(event: GamePreInitializationEvent) = this.enter("pre_init")
+
+    @Listener
+    fun initialization(event: GameInitializationEvent) = this.enter("init")
+
+    @Listener
+    fun serverStarting(event: GameStartingServerEvent) = this.enter("server_starting")
+
+    @Listener
+    fun serverStarted(event: GameStartedServerEvent) = this.enter("server_started")
+
+    @Listener
+    fun stopping(event: GameStoppingEvent) = this.enter("stopping")
+
+    @Listener
+    fun gameReload(event: GameReloadEvent) {
+        for (library in this.libraries) {
+            library.lifecycle("reload")
         }
     }
 
     /**
-     * Generates a [CommandExecutor] using javassist.
-     *
-     * The generated executor will contain one `public`, `native` method called `execute`, which receives a
-     * [CommandSource] and a [CommandContext] and returns a [CommandResult].
-     *
-     * @param[fqcn] The fully-qualified class name (separated with periods) of the class to generate. (example:
-     *              `com.example.generated.MyExecutor`)
+     * Passes the game [state] on to every library.
      */
-    @Suppress("unused") // rust methods
-    fun generateCommandExecutor(fqcn: String): CommandExecutor {
-        val pool = ClassPool.getDefault()
-        val cc = pool.makeClass(fqcn)
-        cc.addInterface(pool.get(CommandExecutor::class.java.name))
-        val executeMethod = CtMethod(
-            pool.get(CommandResult::class.java.name),
-            "execute",
-            arrayOf(pool.get(CommandSource::class.java.name), pool.get(CommandContext::class.java.name)),
-            cc
-        )
-        executeMethod.exceptionTypes = arrayOf(pool.get(CommandException::class.java.name))
-        executeMethod.modifiers = Modifier.PUBLIC or Modifier.NATIVE
-        cc.addMethod(executeMethod)
-        val clazz = cc.toClass()
-        return clazz.newInstance() as CommandExecutor
+    private fun enter(state: String) {
+        this.states.add(state)
+        for (library in this.libraries) {
+            library.lifecycle(state)
+        }
+    }
+
+    private fun load(id: String, libName: String, previousState: ByteArray?) {
+        val shim = LibraryClassLoader(this.javaClass.classLoader).load(this, id, if (this.dev) this.copy(libName) else libName)
+        shim.javaClass.getMethod("setPreviousState", ByteArray::class.java).invoke(shim, previousState)
+        val library = LoadedLibrary(id, libName, shim)
+        this.libraries.add(library)
+        if (!(shim.javaClass.getMethod("init").invoke(shim) as Boolean)) {
+            this.logger.warn("Library $libName returned false during setup, which is indicative of an error.")
+        }
+        for (state in this.states) {
+            library.lifecycle(state)
+        }
     }
 
     /**
-     * Generates an [Object] to be used as a listener. The object is generated using javassist.
-     *
-     * The generated object will contain zero or more `public`, `native` methods, depending on the contents in
-     * [classes].
-     *
-     * If [classes] contains the event GrantAchievementEvent, a method represented by the following source will be
-     * generated.
-     *
-     *
-     * ```
-     *     @Listener
-     *     public native grantAchievementEventReceived(GrantAchievementEvent event);
-     * ```
-     *
-     * A method following this pattern will be generated for every class in [classes].
-     *
-     * If [classes] is empty, an empty object will be generated.
+     * Unloads every library and loads the library file again, handing over the state the old library saved.
      *
-     * @param[fqcn] The fully-qualified class name (separated with periods) of the class to generate. (example:
-     *              `com.example.generated.MyListener`)
-     * @param[classes] The list of classes for which to generate listener methods.
+     * Only available in dev mode, where libraries are loaded from copies, since the JVM loads each file only once.
+     * A library that cannot be unloaded is kept, and one that cannot be loaded again is dropped, without stopping the
+     * others from being reloaded.
      */
-    @Suppress("unused") // rust methods
-    fun generateListeners(fqcn: String, classes: List<Class<out Event>>): Any {
-        val pool = ClassPool.getDefault()
-        val cc = pool.makeClass(fqcn)
-        for (clazz in classes) {
-            val name = clazz.simpleName
-            val eventMethodName = "${name[0].toLowerCase()}${name.substring(1)}Received"
-            val eventMethod = CtMethod(
-                pool.get(Void.TYPE.name),
-                eventMethodName,
-                arrayOf(pool.get(clazz.name)),
-                cc
-            )
-            eventMethod.modifiers = Modifier.PUBLIC or Modifier.NATIVE
-            val ccFile = cc.classFile
-            val constPool = ccFile.constPool
-            val attr = AnnotationsAttribute(constPool, AnnotationsAttribute.visibleTag)
-            val annotation = Annotation(Listener::class.java.name, constPool)
-            attr.addAnnotation(annotation)
-            eventMethod.methodInfo.addAttribute(attr)
-            cc.addMethod(eventMethod)
+    fun reload() {
+        for (library in this.libraries.toList()) {
+            val state = try {
+                library.shim.javaClass.getMethod("unload").invoke(library.shim) as ByteArray?
+            } catch (e: Exception) {
+                this.logger.error("Library ${library.name} could not be unloaded, so it was kept.", e)
+                continue
+            }
+            this.libraries.remove(library)
+            try {
+                this.load(library.id, library.name, state)
+            } catch (e: Throwable) {
+                // a missing or broken library file is an UnsatisfiedLinkError, which is not an Exception
+                this.logger.error("Library ${library.name} could not be loaded again.", e)
+                // load adds the new library before setting it up, so it may be there half set up
+                this.libraries.removeAll { it.id == library.id }
+            }
+        }
+        // lets the old class loaders, and the libraries loaded for them, be collected
+        System.gc()
+    }
+
+    /**
+     * Copies the library [libName] from the library path to a new temporary file, returning the path of the copy.
+     */
+    private fun copy(libName: String): String {
+        val fileName = System.mapLibraryName(libName)
+        val file = (System.getProperty("java.library.path").split(File.pathSeparator) + ".")
+            .map { File(it, fileName) }
+            .firstOrNull { it.exists() } ?: throw UnsatisfiedLinkError("no $libName in java.library.path")
+        val copy = File.createTempFile(libName, fileName)
+        copy.deleteOnExit()
+        file.copyTo(copy, overwrite = true)
+        return copy.absolutePath
+    }
+
+    private data class LoadedLibrary(val id: String, val name: String, val shim: Any) {
+        fun lifecycle(state: String) {
+            this.shim.javaClass.getMethod("lifecycle", String::class.java).invoke(this.shim, state)
         }
-        val clazz = cc.toClass()
-        return clazz.newInstance()
     }
 }
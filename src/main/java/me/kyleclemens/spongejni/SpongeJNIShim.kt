@@ -1,12 +1,117 @@
 package me.kyleclemens.spongejni;
 
-class SpongeJNIShim(val plugin: SpongeJNI) {
+import ninja.leaping.configurate.commented.SimpleCommentedConfigurationNode
+import ninja.leaping.configurate.hocon.HoconConfigurationLoader
+import org.slf4j.Logger
+import org.spongepowered.api.plugin.PluginContainer
+import java.io.File
+import java.lang.reflect.Proxy
+
+/**
+ * The entry point of one native library, loaded by its [LibraryClassLoader].
+ *
+ * @param[plugin] The plugin that loaded the library.
+ * @param[id] The id of the native plugin in the library.
+ */
+class SpongeJNIShim(val plugin: SpongeJNI, val id: String) {
+
+    @Suppress("unused") // used by rust
+    val container: PluginContainer = NativePluginContainer(this.id, this)
+
+    /**
+     * The logger of the native plugin, named after its id, which the `log` crate writes to.
+     */
+    @Suppress("unused") // used by rust
+    val logger: Logger = this.container.logger
+
+    /**
+     * The state saved by the library this one replaced when SpongeJNI reloaded it, or null.
+     */
+    @Suppress("unused") // used by rust
+    var previousState: ByteArray? = null
+
+    /**
+     * The directory of the native plugin's own files, `<id>` in the [SpongeJNI.configDir], which is created if needed.
+     */
+    @Suppress("unused") // used by rust
+    val dataDirectory: String
+        get() = this.plugin.configDir.resolve(this.id).toFile().apply { this.mkdirs() }.absolutePath
+
     /**
      * Native method called when the shim is ready for external code.
      *
-     * The native library should do an initial setup, such as registering listeners and commands, here.
+     * The native library should do an initial setup here, such as registering its lifecycle, which is then told about
+     * the states of the game.
      *
      * @return true if the native library was set up successfully, false if there was an error
      */
     external fun init(): Boolean
+
+    /**
+     * Native method called when the game enters [state], such as `init` or `stopping`, after [init].
+     */
+    external fun lifecycle(state: String)
+
+    /**
+     * Native method called before the library is replaced by a newly built one.
+     *
+     * The native library should unregister everything it registered and release its global references here. Nothing
+     * may call into it afterwards.
+     *
+     * @return the state to hand over to the new library, or null
+     */
+    external fun unload(): ByteArray?
+
+    /**
+     * Creates a [Proxy] implementing [interfaces], whose methods are handled by a Rust object.
+     *
+     * No class is defined for it: every call goes through a [RustInvocationHandler], which passes the method name and
+     * descriptor to this shim's native library.
+     *
+     * @param[interfaces] The interfaces the proxy implements.
+     * @param[handle] The pointer to the Rust object, which the proxy takes ownership of.
+     */
+    @Suppress("unused") // rust methods
+    fun implement(interfaces: List<Class<*>>, handle: Long): Any {
+        return Proxy.newProxyInstance(this.javaClass.classLoader, interfaces.toTypedArray(), RustInvocationHandler(handle))
+    }
+
+    /**
+     * Loads the config of the native plugin from `<id>.conf` in the [SpongeJNI.configDir], returning its values as
+     * maps, lists, strings, numbers and booleans.
+     *
+     * Keys missing from the file are added from [defaults], given in the same form, and the comments in [comments],
+     * by dotted path, are set on the keys that have none, before the file is saved.
+     */
+    @Suppress("unused") // used by rust
+    fun loadConfig(defaults: Any?, comments: Map<String, String>): Any? {
+        val loader = HoconConfigurationLoader.builder()
+            .setPath(this.plugin.configDir.resolve("${this.id}.conf"))
+            .build()
+        val config = loader.load()
+        config.mergeValuesFrom(SimpleCommentedConfigurationNode.root().setValue(defaults))
+        for ((path, comment) in comments) {
+            val node = config.getNode(*path.split('.').toTypedArray<Any>())
+            if (!node.comment.isPresent) {
+                node.setComment(comment)
+            }
+        }
+        loader.save(config)
+        return config.value
+    }
+
+    companion object {
+        /**
+         * Loads the native library [library] for the [LibraryClassLoader] of this class, either by name or, if it is an
+         * absolute path, from that file.
+         */
+        @JvmStatic
+        fun load(library: String) {
+            if (File(library).isAbsolute) {
+                System.load(library)
+            } else {
+                System.loadLibrary(library)
+            }
+        }
+    }
 }